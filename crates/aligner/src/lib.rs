@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashSet};
+use veryl_parser::resource_table;
 use veryl_parser::veryl_token::{Token, VerylToken};
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct Location {
     pub line: u32,
     pub column: u32,
@@ -39,7 +40,8 @@ pub struct Align {
     width: u32,
     line: u32,
     rest: Vec<(Location, u32)>,
-    additions: HashMap<Location, u32>,
+    additions: BTreeMap<Location, u32>,
+    skip_lines: HashSet<u32>,
     pub last_location: Option<Location>,
 }
 
@@ -55,7 +57,7 @@ impl Align {
     pub fn finish_item(&mut self) {
         self.enable = false;
         if let Some(loc) = self.last_location {
-            if self.line > loc.line || loc.line - self.line > 1 {
+            if self.line > loc.line || self.has_gap(loc.line) {
                 self.finish_group();
             }
             self.max_width = u32::max(self.max_width, self.width);
@@ -67,6 +69,30 @@ impl Align {
         }
     }
 
+    /// Finishes the item without aligning it.
+    /// The item keeps the group continuous, but doesn't affect the width of the group.
+    pub fn discard_item(&mut self) {
+        self.enable = false;
+        if let Some(loc) = self.last_location {
+            if self.line > loc.line || self.has_gap(loc.line) {
+                self.finish_group();
+            }
+            self.line = loc.line;
+
+            self.width = 0;
+            self.index += 1;
+        }
+    }
+
+    /// Width of the current item
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    fn has_gap(&self, line: u32) -> bool {
+        (self.line + 1..line).any(|x| !self.skip_lines.contains(&x))
+    }
+
     pub fn start_item(&mut self) {
         self.enable = true;
         self.width = 0;
@@ -125,8 +151,10 @@ pub mod align_kind {
 
 #[derive(Default)]
 pub struct Aligner {
-    pub additions: HashMap<Location, u32>,
+    pub additions: BTreeMap<Location, u32>,
     pub aligns: [Align; 9],
+    /// Lines containing only comments don't split alignment groups
+    pub skip_comment_line: bool,
 }
 
 impl Aligner {
@@ -138,6 +166,22 @@ impl Aligner {
         for i in 0..self.aligns.len() {
             self.aligns[i].token(x);
         }
+
+        if self.skip_comment_line {
+            let mut line = x.token.line;
+            for comment in &x.comments {
+                let text = resource_table::get_str_value(comment.text).unwrap();
+                let end = comment.line + text.trim_end().matches('\n').count() as u32;
+                // comment starting at a new line is placed at comment-only lines,
+                // but doc comment is kept as the separator of documented items
+                if comment.line > line && !text.starts_with("///") {
+                    for x in comment.line..=end {
+                        self.skip_line(x);
+                    }
+                }
+                line = end;
+            }
+        }
     }
 
     pub fn space(&mut self, x: usize) {
@@ -152,6 +196,14 @@ impl Aligner {
         }
     }
 
+    /// Marks the line as transparent for group continuity.
+    /// Items separated only by skipped lines are aligned as the same group.
+    pub fn skip_line(&mut self, line: u32) {
+        for i in 0..self.aligns.len() {
+            self.aligns[i].skip_lines.insert(line);
+        }
+    }
+
     pub fn gather_additions(&mut self) {
         for align in &self.aligns {
             for (x, y) in &align.additions {
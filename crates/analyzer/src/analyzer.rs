@@ -1,24 +1,30 @@
 use crate::analyzer::resource_table::PathId;
-use crate::analyzer_error::AnalyzerError;
+use crate::analyzer_error::{AnalyzerError, AnalyzerErrorCode};
+use crate::attribute::{Attribute as Attr, LintLevel};
 use crate::attribute_table;
+use crate::cdc_table;
 use crate::handlers::*;
+use crate::import_usage::{self, UnusedImportKind};
 use crate::msb_table;
 use crate::namespace::Namespace;
 use crate::namespace_table;
 use crate::symbol::{
     Direction, DocComment, Symbol, SymbolId, SymbolKind, TypeKind, VariableAffiliation,
 };
-use crate::symbol_table;
+use crate::symbol_path::SymbolPath;
+use crate::symbol_table::{self, is_sv_keyword};
 use crate::type_dag;
+use crate::unsafe_table;
 use crate::var_ref::{
     AssignPosition, AssignPositionTree, AssignPositionType, ExpressionTargetType, VarRef,
     VarRefAffiliation, VarRefPath, VarRefType,
 };
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use veryl_metadata::{Build, Lint, Metadata};
+use veryl_metadata::{Build, EnabledFeatures, Lint, LintSeverity, Metadata};
 use veryl_parser::resource_table;
+use veryl_parser::timing;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, TokenSource};
 use veryl_parser::veryl_walker::{Handler, VerylWalker};
@@ -101,6 +107,361 @@ impl<'a> AnalyzerPass3<'a> {
         ret
     }
 
+    pub fn check_emitted_name(&self, project_name: &str, build_opt: &Build) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+
+        let emitted: Vec<_> = self
+            .symbols
+            .iter()
+            .filter_map(|x| emitted_name(x, project_name, build_opt).map(|name| (x, name)))
+            .collect();
+
+        let mut used = None;
+        for (symbol, name) in &emitted {
+            if symbol.token.source != self.path {
+                continue;
+            }
+            for (other, other_name) in &emitted {
+                if symbol.id == other.id || name != other_name {
+                    continue;
+                }
+
+                let used = used.get_or_insert_with(|| used_symbols(project_name));
+                let other_text = format!(
+                    "{} of project {} ({}:{}:{})",
+                    other.token,
+                    other.namespace.paths[0],
+                    other.token.source,
+                    other.token.line,
+                    other.token.column,
+                );
+                let project = symbol.namespace.paths[0].to_string();
+                let identifier = symbol.token.to_string();
+                let token = &symbol.token.into();
+                if used.contains(&symbol.id) && used.contains(&other.id) {
+                    ret.push(AnalyzerError::duplicated_emitted_name(
+                        &identifier,
+                        &project,
+                        name,
+                        &other_text,
+                        self.text,
+                        token,
+                    ));
+                } else {
+                    ret.push(AnalyzerError::duplicated_emitted_name_unused(
+                        &identifier,
+                        &project,
+                        name,
+                        &other_text,
+                        self.text,
+                        token,
+                    ));
+                }
+            }
+        }
+
+        ret
+    }
+
+    pub fn check_emitted_signal_name(&self, build_opt: &Build) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+
+        // Declarations are emitted into the same scope as their namespace
+        let mut scopes: HashMap<&Namespace, Vec<(&Symbol, String)>> = HashMap::new();
+        for symbol in &self.symbols {
+            if symbol.token.source != self.path {
+                continue;
+            }
+            if let Some(name) = emitted_signal_name(symbol) {
+                scopes
+                    .entry(&symbol.namespace)
+                    .or_default()
+                    .push((symbol, name));
+            }
+        }
+
+        for declarations in scopes.values() {
+            for (symbol, name) in declarations {
+                if !is_rewritten(symbol) {
+                    continue;
+                }
+                let identifier = symbol.token.to_string();
+                let token = &symbol.token.into();
+
+                if is_sv_keyword(name) && !build_opt.escape_sv_keyword {
+                    ret.push(AnalyzerError::sv_keyword_emitted_name(
+                        &identifier,
+                        name,
+                        self.text,
+                        token,
+                    ));
+                }
+
+                for (other, other_name) in declarations {
+                    // declarations with the same identifier are reported as duplicated
+                    if symbol.id == other.id
+                        || name != other_name
+                        || symbol.token.text == other.token.text
+                    {
+                        continue;
+                    }
+
+                    let other_text = format!(
+                        "{} ({}:{}:{})",
+                        other.token, other.token.source, other.token.line, other.token.column,
+                    );
+                    ret.push(AnalyzerError::emitted_name_collision(
+                        &identifier,
+                        name,
+                        &other_text,
+                        self.text,
+                        token,
+                        &other.token,
+                    ));
+                    if !is_rewritten(other) {
+                        ret.push(AnalyzerError::emitted_name_collision(
+                            &other.token.to_string(),
+                            other_name,
+                            &format!(
+                                "{} ({}:{}:{})",
+                                symbol.token,
+                                symbol.token.source,
+                                symbol.token.line,
+                                symbol.token.column,
+                            ),
+                            self.text,
+                            &other.token.into(),
+                            &symbol.token,
+                        ));
+                    }
+                }
+            }
+        }
+
+        ret
+    }
+
+    pub fn check_shadowed_identifiers(&self) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+
+        // Package items imported to namespaces
+        let mut imported: HashMap<_, Vec<&Symbol>> = HashMap::new();
+        for symbol in &self.symbols {
+            if !symbol.imported.is_empty() && declaration_kind(&symbol.kind).is_some() {
+                imported.entry(symbol.token.text).or_default().push(symbol);
+            }
+        }
+
+        for symbol in &self.symbols {
+            if symbol.token.source != self.path || declaration_kind(&symbol.kind).is_none() {
+                continue;
+            }
+
+            // Declarations of the outer namespaces
+            let mut outer = None;
+            let mut namespace = symbol.namespace.clone();
+            if namespace.pop().is_some() && namespace.depth() > 0 {
+                let path = SymbolPath::new(&[symbol.token.text]);
+                if let Ok(found) = symbol_table::resolve((&path, &namespace)) {
+                    outer = Some(found.found);
+                }
+            }
+            if outer.is_none() {
+                outer = imported
+                    .get(&symbol.token.text)
+                    .and_then(|x| {
+                        x.iter().find(|x| {
+                            x.id != symbol.id
+                                && x.imported.iter().any(|x| symbol.namespace.included(x))
+                        })
+                    })
+                    .map(|x| (*x).clone());
+            }
+            let Some(outer) = outer else {
+                continue;
+            };
+            if outer.id == symbol.id {
+                continue;
+            }
+            // Arguments are passed explicitly, so only shadowing of generic parameters is reported
+            if matches!(symbol.kind, SymbolKind::Port(_))
+                && !matches!(outer.kind, SymbolKind::GenericParameter(_))
+            {
+                continue;
+            }
+
+            let identifier = symbol.token.to_string();
+            match &outer.kind {
+                SymbolKind::Namespace => ret.push(AnalyzerError::shadowed_builtin(
+                    &identifier,
+                    "namespace",
+                    self.text,
+                    &symbol.token.into(),
+                )),
+                SymbolKind::SystemFunction => ret.push(AnalyzerError::shadowed_builtin(
+                    &identifier,
+                    "function",
+                    self.text,
+                    &symbol.token.into(),
+                )),
+                kind => {
+                    let Some(kind) = declaration_kind(kind) else {
+                        continue;
+                    };
+                    let shadowed = format!(
+                        "{kind} {} ({}:{}:{})",
+                        outer.token, outer.token.source, outer.token.line, outer.token.column,
+                    );
+                    let definition = if outer.token.source == self.path {
+                        Some(outer.token.into())
+                    } else {
+                        None
+                    };
+                    ret.push(AnalyzerError::shadowed_identifier(
+                        &identifier,
+                        &shadowed,
+                        self.text,
+                        &symbol.token.into(),
+                        definition.as_ref(),
+                    ));
+                }
+            }
+        }
+
+        ret
+    }
+
+    pub fn check_imports(&self, input: &Veryl) -> Vec<AnalyzerError> {
+        import_usage::unused_imports(input)
+            .into_iter()
+            .map(|x| match x.kind {
+                UnusedImportKind::Unused => {
+                    AnalyzerError::unused_import(&x.name, self.text, &x.range)
+                }
+                UnusedImportKind::Duplicated => {
+                    AnalyzerError::duplicated_import(&x.name, self.text, &x.range)
+                }
+            })
+            .collect()
+    }
+
+    /// Reports each instantiation cycle once at the first declared module of the cycle
+    pub fn check_recursive_instantiation(&self) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+        let mut reported = HashSet::new();
+
+        for (parent, child, token) in type_dag::instances() {
+            if token.source == self.path {
+                if let Some(path) = type_dag::instance_path(child, parent) {
+                    let parent = type_dag::get_symbol(parent);
+                    let position =
+                        |x: &Symbol| (x.token.source.to_string(), x.token.line, x.token.column);
+                    let anchor = path.iter().min_by_key(|x| position(x)).unwrap();
+                    if anchor.id != parent.id {
+                        continue;
+                    }
+
+                    let mut cycle: Vec<_> = path.iter().map(|x| x.id).collect();
+                    cycle.sort();
+                    if !reported.insert(cycle) {
+                        continue;
+                    }
+
+                    let path: Vec<_> = std::iter::once(&parent)
+                        .chain(path.iter())
+                        .map(|x| x.token.to_string())
+                        .collect();
+                    ret.push(AnalyzerError::recursive_instantiation(
+                        &parent.token.to_string(),
+                        &path.join(" -> "),
+                        self.text,
+                        &token.into(),
+                    ));
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// Reports modules, interfaces and packages of the project which are not reachable from
+    /// `tops` through instantiation, imports and type references.
+    /// Nothing is reported if `tops` is empty because all items of library are exported.
+    pub fn check_unused_modules(&self, project_name: &str, tops: &[String]) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+        if tops.is_empty() {
+            return ret;
+        }
+
+        let project_name = resource_table::insert_str(project_name);
+        let items: Vec<_> = self
+            .symbols
+            .iter()
+            .filter(|x| {
+                matches!(
+                    x.kind,
+                    SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_)
+                ) && x.namespace.paths == [project_name]
+            })
+            .collect();
+
+        // Items declared in a module are treated as the module itself
+        let owner = |x: &Symbol| {
+            let paths = &x.namespace.paths;
+            (paths[0], paths.get(1).copied().unwrap_or(x.token.text))
+        };
+
+        let mut dependencies: HashMap<_, Vec<_>> = HashMap::new();
+        for symbol in type_dag::toposort() {
+            let from = owner(&symbol);
+            for x in type_dag::dependencies(symbol.id) {
+                let to = owner(&x);
+                if from != to {
+                    dependencies.entry(from).or_default().push(to);
+                }
+            }
+        }
+
+        let is_root = |x: &Symbol| {
+            tops.contains(&x.token.to_string())
+                || attribute_table::get(&x.token)
+                    .iter()
+                    .any(|x| matches!(x, Attr::Testbench | Attr::Test(..)))
+        };
+        let mut stack: Vec<_> = items
+            .iter()
+            .filter(|x| is_root(x))
+            .map(|x| owner(x))
+            .collect();
+        let mut reachable = HashSet::new();
+        while let Some(x) = stack.pop() {
+            if reachable.insert(x) {
+                if let Some(x) = dependencies.get(&x) {
+                    stack.extend(x.iter().copied());
+                }
+            }
+        }
+
+        let items: Vec<_> = items
+            .into_iter()
+            .filter(|x| x.token.source == self.path)
+            .collect();
+        let whole_file = items.iter().all(|x| !reachable.contains(&owner(x)));
+        for item in items {
+            if !reachable.contains(&owner(item)) {
+                ret.push(AnalyzerError::unused_module(
+                    &item.kind.to_kind_name(),
+                    &item.token.to_string(),
+                    whole_file,
+                    self.text,
+                    &(&item.token).into(),
+                ));
+            }
+        }
+
+        ret
+    }
+
     pub fn check_assignment(&self) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
 
@@ -128,7 +489,10 @@ impl<'a> AnalyzerPass3<'a> {
             if positions.is_empty() {
                 let full_path = path.full_path();
                 let symbol = symbol_table::get(*full_path.first().unwrap()).unwrap();
-                if must_be_assigned(&symbol.kind) {
+                // output ports of DPI imported function are assigned by foreign function
+                if must_be_assigned(&symbol.kind)
+                    && !attribute_table::contains(&symbol.token, Attr::DpiImport)
+                {
                     let path: Vec<_> = full_path
                         .iter()
                         .map(|x| symbol_table::get(*x).unwrap().token.to_string())
@@ -220,8 +584,69 @@ impl<'a> AnalyzerPass3<'a> {
 }
 
 pub struct Analyzer {
+    project_name: String,
     build_opt: Build,
     lint_opt: Lint,
+    lint_levels: HashMap<AnalyzerErrorCode, LintLevel>,
+    features: HashMap<String, EnabledFeatures>,
+}
+
+fn warn_invalid_lint_code(key: &str, code: &str) {
+    let codes: Vec<_> = AnalyzerErrorCode::all()
+        .iter()
+        .map(|x| x.as_str())
+        .collect();
+    log::warn!(
+        "{code} in {key} is not a valid lint code, valid codes are {}",
+        codes.join(", ")
+    );
+}
+
+fn is_enabled_description(
+    arg: &mut DescriptionGroup,
+    project_name: &str,
+    features: &EnabledFeatures,
+    text: &str,
+    errors: &mut Vec<AnalyzerError>,
+) -> bool {
+    for x in &arg.description_group_list {
+        let attr: Result<Attr, _> = x.attribute.as_ref().try_into();
+        if let Ok(Attr::Feature(name)) = attr {
+            let name = name.to_string();
+            if !features.available.contains(&name) {
+                let available = if features.available.is_empty() {
+                    "none".to_string()
+                } else {
+                    features.available.join(", ")
+                };
+                errors.push(AnalyzerError::unknown_feature(
+                    &name,
+                    project_name,
+                    &available,
+                    text,
+                    &x.attribute.identifier.as_ref().into(),
+                ));
+            } else if !features.enabled.contains(&name) {
+                return false;
+            }
+        }
+    }
+
+    if let DescriptionGroupGroup::LBraceDescriptionGroupGroupListRBrace(x) =
+        arg.description_group_group.as_mut()
+    {
+        x.description_group_group_list.retain_mut(|x| {
+            is_enabled_description(
+                &mut x.description_group,
+                project_name,
+                features,
+                text,
+                errors,
+            )
+        });
+    }
+
+    true
 }
 
 fn new_namespace(name: &str) -> (Token, Symbol) {
@@ -256,49 +681,140 @@ impl Analyzer {
                 }
             }
         }
+        let prj = resource_table::insert_str(&metadata.project.name);
+        for (local_name, name) in &metadata.workspace_dependencies {
+            let (token, symbol) = new_namespace(name);
+            symbol_table::insert(&token, symbol);
+            if local_name != name {
+                let from = resource_table::insert_str(local_name);
+                let to = resource_table::insert_str(name);
+                symbol_table::add_project_local(prj, from, to);
+            }
+        }
+        let mut lint_levels = HashMap::new();
+        for (codes, level, key) in [
+            (&metadata.lint.allow, LintLevel::Allow, "allow"),
+            (&metadata.lint.warn, LintLevel::Warn, "warn"),
+            (&metadata.lint.deny, LintLevel::Deny, "deny"),
+        ] {
+            for code in codes {
+                if let Some(code) = AnalyzerErrorCode::from_name(code) {
+                    lint_levels.insert(code, level);
+                } else {
+                    warn_invalid_lint_code(&format!("[lint] {key}"), code);
+                }
+            }
+        }
+        for (code, severity) in &metadata.lint.severity {
+            if let Some(code) = AnalyzerErrorCode::from_name(code) {
+                let level = match severity {
+                    LintSeverity::Allow => LintLevel::Allow,
+                    LintSeverity::Warn => LintLevel::Warn,
+                    LintSeverity::Deny => LintLevel::Deny,
+                };
+                lint_levels.insert(code, level);
+            } else {
+                warn_invalid_lint_code("[lint.severity]", code);
+            }
+        }
         Analyzer {
+            project_name: metadata.project.name.clone(),
             build_opt: metadata.build.clone(),
             lint_opt: metadata.lint.clone(),
+            lint_levels,
+            features: metadata.feature_table.clone(),
         }
     }
 
+    /// Applies lint level by the innermost `allow`/`warn`/`deny` attribute
+    /// or the project-wide `[lint]` table.
+    /// `allow` suppresses the error, `warn` demotes it to Warning severity,
+    /// and `deny` promotes it to Error severity.
+    /// Structural errors are always reported as error.
+    fn apply_lint_level(&self, errors: Vec<AnalyzerError>) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+        for error in errors {
+            let code = error.error_code();
+            if code.is_structural() {
+                ret.push(error);
+                continue;
+            }
+
+            let level = attribute_table::lint_level(&error.token().beg, code);
+            match level.or_else(|| self.lint_levels.get(&code).copied()) {
+                Some(LintLevel::Allow) => (),
+                Some(LintLevel::Warn) => ret.push(AnalyzerError::warned(error)),
+                Some(LintLevel::Deny) => ret.push(AnalyzerError::denied(error)),
+                None => ret.push(error),
+            }
+        }
+        ret
+    }
+
+    /// Removes declarations disabled by `#[feature]` attribute.
+    /// This should be called before `analyze_pass1` so that disabled declarations are not registered.
+    pub fn apply_features(
+        &self,
+        project_name: &str,
+        text: &str,
+        input: &mut Veryl,
+    ) -> Vec<AnalyzerError> {
+        let mut ret = Vec::new();
+
+        if let Some(features) = self.features.get(project_name) {
+            input.veryl_list.retain_mut(|x| {
+                is_enabled_description(
+                    &mut x.description_group,
+                    project_name,
+                    features,
+                    text,
+                    &mut ret,
+                )
+            });
+        }
+
+        self.apply_lint_level(ret)
+    }
+
     pub fn analyze_pass1<T: AsRef<Path>>(
         &self,
         project_name: &str,
         text: &str,
-        _path: T,
+        path: T,
         input: &Veryl,
     ) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
 
         namespace_table::set_default(&[project_name.into()]);
         let mut pass1 = AnalyzerPass1::new(text, &self.build_opt, &self.lint_opt);
-        pass1.veryl(input);
+        timing::measure("pass1", Some(path.as_ref()), || pass1.veryl(input));
         ret.append(&mut pass1.handlers.get_errors());
 
-        ret
+        self.apply_lint_level(ret)
     }
 
     pub fn analyze_post_pass1() {
-        symbol_table::apply_import();
-        symbol_table::resolve_user_defined();
+        timing::measure("resolve", None, || {
+            symbol_table::apply_import();
+            symbol_table::resolve_user_defined();
+        });
     }
 
     pub fn analyze_pass2<T: AsRef<Path>>(
         &self,
         project_name: &str,
         text: &str,
-        _path: T,
+        path: T,
         input: &Veryl,
     ) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
 
         namespace_table::set_default(&[project_name.into()]);
         let mut pass2 = AnalyzerPass2::new(text, &self.build_opt, &self.lint_opt);
-        pass2.veryl(input);
+        timing::measure("pass2", Some(path.as_ref()), || pass2.veryl(input));
         ret.append(&mut pass2.handlers.get_errors());
 
-        ret
+        self.apply_lint_level(ret)
     }
 
     pub fn analyze_pass3<T: AsRef<Path>>(
@@ -306,25 +822,151 @@ impl Analyzer {
         project_name: &str,
         text: &str,
         path: T,
-        _input: &Veryl,
+        input: &Veryl,
     ) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
 
         namespace_table::set_default(&[project_name.into()]);
         let pass3 = AnalyzerPass3::new(path.as_ref(), text);
-        ret.append(&mut pass3.check_variables());
-        ret.append(&mut pass3.check_assignment());
-        ret.append(&mut pass3.check_unassigned());
+        timing::measure("pass3", Some(path.as_ref()), || {
+            ret.append(&mut pass3.check_variables());
+            ret.append(&mut pass3.check_emitted_name(&self.project_name, &self.build_opt));
+            ret.append(&mut pass3.check_emitted_signal_name(&self.build_opt));
+            ret.append(&mut pass3.check_shadowed_identifiers());
+            ret.append(&mut pass3.check_imports(input));
+            ret.append(&mut pass3.check_recursive_instantiation());
+            if self.lint_opt.unused_module {
+                ret.append(
+                    &mut pass3.check_unused_modules(&self.project_name, &self.build_opt.top),
+                );
+            }
+            ret.append(&mut pass3.check_assignment());
+            ret.append(&mut pass3.check_unassigned());
+        });
 
-        ret
+        self.apply_lint_level(ret)
     }
 
     pub fn clear(&self) {
         attribute_table::clear();
+        cdc_table::clear();
         msb_table::clear();
         namespace_table::clear();
         symbol_table::clear();
         type_dag::clear();
+        unsafe_table::clear();
+    }
+}
+
+/// Returns the name emitted for the top-level declaration in SystemVerilog
+fn emitted_name(symbol: &Symbol, project_name: &str, build_opt: &Build) -> Option<String> {
+    let generic = match &symbol.kind {
+        SymbolKind::Module(x) => !x.generic_parameters.is_empty(),
+        SymbolKind::Interface(x) => !x.generic_parameters.is_empty(),
+        SymbolKind::Package(x) => !x.generic_parameters.is_empty(),
+        _ => return None,
+    };
+    if generic
+        || symbol.namespace.paths.len() != 1
+        || !matches!(symbol.token.source, TokenSource::File(_))
+    {
+        return None;
+    }
+
+    let project = symbol.namespace.paths[0].to_string();
+    let name_override = build_opt.name_override(&project);
+    let prefix = if let Some(prefix) = name_override.and_then(|x| x.prefix.clone()) {
+        prefix
+    } else if project == "$std" {
+        "std_".to_string()
+    } else if project == "$sv" {
+        return None;
+    } else if build_opt.omit_project_prefix && project == project_name {
+        String::new()
+    } else {
+        format!("{project}_")
+    };
+
+    let suffix = name_override
+        .and_then(|x| x.suffix.as_deref())
+        .unwrap_or("");
+
+    let text = symbol.token.to_string();
+    let text = text.strip_prefix("r#").unwrap_or(&text);
+    Some(format!("{prefix}{text}{suffix}"))
+}
+
+/// Returns the prefix and suffix added to the port or variable by the build options
+fn prefix_suffix(symbol: &Symbol) -> (Option<&str>, Option<&str>) {
+    match &symbol.kind {
+        SymbolKind::Port(x) => (x.prefix.as_deref(), x.suffix.as_deref()),
+        SymbolKind::Variable(x) => (x.prefix.as_deref(), x.suffix.as_deref()),
+        _ => (None, None),
+    }
+}
+
+fn is_rewritten(symbol: &Symbol) -> bool {
+    let (prefix, suffix) = prefix_suffix(symbol);
+    prefix.is_some() || suffix.is_some()
+}
+
+/// Returns the emitted name of the declaration which shares the scope with ports and variables
+fn emitted_signal_name(symbol: &Symbol) -> Option<String> {
+    if !matches!(
+        symbol.kind,
+        SymbolKind::Port(_)
+            | SymbolKind::Variable(_)
+            | SymbolKind::Parameter(_)
+            | SymbolKind::Instance(_)
+    ) {
+        return None;
+    }
+
+    let (prefix, suffix) = prefix_suffix(symbol);
+    let text = symbol.token.to_string();
+    let text = text.strip_prefix("r#").unwrap_or(&text);
+    Some(format!(
+        "{}{text}{}",
+        prefix.unwrap_or(""),
+        suffix.unwrap_or("")
+    ))
+}
+
+/// Returns symbols which are emitted by the project build
+fn used_symbols(project_name: &str) -> HashSet<SymbolId> {
+    let mut prj_namespace = Namespace::new();
+    prj_namespace.push(resource_table::insert_str(project_name));
+
+    let mut ret: HashSet<_> = type_dag::connected_components()
+        .into_iter()
+        .filter(|symbols| symbols.iter().any(|x| x.namespace.included(&prj_namespace)))
+        .flatten()
+        .map(|x| x.id)
+        .collect();
+    ret.extend(
+        symbol_table::get_all()
+            .into_iter()
+            .filter(|x| x.namespace.included(&prj_namespace))
+            .map(|x| x.id),
+    );
+    ret
+}
+
+/// Returns the kind name of declarations which can shadow each other
+fn declaration_kind(kind: &SymbolKind) -> Option<&'static str> {
+    match kind {
+        SymbolKind::Port(_) => Some("port"),
+        SymbolKind::Variable(_) => Some("variable"),
+        SymbolKind::Parameter(_) => Some("parameter"),
+        SymbolKind::GenericParameter(_) => Some("generic parameter"),
+        SymbolKind::Function(_) => Some("function"),
+        SymbolKind::Instance(_) => Some("instance"),
+        SymbolKind::TypeDef(_) => Some("type"),
+        SymbolKind::Struct(_) => Some("struct"),
+        SymbolKind::Union(_) => Some("union"),
+        SymbolKind::Enum(_) => Some("enum"),
+        SymbolKind::Genvar => Some("genvar"),
+        _ => None,
     }
 }
 
@@ -520,13 +1162,32 @@ fn check_multiple_assignment(
                 AssignPositionType::DeclarationBranch { .. }
                 | AssignPositionType::Declaration { .. } => {
                     if !x_partial | !y_partial {
-                        ret.push(AnalyzerError::multiple_assignment(
-                            &symbol.token.to_string(),
-                            text,
-                            &symbol.token.into(),
-                            &x_pos.0.last().unwrap().token().into(),
-                            &y_pos.0.last().unwrap().token().into(),
-                        ));
+                        let x_token = x_pos.0.last().unwrap().token();
+                        let y_token = y_pos.0.last().unwrap().token();
+                        let let_pos = if x_pos.0[0].is_let() {
+                            Some((x_token, y_token))
+                        } else if y_pos.0[0].is_let() {
+                            Some((y_token, x_token))
+                        } else {
+                            None
+                        };
+                        if let Some((let_token, assign_token)) = let_pos {
+                            ret.push(AnalyzerError::let_reassignment(
+                                &symbol.token.to_string(),
+                                text,
+                                &symbol.token.into(),
+                                &let_token.into(),
+                                &assign_token.into(),
+                            ));
+                        } else {
+                            ret.push(AnalyzerError::multiple_assignment(
+                                &symbol.token.to_string(),
+                                text,
+                                &symbol.token.into(),
+                                &x_token.into(),
+                                &y_token.into(),
+                            ));
+                        }
                     }
                 }
                 _ => (),
@@ -560,6 +1221,15 @@ fn check_assign_position_tree(
         ));
     }
 
+    if let Some(token) = tree.check_always_ff_assigned_only_in_reset() {
+        ret.push(AnalyzerError::assigned_only_in_reset(
+            &symbol.token.to_string(),
+            text,
+            &symbol.token.into(),
+            &token.into(),
+        ));
+    }
+
     if let Some(token) = tree.check_always_ff_missing_reset() {
         ret.push(AnalyzerError::missing_reset_statement(
             &symbol.token.to_string(),
@@ -1,6 +1,8 @@
-use miette::{self, Diagnostic, NamedSource, SourceSpan};
+use miette::{self, Diagnostic, NamedSource, Severity, SourceSpan};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use thiserror::Error;
-use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_token::{Token, TokenRange};
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum AnalyzerError {
@@ -11,6 +13,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -29,6 +32,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -45,21 +49,175 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(recursive_instantiation),
+        help("parameter-bounded recursion is unsupported"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#recursive_instantiation")
+    )]
+    #[error("module {identifier} is instantiated recursively ({path})")]
+    RecursiveInstantiation {
+        identifier: String,
+        path: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
         code(duplicated_identifier),
-        help(""),
+        help("{hint}"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_identifier")
     )]
     #[error("{identifier} is duplicated")]
     DuplicatedIdentifier {
+        identifier: String,
+        hint: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declared here")]
+        declared_location: Option<SourceSpan>,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(duplicated_attribute),
+        help("remove the redundant attribute"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_attribute"
+        )
+    )]
+    #[error("{attribute} attribute is duplicated")]
+    DuplicatedAttribute {
+        attribute: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(conflicting_attribute),
+        help("remove either attribute"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#conflicting_attribute"
+        )
+    )]
+    #[error("{attribute} attribute conflicts with {other}")]
+    ConflictingAttribute {
+        attribute: String,
+        other: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(duplicated_emitted_name),
+        help("rename either declaration so that their emitted names differ"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_emitted_name")
+    )]
+    #[error(
+        "{identifier} of project {project} is emitted as {emitted}, which collides with {other}"
+    )]
+    DuplicatedEmittedName {
+        identifier: String,
+        project: String,
+        emitted: String,
+        other: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(duplicated_emitted_name_unused),
+        help("this becomes an error when both declarations are used by the project"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_emitted_name_unused")
+    )]
+    #[error("{identifier} of project {project} is emitted as {emitted}, which collides with {other} unused in the project")]
+    DuplicatedEmittedNameUnused {
+        identifier: String,
+        project: String,
+        emitted: String,
+        other: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(emitted_name_collision),
+        help("rename either declaration, or change the prefix and suffix in the build options"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#emitted_name_collision")
+    )]
+    #[error("{identifier} is emitted as {emitted}, which collides with {other}")]
+    EmittedNameCollision {
+        identifier: String,
+        emitted: String,
+        other: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declared here")]
+        declared_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(sv_keyword_emitted_name),
+        help("rename it, or enable escape_sv_keyword to emit it as an escaped identifier"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#sv_keyword_emitted_name")
+    )]
+    #[error("{identifier} is emitted as {emitted}, which is a SystemVerilog keyword")]
+    SvKeywordEmittedName {
+        identifier: String,
+        emitted: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(multiple_init_file),
+        help("remove redundant init_file attributes"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#multiple_init_file"
+        )
+    )]
+    #[error("multiple init_file attributes are applied to {identifier}")]
+    MultipleInitFile {
         identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -75,6 +233,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
         #[label("Assigned")]
         assign_pos0: SourceSpan,
         #[label("Assigned too")]
@@ -83,17 +242,58 @@ pub enum AnalyzerError {
 
     #[diagnostic(
         severity(Error),
+        code(let_reassignment),
+        help("declare it by var if it is assigned in multiple places"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#let_reassignment")
+    )]
+    #[error("{identifier} is declared by let, so it can't be assigned elsewhere")]
+    LetReassignment {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declared by let")]
+        let_pos: SourceSpan,
+        #[label("Assigned again")]
+        assign_pos: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(hierarchical_reference),
+        help("connect it through ports or interfaces"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#hierarchical_reference"
+        )
+    )]
+    #[error("{path} refers to {identifier} which is not declared in the current module")]
+    HierarchicalReference {
+        identifier: String,
+        path: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
         code(invalid_allow),
-        help(""),
+        help("valid codes are {codes}"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_allow")
     )]
-    #[error("{identifier} can't be allowed")]
+    #[error("{identifier} is not a valid lint code")]
     InvalidAllow {
         identifier: String,
+        codes: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -112,6 +312,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -130,6 +331,137 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_assignment_to_input),
+        help("remove the assignment or change the port direction"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_assignment_to_input"
+        )
+    )]
+    #[error("{identifier} can't be assigned because it is input port")]
+    InvalidAssignmentToInput {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declaration")]
+        definition_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_output_connection),
+        help("connect a variable or a concatenation of variables"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_output_connection"
+        )
+    )]
+    #[error("{direction} port {port} can't be connected to expression")]
+    InvalidOutputConnection {
+        port: String,
+        direction: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declaration")]
+        definition_location: Option<SourceSpan>,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_attribute_target),
+        help("apply it to {target}"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_attribute_target"
+        )
+    )]
+    #[error("{attribute} attribute can't be applied here")]
+    InvalidAttributeTarget {
+        attribute: String,
+        target: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_init_file_target),
+        help("apply it to a variable with unpacked array"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_init_file_target"
+        )
+    )]
+    #[error("init_file attribute can be applied to unpacked array variable only")]
+    InvalidInitFileTarget {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_dpi_target),
+        help("apply it to a function declaration"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_dpi_target"
+        )
+    )]
+    #[error("{attribute} attribute can be applied to function declaration only")]
+    InvalidDpiTarget {
+        attribute: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_dpi_type),
+        help("use bit, logic, u32, u64, i32, i64, f32, f64 or string without width and array"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_dpi_type")
+    )]
+    #[error("{identifier} of DPI function {function} is not DPI-compatible")]
+    InvalidDpiType {
+        identifier: String,
+        function: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_dpi_import_body),
+        help("remove statements because DPI imported function is implemented in foreign language"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_dpi_import_body"
+        )
+    )]
+    #[error("DPI imported function {identifier} should have empty body")]
+    InvalidDpiImportBody {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -147,6 +479,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -163,6 +496,40 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_interface_value),
+        help("only members of interface can be accessed like \"{identifier}.member\""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_interface_value")
+    )]
+    #[error("interface {identifier} cannot be used as a value")]
+    InvalidInterfaceValue {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_width_expression),
+        help("use const, param or genvar instead"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_width_expression")
+    )]
+    #[error("{identifier} of kind \"{kind}\" can't be used in width or array size because it is not constant")]
+    InvalidWidthExpression {
+        identifier: String,
+        kind: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -181,6 +548,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -195,6 +563,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -209,6 +578,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -223,6 +593,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -239,6 +610,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -256,6 +628,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -271,6 +644,7 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
@@ -286,6 +660,9 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declaration")]
+        definition_location: SourceSpan,
     },
 
     #[diagnostic(
@@ -301,1262 +678,4160 @@ pub enum AnalyzerError {
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declaration")]
+        definition_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_port_default_value), help(""), url(""))]
-    #[error("#{direction} port #{identifier} cannot have a port default value")]
-    InvalidPortDefaultValue {
+    #[diagnostic(
+        severity(Error),
+        code(unknown_modport_member),
+        help("declare it in the interface"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_modport_member")
+    )]
+    #[error("{identifier} is not a member of interface {interface}")]
+    UnknownModportMember {
         identifier: String,
-        direction: String,
+        interface: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(invalid_reset),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_reset")
+        code(duplicated_modport_item),
+        help("remove the duplicated item"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_modport_item")
     )]
-    #[error("#{identifier} can't be used as a reset because it is not 'reset' type nor a single bit signal")]
-    InvalidReset {
+    #[error("{identifier} is listed more than once in modport {modport}")]
+    DuplicatedModportItem {
         identifier: String,
+        modport: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("First item")]
+        first_location: SourceSpan,
     },
 
     #[diagnostic(
         severity(Error),
-        code(invalid_reset_non_elaborative),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_reset_non_elaborative")
+        code(invalid_modport_access),
+        help("change the modport direction or remove the access"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_modport_access")
     )]
-    #[error("Reset-value cannot be used because it is not evaluable at elaboration time")]
-    InvalidResetNonElaborative {
+    #[error("{identifier} can't be {access} because it is {direction} member of the modport")]
+    InvalidModportAccess {
+        identifier: String,
+        access: String,
+        direction: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(invalid_case_condition_non_elaborative),
-        help(""),
-        url("")
+        code(unexpandable_inout),
+        help("use `if enable {{ value }} else {{ 'z }}` pattern or add #[pad] attribute to the module"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unexpandable_inout")
     )]
-    #[error("Case condition value cannot be used because it is not evaluable at elaboration time")]
-    InvalidCaseConditionNonElaborative {
+    #[error("inout {identifier} can't be expanded because {reason}")]
+    UnexpandableInout {
+        identifier: String,
+        reason: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
-    #[diagnostic(severity(Error), code(invalid_cast), help(""), url(""))]
-    #[error("Casting from {from} to {to} is incompatible")]
-    InvalidCast {
-        from: String,
-        to: String,
+    #[diagnostic(
+        severity(Error),
+        code(unflattenable_struct_port),
+        help("change the type of the port or disable flatten_struct_ports"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unflattenable_struct_port")
+    )]
+    #[error("struct port {identifier} can't be flattened because {reason}")]
+    UnflattenableStructPort {
+        identifier: String,
+        reason: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
-    #[diagnostic(
-        severity(Error),
-        code(invalid_test),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_test")
-    )]
-    #[error("test is invalid because {cause}")]
-    InvalidTest {
-        cause: String,
+    #[diagnostic(severity(Error), code(invalid_port_default_value), help(""), url(""))]
+    #[error("#{direction} port #{identifier} cannot have a port default value")]
+    InvalidPortDefaultValue {
+        identifier: String,
+        direction: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(incompat_proto),
+        code(invalid_reset),
         help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#incompat_proto")
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_reset")
     )]
-    #[error("{identifier} is incompatible with {proto} because {cause}")]
-    IncompatProto {
+    #[error("#{identifier} can't be used as a reset because it is not 'reset' type nor a single bit signal")]
+    InvalidReset {
         identifier: String,
-        proto: String,
-        cause: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(missing_default_argument),
-        help("give default argument"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_default_argument")
+        code(invalid_reset_non_elaborative),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_reset_non_elaborative")
     )]
-    #[error("missing default argument for parameter {identifier}")]
-    MissingDefaultArgument {
-        identifier: String,
+    #[error("Reset-value cannot be used because it is not evaluable at elaboration time")]
+    InvalidResetNonElaborative {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(mismatch_function_arity),
-        help("fix function arguments"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_function_arity")
+        code(invalid_case_condition_non_elaborative),
+        help(""),
+        url("")
     )]
-    #[error("function \"{name}\" has {arity} arguments, but {args} arguments are supplied")]
-    MismatchFunctionArity {
-        name: String,
-        arity: usize,
-        args: usize,
+    #[error("Case condition value cannot be used because it is not evaluable at elaboration time")]
+    InvalidCaseConditionNonElaborative {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(mismatch_generics_arity),
-        help("fix generics arguments"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_generics_arity")
+        code(invalid_for_range_non_elaborative),
+        help("use constant values like parameters for the range"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_for_range_non_elaborative"
+        )
     )]
     #[error(
-        "generics \"{name}\" has {arity} generic arguments, but {args} arguments are supplied"
+        "Range of generate for cannot be used because it is not evaluable at elaboration time"
     )]
-    MismatchGenericsArity {
-        name: String,
-        arity: usize,
-        args: usize,
+    InvalidForRangeNonElaborative {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Error),
-        code(mismatch_attribute_args),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_attribute_args")
+        severity(Warning),
+        code(invalid_for_iteration_count),
+        help("check the range and step, or increase max_for_iterations"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_for_iteration_count"
+        )
     )]
-    #[error("Arguments of \"{name}\" is expected to \"{expected}\"")]
-    MismatchAttributeArgs {
-        name: String,
-        expected: String,
+    #[error("generate for over {range} has {count} iterations")]
+    InvalidForIterationCount {
+        range: String,
+        count: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(mismatch_type),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_type")
+        code(for_index_assignment),
+        help("use another variable instead of the loop variable"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#for_index_assignment"
+        )
     )]
-    #[error("\"{name}\" is expected to \"{expected}\", but it is \"{actual}\"")]
-    MismatchType {
-        name: String,
-        expected: String,
-        actual: String,
+    #[error("loop variable {identifier} of for over {range} can't be assigned in the loop body")]
+    ForIndexAssignment {
+        identifier: String,
+        range: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(mismatch_clock_domain),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_clock_domain")
+        code(invalid_assertion_expression),
+        help("compare the value explicitly to get 1-bit condition"),
+        url("")
     )]
-    #[error("Clock domain crossing is detected")]
-    MismatchClockDomain {
-        clock_domain: String,
-        other_domain: String,
+    #[error("Assertion expression should be 1-bit, but it is {width}-bit")]
+    InvalidAssertionExpression {
+        width: usize,
         #[source_code]
         input: NamedSource<String>,
-        #[label("clock domain {clock_domain}")]
+        #[label("Error location")]
         error_location: SourceSpan,
-        #[label("clock domain {other_domain}")]
-        other_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(severity(Error), code(invalid_cast), help(""), url(""))]
+    #[error("Casting from {from} to {to} is incompatible")]
+    InvalidCast {
+        from: String,
+        to: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(missing_if_reset),
-        help("add if_reset statement"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_if_reset")
+        code(invalid_parameter_value),
+        help("override it by a value within {range}"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_parameter_value"
+        )
     )]
-    #[error("if_reset statement is required for always_ff with reset signal")]
-    MissingIfReset {
+    #[error("{identifier} is overridden by {value}, which is out of {range}")]
+    InvalidParameterValue {
+        identifier: String,
+        value: isize,
+        range: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(missing_port),
-        help("add \"{port}\" port"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_port")
+        severity(Error),
+        code(invalid_concatenation_operand),
+        help("only integral types can be concatenated, and strings only in arguments of display functions"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_concatenation_operand"
+        )
     )]
-    #[error("module \"{name}\" has \"{port}\", but it is not connected")]
-    MissingPort {
-        name: String,
-        port: String,
+    #[error(
+        "operand {position} of concatenation has type {type_name}, which cannot be concatenated"
+    )]
+    InvalidConcatenationOperand {
+        position: usize,
+        type_name: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(missing_clock_signal),
-        help("add clock port"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_clock_signal")
+        code(invalid_repetition_count),
+        help("specify a constant positive integer"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_repetition_count"
+        )
     )]
-    #[error("clock signal is required for always_ff statement")]
-    MissingClockSignal {
+    #[error("repetition count {count} of operand {position} is not a constant positive integer")]
+    InvalidRepetitionCount {
+        count: String,
+        position: usize,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(missing_reset_signal),
-        help("add reset port"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_reset_signal")
+        code(invalid_test),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_test")
     )]
-    #[error("reset signal is required for always_ff with if_reset statement")]
-    MissingResetSignal {
+    #[error("test is invalid because {cause}")]
+    InvalidTest {
+        cause: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(missing_reset_statement),
-        help("add reset statement"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_reset_statement")
+        severity(Error),
+        code(incompat_proto),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#incompat_proto")
     )]
-    #[error("{name} is not reset in if_reset statement")]
-    MissingResetStatement {
-        name: String,
+    #[error("{identifier} is incompatible with {proto} because {cause}")]
+    IncompatProto {
+        identifier: String,
+        proto: String,
+        cause: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
-        #[label("Not reset")]
-        reset: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(missing_tri),
-        help("add tri type modifier"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_tri")
+        code(missing_default_argument),
+        help("give default argument"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_default_argument")
     )]
-    #[error("tri type modifier is required at inout port")]
-    MissingTri {
+    #[error("missing default argument for parameter {identifier}")]
+    MissingDefaultArgument {
+        identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(missing_clock_domain),
-        help("add clock domain annotation"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_clock_domain")
+        code(side_effect_in_expression),
+        help("call the function as a statement before the expression"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#side_effect_in_expression"
+        )
     )]
-    #[error("clock domain annotation is required when there are multiple clocks")]
-    MissingClockDomain {
+    #[error(
+        "function \"{name}\" with {direction} argument \"{argument}\" can't be called in {context}"
+    )]
+    SideEffectInExpression {
+        name: String,
+        direction: String,
+        argument: String,
+        context: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(sv_keyword_usage),
-        help("Change the identifier to a non-SystemVerilog keyword"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#sv_keyword_usage")
+        code(mismatch_function_arity),
+        help("fix function arguments"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_function_arity")
     )]
-    #[error("SystemVerilog keyword may not be used as identifier")]
-    SvKeywordUsage {
-        identifier: String,
+    #[error("function \"{name}\" has {arity} arguments, but {args} arguments are supplied")]
+    MismatchFunctionArity {
+        name: String,
+        arity: usize,
+        args: usize,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(sv_with_implicit_reset),
-        help("Use types with explicit synchronisity and polarity like `reset_async_low`"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#sv_with_implicit_reset")
+        code(mismatch_generics_arity),
+        help("fix generics arguments"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_generics_arity")
     )]
-    #[error("Reset type with implicit synchronisity and polarity can't be connected to SystemVerilog module")]
-    SvWithImplicitReset {
+    #[error(
+        "generics \"{name}\" has {arity} generic arguments, but {args} arguments are supplied"
+    )]
+    MismatchGenericsArity {
+        name: String,
+        arity: usize,
+        args: usize,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(invalid_enum_encoding),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_enum_encoding")
+        code(too_many_generic_arguments),
+        help("remove the extra generic arguments"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_many_generic_arguments")
     )]
-    #[error("{identifier} is not valid enum encoding")]
-    InvalidEnumEncoding {
-        identifier: String,
+    #[error("generics \"{name}\" takes at most {arity} generic arguments, but {args} arguments are supplied")]
+    TooManyGenericArguments {
+        name: String,
+        arity: usize,
+        args: usize,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(invalid_cond_type),
-        help(""),
-        url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_cond_type"
-        )
+        code(mismatch_generic_kind),
+        help("pass a {expected} to the generic parameter"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_generic_kind")
     )]
-    #[error("{identifier} is not valid condition type")]
-    InvalidCondType {
-        identifier: String,
+    #[error(
+        "generic parameter \"{parameter}\" expects a {expected}, but \"{argument}\" is a {actual}"
+    )]
+    MismatchGenericKind {
+        parameter: String,
+        argument: String,
+        expected: String,
+        actual: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(too_large_enum_variant),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_large_enum_variant")
+        code(mismatch_assignment_style),
+        help("assign {identifier} by \"=\" before \"{operator}\" in the same block"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_assignment_style")
     )]
-    #[error("The value of enum variant {identifier} is {value}, it is can't be represented by {width} bits")]
-    TooLargeEnumVariant {
+    #[error("\"{operator}\" to {identifier} in {block} refers the value before the block is evaluated, it is non-blocking style")]
+    MismatchAssignmentStyle {
         identifier: String,
-        value: isize,
-        width: usize,
+        operator: String,
+        block: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Error),
-        code(unevaluatable_enum_variant_value),
-        help(""),
-        url("")
+        severity(Warning),
+        code(mismatch_assignment_style_in_function),
+        help("move the assignment of {identifier} out of function {function}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_assignment_style_in_function")
     )]
-    #[error("The value of enum variant {identifier} cannot be evaluated")]
-    UnevaluatableEnumVariant {
+    #[error("{identifier} is assigned by blocking assignment in function {function} which is called from {block}")]
+    MismatchAssignmentStyleInFunction {
         identifier: String,
+        function: String,
+        block: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
-    #[diagnostic(severity(Error), code(invalid_enum_variant_value), help(""), url(""))]
-    #[error("The value of enum variant {identifier} is not encoded value by {encoding}")]
-    InvalidEnumVariant {
-        identifier: String,
-        encoding: String,
+    #[diagnostic(
+        severity(Warning),
+        code(constant_comparison),
+        help("fix the width of the operands, or cast by \"as\" if the comparison is intended"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#constant_comparison")
+    )]
+    #[error("Comparison between {left} and {right} is always {result} because the literal requires {width} bits")]
+    ConstantComparison {
+        left: String,
+        right: String,
+        width: usize,
+        result: bool,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Error),
-        code(too_large_number),
-        help("increase bit width"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_large_number")
+        severity(Warning),
+        code(mixed_signed_comparison),
+        help("cast by \"as\" to make the signedness of the operands explicit"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mixed_signed_comparison")
     )]
-    #[error("number is over the maximum size of {width} bits")]
-    TooLargeNumber {
-        width: usize,
+    #[error("Comparison between {left} and {right} mixes signed and unsigned operands, so it is evaluated as unsigned")]
+    MixedSignedComparison {
+        left: String,
+        right: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(too_much_enum_variant),
+        code(mismatch_attribute_args),
         help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_much_enum_variant")
-    )]
-    #[error(
-        "enum {identifier} has {number} variants, they are can't be represented by {width} bits"
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_attribute_args")
     )]
-    TooMuchEnumVariant {
-        identifier: String,
-        number: usize,
-        width: usize,
+    #[error("Arguments of \"{name}\" is expected to \"{expected}\"")]
+    MismatchAttributeArgs {
+        name: String,
+        expected: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(undefined_identifier),
+        code(mismatch_type),
         help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#undefined_identifier")
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_type")
     )]
-    #[error("{identifier} is undefined")]
-    UndefinedIdentifier {
-        identifier: String,
+    #[error("\"{name}\" is expected to \"{expected}\", but it is \"{actual}\"")]
+    MismatchType {
+        name: String,
+        expected: String,
+        actual: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(referring_package_before_definition),
-        help("change order of package definitions"),
-        url("")
+        code(interface_array_index_out_of_range),
+        help("use index less than the array size"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#interface_array_index_out_of_range")
     )]
-    #[error("pakcakge {identifier} is referred before it is defined.")]
-    ReferringPackageBeforeDefinition {
+    #[error(
+        "index {index} is out of range of interface array \"{identifier}\" whose size is {size}"
+    )]
+    InterfaceArrayIndexOutOfRange {
         identifier: String,
+        index: isize,
+        size: isize,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declaration")]
+        definition_location: SourceSpan,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unresolvable_generic_argument),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unresolvable_generic_argument")
+        code(mismatch_interface_array_size),
+        help("connect an element of the interface array, or change the array size of the port"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_interface_array_size")
     )]
-    #[error("{identifier} can't be resolved from the definition of generics")]
-    UnresolvableGenericArgument {
+    #[error(
+        "interface \"{identifier}\" {actual} can't be connected to port \"{port}\" {expected}"
+    )]
+    MismatchInterfaceArraySize {
         identifier: String,
+        port: String,
+        expected: String,
+        actual: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
-        #[label("Definition")]
+        token: TokenRange,
+        #[label("Declaration")]
         definition_location: SourceSpan,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_attribute),
+        code(mismatch_clock_domain),
         help(""),
-        url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_attribute"
-        )
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_clock_domain")
     )]
-    #[error("\"{name}\" is not valid attribute")]
-    UnknownAttribute {
-        name: String,
+    #[error("Clock domain crossing is detected")]
+    MismatchClockDomain {
+        clock_domain: String,
+        other_domain: String,
         #[source_code]
         input: NamedSource<String>,
-        #[label("Error location")]
+        #[label("clock domain {clock_domain}")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("clock domain {other_domain}")]
+        other_location: SourceSpan,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_embed_lang),
-        help(""),
-        url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_embed_lang"
-        )
+        code(missing_if_reset),
+        help("add if_reset statement"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_if_reset")
     )]
-    #[error("\"{name}\" is not valid embed language")]
-    UnknownEmbedLang {
-        name: String,
+    #[error("if_reset statement is required for always_ff with reset signal")]
+    MissingIfReset {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Error),
-        code(unknown_embed_way),
-        help(""),
-        url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_embed_way"
-        )
+        severity(Warning),
+        code(missing_port),
+        help("add \"{port}\" port"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_port")
     )]
-    #[error("\"{name}\" is not valid embed way")]
-    UnknownEmbedWay {
+    #[error("module \"{name}\" has \"{port}\", but it is not connected")]
+    MissingPort {
         name: String,
+        port: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_include_way),
-        help(""),
-        url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_include_way"
-        )
+        code(missing_clock_signal),
+        help("add clock port"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_clock_signal")
     )]
-    #[error("\"{name}\" is not valid include way")]
-    UnknownIncludeWay {
-        name: String,
+    #[error("clock signal is required for always_ff statement")]
+    MissingClockSignal {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_member),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_member")
+        code(missing_assertion_clock),
+        help("add a single clock port to be used as default clock"),
+        url("")
     )]
-    #[error("\"{name}\" doesn't have member \"{member}\"")]
-    UnknownMember {
+    #[error("concurrent assertion requires default clock of the enclosing module")]
+    MissingAssertionClock {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(missing_reset),
+        help("add if_reset statement to always_ff"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_reset")
+    )]
+    #[error("{name} is assigned in always_ff without reset")]
+    MissingReset {
         name: String,
-        member: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_unsafe),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_unsafe")
+        code(missing_reset_signal),
+        help("add reset port"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_reset_signal")
     )]
-    #[error("\"{name}\" is not valid unsafe identifier")]
-    UnknownUnsafe {
+    #[error("reset signal is required for always_ff with if_reset statement")]
+    MissingResetSignal {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(missing_reset_statement),
+        help("add reset statement"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_reset_statement")
+    )]
+    #[error("{name} is not reset in if_reset statement")]
+    MissingResetStatement {
         name: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Not reset")]
+        reset: SourceSpan,
     },
 
     #[diagnostic(
-        severity(Error),
-        code(private_member),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#private_member")
+        severity(Warning),
+        code(assigned_only_in_reset),
+        help("assign it in the non-reset branch, or make it a constant"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#assigned_only_in_reset")
     )]
-    #[error("\"{name}\" is private member")]
-    PrivateMember {
+    #[error("{name} is assigned only in the reset branch and holds its reset value forever")]
+    AssignedOnlyInReset {
         name: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Reset branch")]
+        reset: SourceSpan,
     },
 
     #[diagnostic(
-        severity(Error),
-        code(unknown_msb),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_msb")
+        severity(Warning),
+        code(missing_init_file),
+        help("the path is resolved relative to the source file"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_init_file"
+        )
     )]
-    #[error("resolving msb is failed")]
-    UnknownMsb {
+    #[error("init file \"{path}\" is not found")]
+    MissingInitFile {
+        path: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_port),
-        help("remove \"{port}\" port"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_port")
+        code(missing_tri),
+        help("add tri type modifier"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_tri")
     )]
-    #[error("module \"{name}\" doesn't have port \"{port}\", but it is connected")]
-    UnknownPort {
-        name: String,
-        port: String,
+    #[error("tri type modifier is required at inout port")]
+    MissingTri {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(unknown_param),
-        help("remove \"{param}\" param"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_param")
+        code(missing_clock_domain),
+        help("add clock domain annotation"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_clock_domain")
     )]
-    #[error("module \"{name}\" doesn't have param \"{param}\", but it is overrided")]
-    UnknownParam {
-        name: String,
-        param: String,
+    #[error("clock domain annotation is required when there are multiple clocks")]
+    MissingClockDomain {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(unused_variable),
-        help("add prefix `_` to unused variable name"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unused_variable")
+        severity(Error),
+        code(sv_keyword_usage),
+        help("Change the identifier to a non-SystemVerilog keyword"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#sv_keyword_usage")
     )]
-    #[error("{identifier} is unused")]
-    UnusedVariable {
+    #[error("SystemVerilog keyword may not be used as identifier")]
+    SvKeywordUsage {
         identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(unused_return),
-        help("add variable assignment for function return"),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unused_return")
+        severity(Error),
+        code(sv_with_implicit_reset),
+        help("Use types with explicit synchronisity and polarity like `reset_async_low`"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#sv_with_implicit_reset")
     )]
-    #[error("return value of {identifier} is unused")]
-    UnusedReturn {
-        identifier: String,
+    #[error("Reset type with implicit synchronisity and polarity can't be connected to SystemVerilog module")]
+    SvWithImplicitReset {
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(unassign_variable),
+        severity(Error),
+        code(invalid_enum_encoding),
         help(""),
-        url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unassign_variable"
-        )
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_enum_encoding")
     )]
-    #[error("{identifier} is unassigned")]
-    UnassignVariable {
+    #[error("{identifier} is not valid enum encoding")]
+    InvalidEnumEncoding {
         identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
-        severity(Warning),
-        code(uncovered_branch),
-        help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#uncovered_branch")
+        severity(Error),
+        code(invalid_period),
+        help("specify a positive number followed by time unit like \"5ns\""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_period")
     )]
-    #[error("{identifier} is not covered by all branches, it causes latch generation")]
-    UncoveredBranch {
+    #[error("\"{identifier}\" is not valid clock period")]
+    InvalidPeriod {
         identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
-        #[label("Uncovered")]
-        uncovered: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(reserved_identifier),
-        help("prefix `__` can't be used"),
+        code(invalid_cond_type),
+        help(""),
         url(
-            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#reserved_identifier"
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_cond_type"
         )
     )]
-    #[error("{identifier} is reverved for compiler usage")]
-    ReservedIdentifier {
+    #[error("{identifier} is not valid condition type")]
+    InvalidCondType {
         identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(include_failure),
+        code(too_large_enum_variant),
         help(""),
-        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#include_failure")
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_large_enum_variant")
     )]
-    #[error("\"{name}\" can't be read because \"{cause}\"")]
-    IncludeFailure {
-        name: String,
-        cause: String,
+    #[error("The value of enum variant {identifier} is {value}, it is can't be represented by {width} bits")]
+    TooLargeEnumVariant {
+        identifier: String,
+        value: isize,
+        width: usize,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
 
     #[diagnostic(
         severity(Error),
-        code(wrong_seperator),
-        help("replace valid separator \"{valid_separator}\""),
+        code(unevaluatable_enum_variant_value),
+        help(""),
         url("")
     )]
-    #[error("separator \"{separator}\" can't be used at here")]
-    WrongSeparator {
-        separator: String,
-        valid_separator: String,
+    #[error("The value of enum variant {identifier} cannot be evaluated")]
+    UnevaluatableEnumVariant {
+        identifier: String,
         #[source_code]
         input: NamedSource<String>,
         #[label("Error location")]
         error_location: SourceSpan,
+        token: TokenRange,
     },
-}
 
-impl AnalyzerError {
-    fn named_source(source: &str, token: &TokenRange) -> NamedSource<String> {
-        NamedSource::new(token.beg.source.to_string(), source.to_string())
+    #[diagnostic(severity(Error), code(invalid_enum_variant_value), help(""), url(""))]
+    #[error("The value of enum variant {identifier} is not encoded value by {encoding}")]
+    InvalidEnumVariant {
+        identifier: String,
+        encoding: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(too_large_number),
+        help("increase bit width to {required} bits or more"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_large_number")
+    )]
+    #[error("number requires {required} bits, but it is over the maximum size of {width} bits")]
+    TooLargeNumber {
+        width: usize,
+        required: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(zero_width_number),
+        help("specify positive bit width"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#zero_width_number"
+        )
+    )]
+    #[error("bit width of number should be positive")]
+    ZeroWidthNumber {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(large_unsized_number),
+        help("add explicit bit width"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#large_unsized_number"
+        )
+    )]
+    #[error("unsized number requires {required} bits, but width of unsized number over 32 bits is implementation-defined")]
+    LargeUnsizedNumber {
+        required: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(zero_width),
+        help("specify positive bit width"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#zero_width")
+    )]
+    #[error("width is evaluated to 0")]
+    ZeroWidth {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(zero_repetition),
+        help("remove the item or specify positive repetition count"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#zero_repetition")
+    )]
+    #[error("repetition count of concatenation is evaluated to 0")]
+    ZeroRepetition {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(zero_by_default_parameter),
+        help("change the default value of {identifier}"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#zero_by_default_parameter"
+        )
+    )]
+    #[error("{context} is evaluated to 0 by the default value of parameter {identifier}")]
+    ZeroByDefaultParameter {
+        context: String,
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(redundant_width),
+        help("remove the width because 1-bit is the default"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#redundant_width")
+    )]
+    #[error("width 1 of {kind} is redundant")]
+    RedundantWidth {
+        kind: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(mismatch_branch_width),
+        help("the narrower branch is extended implicitly"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_branch_width"
+        )
+    )]
+    #[error("branch is {width} bits but the other branch is {other_width} bits")]
+    MismatchBranchWidth {
+        width: usize,
+        other_width: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(mismatch_concatenation_width),
+        help("the concatenation is extended or truncated implicitly"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_concatenation_width"
+        )
+    )]
+    #[error("expression is {width} bits but {target} expects {expected}")]
+    MismatchConcatenationWidth {
+        width: usize,
+        target: String,
+        expected: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(prefer_let),
+        help("replace the declaration by \"{fix}\" and remove the assign declaration"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#prefer_let")
+    )]
+    #[error(
+        "{identifier} is assigned only once by assign declaration, so it can be declared by let"
+    )]
+    PreferLet {
+        identifier: String,
+        fix: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Assigned only here")]
+        assign_pos: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(magic_number),
+        help("extract the value into a const with a descriptive name"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#magic_number")
+    )]
+    #[error("magic number {value} is used in {context}")]
+    MagicNumber {
+        value: String,
+        context: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(too_many_select),
+        help("remove extra select"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_many_select")
+    )]
+    #[error("\"{identifier}\" has {dimension} dimensions, but {select} selects are applied")]
+    TooManySelect {
+        identifier: String,
+        dimension: usize,
+        select: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(missing_array_index),
+        help("add index to select an array element"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_array_index")
+    )]
+    #[error(
+        "\"{identifier}\" has {dimension} array dimensions, but only {select} selects are applied"
+    )]
+    MissingArrayIndex {
+        identifier: String,
+        dimension: usize,
+        select: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(too_much_enum_variant),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_much_enum_variant")
+    )]
+    #[error(
+        "enum {identifier} has {number} variants, they are can't be represented by {width} bits"
+    )]
+    TooMuchEnumVariant {
+        identifier: String,
+        number: usize,
+        width: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(undefined_identifier),
+        help("{hint}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#undefined_identifier")
+    )]
+    #[error("{identifier} is undefined")]
+    UndefinedIdentifier {
+        identifier: String,
+        hint: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(referring_package_before_definition),
+        help("change order of package definitions"),
+        url("")
+    )]
+    #[error("pakcakge {identifier} is referred before it is defined.")]
+    ReferringPackageBeforeDefinition {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(use_before_declaration),
+        help("move the declaration before its use, or enable reorder_consts in [build]"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#use_before_declaration"
+        )
+    )]
+    #[error("{identifier} is used before it is declared")]
+    UseBeforeDeclaration {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Declaration")]
+        definition_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unresolvable_generic_argument),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unresolvable_generic_argument")
+    )]
+    #[error("{identifier} can't be resolved from the definition of generics")]
+    UnresolvableGenericArgument {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Definition")]
+        definition_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_attribute),
+        help("{hint}"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_attribute"
+        )
+    )]
+    #[error("\"{name}\" is not valid attribute")]
+    UnknownAttribute {
+        name: String,
+        hint: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_feature),
+        help("available features: {available}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_feature")
+    )]
+    #[error("feature \"{name}\" is not defined in project \"{project}\"")]
+    UnknownFeature {
+        name: String,
+        project: String,
+        available: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_embed_lang),
+        help(""),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_embed_lang"
+        )
+    )]
+    #[error("\"{name}\" is not valid embed language")]
+    UnknownEmbedLang {
+        name: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_embed_way),
+        help(""),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_embed_way"
+        )
+    )]
+    #[error("\"{name}\" is not valid embed way")]
+    UnknownEmbedWay {
+        name: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_include_way),
+        help(""),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_include_way"
+        )
+    )]
+    #[error("\"{name}\" is not valid include way")]
+    UnknownIncludeWay {
+        name: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_member),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_member")
+    )]
+    #[error("\"{name}\" doesn't have member \"{member}\"")]
+    UnknownMember {
+        name: String,
+        member: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_modport),
+        help("{hint}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_modport")
+    )]
+    #[error("interface \"{interface}\" doesn't have modport \"{modport}\"")]
+    UnknownModport {
+        interface: String,
+        modport: String,
+        hint: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(duplicated_struct_member),
+        help("remove the duplicated member"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_struct_member")
+    )]
+    #[error("member \"{member}\" of \"{name}\" is initialized more than once")]
+    DuplicatedStructMember {
+        name: String,
+        member: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(missing_struct_member),
+        help("initialize them, or add \"..default(value)\" to fill the rest"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#missing_struct_member")
+    )]
+    #[error("\"{name}\" is constructed without member {members}")]
+    MissingStructMember {
+        name: String,
+        members: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(non_synthesizable),
+        help(
+            "move it into a module with #[testbench] or #[test], or relax [lint] synthesis_subset"
+        ),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#non_synthesizable"
+        )
+    )]
+    #[error("{kind} is not synthesizable")]
+    NonSynthesizable {
+        kind: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_unsafe),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_unsafe")
+    )]
+    #[error("\"{name}\" is not valid unsafe identifier")]
+    UnknownUnsafe {
+        name: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(private_symbol_access),
+        help("remove \"private\" attribute or refer it from the package only"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#private_symbol_access"
+        )
+    )]
+    #[error("\"{identifier}\" is private item of package \"{package}\"")]
+    PrivateSymbolAccess {
+        identifier: String,
+        package: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Definition")]
+        definition_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(private_member),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#private_member")
+    )]
+    #[error("\"{name}\" is private member")]
+    PrivateMember {
+        name: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_msb),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_msb")
+    )]
+    #[error("resolving msb is failed")]
+    UnknownMsb {
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_port),
+        help("remove \"{port}\" port"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_port")
+    )]
+    #[error("module \"{name}\" doesn't have port \"{port}\", but it is connected")]
+    UnknownPort {
+        name: String,
+        port: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unknown_param),
+        help("{hint}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_param")
+    )]
+    #[error("module \"{name}\" doesn't have param \"{param}\", but it is overrided")]
+    UnknownParam {
+        name: String,
+        param: String,
+        hint: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unused_variable),
+        help("add prefix `_` to unused variable name"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unused_variable")
+    )]
+    #[error("{identifier} is unused")]
+    UnusedVariable {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unused_import),
+        help("remove the unused import"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unused_import")
+    )]
+    #[error("import of {identifier} is unused")]
+    UnusedImport {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unused_module),
+        help("{hint}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unused_module")
+    )]
+    #[error("{kind} {identifier} is not reachable from the top modules")]
+    UnusedModule {
+        kind: String,
+        identifier: String,
+        hint: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(duplicated_import),
+        help("remove the duplicated import"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_import"
+        )
+    )]
+    #[error("{identifier} is imported multiply in the same scope")]
+    DuplicatedImport {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unused_return_value),
+        help("add variable assignment for function return"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unused_return_value"
+        )
+    )]
+    #[error("return value of {identifier} is unused{detail}")]
+    UnusedReturnValue {
+        identifier: String,
+        detail: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(dpi_call_in_always_comb),
+        help("call it from always_ff, initial or final because simulator can't know side effects of foreign function"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#dpi_call_in_always_comb"
+        )
+    )]
+    #[error("DPI imported function {identifier} is called in always_comb")]
+    DpiCallInAlwaysComb {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(truncated_enum_cast),
+        help("check the width of enum types"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#truncated_enum_cast"
+        )
+    )]
+    #[error("casting {from} ({from_width} bits) to {to} ({to_width} bits) truncates the value")]
+    TruncatedEnumCast {
+        from: String,
+        from_width: usize,
+        to: String,
+        to_width: usize,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(clock_read_as_data),
+        help("clock signal should be used as the event of always_ff only"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#clock_read_as_data"
+        )
+    )]
+    #[error("clock {identifier} is read as data in always_ff")]
+    ClockReadAsData {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(clock_reset_as_data),
+        help("use an enable signal or a synchronizer, or cast by \"as logic\" if it is intended"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#clock_reset_as_data"
+        )
+    )]
+    #[error("{kind} {identifier} is used as data in condition or comparison")]
+    ClockResetAsData {
+        kind: String,
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(shadowed_builtin),
+        help("rename the declaration"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#shadowed_builtin")
+    )]
+    #[error("{identifier} shadows builtin {kind}")]
+    ShadowedBuiltin {
+        identifier: String,
+        kind: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(shadowed_identifier),
+        help("rename either declaration to avoid confusion"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#shadowed_identifier"
+        )
+    )]
+    #[error("{identifier} shadows {shadowed}")]
+    ShadowedIdentifier {
+        identifier: String,
+        shadowed: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Shadowed declaration")]
+        definition_location: Option<SourceSpan>,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(unassign_variable),
+        help(""),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unassign_variable"
+        )
+    )]
+    #[error("{identifier} is unassigned")]
+    UnassignVariable {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(uncovered_branch),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#uncovered_branch")
+    )]
+    #[error("{identifier} is not covered by all branches, it causes latch generation")]
+    UncoveredBranch {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+        #[label("Uncovered")]
+        uncovered: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Warning),
+        code(deep_combinational_path),
+        help("insert a register into the path or increase max_comb_depth"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#deep_combinational_path"
+        )
+    )]
+    #[error("combinational path to {identifier} has depth {depth} exceeding {max_depth} ({path})")]
+    DeepCombinationalPath {
+        identifier: String,
+        depth: usize,
+        max_depth: usize,
+        path: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(reserved_identifier),
+        help("prefix `__` can't be used"),
+        url(
+            "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#reserved_identifier"
+        )
+    )]
+    #[error("{identifier} is reverved for compiler usage")]
+    ReservedIdentifier {
+        identifier: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(include_failure),
+        help(""),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#include_failure")
+    )]
+    #[error("\"{name}\" can't be read because \"{cause}\"")]
+    IncludeFailure {
+        name: String,
+        cause: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(wrong_seperator),
+        help("replace valid separator \"{valid_separator}\""),
+        url("")
+    )]
+    #[error("separator \"{separator}\" can't be used at here")]
+    WrongSeparator {
+        separator: String,
+        valid_separator: String,
+        #[source_code]
+        input: NamedSource<String>,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token: TokenRange,
+    },
+
+    #[diagnostic(forward(error), severity(Error))]
+    #[error(transparent)]
+    Denied { error: Box<AnalyzerError> },
+
+    #[diagnostic(forward(error), severity(Warning))]
+    #[error(transparent)]
+    Warned { error: Box<AnalyzerError> },
+}
+
+/// Stable identifier of each AnalyzerError for machine-readable outputs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AnalyzerErrorCode {
+    AnonymousIdentifierUsage,
+    CallNonFunction,
+    CyclicTypeDependency,
+    RecursiveInstantiation,
+    DuplicatedIdentifier,
+    DuplicatedAttribute,
+    ConflictingAttribute,
+    DuplicatedEmittedName,
+    DuplicatedEmittedNameUnused,
+    EmittedNameCollision,
+    SvKeywordEmittedName,
+    MultipleInitFile,
+    MultipleAssignment,
+    LetReassignment,
+    HierarchicalReference,
+    InvalidAllow,
+    InvalidAssignment,
+    InvalidAssignmentToConst,
+    InvalidAssignmentToInput,
+    InvalidOutputConnection,
+    InvalidAttributeTarget,
+    InvalidInitFileTarget,
+    InvalidDpiTarget,
+    InvalidDpiType,
+    InvalidDpiImportBody,
+    InvalidDirection,
+    InvalidFactor,
+    InvalidInterfaceValue,
+    InvalidWidthExpression,
+    InvalidIdentifier,
+    InvalidImport,
+    InvalidLsb,
+    InvalidMsb,
+    InvalidNumberCharacter,
+    InvalidStatement,
+    InvalidClock,
+    InvalidModportVariableItem,
+    InvalidModportFunctionItem,
+    UnknownModportMember,
+    DuplicatedModportItem,
+    InvalidModportAccess,
+    UnexpandableInout,
+    UnflattenableStructPort,
+    InvalidPortDefaultValue,
+    InvalidReset,
+    InvalidResetNonElaborative,
+    InvalidCaseConditionNonElaborative,
+    InvalidForRangeNonElaborative,
+    InvalidForIterationCount,
+    ForIndexAssignment,
+    InvalidAssertionExpression,
+    InvalidCast,
+    InvalidParameterValue,
+    InvalidConcatenationOperand,
+    InvalidRepetitionCount,
+    InvalidTest,
+    IncompatProto,
+    MissingDefaultArgument,
+    SideEffectInExpression,
+    MismatchFunctionArity,
+    MismatchGenericsArity,
+    TooManyGenericArguments,
+    MismatchGenericKind,
+    MismatchAssignmentStyle,
+    MismatchAssignmentStyleInFunction,
+    ConstantComparison,
+    MixedSignedComparison,
+    MismatchAttributeArgs,
+    MismatchType,
+    InterfaceArrayIndexOutOfRange,
+    MismatchInterfaceArraySize,
+    MismatchClockDomain,
+    MissingIfReset,
+    MissingPort,
+    MissingClockSignal,
+    MissingAssertionClock,
+    MissingReset,
+    MissingResetSignal,
+    MissingResetStatement,
+    AssignedOnlyInReset,
+    MissingInitFile,
+    MissingTri,
+    MissingClockDomain,
+    SvKeywordUsage,
+    SvWithImplicitReset,
+    InvalidEnumEncoding,
+    InvalidCondType,
+    InvalidPeriod,
+    TooLargeEnumVariant,
+    UnevaluatableEnumVariant,
+    InvalidEnumVariant,
+    TooLargeNumber,
+    ZeroWidthNumber,
+    LargeUnsizedNumber,
+    ZeroWidth,
+    ZeroRepetition,
+    ZeroByDefaultParameter,
+    RedundantWidth,
+    MismatchBranchWidth,
+    MismatchConcatenationWidth,
+    PreferLet,
+    MagicNumber,
+    TooManySelect,
+    MissingArrayIndex,
+    TooMuchEnumVariant,
+    UndefinedIdentifier,
+    ReferringPackageBeforeDefinition,
+    UseBeforeDeclaration,
+    UnresolvableGenericArgument,
+    UnknownAttribute,
+    UnknownFeature,
+    UnknownEmbedLang,
+    UnknownEmbedWay,
+    UnknownIncludeWay,
+    UnknownMember,
+    UnknownModport,
+    DuplicatedStructMember,
+    MissingStructMember,
+    NonSynthesizable,
+    UnknownUnsafe,
+    PrivateSymbolAccess,
+    PrivateMember,
+    UnknownMsb,
+    UnknownPort,
+    UnknownParam,
+    UnusedVariable,
+    UnusedImport,
+    UnusedModule,
+    DuplicatedImport,
+    UnusedReturnValue,
+    DpiCallInAlwaysComb,
+    TruncatedEnumCast,
+    ClockReadAsData,
+    ClockResetAsData,
+    ShadowedBuiltin,
+    ShadowedIdentifier,
+    UnassignVariable,
+    UncoveredBranch,
+    DeepCombinationalPath,
+    ReservedIdentifier,
+    IncludeFailure,
+    WrongSeparator,
+}
+
+impl AnalyzerErrorCode {
+    pub fn all() -> &'static [AnalyzerErrorCode] {
+        &[
+            AnalyzerErrorCode::AnonymousIdentifierUsage,
+            AnalyzerErrorCode::CallNonFunction,
+            AnalyzerErrorCode::CyclicTypeDependency,
+            AnalyzerErrorCode::RecursiveInstantiation,
+            AnalyzerErrorCode::DuplicatedIdentifier,
+            AnalyzerErrorCode::DuplicatedAttribute,
+            AnalyzerErrorCode::ConflictingAttribute,
+            AnalyzerErrorCode::DuplicatedEmittedName,
+            AnalyzerErrorCode::DuplicatedEmittedNameUnused,
+            AnalyzerErrorCode::EmittedNameCollision,
+            AnalyzerErrorCode::SvKeywordEmittedName,
+            AnalyzerErrorCode::MultipleInitFile,
+            AnalyzerErrorCode::MultipleAssignment,
+            AnalyzerErrorCode::LetReassignment,
+            AnalyzerErrorCode::HierarchicalReference,
+            AnalyzerErrorCode::InvalidAllow,
+            AnalyzerErrorCode::InvalidAssignment,
+            AnalyzerErrorCode::InvalidAssignmentToConst,
+            AnalyzerErrorCode::InvalidAssignmentToInput,
+            AnalyzerErrorCode::InvalidOutputConnection,
+            AnalyzerErrorCode::InvalidAttributeTarget,
+            AnalyzerErrorCode::InvalidInitFileTarget,
+            AnalyzerErrorCode::InvalidDpiTarget,
+            AnalyzerErrorCode::InvalidDpiType,
+            AnalyzerErrorCode::InvalidDpiImportBody,
+            AnalyzerErrorCode::InvalidDirection,
+            AnalyzerErrorCode::InvalidFactor,
+            AnalyzerErrorCode::InvalidInterfaceValue,
+            AnalyzerErrorCode::InvalidWidthExpression,
+            AnalyzerErrorCode::InvalidIdentifier,
+            AnalyzerErrorCode::InvalidImport,
+            AnalyzerErrorCode::InvalidLsb,
+            AnalyzerErrorCode::InvalidMsb,
+            AnalyzerErrorCode::InvalidNumberCharacter,
+            AnalyzerErrorCode::InvalidStatement,
+            AnalyzerErrorCode::InvalidClock,
+            AnalyzerErrorCode::InvalidModportVariableItem,
+            AnalyzerErrorCode::InvalidModportFunctionItem,
+            AnalyzerErrorCode::UnknownModportMember,
+            AnalyzerErrorCode::DuplicatedModportItem,
+            AnalyzerErrorCode::InvalidModportAccess,
+            AnalyzerErrorCode::UnexpandableInout,
+            AnalyzerErrorCode::UnflattenableStructPort,
+            AnalyzerErrorCode::InvalidPortDefaultValue,
+            AnalyzerErrorCode::InvalidReset,
+            AnalyzerErrorCode::InvalidResetNonElaborative,
+            AnalyzerErrorCode::InvalidCaseConditionNonElaborative,
+            AnalyzerErrorCode::InvalidForRangeNonElaborative,
+            AnalyzerErrorCode::InvalidForIterationCount,
+            AnalyzerErrorCode::ForIndexAssignment,
+            AnalyzerErrorCode::InvalidAssertionExpression,
+            AnalyzerErrorCode::InvalidCast,
+            AnalyzerErrorCode::InvalidParameterValue,
+            AnalyzerErrorCode::InvalidConcatenationOperand,
+            AnalyzerErrorCode::InvalidRepetitionCount,
+            AnalyzerErrorCode::InvalidTest,
+            AnalyzerErrorCode::IncompatProto,
+            AnalyzerErrorCode::MissingDefaultArgument,
+            AnalyzerErrorCode::SideEffectInExpression,
+            AnalyzerErrorCode::MismatchFunctionArity,
+            AnalyzerErrorCode::MismatchGenericsArity,
+            AnalyzerErrorCode::TooManyGenericArguments,
+            AnalyzerErrorCode::MismatchGenericKind,
+            AnalyzerErrorCode::MismatchAssignmentStyle,
+            AnalyzerErrorCode::MismatchAssignmentStyleInFunction,
+            AnalyzerErrorCode::ConstantComparison,
+            AnalyzerErrorCode::MixedSignedComparison,
+            AnalyzerErrorCode::MismatchAttributeArgs,
+            AnalyzerErrorCode::MismatchType,
+            AnalyzerErrorCode::InterfaceArrayIndexOutOfRange,
+            AnalyzerErrorCode::MismatchInterfaceArraySize,
+            AnalyzerErrorCode::MismatchClockDomain,
+            AnalyzerErrorCode::MissingIfReset,
+            AnalyzerErrorCode::MissingPort,
+            AnalyzerErrorCode::MissingClockSignal,
+            AnalyzerErrorCode::MissingAssertionClock,
+            AnalyzerErrorCode::MissingReset,
+            AnalyzerErrorCode::MissingResetSignal,
+            AnalyzerErrorCode::MissingResetStatement,
+            AnalyzerErrorCode::AssignedOnlyInReset,
+            AnalyzerErrorCode::MissingInitFile,
+            AnalyzerErrorCode::MissingTri,
+            AnalyzerErrorCode::MissingClockDomain,
+            AnalyzerErrorCode::SvKeywordUsage,
+            AnalyzerErrorCode::SvWithImplicitReset,
+            AnalyzerErrorCode::InvalidEnumEncoding,
+            AnalyzerErrorCode::InvalidCondType,
+            AnalyzerErrorCode::InvalidPeriod,
+            AnalyzerErrorCode::TooLargeEnumVariant,
+            AnalyzerErrorCode::UnevaluatableEnumVariant,
+            AnalyzerErrorCode::InvalidEnumVariant,
+            AnalyzerErrorCode::TooLargeNumber,
+            AnalyzerErrorCode::ZeroWidthNumber,
+            AnalyzerErrorCode::LargeUnsizedNumber,
+            AnalyzerErrorCode::ZeroWidth,
+            AnalyzerErrorCode::ZeroRepetition,
+            AnalyzerErrorCode::ZeroByDefaultParameter,
+            AnalyzerErrorCode::RedundantWidth,
+            AnalyzerErrorCode::MismatchBranchWidth,
+            AnalyzerErrorCode::MismatchConcatenationWidth,
+            AnalyzerErrorCode::PreferLet,
+            AnalyzerErrorCode::MagicNumber,
+            AnalyzerErrorCode::TooManySelect,
+            AnalyzerErrorCode::MissingArrayIndex,
+            AnalyzerErrorCode::TooMuchEnumVariant,
+            AnalyzerErrorCode::UndefinedIdentifier,
+            AnalyzerErrorCode::ReferringPackageBeforeDefinition,
+            AnalyzerErrorCode::UseBeforeDeclaration,
+            AnalyzerErrorCode::UnresolvableGenericArgument,
+            AnalyzerErrorCode::UnknownAttribute,
+            AnalyzerErrorCode::UnknownFeature,
+            AnalyzerErrorCode::UnknownEmbedLang,
+            AnalyzerErrorCode::UnknownEmbedWay,
+            AnalyzerErrorCode::UnknownIncludeWay,
+            AnalyzerErrorCode::UnknownMember,
+            AnalyzerErrorCode::UnknownModport,
+            AnalyzerErrorCode::DuplicatedStructMember,
+            AnalyzerErrorCode::MissingStructMember,
+            AnalyzerErrorCode::NonSynthesizable,
+            AnalyzerErrorCode::UnknownUnsafe,
+            AnalyzerErrorCode::PrivateSymbolAccess,
+            AnalyzerErrorCode::PrivateMember,
+            AnalyzerErrorCode::UnknownMsb,
+            AnalyzerErrorCode::UnknownPort,
+            AnalyzerErrorCode::UnknownParam,
+            AnalyzerErrorCode::UnusedVariable,
+            AnalyzerErrorCode::UnusedImport,
+            AnalyzerErrorCode::UnusedModule,
+            AnalyzerErrorCode::DuplicatedImport,
+            AnalyzerErrorCode::UnusedReturnValue,
+            AnalyzerErrorCode::DpiCallInAlwaysComb,
+            AnalyzerErrorCode::TruncatedEnumCast,
+            AnalyzerErrorCode::ClockReadAsData,
+            AnalyzerErrorCode::ClockResetAsData,
+            AnalyzerErrorCode::ShadowedBuiltin,
+            AnalyzerErrorCode::ShadowedIdentifier,
+            AnalyzerErrorCode::UnassignVariable,
+            AnalyzerErrorCode::UncoveredBranch,
+            AnalyzerErrorCode::DeepCombinationalPath,
+            AnalyzerErrorCode::ReservedIdentifier,
+            AnalyzerErrorCode::IncludeFailure,
+            AnalyzerErrorCode::WrongSeparator,
+        ]
+    }
+
+    /// Structural errors can't be suppressed or demoted by lint level.
+    /// They leave references unresolved or make the emitted code invalid,
+    /// while the others are design rule checks which projects may relax.
+    pub fn is_structural(&self) -> bool {
+        matches!(
+            self,
+            AnalyzerErrorCode::CallNonFunction
+                | AnalyzerErrorCode::CyclicTypeDependency
+                | AnalyzerErrorCode::DuplicatedIdentifier
+                | AnalyzerErrorCode::IncludeFailure
+                | AnalyzerErrorCode::InvalidNumberCharacter
+                | AnalyzerErrorCode::MismatchFunctionArity
+                | AnalyzerErrorCode::MismatchGenericsArity
+                | AnalyzerErrorCode::MismatchType
+                | AnalyzerErrorCode::RecursiveInstantiation
+                | AnalyzerErrorCode::ReferringPackageBeforeDefinition
+                | AnalyzerErrorCode::TooLargeNumber
+                | AnalyzerErrorCode::UndefinedIdentifier
+                | AnalyzerErrorCode::UnknownMember
+                | AnalyzerErrorCode::UnknownPort
+                | AnalyzerErrorCode::UnresolvableGenericArgument
+        )
+    }
+
+    pub fn from_name(name: &str) -> Option<AnalyzerErrorCode> {
+        // former names kept for compatibility
+        if name == "unused_return" {
+            return Some(AnalyzerErrorCode::UnusedReturnValue);
+        }
+        AnalyzerErrorCode::all()
+            .iter()
+            .find(|x| x.as_str() == name)
+            .copied()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalyzerErrorCode::AnonymousIdentifierUsage => "anonymous_identifier_usage",
+            AnalyzerErrorCode::CallNonFunction => "call_non_function",
+            AnalyzerErrorCode::CyclicTypeDependency => "cyclice_type_dependency",
+            AnalyzerErrorCode::RecursiveInstantiation => "recursive_instantiation",
+            AnalyzerErrorCode::DuplicatedIdentifier => "duplicated_identifier",
+            AnalyzerErrorCode::DuplicatedAttribute => "duplicated_attribute",
+            AnalyzerErrorCode::ConflictingAttribute => "conflicting_attribute",
+            AnalyzerErrorCode::DuplicatedEmittedName => "duplicated_emitted_name",
+            AnalyzerErrorCode::DuplicatedEmittedNameUnused => "duplicated_emitted_name_unused",
+            AnalyzerErrorCode::EmittedNameCollision => "emitted_name_collision",
+            AnalyzerErrorCode::SvKeywordEmittedName => "sv_keyword_emitted_name",
+            AnalyzerErrorCode::MultipleInitFile => "multiple_init_file",
+            AnalyzerErrorCode::MultipleAssignment => "multiple_assignment",
+            AnalyzerErrorCode::LetReassignment => "let_reassignment",
+            AnalyzerErrorCode::HierarchicalReference => "hierarchical_reference",
+            AnalyzerErrorCode::InvalidAllow => "invalid_allow",
+            AnalyzerErrorCode::InvalidAssignment => "invalid_assignment",
+            AnalyzerErrorCode::InvalidAssignmentToConst => "invalid_assignment_to_const",
+            AnalyzerErrorCode::InvalidAssignmentToInput => "invalid_assignment_to_input",
+            AnalyzerErrorCode::InvalidOutputConnection => "invalid_output_connection",
+            AnalyzerErrorCode::InvalidAttributeTarget => "invalid_attribute_target",
+            AnalyzerErrorCode::InvalidInitFileTarget => "invalid_init_file_target",
+            AnalyzerErrorCode::InvalidDpiTarget => "invalid_dpi_target",
+            AnalyzerErrorCode::InvalidDpiType => "invalid_dpi_type",
+            AnalyzerErrorCode::InvalidDpiImportBody => "invalid_dpi_import_body",
+            AnalyzerErrorCode::InvalidDirection => "invalid_direction",
+            AnalyzerErrorCode::InvalidFactor => "invalid_factor",
+            AnalyzerErrorCode::InvalidInterfaceValue => "invalid_interface_value",
+            AnalyzerErrorCode::InvalidWidthExpression => "invalid_width_expression",
+            AnalyzerErrorCode::InvalidIdentifier => "invalid_identifier",
+            AnalyzerErrorCode::InvalidImport => "invalid_import",
+            AnalyzerErrorCode::InvalidLsb => "invalid_lsb",
+            AnalyzerErrorCode::InvalidMsb => "invalid_msb",
+            AnalyzerErrorCode::InvalidNumberCharacter => "invalid_number_character",
+            AnalyzerErrorCode::InvalidStatement => "invalid_statement",
+            AnalyzerErrorCode::InvalidClock => "invalid_clock",
+            AnalyzerErrorCode::InvalidModportVariableItem => "invalid_modport_variable_item",
+            AnalyzerErrorCode::InvalidModportFunctionItem => "invalid_modport_function_item",
+            AnalyzerErrorCode::UnknownModportMember => "unknown_modport_member",
+            AnalyzerErrorCode::DuplicatedModportItem => "duplicated_modport_item",
+            AnalyzerErrorCode::InvalidModportAccess => "invalid_modport_access",
+            AnalyzerErrorCode::UnexpandableInout => "unexpandable_inout",
+            AnalyzerErrorCode::UnflattenableStructPort => "unflattenable_struct_port",
+            AnalyzerErrorCode::InvalidPortDefaultValue => "invalid_port_default_value",
+            AnalyzerErrorCode::InvalidReset => "invalid_reset",
+            AnalyzerErrorCode::InvalidResetNonElaborative => "invalid_reset_non_elaborative",
+            AnalyzerErrorCode::InvalidCaseConditionNonElaborative => {
+                "invalid_case_condition_non_elaborative"
+            }
+            AnalyzerErrorCode::InvalidForRangeNonElaborative => "invalid_for_range_non_elaborative",
+            AnalyzerErrorCode::InvalidForIterationCount => "invalid_for_iteration_count",
+            AnalyzerErrorCode::ForIndexAssignment => "for_index_assignment",
+            AnalyzerErrorCode::InvalidAssertionExpression => "invalid_assertion_expression",
+            AnalyzerErrorCode::InvalidCast => "invalid_cast",
+            AnalyzerErrorCode::InvalidParameterValue => "invalid_parameter_value",
+            AnalyzerErrorCode::InvalidConcatenationOperand => "invalid_concatenation_operand",
+            AnalyzerErrorCode::InvalidRepetitionCount => "invalid_repetition_count",
+            AnalyzerErrorCode::InvalidTest => "invalid_test",
+            AnalyzerErrorCode::IncompatProto => "incompat_proto",
+            AnalyzerErrorCode::MissingDefaultArgument => "missing_default_argument",
+            AnalyzerErrorCode::SideEffectInExpression => "side_effect_in_expression",
+            AnalyzerErrorCode::MismatchFunctionArity => "mismatch_function_arity",
+            AnalyzerErrorCode::MismatchGenericsArity => "mismatch_generics_arity",
+            AnalyzerErrorCode::TooManyGenericArguments => "too_many_generic_arguments",
+            AnalyzerErrorCode::MismatchGenericKind => "mismatch_generic_kind",
+            AnalyzerErrorCode::MismatchAssignmentStyle => "mismatch_assignment_style",
+            AnalyzerErrorCode::MismatchAssignmentStyleInFunction => {
+                "mismatch_assignment_style_in_function"
+            }
+            AnalyzerErrorCode::ConstantComparison => "constant_comparison",
+            AnalyzerErrorCode::MixedSignedComparison => "mixed_signed_comparison",
+            AnalyzerErrorCode::MismatchAttributeArgs => "mismatch_attribute_args",
+            AnalyzerErrorCode::MismatchType => "mismatch_type",
+            AnalyzerErrorCode::InterfaceArrayIndexOutOfRange => {
+                "interface_array_index_out_of_range"
+            }
+            AnalyzerErrorCode::MismatchInterfaceArraySize => "mismatch_interface_array_size",
+            AnalyzerErrorCode::MismatchClockDomain => "mismatch_clock_domain",
+            AnalyzerErrorCode::MissingIfReset => "missing_if_reset",
+            AnalyzerErrorCode::MissingPort => "missing_port",
+            AnalyzerErrorCode::MissingClockSignal => "missing_clock_signal",
+            AnalyzerErrorCode::MissingAssertionClock => "missing_assertion_clock",
+            AnalyzerErrorCode::MissingReset => "missing_reset",
+            AnalyzerErrorCode::MissingResetSignal => "missing_reset_signal",
+            AnalyzerErrorCode::MissingResetStatement => "missing_reset_statement",
+            AnalyzerErrorCode::AssignedOnlyInReset => "assigned_only_in_reset",
+            AnalyzerErrorCode::MissingInitFile => "missing_init_file",
+            AnalyzerErrorCode::MissingTri => "missing_tri",
+            AnalyzerErrorCode::MissingClockDomain => "missing_clock_domain",
+            AnalyzerErrorCode::SvKeywordUsage => "sv_keyword_usage",
+            AnalyzerErrorCode::SvWithImplicitReset => "sv_with_implicit_reset",
+            AnalyzerErrorCode::InvalidEnumEncoding => "invalid_enum_encoding",
+            AnalyzerErrorCode::InvalidCondType => "invalid_cond_type",
+            AnalyzerErrorCode::InvalidPeriod => "invalid_period",
+            AnalyzerErrorCode::TooLargeEnumVariant => "too_large_enum_variant",
+            AnalyzerErrorCode::UnevaluatableEnumVariant => "unevaluatable_enum_variant_value",
+            AnalyzerErrorCode::InvalidEnumVariant => "invalid_enum_variant_value",
+            AnalyzerErrorCode::TooLargeNumber => "too_large_number",
+            AnalyzerErrorCode::ZeroWidthNumber => "zero_width_number",
+            AnalyzerErrorCode::LargeUnsizedNumber => "large_unsized_number",
+            AnalyzerErrorCode::ZeroWidth => "zero_width",
+            AnalyzerErrorCode::ZeroRepetition => "zero_repetition",
+            AnalyzerErrorCode::ZeroByDefaultParameter => "zero_by_default_parameter",
+            AnalyzerErrorCode::RedundantWidth => "redundant_width",
+            AnalyzerErrorCode::MismatchBranchWidth => "mismatch_branch_width",
+            AnalyzerErrorCode::MismatchConcatenationWidth => "mismatch_concatenation_width",
+            AnalyzerErrorCode::PreferLet => "prefer_let",
+            AnalyzerErrorCode::MagicNumber => "magic_number",
+            AnalyzerErrorCode::TooManySelect => "too_many_select",
+            AnalyzerErrorCode::MissingArrayIndex => "missing_array_index",
+            AnalyzerErrorCode::TooMuchEnumVariant => "too_much_enum_variant",
+            AnalyzerErrorCode::UndefinedIdentifier => "undefined_identifier",
+            AnalyzerErrorCode::ReferringPackageBeforeDefinition => {
+                "referring_package_before_definition"
+            }
+            AnalyzerErrorCode::UseBeforeDeclaration => "use_before_declaration",
+            AnalyzerErrorCode::UnresolvableGenericArgument => "unresolvable_generic_argument",
+            AnalyzerErrorCode::UnknownAttribute => "unknown_attribute",
+            AnalyzerErrorCode::UnknownFeature => "unknown_feature",
+            AnalyzerErrorCode::UnknownEmbedLang => "unknown_embed_lang",
+            AnalyzerErrorCode::UnknownEmbedWay => "unknown_embed_way",
+            AnalyzerErrorCode::UnknownIncludeWay => "unknown_include_way",
+            AnalyzerErrorCode::UnknownMember => "unknown_member",
+            AnalyzerErrorCode::UnknownModport => "unknown_modport",
+            AnalyzerErrorCode::DuplicatedStructMember => "duplicated_struct_member",
+            AnalyzerErrorCode::MissingStructMember => "missing_struct_member",
+            AnalyzerErrorCode::NonSynthesizable => "non_synthesizable",
+            AnalyzerErrorCode::UnknownUnsafe => "unknown_unsafe",
+            AnalyzerErrorCode::PrivateSymbolAccess => "private_symbol_access",
+            AnalyzerErrorCode::PrivateMember => "private_member",
+            AnalyzerErrorCode::UnknownMsb => "unknown_msb",
+            AnalyzerErrorCode::UnknownPort => "unknown_port",
+            AnalyzerErrorCode::UnknownParam => "unknown_param",
+            AnalyzerErrorCode::UnusedVariable => "unused_variable",
+            AnalyzerErrorCode::UnusedImport => "unused_import",
+            AnalyzerErrorCode::UnusedModule => "unused_module",
+            AnalyzerErrorCode::DuplicatedImport => "duplicated_import",
+            AnalyzerErrorCode::UnusedReturnValue => "unused_return_value",
+            AnalyzerErrorCode::DpiCallInAlwaysComb => "dpi_call_in_always_comb",
+            AnalyzerErrorCode::TruncatedEnumCast => "truncated_enum_cast",
+            AnalyzerErrorCode::ClockReadAsData => "clock_read_as_data",
+            AnalyzerErrorCode::ClockResetAsData => "clock_reset_as_data",
+            AnalyzerErrorCode::ShadowedBuiltin => "shadowed_builtin",
+            AnalyzerErrorCode::ShadowedIdentifier => "shadowed_identifier",
+            AnalyzerErrorCode::UnassignVariable => "unassign_variable",
+            AnalyzerErrorCode::UncoveredBranch => "uncovered_branch",
+            AnalyzerErrorCode::DeepCombinationalPath => "deep_combinational_path",
+            AnalyzerErrorCode::ReservedIdentifier => "reserved_identifier",
+            AnalyzerErrorCode::IncludeFailure => "include_failure",
+            AnalyzerErrorCode::WrongSeparator => "wrong_seperator",
+        }
+    }
+}
+
+impl fmt::Display for AnalyzerErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Source location of AnalyzerError
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnalyzerErrorLocation {
+    pub path: String,
+    /// 1-based line number
+    pub start_line: usize,
+    /// 1-based column number
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub label: Option<String>,
+}
+
+impl AnalyzerError {
+    fn named_source(source: &str, token: &TokenRange) -> NamedSource<String> {
+        NamedSource::new(token.beg.source.to_string(), source.to_string())
+    }
+
+    pub fn error_code(&self) -> AnalyzerErrorCode {
+        match self {
+            AnalyzerError::Denied { error } | AnalyzerError::Warned { error } => error.error_code(),
+            AnalyzerError::AnonymousIdentifierUsage { .. } => {
+                AnalyzerErrorCode::AnonymousIdentifierUsage
+            }
+            AnalyzerError::CallNonFunction { .. } => AnalyzerErrorCode::CallNonFunction,
+            AnalyzerError::CyclicTypeDependency { .. } => AnalyzerErrorCode::CyclicTypeDependency,
+            AnalyzerError::RecursiveInstantiation { .. } => {
+                AnalyzerErrorCode::RecursiveInstantiation
+            }
+            AnalyzerError::DuplicatedIdentifier { .. } => AnalyzerErrorCode::DuplicatedIdentifier,
+            AnalyzerError::DuplicatedAttribute { .. } => AnalyzerErrorCode::DuplicatedAttribute,
+            AnalyzerError::ConflictingAttribute { .. } => AnalyzerErrorCode::ConflictingAttribute,
+            AnalyzerError::DuplicatedEmittedName { .. } => AnalyzerErrorCode::DuplicatedEmittedName,
+            AnalyzerError::DuplicatedEmittedNameUnused { .. } => {
+                AnalyzerErrorCode::DuplicatedEmittedNameUnused
+            }
+            AnalyzerError::EmittedNameCollision { .. } => AnalyzerErrorCode::EmittedNameCollision,
+            AnalyzerError::SvKeywordEmittedName { .. } => AnalyzerErrorCode::SvKeywordEmittedName,
+            AnalyzerError::MultipleInitFile { .. } => AnalyzerErrorCode::MultipleInitFile,
+            AnalyzerError::MultipleAssignment { .. } => AnalyzerErrorCode::MultipleAssignment,
+            AnalyzerError::LetReassignment { .. } => AnalyzerErrorCode::LetReassignment,
+            AnalyzerError::HierarchicalReference { .. } => AnalyzerErrorCode::HierarchicalReference,
+            AnalyzerError::InvalidAllow { .. } => AnalyzerErrorCode::InvalidAllow,
+            AnalyzerError::InvalidAssignment { .. } => AnalyzerErrorCode::InvalidAssignment,
+            AnalyzerError::InvalidAssignmentToConst { .. } => {
+                AnalyzerErrorCode::InvalidAssignmentToConst
+            }
+            AnalyzerError::InvalidAssignmentToInput { .. } => {
+                AnalyzerErrorCode::InvalidAssignmentToInput
+            }
+            AnalyzerError::InvalidOutputConnection { .. } => {
+                AnalyzerErrorCode::InvalidOutputConnection
+            }
+            AnalyzerError::InvalidAttributeTarget { .. } => {
+                AnalyzerErrorCode::InvalidAttributeTarget
+            }
+            AnalyzerError::InvalidInitFileTarget { .. } => AnalyzerErrorCode::InvalidInitFileTarget,
+            AnalyzerError::InvalidDpiTarget { .. } => AnalyzerErrorCode::InvalidDpiTarget,
+            AnalyzerError::InvalidDpiType { .. } => AnalyzerErrorCode::InvalidDpiType,
+            AnalyzerError::InvalidDpiImportBody { .. } => AnalyzerErrorCode::InvalidDpiImportBody,
+            AnalyzerError::InvalidDirection { .. } => AnalyzerErrorCode::InvalidDirection,
+            AnalyzerError::InvalidFactor { .. } => AnalyzerErrorCode::InvalidFactor,
+            AnalyzerError::InvalidInterfaceValue { .. } => AnalyzerErrorCode::InvalidInterfaceValue,
+            AnalyzerError::InvalidWidthExpression { .. } => {
+                AnalyzerErrorCode::InvalidWidthExpression
+            }
+            AnalyzerError::InvalidIdentifier { .. } => AnalyzerErrorCode::InvalidIdentifier,
+            AnalyzerError::InvalidImport { .. } => AnalyzerErrorCode::InvalidImport,
+            AnalyzerError::InvalidLsb { .. } => AnalyzerErrorCode::InvalidLsb,
+            AnalyzerError::InvalidMsb { .. } => AnalyzerErrorCode::InvalidMsb,
+            AnalyzerError::InvalidNumberCharacter { .. } => {
+                AnalyzerErrorCode::InvalidNumberCharacter
+            }
+            AnalyzerError::InvalidStatement { .. } => AnalyzerErrorCode::InvalidStatement,
+            AnalyzerError::InvalidClock { .. } => AnalyzerErrorCode::InvalidClock,
+            AnalyzerError::InvalidModportVariableItem { .. } => {
+                AnalyzerErrorCode::InvalidModportVariableItem
+            }
+            AnalyzerError::InvalidModportFunctionItem { .. } => {
+                AnalyzerErrorCode::InvalidModportFunctionItem
+            }
+            AnalyzerError::UnknownModportMember { .. } => AnalyzerErrorCode::UnknownModportMember,
+            AnalyzerError::DuplicatedModportItem { .. } => AnalyzerErrorCode::DuplicatedModportItem,
+            AnalyzerError::InvalidModportAccess { .. } => AnalyzerErrorCode::InvalidModportAccess,
+            AnalyzerError::UnexpandableInout { .. } => AnalyzerErrorCode::UnexpandableInout,
+            AnalyzerError::UnflattenableStructPort { .. } => {
+                AnalyzerErrorCode::UnflattenableStructPort
+            }
+            AnalyzerError::InvalidPortDefaultValue { .. } => {
+                AnalyzerErrorCode::InvalidPortDefaultValue
+            }
+            AnalyzerError::InvalidReset { .. } => AnalyzerErrorCode::InvalidReset,
+            AnalyzerError::InvalidResetNonElaborative { .. } => {
+                AnalyzerErrorCode::InvalidResetNonElaborative
+            }
+            AnalyzerError::InvalidCaseConditionNonElaborative { .. } => {
+                AnalyzerErrorCode::InvalidCaseConditionNonElaborative
+            }
+            AnalyzerError::InvalidForRangeNonElaborative { .. } => {
+                AnalyzerErrorCode::InvalidForRangeNonElaborative
+            }
+            AnalyzerError::InvalidForIterationCount { .. } => {
+                AnalyzerErrorCode::InvalidForIterationCount
+            }
+            AnalyzerError::ForIndexAssignment { .. } => AnalyzerErrorCode::ForIndexAssignment,
+            AnalyzerError::InvalidAssertionExpression { .. } => {
+                AnalyzerErrorCode::InvalidAssertionExpression
+            }
+            AnalyzerError::InvalidCast { .. } => AnalyzerErrorCode::InvalidCast,
+            AnalyzerError::InvalidParameterValue { .. } => AnalyzerErrorCode::InvalidParameterValue,
+            AnalyzerError::InvalidConcatenationOperand { .. } => {
+                AnalyzerErrorCode::InvalidConcatenationOperand
+            }
+            AnalyzerError::InvalidRepetitionCount { .. } => {
+                AnalyzerErrorCode::InvalidRepetitionCount
+            }
+            AnalyzerError::InvalidTest { .. } => AnalyzerErrorCode::InvalidTest,
+            AnalyzerError::IncompatProto { .. } => AnalyzerErrorCode::IncompatProto,
+            AnalyzerError::MissingDefaultArgument { .. } => {
+                AnalyzerErrorCode::MissingDefaultArgument
+            }
+            AnalyzerError::SideEffectInExpression { .. } => {
+                AnalyzerErrorCode::SideEffectInExpression
+            }
+            AnalyzerError::MismatchFunctionArity { .. } => AnalyzerErrorCode::MismatchFunctionArity,
+            AnalyzerError::MismatchGenericsArity { .. } => AnalyzerErrorCode::MismatchGenericsArity,
+            AnalyzerError::TooManyGenericArguments { .. } => {
+                AnalyzerErrorCode::TooManyGenericArguments
+            }
+            AnalyzerError::MismatchGenericKind { .. } => AnalyzerErrorCode::MismatchGenericKind,
+            AnalyzerError::MismatchAssignmentStyle { .. } => {
+                AnalyzerErrorCode::MismatchAssignmentStyle
+            }
+            AnalyzerError::MismatchAssignmentStyleInFunction { .. } => {
+                AnalyzerErrorCode::MismatchAssignmentStyleInFunction
+            }
+            AnalyzerError::ConstantComparison { .. } => AnalyzerErrorCode::ConstantComparison,
+            AnalyzerError::MixedSignedComparison { .. } => AnalyzerErrorCode::MixedSignedComparison,
+            AnalyzerError::MismatchAttributeArgs { .. } => AnalyzerErrorCode::MismatchAttributeArgs,
+            AnalyzerError::MismatchType { .. } => AnalyzerErrorCode::MismatchType,
+            AnalyzerError::InterfaceArrayIndexOutOfRange { .. } => {
+                AnalyzerErrorCode::InterfaceArrayIndexOutOfRange
+            }
+            AnalyzerError::MismatchInterfaceArraySize { .. } => {
+                AnalyzerErrorCode::MismatchInterfaceArraySize
+            }
+            AnalyzerError::MismatchClockDomain { .. } => AnalyzerErrorCode::MismatchClockDomain,
+            AnalyzerError::MissingIfReset { .. } => AnalyzerErrorCode::MissingIfReset,
+            AnalyzerError::MissingPort { .. } => AnalyzerErrorCode::MissingPort,
+            AnalyzerError::MissingClockSignal { .. } => AnalyzerErrorCode::MissingClockSignal,
+            AnalyzerError::MissingAssertionClock { .. } => AnalyzerErrorCode::MissingAssertionClock,
+            AnalyzerError::MissingReset { .. } => AnalyzerErrorCode::MissingReset,
+            AnalyzerError::MissingResetSignal { .. } => AnalyzerErrorCode::MissingResetSignal,
+            AnalyzerError::MissingResetStatement { .. } => AnalyzerErrorCode::MissingResetStatement,
+            AnalyzerError::AssignedOnlyInReset { .. } => AnalyzerErrorCode::AssignedOnlyInReset,
+            AnalyzerError::MissingInitFile { .. } => AnalyzerErrorCode::MissingInitFile,
+            AnalyzerError::MissingTri { .. } => AnalyzerErrorCode::MissingTri,
+            AnalyzerError::MissingClockDomain { .. } => AnalyzerErrorCode::MissingClockDomain,
+            AnalyzerError::SvKeywordUsage { .. } => AnalyzerErrorCode::SvKeywordUsage,
+            AnalyzerError::SvWithImplicitReset { .. } => AnalyzerErrorCode::SvWithImplicitReset,
+            AnalyzerError::InvalidEnumEncoding { .. } => AnalyzerErrorCode::InvalidEnumEncoding,
+            AnalyzerError::InvalidCondType { .. } => AnalyzerErrorCode::InvalidCondType,
+            AnalyzerError::InvalidPeriod { .. } => AnalyzerErrorCode::InvalidPeriod,
+            AnalyzerError::TooLargeEnumVariant { .. } => AnalyzerErrorCode::TooLargeEnumVariant,
+            AnalyzerError::UnevaluatableEnumVariant { .. } => {
+                AnalyzerErrorCode::UnevaluatableEnumVariant
+            }
+            AnalyzerError::InvalidEnumVariant { .. } => AnalyzerErrorCode::InvalidEnumVariant,
+            AnalyzerError::TooLargeNumber { .. } => AnalyzerErrorCode::TooLargeNumber,
+            AnalyzerError::ZeroWidthNumber { .. } => AnalyzerErrorCode::ZeroWidthNumber,
+            AnalyzerError::LargeUnsizedNumber { .. } => AnalyzerErrorCode::LargeUnsizedNumber,
+            AnalyzerError::ZeroWidth { .. } => AnalyzerErrorCode::ZeroWidth,
+            AnalyzerError::ZeroRepetition { .. } => AnalyzerErrorCode::ZeroRepetition,
+            AnalyzerError::ZeroByDefaultParameter { .. } => {
+                AnalyzerErrorCode::ZeroByDefaultParameter
+            }
+            AnalyzerError::RedundantWidth { .. } => AnalyzerErrorCode::RedundantWidth,
+            AnalyzerError::MismatchBranchWidth { .. } => AnalyzerErrorCode::MismatchBranchWidth,
+            AnalyzerError::MismatchConcatenationWidth { .. } => {
+                AnalyzerErrorCode::MismatchConcatenationWidth
+            }
+            AnalyzerError::PreferLet { .. } => AnalyzerErrorCode::PreferLet,
+            AnalyzerError::MagicNumber { .. } => AnalyzerErrorCode::MagicNumber,
+            AnalyzerError::TooManySelect { .. } => AnalyzerErrorCode::TooManySelect,
+            AnalyzerError::MissingArrayIndex { .. } => AnalyzerErrorCode::MissingArrayIndex,
+            AnalyzerError::TooMuchEnumVariant { .. } => AnalyzerErrorCode::TooMuchEnumVariant,
+            AnalyzerError::UndefinedIdentifier { .. } => AnalyzerErrorCode::UndefinedIdentifier,
+            AnalyzerError::ReferringPackageBeforeDefinition { .. } => {
+                AnalyzerErrorCode::ReferringPackageBeforeDefinition
+            }
+            AnalyzerError::UseBeforeDeclaration { .. } => AnalyzerErrorCode::UseBeforeDeclaration,
+            AnalyzerError::UnresolvableGenericArgument { .. } => {
+                AnalyzerErrorCode::UnresolvableGenericArgument
+            }
+            AnalyzerError::UnknownAttribute { .. } => AnalyzerErrorCode::UnknownAttribute,
+            AnalyzerError::UnknownFeature { .. } => AnalyzerErrorCode::UnknownFeature,
+            AnalyzerError::UnknownEmbedLang { .. } => AnalyzerErrorCode::UnknownEmbedLang,
+            AnalyzerError::UnknownEmbedWay { .. } => AnalyzerErrorCode::UnknownEmbedWay,
+            AnalyzerError::UnknownIncludeWay { .. } => AnalyzerErrorCode::UnknownIncludeWay,
+            AnalyzerError::UnknownMember { .. } => AnalyzerErrorCode::UnknownMember,
+            AnalyzerError::UnknownModport { .. } => AnalyzerErrorCode::UnknownModport,
+            AnalyzerError::DuplicatedStructMember { .. } => {
+                AnalyzerErrorCode::DuplicatedStructMember
+            }
+            AnalyzerError::MissingStructMember { .. } => AnalyzerErrorCode::MissingStructMember,
+            AnalyzerError::NonSynthesizable { .. } => AnalyzerErrorCode::NonSynthesizable,
+            AnalyzerError::UnknownUnsafe { .. } => AnalyzerErrorCode::UnknownUnsafe,
+            AnalyzerError::PrivateSymbolAccess { .. } => AnalyzerErrorCode::PrivateSymbolAccess,
+            AnalyzerError::PrivateMember { .. } => AnalyzerErrorCode::PrivateMember,
+            AnalyzerError::UnknownMsb { .. } => AnalyzerErrorCode::UnknownMsb,
+            AnalyzerError::UnknownPort { .. } => AnalyzerErrorCode::UnknownPort,
+            AnalyzerError::UnknownParam { .. } => AnalyzerErrorCode::UnknownParam,
+            AnalyzerError::UnusedVariable { .. } => AnalyzerErrorCode::UnusedVariable,
+            AnalyzerError::UnusedImport { .. } => AnalyzerErrorCode::UnusedImport,
+            AnalyzerError::UnusedModule { .. } => AnalyzerErrorCode::UnusedModule,
+            AnalyzerError::DuplicatedImport { .. } => AnalyzerErrorCode::DuplicatedImport,
+            AnalyzerError::UnusedReturnValue { .. } => AnalyzerErrorCode::UnusedReturnValue,
+            AnalyzerError::DpiCallInAlwaysComb { .. } => AnalyzerErrorCode::DpiCallInAlwaysComb,
+            AnalyzerError::TruncatedEnumCast { .. } => AnalyzerErrorCode::TruncatedEnumCast,
+            AnalyzerError::ClockReadAsData { .. } => AnalyzerErrorCode::ClockReadAsData,
+            AnalyzerError::ClockResetAsData { .. } => AnalyzerErrorCode::ClockResetAsData,
+            AnalyzerError::ShadowedBuiltin { .. } => AnalyzerErrorCode::ShadowedBuiltin,
+            AnalyzerError::ShadowedIdentifier { .. } => AnalyzerErrorCode::ShadowedIdentifier,
+            AnalyzerError::UnassignVariable { .. } => AnalyzerErrorCode::UnassignVariable,
+            AnalyzerError::UncoveredBranch { .. } => AnalyzerErrorCode::UncoveredBranch,
+            AnalyzerError::DeepCombinationalPath { .. } => AnalyzerErrorCode::DeepCombinationalPath,
+            AnalyzerError::ReservedIdentifier { .. } => AnalyzerErrorCode::ReservedIdentifier,
+            AnalyzerError::IncludeFailure { .. } => AnalyzerErrorCode::IncludeFailure,
+            AnalyzerError::WrongSeparator { .. } => AnalyzerErrorCode::WrongSeparator,
+        }
+    }
+
+    /// Returns the token range where the error occurs
+    pub fn token(&self) -> &TokenRange {
+        match self {
+            AnalyzerError::Denied { error } | AnalyzerError::Warned { error } => error.token(),
+            AnalyzerError::AnonymousIdentifierUsage { token, .. }
+            | AnalyzerError::CallNonFunction { token, .. }
+            | AnalyzerError::CyclicTypeDependency { token, .. }
+            | AnalyzerError::RecursiveInstantiation { token, .. }
+            | AnalyzerError::DuplicatedIdentifier { token, .. }
+            | AnalyzerError::DuplicatedAttribute { token, .. }
+            | AnalyzerError::ConflictingAttribute { token, .. }
+            | AnalyzerError::DuplicatedEmittedName { token, .. }
+            | AnalyzerError::DuplicatedEmittedNameUnused { token, .. }
+            | AnalyzerError::EmittedNameCollision { token, .. }
+            | AnalyzerError::SvKeywordEmittedName { token, .. }
+            | AnalyzerError::MultipleInitFile { token, .. }
+            | AnalyzerError::MultipleAssignment { token, .. }
+            | AnalyzerError::LetReassignment { token, .. }
+            | AnalyzerError::HierarchicalReference { token, .. }
+            | AnalyzerError::InvalidAllow { token, .. }
+            | AnalyzerError::InvalidAssignment { token, .. }
+            | AnalyzerError::InvalidAssignmentToConst { token, .. }
+            | AnalyzerError::InvalidAssignmentToInput { token, .. }
+            | AnalyzerError::InvalidOutputConnection { token, .. }
+            | AnalyzerError::InvalidAttributeTarget { token, .. }
+            | AnalyzerError::InvalidInitFileTarget { token, .. }
+            | AnalyzerError::InvalidDpiTarget { token, .. }
+            | AnalyzerError::InvalidDpiType { token, .. }
+            | AnalyzerError::InvalidDpiImportBody { token, .. }
+            | AnalyzerError::InvalidDirection { token, .. }
+            | AnalyzerError::InvalidFactor { token, .. }
+            | AnalyzerError::InvalidInterfaceValue { token, .. }
+            | AnalyzerError::InvalidWidthExpression { token, .. }
+            | AnalyzerError::InvalidIdentifier { token, .. }
+            | AnalyzerError::InvalidImport { token, .. }
+            | AnalyzerError::InvalidLsb { token, .. }
+            | AnalyzerError::InvalidMsb { token, .. }
+            | AnalyzerError::InvalidNumberCharacter { token, .. }
+            | AnalyzerError::InvalidStatement { token, .. }
+            | AnalyzerError::InvalidClock { token, .. }
+            | AnalyzerError::InvalidModportVariableItem { token, .. }
+            | AnalyzerError::InvalidModportFunctionItem { token, .. }
+            | AnalyzerError::UnknownModportMember { token, .. }
+            | AnalyzerError::DuplicatedModportItem { token, .. }
+            | AnalyzerError::InvalidModportAccess { token, .. }
+            | AnalyzerError::UnexpandableInout { token, .. }
+            | AnalyzerError::UnflattenableStructPort { token, .. }
+            | AnalyzerError::InvalidPortDefaultValue { token, .. }
+            | AnalyzerError::InvalidReset { token, .. }
+            | AnalyzerError::InvalidResetNonElaborative { token, .. }
+            | AnalyzerError::InvalidCaseConditionNonElaborative { token, .. }
+            | AnalyzerError::InvalidForRangeNonElaborative { token, .. }
+            | AnalyzerError::InvalidForIterationCount { token, .. }
+            | AnalyzerError::ForIndexAssignment { token, .. }
+            | AnalyzerError::InvalidAssertionExpression { token, .. }
+            | AnalyzerError::InvalidCast { token, .. }
+            | AnalyzerError::InvalidParameterValue { token, .. }
+            | AnalyzerError::InvalidConcatenationOperand { token, .. }
+            | AnalyzerError::InvalidRepetitionCount { token, .. }
+            | AnalyzerError::InvalidTest { token, .. }
+            | AnalyzerError::IncompatProto { token, .. }
+            | AnalyzerError::MissingDefaultArgument { token, .. }
+            | AnalyzerError::SideEffectInExpression { token, .. }
+            | AnalyzerError::MismatchFunctionArity { token, .. }
+            | AnalyzerError::MismatchGenericsArity { token, .. }
+            | AnalyzerError::TooManyGenericArguments { token, .. }
+            | AnalyzerError::MismatchGenericKind { token, .. }
+            | AnalyzerError::MismatchAssignmentStyle { token, .. }
+            | AnalyzerError::MismatchAssignmentStyleInFunction { token, .. }
+            | AnalyzerError::ConstantComparison { token, .. }
+            | AnalyzerError::MixedSignedComparison { token, .. }
+            | AnalyzerError::MismatchAttributeArgs { token, .. }
+            | AnalyzerError::MismatchType { token, .. }
+            | AnalyzerError::InterfaceArrayIndexOutOfRange { token, .. }
+            | AnalyzerError::MismatchInterfaceArraySize { token, .. }
+            | AnalyzerError::MismatchClockDomain { token, .. }
+            | AnalyzerError::MissingIfReset { token, .. }
+            | AnalyzerError::MissingPort { token, .. }
+            | AnalyzerError::MissingClockSignal { token, .. }
+            | AnalyzerError::MissingAssertionClock { token, .. }
+            | AnalyzerError::MissingReset { token, .. }
+            | AnalyzerError::MissingResetSignal { token, .. }
+            | AnalyzerError::MissingResetStatement { token, .. }
+            | AnalyzerError::AssignedOnlyInReset { token, .. }
+            | AnalyzerError::MissingInitFile { token, .. }
+            | AnalyzerError::MissingTri { token, .. }
+            | AnalyzerError::MissingClockDomain { token, .. }
+            | AnalyzerError::SvKeywordUsage { token, .. }
+            | AnalyzerError::SvWithImplicitReset { token, .. }
+            | AnalyzerError::InvalidEnumEncoding { token, .. }
+            | AnalyzerError::InvalidPeriod { token, .. }
+            | AnalyzerError::InvalidCondType { token, .. }
+            | AnalyzerError::TooLargeEnumVariant { token, .. }
+            | AnalyzerError::UnevaluatableEnumVariant { token, .. }
+            | AnalyzerError::InvalidEnumVariant { token, .. }
+            | AnalyzerError::TooLargeNumber { token, .. }
+            | AnalyzerError::ZeroWidthNumber { token, .. }
+            | AnalyzerError::LargeUnsizedNumber { token, .. }
+            | AnalyzerError::ZeroWidth { token, .. }
+            | AnalyzerError::ZeroRepetition { token, .. }
+            | AnalyzerError::ZeroByDefaultParameter { token, .. }
+            | AnalyzerError::RedundantWidth { token, .. }
+            | AnalyzerError::MismatchBranchWidth { token, .. }
+            | AnalyzerError::MismatchConcatenationWidth { token, .. }
+            | AnalyzerError::PreferLet { token, .. }
+            | AnalyzerError::MagicNumber { token, .. }
+            | AnalyzerError::TooManySelect { token, .. }
+            | AnalyzerError::MissingArrayIndex { token, .. }
+            | AnalyzerError::TooMuchEnumVariant { token, .. }
+            | AnalyzerError::UndefinedIdentifier { token, .. }
+            | AnalyzerError::ReferringPackageBeforeDefinition { token, .. }
+            | AnalyzerError::UseBeforeDeclaration { token, .. }
+            | AnalyzerError::UnresolvableGenericArgument { token, .. }
+            | AnalyzerError::UnknownAttribute { token, .. }
+            | AnalyzerError::UnknownFeature { token, .. }
+            | AnalyzerError::UnknownEmbedLang { token, .. }
+            | AnalyzerError::UnknownEmbedWay { token, .. }
+            | AnalyzerError::UnknownIncludeWay { token, .. }
+            | AnalyzerError::UnknownMember { token, .. }
+            | AnalyzerError::UnknownModport { token, .. }
+            | AnalyzerError::DuplicatedStructMember { token, .. }
+            | AnalyzerError::MissingStructMember { token, .. }
+            | AnalyzerError::NonSynthesizable { token, .. }
+            | AnalyzerError::UnknownUnsafe { token, .. }
+            | AnalyzerError::PrivateSymbolAccess { token, .. }
+            | AnalyzerError::PrivateMember { token, .. }
+            | AnalyzerError::UnknownMsb { token, .. }
+            | AnalyzerError::UnknownPort { token, .. }
+            | AnalyzerError::UnknownParam { token, .. }
+            | AnalyzerError::UnusedVariable { token, .. }
+            | AnalyzerError::UnusedImport { token, .. }
+            | AnalyzerError::UnusedModule { token, .. }
+            | AnalyzerError::DuplicatedImport { token, .. }
+            | AnalyzerError::UnusedReturnValue { token, .. }
+            | AnalyzerError::DpiCallInAlwaysComb { token, .. }
+            | AnalyzerError::TruncatedEnumCast { token, .. }
+            | AnalyzerError::ClockReadAsData { token, .. }
+            | AnalyzerError::ClockResetAsData { token, .. }
+            | AnalyzerError::ShadowedBuiltin { token, .. }
+            | AnalyzerError::ShadowedIdentifier { token, .. }
+            | AnalyzerError::UnassignVariable { token, .. }
+            | AnalyzerError::UncoveredBranch { token, .. }
+            | AnalyzerError::DeepCombinationalPath { token, .. }
+            | AnalyzerError::ReservedIdentifier { token, .. }
+            | AnalyzerError::IncludeFailure { token, .. }
+            | AnalyzerError::WrongSeparator { token, .. } => token,
+        }
+    }
+
+    /// Returns labeled locations. The first one is the primary error location,
+    /// and the rest are related locations like the other assignment of multiple assignment.
+    pub fn locations(&self) -> Vec<AnalyzerErrorLocation> {
+        let mut ret = Vec::new();
+        if let (Some(source), Some(labels)) = (self.source_code(), self.labels()) {
+            for label in labels {
+                let start = source.read_span(label.inner(), 0, 0);
+                let end = SourceSpan::new((label.offset() + label.len()).into(), 0);
+                let end = source.read_span(&end, 0, 0);
+                if let (Ok(start), Ok(end)) = (start, end) {
+                    ret.push(AnalyzerErrorLocation {
+                        path: start.name().unwrap_or_default().to_string(),
+                        start_line: start.line() + 1,
+                        start_column: start.column() + 1,
+                        end_line: end.line() + 1,
+                        end_column: end.column() + 1,
+                        label: label.label().map(|x| x.to_string()),
+                    });
+                }
+            }
+        }
+        ret
+    }
+
+    /// Returns the severity before lint level is applied
+    pub fn default_severity(&self) -> Option<Severity> {
+        match self {
+            AnalyzerError::Denied { error } | AnalyzerError::Warned { error } => {
+                error.default_severity()
+            }
+            _ => self.severity(),
+        }
+    }
+
+    /// Promotes the error to Error severity by `deny` lint level
+    pub fn denied(error: AnalyzerError) -> Self {
+        match error {
+            AnalyzerError::Denied { .. } => error,
+            AnalyzerError::Warned { error } => AnalyzerError::denied(*error),
+            _ => AnalyzerError::Denied {
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// Demotes the error to Warning severity by `warn` lint level
+    pub fn warned(error: AnalyzerError) -> Self {
+        match error {
+            AnalyzerError::Warned { .. } => error,
+            AnalyzerError::Denied { error } => AnalyzerError::warned(*error),
+            _ if matches!(error.severity(), Some(Severity::Error) | None) => {
+                AnalyzerError::Warned {
+                    error: Box::new(error),
+                }
+            }
+            _ => error,
+        }
+    }
+
+    pub fn anonymous_identifier_usage(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::AnonymousIdentifierUsage {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn call_non_function(
+        identifier: &str,
+        kind: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::CallNonFunction {
+            identifier: identifier.into(),
+            kind: kind.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn cyclic_type_dependency(
+        source: &str,
+        start: &str,
+        end: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::CyclicTypeDependency {
+            start: start.into(),
+            end: end.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn recursive_instantiation(
+        identifier: &str,
+        path: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::RecursiveInstantiation {
+            identifier: identifier.into(),
+            path: path.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn duplicated_identifier(
+        identifier: &str,
+        declared: Option<&Token>,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let location = |x: &Token| format!("{}:{}:{}", x.source, x.line, x.column);
+        let hint = if let Some(declared) = declared {
+            format!(
+                "{identifier} is declared at {} and {}",
+                location(declared),
+                location(&token.beg)
+            )
+        } else {
+            String::new()
+        };
+        // The other declaration can be labeled only if it is in the same source
+        let declared_location = declared
+            .filter(|x| x.source == token.beg.source)
+            .map(|x| x.into());
+        AnalyzerError::DuplicatedIdentifier {
+            identifier: identifier.to_string(),
+            hint,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            declared_location,
+        }
+    }
+
+    pub fn duplicated_attribute(attribute: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::DuplicatedAttribute {
+            attribute: attribute.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn conflicting_attribute(
+        attribute: &str,
+        other: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::ConflictingAttribute {
+            attribute: attribute.to_string(),
+            other: other.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn duplicated_emitted_name(
+        identifier: &str,
+        project: &str,
+        emitted: &str,
+        other: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::DuplicatedEmittedName {
+            identifier: identifier.to_string(),
+            project: project.to_string(),
+            emitted: emitted.to_string(),
+            other: other.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn duplicated_emitted_name_unused(
+        identifier: &str,
+        project: &str,
+        emitted: &str,
+        other: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::DuplicatedEmittedNameUnused {
+            identifier: identifier.to_string(),
+            project: project.to_string(),
+            emitted: emitted.to_string(),
+            other: other.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn emitted_name_collision(
+        identifier: &str,
+        emitted: &str,
+        other: &str,
+        source: &str,
+        token: &TokenRange,
+        declared: &Token,
+    ) -> Self {
+        AnalyzerError::EmittedNameCollision {
+            identifier: identifier.to_string(),
+            emitted: emitted.to_string(),
+            other: other.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            declared_location: declared.into(),
+        }
+    }
+
+    pub fn sv_keyword_emitted_name(
+        identifier: &str,
+        emitted: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::SvKeywordEmittedName {
+            identifier: identifier.to_string(),
+            emitted: emitted.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn multiple_init_file(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MultipleInitFile {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn multiple_assignment(
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+        assign_pos0: &TokenRange,
+        assign_pos1: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MultipleAssignment {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            assign_pos0: assign_pos0.into(),
+            assign_pos1: assign_pos1.into(),
+        }
+    }
+
+    pub fn let_reassignment(
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+        let_pos: &TokenRange,
+        assign_pos: &TokenRange,
+    ) -> Self {
+        AnalyzerError::LetReassignment {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            let_pos: let_pos.into(),
+            assign_pos: assign_pos.into(),
+        }
+    }
+
+    pub fn hierarchical_reference(
+        identifier: &str,
+        path: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::HierarchicalReference {
+            identifier: identifier.to_string(),
+            path: path.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_allow(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        let codes: Vec<_> = AnalyzerErrorCode::all()
+            .iter()
+            .map(|x| x.as_str())
+            .collect();
+        AnalyzerError::InvalidAllow {
+            identifier: identifier.to_string(),
+            codes: codes.join(", "),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_assignment(
+        identifier: &str,
+        source: &str,
+        kind: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidAssignment {
+            identifier: identifier.into(),
+            kind: kind.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_assignment_to_const(
+        identifier: &str,
+        source: &str,
+        kind: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidAssignmentToConst {
+            identifier: identifier.into(),
+            kind: kind.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_assignment_to_input(
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+        definition_token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidAssignmentToInput {
+            identifier: identifier.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
+        }
+    }
+
+    pub fn invalid_output_connection(
+        port: &str,
+        direction: &str,
+        source: &str,
+        token: &TokenRange,
+        definition_token: Option<&TokenRange>,
+    ) -> Self {
+        AnalyzerError::InvalidOutputConnection {
+            port: port.into(),
+            direction: direction.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.map(|x| x.into()),
+        }
+    }
+
+    pub fn invalid_attribute_target(
+        attribute: &str,
+        target: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidAttributeTarget {
+            attribute: attribute.to_string(),
+            target: target.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_init_file_target(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidInitFileTarget {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_dpi_target(attribute: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidDpiTarget {
+            attribute: attribute.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_dpi_type(
+        identifier: &str,
+        function: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidDpiType {
+            identifier: identifier.to_string(),
+            function: function.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_dpi_import_body(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidDpiImportBody {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_direction(kind: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidDirection {
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_factor(identifier: &str, kind: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidFactor {
+            identifier: identifier.to_string(),
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_interface_value(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidInterfaceValue {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_width_expression(
+        identifier: &str,
+        kind: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidWidthExpression {
+            identifier: identifier.to_string(),
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_identifier(
+        identifier: &str,
+        rule: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidIdentifier {
+            identifier: identifier.to_string(),
+            rule: rule.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_import(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidImport {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_lsb(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidLsb {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_msb(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidMsb {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_number_character(
+        cause: char,
+        kind: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidNumberCharacter {
+            cause,
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_statement(kind: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidStatement {
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
     }
 
-    pub fn anonymous_identifier_usage(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::AnonymousIdentifierUsage {
+    pub fn invalid_clock(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidClock {
+            identifier: identifier.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn call_non_function(
+    pub fn invalid_reset(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidReset {
+            identifier: identifier.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_reset_non_elaborative(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidResetNonElaborative {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_case_condition_non_elaborative(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidCaseConditionNonElaborative {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_for_range_non_elaborative(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidForRangeNonElaborative {
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_for_iteration_count(
+        range: &str,
+        count: Option<usize>,
+        max: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let count = match count {
+            Some(x) => x.to_string(),
+            None => format!("more than {max}"),
+        };
+        AnalyzerError::InvalidForIterationCount {
+            range: range.to_string(),
+            count,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn for_index_assignment(
         identifier: &str,
-        kind: &str,
+        range: &str,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::CallNonFunction {
+        AnalyzerError::ForIndexAssignment {
+            identifier: identifier.to_string(),
+            range: range.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_assertion_expression(width: usize, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidAssertionExpression {
+            width,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_cast(from: &str, to: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidCast {
+            from: from.into(),
+            to: to.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_parameter_value(
+        identifier: &str,
+        value: isize,
+        range: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidParameterValue {
+            identifier: identifier.to_string(),
+            value,
+            range: range.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_concatenation_operand(
+        position: usize,
+        type_name: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidConcatenationOperand {
+            position,
+            type_name: type_name.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_repetition_count(
+        count: &str,
+        position: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidRepetitionCount {
+            count: count.to_string(),
+            position,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_test(cause: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidTest {
+            cause: cause.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_modport_variable_item(
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+        definition_token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidModportVariableItem {
             identifier: identifier.into(),
-            kind: kind.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
         }
     }
 
-    pub fn cyclic_type_dependency(
+    pub fn invalid_modport_function_item(
+        identifier: &str,
         source: &str,
-        start: &str,
-        end: &str,
         token: &TokenRange,
+        definition_token: &TokenRange,
     ) -> Self {
-        AnalyzerError::CyclicTypeDependency {
-            start: start.into(),
-            end: end.into(),
+        AnalyzerError::InvalidModportFunctionItem {
+            identifier: identifier.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
         }
     }
 
-    pub fn duplicated_identifier(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::DuplicatedIdentifier {
-            identifier: identifier.to_string(),
+    pub fn unknown_modport_member(
+        identifier: &str,
+        interface: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::UnknownModportMember {
+            identifier: identifier.into(),
+            interface: interface.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn multiple_assignment(
+    pub fn duplicated_modport_item(
         identifier: &str,
+        modport: &str,
         source: &str,
         token: &TokenRange,
-        assign_pos0: &TokenRange,
-        assign_pos1: &TokenRange,
+        first_token: &TokenRange,
     ) -> Self {
-        AnalyzerError::MultipleAssignment {
-            identifier: identifier.to_string(),
+        AnalyzerError::DuplicatedModportItem {
+            identifier: identifier.into(),
+            modport: modport.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
-            assign_pos0: assign_pos0.into(),
-            assign_pos1: assign_pos1.into(),
+            token: *token,
+            first_location: first_token.into(),
         }
     }
 
-    pub fn invalid_allow(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidAllow {
-            identifier: identifier.to_string(),
+    pub fn invalid_modport_access(
+        identifier: &str,
+        access: &str,
+        direction: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidModportAccess {
+            identifier: identifier.into(),
+            access: access.into(),
+            direction: direction.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unexpandable_inout(
+        identifier: &str,
+        reason: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::UnexpandableInout {
+            identifier: identifier.into(),
+            reason: reason.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unflattenable_struct_port(
+        identifier: &str,
+        reason: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::UnflattenableStructPort {
+            identifier: identifier.into(),
+            reason: reason.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_port_default_value(
+        identifier: &str,
+        direction: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidPortDefaultValue {
+            identifier: identifier.into(),
+            direction: direction.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn incompat_proto(
+        identifier: &str,
+        proto: &str,
+        cause: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::IncompatProto {
+            identifier: identifier.into(),
+            proto: proto.into(),
+            cause: cause.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn missing_default_argument(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingDefaultArgument {
+            identifier: identifier.into(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn side_effect_in_expression(
+        name: &str,
+        direction: &str,
+        argument: &str,
+        context: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::SideEffectInExpression {
+            name: name.to_string(),
+            direction: direction.to_string(),
+            argument: argument.to_string(),
+            context: context.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn mismatch_function_arity(
+        name: &str,
+        arity: usize,
+        args: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MismatchFunctionArity {
+            name: name.to_string(),
+            arity,
+            args,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn mismatch_generics_arity(
+        name: &str,
+        arity: usize,
+        args: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MismatchGenericsArity {
+            name: name.to_string(),
+            arity,
+            args,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_assignment(
-        identifier: &str,
+    pub fn too_many_generic_arguments(
+        name: &str,
+        arity: usize,
+        args: usize,
         source: &str,
-        kind: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidAssignment {
-            identifier: identifier.into(),
-            kind: kind.into(),
+        AnalyzerError::TooManyGenericArguments {
+            name: name.to_string(),
+            arity,
+            args,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_assignment_to_const(
-        identifier: &str,
+    pub fn mismatch_generic_kind(
+        parameter: &str,
+        argument: &str,
+        expected: &str,
+        actual: &str,
         source: &str,
-        kind: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidAssignmentToConst {
-            identifier: identifier.into(),
-            kind: kind.into(),
+        AnalyzerError::MismatchGenericKind {
+            parameter: parameter.to_string(),
+            argument: argument.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_direction(kind: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidDirection {
-            kind: kind.to_string(),
+    pub fn mismatch_type(
+        name: &str,
+        expected: &str,
+        actual: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MismatchType {
+            name: name.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_factor(identifier: &str, kind: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidFactor {
+    pub fn interface_array_index_out_of_range(
+        identifier: &str,
+        index: isize,
+        size: isize,
+        source: &str,
+        token: &TokenRange,
+        definition_token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InterfaceArrayIndexOutOfRange {
             identifier: identifier.to_string(),
-            kind: kind.to_string(),
+            index,
+            size,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
         }
     }
 
-    pub fn invalid_identifier(
+    pub fn mismatch_interface_array_size(
         identifier: &str,
-        rule: &str,
+        port: &str,
+        expected: &str,
+        actual: &str,
         source: &str,
         token: &TokenRange,
+        definition_token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidIdentifier {
+        AnalyzerError::MismatchInterfaceArraySize {
             identifier: identifier.to_string(),
-            rule: rule.to_string(),
+            port: port.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
         }
     }
 
-    pub fn invalid_import(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidImport {
+    pub fn mismatch_clock_domain(
+        clock_domain: &str,
+        other_domain: &str,
+        source: &str,
+        token: &TokenRange,
+        other_token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MismatchClockDomain {
+            clock_domain: clock_domain.to_string(),
+            other_domain: other_domain.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            other_location: other_token.into(),
         }
     }
 
-    pub fn invalid_lsb(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidLsb {
+    pub fn missing_clock_signal(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingClockSignal {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_msb(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidMsb {
+    pub fn missing_assertion_clock(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingAssertionClock {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_number_character(
-        cause: char,
-        kind: &str,
-        source: &str,
-        token: &TokenRange,
-    ) -> Self {
-        AnalyzerError::InvalidNumberCharacter {
-            cause,
-            kind: kind.to_string(),
+    pub fn missing_if_reset(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingIfReset {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_statement(kind: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidStatement {
-            kind: kind.to_string(),
+    pub fn missing_reset(name: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingReset {
+            name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_clock(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidClock {
-            identifier: identifier.into(),
+    pub fn missing_reset_signal(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingResetSignal {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_reset(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidReset {
-            identifier: identifier.into(),
+    pub fn missing_reset_statement(
+        name: &str,
+        source: &str,
+        token: &TokenRange,
+        reset: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MissingResetStatement {
+            name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            reset: reset.into(),
         }
     }
 
-    pub fn invalid_reset_non_elaborative(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidResetNonElaborative {
+    pub fn assigned_only_in_reset(
+        name: &str,
+        source: &str,
+        token: &TokenRange,
+        reset: &TokenRange,
+    ) -> Self {
+        AnalyzerError::AssignedOnlyInReset {
+            name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            reset: reset.into(),
         }
     }
 
-    pub fn invalid_case_condition_non_elaborative(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidCaseConditionNonElaborative {
+    pub fn missing_init_file(path: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingInitFile {
+            path: path.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_cast(from: &str, to: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidCast {
-            from: from.into(),
-            to: to.into(),
+    pub fn missing_tri(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingTri {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_test(cause: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidTest {
-            cause: cause.into(),
+    pub fn missing_clock_domain(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingClockDomain {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_modport_variable_item(
+    pub fn mismatch_assignment_style(
         identifier: &str,
+        operator: &str,
+        block: &str,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidModportVariableItem {
+        AnalyzerError::MismatchAssignmentStyle {
             identifier: identifier.into(),
+            operator: operator.into(),
+            block: block.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_modport_function_item(
+    pub fn mismatch_assignment_style_in_function(
         identifier: &str,
+        function: &str,
+        block: &str,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidModportFunctionItem {
+        AnalyzerError::MismatchAssignmentStyleInFunction {
             identifier: identifier.into(),
+            function: function.into(),
+            block: block.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_port_default_value(
-        identifier: &str,
-        direction: &str,
+    pub fn constant_comparison(
+        left: &str,
+        right: &str,
+        width: usize,
+        result: bool,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidPortDefaultValue {
-            identifier: identifier.into(),
-            direction: direction.into(),
+        AnalyzerError::ConstantComparison {
+            left: left.into(),
+            right: right.into(),
+            width,
+            result,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn incompat_proto(
-        identifier: &str,
-        proto: &str,
-        cause: &str,
+    pub fn mixed_signed_comparison(
+        left: &str,
+        right: &str,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::IncompatProto {
-            identifier: identifier.into(),
-            proto: proto.into(),
-            cause: cause.into(),
-            input: AnalyzerError::named_source(source, token),
-            error_location: token.into(),
-        }
-    }
-
-    pub fn missing_default_argument(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingDefaultArgument {
-            identifier: identifier.into(),
+        AnalyzerError::MixedSignedComparison {
+            left: left.into(),
+            right: right.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn mismatch_function_arity(
+    pub fn mismatch_attribute_args(
         name: &str,
-        arity: usize,
-        args: usize,
+        expected: &str,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::MismatchFunctionArity {
+        AnalyzerError::MismatchAttributeArgs {
             name: name.to_string(),
-            arity,
-            args,
+            expected: expected.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn mismatch_generics_arity(
-        name: &str,
-        arity: usize,
-        args: usize,
-        source: &str,
-        token: &TokenRange,
-    ) -> Self {
-        AnalyzerError::MismatchGenericsArity {
+    pub fn missing_port(name: &str, port: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingPort {
             name: name.to_string(),
-            arity,
-            args,
+            port: port.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn mismatch_type(
-        name: &str,
-        expected: &str,
-        actual: &str,
-        source: &str,
-        token: &TokenRange,
-    ) -> Self {
-        AnalyzerError::MismatchType {
-            name: name.to_string(),
-            expected: expected.to_string(),
-            actual: actual.to_string(),
+    pub fn sv_keyword_usage(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::SvKeywordUsage {
+            identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn mismatch_clock_domain(
-        clock_domain: &str,
-        other_domain: &str,
-        source: &str,
-        token: &TokenRange,
-        other_token: &TokenRange,
-    ) -> Self {
-        AnalyzerError::MismatchClockDomain {
-            clock_domain: clock_domain.to_string(),
-            other_domain: other_domain.to_string(),
+    pub fn sv_with_implicit_reset(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::SvWithImplicitReset {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
-            other_location: other_token.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_clock_signal(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingClockSignal {
+    pub fn invalid_enum_encoding(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidEnumEncoding {
+            identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_if_reset(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingIfReset {
+    pub fn invalid_cond_type(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidCondType {
+            identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_reset_signal(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingResetSignal {
+    pub fn invalid_period(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidPeriod {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn too_large_enum_variant(
+        identifier: &str,
+        value: isize,
+        width: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::TooLargeEnumVariant {
+            identifier: identifier.to_string(),
+            value,
+            width,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unevaluatable_enum_variant(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnevaluatableEnumVariant {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn invalid_enum_variant_value(
+        identifier: &str,
+        encoding: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::InvalidEnumVariant {
+            identifier: identifier.to_string(),
+            encoding: encoding.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_reset_statement(
-        name: &str,
+    pub fn too_large_number(
+        width: usize,
+        required: usize,
         source: &str,
         token: &TokenRange,
-        reset: &TokenRange,
     ) -> Self {
-        AnalyzerError::MissingResetStatement {
-            name: name.to_string(),
+        AnalyzerError::TooLargeNumber {
+            width,
+            required,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
-            reset: reset.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_tri(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingTri {
+    pub fn zero_width_number(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::ZeroWidthNumber {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_clock_domain(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingClockDomain {
+    pub fn large_unsized_number(required: usize, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::LargeUnsizedNumber {
+            required,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn mismatch_attribute_args(
-        name: &str,
-        expected: &str,
-        source: &str,
-        token: &TokenRange,
-    ) -> Self {
-        AnalyzerError::MismatchAttributeArgs {
-            name: name.to_string(),
-            expected: expected.to_string(),
+    pub fn zero_width(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::ZeroWidth {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn missing_port(name: &str, port: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::MissingPort {
-            name: name.to_string(),
-            port: port.to_string(),
+    pub fn zero_repetition(source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::ZeroRepetition {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn sv_keyword_usage(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::SvKeywordUsage {
-            identifier: identifier.to_string(),
+    pub fn zero_by_default_parameter(
+        context: &str,
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::ZeroByDefaultParameter {
+            context: context.into(),
+            identifier: identifier.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn sv_with_implicit_reset(source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::SvWithImplicitReset {
+    pub fn redundant_width(kind: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::RedundantWidth {
+            kind: kind.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_enum_encoding(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidEnumEncoding {
-            identifier: identifier.to_string(),
+    pub fn mismatch_branch_width(
+        width: usize,
+        other_width: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MismatchBranchWidth {
+            width,
+            other_width,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_cond_type(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::InvalidCondType {
-            identifier: identifier.to_string(),
+    pub fn mismatch_concatenation_width(
+        width: usize,
+        target: &str,
+        expected: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MismatchConcatenationWidth {
+            width,
+            target: target.into(),
+            expected,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn too_large_enum_variant(
+    pub fn prefer_let(
         identifier: &str,
-        value: isize,
-        width: usize,
+        fix: &str,
         source: &str,
         token: &TokenRange,
+        assign_pos: &TokenRange,
     ) -> Self {
-        AnalyzerError::TooLargeEnumVariant {
+        AnalyzerError::PreferLet {
             identifier: identifier.to_string(),
-            value,
-            width,
+            fix: fix.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            assign_pos: assign_pos.into(),
         }
     }
 
-    pub fn unevaluatable_enum_variant(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::UnevaluatableEnumVariant {
-            identifier: identifier.to_string(),
+    pub fn magic_number(value: &str, context: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MagicNumber {
+            value: value.into(),
+            context: context.into(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn invalid_enum_variant_value(
+    pub fn too_many_select(
         identifier: &str,
-        encoding: &str,
+        dimension: usize,
+        select: usize,
         source: &str,
         token: &TokenRange,
     ) -> Self {
-        AnalyzerError::InvalidEnumVariant {
+        AnalyzerError::TooManySelect {
             identifier: identifier.to_string(),
-            encoding: encoding.to_string(),
+            dimension,
+            select,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn too_large_number(width: usize, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::TooLargeNumber {
-            width,
+    pub fn missing_array_index(
+        identifier: &str,
+        dimension: usize,
+        select: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MissingArrayIndex {
+            identifier: identifier.to_string(),
+            dimension,
+            select,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1573,14 +4848,31 @@ impl AnalyzerError {
             width,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn undefined_identifier(identifier: &str, source: &str, token: &TokenRange) -> Self {
+    pub fn undefined_identifier(
+        identifier: &str,
+        similar: &[String],
+        package: Option<&str>,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let mut hints = Vec::new();
+        if !similar.is_empty() {
+            let similar: Vec<_> = similar.iter().map(|x| format!("\"{x}\"")).collect();
+            hints.push(format!("did you mean {}?", similar.join(", ")));
+        }
+        if let Some(package) = package {
+            hints.push(format!("did you forget \"import {package}::*\"?"));
+        }
         AnalyzerError::UndefinedIdentifier {
             identifier: identifier.to_string(),
+            hint: hints.join(" "),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1593,6 +4885,22 @@ impl AnalyzerError {
             identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn use_before_declaration(
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+        definition_token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::UseBeforeDeclaration {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
         }
     }
 
@@ -1606,15 +4914,43 @@ impl AnalyzerError {
             identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
             definition_location: definition_token.into(),
         }
     }
 
-    pub fn unknown_attribute(name: &str, source: &str, token: &TokenRange) -> Self {
+    pub fn unknown_attribute(
+        name: &str,
+        similar: Option<&str>,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let hint = similar
+            .map(|x| format!("did you mean \"{x}\"?"))
+            .unwrap_or_default();
         AnalyzerError::UnknownAttribute {
             name: name.to_string(),
+            hint,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unknown_feature(
+        name: &str,
+        project: &str,
+        available: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::UnknownFeature {
+            name: name.to_string(),
+            project: project.to_string(),
+            available: available.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1623,6 +4959,7 @@ impl AnalyzerError {
             name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1631,6 +4968,7 @@ impl AnalyzerError {
             name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1639,6 +4977,7 @@ impl AnalyzerError {
             name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1648,6 +4987,69 @@ impl AnalyzerError {
             member: member.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unknown_modport(
+        interface: &str,
+        modport: &str,
+        candidates: &[String],
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let hint = if candidates.is_empty() {
+            format!("interface \"{interface}\" has no modport")
+        } else {
+            let candidates: Vec<_> = candidates.iter().map(|x| format!("\"{x}\"")).collect();
+            format!("available modports: {}", candidates.join(", "))
+        };
+        AnalyzerError::UnknownModport {
+            interface: interface.to_string(),
+            modport: modport.to_string(),
+            hint,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn duplicated_struct_member(
+        name: &str,
+        member: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::DuplicatedStructMember {
+            name: name.to_string(),
+            member: member.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn missing_struct_member(
+        name: &str,
+        members: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::MissingStructMember {
+            name: name.to_string(),
+            members: members.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn non_synthesizable(kind: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::NonSynthesizable {
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1656,6 +5058,24 @@ impl AnalyzerError {
             name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn private_symbol_access(
+        identifier: &str,
+        package: &str,
+        source: &str,
+        token: &TokenRange,
+        definition_token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::PrivateSymbolAccess {
+            identifier: identifier.to_string(),
+            package: package.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.into(),
         }
     }
 
@@ -1664,6 +5084,7 @@ impl AnalyzerError {
             name: name.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1671,6 +5092,7 @@ impl AnalyzerError {
         AnalyzerError::UnknownMsb {
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1680,15 +5102,27 @@ impl AnalyzerError {
             port: port.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn unknown_param(name: &str, param: &str, source: &str, token: &TokenRange) -> Self {
+    pub fn unknown_param(
+        name: &str,
+        param: &str,
+        similar: Option<&str>,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let hint = similar
+            .map(|x| format!("did you mean \"{x}\"?"))
+            .unwrap_or_else(|| format!("remove \"{param}\" param"));
         AnalyzerError::UnknownParam {
             name: name.to_string(),
             param: param.to_string(),
+            hint,
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1697,14 +5131,151 @@ impl AnalyzerError {
             identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unused_import(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnusedImport {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unused_module(
+        kind: &str,
+        identifier: &str,
+        whole_file: bool,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let hint = if whole_file {
+            "all items in this file are unused, so the file can be removed".to_string()
+        } else {
+            format!("remove {identifier} or instantiate it from the top modules")
+        };
+        AnalyzerError::UnusedModule {
+            kind: kind.to_string(),
+            identifier: identifier.to_string(),
+            hint,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn duplicated_import(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::DuplicatedImport {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn unused_return_value(
+        identifier: &str,
+        no_effect: bool,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        let detail = if no_effect {
+            ", and the call has no effect"
+        } else {
+            ""
+        };
+        AnalyzerError::UnusedReturnValue {
+            identifier: identifier.to_string(),
+            detail: detail.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn dpi_call_in_always_comb(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::DpiCallInAlwaysComb {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn truncated_enum_cast(
+        from: &str,
+        from_width: usize,
+        to: &str,
+        to_width: usize,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::TruncatedEnumCast {
+            from: from.to_string(),
+            from_width,
+            to: to.to_string(),
+            to_width,
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn clock_read_as_data(identifier: &str, source: &str, token: &TokenRange) -> Self {
+        AnalyzerError::ClockReadAsData {
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn clock_reset_as_data(
+        kind: &str,
+        identifier: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::ClockResetAsData {
+            kind: kind.to_string(),
+            identifier: identifier.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
+    pub fn shadowed_builtin(
+        identifier: &str,
+        kind: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::ShadowedBuiltin {
+            identifier: identifier.to_string(),
+            kind: kind.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
         }
     }
 
-    pub fn unused_return(identifier: &str, source: &str, token: &TokenRange) -> Self {
-        AnalyzerError::UnusedReturn {
+    pub fn shadowed_identifier(
+        identifier: &str,
+        shadowed: &str,
+        source: &str,
+        token: &TokenRange,
+        definition_token: Option<&TokenRange>,
+    ) -> Self {
+        AnalyzerError::ShadowedIdentifier {
             identifier: identifier.to_string(),
+            shadowed: shadowed.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
+            definition_location: definition_token.map(|x| x.into()),
         }
     }
 
@@ -1713,6 +5284,7 @@ impl AnalyzerError {
             identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1726,15 +5298,36 @@ impl AnalyzerError {
             identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
             uncovered: uncovered.into(),
         }
     }
 
+    pub fn deep_combinational_path(
+        identifier: &str,
+        depth: usize,
+        max_depth: usize,
+        path: &str,
+        source: &str,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::DeepCombinationalPath {
+            identifier: identifier.to_string(),
+            depth,
+            max_depth,
+            path: path.to_string(),
+            input: AnalyzerError::named_source(source, token),
+            error_location: token.into(),
+            token: *token,
+        }
+    }
+
     pub fn reserved_identifier(identifier: &str, source: &str, token: &TokenRange) -> Self {
         AnalyzerError::ReservedIdentifier {
             identifier: identifier.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1744,6 +5337,7 @@ impl AnalyzerError {
             cause: cause.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 
@@ -1754,6 +5348,7 @@ impl AnalyzerError {
             valid_separator: valid_separator.to_string(),
             input: AnalyzerError::named_source(source, token),
             error_location: token.into(),
+            token: *token,
         }
     }
 }
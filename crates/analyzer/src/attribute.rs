@@ -1,18 +1,32 @@
+use crate::analyzer_error::AnalyzerErrorCode;
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fmt;
 use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::veryl_token::Token;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Attribute {
     Ifdef(StrId),
     Ifndef(StrId),
     Sv(StrId),
-    Allow(AllowItem),
+    Lint(LintLevel, AnalyzerErrorCode),
     EnumEncoding(EnumEncodingItem),
     EnumMemberPrefix(StrId),
     Test(Token, Option<StrId>),
     CondType(CondTypeItem),
+    Pad,
+    Feature(StrId),
+    InitFile(StrId),
+    DpiImport,
+    DpiExport,
+    NoPreamble,
+    ExportHeader,
+    Private,
+    DocInclude(StrId),
+    Testbench,
+    Period(StrId),
+    Range(isize, isize),
 }
 
 impl fmt::Display for Attribute {
@@ -21,11 +35,23 @@ impl fmt::Display for Attribute {
             Attribute::Ifdef(x) => format!("ifdef({})", x),
             Attribute::Ifndef(x) => format!("ifndef({})", x),
             Attribute::Sv(x) => format!("sv(\"{}\")", x),
-            Attribute::Allow(x) => format!("allow({})", x),
+            Attribute::Lint(x, y) => format!("{}({})", x, y),
             Attribute::EnumEncoding(x) => format!("enum_encoding({})", x),
             Attribute::EnumMemberPrefix(x) => format!("enum_member_prefix({})", x),
             Attribute::Test(x, _) => format!("test({})", x.text),
             Attribute::CondType(x) => format!("cond_type({})", x),
+            Attribute::Pad => "pad".to_string(),
+            Attribute::Feature(x) => format!("feature({})", x),
+            Attribute::InitFile(x) => format!("init_file(\"{}\")", x),
+            Attribute::DpiImport => "dpi_import".to_string(),
+            Attribute::DpiExport => "dpi_export".to_string(),
+            Attribute::NoPreamble => "no_preamble".to_string(),
+            Attribute::ExportHeader => "export_header".to_string(),
+            Attribute::Private => "private".to_string(),
+            Attribute::DocInclude(x) => format!("doc_include(\"{}\")", x),
+            Attribute::Testbench => "testbench".to_string(),
+            Attribute::Period(x) => format!("period(\"{}\")", x),
+            Attribute::Range(x, y) => format!("range(\"{}\", \"{}\")", x, y),
         };
         text.fmt(f)
     }
@@ -38,6 +64,18 @@ pub enum AttributeError {
     InvalidAllow(StrId),
     InvalidEnumEncoding(StrId),
     InvalidCondType(StrId),
+    InvalidPeriod(StrId),
+}
+
+fn get_args_len(args: &Option<veryl_parser::veryl_grammar_trait::AttributeOpt>) -> usize {
+    use veryl_parser::veryl_grammar_trait as g;
+
+    if let Some(ref x) = args {
+        let args: Vec<g::AttributeItem> = x.attribute_list.as_ref().into();
+        args.len()
+    } else {
+        0
+    }
 }
 
 fn get_arg_ident(
@@ -60,6 +98,15 @@ fn get_arg_ident(
     }
 }
 
+/// Returns the integer written in a string literal like `"1024"`
+fn get_arg_number(
+    args: &Option<veryl_parser::veryl_grammar_trait::AttributeOpt>,
+    pos: usize,
+) -> Option<isize> {
+    let text = get_arg_string(args, pos)?.to_string();
+    text.trim_matches('"').trim().replace('_', "").parse().ok()
+}
+
 fn get_arg_string(
     args: &Option<veryl_parser::veryl_grammar_trait::AttributeOpt>,
     pos: usize,
@@ -85,9 +132,8 @@ struct Pattern {
     pub ifndef: StrId,
     pub sv: StrId,
     pub allow: StrId,
-    pub missing_port: StrId,
-    pub missing_reset_statement: StrId,
-    pub unused_variable: StrId,
+    pub warn: StrId,
+    pub deny: StrId,
     pub enum_encoding: StrId,
     pub sequential: StrId,
     pub onehot: StrId,
@@ -99,6 +145,18 @@ struct Pattern {
     pub unique0: StrId,
     pub priority: StrId,
     pub none: StrId,
+    pub pad: StrId,
+    pub feature: StrId,
+    pub init_file: StrId,
+    pub dpi_import: StrId,
+    pub dpi_export: StrId,
+    pub no_preamble: StrId,
+    pub export_header: StrId,
+    pub private: StrId,
+    pub doc_include: StrId,
+    pub testbench: StrId,
+    pub period: StrId,
+    pub range: StrId,
 }
 
 impl Pattern {
@@ -108,9 +166,8 @@ impl Pattern {
             ifndef: resource_table::insert_str("ifndef"),
             sv: resource_table::insert_str("sv"),
             allow: resource_table::insert_str("allow"),
-            missing_port: resource_table::insert_str("missing_port"),
-            missing_reset_statement: resource_table::insert_str("missing_reset_statement"),
-            unused_variable: resource_table::insert_str("unused_variable"),
+            warn: resource_table::insert_str("warn"),
+            deny: resource_table::insert_str("deny"),
             enum_encoding: resource_table::insert_str("enum_encoding"),
             sequential: resource_table::insert_str("sequential"),
             onehot: resource_table::insert_str("onehot"),
@@ -122,6 +179,18 @@ impl Pattern {
             unique0: resource_table::insert_str("unique0"),
             priority: resource_table::insert_str("priority"),
             none: resource_table::insert_str("none"),
+            pad: resource_table::insert_str("pad"),
+            feature: resource_table::insert_str("feature"),
+            init_file: resource_table::insert_str("init_file"),
+            dpi_import: resource_table::insert_str("dpi_import"),
+            dpi_export: resource_table::insert_str("dpi_export"),
+            no_preamble: resource_table::insert_str("no_preamble"),
+            export_header: resource_table::insert_str("export_header"),
+            private: resource_table::insert_str("private"),
+            doc_include: resource_table::insert_str("doc_include"),
+            testbench: resource_table::insert_str("testbench"),
+            period: resource_table::insert_str("period"),
+            range: resource_table::insert_str("range"),
         }
     }
 }
@@ -132,9 +201,11 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
     type Error = AttributeError;
 
     fn try_from(value: &veryl_parser::veryl_grammar_trait::Attribute) -> Result<Self, Self::Error> {
+        let len = get_args_len(&value.attribute_opt);
+
         PAT.with_borrow(|pat| match value.identifier.identifier_token.token.text {
             x if x == pat.ifdef || x == pat.ifndef => {
-                let arg = get_arg_ident(&value.attribute_opt, 0);
+                let arg = get_arg_ident(&value.attribute_opt, 0).filter(|_| len == 1);
 
                 if let Some(arg) = arg {
                     if x == pat.ifdef {
@@ -147,7 +218,7 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                 }
             }
             x if x == pat.sv => {
-                let arg = get_arg_string(&value.attribute_opt, 0);
+                let arg = get_arg_string(&value.attribute_opt, 0).filter(|_| len == 1);
 
                 if let Some(arg) = arg {
                     Ok(Attribute::Sv(arg.text))
@@ -155,26 +226,29 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                     Err(AttributeError::MismatchArgs("single string"))
                 }
             }
-            x if x == pat.allow => {
-                let arg = get_arg_ident(&value.attribute_opt, 0);
+            x if x == pat.allow || x == pat.warn || x == pat.deny => {
+                let arg = get_arg_ident(&value.attribute_opt, 0).filter(|_| len == 1);
+                let level = if x == pat.allow {
+                    LintLevel::Allow
+                } else if x == pat.warn {
+                    LintLevel::Warn
+                } else {
+                    LintLevel::Deny
+                };
 
                 if let Some(arg) = arg {
-                    match arg.text {
-                        x if x == pat.missing_port => Ok(Attribute::Allow(AllowItem::MissingPort)),
-                        x if x == pat.missing_reset_statement => {
-                            Ok(Attribute::Allow(AllowItem::MissingResetStatement))
-                        }
-                        x if x == pat.unused_variable => {
-                            Ok(Attribute::Allow(AllowItem::UnusedVariable))
-                        }
-                        _ => Err(AttributeError::InvalidAllow(arg.text)),
+                    let code = AnalyzerErrorCode::from_name(&arg.text.to_string());
+                    if let Some(code) = code {
+                        Ok(Attribute::Lint(level, code))
+                    } else {
+                        Err(AttributeError::InvalidAllow(arg.text))
                     }
                 } else {
-                    Err(AttributeError::MismatchArgs("allowable rule"))
+                    Err(AttributeError::MismatchArgs("lint code"))
                 }
             }
             x if x == pat.enum_encoding => {
-                let arg = get_arg_ident(&value.attribute_opt, 0);
+                let arg = get_arg_ident(&value.attribute_opt, 0).filter(|_| len == 1);
 
                 if let Some(arg) = arg {
                     match arg.text {
@@ -192,7 +266,7 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                 }
             }
             x if x == pat.enum_member_prefix => {
-                let arg = get_arg_ident(&value.attribute_opt, 0);
+                let arg = get_arg_ident(&value.attribute_opt, 0).filter(|_| len == 1);
 
                 if let Some(arg) = arg {
                     Ok(Attribute::EnumMemberPrefix(arg.text))
@@ -204,14 +278,16 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                 let arg = get_arg_ident(&value.attribute_opt, 0);
                 let top = get_arg_ident(&value.attribute_opt, 1);
 
-                if let Some(arg) = arg {
-                    Ok(Attribute::Test(arg, top.map(|x| x.text)))
-                } else {
-                    Err(AttributeError::MismatchArgs("single identifier"))
+                match (arg, top, len) {
+                    (Some(arg), None, 1) => Ok(Attribute::Test(arg, None)),
+                    (Some(arg), Some(top), 2) => Ok(Attribute::Test(arg, Some(top.text))),
+                    _ => Err(AttributeError::MismatchArgs(
+                        "test name and optional top module name",
+                    )),
                 }
             }
             x if x == pat.cond_type => {
-                let arg = get_arg_ident(&value.attribute_opt, 0);
+                let arg = get_arg_ident(&value.attribute_opt, 0).filter(|_| len == 1);
 
                 if let Some(arg) = arg {
                     match arg.text {
@@ -225,30 +301,219 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                     Err(AttributeError::MismatchArgs("condition type"))
                 }
             }
+            x if x == pat.pad => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument"))
+                } else {
+                    Ok(Attribute::Pad)
+                }
+            }
+            x if x == pat.feature => {
+                let arg = get_arg_ident(&value.attribute_opt, 0).filter(|_| len == 1);
+
+                if let Some(arg) = arg {
+                    Ok(Attribute::Feature(arg.text))
+                } else {
+                    Err(AttributeError::MismatchArgs("single identifier"))
+                }
+            }
+            x if x == pat.init_file => {
+                let arg = get_arg_string(&value.attribute_opt, 0).filter(|_| len == 1);
+
+                if let Some(arg) = arg {
+                    let text = arg.text.to_string();
+                    let text = &text[1..text.len() - 1];
+                    Ok(Attribute::InitFile(resource_table::insert_str(text)))
+                } else {
+                    Err(AttributeError::MismatchArgs("single string"))
+                }
+            }
+            x if x == pat.dpi_import || x == pat.dpi_export => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument"))
+                } else if x == pat.dpi_import {
+                    Ok(Attribute::DpiImport)
+                } else {
+                    Ok(Attribute::DpiExport)
+                }
+            }
+            x if x == pat.no_preamble => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument"))
+                } else {
+                    Ok(Attribute::NoPreamble)
+                }
+            }
+            x if x == pat.export_header => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument"))
+                } else {
+                    Ok(Attribute::ExportHeader)
+                }
+            }
+            x if x == pat.private => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument"))
+                } else {
+                    Ok(Attribute::Private)
+                }
+            }
+            x if x == pat.doc_include => {
+                let arg = get_arg_string(&value.attribute_opt, 0).filter(|_| len == 1);
+
+                if let Some(arg) = arg {
+                    let text = arg.text.to_string();
+                    let text = &text[1..text.len() - 1];
+                    Ok(Attribute::DocInclude(resource_table::insert_str(text)))
+                } else {
+                    Err(AttributeError::MismatchArgs("single string"))
+                }
+            }
+            x if x == pat.testbench => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument"))
+                } else {
+                    Ok(Attribute::Testbench)
+                }
+            }
+            x if x == pat.period => {
+                let arg = get_arg_string(&value.attribute_opt, 0).filter(|_| len == 1);
+
+                if let Some(arg) = arg {
+                    let text = arg.text.to_string();
+                    let text = resource_table::insert_str(&text[1..text.len() - 1]);
+                    if period_ns(&text.to_string()).is_some() {
+                        Ok(Attribute::Period(text))
+                    } else {
+                        Err(AttributeError::InvalidPeriod(text))
+                    }
+                } else {
+                    Err(AttributeError::MismatchArgs("single string"))
+                }
+            }
+            x if x == pat.range => {
+                let min = get_arg_number(&value.attribute_opt, 0);
+                let max = get_arg_number(&value.attribute_opt, 1);
+
+                match (min, max, len) {
+                    (Some(min), Some(max), 2) if min <= max => Ok(Attribute::Range(min, max)),
+                    _ => Err(AttributeError::MismatchArgs(
+                        "minimum and maximum numbers as strings",
+                    )),
+                }
+            }
             _ => Err(AttributeError::UnknownAttribute),
         })
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum AllowItem {
-    MissingPort,
-    MissingResetStatement,
-    UnusedVariable,
+const ATTRIBUTE_NAMES: &[&str] = &[
+    "ifdef",
+    "ifndef",
+    "sv",
+    "allow",
+    "warn",
+    "deny",
+    "enum_encoding",
+    "enum_member_prefix",
+    "test",
+    "cond_type",
+    "pad",
+    "feature",
+    "init_file",
+    "dpi_import",
+    "dpi_export",
+    "no_preamble",
+    "export_header",
+    "private",
+    "doc_include",
+    "testbench",
+    "period",
+    "range",
+];
+
+/// Returns the clock period in nanoseconds from the text like `5ns` or `2.5 ns`
+pub fn period_ns(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let pos = text.find(|x: char| x.is_ascii_alphabetic())?;
+    let (value, unit) = text.split_at(pos);
+    let value: f64 = value.trim().parse().ok()?;
+    let scale = match unit {
+        "fs" => 1e-6,
+        "ps" => 1e-3,
+        "ns" => 1.0,
+        "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    if value.is_finite() && value > 0.0 {
+        Some(value * scale)
+    } else {
+        None
+    }
+}
+
+/// Edit distance which counts transposition of adjacent characters as one edit
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<_> = a.chars().collect();
+    let b: Vec<_> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, x) in d.iter_mut().enumerate() {
+        x[0] = i;
+    }
+    for (j, x) in d[0].iter_mut().enumerate() {
+        *x = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// Returns the known attribute name which is the most similar to `name`
+pub fn similar_attribute(name: &str) -> Option<&'static str> {
+    most_similar(name, ATTRIBUTE_NAMES).copied()
+}
+
+/// Returns the candidate which is the most similar to `name` within the edit distance threshold
+pub(crate) fn most_similar<'a, T: AsRef<str>>(name: &str, candidates: &'a [T]) -> Option<&'a T> {
+    let threshold = (name.len() / 3).max(1);
+    candidates
+        .iter()
+        .map(|x| (edit_distance(name, x.as_ref()), x))
+        .filter(|(d, _)| *d <= threshold)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, x)| x)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
 }
 
-impl fmt::Display for AllowItem {
+impl fmt::Display for LintLevel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let text = match self {
-            AllowItem::MissingPort => "missing_port",
-            AllowItem::MissingResetStatement => "missing_reset_statement",
-            AllowItem::UnusedVariable => "unused_variable",
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
         };
         text.fmt(f)
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EnumEncodingItem {
     #[default]
     Sequential,
@@ -267,7 +532,7 @@ impl fmt::Display for EnumEncodingItem {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CondTypeItem {
     Unique,
     Unique0,
@@ -1,4 +1,5 @@
-use crate::attribute::Attribute;
+use crate::analyzer_error::AnalyzerErrorCode;
+use crate::attribute::{Attribute, LintLevel};
 use crate::range_table::RangeTable;
 use std::cell::RefCell;
 use veryl_parser::veryl_token::{Token, TokenRange};
@@ -25,6 +26,24 @@ pub fn contains(token: &Token, value: Attribute) -> bool {
     ATTRIBUTE_TABLE.with(|f| f.borrow().contains(token, &value))
 }
 
+/// Returns the lint level of the code specified by the innermost attribute
+pub fn lint_level(token: &Token, code: AnalyzerErrorCode) -> Option<LintLevel> {
+    ATTRIBUTE_TABLE.with(|f| {
+        let attr = f
+            .borrow()
+            .get_innermost(token, |x| matches!(x, Attribute::Lint(_, x) if *x == code));
+        if let Some(Attribute::Lint(x, _)) = attr {
+            Some(x)
+        } else {
+            None
+        }
+    })
+}
+
+pub fn is_allowed(token: &Token, code: AnalyzerErrorCode) -> bool {
+    lint_level(token, code) == Some(LintLevel::Allow)
+}
+
 pub fn dump() -> String {
     ATTRIBUTE_TABLE.with(|f| format!("AttributeTable {}", f.borrow().dump()))
 }
@@ -0,0 +1,228 @@
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::{SymbolKind, Type, TypeKind, TypeModifier, UserDefinedType};
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Stringifier;
+
+/// Class of source and target types of `as` casting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastClass {
+    /// logic, bit, u32, u64 and user-defined types aliasing them
+    Unsigned,
+    /// i32, i64 and signed logic
+    Signed,
+    /// Width casting like `as 8`, which keeps signedness of the source
+    Width,
+    Enum,
+    /// struct or union
+    Struct,
+    Float,
+    Clock,
+    Reset,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CastType {
+    pub class: CastClass,
+    pub name: String,
+    pub width: Option<usize>,
+}
+
+/// How `as` casting is lowered to SystemVerilog
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastLowering {
+    /// `T'(expr)`
+    Cast,
+    /// `T'(expr)` with a comment because the upper bits are dropped
+    Truncate { from: usize, to: usize },
+    /// `$signed(T'(expr))`
+    Signed,
+    /// `$unsigned(T'(expr))`
+    Unsigned,
+    /// Casting which makes no sense like struct to integer of different width
+    Invalid,
+}
+
+/// Returns the lowering of casting `src` to `dst`.
+///
+/// | src \ dst | Unsigned        | Signed          | Width           | Enum            | Struct          |
+/// |-----------|-----------------|-----------------|-----------------|-----------------|-----------------|
+/// | Unsigned  | Cast / Truncate | Signed          | Cast / Truncate | Cast / Truncate | Cast / Invalid  |
+/// | Signed    | Unsigned        | Cast / Truncate | Cast / Truncate | Cast / Truncate | Cast / Invalid  |
+/// | Enum      | Cast / Truncate | Signed          | Cast / Truncate | Cast / Truncate | Cast / Truncate |
+/// | Struct    | Cast / Invalid  | Cast / Invalid  | Cast / Invalid  | Cast / Truncate | Cast / Truncate |
+///
+/// Truncate is selected if the source is wider than the target, and Invalid is selected if
+/// widths of struct and integral type differ. Casting from or to float, clock and reset is
+/// always Cast.
+pub fn lowering(src: &CastType, dst: &CastType) -> CastLowering {
+    use CastClass::*;
+
+    match (src.class, dst.class) {
+        (Float | Clock | Reset, _) | (_, Float | Clock | Reset) => CastLowering::Cast,
+        (Struct, Unsigned | Signed | Width) | (Unsigned | Signed, Struct) => {
+            match (src.width, dst.width) {
+                (Some(x), Some(y)) if x != y => CastLowering::Invalid,
+                _ => CastLowering::Cast,
+            }
+        }
+        (Signed, Unsigned) => CastLowering::Unsigned,
+        (Unsigned | Enum, Signed) => CastLowering::Signed,
+        _ => match (src.width, dst.width) {
+            (Some(from), Some(to)) if from > to => CastLowering::Truncate { from, to },
+            _ => CastLowering::Cast,
+        },
+    }
+}
+
+/// Returns the type of the casting source if it can be determined
+pub fn source_type(arg: &Expression12) -> Option<CastType> {
+    if !arg.expression12_list.is_empty() {
+        return None;
+    }
+    match arg.factor.as_ref() {
+        Factor::IdentifierFactor(x) => {
+            let x = &x.identifier_factor;
+            if x.identifier_factor_opt.is_some() {
+                return None;
+            }
+            let identifier = x.expression_identifier.as_ref();
+            if identifier.expression_identifier_opt.is_some()
+                || !identifier.expression_identifier_list.is_empty()
+                || identifier
+                    .expression_identifier_list0
+                    .iter()
+                    .any(|x| !x.expression_identifier_list0_list.is_empty())
+            {
+                return None;
+            }
+
+            let symbol = symbol_table::resolve(identifier).ok()?;
+            match symbol.found.kind {
+                SymbolKind::Variable(_)
+                | SymbolKind::Port(_)
+                | SymbolKind::Parameter(_)
+                | SymbolKind::StructMember(_)
+                | SymbolKind::UnionMember(_) => cast_type(symbol.found.kind.get_type()?),
+                _ => None,
+            }
+        }
+        // chained casting like `(a as T) as U`
+        Factor::LParenExpressionRParen(x) => {
+            let x = expression_operand(&x.expression)?;
+            target_type(&x.expression11_opt.as_ref()?.casting_type)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the type of the casting target if it can be determined
+pub fn target_type(arg: &CastingType) -> Option<CastType> {
+    let (class, width) = match arg {
+        CastingType::U32(_) => (CastClass::Unsigned, Some(32)),
+        CastingType::U64(_) => (CastClass::Unsigned, Some(64)),
+        CastingType::I32(_) => (CastClass::Signed, Some(32)),
+        CastingType::I64(_) => (CastClass::Signed, Some(64)),
+        CastingType::F32(_) | CastingType::F64(_) => (CastClass::Float, None),
+        CastingType::Bit(_) | CastingType::Logic(_) => (CastClass::Unsigned, Some(1)),
+        CastingType::BaseLess(x) => {
+            let text = x.base_less.base_less_token.to_string().replace('_', "");
+            (CastClass::Width, text.parse().ok())
+        }
+        CastingType::Based(_) => (CastClass::Width, None),
+        CastingType::UserDefinedType(x) => {
+            let symbol =
+                symbol_table::resolve(x.user_defined_type.scoped_identifier.as_ref()).ok()?;
+            return match symbol.found.kind {
+                // parameter value is used as width like `as WIDTH`
+                SymbolKind::Parameter(ref x) if x.r#type.kind != TypeKind::Type => {
+                    let width = match Evaluator::new().expression(&x.value) {
+                        Evaluated::Fixed { value, .. } => value.try_into().ok(),
+                        _ => None,
+                    };
+                    Some(CastType {
+                        class: CastClass::Width,
+                        name: symbol.found.token.to_string(),
+                        width,
+                    })
+                }
+                SymbolKind::Enum(_)
+                | SymbolKind::Struct(_)
+                | SymbolKind::Union(_)
+                | SymbolKind::TypeDef(_) => {
+                    let r#type = Type {
+                        modifier: vec![],
+                        kind: TypeKind::UserDefined(UserDefinedType {
+                            path: vec![symbol.found.token.text],
+                            symbol: Some(symbol.found.id),
+                        }),
+                        width: vec![],
+                        array: vec![],
+                        is_const: false,
+                    };
+                    let mut ret = cast_type(&r#type)?;
+                    ret.name = symbol.found.token.to_string();
+                    Some(ret)
+                }
+                _ => None,
+            };
+        }
+        CastingType::Clock(_) | CastingType::ClockPosedge(_) | CastingType::ClockNegedge(_) => {
+            (CastClass::Clock, None)
+        }
+        CastingType::Reset(_)
+        | CastingType::ResetAsyncHigh(_)
+        | CastingType::ResetAsyncLow(_)
+        | CastingType::ResetSyncHigh(_)
+        | CastingType::ResetSyncLow(_) => (CastClass::Reset, None),
+    };
+
+    let mut stringifier = Stringifier::new();
+    stringifier.casting_type(arg);
+    Some(CastType {
+        class,
+        name: stringifier.as_str().to_string(),
+        width,
+    })
+}
+
+fn cast_type(arg: &Type) -> Option<CastType> {
+    if !arg.array.is_empty() {
+        return None;
+    }
+
+    let class = match &arg.kind {
+        TypeKind::Bit | TypeKind::Logic | TypeKind::U32 | TypeKind::U64 => {
+            if arg.modifier.contains(&TypeModifier::Signed) {
+                CastClass::Signed
+            } else {
+                CastClass::Unsigned
+            }
+        }
+        TypeKind::I32 | TypeKind::I64 => CastClass::Signed,
+        TypeKind::F32 | TypeKind::F64 => CastClass::Float,
+        TypeKind::UserDefined(x) => {
+            let symbol = symbol_table::get(x.symbol?)?;
+            match symbol.kind {
+                SymbolKind::Enum(_) => CastClass::Enum,
+                SymbolKind::Struct(_) | SymbolKind::Union(_) => CastClass::Struct,
+                SymbolKind::TypeDef(ref x) if arg.width.is_empty() => {
+                    let mut ret = cast_type(&x.r#type)?;
+                    ret.name = arg.to_string();
+                    return Some(ret);
+                }
+                _ => return None,
+            }
+        }
+        x if x.is_clock() => CastClass::Clock,
+        x if x.is_reset() => CastClass::Reset,
+        _ => return None,
+    };
+
+    Some(CastType {
+        class,
+        name: arg.to_string(),
+        width: Evaluator::new().total_width(arg),
+    })
+}
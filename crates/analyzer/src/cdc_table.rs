@@ -0,0 +1,72 @@
+use crate::symbol::{ClockDomain, SymbolId};
+use std::cell::RefCell;
+use veryl_parser::resource_table::PathId;
+use veryl_parser::veryl_token::{TokenRange, TokenSource};
+
+/// Clock domain crossing allowed by `unsafe (cdc)`
+#[derive(Clone, Debug)]
+pub struct Crossing {
+    /// Module including the crossing
+    pub module: SymbolId,
+    pub from: ClockDomain,
+    pub to: ClockDomain,
+    pub range: TokenRange,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CdcTable {
+    table: Vec<Crossing>,
+}
+
+impl CdcTable {
+    pub fn insert(&mut self, crossing: Crossing) {
+        self.table.push(crossing);
+    }
+
+    pub fn get_all(&self) -> Vec<Crossing> {
+        self.table.clone()
+    }
+
+    pub fn drop(&mut self, file_path: PathId) {
+        self.table
+            .retain(|x| x.range.beg.source != TokenSource::File(file_path));
+    }
+
+    pub fn dump(&self) -> String {
+        let mut ret = "[\n".to_string();
+        for x in &self.table {
+            ret.push_str(&format!(
+                "    {}:{}: {} -> {},\n",
+                x.range.beg.line, x.range.beg.column, x.from, x.to
+            ));
+        }
+        ret.push(']');
+        ret
+    }
+
+    pub fn clear(&mut self) {
+        self.table.clear()
+    }
+}
+
+thread_local!(static CDC_TABLE: RefCell<CdcTable> = RefCell::new(CdcTable::default()));
+
+pub fn insert(crossing: Crossing) {
+    CDC_TABLE.with(|f| f.borrow_mut().insert(crossing))
+}
+
+pub fn get_all() -> Vec<Crossing> {
+    CDC_TABLE.with(|f| f.borrow().get_all())
+}
+
+pub fn drop(file_path: PathId) {
+    CDC_TABLE.with(|f| f.borrow_mut().drop(file_path))
+}
+
+pub fn dump() -> String {
+    CDC_TABLE.with(|f| format!("CdcTable {}", f.borrow().dump()))
+}
+
+pub fn clear() {
+    CDC_TABLE.with(|f| f.borrow_mut().clear())
+}
@@ -1,4 +1,4 @@
-use crate::symbol::{Type, TypeKind};
+use crate::symbol::{SymbolId, SymbolKind, Type, TypeKind};
 use crate::symbol_table::{self, ResolveError, ResolveResult};
 use veryl_parser::veryl_grammar_trait::*;
 
@@ -401,6 +401,61 @@ impl Evaluator {
         }
     }
 
+    /// Returns total bit width of the packed type including user-defined types.
+    /// Returns `None` if the width can't be determined statically.
+    pub fn total_width(&mut self, x: &Type) -> Option<usize> {
+        if !x.array.is_empty() {
+            return None;
+        }
+
+        let mut ret = match x.kind {
+            TypeKind::U32 | TypeKind::I32 | TypeKind::F32 => 32,
+            TypeKind::U64 | TypeKind::I64 | TypeKind::F64 => 64,
+            TypeKind::Bit | TypeKind::Logic => 1,
+            TypeKind::UserDefined(ref x) => {
+                let symbol = symbol_table::get(x.symbol?)?;
+                match symbol.kind {
+                    SymbolKind::Struct(ref x) => {
+                        let mut width = 0;
+                        for member in &x.members {
+                            width += self.member_width(*member)?;
+                        }
+                        width
+                    }
+                    SymbolKind::Union(ref x) => {
+                        let mut width = 0;
+                        for member in &x.members {
+                            width = width.max(self.member_width(*member)?);
+                        }
+                        width
+                    }
+                    SymbolKind::Enum(ref x) if x.width != 0 => x.width,
+                    SymbolKind::TypeDef(ref x) => self.total_width(&x.r#type)?,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        for width in &x.width {
+            if let Evaluated::Fixed { value, .. } = self.expression(width) {
+                ret *= usize::try_from(value).ok()?;
+            } else {
+                return None;
+            }
+        }
+
+        Some(ret)
+    }
+
+    fn member_width(&mut self, id: SymbolId) -> Option<usize> {
+        match symbol_table::get(id)?.kind {
+            SymbolKind::StructMember(x) => self.total_width(&x.r#type),
+            SymbolKind::UnionMember(x) => self.total_width(&x.r#type),
+            _ => None,
+        }
+    }
+
     fn exponent(&mut self, _arg: &Exponent) -> Evaluated {
         Evaluated::Unknown
     }
@@ -622,6 +677,10 @@ impl Evaluator {
                 CastingType::ResetAsyncLow(_) => Evaluated::ResetAsyncLow,
                 CastingType::ResetSyncHigh(_) => Evaluated::ResetSyncHigh,
                 CastingType::ResetSyncLow(_) => Evaluated::ResetSyncLow,
+                // casting clock or reset to bit/logic makes it data
+                CastingType::Bit(_) | CastingType::Logic(_) if ret.is_clock() || ret.is_reset() => {
+                    Evaluated::Variable { width: 1 }
+                }
                 _ => ret,
             }
         } else {
@@ -703,40 +762,66 @@ impl Evaluator {
         }
     }
 
-    fn do_concatenation(&mut self, exp: Evaluated, rep: Evaluated) -> Evaluated {
-        match exp {
-            Evaluated::Fixed {
-                width: ewidth,
-                value: eval,
-            } => match rep {
+    fn do_concatenation(&mut self, upper: Evaluated, lower: Evaluated) -> Evaluated {
+        match (upper, lower) {
+            (
                 Evaluated::Fixed {
-                    width: rwidth,
-                    value: rval,
-                } => {
-                    let width = ewidth * rwidth;
-                    let mut value = eval;
-                    for _ in 0..rval {
-                        value <<= ewidth;
-                        value |= eval;
-                    }
-                    Evaluated::Fixed { width, value }
-                }
-                Evaluated::Variable { width: rwidth } => Evaluated::Variable {
-                    width: ewidth * rwidth,
+                    width: uwidth,
+                    value: uval,
                 },
-                Evaluated::UnknownStatic => Evaluated::UnknownStatic,
-                _ => Evaluated::Unknown,
-            },
-            Evaluated::Variable { width: ewidth } => match rep {
-                Evaluated::Fixed { width: rwidth, .. } | Evaluated::Variable { width: rwidth } => {
-                    Evaluated::Variable {
-                        width: ewidth * rwidth,
-                    }
+                Evaluated::Fixed {
+                    width: lwidth,
+                    value: lval,
+                },
+            ) => {
+                let width = uwidth + lwidth;
+                if width >= isize::BITS as usize - 1 {
+                    return Evaluated::Variable { width };
+                }
+                let mask = (1 << lwidth) - 1;
+                Evaluated::Fixed {
+                    width,
+                    value: (uval << lwidth) | (lval & mask),
                 }
-                Evaluated::UnknownStatic => Evaluated::UnknownStatic,
-                _ => Evaluated::Unknown,
+            }
+            (
+                Evaluated::Fixed { width: uwidth, .. } | Evaluated::Variable { width: uwidth },
+                Evaluated::Fixed { width: lwidth, .. } | Evaluated::Variable { width: lwidth },
+            ) => Evaluated::Variable {
+                width: uwidth + lwidth,
             },
-            Evaluated::UnknownStatic => Evaluated::UnknownStatic,
+            (Evaluated::UnknownStatic, _)
+            | (Evaluated::Fixed { .. } | Evaluated::Variable { .. }, Evaluated::UnknownStatic) => {
+                Evaluated::UnknownStatic
+            }
+            _ => Evaluated::Unknown,
+        }
+    }
+
+    fn do_repetition(&mut self, exp: Evaluated, rep: Evaluated) -> Evaluated {
+        match (exp, rep) {
+            // repetition count should be a constant positive integer
+            (_, Evaluated::Fixed { value: count, .. }) if count < 0 => Evaluated::Unknown,
+            (Evaluated::Fixed { width, value }, Evaluated::Fixed { value: count, .. }) => {
+                let total = width * count as usize;
+                if total >= isize::BITS as usize - 1 {
+                    return Evaluated::Variable { width: total };
+                }
+                let mut ret = Evaluated::Fixed { width: 0, value: 0 };
+                for _ in 0..count {
+                    ret = self.do_concatenation(ret, Evaluated::Fixed { width, value });
+                }
+                ret
+            }
+            (Evaluated::Variable { width }, Evaluated::Fixed { value: count, .. }) => {
+                Evaluated::Variable {
+                    width: width * count as usize,
+                }
+            }
+            (Evaluated::UnknownStatic, _)
+            | (Evaluated::Fixed { .. } | Evaluated::Variable { .. }, Evaluated::UnknownStatic) => {
+                Evaluated::UnknownStatic
+            }
             _ => Evaluated::Unknown,
         }
     }
@@ -745,7 +830,7 @@ impl Evaluator {
         let e = self.expression(arg.expression.as_ref());
         if let Some(cio) = &arg.concatenation_item_opt {
             let c = self.expression(cio.expression.as_ref());
-            self.do_concatenation(e, c)
+            self.do_repetition(e, c)
         } else {
             e
         }
@@ -756,16 +841,12 @@ impl Evaluator {
     }
 
     fn concatenation_list(&mut self, arg: &ConcatenationList) -> Evaluated {
-        let mut eval_vec = vec![];
-        eval_vec.push(self.concatenation_item(arg.concatenation_item.as_ref()));
+        let mut ret = self.concatenation_item(arg.concatenation_item.as_ref());
         for cll in arg.concatenation_list_list.iter() {
-            eval_vec.push(self.concatenation_list_list(cll));
+            let x = self.concatenation_list_list(cll);
+            ret = self.do_concatenation(ret, x);
         }
-        eval_vec.reverse();
-        let default_value = Evaluated::Fixed { width: 1, value: 0 };
-        eval_vec
-            .iter()
-            .fold(default_value, |acc, x| self.do_concatenation(acc, *x))
+        ret
     }
 
     fn array_literal_item_group_default_colon_expression(
@@ -786,7 +867,7 @@ impl Evaluator {
                 let exp_eval = self.expression(x.expression.as_ref());
                 if let Some(alio) = &x.array_literal_item_opt {
                     let repeat_exp = self.expression(alio.expression.as_ref());
-                    self.do_concatenation(exp_eval, repeat_exp)
+                    self.do_repetition(exp_eval, repeat_exp)
                 } else {
                     exp_eval
                 }
@@ -1,41 +1,73 @@
+pub mod check_assertion;
+pub mod check_assignment_style;
 pub mod check_attribute;
 pub mod check_clock_domain;
 pub mod check_clock_reset;
+pub mod check_comb_depth;
+pub mod check_concatenation;
+pub mod check_declaration_order;
 pub mod check_embed_include;
 pub mod check_enum;
 pub mod check_expression;
+pub mod check_for_loop;
 pub mod check_function;
+pub mod check_hierarchical_reference;
 pub mod check_identifier;
+pub mod check_inout;
+pub mod check_magic_number;
 pub mod check_modport;
 pub mod check_msb_lsb;
 pub mod check_number;
 pub mod check_port;
+pub mod check_prefer_let;
 pub mod check_proto;
+pub mod check_reset;
 pub mod check_separator;
 pub mod check_statement;
+pub mod check_struct_port;
+pub mod check_synthesis;
 pub mod check_type;
+pub mod check_type_compatibility;
 pub mod check_unsafe;
 pub mod check_var_ref;
+pub mod check_width_expression;
+pub mod check_width_value;
 pub mod create_reference;
 pub mod create_symbol_table;
+use check_assertion::*;
+use check_assignment_style::*;
 use check_attribute::*;
 use check_clock_domain::*;
 use check_clock_reset::*;
+use check_comb_depth::*;
+use check_concatenation::*;
+use check_declaration_order::*;
 use check_embed_include::*;
 use check_enum::*;
 use check_expression::*;
+use check_for_loop::*;
 use check_function::*;
+use check_hierarchical_reference::*;
 use check_identifier::*;
+use check_inout::*;
+use check_magic_number::*;
 use check_modport::*;
 use check_msb_lsb::*;
 use check_number::*;
 use check_port::*;
+use check_prefer_let::*;
 use check_proto::*;
+use check_reset::*;
 use check_separator::*;
 use check_statement::*;
+use check_struct_port::*;
+use check_synthesis::*;
 use check_type::*;
+use check_type_compatibility::*;
 use check_unsafe::*;
 use check_var_ref::*;
+use check_width_expression::*;
+use check_width_value::*;
 use create_reference::*;
 use create_symbol_table::*;
 
@@ -96,69 +128,133 @@ impl<'a> Pass1Handlers<'a> {
 }
 
 pub struct Pass2Handlers<'a> {
+    check_assertion: CheckAssertion<'a>,
+    check_assignment_style: CheckAssignmentStyle<'a>,
     check_separator: CheckSeparator<'a>,
     check_enum: CheckEnum<'a>,
     check_modport: CheckModport<'a>,
     check_function: CheckFunction<'a>,
+    check_inout: CheckInout<'a>,
+    check_struct_port: CheckStructPort<'a>,
     check_msb_lsb: CheckMsbLsb<'a>,
     check_var_ref: CheckVarRef<'a>,
+    check_prefer_let: CheckPreferLet<'a>,
+    check_magic_number: CheckMagicNumber<'a>,
+    check_hierarchical_reference: CheckHierarchicalReference<'a>,
     check_clock_reset: CheckClockReset<'a>,
+    check_comb_depth: CheckCombDepth<'a>,
+    check_concatenation: CheckConcatenation<'a>,
+    check_declaration_order: CheckDeclarationOrder<'a>,
+    check_reset: CheckReset<'a>,
+    check_synthesis: CheckSynthesis<'a>,
     create_reference: CreateReference<'a>,
     check_expression: CheckExpression<'a>,
+    check_for_loop: CheckForLoop<'a>,
     check_clock_domain: CheckClockDomain<'a>,
     check_proto: CheckProto<'a>,
     check_type: CheckType<'a>,
+    check_type_compatibility: CheckTypeCompatibility<'a>,
+    check_width_expression: CheckWidthExpression<'a>,
+    check_width_value: CheckWidthValue<'a>,
 }
 
 impl<'a> Pass2Handlers<'a> {
-    pub fn new(text: &'a str, _build_opt: &'a Build, _lint_opt: &'a Lint) -> Self {
+    pub fn new(text: &'a str, build_opt: &'a Build, lint_opt: &'a Lint) -> Self {
         Self {
+            check_assertion: CheckAssertion::new(text),
+            check_assignment_style: CheckAssignmentStyle::new(text),
             check_separator: CheckSeparator::new(text),
             check_enum: CheckEnum::new(text),
             check_modport: CheckModport::new(text),
             check_function: CheckFunction::new(text),
+            check_inout: CheckInout::new(text, build_opt),
+            check_struct_port: CheckStructPort::new(text, build_opt),
             check_msb_lsb: CheckMsbLsb::new(text),
             check_var_ref: CheckVarRef::new(text),
+            check_prefer_let: CheckPreferLet::new(text, lint_opt),
+            check_magic_number: CheckMagicNumber::new(text, lint_opt),
+            check_hierarchical_reference: CheckHierarchicalReference::new(text),
             check_clock_reset: CheckClockReset::new(text),
+            check_comb_depth: CheckCombDepth::new(text, lint_opt),
+            check_concatenation: CheckConcatenation::new(text),
+            check_declaration_order: CheckDeclarationOrder::new(text, build_opt),
+            check_reset: CheckReset::new(text, lint_opt),
+            check_synthesis: CheckSynthesis::new(text, lint_opt),
             create_reference: CreateReference::new(text),
             check_expression: CheckExpression::new(text),
+            check_for_loop: CheckForLoop::new(text, lint_opt),
             check_clock_domain: CheckClockDomain::new(text),
             check_proto: CheckProto::new(text),
             check_type: CheckType::new(text),
+            check_type_compatibility: CheckTypeCompatibility::new(text),
+            check_width_expression: CheckWidthExpression::new(text),
+            check_width_value: CheckWidthValue::new(text, lint_opt),
         }
     }
 
     pub fn get_handlers(&mut self) -> Vec<&mut dyn Handler> {
         vec![
+            &mut self.check_assertion as &mut dyn Handler,
+            &mut self.check_assignment_style as &mut dyn Handler,
             &mut self.check_separator as &mut dyn Handler,
             &mut self.check_enum as &mut dyn Handler,
             &mut self.check_modport as &mut dyn Handler,
             &mut self.check_function as &mut dyn Handler,
+            &mut self.check_inout as &mut dyn Handler,
+            &mut self.check_struct_port as &mut dyn Handler,
             &mut self.check_msb_lsb as &mut dyn Handler,
             &mut self.check_var_ref as &mut dyn Handler,
+            &mut self.check_prefer_let as &mut dyn Handler,
+            &mut self.check_magic_number as &mut dyn Handler,
+            &mut self.check_hierarchical_reference as &mut dyn Handler,
             &mut self.check_clock_reset as &mut dyn Handler,
+            &mut self.check_comb_depth as &mut dyn Handler,
+            &mut self.check_concatenation as &mut dyn Handler,
+            &mut self.check_declaration_order as &mut dyn Handler,
+            &mut self.check_reset as &mut dyn Handler,
+            &mut self.check_synthesis as &mut dyn Handler,
             &mut self.create_reference as &mut dyn Handler,
             &mut self.check_expression as &mut dyn Handler,
+            &mut self.check_for_loop as &mut dyn Handler,
             &mut self.check_clock_domain as &mut dyn Handler,
             &mut self.check_proto as &mut dyn Handler,
             &mut self.check_type as &mut dyn Handler,
+            &mut self.check_type_compatibility as &mut dyn Handler,
+            &mut self.check_width_expression as &mut dyn Handler,
+            &mut self.check_width_value as &mut dyn Handler,
         ]
     }
 
     pub fn get_errors(&mut self) -> Vec<AnalyzerError> {
         let mut ret = Vec::new();
+        ret.append(&mut self.check_assertion.errors);
+        ret.append(&mut self.check_assignment_style.errors);
         ret.append(&mut self.check_separator.errors);
         ret.append(&mut self.check_enum.errors);
         ret.append(&mut self.check_modport.errors);
         ret.append(&mut self.check_function.errors);
+        ret.append(&mut self.check_inout.errors);
+        ret.append(&mut self.check_struct_port.errors);
         ret.append(&mut self.check_msb_lsb.errors);
         ret.append(&mut self.check_var_ref.errors);
+        ret.append(&mut self.check_prefer_let.errors);
+        ret.append(&mut self.check_magic_number.errors);
+        ret.append(&mut self.check_hierarchical_reference.errors);
         ret.append(&mut self.check_clock_reset.errors);
+        ret.append(&mut self.check_comb_depth.errors);
+        ret.append(&mut self.check_concatenation.errors);
+        ret.append(&mut self.check_declaration_order.errors);
+        ret.append(&mut self.check_reset.errors);
+        ret.append(&mut self.check_synthesis.errors);
         ret.append(&mut self.create_reference.errors);
         ret.append(&mut self.check_expression.errors);
+        ret.append(&mut self.check_for_loop.errors);
         ret.append(&mut self.check_clock_domain.errors);
         ret.append(&mut self.check_proto.errors);
         ret.append(&mut self.check_type.errors);
+        ret.append(&mut self.check_type_compatibility.errors);
+        ret.append(&mut self.check_width_expression.errors);
+        ret.append(&mut self.check_width_value.errors);
         ret
     }
 }
@@ -0,0 +1,105 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::SymbolKind;
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+pub struct CheckAssertion<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    default_clock_exists: bool,
+    has_select: bool,
+    evaluator: Evaluator,
+}
+
+impl<'a> CheckAssertion<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn check_expression(&mut self, arg: &Expression) {
+        // width of selected or member-accessed identifiers can't be evaluated exactly
+        if self.has_select {
+            return;
+        }
+
+        if let Evaluated::Variable { width } = self.evaluator.expression(arg) {
+            if width > 1 {
+                self.errors
+                    .push(AnalyzerError::invalid_assertion_expression(
+                        width,
+                        self.text,
+                        &arg.into(),
+                    ));
+            }
+        }
+    }
+}
+
+impl Handler for CheckAssertion<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckAssertion<'_> {
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                if let Ok(found) = symbol_table::resolve(arg.identifier.as_ref()) {
+                    if let SymbolKind::Module(x) = found.found.kind {
+                        self.default_clock_exists = x.default_clock.is_some();
+                    }
+                }
+            }
+            HandlerPoint::After => self.default_clock_exists = false,
+        }
+        Ok(())
+    }
+
+    fn select(&mut self, _arg: &Select) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.has_select = true;
+        }
+        Ok(())
+    }
+
+    fn dot(&mut self, _arg: &Dot) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.has_select = true;
+        }
+        Ok(())
+    }
+
+    fn assertion_statement(&mut self, arg: &AssertionStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.has_select = false,
+            HandlerPoint::After => self.check_expression(&arg.expression),
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                // concurrent assertion is clocked by the default clock of the enclosing module
+                if !self.default_clock_exists {
+                    self.errors.push(AnalyzerError::missing_assertion_clock(
+                        self.text,
+                        &arg.property.property_token.token.into(),
+                    ));
+                }
+                self.has_select = false;
+            }
+            HandlerPoint::After => self.check_expression(&arg.expression),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,380 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::namespace::Namespace;
+use crate::symbol::{Symbol, SymbolId, SymbolKind, VariableAffiliation};
+use crate::symbol_table;
+use std::collections::{HashMap, HashSet};
+use veryl_parser::resource_table::TokenId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BlockKind {
+    AlwaysFf,
+    AlwaysComb,
+}
+
+impl BlockKind {
+    fn name(&self) -> &'static str {
+        match self {
+            BlockKind::AlwaysFf => "always_ff",
+            BlockKind::AlwaysComb => "always_comb",
+        }
+    }
+}
+
+struct FunctionAssign {
+    identifier: String,
+    token: Token,
+    compound: bool,
+}
+
+struct FunctionCall {
+    function: SymbolId,
+    block: BlockKind,
+}
+
+/// Assigned paths tracked through the arms of a branch statement
+struct Branch {
+    /// Opening braces of statement blocks which are arms of the branch
+    arms: Vec<TokenId>,
+    /// Whether one of the arms is always taken
+    exhaustive: bool,
+    /// Assigned paths before the branch
+    entry: HashSet<Vec<SymbolId>>,
+    /// Assigned paths which are assigned in every arm visited so far
+    joined: Option<HashSet<Vec<SymbolId>>>,
+}
+
+#[derive(Default)]
+pub struct CheckAssignmentStyle<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    block: Option<BlockKind>,
+    function: Option<(SymbolId, Namespace)>,
+    assigned: HashSet<Vec<SymbolId>>,
+    branches: Vec<Branch>,
+    function_assigns: HashMap<SymbolId, Vec<FunctionAssign>>,
+    function_calls: Vec<FunctionCall>,
+}
+
+impl<'a> CheckAssignmentStyle<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn add_function_call(&mut self, arg: &ExpressionIdentifier) {
+        if let Some(block) = self.block {
+            if let Ok(symbol) = symbol_table::resolve(arg) {
+                let function = match symbol.found.kind {
+                    SymbolKind::Function(_) => symbol.found.id,
+                    SymbolKind::ModportFunctionMember(x) => x.function,
+                    _ => return,
+                };
+                self.function_calls.push(FunctionCall { function, block });
+            }
+        }
+    }
+
+    fn is_assigned(&self, path: &[SymbolId]) -> bool {
+        // Assignment to a whole variable also assigns its members
+        (1..=path.len()).any(|i| self.assigned.contains(&path[..i]))
+    }
+
+    fn enter_branch(&mut self, arms: Vec<TokenId>, exhaustive: bool) {
+        self.branches.push(Branch {
+            arms,
+            exhaustive,
+            entry: self.assigned.clone(),
+            joined: None,
+        });
+    }
+
+    fn leave_branch(&mut self) {
+        if let Some(branch) = self.branches.pop() {
+            // Paths are assigned after the branch only if every arm assigns them
+            self.assigned = match branch.joined {
+                Some(joined) if branch.exhaustive => joined,
+                _ => branch.entry,
+            };
+        }
+    }
+
+    fn enter_arm(&mut self) {
+        if let Some(branch) = self.branches.last() {
+            self.assigned = branch.entry.clone();
+        }
+    }
+
+    fn leave_arm(&mut self) {
+        if let Some(branch) = self.branches.last_mut() {
+            let joined = match branch.joined.take() {
+                Some(x) => x.intersection(&self.assigned).cloned().collect(),
+                None => self.assigned.clone(),
+            };
+            branch.joined = Some(joined);
+        }
+    }
+
+    fn is_arm(&self, arg: &StatementBlock) -> bool {
+        self.branches
+            .last()
+            .map(|x| x.arms.contains(&arg.l_brace.l_brace_token.token.id))
+            .unwrap_or(false)
+    }
+
+    fn check_function_calls(&mut self) {
+        for call in &self.function_calls {
+            let assigns = if let Some(x) = self.function_assigns.get(&call.function) {
+                x
+            } else {
+                continue;
+            };
+            let function = symbol_table::get(call.function).unwrap();
+            for assign in assigns {
+                // Assignments in function body are always emitted as blocking assignment.
+                // It is a register update with blocking style in always_ff, and
+                // compound assignment accumulates the previous value in always_comb.
+                if call.block == BlockKind::AlwaysFf || assign.compound {
+                    self.errors
+                        .push(AnalyzerError::mismatch_assignment_style_in_function(
+                            &assign.identifier,
+                            &function.token.to_string(),
+                            call.block.name(),
+                            self.text,
+                            &assign.token.into(),
+                        ));
+                }
+            }
+        }
+        self.function_calls.clear();
+        self.function_assigns.clear();
+    }
+}
+
+impl Handler for CheckAssignmentStyle<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+/// Returns whether the assigned symbol is emitted as blocking assignment in always_ff
+fn is_blocking_in_always_ff(kind: &SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::Variable(x)
+            if matches!(
+                x.affiliation,
+                VariableAffiliation::StatementBlock | VariableAffiliation::Function
+            )
+    )
+}
+
+/// Returns whether the assigned symbol is declared outside of function
+fn is_outside_of_function(symbol: &Symbol, function: &Namespace) -> bool {
+    match symbol.kind {
+        SymbolKind::Variable(_) | SymbolKind::Port(_) => !symbol.namespace.included(function),
+        SymbolKind::ModportVariableMember(_) => true,
+        _ => false,
+    }
+}
+
+impl VerylGrammarTrait for CheckAssignmentStyle<'_> {
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let x = match &*arg.identifier_statement_group {
+                IdentifierStatementGroup::Assignment(x) => x,
+                IdentifierStatementGroup::FunctionCall(_) => {
+                    self.add_function_call(&arg.expression_identifier);
+                    return Ok(());
+                }
+            };
+
+            let symbol = if let Ok(x) = symbol_table::resolve(arg.expression_identifier.as_ref()) {
+                x
+            } else {
+                return Ok(());
+            };
+            let identifier = arg.expression_identifier.identifier().token.to_string();
+            let (token, compound) = match &*x.assignment.assignment_group {
+                AssignmentGroup::Equ(x) => (x.equ.equ_token.token, false),
+                AssignmentGroup::AssignmentOperator(x) => {
+                    (x.assignment_operator.assignment_operator_token.token, true)
+                }
+            };
+
+            if let Some((function, namespace)) = &self.function {
+                if is_outside_of_function(&symbol.found, namespace) {
+                    self.function_assigns
+                        .entry(*function)
+                        .or_default()
+                        .push(FunctionAssign {
+                            identifier,
+                            token,
+                            compound,
+                        });
+                }
+            } else if let Some(block) = self.block {
+                // Compound assignment emitted as blocking assignment reads the value
+                // before the block is evaluated if the target is not assigned yet.
+                let blocking = match block {
+                    BlockKind::AlwaysComb => true,
+                    BlockKind::AlwaysFf => is_blocking_in_always_ff(&symbol.found.kind),
+                };
+                if blocking && compound && !self.is_assigned(&symbol.full_path) {
+                    self.errors.push(AnalyzerError::mismatch_assignment_style(
+                        &identifier,
+                        &token.to_string(),
+                        block.name(),
+                        self.text,
+                        &token.into(),
+                    ));
+                }
+                self.assigned.insert(symbol.full_path);
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.function_call().is_some() {
+                self.add_function_call(&arg.expression_identifier);
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, _arg: &AlwaysFfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.block = Some(BlockKind::AlwaysFf);
+                self.assigned.clear();
+            }
+            HandlerPoint::After => self.block = None,
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.block = Some(BlockKind::AlwaysComb);
+                self.assigned.clear();
+            }
+            HandlerPoint::After => self.block = None,
+        }
+        Ok(())
+    }
+
+    fn statement_block(&mut self, arg: &StatementBlock) -> Result<(), ParolError> {
+        if self.is_arm(arg) {
+            match self.point {
+                HandlerPoint::Before => self.enter_arm(),
+                HandlerPoint::After => self.leave_arm(),
+            }
+        }
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let mut arms = vec![arg.statement_block.l_brace.l_brace_token.token.id];
+                for x in &arg.if_statement_list {
+                    arms.push(x.statement_block.l_brace.l_brace_token.token.id);
+                }
+                if let Some(x) = &arg.if_statement_opt {
+                    arms.push(x.statement_block.l_brace.l_brace_token.token.id);
+                }
+                self.enter_branch(arms, arg.if_statement_opt.is_some());
+            }
+            HandlerPoint::After => self.leave_branch(),
+        }
+        Ok(())
+    }
+
+    fn if_reset_statement(&mut self, arg: &IfResetStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let mut arms = vec![arg.statement_block.l_brace.l_brace_token.token.id];
+                for x in &arg.if_reset_statement_list {
+                    arms.push(x.statement_block.l_brace.l_brace_token.token.id);
+                }
+                if let Some(x) = &arg.if_reset_statement_opt {
+                    arms.push(x.statement_block.l_brace.l_brace_token.token.id);
+                }
+                self.enter_branch(arms, arg.if_reset_statement_opt.is_some());
+            }
+            HandlerPoint::After => self.leave_branch(),
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let exhaustive = arg
+                    .case_statement_list
+                    .iter()
+                    .any(|x| matches!(*x.case_item.case_item_group, CaseItemGroup::Defaul(_)));
+                self.enter_branch(vec![], exhaustive);
+            }
+            HandlerPoint::After => self.leave_branch(),
+        }
+        Ok(())
+    }
+
+    fn case_item(&mut self, _arg: &CaseItem) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.enter_arm(),
+            HandlerPoint::After => self.leave_arm(),
+        }
+        Ok(())
+    }
+
+    fn switch_statement(&mut self, arg: &SwitchStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let exhaustive = arg.switch_statement_list.iter().any(|x| {
+                    matches!(*x.switch_item.switch_item_group, SwitchItemGroup::Defaul(_))
+                });
+                self.enter_branch(vec![], exhaustive);
+            }
+            HandlerPoint::After => self.leave_branch(),
+        }
+        Ok(())
+    }
+
+    fn switch_item(&mut self, _arg: &SwitchItem) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.enter_arm(),
+            HandlerPoint::After => self.leave_arm(),
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+                    self.function = Some((symbol.found.id, symbol.found.inner_namespace()));
+                }
+            }
+            HandlerPoint::After => self.function = None,
+        }
+        Ok(())
+    }
+
+    fn veryl(&mut self, _arg: &Veryl) -> Result<(), ParolError> {
+        if let HandlerPoint::After = self.point {
+            self.check_function_calls();
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,103 @@
 use crate::analyzer_error::AnalyzerError;
-use crate::attribute::AttributeError;
+use crate::attribute::{self, Attribute as Attr, AttributeError};
 use crate::attribute_table;
+use std::collections::HashSet;
 use veryl_parser::last_token::LastToken;
+use veryl_parser::resource_table::{self, TokenId};
 use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenSource;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 use veryl_parser::ParolError;
 
+/// Kind of items which attributes are applied to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    Module,
+    Embed,
+    Description,
+    Package,
+    Enum,
+    Declaration,
+    Statement,
+    Member,
+    Port,
+    Parameter,
+}
+
+impl Target {
+    fn from_generate_item(x: &GenerateItem) -> Self {
+        match x {
+            GenerateItem::EnumDeclaration(_) => Target::Enum,
+            _ => Target::Declaration,
+        }
+    }
+}
+
+/// Returns description of valid targets if `attr` can't be applied to `target`.
+/// init_file, dpi and private attributes are checked separately.
+fn invalid_target(attr: &Attr, target: Target) -> Option<&'static str> {
+    match attr {
+        Attr::EnumEncoding(_) | Attr::EnumMemberPrefix(_) if target != Target::Enum => {
+            Some("an enum declaration")
+        }
+        Attr::Test(..) if !matches!(target, Target::Module | Target::Embed) => {
+            Some("a module, embed or include declaration")
+        }
+        Attr::Pad | Attr::Testbench if target != Target::Module => Some("a module declaration"),
+        Attr::Feature(_) | Attr::NoPreamble
+            if !matches!(
+                target,
+                Target::Module | Target::Embed | Target::Description | Target::Package
+            ) =>
+        {
+            Some("a top-level declaration")
+        }
+        Attr::ExportHeader if target != Target::Package => Some("a package declaration"),
+        Attr::DocInclude(_)
+            if !matches!(
+                target,
+                Target::Module | Target::Description | Target::Package
+            ) =>
+        {
+            Some("a top-level declaration except embed and include")
+        }
+        Attr::CondType(_) if !matches!(target, Target::Statement | Target::Declaration) => {
+            Some("a statement or a declaration including statements")
+        }
+        Attr::Period(_) if target != Target::Port => Some("a port declaration"),
+        Attr::Range(..) if target != Target::Parameter => Some("a parameter declaration"),
+        _ => None,
+    }
+}
+
+/// Returns whether `x` and `y` can't be applied to the same item together
+fn is_conflicting(x: &Attr, y: &Attr) -> bool {
+    match (x, y) {
+        (Attr::Ifdef(x), Attr::Ifndef(y)) | (Attr::Ifndef(x), Attr::Ifdef(y)) => x == y,
+        (Attr::DpiImport, Attr::DpiExport) | (Attr::DpiExport, Attr::DpiImport) => true,
+        (Attr::Lint(_, x), Attr::Lint(_, y)) => x == y,
+        (Attr::EnumEncoding(_), Attr::EnumEncoding(_))
+        | (Attr::EnumMemberPrefix(_), Attr::EnumMemberPrefix(_))
+        | (Attr::CondType(_), Attr::CondType(_))
+        | (Attr::Period(_), Attr::Period(_))
+        | (Attr::Range(..), Attr::Range(..))
+        | (Attr::Test(..), Attr::Test(..)) => true,
+        _ => false,
+    }
+}
+
+fn is_duplicated(x: &Attr, y: &Attr) -> bool {
+    match (x, y) {
+        (Attr::Test(x, _), Attr::Test(y, _)) => x.text == y.text,
+        _ => x == y,
+    }
+}
+
 pub struct CheckAttribute<'a> {
     pub errors: Vec<AnalyzerError>,
     text: &'a str,
     point: HandlerPoint,
+    target_checked: HashSet<TokenId>,
 }
 
 impl<'a> CheckAttribute<'a> {
@@ -18,6 +106,132 @@ impl<'a> CheckAttribute<'a> {
             errors: Vec::new(),
             text,
             point: HandlerPoint::Before,
+            target_checked: HashSet::new(),
+        }
+    }
+
+    fn check_attributes(&mut self, attrs: &[&Attribute], targets: &[Target]) {
+        let mut checked: Vec<Attr> = Vec::new();
+        for arg in attrs {
+            // invalid attributes are reported at the attribute handler
+            let Ok(attr) = Attr::try_from(*arg) else {
+                continue;
+            };
+            // multiple init_file attributes are reported as multiple_init_file
+            if matches!(attr, Attr::InitFile(_)) {
+                continue;
+            }
+
+            let name = arg.identifier.identifier_token.to_string();
+            if checked.iter().any(|x| is_duplicated(x, &attr)) {
+                self.errors.push(AnalyzerError::duplicated_attribute(
+                    &name,
+                    self.text,
+                    &arg.identifier.as_ref().into(),
+                ));
+            } else if let Some(other) = checked.iter().find(|x| is_conflicting(x, &attr)) {
+                self.errors.push(AnalyzerError::conflicting_attribute(
+                    &attr.to_string(),
+                    &other.to_string(),
+                    self.text,
+                    &arg.identifier.as_ref().into(),
+                ));
+            } else if let Some(target) = targets.iter().find_map(|x| invalid_target(&attr, *x)) {
+                self.errors.push(AnalyzerError::invalid_attribute_target(
+                    &name,
+                    target,
+                    self.text,
+                    &arg.identifier.as_ref().into(),
+                ));
+            }
+            checked.push(attr);
+        }
+    }
+
+    fn check_init_file(&mut self, attrs: &[&Attribute], item: Option<&GenerateItem>) {
+        let attrs: Vec<_> = attrs
+            .iter()
+            .filter_map(|x| match Attr::try_from(*x) {
+                Ok(Attr::InitFile(path)) => Some((*x, path)),
+                _ => None,
+            })
+            .collect();
+
+        let target = match item {
+            Some(GenerateItem::VarDeclaration(x)) => {
+                let x = &x.var_declaration;
+                x.array_type.array_type_opt.as_ref().map(|_| &x.identifier)
+            }
+            _ => None,
+        };
+
+        for (i, (attr, path)) in attrs.iter().enumerate() {
+            self.target_checked.insert(attr.hash.hash_token.token.id);
+
+            let Some(target) = target else {
+                self.errors.push(AnalyzerError::invalid_init_file_target(
+                    self.text,
+                    &attr.identifier.as_ref().into(),
+                ));
+                continue;
+            };
+
+            if i > 0 {
+                self.errors.push(AnalyzerError::multiple_init_file(
+                    &target.identifier_token.to_string(),
+                    self.text,
+                    &attr.identifier.as_ref().into(),
+                ));
+                continue;
+            }
+
+            // Relative path is resolved from the directory of the source file
+            if let TokenSource::File(source) = attr.hash.hash_token.token.source {
+                let source = resource_table::get_path_value(source).unwrap();
+                if let Some(dir) = source.parent() {
+                    let path = path.to_string();
+                    if !dir.join(&path).exists() {
+                        self.errors.push(AnalyzerError::missing_init_file(
+                            &path,
+                            self.text,
+                            &attr.identifier.as_ref().into(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_private(&mut self, attrs: &[&Attribute], is_declaration: bool) {
+        for attr in attrs {
+            if matches!(Attr::try_from(*attr), Ok(Attr::Private)) {
+                self.target_checked.insert(attr.hash.hash_token.token.id);
+
+                if !is_declaration {
+                    self.errors.push(AnalyzerError::invalid_attribute_target(
+                        &attr.identifier.identifier_token.to_string(),
+                        "a declaration in a package",
+                        self.text,
+                        &attr.identifier.as_ref().into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn check_dpi(&mut self, attrs: &[&Attribute], is_function: bool) {
+        for attr in attrs {
+            if matches!(Attr::try_from(*attr), Ok(Attr::DpiImport | Attr::DpiExport)) {
+                self.target_checked.insert(attr.hash.hash_token.token.id);
+
+                if !is_function {
+                    self.errors.push(AnalyzerError::invalid_dpi_target(
+                        &attr.identifier.identifier_token.to_string(),
+                        self.text,
+                        &attr.identifier.as_ref().into(),
+                    ));
+                }
+            }
         }
     }
 }
@@ -36,14 +250,43 @@ impl VerylGrammarTrait for CheckAttribute<'_> {
 
             match attr {
                 Ok(attr) => {
+                    // init_file, dpi and private not checked at the declaration groups are placed at invalid position
+                    if !self.target_checked.contains(&arg.hash.hash_token.token.id) {
+                        match attr {
+                            Attr::InitFile(_) => {
+                                self.errors.push(AnalyzerError::invalid_init_file_target(
+                                    self.text,
+                                    &arg.identifier.as_ref().into(),
+                                ));
+                            }
+                            Attr::DpiImport | Attr::DpiExport => {
+                                self.errors.push(AnalyzerError::invalid_dpi_target(
+                                    &arg.identifier.identifier_token.to_string(),
+                                    self.text,
+                                    &arg.identifier.as_ref().into(),
+                                ));
+                            }
+                            Attr::Private => {
+                                self.errors.push(AnalyzerError::invalid_attribute_target(
+                                    &arg.identifier.identifier_token.to_string(),
+                                    "a declaration in a package",
+                                    self.text,
+                                    &arg.identifier.as_ref().into(),
+                                ));
+                            }
+                            _ => (),
+                        }
+                    }
                     attribute_table::begin(arg.hash.hash_token.token, Some(attr));
                 }
                 Err(err) => {
                     attribute_table::begin(arg.hash.hash_token.token, None);
                     match err {
                         AttributeError::UnknownAttribute => {
+                            let name = arg.identifier.identifier_token.to_string();
                             self.errors.push(AnalyzerError::unknown_attribute(
-                                &arg.identifier.identifier_token.to_string(),
+                                &name,
+                                attribute::similar_attribute(&name),
                                 self.text,
                                 &arg.identifier.as_ref().into(),
                             ));
@@ -77,6 +320,13 @@ impl VerylGrammarTrait for CheckAttribute<'_> {
                                 &arg.identifier.as_ref().into(),
                             ));
                         }
+                        AttributeError::InvalidPeriod(x) => {
+                            self.errors.push(AnalyzerError::invalid_period(
+                                &x.to_string(),
+                                self.text,
+                                &arg.identifier.as_ref().into(),
+                            ));
+                        }
                     }
                 }
             }
@@ -85,169 +335,395 @@ impl VerylGrammarTrait for CheckAttribute<'_> {
     }
 
     fn statement_block_group(&mut self, arg: &StatementBlockGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.statement_block_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .statement_block_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let items: Vec<StatementBlockItem> = arg.into();
+                let targets: Vec<_> = items
+                    .iter()
+                    .map(|x| match x {
+                        StatementBlockItem::Statement(_) => Target::Statement,
+                        _ => Target::Declaration,
+                    })
+                    .collect();
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.statement_block_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.statement_block_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.statement_block_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn modport_group(&mut self, arg: &ModportGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.modport_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .modport_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Member];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.modport_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.modport_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.modport_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn enum_group(&mut self, arg: &EnumGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.enum_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .enum_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Member];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.enum_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.enum_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.enum_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn struct_union_group(&mut self, arg: &StructUnionGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.struct_union_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .struct_union_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Member];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.struct_union_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.struct_union_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.struct_union_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn inst_parameter_group(&mut self, arg: &InstParameterGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.inst_parameter_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .inst_parameter_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Member];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.inst_parameter_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.inst_parameter_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.inst_parameter_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn inst_port_group(&mut self, arg: &InstPortGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.inst_port_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .inst_port_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Member];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.inst_port_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.inst_port_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.inst_port_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn with_parameter_group(&mut self, arg: &WithParameterGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.with_parameter_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .with_parameter_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Parameter];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.with_parameter_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.with_parameter_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.with_parameter_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn port_declaration_group(&mut self, arg: &PortDeclarationGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.port_declaration_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .port_declaration_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let targets = [Target::Port];
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.port_declaration_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.port_declaration_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.port_declaration_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn module_group(&mut self, arg: &ModuleGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.module_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .module_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let items: Vec<ModuleItem> = arg.into();
+                let targets: Vec<_> = items
+                    .iter()
+                    .map(|x| Target::from_generate_item(&x.generate_item))
+                    .collect();
+                self.check_attributes(&attrs, &targets);
+                let item = match arg.module_group_group.as_ref() {
+                    ModuleGroupGroup::ModuleItem(x) => Some(x.module_item.generate_item.as_ref()),
+                    _ => None,
+                };
+                self.check_init_file(&attrs, item);
+                self.check_dpi(
+                    &attrs,
+                    matches!(item, Some(GenerateItem::FunctionDeclaration(_))),
+                );
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.module_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.module_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.module_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn interface_group(&mut self, arg: &InterfaceGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.interface_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .interface_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let items: Vec<InterfaceItem> = arg.into();
+                let targets: Vec<_> = items
+                    .iter()
+                    .map(|x| match x {
+                        InterfaceItem::GenerateItem(x) => {
+                            Target::from_generate_item(&x.generate_item)
+                        }
+                        _ => Target::Declaration,
+                    })
+                    .collect();
+                self.check_attributes(&attrs, &targets);
+                let item = match arg.interface_group_group.as_ref() {
+                    InterfaceGroupGroup::InterfaceItem(x) => match x.interface_item.as_ref() {
+                        InterfaceItem::GenerateItem(x) => Some(x.generate_item.as_ref()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                self.check_init_file(&attrs, item);
+                self.check_dpi(
+                    &attrs,
+                    matches!(item, Some(GenerateItem::FunctionDeclaration(_))),
+                );
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.interface_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.interface_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.interface_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn generate_group(&mut self, arg: &GenerateGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.generate_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .generate_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let items: Vec<GenerateItem> = arg.into();
+                let targets: Vec<_> = items.iter().map(Target::from_generate_item).collect();
+                self.check_attributes(&attrs, &targets);
+                let item = match arg.generate_group_group.as_ref() {
+                    GenerateGroupGroup::GenerateItem(x) => Some(x.generate_item.as_ref()),
+                    _ => None,
+                };
+                self.check_init_file(&attrs, item);
+                self.check_dpi(
+                    &attrs,
+                    matches!(item, Some(GenerateItem::FunctionDeclaration(_))),
+                );
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.generate_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.generate_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.generate_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn package_group(&mut self, arg: &PackageGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.package_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .package_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let items: Vec<PackageItem> = arg.into();
+                let targets: Vec<_> = items
+                    .iter()
+                    .map(|x| match x {
+                        PackageItem::EnumDeclaration(_) => Target::Enum,
+                        _ => Target::Declaration,
+                    })
+                    .collect();
+                self.check_attributes(&attrs, &targets);
+                let is_function = matches!(
+                    arg.package_group_group.as_ref(),
+                    PackageGroupGroup::PackageItem(x)
+                        if matches!(x.package_item.as_ref(), PackageItem::FunctionDeclaration(_))
+                );
+                self.check_dpi(&attrs, is_function);
+                let is_declaration = !matches!(
+                    arg.package_group_group.as_ref(),
+                    PackageGroupGroup::PackageItem(x)
+                        if matches!(
+                            x.package_item.as_ref(),
+                            PackageItem::ImportDeclaration(_) | PackageItem::ExportDeclaration(_)
+                        )
+                );
+                self.check_private(&attrs, is_declaration);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.package_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.package_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.package_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
     }
 
     fn description_group(&mut self, arg: &DescriptionGroup) -> Result<(), ParolError> {
-        if let HandlerPoint::After = self.point {
-            let mut last_token = LastToken::default();
-            last_token.description_group(arg);
-            let last_token = last_token.token().unwrap();
+        match self.point {
+            HandlerPoint::Before => {
+                let attrs: Vec<_> = arg
+                    .description_group_list
+                    .iter()
+                    .map(|x| x.attribute.as_ref())
+                    .collect();
+                let items: Vec<DescriptionItem> = arg.into();
+                let targets: Vec<_> = items
+                    .iter()
+                    .map(|x| match x {
+                        DescriptionItem::ModuleDeclaration(_) => Target::Module,
+                        DescriptionItem::EmbedDeclaration(_)
+                        | DescriptionItem::IncludeDeclaration(_) => Target::Embed,
+                        DescriptionItem::PackageDeclaration(_) => Target::Package,
+                        _ => Target::Description,
+                    })
+                    .collect();
+                self.check_attributes(&attrs, &targets);
+            }
+            HandlerPoint::After => {
+                let mut last_token = LastToken::default();
+                last_token.description_group(arg);
+                let last_token = last_token.token().unwrap();
 
-            for _ in &arg.description_group_list {
-                attribute_table::end(last_token);
+                for _ in &arg.description_group_list {
+                    attribute_table::end(last_token);
+                }
             }
         }
         Ok(())
@@ -1,15 +1,87 @@
 use crate::analyzer_error::AnalyzerError;
+use crate::cdc_table::{self, Crossing};
 use crate::r#unsafe::Unsafe;
-use crate::symbol::{ClockDomain, SymbolId, SymbolKind};
+use crate::symbol::{ClockDomain, SymbolId, SymbolKind, VariableAffiliation};
 use crate::symbol_table;
 use crate::unsafe_table;
 use std::collections::HashMap;
 use veryl_parser::resource_table::StrId;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, TokenRange};
-use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 use veryl_parser::ParolError;
 
+fn signal_clock_domain(kind: &SymbolKind) -> Option<ClockDomain> {
+    match kind {
+        SymbolKind::Port(x) => Some(x.clock_domain),
+        SymbolKind::Variable(x) => Some(x.clock_domain),
+        _ => None,
+    }
+}
+
+/// Returns the clock domain of the clock which drives `always_ff`
+fn always_ff_clock_domain(
+    arg: &AlwaysFfDeclaration,
+    default_clock: Option<SymbolId>,
+) -> Option<ClockDomain> {
+    if let Some(ref x) = arg.always_ff_declaration_opt {
+        // clock domain is assigned to base identifier
+        let ident = x
+            .always_ff_event_list
+            .always_ff_clock
+            .hierarchical_identifier
+            .identifier
+            .as_ref();
+        let symbol = symbol_table::resolve(ident).ok()?;
+        signal_clock_domain(&symbol.found.kind)
+    } else {
+        let symbol = symbol_table::get(default_clock?)?;
+        signal_clock_domain(&symbol.kind)
+    }
+}
+
+/// Infers clock domains of variables without clock domain annotation
+/// from `always_ff` which assigns them
+struct ClockDomainInferrer {
+    default_clock: Option<SymbolId>,
+    clock_domain: Option<ClockDomain>,
+}
+
+impl VerylWalker for ClockDomainInferrer {
+    /// Semantic action for non-terminal 'AlwaysFfDeclaration'
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
+        self.clock_domain = always_ff_clock_domain(arg, self.default_clock);
+        if let Some(ClockDomain::Explicit(_)) = self.clock_domain {
+            self.statement_block(&arg.statement_block);
+        }
+        self.clock_domain = None;
+    }
+
+    /// Semantic action for non-terminal 'IdentifierStatement'
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) {
+        let (Some(clock_domain), IdentifierStatementGroup::Assignment(_)) =
+            (self.clock_domain, arg.identifier_statement_group.as_ref())
+        else {
+            return;
+        };
+        let ident = arg.expression_identifier.scoped_identifier.as_ref();
+        let Ok(symbol) = symbol_table::resolve(ident) else {
+            return;
+        };
+        let mut symbol = symbol.found;
+        if let SymbolKind::Variable(ref mut x) = symbol.kind {
+            // the first assignment determines the clock domain,
+            // and the others are checked by `CheckClockDomain`
+            if x.affiliation == VariableAffiliation::Module
+                && x.clock_domain == ClockDomain::Implicit
+            {
+                x.clock_domain = clock_domain;
+                symbol_table::update(symbol);
+            }
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct CheckClockDomain<'a> {
     pub errors: Vec<AnalyzerError>,
@@ -19,6 +91,7 @@ pub struct CheckClockDomain<'a> {
     inst_clock_domains: HashMap<StrId, (ClockDomain, TokenRange)>,
     always_ff_clock_domain: Option<(ClockDomain, TokenRange)>,
     default_clock: Option<SymbolId>,
+    module: Option<SymbolId>,
 }
 
 impl<'a> CheckClockDomain<'a> {
@@ -41,22 +114,45 @@ impl<'a> CheckClockDomain<'a> {
         }
     }
 
+    /// Reports mismatch of clock domains, or records it as crossing if it is allowed by `unsafe (cdc)`
+    fn check_compatible(
+        &mut self,
+        token: &Token,
+        from: &(ClockDomain, TokenRange),
+        to: &(ClockDomain, TokenRange),
+    ) {
+        if from.0.compatible(&to.0) {
+            return;
+        }
+
+        if unsafe_table::contains(token, Unsafe::Cdc) {
+            if let Some(module) = self.module {
+                cdc_table::insert(Crossing {
+                    module,
+                    from: from.0,
+                    to: to.0,
+                    range: to.1,
+                });
+            }
+        } else {
+            self.errors.push(AnalyzerError::mismatch_clock_domain(
+                &to.0.to_string(),
+                &from.0.to_string(),
+                self.text,
+                &to.1,
+                &from.1,
+            ));
+        }
+    }
+
     fn check_expr_clock_domains(&mut self, token: &Token) -> ClockDomain {
         let mut prev: Option<(ClockDomain, TokenRange)> = self.always_ff_clock_domain;
-        for curr in &self.expr_clock_domains {
+        for curr in self.expr_clock_domains.clone() {
             if let Some(prev) = prev {
-                if !curr.0.compatible(&prev.0) && !unsafe_table::contains(token, Unsafe::Cdc) {
-                    self.errors.push(AnalyzerError::mismatch_clock_domain(
-                        &curr.0.to_string(),
-                        &prev.0.to_string(),
-                        self.text,
-                        &curr.1,
-                        &prev.1,
-                    ));
-                }
+                self.check_compatible(token, &prev, &curr);
             }
 
-            prev = Some(*curr);
+            prev = Some(curr);
         }
         prev.map(|(x, _)| x).unwrap_or(ClockDomain::None)
     }
@@ -127,40 +223,37 @@ impl VerylGrammarTrait for CheckClockDomain<'_> {
         match self.point {
             HandlerPoint::Before => {
                 let range: TokenRange = arg.always_ff.always_ff_token.token.into();
-                if let Some(ref x) = arg.always_ff_declaration_opt {
-                    // clock domain is assigned to base identifier
-                    let ident = x
-                        .always_ff_event_list
-                        .always_ff_clock
-                        .hierarchical_identifier
-                        .identifier
-                        .as_ref();
-                    if let Ok(symbol) = symbol_table::resolve(ident) {
-                        match symbol.found.kind {
-                            SymbolKind::Port(x) => {
-                                self.always_ff_clock_domain = Some((x.clock_domain, range))
-                            }
-                            SymbolKind::Variable(x) => {
-                                self.always_ff_clock_domain = Some((x.clock_domain, range))
-                            }
-                            _ => (),
+                self.always_ff_clock_domain =
+                    always_ff_clock_domain(arg, self.default_clock).map(|x| (x, range));
+            }
+            HandlerPoint::After => self.always_ff_clock_domain = None,
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.expr_clock_domains.clear();
+
+                // concurrent assertion is sampled by the default clock
+                let range: TokenRange = arg.property.property_token.token.into();
+                if let Some(symbol) = self.default_clock.and_then(symbol_table::get) {
+                    match symbol.kind {
+                        SymbolKind::Port(x) => {
+                            self.always_ff_clock_domain = Some((x.clock_domain, range))
                         }
-                    }
-                } else if let Some(ref x) = self.default_clock {
-                    if let Some(symbol) = symbol_table::get(*x) {
-                        match symbol.kind {
-                            SymbolKind::Port(x) => {
-                                self.always_ff_clock_domain = Some((x.clock_domain, range))
-                            }
-                            SymbolKind::Variable(x) => {
-                                self.always_ff_clock_domain = Some((x.clock_domain, range))
-                            }
-                            _ => (),
+                        SymbolKind::Variable(x) => {
+                            self.always_ff_clock_domain = Some((x.clock_domain, range))
                         }
+                        _ => (),
                     }
                 }
             }
-            HandlerPoint::After => self.always_ff_clock_domain = None,
+            HandlerPoint::After => {
+                self.check_expr_clock_domains(&arg.semicolon.semicolon_token.token);
+                self.always_ff_clock_domain = None;
+            }
         }
         Ok(())
     }
@@ -215,43 +308,25 @@ impl VerylGrammarTrait for CheckClockDomain<'_> {
                             let mut connection_table =
                                 HashMap::<ClockDomain, (ClockDomain, TokenRange)>::new();
                             for x in &x.ports {
-                                if let Some(connected) = self.inst_clock_domains.get(&x.name()) {
+                                if let Some(connected) =
+                                    self.inst_clock_domains.get(&x.name()).copied()
+                                {
                                     let port_domain = x.property().clock_domain;
                                     if let Some(assigned) = connection_table.get(&port_domain) {
-                                        if !assigned.0.compatible(&connected.0)
-                                            && !unsafe_table::contains(token, Unsafe::Cdc)
-                                        {
-                                            self.errors.push(AnalyzerError::mismatch_clock_domain(
-                                                &connected.0.to_string(),
-                                                &assigned.0.to_string(),
-                                                self.text,
-                                                &connected.1,
-                                                &assigned.1,
-                                            ));
-                                        }
+                                        self.check_compatible(token, assigned, &connected);
                                     } else {
-                                        connection_table.insert(port_domain, *connected);
+                                        connection_table.insert(port_domain, connected);
                                     }
                                 }
                             }
                         }
                         SymbolKind::SystemVerilog => {
                             let mut prev: Option<(ClockDomain, TokenRange)> = None;
-                            for curr in self.inst_clock_domains.values() {
+                            for curr in self.inst_clock_domains.clone().into_values() {
                                 if let Some(prev) = prev {
-                                    if !prev.0.compatible(&curr.0)
-                                        && !unsafe_table::contains(token, Unsafe::Cdc)
-                                    {
-                                        self.errors.push(AnalyzerError::mismatch_clock_domain(
-                                            &curr.0.to_string(),
-                                            &prev.0.to_string(),
-                                            self.text,
-                                            &curr.1,
-                                            &prev.1,
-                                        ));
-                                    }
+                                    self.check_compatible(token, &prev, &curr);
                                 }
-                                prev = Some(*curr);
+                                prev = Some(curr);
                             }
                         }
                         _ => (),
@@ -268,6 +343,13 @@ impl VerylGrammarTrait for CheckClockDomain<'_> {
             if let SymbolKind::Module(ref x) = symbol.found.kind {
                 self.default_clock = x.default_clock;
             }
+            self.module = Some(symbol.found.id);
+
+            let mut inferrer = ClockDomainInferrer {
+                default_clock: self.default_clock,
+                clock_domain: None,
+            };
+            inferrer.module_declaration(arg);
         }
         Ok(())
     }
@@ -1,6 +1,7 @@
 use crate::analyzer_error::AnalyzerError;
 use crate::evaluator::{Evaluated, Evaluator};
-use crate::symbol::{SymbolKind, TypeKind};
+use crate::symbol::{Symbol, SymbolKind, Type, TypeKind};
+use crate::symbol_path::SymbolPath;
 use crate::symbol_table;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
@@ -30,6 +31,147 @@ impl<'a> CheckClockReset<'a> {
     }
 }
 
+/// Returns the type of symbol which can be placed at the event of always_ff
+fn signal_type(symbol: &Symbol) -> Option<Type> {
+    match &symbol.kind {
+        SymbolKind::Port(x) => x.r#type.clone(),
+        SymbolKind::Variable(x) => Some(x.r#type.clone()),
+        SymbolKind::StructMember(x) => Some(x.r#type.clone()),
+        SymbolKind::ModportVariableMember(_) => {
+            // Modport member refers the variable of the interface
+            let path = SymbolPath::new(&[symbol.token.text]);
+            let mut namespace = symbol.namespace.clone();
+            namespace.pop();
+            let found = symbol_table::resolve((&path, &namespace)).ok()?;
+            if let SymbolKind::Variable(x) = found.found.kind {
+                Some(x.r#type)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_clock(r#type: &Type) -> bool {
+    matches!(
+        r#type.kind,
+        TypeKind::Clock | TypeKind::ClockPosedge | TypeKind::ClockNegedge
+    )
+}
+
+fn is_reset(r#type: &Type) -> bool {
+    matches!(
+        r#type.kind,
+        TypeKind::Reset
+            | TypeKind::ResetAsyncHigh
+            | TypeKind::ResetAsyncLow
+            | TypeKind::ResetSyncHigh
+            | TypeKind::ResetSyncLow
+    )
+}
+
+/// Returns the operand if the expression consists of a single operand without any binary operator
+fn operand01(arg: &Expression01) -> Option<&Expression11> {
+    if !arg.expression01_list.is_empty() {
+        return None;
+    }
+    operand02(&arg.expression02)
+}
+
+fn operand02(arg: &Expression02) -> Option<&Expression11> {
+    if !arg.expression02_list.is_empty() {
+        return None;
+    }
+    let exp = &*arg.expression03;
+    if !exp.expression03_list.is_empty() {
+        return None;
+    }
+    let exp = &*exp.expression04;
+    if !exp.expression04_list.is_empty() {
+        return None;
+    }
+    let exp = &*exp.expression05;
+    if !exp.expression05_list.is_empty() {
+        return None;
+    }
+    operand06(&exp.expression06)
+}
+
+fn operand06(arg: &Expression06) -> Option<&Expression11> {
+    if !arg.expression06_list.is_empty() {
+        return None;
+    }
+    operand07(&arg.expression07)
+}
+
+fn operand07(arg: &Expression07) -> Option<&Expression11> {
+    if !arg.expression07_list.is_empty() {
+        return None;
+    }
+    let exp = &*arg.expression08;
+    if !exp.expression08_list.is_empty() {
+        return None;
+    }
+    let exp = &*exp.expression09;
+    if !exp.expression09_list.is_empty() {
+        return None;
+    }
+    let exp = &*exp.expression10;
+    if !exp.expression10_list.is_empty() {
+        return None;
+    }
+    Some(&exp.expression11)
+}
+
+impl CheckClockReset<'_> {
+    /// Checks whether a clock or reset is used as data by a condition or an operand of comparison.
+    /// Unary operators like `!rst` are included, and casting by `as` is not.
+    fn check_data_operand(&mut self, arg: Option<&Expression11>) {
+        let Some(arg) = arg else {
+            return;
+        };
+        if arg.expression11_opt.is_some() {
+            return;
+        }
+        let Factor::IdentifierFactor(x) = arg.expression12.factor.as_ref() else {
+            return;
+        };
+        let x = &x.identifier_factor;
+        if x.identifier_factor_opt.is_some() {
+            return;
+        }
+        let Ok(found) = symbol_table::resolve(x.expression_identifier.as_ref()) else {
+            return;
+        };
+        let Some(r#type) = signal_type(&found.found) else {
+            return;
+        };
+
+        let kind = if is_clock(&r#type) {
+            // Clock in always_ff is reported as clock_read_as_data
+            if self.in_always_ff {
+                return;
+            }
+            "clock"
+        } else if is_reset(&r#type) {
+            "reset"
+        } else {
+            return;
+        };
+        self.errors.push(AnalyzerError::clock_reset_as_data(
+            kind,
+            &found.found.token.to_string(),
+            self.text,
+            &x.expression_identifier.as_ref().into(),
+        ));
+    }
+
+    fn check_condition(&mut self, arg: &Expression) {
+        self.check_data_operand(expression_operand(arg));
+    }
+}
+
 impl Handler for CheckClockReset<'_> {
     fn set_point(&mut self, p: HandlerPoint) {
         self.point = p;
@@ -146,30 +288,10 @@ impl VerylGrammarTrait for CheckClockReset<'_> {
             HandlerPoint::Before => self.n_of_select = 0,
             HandlerPoint::After => {
                 if let Ok(found) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
-                    let symbol = found.found;
-                    let valid_clock = match symbol.kind {
-                        SymbolKind::Port(x) => {
-                            let clock = x.r#type.clone().unwrap();
-                            let n_of_select = clock.width.len() + clock.array.len();
-                            match clock.kind {
-                                TypeKind::Clock
-                                | TypeKind::ClockPosedge
-                                | TypeKind::ClockNegedge => n_of_select == self.n_of_select,
-                                _ => false,
-                            }
-                        }
-                        SymbolKind::Variable(x) => {
-                            let clock = x.r#type;
-                            let n_of_select = clock.width.len() + clock.array.len();
-                            match clock.kind {
-                                TypeKind::Clock
-                                | TypeKind::ClockPosedge
-                                | TypeKind::ClockNegedge => n_of_select == self.n_of_select,
-                                _ => false,
-                            }
-                        }
-                        _ => false,
-                    };
+                    let valid_clock = signal_type(&found.found).is_some_and(|x| {
+                        let n_of_select = x.width.len() + x.array.len();
+                        is_clock(&x) && n_of_select == self.n_of_select
+                    });
 
                     if !valid_clock {
                         let token = &arg
@@ -194,34 +316,10 @@ impl VerylGrammarTrait for CheckClockReset<'_> {
             HandlerPoint::Before => self.n_of_select = 0,
             HandlerPoint::After => {
                 if let Ok(found) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
-                    let symbol = found.found;
-                    let valid_reset = match symbol.kind {
-                        SymbolKind::Port(x) => {
-                            let reset = x.r#type.clone().unwrap();
-                            let n_of_select = reset.width.len() + reset.array.len();
-                            match reset.kind {
-                                TypeKind::Reset
-                                | TypeKind::ResetAsyncHigh
-                                | TypeKind::ResetAsyncLow
-                                | TypeKind::ResetSyncHigh
-                                | TypeKind::ResetSyncLow => n_of_select == self.n_of_select,
-                                _ => false,
-                            }
-                        }
-                        SymbolKind::Variable(x) => {
-                            let reset = x.r#type;
-                            let n_of_select = reset.width.len() + reset.array.len();
-                            match reset.kind {
-                                TypeKind::Reset
-                                | TypeKind::ResetAsyncHigh
-                                | TypeKind::ResetAsyncLow
-                                | TypeKind::ResetSyncHigh
-                                | TypeKind::ResetSyncLow => n_of_select == self.n_of_select,
-                                _ => false,
-                            }
-                        }
-                        _ => false,
-                    };
+                    let valid_reset = signal_type(&found.found).is_some_and(|x| {
+                        let n_of_select = x.width.len() + x.array.len();
+                        is_reset(&x) && n_of_select == self.n_of_select
+                    });
 
                     if !valid_reset {
                         let token = &arg
@@ -255,6 +353,105 @@ impl VerylGrammarTrait for CheckClockReset<'_> {
         Ok(())
     }
 
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.in_always_ff && arg.identifier_factor_opt.is_none() {
+                if let Ok(found) = symbol_table::resolve(arg.expression_identifier.as_ref()) {
+                    if signal_type(&found.found).is_some_and(|x| is_clock(&x)) {
+                        self.errors.push(AnalyzerError::clock_read_as_data(
+                            &found.found.token.to_string(),
+                            self.text,
+                            &arg.expression_identifier.as_ref().into(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expression(&mut self, arg: &Expression) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !arg.expression_list.is_empty() {
+                self.check_data_operand(operand01(&arg.expression01));
+                for x in &arg.expression_list {
+                    self.check_data_operand(operand01(&x.expression01));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expression01(&mut self, arg: &Expression01) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !arg.expression01_list.is_empty() {
+                self.check_data_operand(operand02(&arg.expression02));
+                for x in &arg.expression01_list {
+                    self.check_data_operand(operand02(&x.expression02));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expression05(&mut self, arg: &Expression05) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !arg.expression05_list.is_empty() {
+                self.check_data_operand(operand06(&arg.expression06));
+                for x in &arg.expression05_list {
+                    self.check_data_operand(operand06(&x.expression06));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expression06(&mut self, arg: &Expression06) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !arg.expression06_list.is_empty() {
+                self.check_data_operand(operand07(&arg.expression07));
+                for x in &arg.expression06_list {
+                    self.check_data_operand(operand07(&x.expression07));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn if_expression(&mut self, arg: &IfExpression) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_condition(&arg.expression);
+            for x in &arg.if_expression_list {
+                self.check_condition(&x.expression);
+            }
+        }
+        Ok(())
+    }
+
+    fn case_expression(&mut self, arg: &CaseExpression) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_condition(&arg.expression);
+        }
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_condition(&arg.expression);
+            for x in &arg.if_statement_list {
+                self.check_condition(&x.expression);
+            }
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_condition(&arg.expression);
+        }
+        Ok(())
+    }
+
     fn assignment(&mut self, arg: &Assignment) -> Result<(), ParolError> {
         use Evaluated::*;
         if let HandlerPoint::Before = self.point {
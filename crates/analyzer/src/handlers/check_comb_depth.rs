@@ -0,0 +1,318 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::symbol::{SymbolId, SymbolKind};
+use crate::symbol_path::SymbolPathNamespace;
+use crate::symbol_table;
+use std::collections::{HashMap, HashSet};
+use veryl_metadata::Lint;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+pub(crate) struct SignalCollector {
+    signals: Vec<SymbolId>,
+}
+
+impl SignalCollector {
+    pub(crate) fn collect(arg: &Expression) -> Vec<SymbolId> {
+        let mut collector = SignalCollector::default();
+        collector.expression(arg);
+        collector.signals
+    }
+}
+
+impl VerylWalker for SignalCollector {
+    /// Semantic action for non-terminal 'ExpressionIdentifier'
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if let Some(id) = signal(arg) {
+            self.signals.push(id);
+        }
+        for x in &arg.expression_identifier_list {
+            self.select(&x.select);
+        }
+        for x in &arg.expression_identifier_list0 {
+            for x in &x.expression_identifier_list0_list {
+                self.select(&x.select);
+            }
+        }
+    }
+}
+
+pub(crate) fn signal<T: Into<SymbolPathNamespace>>(arg: T) -> Option<SymbolId> {
+    let symbol = symbol_table::resolve(arg).ok()?;
+    let id = *symbol.full_path.first()?;
+    let symbol = symbol_table::get(id)?;
+    match symbol.kind {
+        SymbolKind::Variable(_) | SymbolKind::Port(_) => Some(id),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+pub struct CheckCombDepth<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    max_comb_depth: Option<usize>,
+    in_always_comb: bool,
+    in_always_ff: bool,
+    conditions: Vec<Vec<SymbolId>>,
+    dependencies: HashMap<SymbolId, (Token, Vec<SymbolId>)>,
+    registers: HashSet<SymbolId>,
+}
+
+impl<'a> CheckCombDepth<'a> {
+    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+        Self {
+            text,
+            max_comb_depth: lint_opt.max_comb_depth,
+            ..Default::default()
+        }
+    }
+
+    fn add_dependency(&mut self, target: SymbolId, token: &Token, sources: Vec<SymbolId>) {
+        let entry = self
+            .dependencies
+            .entry(target)
+            .or_insert_with(|| (*token, Vec::new()));
+        entry.1.extend(sources);
+        entry.1.extend(self.conditions.iter().flatten());
+    }
+
+    /// Returns the depth and the previous signal on the longest combinational path to `id`.
+    /// Signals assigned in `always_ff` and signals without combinational dependency have depth 0.
+    fn depth(
+        &self,
+        id: SymbolId,
+        depths: &mut HashMap<SymbolId, (usize, Option<SymbolId>)>,
+        visiting: &mut HashSet<SymbolId>,
+    ) -> usize {
+        if let Some((depth, _)) = depths.get(&id) {
+            return *depth;
+        }
+        if self.registers.contains(&id) {
+            return 0;
+        }
+        let Some((_, sources)) = self.dependencies.get(&id) else {
+            return 0;
+        };
+
+        // combinational loop is not the target of this check
+        visiting.insert(id);
+        let mut ret = (1, None);
+        for source in sources {
+            if visiting.contains(source) {
+                continue;
+            }
+            let depth = self.depth(*source, depths, visiting) + 1;
+            if depth > ret.0 {
+                ret = (depth, Some(*source));
+            }
+        }
+        visiting.remove(&id);
+
+        depths.insert(id, ret);
+        ret.0
+    }
+
+    fn check(&mut self, max_depth: usize) {
+        let mut depths = HashMap::new();
+        let mut visiting = HashSet::new();
+        let mut targets: Vec<_> = self.dependencies.keys().copied().collect();
+        targets.sort_by_key(|x| self.dependencies[x].0.id);
+        for target in &targets {
+            self.depth(*target, &mut depths, &mut visiting);
+        }
+
+        // report only the end of paths because every signal after an exceeding signal exceeds too
+        let exceeded: HashSet<_> = depths
+            .iter()
+            .filter(|(_, (depth, _))| *depth > max_depth)
+            .map(|(id, _)| *id)
+            .collect();
+        let continued: HashSet<_> = depths.values().filter_map(|(_, prev)| *prev).collect();
+
+        for target in targets {
+            if !exceeded.contains(&target) || continued.contains(&target) {
+                continue;
+            }
+
+            let mut path = vec![target];
+            while let Some((_, Some(prev))) = depths.get(path.last().unwrap()) {
+                path.push(*prev);
+            }
+            if let Some((_, sources)) = self.dependencies.get(path.last().unwrap()) {
+                if let Some(source) = sources.iter().find(|x| !path.contains(x)) {
+                    path.push(*source);
+                }
+            }
+            let path: Vec<_> = path
+                .iter()
+                .rev()
+                .map(|x| symbol_table::get(*x).unwrap().token.to_string())
+                .collect();
+
+            let token = &self.dependencies[&target].0;
+            self.errors.push(AnalyzerError::deep_combinational_path(
+                &token.to_string(),
+                depths[&target].0,
+                max_depth,
+                &path.join(" -> "),
+                self.text,
+                &token.into(),
+            ));
+        }
+    }
+}
+
+impl Handler for CheckCombDepth<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckCombDepth<'_> {
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(target) = signal(arg.hierarchical_identifier.as_ref()) {
+                let sources = SignalCollector::collect(&arg.expression);
+                let token = arg
+                    .hierarchical_identifier
+                    .identifier
+                    .identifier_token
+                    .token;
+                self.add_dependency(target, &token, sources);
+            }
+        }
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(target) = signal(arg.identifier.as_ref()) {
+                let sources = SignalCollector::collect(&arg.expression);
+                let token = arg.identifier.identifier_token.token;
+                self.add_dependency(target, &token, sources);
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, _arg: &AlwaysFfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_always_ff = true,
+            HandlerPoint::After => self.in_always_ff = false,
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_always_comb = true,
+            HandlerPoint::After => self.in_always_comb = false,
+        }
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let mut conditions = SignalCollector::collect(&arg.expression);
+                    for x in &arg.if_statement_list {
+                        conditions.append(&mut SignalCollector::collect(&x.expression));
+                    }
+                    self.conditions.push(conditions);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let conditions = SignalCollector::collect(&arg.expression);
+                    self.conditions.push(conditions);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn switch_statement(&mut self, arg: &SwitchStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let mut conditions = Vec::new();
+                    for x in &arg.switch_statement_list {
+                        if let SwitchItemGroup::SwitchCondition(x) =
+                            x.switch_item.switch_item_group.as_ref()
+                        {
+                            let x = &x.switch_condition;
+                            conditions.append(&mut SignalCollector::collect(&x.expression));
+                            for x in &x.switch_condition_list {
+                                conditions.append(&mut SignalCollector::collect(&x.expression));
+                            }
+                        }
+                    }
+                    self.conditions.push(conditions);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn let_statement(&mut self, arg: &LetStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.in_always_comb {
+                if let Some(target) = signal(arg.identifier.as_ref()) {
+                    let sources = SignalCollector::collect(&arg.expression);
+                    let token = arg.identifier.identifier_token.token;
+                    self.add_dependency(target, &token, sources);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(x) = arg.identifier_statement_group.as_ref()
+            {
+                if let Some(target) = signal(arg.expression_identifier.as_ref()) {
+                    if self.in_always_ff {
+                        self.registers.insert(target);
+                    } else if self.in_always_comb {
+                        let sources = SignalCollector::collect(&x.assignment.expression);
+                        let token = arg.expression_identifier.identifier().token;
+                        self.add_dependency(target, &token, sources);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, _arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::After = self.point {
+            if let Some(max_depth) = self.max_comb_depth {
+                self.check(max_depth);
+            }
+            self.dependencies.clear();
+            self.registers.clear();
+        }
+        Ok(())
+    }
+}
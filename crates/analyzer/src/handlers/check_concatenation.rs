@@ -0,0 +1,266 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::{SymbolKind, Type, TypeKind};
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::{ParolError, Stringifier};
+
+/// System functions which format their arguments as strings
+const DISPLAY_FUNCTIONS: &[&str] = &[
+    "$display",
+    "$displayb",
+    "$displayh",
+    "$displayo",
+    "$error",
+    "$fatal",
+    "$fdisplay",
+    "$fdisplayb",
+    "$fdisplayh",
+    "$fdisplayo",
+    "$fwrite",
+    "$fwriteb",
+    "$fwriteh",
+    "$fwriteo",
+    "$info",
+    "$monitor",
+    "$monitorb",
+    "$monitorh",
+    "$monitoro",
+    "$sformat",
+    "$sformatf",
+    "$strobe",
+    "$strobeb",
+    "$strobeh",
+    "$strobeo",
+    "$swrite",
+    "$swriteb",
+    "$swriteh",
+    "$swriteo",
+    "$warning",
+    "$write",
+    "$writeb",
+    "$writeh",
+    "$writeo",
+];
+
+#[derive(Default)]
+pub struct CheckConcatenation<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    evaluator: Evaluator,
+    /// Depth of arguments of display functions, where strings can be concatenated
+    display_depth: usize,
+}
+
+impl<'a> CheckConcatenation<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+}
+
+impl Handler for CheckConcatenation<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+fn is_display_function(arg: &ExpressionIdentifier) -> bool {
+    if !arg.scoped_identifier.scoped_identifier_list.is_empty() {
+        return false;
+    }
+    let ScopedIdentifierGroup::DollarIdentifier(ref x) =
+        *arg.scoped_identifier.scoped_identifier_group
+    else {
+        return false;
+    };
+    let name = x.dollar_identifier.dollar_identifier_token.to_string();
+    DISPLAY_FUNCTIONS.contains(&name.as_str())
+}
+
+/// Finds the first variable or port referred in the expression
+#[derive(Default)]
+struct VariableFinder {
+    found: bool,
+}
+
+impl VerylWalker for VariableFinder {
+    /// Semantic action for non-terminal 'ExpressionIdentifier'
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        let Ok(symbol) = symbol_table::resolve(arg) else {
+            return;
+        };
+        match symbol.found.kind {
+            SymbolKind::Variable(ref x) if !x.loop_variable => self.found = true,
+            SymbolKind::Port(_) => self.found = true,
+            _ => (),
+        }
+    }
+}
+
+/// Returns the number of selects applied to the symbol which `arg` refers to,
+/// or `None` if the width is selected
+fn select_count(arg: &ExpressionIdentifier) -> Option<usize> {
+    if arg.expression_identifier_opt.is_some() {
+        return None;
+    }
+    if let Some(x) = arg.expression_identifier_list0.last() {
+        Some(x.expression_identifier_list0_list.len())
+    } else {
+        Some(arg.expression_identifier_list.len())
+    }
+}
+
+impl CheckConcatenation<'_> {
+    /// Returns the name of the type if the type cannot be concatenated
+    fn invalid_type(&self, arg: &Type, select_count: usize) -> Option<String> {
+        if arg.array.len() > select_count {
+            return Some(arg.to_string());
+        }
+        if !arg.array.is_empty() {
+            // the element of the array is selected
+            return None;
+        }
+        match &arg.kind {
+            TypeKind::F32 | TypeKind::F64 | TypeKind::Type => Some(arg.to_string()),
+            TypeKind::String if self.display_depth == 0 => Some(arg.to_string()),
+            TypeKind::UserDefined(x) => {
+                let symbol = symbol_table::get(x.symbol?)?;
+                if let SymbolKind::TypeDef(ref x) = symbol.kind {
+                    self.invalid_type(&x.r#type, 0).map(|_| arg.to_string())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the type if the operand cannot be concatenated.
+    /// Operands with operators are not checked because they are integral values.
+    fn invalid_operand(&self, arg: &Expression) -> Option<String> {
+        let x = expression_operand(arg)?;
+        if let Some(ref x) = x.expression11_opt {
+            return match x.casting_type.as_ref() {
+                CastingType::F32(_) => Some("f32".to_string()),
+                CastingType::F64(_) => Some("f64".to_string()),
+                _ => None,
+            };
+        }
+        let x = x.expression12.as_ref();
+        if !x.expression12_list.is_empty() {
+            return None;
+        }
+        match x.factor.as_ref() {
+            Factor::Number(x) => match x.number.as_ref() {
+                Number::RealNumber(_) => Some("f64".to_string()),
+                Number::IntegralNumber(_) => None,
+            },
+            Factor::StringLiteral(_) if self.display_depth == 0 => Some("string".to_string()),
+            Factor::LParenExpressionRParen(x) => self.invalid_operand(&x.expression),
+            Factor::IdentifierFactor(x) => {
+                let x = &x.identifier_factor;
+                if x.struct_constructor().is_some() {
+                    return None;
+                }
+                let symbol = symbol_table::resolve(x.expression_identifier.as_ref()).ok()?;
+                if x.function_call().is_some() {
+                    return match symbol.found.kind {
+                        SymbolKind::Function(ref x) => self.invalid_type(x.ret.as_ref()?, 0),
+                        _ => None,
+                    };
+                }
+                match symbol.found.kind {
+                    SymbolKind::Variable(_)
+                    | SymbolKind::Port(_)
+                    | SymbolKind::Parameter(_)
+                    | SymbolKind::StructMember(_)
+                    | SymbolKind::UnionMember(_) => self.invalid_type(
+                        symbol.found.kind.get_type()?,
+                        select_count(&x.expression_identifier)?,
+                    ),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn check_repetition_count(&mut self, arg: &Expression, position: usize) {
+        let mut finder = VariableFinder::default();
+        finder.expression(arg);
+        let invalid = finder.found
+            || self.invalid_operand(arg).is_some()
+            // zero is reported as zero_repetition
+            || matches!(self.evaluator.expression(arg), Evaluated::Fixed { value, .. } if value < 0);
+        if invalid {
+            let mut stringifier = Stringifier::new();
+            stringifier.expression(arg);
+            self.errors.push(AnalyzerError::invalid_repetition_count(
+                stringifier.as_str(),
+                position,
+                self.text,
+                &arg.into(),
+            ));
+        }
+    }
+}
+
+impl VerylGrammarTrait for CheckConcatenation<'_> {
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
+        if arg.function_call().is_some() && is_display_function(&arg.expression_identifier) {
+            match self.point {
+                HandlerPoint::Before => self.display_depth += 1,
+                HandlerPoint::After => self.display_depth -= 1,
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if matches!(
+            *arg.identifier_statement_group,
+            IdentifierStatementGroup::FunctionCall(_)
+        ) && is_display_function(&arg.expression_identifier)
+        {
+            match self.point {
+                HandlerPoint::Before => self.display_depth += 1,
+                HandlerPoint::After => self.display_depth -= 1,
+            }
+        }
+        Ok(())
+    }
+
+    fn concatenation_list(&mut self, arg: &ConcatenationList) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            // nested concatenations are checked by their own `concatenation_list`
+            let mut items = vec![arg.concatenation_item.as_ref()];
+            items.extend(
+                arg.concatenation_list_list
+                    .iter()
+                    .map(|x| x.concatenation_item.as_ref()),
+            );
+
+            for (i, item) in items.into_iter().enumerate() {
+                let position = i + 1;
+                if let Some(type_name) = self.invalid_operand(&item.expression) {
+                    self.errors
+                        .push(AnalyzerError::invalid_concatenation_operand(
+                            position,
+                            &type_name,
+                            self.text,
+                            &item.expression.as_ref().into(),
+                        ));
+                }
+                if let Some(ref x) = item.concatenation_item_opt {
+                    self.check_repetition_count(&x.expression, position);
+                }
+            }
+        }
+        Ok(())
+    }
+}
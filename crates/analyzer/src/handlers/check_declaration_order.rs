@@ -0,0 +1,107 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::namespace_table;
+use crate::symbol::{ParameterKind, SymbolKind};
+use crate::symbol_table;
+use veryl_metadata::Build;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+pub struct CheckDeclarationOrder<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    reorder_consts: bool,
+    in_header: bool,
+    function_depth: usize,
+}
+
+impl<'a> CheckDeclarationOrder<'a> {
+    pub fn new(text: &'a str, build_opt: &'a Build) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+            reorder_consts: build_opt.reorder_consts,
+            in_header: false,
+            function_depth: 0,
+        }
+    }
+
+    fn header(&mut self) {
+        if self.function_depth == 0 {
+            self.in_header = matches!(self.point, HandlerPoint::Before);
+        }
+    }
+}
+
+impl Handler for CheckDeclarationOrder<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckDeclarationOrder<'_> {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let Ok(symbol) = symbol_table::resolve(arg) else {
+                return Ok(());
+            };
+            let symbol = symbol.found;
+            let SymbolKind::Parameter(ref x) = symbol.kind else {
+                return Ok(());
+            };
+            if !matches!(x.kind, ParameterKind::Const) {
+                return Ok(());
+            }
+
+            let range: TokenRange = arg.into();
+            let token = range.beg;
+            let declared_after = symbol.token.source == token.source
+                && (symbol.token.line, symbol.token.column) > (token.line, token.column);
+            let in_scope = namespace_table::get(token.id)
+                .map(|x| x.included(&symbol.namespace))
+                .unwrap_or(false);
+            if !declared_after || !in_scope {
+                return Ok(());
+            }
+
+            // const declarations directly in module, interface and package are
+            // emitted before their uses in the body if reorder_consts is enabled
+            let reordered = self.reorder_consts && !self.in_header && symbol.namespace.depth() == 2;
+            if !reordered {
+                self.errors.push(AnalyzerError::use_before_declaration(
+                    &symbol.token.to_string(),
+                    self.text,
+                    &range,
+                    &symbol.token.into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn with_generic_parameter(&mut self, _arg: &WithGenericParameter) -> Result<(), ParolError> {
+        self.header();
+        Ok(())
+    }
+
+    fn with_parameter(&mut self, _arg: &WithParameter) -> Result<(), ParolError> {
+        self.header();
+        Ok(())
+    }
+
+    fn port_declaration(&mut self, _arg: &PortDeclaration) -> Result<(), ParolError> {
+        self.header();
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, _arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.function_depth += 1,
+            HandlerPoint::After => self.function_depth -= 1,
+        }
+        Ok(())
+    }
+}
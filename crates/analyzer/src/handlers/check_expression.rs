@@ -1,9 +1,13 @@
 use crate::analyzer_error::AnalyzerError;
 use crate::evaluator::{Evaluated, Evaluator};
-use crate::symbol::{Direction, GenericBoundKind, SymbolId, SymbolKind};
+use crate::namespace::Namespace;
+use crate::symbol::{
+    Direction, GenericBoundKind, InstanceProperty, SymbolId, SymbolKind, Type, TypeKind,
+    TypeModifier,
+};
 use crate::symbol_table;
 use veryl_parser::veryl_grammar_trait::*;
-use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_token::{Token, TokenRange};
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 use veryl_parser::ParolError;
 
@@ -17,6 +21,9 @@ pub struct CheckExpression<'a> {
     in_inst_declaration: bool,
     port_direction: Option<Direction>,
     in_input_port_default_value: bool,
+    /// The identifier connected to an instance port directly.
+    /// Interfaces can be referred only at this position.
+    port_connection: Option<Token>,
 }
 
 impl<'a> CheckExpression<'a> {
@@ -28,6 +35,251 @@ impl<'a> CheckExpression<'a> {
     }
 }
 
+enum Operand {
+    Variable {
+        r#type: String,
+        width: usize,
+        signed: bool,
+    },
+    Literal {
+        text: String,
+        value: usize,
+        signed: Option<bool>,
+    },
+}
+
+impl Operand {
+    fn name(&self) -> String {
+        match self {
+            Operand::Variable { r#type, .. } => format!("\"{}\"", r#type),
+            Operand::Literal { text, .. } => text.clone(),
+        }
+    }
+
+    fn signed(&self) -> Option<bool> {
+        match self {
+            Operand::Variable { signed, .. } => Some(*signed),
+            Operand::Literal { signed, .. } => *signed,
+        }
+    }
+}
+
+impl CheckExpression<'_> {
+    /// Returns the operand of comparison if its width and value range are known.
+    /// Operands which have operators or casts by `as` are not checked.
+    fn operand(&mut self, arg: &Expression12) -> Option<Operand> {
+        if !arg.expression12_list.is_empty() {
+            return None;
+        }
+        match arg.factor.as_ref() {
+            Factor::Number(x) => {
+                // Base-less literals are signed 32-bit integer in SystemVerilog,
+                // but it is natural to compare them with unsigned operands.
+                let (text, signed) = match x.number.as_ref() {
+                    Number::IntegralNumber(x) => match x.integral_number.as_ref() {
+                        IntegralNumber::Based(x) => {
+                            let text = x.based.based_token.to_string();
+                            let signed = text.contains("'s");
+                            (text, Some(signed))
+                        }
+                        IntegralNumber::BaseLess(x) => {
+                            (x.base_less.base_less_token.to_string(), None)
+                        }
+                        IntegralNumber::AllBit(_) => return None,
+                    },
+                    Number::RealNumber(_) => return None,
+                };
+                if let Evaluated::Fixed { value, .. } = self.evaluator.expression12(arg) {
+                    Some(Operand::Literal {
+                        text,
+                        value: value.try_into().ok()?,
+                        signed,
+                    })
+                } else {
+                    None
+                }
+            }
+            Factor::IdentifierFactor(x) => {
+                let x = &x.identifier_factor;
+                let expid = x.expression_identifier.as_ref();
+                if x.identifier_factor_opt.is_some()
+                    || expid.expression_identifier_opt.is_some()
+                    || !expid.expression_identifier_list.is_empty()
+                    || !expid.expression_identifier_list0.is_empty()
+                {
+                    return None;
+                }
+                let symbol = symbol_table::resolve(expid).ok()?;
+                let r#type = match &symbol.found.kind {
+                    SymbolKind::Variable(x) => x.r#type.clone(),
+                    SymbolKind::Port(x) => x.r#type.clone()?,
+                    _ => return None,
+                };
+                if !r#type.array.is_empty() {
+                    return None;
+                }
+                let signed = is_signed(&r#type);
+                let width = self.evaluator.type_width(r#type.clone())?;
+                Some(Operand::Variable {
+                    r#type: r#type.to_string(),
+                    width,
+                    signed,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn check_comparison(
+        &mut self,
+        left: Option<&Expression12>,
+        right: Option<&Expression12>,
+        operator: &Token,
+    ) {
+        let (left, right) = if let (Some(left), Some(right)) = (left, right) {
+            (left, right)
+        } else {
+            return;
+        };
+        let (left, right) =
+            if let (Some(left), Some(right)) = (self.operand(left), self.operand(right)) {
+                (left, right)
+            } else {
+                return;
+            };
+
+        let operator_text = operator.to_string();
+        let constant = match (&left, &right) {
+            (Operand::Variable { width, signed, .. }, Operand::Literal { value, .. }) => {
+                constant_comparison(*width, *signed, *value, &operator_text, false)
+                    .map(|x| (x, *value))
+            }
+            (Operand::Literal { value, .. }, Operand::Variable { width, signed, .. }) => {
+                constant_comparison(*width, *signed, *value, &operator_text, true)
+                    .map(|x| (x, *value))
+            }
+            _ => None,
+        };
+
+        if let Some((result, value)) = constant {
+            let width = (usize::BITS - value.leading_zeros()).max(1) as usize;
+            self.errors.push(AnalyzerError::constant_comparison(
+                &left.name(),
+                &right.name(),
+                width,
+                result,
+                self.text,
+                &operator.into(),
+            ));
+        } else if let (Some(x), Some(y)) = (left.signed(), right.signed()) {
+            if x != y {
+                self.errors.push(AnalyzerError::mixed_signed_comparison(
+                    &left.name(),
+                    &right.name(),
+                    self.text,
+                    &operator.into(),
+                ));
+            }
+        }
+    }
+}
+
+fn is_signed(arg: &Type) -> bool {
+    matches!(arg.kind, TypeKind::I32 | TypeKind::I64)
+        || arg.modifier.contains(&TypeModifier::Signed)
+}
+
+/// Returns the result of comparison if the literal is out of the range of the variable.
+/// `reversed` means that the literal is placed at the left hand side.
+fn constant_comparison(
+    width: usize,
+    signed: bool,
+    value: usize,
+    operator: &str,
+    reversed: bool,
+) -> Option<bool> {
+    // zero-width variable has no range to compare
+    if width == 0 {
+        return None;
+    }
+
+    let max_width = if signed { width - 1 } else { width };
+    if max_width >= usize::BITS as usize || value < (1 << max_width) {
+        return None;
+    }
+
+    // The literal is larger than any value of the variable
+    let variable_is_less = match operator {
+        "==" | "===" | "==?" => return Some(false),
+        "!=" | "!==" | "!=?" => return Some(true),
+        "<:" | "<=" => true,
+        ">:" | ">=" => false,
+        _ => return None,
+    };
+    Some(variable_is_less != reversed)
+}
+
+fn expression06_operand(arg: &Expression06) -> Option<&Expression12> {
+    if arg.expression06_list.is_empty() {
+        expression07_operand(&arg.expression07)
+    } else {
+        None
+    }
+}
+
+fn expression07_operand(arg: &Expression07) -> Option<&Expression12> {
+    if arg.expression07_list.is_empty() {
+        expression08_operand(&arg.expression08)
+    } else {
+        None
+    }
+}
+
+fn expression08_operand(arg: &Expression08) -> Option<&Expression12> {
+    if arg.expression08_list.is_empty() {
+        expression09_operand(&arg.expression09)
+    } else {
+        None
+    }
+}
+
+fn expression09_operand(arg: &Expression09) -> Option<&Expression12> {
+    if arg.expression09_list.is_empty() {
+        expression10_operand(&arg.expression10)
+    } else {
+        None
+    }
+}
+
+fn expression10_operand(arg: &Expression10) -> Option<&Expression12> {
+    if arg.expression10_list.is_empty() && arg.expression11.expression11_opt.is_none() {
+        Some(&arg.expression11.expression12)
+    } else {
+        None
+    }
+}
+
+impl CheckExpression<'_> {
+    fn is_port_connection(&self, arg: &ExpressionIdentifier) -> bool {
+        self.port_connection
+            .map(|x| x.id == arg.identifier().token.id)
+            .unwrap_or(false)
+    }
+}
+
+fn is_interface_instance(arg: &InstanceProperty, namespace: &Namespace) -> bool {
+    let Ok(symbol) = symbol_table::resolve((&arg.type_name.mangled_path(), namespace)) else {
+        return false;
+    };
+    match symbol.found.kind {
+        SymbolKind::Interface(_) => true,
+        SymbolKind::GenericInstance(x) => symbol_table::get(x.base)
+            .map(|x| matches!(x.kind, SymbolKind::Interface(_)))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
 impl Handler for CheckExpression<'_> {
     fn set_point(&mut self, p: HandlerPoint) {
         self.point = p;
@@ -79,6 +331,34 @@ impl VerylGrammarTrait for CheckExpression<'_> {
         Ok(())
     }
 
+    fn expression05(&mut self, arg: &Expression05) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.expression05_list.len() == 1 {
+                let x = &arg.expression05_list[0];
+                self.check_comparison(
+                    expression06_operand(&arg.expression06),
+                    expression06_operand(&x.expression06),
+                    &x.operator06.operator06_token.token,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn expression06(&mut self, arg: &Expression06) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.expression06_list.len() == 1 {
+                let x = &arg.expression06_list[0];
+                self.check_comparison(
+                    expression07_operand(&arg.expression07),
+                    expression07_operand(&x.expression07),
+                    &x.operator07.operator07_token.token,
+                );
+            }
+        }
+        Ok(())
+    }
+
     fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             let expid = arg.expression_identifier.as_ref();
@@ -92,18 +372,29 @@ impl VerylGrammarTrait for CheckExpression<'_> {
                     || AnalyzerError::invalid_factor(&identifier, &kind_name, self.text, &token);
 
                 match rr.found.kind {
-                    SymbolKind::Function(_) | SymbolKind::ModportFunctionMember(_) => {
-                        if arg.identifier_factor_opt.is_none() {
-                            self.errors.push(error());
-                        }
+                    SymbolKind::Function(_)
+                    | SymbolKind::ModportFunctionMember(_)
+                    | SymbolKind::SystemFunction
+                        if arg.function_call().is_none() =>
+                    {
+                        self.errors.push(error());
                     }
-                    SymbolKind::SystemFunction => {
-                        if arg.identifier_factor_opt.is_none() {
-                            self.errors.push(error());
-                        }
+                    SymbolKind::Function(_)
+                    | SymbolKind::ModportFunctionMember(_)
+                    | SymbolKind::SystemFunction => {}
+                    SymbolKind::Instance(ref x)
+                        if is_interface_instance(x, &rr.found.namespace)
+                            && !self.is_port_connection(expid) =>
+                    {
+                        self.errors.push(AnalyzerError::invalid_interface_value(
+                            &identifier,
+                            self.text,
+                            &token,
+                        ));
                     }
-                    // instance can be used as factor in inst_declaration
-                    SymbolKind::Instance(_) if self.in_inst_declaration => (),
+                    // interface instances can be connected to instance ports
+                    SymbolKind::Instance(ref x)
+                        if is_interface_instance(x, &rr.found.namespace) => {}
                     SymbolKind::Module(_)
                     | SymbolKind::ProtoModule(_)
                     | SymbolKind::Interface(_)
@@ -117,15 +408,16 @@ impl VerylGrammarTrait for CheckExpression<'_> {
                         self.errors.push(error());
                     }
                     SymbolKind::Port(x) => {
-                        if !self.in_inst_declaration {
-                            match x.direction {
-                                Direction::Interface | Direction::Modport => {
-                                    // modport and interface direction can be used as factor in inst_declaration
-                                    self.errors.push(error());
-                                }
-                                _ => {}
+                        if matches!(x.direction, Direction::Interface | Direction::Modport) {
+                            // modport and interface direction can be connected to instance ports only
+                            if !self.is_port_connection(expid) {
+                                self.errors.push(AnalyzerError::invalid_interface_value(
+                                    &identifier,
+                                    self.text,
+                                    &token,
+                                ));
                             }
-                        } else if self.in_input_port_default_value {
+                        } else if self.in_inst_declaration && self.in_input_port_default_value {
                             // port cannot be used for port default value
                             self.errors.push(error());
                         }
@@ -152,7 +444,7 @@ impl VerylGrammarTrait for CheckExpression<'_> {
                 }
             }
 
-            if arg.identifier_factor_opt.is_some() {
+            if arg.function_call().is_some() {
                 // Must be a function call
                 let expid = arg.expression_identifier.as_ref();
                 if let Ok(rr) = symbol_table::resolve(expid) {
@@ -199,6 +491,20 @@ impl VerylGrammarTrait for CheckExpression<'_> {
         Ok(())
     }
 
+    fn inst_port_item(&mut self, arg: &InstPortItem) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.port_connection = arg
+                    .inst_port_item_opt
+                    .as_ref()
+                    .and_then(|x| expression_identifier(&x.expression))
+                    .map(|x| x.identifier().token);
+            }
+            HandlerPoint::After => self.port_connection = None,
+        }
+        Ok(())
+    }
+
     fn inst_declaration(&mut self, _arg: &InstDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => self.in_inst_declaration = true,
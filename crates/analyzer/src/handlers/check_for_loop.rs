@@ -0,0 +1,237 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::SymbolId;
+use crate::symbol_table;
+use veryl_metadata::Lint;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::{ParolError, Stringifier};
+
+const DEFAULT_MAX_FOR_ITERATIONS: usize = 100000;
+
+#[derive(Default)]
+pub struct CheckForLoop<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    evaluator: Evaluator,
+    max_for_iterations: usize,
+    loop_variables: Vec<Option<(SymbolId, String)>>,
+}
+
+impl<'a> CheckForLoop<'a> {
+    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+        Self {
+            text,
+            max_for_iterations: lint_opt
+                .max_for_iterations
+                .unwrap_or(DEFAULT_MAX_FOR_ITERATIONS),
+            ..Default::default()
+        }
+    }
+
+    fn evaluate_bound(&mut self, arg: &Expression) -> (Evaluated, String) {
+        let evaluated = self.evaluator.expression(arg);
+        let text = if let Evaluated::Fixed { value, .. } = evaluated {
+            value.to_string()
+        } else {
+            let mut stringifier = Stringifier::new();
+            stringifier.expression(arg);
+            stringifier.as_str().to_string()
+        };
+        (evaluated, text)
+    }
+
+    fn range_string(&mut self, arg: &Range) -> String {
+        let (_, start) = self.evaluate_bound(&arg.expression);
+        if let Some(x) = &arg.range_opt {
+            let (_, end) = self.evaluate_bound(&x.expression);
+            let operator = match &*x.range_operator {
+                RangeOperator::DotDot(_) => "..",
+                RangeOperator::DotDotEqu(_) => "..=",
+            };
+            format!("{start}{operator}{end}")
+        } else {
+            start
+        }
+    }
+
+    /// Counts iterations as the emitted SystemVerilog loop does.
+    /// `None` means that the count exceeds `max`.
+    fn count_iterations(
+        start: isize,
+        end: isize,
+        inclusive: bool,
+        step: Option<(&str, isize)>,
+        max: usize,
+    ) -> Option<usize> {
+        let (start, end) = (start as i128, end as i128);
+        let mut i = start;
+        let mut count = 0;
+        while if inclusive { i <= end } else { i < end } {
+            count += 1;
+            if count > max {
+                return None;
+            }
+            let next = match step {
+                None => i.checked_add(1),
+                Some(("+=", x)) => i.checked_add(x as i128),
+                Some(("-=", x)) => i.checked_sub(x as i128),
+                Some(("*=", x)) => i.checked_mul(x as i128),
+                Some(("/=", x)) => i.checked_div(x as i128),
+                Some(("<<=", x)) | Some(("<<<=", x)) => {
+                    u32::try_from(x).ok().and_then(|x| i.checked_shl(x))
+                }
+                Some((">>=", x)) | Some((">>>=", x)) => {
+                    u32::try_from(x).ok().and_then(|x| i.checked_shr(x))
+                }
+                // the other operators are not checked
+                Some(_) => return Some(count),
+            };
+            match next {
+                // the loop variable overflows
+                None => return Some(count),
+                // the loop doesn't progress
+                Some(x) if x == i => return None,
+                Some(x) => i = x,
+            }
+        }
+        Some(count)
+    }
+
+    fn check_generate_for(&mut self, arg: &GenerateForDeclaration) {
+        let start = self.evaluator.expression(&arg.range.expression);
+        let (end, inclusive) = if let Some(x) = &arg.range.range_opt {
+            let end = self.evaluator.expression(&x.expression);
+            let inclusive = matches!(&*x.range_operator, RangeOperator::DotDotEqu(_));
+            (end, inclusive)
+        } else {
+            (start, true)
+        };
+
+        let non_elaborative = |x: &Evaluated| matches!(x, Evaluated::Variable { .. });
+        if non_elaborative(&start) {
+            self.errors
+                .push(AnalyzerError::invalid_for_range_non_elaborative(
+                    self.text,
+                    &arg.range.expression.as_ref().into(),
+                ));
+            return;
+        }
+        if let Some(x) = &arg.range.range_opt {
+            if non_elaborative(&end) {
+                self.errors
+                    .push(AnalyzerError::invalid_for_range_non_elaborative(
+                        self.text,
+                        &x.expression.as_ref().into(),
+                    ));
+                return;
+            }
+        }
+
+        let step = if let Some(x) = &arg.generate_for_declaration_opt {
+            let evaluated = self.evaluator.expression(&x.expression);
+            if non_elaborative(&evaluated) {
+                self.errors
+                    .push(AnalyzerError::invalid_for_range_non_elaborative(
+                        self.text,
+                        &x.expression.as_ref().into(),
+                    ));
+                return;
+            }
+            if let Evaluated::Fixed { value, .. } = evaluated {
+                Some((
+                    x.assignment_operator.assignment_operator_token.to_string(),
+                    value,
+                ))
+            } else {
+                return;
+            }
+        } else {
+            None
+        };
+
+        if let (Evaluated::Fixed { value: start, .. }, Evaluated::Fixed { value: end, .. }) =
+            (start, end)
+        {
+            let max = self.max_for_iterations;
+            let step = step.as_ref().map(|(op, x)| (op.as_str(), *x));
+            let count = Self::count_iterations(start, end, inclusive, step, max);
+            if matches!(count, Some(0) | None) {
+                let range = self.range_string(&arg.range);
+                let beg: TokenRange = arg.range.expression.as_ref().into();
+                let end: TokenRange = if let Some(x) = &arg.range.range_opt {
+                    x.expression.as_ref().into()
+                } else {
+                    beg
+                };
+                let token = TokenRange {
+                    beg: beg.beg,
+                    end: end.end,
+                };
+                self.errors.push(AnalyzerError::invalid_for_iteration_count(
+                    &range, count, max, self.text, &token,
+                ));
+            }
+        }
+    }
+}
+
+impl Handler for CheckForLoop<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckForLoop<'_> {
+    fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_generate_for(arg);
+        }
+        Ok(())
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let range = self.range_string(&arg.range);
+                let id = symbol_table::resolve(arg.identifier.as_ref())
+                    .ok()
+                    .map(|x| (x.found.id, range));
+                self.loop_variables.push(id);
+            }
+            HandlerPoint::After => {
+                self.loop_variables.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !matches!(
+                &*arg.identifier_statement_group,
+                IdentifierStatementGroup::Assignment(_)
+            ) {
+                return Ok(());
+            }
+            if let Ok(symbol) = symbol_table::resolve(arg.expression_identifier.as_ref()) {
+                let found = self
+                    .loop_variables
+                    .iter()
+                    .flatten()
+                    .find(|(id, _)| *id == symbol.found.id);
+                if let Some((_, range)) = found {
+                    self.errors.push(AnalyzerError::for_index_assignment(
+                        &symbol.found.token.to_string(),
+                        range,
+                        self.text,
+                        &arg.expression_identifier.as_ref().into(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,28 @@
 use crate::analyzer_error::AnalyzerError;
-use crate::symbol::SymbolKind;
+use crate::attribute::Attribute as Attr;
+use crate::attribute_table;
+use crate::namespace::Namespace;
+use crate::symbol::{Direction, Symbol, SymbolId, SymbolKind, Type, TypeKind};
 use crate::symbol_path::SymbolPath;
 use crate::symbol_table;
+use std::collections::{HashMap, HashSet};
+use veryl_parser::resource_table::TokenId;
 use veryl_parser::veryl_grammar_trait::*;
-use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 use veryl_parser::ParolError;
 
 pub struct CheckFunction<'a> {
     pub errors: Vec<AnalyzerError>,
     text: &'a str,
     point: HandlerPoint,
+    in_comb: bool,
+    functions: Vec<Option<(SymbolId, Namespace)>>,
+    declared: HashSet<SymbolId>,
+    impure: HashSet<SymbolId>,
+    callees: HashMap<SymbolId, Vec<SymbolId>>,
+    unused_returns: Vec<(String, SymbolId, TokenRange)>,
+    side_effect_calls: HashSet<TokenId>,
 }
 
 impl<'a> CheckFunction<'a> {
@@ -18,8 +31,122 @@ impl<'a> CheckFunction<'a> {
             errors: Vec::new(),
             text,
             point: HandlerPoint::Before,
+            in_comb: false,
+            functions: Vec::new(),
+            declared: HashSet::new(),
+            impure: HashSet::new(),
+            callees: HashMap::new(),
+            unused_returns: Vec::new(),
+            side_effect_calls: HashSet::new(),
         }
     }
+
+    /// Returns whether the function reads and writes no state outside of it.
+    /// Functions declared in other files are not pure because their bodies are unknown.
+    fn is_pure(&self, id: SymbolId, visiting: &mut HashSet<SymbolId>) -> bool {
+        if !self.declared.contains(&id) || self.impure.contains(&id) {
+            return false;
+        }
+        if !visiting.insert(id) {
+            return true;
+        }
+        self.callees
+            .get(&id)
+            .map(|x| x.iter().all(|x| self.is_pure(*x, visiting)))
+            .unwrap_or(true)
+    }
+
+    /// Checks function calls in `arg` which is emitted as a pure expression like conditions
+    fn check_side_effect(&mut self, arg: &Expression, context: &str) {
+        for call in FunctionCallCollector::collect(arg) {
+            // nested contexts like if expression in condition are checked once
+            let token = call.identifier().token;
+            if !self.side_effect_calls.insert(token.id) {
+                continue;
+            }
+            let Ok(symbol) = symbol_table::resolve(&call) else {
+                continue;
+            };
+            let function = match symbol.found.kind {
+                SymbolKind::Function(x) => x,
+                SymbolKind::ModportFunctionMember(x) => {
+                    if let SymbolKind::Function(x) = symbol_table::get(x.function).unwrap().kind {
+                        x
+                    } else {
+                        continue;
+                    }
+                }
+                _ => continue,
+            };
+            let name = symbol.found.token.to_string();
+            for port in &function.ports {
+                let direction = port.property().direction;
+                if matches!(direction, Direction::Output | Direction::Inout) {
+                    self.errors.push(AnalyzerError::side_effect_in_expression(
+                        &name,
+                        &direction.to_string(),
+                        &port.name().to_string(),
+                        context,
+                        self.text,
+                        &(&call).into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn check_dpi_call(&mut self, symbol: &Symbol, arg: &ExpressionIdentifier) {
+        if self.in_comb && attribute_table::contains(&symbol.token, Attr::DpiImport) {
+            self.errors.push(AnalyzerError::dpi_call_in_always_comb(
+                &symbol.token.to_string(),
+                self.text,
+                &arg.into(),
+            ));
+        }
+    }
+}
+
+#[derive(Default)]
+struct FunctionCallCollector {
+    calls: Vec<ExpressionIdentifier>,
+}
+
+impl FunctionCallCollector {
+    fn collect(arg: &Expression) -> Vec<ExpressionIdentifier> {
+        let mut collector = FunctionCallCollector::default();
+        collector.expression(arg);
+        collector.calls
+    }
+}
+
+impl VerylWalker for FunctionCallCollector {
+    /// Semantic action for non-terminal 'IdentifierFactor'
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) {
+        self.expression_identifier(&arg.expression_identifier);
+        if let Some(x) = arg.function_call() {
+            self.calls.push(arg.expression_identifier.as_ref().clone());
+            self.function_call(x);
+        } else if let Some(x) = arg.struct_constructor() {
+            self.struct_constructor(x);
+        }
+    }
+}
+
+fn is_dpi_compatible(r#type: &Type) -> bool {
+    r#type.width.is_empty()
+        && r#type.array.is_empty()
+        && matches!(
+            r#type.kind,
+            TypeKind::Bit
+                | TypeKind::Logic
+                | TypeKind::U32
+                | TypeKind::U64
+                | TypeKind::I32
+                | TypeKind::I64
+                | TypeKind::F32
+                | TypeKind::F64
+                | TypeKind::String
+        )
 }
 
 impl Handler for CheckFunction<'_> {
@@ -29,6 +156,181 @@ impl Handler for CheckFunction<'_> {
 }
 
 impl VerylGrammarTrait for CheckFunction<'_> {
+    fn veryl(&mut self, _arg: &Veryl) -> Result<(), ParolError> {
+        if let HandlerPoint::After = self.point {
+            for (name, id, range) in std::mem::take(&mut self.unused_returns) {
+                let no_effect = self.is_pure(id, &mut HashSet::new());
+                self.errors.push(AnalyzerError::unused_return_value(
+                    &name, no_effect, self.text, &range,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::After = self.point {
+            self.functions.pop();
+        }
+
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.identifier.identifier_token.token;
+            let dpi_import = attribute_table::contains(token, Attr::DpiImport);
+            let dpi_export = attribute_table::contains(token, Attr::DpiExport);
+
+            let function = symbol_table::resolve(arg.identifier.as_ref())
+                .ok()
+                .map(|x| (x.found.id, x.found.inner_namespace()));
+            if let Some((id, _)) = function {
+                self.declared.insert(id);
+                if dpi_import {
+                    self.impure.insert(id);
+                }
+            }
+            self.functions.push(function);
+
+            if !dpi_import && !dpi_export {
+                return Ok(());
+            }
+
+            let name = token.to_string();
+            if dpi_import && !arg.statement_block.statement_block_list.is_empty() {
+                self.errors.push(AnalyzerError::invalid_dpi_import_body(
+                    &name,
+                    self.text,
+                    &arg.identifier.as_ref().into(),
+                ));
+            }
+
+            if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+                if let SymbolKind::Function(x) = symbol.found.kind {
+                    for port in &x.ports {
+                        let property = port.property();
+                        let valid_direction = matches!(
+                            property.direction,
+                            Direction::Input | Direction::Output | Direction::Inout
+                        );
+                        let valid_type = property
+                            .r#type
+                            .as_ref()
+                            .map(is_dpi_compatible)
+                            .unwrap_or(false);
+                        if !valid_direction || !valid_type {
+                            self.errors.push(AnalyzerError::invalid_dpi_type(
+                                &port.name().to_string(),
+                                &name,
+                                self.text,
+                                &port.token.token.into(),
+                            ));
+                        }
+                    }
+
+                    if let (Some(ret), Some(opt)) = (&x.ret, &arg.function_declaration_opt1) {
+                        if !is_dpi_compatible(ret) {
+                            self.errors.push(AnalyzerError::invalid_dpi_type(
+                                "return type",
+                                &name,
+                                self.text,
+                                &opt.scalar_type.as_ref().into(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_side_effect(&arg.expression, "condition");
+            for x in &arg.if_statement_list {
+                self.check_side_effect(&x.expression, "condition");
+            }
+        }
+        Ok(())
+    }
+
+    fn if_reset_statement(&mut self, arg: &IfResetStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            for x in &arg.if_reset_statement_list {
+                self.check_side_effect(&x.expression, "condition");
+            }
+        }
+        Ok(())
+    }
+
+    fn if_expression(&mut self, arg: &IfExpression) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_side_effect(&arg.expression, "condition");
+            for x in &arg.if_expression_list {
+                self.check_side_effect(&x.expression, "condition");
+            }
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_side_effect(&arg.expression, "condition");
+        }
+        Ok(())
+    }
+
+    fn generate_if_declaration(&mut self, arg: &GenerateIfDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_side_effect(&arg.expression, "condition");
+            for x in &arg.generate_if_declaration_list {
+                self.check_side_effect(&x.expression, "condition");
+            }
+        }
+        Ok(())
+    }
+
+    fn width(&mut self, arg: &Width) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_side_effect(&arg.expression, "width");
+            for x in &arg.width_list {
+                self.check_side_effect(&x.expression, "width");
+            }
+        }
+        Ok(())
+    }
+
+    fn array(&mut self, arg: &Array) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_side_effect(&arg.expression, "width");
+            for x in &arg.array_list {
+                self.check_side_effect(&x.expression, "width");
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_port_item(&mut self, arg: &InstPortItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.inst_port_item_opt {
+                self.check_side_effect(&x.expression, "port connection");
+            }
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        self.in_comb = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, _arg: &AssignDeclaration) -> Result<(), ParolError> {
+        self.in_comb = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, _arg: &LetDeclaration) -> Result<(), ParolError> {
+        self.in_comb = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
     fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             if let IdentifierStatementGroup::FunctionCall(_) = &*arg.identifier_statement_group {
@@ -51,8 +353,16 @@ impl VerylGrammarTrait for CheckFunction<'_> {
                         }
                         _ => return Ok(()),
                     };
+                    self.check_dpi_call(&function_symbol, &arg.expression_identifier);
                     if let SymbolKind::Function(x) = function_symbol.kind {
-                        if x.ret.is_some() {
+                        // the call may be intended for its output ports
+                        let has_output = x.ports.iter().any(|x| {
+                            matches!(
+                                x.property().direction,
+                                Direction::Output | Direction::Inout | Direction::Ref
+                            )
+                        });
+                        if x.ret.is_some() && !has_output {
                             let name = format!(
                                 "{}",
                                 SymbolPath::from(arg.expression_identifier.as_ref())
@@ -60,10 +370,10 @@ impl VerylGrammarTrait for CheckFunction<'_> {
                                     .last()
                                     .unwrap()
                             );
-                            self.errors.push(AnalyzerError::unused_return(
-                                &name,
-                                self.text,
-                                &arg.expression_identifier.as_ref().into(),
+                            self.unused_returns.push((
+                                name,
+                                function_symbol.id,
+                                arg.expression_identifier.as_ref().into(),
                             ));
                         }
                     }
@@ -74,10 +384,49 @@ impl VerylGrammarTrait for CheckFunction<'_> {
         Ok(())
     }
 
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let Some(Some((function, namespace))) = self.functions.last() else {
+                return Ok(());
+            };
+            let function = *function;
+
+            // system functions may have side effects
+            if matches!(
+                arg.scoped_identifier.scoped_identifier_group.as_ref(),
+                ScopedIdentifierGroup::DollarIdentifier(_)
+            ) {
+                self.impure.insert(function);
+                return Ok(());
+            }
+
+            if let Ok(symbol) = symbol_table::resolve(arg) {
+                match symbol.found.kind {
+                    SymbolKind::Variable(_) | SymbolKind::Port(_)
+                        if !symbol.found.namespace.included(namespace) =>
+                    {
+                        self.impure.insert(function);
+                    }
+                    SymbolKind::Function(_) => {
+                        self.callees
+                            .entry(function)
+                            .or_default()
+                            .push(symbol.found.id);
+                    }
+                    SymbolKind::ModportFunctionMember(_) => {
+                        self.impure.insert(function);
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             // not function call
-            if arg.identifier_factor_opt.is_none() {
+            if arg.function_call().is_none() {
                 return Ok(());
             }
             // skip system function
@@ -92,6 +441,10 @@ impl VerylGrammarTrait for CheckFunction<'_> {
             }
 
             if let Ok(symbol) = symbol_table::resolve(arg.expression_identifier.as_ref()) {
+                if let SymbolKind::Function(_) = symbol.found.kind {
+                    self.check_dpi_call(&symbol.found, &arg.expression_identifier);
+                }
+
                 let arity = match symbol.found.kind {
                     SymbolKind::Function(x) => Some(x.ports.len()),
                     SymbolKind::ModportFunctionMember(x) => {
@@ -106,8 +459,8 @@ impl VerylGrammarTrait for CheckFunction<'_> {
                 };
 
                 let mut args = 0;
-                if let Some(ref x) = arg.identifier_factor_opt {
-                    if let Some(ref x) = x.function_call.function_call_opt {
+                if let Some(x) = arg.function_call() {
+                    if let Some(ref x) = x.function_call_opt {
                         args += 1;
                         args += x.argument_list.argument_list_list.len();
                     }
@@ -0,0 +1,126 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::namespace::Namespace;
+use crate::symbol::{SymbolId, SymbolKind};
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+use veryl_parser::Stringifier;
+
+#[derive(Default)]
+pub struct CheckHierarchicalReference<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    namespace: Option<Namespace>,
+}
+
+impl<'a> CheckHierarchicalReference<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn enter(&mut self, identifier: &Identifier) {
+        if let Ok(symbol) = symbol_table::resolve(identifier) {
+            self.namespace = Some(symbol.found.inner_namespace());
+        }
+    }
+
+    /// Reports the first instance in the path which is not declared in the current module,
+    /// like `top.u_core.signal` or `u_sub.u_leaf.signal`.
+    fn check(&mut self, full_path: &[SymbolId], tokens: &[Token], path: &str) {
+        let Some(namespace) = &self.namespace else {
+            return;
+        };
+
+        for id in full_path {
+            let Some(symbol) = symbol_table::get(*id) else {
+                continue;
+            };
+            if !matches!(symbol.kind, SymbolKind::Instance(_))
+                || symbol.namespace.included(namespace)
+            {
+                continue;
+            }
+
+            if let Some(token) = tokens.iter().find(|x| x.text == symbol.token.text) {
+                self.errors.push(AnalyzerError::hierarchical_reference(
+                    &token.to_string(),
+                    path,
+                    self.text,
+                    &token.into(),
+                ));
+            }
+            return;
+        }
+    }
+}
+
+impl Handler for CheckHierarchicalReference<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckHierarchicalReference<'_> {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.namespace.is_none() {
+                return Ok(());
+            }
+            if let Ok(symbol) = symbol_table::resolve(arg) {
+                let mut tokens = vec![arg.identifier().token];
+                for x in &arg.scoped_identifier.scoped_identifier_list {
+                    tokens.push(x.identifier.identifier_token.token);
+                }
+                for x in &arg.expression_identifier_list0 {
+                    tokens.push(x.identifier.identifier_token.token);
+                }
+
+                let mut stringifier = Stringifier::new();
+                stringifier.expression_identifier(arg);
+                self.check(&symbol.full_path, &tokens, stringifier.as_str());
+            }
+        }
+        Ok(())
+    }
+
+    fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.namespace.is_none() {
+                return Ok(());
+            }
+            if let Ok(symbol) = symbol_table::resolve(arg) {
+                let mut tokens = vec![arg.identifier.identifier_token.token];
+                for x in &arg.hierarchical_identifier_list0 {
+                    tokens.push(x.identifier.identifier_token.token);
+                }
+
+                let mut stringifier = Stringifier::new();
+                stringifier.hierarchical_identifier(arg);
+                self.check(&symbol.full_path, &tokens, stringifier.as_str());
+            }
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.enter(&arg.identifier),
+            HandlerPoint::After => self.namespace = None,
+        }
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.enter(&arg.identifier),
+            HandlerPoint::After => self.namespace = None,
+        }
+        Ok(())
+    }
+}
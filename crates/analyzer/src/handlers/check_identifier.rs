@@ -20,6 +20,7 @@ pub struct CheckIdentifier<'a> {
 enum Kind {
     ClockDomain,
     Enum,
+    EnumMember,
     Function,
     FunctionInout,
     FunctionInput,
@@ -31,10 +32,12 @@ enum Kind {
     Module,
     Package,
     Parameter,
+    PortClock,
     PortInout,
     PortInput,
     PortModport,
     PortOutput,
+    PortReset,
     Reg,
     Struct,
     Union,
@@ -61,6 +64,7 @@ impl<'a> CheckIdentifier<'a> {
         let prefix = match kind {
             Kind::ClockDomain => &opt.prefix_clock_domain,
             Kind::Enum => &opt.prefix_enum,
+            Kind::EnumMember => &opt.prefix_enum_member,
             Kind::Function => &opt.prefix_function,
             Kind::FunctionInout => &opt.prefix_function_inout,
             Kind::FunctionInput => &opt.prefix_function_input,
@@ -72,10 +76,12 @@ impl<'a> CheckIdentifier<'a> {
             Kind::Module => &opt.prefix_module,
             Kind::Package => &opt.prefix_package,
             Kind::Parameter => &opt.prefix_parameter,
+            Kind::PortClock => &opt.prefix_port_clock,
             Kind::PortInout => &opt.prefix_port_inout,
             Kind::PortInput => &opt.prefix_port_input,
             Kind::PortModport => &opt.prefix_port_modport,
             Kind::PortOutput => &opt.prefix_port_output,
+            Kind::PortReset => &opt.prefix_port_reset,
             Kind::Reg => &opt.prefix_reg,
             Kind::Struct => &opt.prefix_struct,
             Kind::Union => &opt.prefix_union,
@@ -86,6 +92,7 @@ impl<'a> CheckIdentifier<'a> {
         let suffix = match kind {
             Kind::ClockDomain => &opt.suffix_clock_domain,
             Kind::Enum => &opt.suffix_enum,
+            Kind::EnumMember => &opt.suffix_enum_member,
             Kind::Function => &opt.suffix_function,
             Kind::FunctionInout => &opt.suffix_function_inout,
             Kind::FunctionInput => &opt.suffix_function_input,
@@ -97,10 +104,12 @@ impl<'a> CheckIdentifier<'a> {
             Kind::Module => &opt.suffix_module,
             Kind::Package => &opt.suffix_package,
             Kind::Parameter => &opt.suffix_parameter,
+            Kind::PortClock => &opt.suffix_port_clock,
             Kind::PortInout => &opt.suffix_port_inout,
             Kind::PortInput => &opt.suffix_port_input,
             Kind::PortModport => &opt.suffix_port_modport,
             Kind::PortOutput => &opt.suffix_port_output,
+            Kind::PortReset => &opt.suffix_port_reset,
             Kind::Reg => &opt.suffix_reg,
             Kind::Struct => &opt.suffix_struct,
             Kind::Union => &opt.suffix_union,
@@ -111,6 +120,7 @@ impl<'a> CheckIdentifier<'a> {
         let case = match kind {
             Kind::ClockDomain => &opt.case_clock_domain,
             Kind::Enum => &opt.case_enum,
+            Kind::EnumMember => &opt.case_enum_member,
             Kind::Function => &opt.case_function,
             Kind::FunctionInout => &opt.case_function_inout,
             Kind::FunctionInput => &opt.case_function_input,
@@ -122,10 +132,12 @@ impl<'a> CheckIdentifier<'a> {
             Kind::Module => &opt.case_module,
             Kind::Package => &opt.case_package,
             Kind::Parameter => &opt.case_parameter,
+            Kind::PortClock => &opt.case_port_clock,
             Kind::PortInout => &opt.case_port_inout,
             Kind::PortInput => &opt.case_port_input,
             Kind::PortModport => &opt.case_port_modport,
             Kind::PortOutput => &opt.case_port_output,
+            Kind::PortReset => &opt.case_port_reset,
             Kind::Reg => &opt.case_reg,
             Kind::Struct => &opt.case_struct,
             Kind::Union => &opt.case_union,
@@ -136,6 +148,7 @@ impl<'a> CheckIdentifier<'a> {
         let re_required = match kind {
             Kind::ClockDomain => &opt.re_required_clock_domain,
             Kind::Enum => &opt.re_required_enum,
+            Kind::EnumMember => &opt.re_required_enum_member,
             Kind::Function => &opt.re_required_function,
             Kind::FunctionInout => &opt.re_required_function_inout,
             Kind::FunctionInput => &opt.re_required_function_input,
@@ -147,10 +160,12 @@ impl<'a> CheckIdentifier<'a> {
             Kind::Module => &opt.re_required_module,
             Kind::Package => &opt.re_required_package,
             Kind::Parameter => &opt.re_required_parameter,
+            Kind::PortClock => &opt.re_required_port_clock,
             Kind::PortInout => &opt.re_required_port_inout,
             Kind::PortInput => &opt.re_required_port_input,
             Kind::PortModport => &opt.re_required_port_modport,
             Kind::PortOutput => &opt.re_required_port_output,
+            Kind::PortReset => &opt.re_required_port_reset,
             Kind::Reg => &opt.re_required_reg,
             Kind::Struct => &opt.re_required_struct,
             Kind::Union => &opt.re_required_union,
@@ -161,6 +176,7 @@ impl<'a> CheckIdentifier<'a> {
         let re_forbidden = match kind {
             Kind::ClockDomain => &opt.re_forbidden_clock_domain,
             Kind::Enum => &opt.re_forbidden_enum,
+            Kind::EnumMember => &opt.re_forbidden_enum_member,
             Kind::Function => &opt.re_forbidden_function,
             Kind::FunctionInout => &opt.re_forbidden_function_inout,
             Kind::FunctionInput => &opt.re_forbidden_function_input,
@@ -172,10 +188,12 @@ impl<'a> CheckIdentifier<'a> {
             Kind::Module => &opt.re_forbidden_module,
             Kind::Package => &opt.re_forbidden_package,
             Kind::Parameter => &opt.re_forbidden_parameter,
+            Kind::PortClock => &opt.re_forbidden_port_clock,
             Kind::PortInout => &opt.re_forbidden_port_inout,
             Kind::PortInput => &opt.re_forbidden_port_input,
             Kind::PortModport => &opt.re_forbidden_port_modport,
             Kind::PortOutput => &opt.re_forbidden_port_output,
+            Kind::PortReset => &opt.re_forbidden_port_reset,
             Kind::Reg => &opt.re_forbidden_reg,
             Kind::Struct => &opt.re_forbidden_struct,
             Kind::Union => &opt.re_forbidden_union,
@@ -335,6 +353,13 @@ impl VerylGrammarTrait for CheckIdentifier<'_> {
         Ok(())
     }
 
+    fn enum_item(&mut self, arg: &EnumItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token.token, Kind::EnumMember);
+        }
+        Ok(())
+    }
+
     fn struct_union_declaration(&mut self, arg: &StructUnionDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
             match &*arg.struct_union {
@@ -345,7 +370,6 @@ impl VerylGrammarTrait for CheckIdentifier<'_> {
                     self.check(&arg.identifier.identifier_token.token, Kind::Union);
                 }
             }
-            self.check(&arg.identifier.identifier_token.token, Kind::Struct);
         }
         Ok(())
     }
@@ -395,6 +419,12 @@ impl VerylGrammarTrait for CheckIdentifier<'_> {
             if let Some(kind) = kind {
                 self.check(&arg.identifier.identifier_token.token, kind);
             }
+
+            if !self.in_function {
+                if let Some(kind) = clock_reset_kind(arg) {
+                    self.check(&arg.identifier.identifier_token.token, kind);
+                }
+            }
         }
         Ok(())
     }
@@ -453,6 +483,39 @@ impl VerylGrammarTrait for CheckIdentifier<'_> {
         }
         Ok(())
     }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check(&arg.identifier.identifier_token.token, Kind::Var)
+        }
+        Ok(())
+    }
+}
+
+/// Returns the kind of clock or reset port which is checked in addition to its direction
+fn clock_reset_kind(arg: &PortDeclarationItem) -> Option<Kind> {
+    let x = match &*arg.port_declaration_item_group {
+        PortDeclarationItemGroup::PortTypeConcrete(x) => &x.port_type_concrete,
+        PortDeclarationItemGroup::PortTypeAbstract(_) => return None,
+    };
+    let x = match &*x.array_type.scalar_type.scalar_type_group {
+        ScalarTypeGroup::FactorType(x) => &x.factor_type,
+        ScalarTypeGroup::UserDefinedTypeScalarTypeOpt(_) => return None,
+    };
+    match &*x.factor_type_group {
+        FactorTypeGroup::VariableTypeFactorTypeOpt(x) => match &*x.variable_type {
+            VariableType::Clock(_)
+            | VariableType::ClockPosedge(_)
+            | VariableType::ClockNegedge(_) => Some(Kind::PortClock),
+            VariableType::Reset(_)
+            | VariableType::ResetAsyncHigh(_)
+            | VariableType::ResetAsyncLow(_)
+            | VariableType::ResetSyncHigh(_)
+            | VariableType::ResetSyncLow(_) => Some(Kind::PortReset),
+            _ => None,
+        },
+        FactorTypeGroup::FixedType(_) => None,
+    }
 }
 
 fn is_lower_camel_case(text: &str) -> bool {
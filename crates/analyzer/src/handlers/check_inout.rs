@@ -0,0 +1,234 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::attribute::Attribute as Attr;
+use crate::attribute_table;
+use crate::symbol::{SymbolId, SymbolKind};
+use crate::symbol_table;
+use std::collections::HashSet;
+use veryl_metadata::Build;
+use veryl_parser::resource_table::StrId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+struct IdentifierCollector {
+    identifiers: Vec<(SymbolId, TokenRange)>,
+}
+
+impl VerylWalker for IdentifierCollector {
+    /// Semantic action for non-terminal 'ExpressionIdentifier'
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if let Ok(symbol) = symbol_table::resolve(arg) {
+            self.identifiers.push((symbol.found.id, arg.into()));
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CheckInout<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    expand_inout: bool,
+    in_pad_module: bool,
+    inouts: Vec<SymbolId>,
+    assigned: HashSet<SymbolId>,
+}
+
+impl<'a> CheckInout<'a> {
+    pub fn new(text: &'a str, build_opt: &'a Build) -> Self {
+        Self {
+            text,
+            expand_inout: build_opt.expand_inout,
+            ..Default::default()
+        }
+    }
+
+    fn error(&mut self, identifier: &str, reason: &str, token: &TokenRange) {
+        self.errors.push(AnalyzerError::unexpandable_inout(
+            identifier, reason, self.text, token,
+        ));
+    }
+
+    fn check_port_connection(
+        &mut self,
+        port: &InstPortItem,
+        child_inouts: &[StrId],
+        is_array: bool,
+    ) {
+        let port_name = port.identifier.identifier_token.token.text;
+        let is_child_inout = child_inouts.contains(&port_name);
+        let (connected, identifiers): (Option<&ExpressionIdentifier>, _) =
+            if let Some(ref x) = port.inst_port_item_opt {
+                let mut collector = IdentifierCollector::default();
+                collector.expression(&x.expression);
+                (expression_identifier(&x.expression), collector.identifiers)
+            } else {
+                let identifiers = symbol_table::resolve(port.identifier.as_ref())
+                    .map(|x| vec![(x.found.id, port.identifier.as_ref().into())])
+                    .unwrap_or_default();
+                (None, identifiers)
+            };
+        let is_plain = port.inst_port_item_opt.is_none()
+            || connected.is_some_and(|x| {
+                x.expression_identifier_list.is_empty() && x.expression_identifier_list0.is_empty()
+            });
+
+        let port_token: TokenRange = port.identifier.as_ref().into();
+        if self.in_pad_module {
+            if is_child_inout {
+                if !is_plain {
+                    self.error(
+                        &port_name.to_string(),
+                        "it is connected to an expression",
+                        &port_token,
+                    );
+                } else if is_array {
+                    self.error(
+                        &port_name.to_string(),
+                        "it is a port of instance array",
+                        &port_token,
+                    );
+                }
+            }
+        } else if is_child_inout {
+            let is_inout =
+                is_plain && identifiers.len() == 1 && self.inouts.contains(&identifiers[0].0);
+            if !is_inout {
+                self.error(
+                    &port_name.to_string(),
+                    "it is connected to a signal other than an inout port",
+                    &port_token,
+                );
+            }
+        } else {
+            for (id, token) in &identifiers {
+                if self.inouts.contains(id) {
+                    let name = symbol_table::get(*id).unwrap().token.to_string();
+                    self.error(
+                        &name,
+                        "it is connected to a port which is not expanded",
+                        token,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Handler for CheckInout<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckInout<'_> {
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Ok(symbol) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
+                let id = symbol.found.id;
+                if self.inouts.contains(&id) {
+                    let name = symbol.found.token.to_string();
+                    let token: TokenRange = arg.hierarchical_identifier.as_ref().into();
+                    let partial = !arg
+                        .hierarchical_identifier
+                        .hierarchical_identifier_list
+                        .is_empty()
+                        || !arg
+                            .hierarchical_identifier
+                            .hierarchical_identifier_list0
+                            .is_empty();
+
+                    if partial {
+                        self.error(&name, "it is assigned partially", &token);
+                    } else if tristate_expression(&arg.expression).is_none() {
+                        self.error(
+                            &name,
+                            "it is assigned by an expression other than `if enable { value } else { 'z }`",
+                            &token,
+                        );
+                    } else if !self.assigned.insert(id) {
+                        self.error(&name, "it is assigned multiple times", &token);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(_) = arg.identifier_statement_group.as_ref()
+            {
+                if let Ok(symbol) = symbol_table::resolve(arg.expression_identifier.as_ref()) {
+                    if self.inouts.contains(&symbol.found.id) {
+                        self.error(
+                            &symbol.found.token.to_string(),
+                            "it is assigned by a statement",
+                            &arg.expression_identifier.as_ref().into(),
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.expand_inout {
+                return Ok(());
+            }
+
+            let child = symbol_table::resolve(arg.scoped_identifier.as_ref())
+                .ok()
+                .and_then(|x| match x.found.kind {
+                    SymbolKind::GenericInstance(ref x) => symbol_table::get(x.base),
+                    _ => Some(x.found),
+                });
+            let child_inouts: Vec<_> = child
+                .map(|x| {
+                    x.expanded_inout_ports(self.expand_inout)
+                        .iter()
+                        .map(|x| x.name())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if let Some(ref x) = arg.inst_declaration_opt1 {
+                if let Some(ref x) = x.inst_declaration_opt2 {
+                    let ports: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+                    let is_array = arg.inst_declaration_opt.is_some();
+                    for port in &ports {
+                        self.check_port_connection(port, &child_inouts, is_array);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+                    self.inouts = symbol
+                        .found
+                        .expanded_inout_ports(self.expand_inout)
+                        .iter()
+                        .map(|x| x.symbol)
+                        .collect();
+                    self.in_pad_module = self.expand_inout
+                        && attribute_table::contains(&symbol.found.token, Attr::Pad);
+                }
+            }
+            HandlerPoint::After => {
+                self.inouts.clear();
+                self.assigned.clear();
+                self.in_pad_module = false;
+            }
+        }
+        Ok(())
+    }
+}
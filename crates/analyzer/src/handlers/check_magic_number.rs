@@ -0,0 +1,204 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::attribute::Attribute as Attr;
+use crate::attribute_table;
+use veryl_metadata::{Lint, LintMagicNumber, MagicNumberContext};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+pub struct CheckMagicNumber<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    opt: LintMagicNumber,
+    contexts: Vec<MagicNumberContext>,
+    in_testbench: bool,
+    in_const: bool,
+}
+
+impl<'a> CheckMagicNumber<'a> {
+    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+        Self {
+            text,
+            opt: lint_opt.magic_number.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn enter(&mut self, context: MagicNumberContext) {
+        match self.point {
+            HandlerPoint::Before => self.contexts.push(context),
+            HandlerPoint::After => {
+                self.contexts.pop();
+            }
+        }
+    }
+
+    fn check(&mut self, token: &Token, width: Option<usize>, base: u32, number: &str) {
+        if !self.opt.enable || self.in_testbench || self.in_const {
+            return;
+        }
+        let Some(context) = self.contexts.last().copied() else {
+            return;
+        };
+        if self.opt.allow_contexts.contains(&context) {
+            return;
+        }
+
+        let number = number.replace('_', "");
+        let number = number.trim_start_matches('0');
+        // 4-state values are not numbers
+        if number.chars().any(|x| matches!(x, 'x' | 'z' | 'X' | 'Z')) {
+            return;
+        }
+        if number.is_empty() || number == "1" || is_all_ones(number, base, width) {
+            return;
+        }
+        if let Ok(value) = u128::from_str_radix(number, base) {
+            if self.opt.allow_values.iter().any(|x| *x as u128 == value) {
+                return;
+            }
+        }
+
+        let context = match context {
+            MagicNumberContext::Assignment => "assignment",
+            MagicNumberContext::Comparison => "comparison",
+            MagicNumberContext::Index => "index",
+            MagicNumberContext::Width => "width",
+        };
+        self.errors.push(AnalyzerError::magic_number(
+            &token.to_string(),
+            context,
+            self.text,
+            &token.into(),
+        ));
+    }
+}
+
+impl Handler for CheckMagicNumber<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+/// Returns whether all bits of the sized number are 1.
+/// `number` should not contain `_` and leading zeros.
+fn is_all_ones(number: &str, base: u32, width: Option<usize>) -> bool {
+    let Some(width) = width else {
+        return false;
+    };
+    if base == 10 {
+        return width < 128 && number.parse::<u128>() == Ok((1 << width) - 1);
+    }
+
+    let digit_width = base.trailing_zeros() as usize;
+    let bits: String = number
+        .chars()
+        .map(|x| format!("{:0digit_width$b}", x.to_digit(base).unwrap_or(0)))
+        .collect();
+    let bits = bits.trim_start_matches('0');
+    bits.len() == width && bits.chars().all(|x| x == '1')
+}
+
+fn is_testbench(arg: &ModuleDeclaration) -> bool {
+    attribute_table::get(&arg.module.module_token.token)
+        .iter()
+        .any(|x| matches!(x, Attr::Testbench | Attr::Test(..)))
+}
+
+impl VerylGrammarTrait for CheckMagicNumber<'_> {
+    fn based(&mut self, arg: &Based) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.based_token.token;
+            let text = token.to_string();
+            let (width, tail) = text.split_once('\'').unwrap();
+            let width = width.replace('_', "").parse().ok();
+            let tail = tail.strip_prefix('s').unwrap_or(tail);
+            let base = match &tail[0..1] {
+                "b" => 2,
+                "o" => 8,
+                "d" => 10,
+                _ => 16,
+            };
+            self.check(token, width, base, &tail[1..]);
+        }
+        Ok(())
+    }
+
+    fn base_less(&mut self, arg: &BaseLess) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.base_less_token.token;
+            self.check(token, None, 10, &token.to_string());
+        }
+        Ok(())
+    }
+
+    fn expression05(&mut self, arg: &Expression05) -> Result<(), ParolError> {
+        if !arg.expression05_list.is_empty() {
+            self.enter(MagicNumberContext::Comparison);
+        }
+        Ok(())
+    }
+
+    fn expression06(&mut self, arg: &Expression06) -> Result<(), ParolError> {
+        if !arg.expression06_list.is_empty() {
+            self.enter(MagicNumberContext::Comparison);
+        }
+        Ok(())
+    }
+
+    fn select(&mut self, _arg: &Select) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Index);
+        Ok(())
+    }
+
+    fn width(&mut self, _arg: &Width) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Width);
+        Ok(())
+    }
+
+    fn array(&mut self, _arg: &Array) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Width);
+        Ok(())
+    }
+
+    fn assignment(&mut self, _arg: &Assignment) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Assignment);
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, _arg: &AssignDeclaration) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Assignment);
+        Ok(())
+    }
+
+    fn let_statement(&mut self, _arg: &LetStatement) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Assignment);
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, _arg: &LetDeclaration) -> Result<(), ParolError> {
+        self.enter(MagicNumberContext::Assignment);
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, _arg: &WithParameterItem) -> Result<(), ParolError> {
+        self.in_const = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, _arg: &ConstDeclaration) -> Result<(), ParolError> {
+        self.in_const = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        self.in_testbench = match self.point {
+            HandlerPoint::Before => is_testbench(arg),
+            HandlerPoint::After => false,
+        };
+        Ok(())
+    }
+}
@@ -1,7 +1,11 @@
 use crate::analyzer_error::AnalyzerError;
+use crate::namespace_table;
 use crate::symbol::SymbolKind;
 use crate::symbol_table;
+use std::collections::HashMap;
+use veryl_parser::resource_table::StrId;
 use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 use veryl_parser::ParolError;
 
@@ -9,6 +13,9 @@ pub struct CheckModport<'a> {
     pub errors: Vec<AnalyzerError>,
     text: &'a str,
     point: HandlerPoint,
+    interface: Option<StrId>,
+    modport: Option<StrId>,
+    items: HashMap<StrId, TokenRange>,
 }
 
 impl<'a> CheckModport<'a> {
@@ -17,6 +24,9 @@ impl<'a> CheckModport<'a> {
             errors: Vec::new(),
             text,
             point: HandlerPoint::Before,
+            interface: None,
+            modport: None,
+            items: HashMap::new(),
         }
     }
 }
@@ -28,30 +38,82 @@ impl Handler for CheckModport<'_> {
 }
 
 impl VerylGrammarTrait for CheckModport<'_> {
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.interface = Some(arg.identifier.identifier_token.token.text)
+            }
+            HandlerPoint::After => self.interface = None,
+        }
+        Ok(())
+    }
+
+    fn modport_declaration(&mut self, arg: &ModportDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.modport = Some(arg.identifier.identifier_token.token.text);
+            self.items.clear();
+        }
+        Ok(())
+    }
+
     fn modport_item(&mut self, arg: &ModportItem) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
-            if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-                match &*arg.direction {
-                    Direction::Ref(_) | Direction::Modport(_) => {}
-                    Direction::Import(_) => {
-                        if !matches!(symbol.found.kind, SymbolKind::Function(_)) {
-                            self.errors
-                                .push(AnalyzerError::invalid_modport_function_item(
-                                    &arg.identifier.identifier_token.token.to_string(),
-                                    self.text,
-                                    &arg.identifier.as_ref().into(),
-                                ));
-                        }
+            let token = &arg.identifier.identifier_token.token;
+            let range: TokenRange = arg.identifier.as_ref().into();
+
+            // the same member can be included in different modports
+            if let Some(first) = self.items.get(&token.text) {
+                self.errors.push(AnalyzerError::duplicated_modport_item(
+                    &token.to_string(),
+                    &self.modport.map(|x| x.to_string()).unwrap_or_default(),
+                    self.text,
+                    &range,
+                    first,
+                ));
+                return Ok(());
+            }
+            self.items.insert(token.text, range);
+
+            // undefined identifier is reported by create_reference
+            let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) else {
+                return Ok(());
+            };
+
+            // members are declared in the same namespace as the modport
+            let namespace = namespace_table::get(token.id);
+            if namespace.as_ref() != Some(&symbol.found.namespace) {
+                self.errors.push(AnalyzerError::unknown_modport_member(
+                    &token.to_string(),
+                    &self.interface.map(|x| x.to_string()).unwrap_or_default(),
+                    self.text,
+                    &range,
+                ));
+                return Ok(());
+            }
+
+            let definition: TokenRange = symbol.found.token.into();
+            match &*arg.direction {
+                Direction::Ref(_) | Direction::Modport(_) => {}
+                Direction::Import(_) => {
+                    if !matches!(symbol.found.kind, SymbolKind::Function(_)) {
+                        self.errors
+                            .push(AnalyzerError::invalid_modport_function_item(
+                                &token.to_string(),
+                                self.text,
+                                &range,
+                                &definition,
+                            ));
                     }
-                    _ => {
-                        if !matches!(symbol.found.kind, SymbolKind::Variable(_)) {
-                            self.errors
-                                .push(AnalyzerError::invalid_modport_variable_item(
-                                    &arg.identifier.identifier_token.token.to_string(),
-                                    self.text,
-                                    &arg.identifier.as_ref().into(),
-                                ));
-                        }
+                }
+                _ => {
+                    if !matches!(symbol.found.kind, SymbolKind::Variable(_)) {
+                        self.errors
+                            .push(AnalyzerError::invalid_modport_variable_item(
+                                &token.to_string(),
+                                self.text,
+                                &range,
+                                &definition,
+                            ));
                     }
                 }
             }
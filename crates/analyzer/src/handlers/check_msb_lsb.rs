@@ -5,7 +5,10 @@ use crate::symbol::Type as SymType;
 use crate::symbol::{Direction, SymbolKind, TypeKind};
 use crate::symbol_path::{SymbolPath, SymbolPathNamespace};
 use crate::symbol_table;
+use std::collections::HashSet;
+use veryl_parser::resource_table::TokenId;
 use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 use veryl_parser::ParolError;
 
@@ -17,6 +20,7 @@ pub struct CheckMsbLsb<'a> {
     select_dimension: Vec<usize>,
     in_expression_identifier: bool,
     in_select: bool,
+    whole_array: HashSet<TokenId>,
 }
 
 impl<'a> CheckMsbLsb<'a> {
@@ -29,6 +33,66 @@ impl<'a> CheckMsbLsb<'a> {
             select_dimension: Vec::new(),
             in_expression_identifier: false,
             in_select: false,
+            whole_array: HashSet::new(),
+        }
+    }
+
+    /// Marks the expression as a context referring whole array like port connection
+    fn mark_whole_array(&mut self, arg: &Expression) {
+        if let Some(x) = expression_identifier(arg) {
+            self.whole_array.insert(x.identifier().token.id);
+        }
+    }
+
+    /// Checks the number of selects applied to each member of hierarchical identifier.
+    /// `members` is the number of selects for each member separated by `.`.
+    fn check_select_count(
+        &mut self,
+        path: SymbolPathNamespace,
+        members: &[usize],
+        whole_array: bool,
+        token: &TokenRange,
+    ) {
+        let Ok(x) = symbol_table::resolve(path) else {
+            return;
+        };
+        if x.full_path.len() < members.len() {
+            return;
+        }
+
+        let symbols = &x.full_path[x.full_path.len() - members.len()..];
+        for (i, (id, select)) in symbols.iter().zip(members).enumerate() {
+            let symbol = symbol_table::get(*id).unwrap();
+            let r#type = match symbol.kind {
+                SymbolKind::Variable(x) => Some(x.r#type),
+                SymbolKind::Port(x) => x.r#type,
+                SymbolKind::Parameter(x) => Some(x.r#type),
+                SymbolKind::StructMember(x) => Some(x.r#type),
+                SymbolKind::UnionMember(x) => Some(x.r#type),
+                _ => None,
+            };
+            let Some((array, total)) = r#type.and_then(|x| dimension(&x)) else {
+                continue;
+            };
+
+            let identifier = symbol.token.to_string();
+            if *select > total {
+                self.errors.push(AnalyzerError::too_many_select(
+                    &identifier,
+                    total,
+                    *select,
+                    self.text,
+                    token,
+                ));
+            } else if *select < array && (i + 1 < members.len() || !whole_array) {
+                self.errors.push(AnalyzerError::missing_array_index(
+                    &identifier,
+                    array,
+                    *select,
+                    self.text,
+                    token,
+                ));
+            }
         }
     }
 }
@@ -53,6 +117,38 @@ fn trace_type(r#type: &SymType) -> Vec<(SymType, Option<SymbolKind>)> {
     ret
 }
 
+/// Returns the number of array dimensions and the total number of selectable dimensions
+fn dimension(r#type: &SymType) -> Option<(usize, usize)> {
+    let types = trace_type(r#type);
+    let mut array = 0;
+    let mut total = 0;
+    for (t, _) in &types {
+        array += t.array.len();
+        total += t.array.len() + t.width.len();
+    }
+
+    // types without explicit width and user defined packed types can be selected by bit
+    let (t, k) = types.last().unwrap();
+    let packed = match k {
+        Some(SymbolKind::Enum(_) | SymbolKind::Struct(_) | SymbolKind::Union(_)) => 1,
+        Some(_) => return None,
+        None => match t.kind {
+            TypeKind::F32 | TypeKind::F64 | TypeKind::Type | TypeKind::String => return None,
+            TypeKind::UserDefined(_) => return None,
+            _ => t.width.is_empty() as usize,
+        },
+    };
+    Some((array, total + packed))
+}
+
+/// Array literal like `'{default: 0}` can be assigned to whole array
+fn is_array_literal(arg: &Expression) -> bool {
+    matches!(
+        expression_factor(arg),
+        Some(Factor::QuoteLBraceArrayLiteralListRBrace(_))
+    )
+}
+
 impl VerylGrammarTrait for CheckMsbLsb<'_> {
     fn lsb(&mut self, arg: &Lsb) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
@@ -163,6 +259,8 @@ impl VerylGrammarTrait for CheckMsbLsb<'_> {
                     .unwrap()
                     .0
                     .push(arg.identifier_token.token.text);
+                // selects are counted for each member of hierarchical path
+                *self.select_dimension.last_mut().unwrap() = 0;
             }
         }
         Ok(())
@@ -183,9 +281,79 @@ impl VerylGrammarTrait for CheckMsbLsb<'_> {
         Ok(())
     }
 
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            // clock and reset of always_ff are checked by CheckClockReset
+            let whole_array = is_array_literal(&arg.expression);
+            let arg = arg.hierarchical_identifier.as_ref();
+            let mut members = vec![arg.hierarchical_identifier_list.len()];
+            for x in &arg.hierarchical_identifier_list0 {
+                members.push(x.hierarchical_identifier_list0_list.len());
+            }
+            self.check_select_count(arg.into(), &members, whole_array, &arg.into());
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(x) = arg.identifier_statement_group.as_ref()
+            {
+                if is_array_literal(&x.assignment.expression) {
+                    let id = arg.expression_identifier.identifier().token.id;
+                    self.whole_array.insert(id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_port_item(&mut self, arg: &InstPortItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.inst_port_item_opt {
+                self.mark_whole_array(&x.expression);
+            }
+        }
+        Ok(())
+    }
+
+    fn concatenation_item(&mut self, arg: &ConcatenationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.mark_whole_array(&arg.expression);
+        }
+        Ok(())
+    }
+
+    fn array_literal_item(&mut self, arg: &ArrayLiteralItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let ArrayLiteralItemGroup::ExpressionArrayLiteralItemOpt(x) =
+                arg.array_literal_item_group.as_ref()
+            {
+                self.mark_whole_array(&x.expression);
+            }
+        }
+        Ok(())
+    }
+
+    fn argument_item(&mut self, arg: &ArgumentItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.mark_whole_array(&arg.expression);
+        }
+        Ok(())
+    }
+
     fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
+                if arg.expression_identifier_opt.is_none() {
+                    let mut members = vec![arg.expression_identifier_list.len()];
+                    for x in &arg.expression_identifier_list0 {
+                        members.push(x.expression_identifier_list0_list.len());
+                    }
+                    let whole_array = self.whole_array.contains(&arg.identifier().token.id);
+                    self.check_select_count(arg.into(), &members, whole_array, &arg.into());
+                }
+
                 let namespace = namespace_table::get(arg.identifier().token.id).unwrap();
                 let symbol_path = SymbolPath::default();
                 self.identifier_path
@@ -196,7 +364,8 @@ impl VerylGrammarTrait for CheckMsbLsb<'_> {
             HandlerPoint::After => {
                 self.identifier_path.pop();
                 self.select_dimension.pop();
-                self.in_expression_identifier = false;
+                // expression identifier may be nested in select of outer expression identifier
+                self.in_expression_identifier = !self.identifier_path.is_empty();
             }
         }
         Ok(())
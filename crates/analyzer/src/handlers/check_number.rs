@@ -6,6 +6,10 @@ use veryl_parser::ParolError;
 const BINARY_CHARS: [char; 6] = ['0', '1', 'x', 'z', 'X', 'Z'];
 const OCTAL_CHARS: [char; 12] = ['0', '1', '2', '3', '4', '5', '6', '7', 'x', 'z', 'X', 'Z'];
 const DECIMAL_CHARS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+const HEXADECIMAL_CHARS: [char; 26] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'A', 'B', 'C',
+    'D', 'E', 'F', 'x', 'z', 'X', 'Z',
+];
 
 #[derive(Default)]
 pub struct CheckNumber<'a> {
@@ -29,6 +33,40 @@ impl Handler for CheckNumber<'_> {
     }
 }
 
+/// Returns bit width required to represent the decimal number
+fn decimal_width(number: &str) -> usize {
+    // little endian 32bit limbs
+    let mut limbs: Vec<u32> = Vec::new();
+    for x in number.chars() {
+        let mut carry = x.to_digit(10).unwrap() as u64;
+        for limb in limbs.iter_mut() {
+            let value = *limb as u64 * 10 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+    }
+
+    if let Some(x) = limbs.last() {
+        (limbs.len() - 1) * 32 + (32 - x.leading_zeros() as usize)
+    } else {
+        0
+    }
+}
+
+/// Returns bit width required to represent the number.
+/// `number` should not contain `_` and leading zeros.
+fn required_width(number: &str, base: u32) -> usize {
+    let width = if base == 10 {
+        decimal_width(number)
+    } else {
+        strnum_bitwidth::bitwidth(number, base).unwrap()
+    };
+    width.max(1)
+}
+
 impl VerylGrammarTrait for CheckNumber<'_> {
     fn based(&mut self, arg: &Based) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
@@ -47,58 +85,66 @@ impl VerylGrammarTrait for CheckNumber<'_> {
             let number = number.replace('_', "");
             let number = number.trim_start_matches('0');
 
-            let base = match base {
-                "b" => {
-                    if let Some(x) = number.chars().find(|x| !BINARY_CHARS.contains(x)) {
-                        self.errors.push(AnalyzerError::invalid_number_character(
-                            x,
-                            "binary",
-                            self.text,
-                            &token.into(),
-                        ));
-                    }
-                    2
-                }
-                "o" => {
-                    if let Some(x) = number.chars().find(|x| !OCTAL_CHARS.contains(x)) {
-                        self.errors.push(AnalyzerError::invalid_number_character(
-                            x,
-                            "octal",
-                            self.text,
-                            &token.into(),
-                        ));
-                    }
-                    8
-                }
-                "d" => {
-                    if let Some(x) = number.chars().find(|x| !DECIMAL_CHARS.contains(x)) {
-                        self.errors.push(AnalyzerError::invalid_number_character(
-                            x,
-                            "decimal",
-                            self.text,
-                            &token.into(),
-                        ));
-                    }
-                    10
-                }
-                "h" => 16,
+            if width == Some(0) {
+                self.errors
+                    .push(AnalyzerError::zero_width_number(self.text, &token.into()));
+            }
+
+            let (base, kind, chars) = match base {
+                "b" => (2, "binary", BINARY_CHARS.as_slice()),
+                "o" => (8, "octal", OCTAL_CHARS.as_slice()),
+                "d" => (10, "decimal", DECIMAL_CHARS.as_slice()),
+                "h" => (16, "hexadecimal", HEXADECIMAL_CHARS.as_slice()),
                 _ => unreachable!(),
             };
 
-            if let Some(actual_width) = strnum_bitwidth::bitwidth(number, base) {
-                if let Some(width) = width {
-                    if actual_width > width {
-                        self.errors.push(AnalyzerError::too_large_number(
-                            width,
-                            self.text,
-                            &token.into(),
-                        ));
-                    }
+            if let Some(x) = number.chars().find(|x| !chars.contains(x)) {
+                self.errors.push(AnalyzerError::invalid_number_character(
+                    x,
+                    kind,
+                    self.text,
+                    &token.into(),
+                ));
+                return Ok(());
+            }
+
+            let required = required_width(number, base);
+            match width {
+                Some(width) if width != 0 && required > width => {
+                    self.errors.push(AnalyzerError::too_large_number(
+                        width,
+                        required,
+                        self.text,
+                        &token.into(),
+                    ));
                 }
-            } else if width.is_none() {
-                // bitwidth calculation may be failed over 128bit.
-                self.errors.push(AnalyzerError::too_large_number(
-                    128,
+                // unsized decimal number is sized through u128 at emitting
+                None if base == 10 && required > 128 => {
+                    self.errors.push(AnalyzerError::too_large_number(
+                        128,
+                        required,
+                        self.text,
+                        &token.into(),
+                    ));
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn base_less(&mut self, arg: &BaseLess) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.base_less_token.token;
+            let number = token.to_string().replace('_', "");
+            let number = number.trim_start_matches('0');
+
+            // base-less number is emitted as unsized number
+            let required = required_width(number, 10);
+            if required > 32 {
+                self.errors.push(AnalyzerError::large_unsized_number(
+                    required,
                     self.text,
                     &token.into(),
                 ));
@@ -107,4 +153,19 @@ impl VerylGrammarTrait for CheckNumber<'_> {
 
         Ok(())
     }
+
+    fn all_bit(&mut self, arg: &AllBit) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = &arg.all_bit_token.token;
+            let text = token.to_string();
+            let (width, _) = text.split_once('\'').unwrap();
+
+            if !width.is_empty() && width.replace('_', "").parse::<usize>() == Ok(0) {
+                self.errors
+                    .push(AnalyzerError::zero_width_number(self.text, &token.into()));
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,165 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::namespace_table;
+use crate::symbol::{SymbolId, SymbolKind, VariableAffiliation};
+use crate::symbol_table;
+use std::collections::HashMap;
+use veryl_metadata::Lint;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+struct VarDecl {
+    id: SymbolId,
+    range: TokenRange,
+    /// From the identifier to the end of the type
+    body: TokenRange,
+}
+
+struct AssignDecl {
+    id: SymbolId,
+    range: TokenRange,
+    expression: TokenRange,
+}
+
+#[derive(Default)]
+pub struct CheckPreferLet<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    prefer_let: bool,
+    vars: Vec<VarDecl>,
+    assigns: Vec<AssignDecl>,
+}
+
+impl<'a> CheckPreferLet<'a> {
+    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+        Self {
+            text,
+            prefer_let: lint_opt.prefer_let,
+            ..Default::default()
+        }
+    }
+
+    fn source(&self, range: &TokenRange) -> &str {
+        let beg = range.beg.pos as usize;
+        let end = (range.end.pos + range.end.length) as usize;
+        self.text.get(beg..end).unwrap_or("")
+    }
+
+    fn check(&mut self) {
+        if self.vars.is_empty() {
+            return;
+        }
+
+        // Drivers from always blocks, instances and functions are counted too
+        let mut drivers: HashMap<SymbolId, usize> = HashMap::new();
+        for assign in symbol_table::get_assign_list() {
+            if let Some(id) = assign.path.full_path().first() {
+                *drivers.entry(*id).or_default() += 1;
+            }
+        }
+
+        for var in &self.vars {
+            if drivers.get(&var.id) != Some(&1) {
+                continue;
+            }
+            let mut assigns = self.assigns.iter().filter(|x| x.id == var.id);
+            let (Some(assign), None) = (assigns.next(), assigns.next()) else {
+                continue;
+            };
+
+            let fix = format!(
+                "let {} = {};",
+                self.source(&var.body),
+                self.source(&assign.expression)
+            );
+            self.errors.push(AnalyzerError::prefer_let(
+                &var.body.beg.to_string(),
+                &fix,
+                self.text,
+                &var.range,
+                &assign.range,
+            ));
+        }
+    }
+}
+
+impl Handler for CheckPreferLet<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckPreferLet<'_> {
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.prefer_let {
+                return Ok(());
+            }
+
+            let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) else {
+                return Ok(());
+            };
+            if !matches!(
+                symbol.found.kind,
+                SymbolKind::Variable(ref x) if x.affiliation == VariableAffiliation::Module
+            ) {
+                return Ok(());
+            }
+
+            let r#type: TokenRange = arg.array_type.as_ref().into();
+            self.vars.push(VarDecl {
+                id: symbol.found.id,
+                range: TokenRange::new(&arg.var.var_token, &arg.semicolon.semicolon_token),
+                body: TokenRange {
+                    beg: arg.identifier.identifier_token.token,
+                    end: r#type.end,
+                },
+            });
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.prefer_let {
+                return Ok(());
+            }
+
+            // Partial assignment can't be replaced by let
+            let target = &arg.hierarchical_identifier;
+            if !target.hierarchical_identifier_list.is_empty()
+                || !target.hierarchical_identifier_list0.is_empty()
+            {
+                return Ok(());
+            }
+
+            let Ok(symbol) = symbol_table::resolve(target.identifier.as_ref()) else {
+                return Ok(());
+            };
+
+            // let should be declared in the same namespace as var (e.g. not in generate blocks)
+            let token = &target.identifier.identifier_token.token;
+            if namespace_table::get(token.id) != Some(symbol.found.namespace.clone()) {
+                return Ok(());
+            }
+
+            self.assigns.push(AssignDecl {
+                id: symbol.found.id,
+                range: TokenRange::new(&arg.assign.assign_token, &arg.semicolon.semicolon_token),
+                expression: arg.expression.as_ref().into(),
+            });
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, _arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::After = self.point {
+            self.check();
+            self.vars.clear();
+            self.assigns.clear();
+        }
+        Ok(())
+    }
+}
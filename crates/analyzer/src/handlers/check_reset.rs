@@ -0,0 +1,241 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::handlers::check_comb_depth::{signal, SignalCollector};
+use crate::symbol::{Direction, SymbolId, SymbolKind, Type, TypeKind};
+use crate::symbol_table;
+use std::collections::{HashMap, HashSet};
+use veryl_metadata::{Lint, RequireReset};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+pub struct CheckReset<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    require_reset: RequireReset,
+    in_always_comb: bool,
+    in_always_ff: bool,
+    has_reset: bool,
+    conditions: Vec<Vec<SymbolId>>,
+    assigns: Vec<(SymbolId, Token)>,
+    unreset: Vec<(SymbolId, Token)>,
+    dependencies: HashMap<SymbolId, Vec<SymbolId>>,
+}
+
+impl<'a> CheckReset<'a> {
+    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+        Self {
+            text,
+            require_reset: lint_opt.require_reset,
+            ..Default::default()
+        }
+    }
+
+    fn add_dependency(&mut self, target: SymbolId, sources: Vec<SymbolId>) {
+        let entry = self.dependencies.entry(target).or_default();
+        entry.extend(sources);
+        entry.extend(self.conditions.iter().flatten());
+    }
+
+    /// Returns signals driving output ports through combinational logic
+    fn output_drivers(&self) -> HashSet<SymbolId> {
+        let mut ret = HashSet::new();
+        let mut stack: Vec<_> = self
+            .unreset
+            .iter()
+            .map(|(id, _)| *id)
+            .chain(self.dependencies.keys().copied())
+            .filter(|id| is_output(*id))
+            .collect();
+
+        while let Some(id) = stack.pop() {
+            if ret.insert(id) {
+                if let Some(sources) = self.dependencies.get(&id) {
+                    stack.extend(sources.iter().copied());
+                }
+            }
+        }
+        ret
+    }
+
+    fn check(&mut self) {
+        let drivers = if self.require_reset == RequireReset::Control {
+            self.output_drivers()
+        } else {
+            HashSet::new()
+        };
+
+        for (id, token) in &self.unreset {
+            let required = match self.require_reset {
+                RequireReset::None => false,
+                RequireReset::Control => is_enum(*id) || drivers.contains(id),
+                RequireReset::All => true,
+            };
+            if required {
+                let symbol = symbol_table::get(*id).unwrap();
+                self.errors.push(AnalyzerError::missing_reset(
+                    &symbol.token.to_string(),
+                    self.text,
+                    &token.into(),
+                ));
+            }
+        }
+    }
+}
+
+fn is_output(id: SymbolId) -> bool {
+    let Some(symbol) = symbol_table::get(id) else {
+        return false;
+    };
+    matches!(symbol.kind, SymbolKind::Port(ref x) if x.direction == Direction::Output)
+}
+
+fn is_enum(id: SymbolId) -> bool {
+    let Some(symbol) = symbol_table::get(id) else {
+        return false;
+    };
+    let r#type = match symbol.kind {
+        SymbolKind::Variable(ref x) => Some(&x.r#type),
+        SymbolKind::Port(ref x) => x.r#type.as_ref(),
+        _ => None,
+    };
+    let Some(Type {
+        kind: TypeKind::UserDefined(ref x),
+        ..
+    }) = r#type
+    else {
+        return false;
+    };
+    x.symbol
+        .and_then(symbol_table::get)
+        .map(|x| matches!(x.kind, SymbolKind::Enum(_)))
+        .unwrap_or(false)
+}
+
+impl Handler for CheckReset<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckReset<'_> {
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(target) = signal(arg.hierarchical_identifier.as_ref()) {
+                let sources = SignalCollector::collect(&arg.expression);
+                self.add_dependency(target, sources);
+            }
+        }
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(target) = signal(arg.identifier.as_ref()) {
+                let sources = SignalCollector::collect(&arg.expression);
+                self.add_dependency(target, sources);
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, _arg: &AlwaysFfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.in_always_ff = true;
+                self.has_reset = false;
+            }
+            HandlerPoint::After => {
+                self.in_always_ff = false;
+                // signals not reset in if_reset are reported as missing_reset_statement
+                if !self.has_reset {
+                    let mut assigns = std::mem::take(&mut self.assigns);
+                    let mut found = HashSet::new();
+                    assigns.retain(|(id, _)| found.insert(*id));
+                    self.unreset.append(&mut assigns);
+                }
+                self.assigns.clear();
+            }
+        }
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, _arg: &AlwaysCombDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_always_comb = true,
+            HandlerPoint::After => self.in_always_comb = false,
+        }
+        Ok(())
+    }
+
+    fn if_reset_statement(&mut self, _arg: &IfResetStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.in_always_ff {
+                self.has_reset = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let mut conditions = SignalCollector::collect(&arg.expression);
+                    for x in &arg.if_statement_list {
+                        conditions.append(&mut SignalCollector::collect(&x.expression));
+                    }
+                    self.conditions.push(conditions);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if self.in_always_comb {
+            match self.point {
+                HandlerPoint::Before => {
+                    let conditions = SignalCollector::collect(&arg.expression);
+                    self.conditions.push(conditions);
+                }
+                HandlerPoint::After => {
+                    self.conditions.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::Assignment(x) = arg.identifier_statement_group.as_ref()
+            {
+                if let Some(target) = signal(arg.expression_identifier.as_ref()) {
+                    if self.in_always_ff {
+                        let token = arg.expression_identifier.identifier().token;
+                        self.assigns.push((target, token));
+                    } else if self.in_always_comb {
+                        let sources = SignalCollector::collect(&x.assignment.expression);
+                        self.add_dependency(target, sources);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, _arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::After = self.point {
+            self.check();
+            self.unreset.clear();
+            self.dependencies.clear();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::namespace::Namespace;
+use crate::struct_port::flatten_struct_port;
+use crate::symbol::SymbolKind;
+use crate::symbol_path::SymbolPath;
+use crate::symbol_table;
+use std::collections::HashMap;
+use veryl_metadata::Build;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+#[derive(Default)]
+pub struct CheckStructPort<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    flatten_struct_ports: bool,
+}
+
+impl<'a> CheckStructPort<'a> {
+    pub fn new(text: &'a str, build_opt: &'a Build) -> Self {
+        Self {
+            text,
+            flatten_struct_ports: build_opt.flatten_struct_ports,
+            ..Default::default()
+        }
+    }
+
+    fn error(&mut self, identifier: &str, reason: &str, token: &TokenRange) {
+        self.errors.push(AnalyzerError::unflattenable_struct_port(
+            identifier, reason, self.text, token,
+        ));
+    }
+}
+
+impl Handler for CheckStructPort<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+/// Returns the description of the item declared in the module with the name
+fn declared_item(name: &str, namespace: &Namespace) -> Option<String> {
+    let path = SymbolPath::new(&[resource_table::insert_str(name)]);
+    let symbol = symbol_table::resolve((&path, namespace)).ok()?;
+    (symbol.found.namespace == *namespace)
+        .then(|| format!("{} `{name}`", symbol.found.kind.to_kind_name()))
+}
+
+impl VerylGrammarTrait for CheckStructPort<'_> {
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.flatten_struct_ports {
+                return Ok(());
+            }
+            let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) else {
+                return Ok(());
+            };
+            let SymbolKind::Module(ref x) = symbol.found.kind else {
+                return Ok(());
+            };
+
+            // struct-typed ports are emitted as variables of the original names
+            let mut names: HashMap<String, String> = x
+                .ports
+                .iter()
+                .map(|x| {
+                    let name = x.name().to_string();
+                    let name = name.strip_prefix("r#").unwrap_or(&name).to_string();
+                    (name.clone(), format!("port `{name}`"))
+                })
+                .collect();
+            let namespace = symbol.found.inner_namespace();
+
+            for port in symbol
+                .found
+                .flattened_struct_ports(self.flatten_struct_ports)
+            {
+                let identifier = port.token.to_string();
+                let token: TokenRange = port.token.token.into();
+                let elements = match flatten_struct_port(&port) {
+                    Ok(x) => x,
+                    Err(reason) => {
+                        self.error(&identifier, &reason, &token);
+                        continue;
+                    }
+                };
+
+                for element in &elements {
+                    for member in &element.members {
+                        let conflict = names
+                            .get(&member.name)
+                            .cloned()
+                            .or_else(|| declared_item(&member.name, &namespace));
+                        if let Some(conflict) = conflict {
+                            let reason = format!(
+                                "it is flattened into `{}`, which conflicts with {conflict}",
+                                member.name
+                            );
+                            self.error(&identifier, &reason, &token);
+                        } else {
+                            let path = format!("{identifier}{}{}", element.select, member.select);
+                            names.insert(member.name.clone(), format!("`{path}`"));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.flatten_struct_ports {
+                return Ok(());
+            }
+
+            let child = symbol_table::resolve(arg.scoped_identifier.as_ref())
+                .ok()
+                .and_then(|x| match x.found.kind {
+                    SymbolKind::GenericInstance(ref x) => symbol_table::get(x.base),
+                    _ => Some(x.found),
+                });
+            // ports which can't be flattened are reported at the module declaration
+            let child_ports: Vec<_> = child
+                .map(|x| {
+                    x.flattened_struct_ports(self.flatten_struct_ports)
+                        .iter()
+                        .filter(|x| flatten_struct_port(x).is_ok())
+                        .map(|x| x.name())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if let Some(ref x) = arg.inst_declaration_opt1 {
+                if let Some(ref x) = x.inst_declaration_opt2 {
+                    let ports: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+                    for port in &ports {
+                        let port_name = port.identifier.identifier_token.token.text;
+                        if !child_ports.contains(&port_name) {
+                            continue;
+                        }
+
+                        let connected_to_expression = port
+                            .inst_port_item_opt
+                            .as_ref()
+                            .is_some_and(|x| expression_identifier(&x.expression).is_none());
+                        let token: TokenRange = port.identifier.as_ref().into();
+                        if arg.inst_declaration_opt.is_some() {
+                            self.error(
+                                &port_name.to_string(),
+                                "it is a port of instance array",
+                                &token,
+                            );
+                        } else if connected_to_expression {
+                            self.error(
+                                &port_name.to_string(),
+                                "it is connected to an expression",
+                                &token,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::attribute::Attribute as Attr;
+use crate::attribute_table;
+use veryl_metadata::{Lint, SynthesisSubset};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+/// Messages and assertions which synthesis tools ignore
+const SIMULATION_MESSAGES: &[&str] = &[
+    "$display",
+    "$displayb",
+    "$displayh",
+    "$displayo",
+    "$error",
+    "$exit",
+    "$fatal",
+    "$finish",
+    "$info",
+    "$monitor",
+    "$monitorb",
+    "$monitorh",
+    "$monitoro",
+    "$monitoroff",
+    "$monitoron",
+    "$stop",
+    "$strobe",
+    "$strobeb",
+    "$strobeh",
+    "$strobeo",
+    "$warning",
+    "$write",
+    "$writeb",
+    "$writeh",
+    "$writeo",
+];
+
+/// System functions which work only in simulation
+const SIMULATION_ONLY: &[&str] = &[
+    "$assertcontrol",
+    "$assertfailoff",
+    "$assertfailon",
+    "$assertkill",
+    "$assertnonvacuouson",
+    "$assertoff",
+    "$asserton",
+    "$assertpassoff",
+    "$assertpasson",
+    "$assertvacuousoff",
+    "$coverage_control",
+    "$coverage_get",
+    "$coverage_get_max",
+    "$coverage_merge",
+    "$coverage_save",
+    "$dist_chi_square",
+    "$dist_erlang",
+    "$dist_exponential",
+    "$dist_normal",
+    "$dist_poisson",
+    "$dist_t",
+    "$dist_uniform",
+    "$dumpall",
+    "$dumpfile",
+    "$dumpflush",
+    "$dumplimit",
+    "$dumpoff",
+    "$dumpon",
+    "$dumpports",
+    "$dumpportsall",
+    "$dumpportsflush",
+    "$dumpportslimit",
+    "$dumpportsoff",
+    "$dumpportson",
+    "$dumpvars",
+    "$fclose",
+    "$fdisplay",
+    "$fdisplayb",
+    "$fdisplayh",
+    "$fdisplayo",
+    "$feof",
+    "$ferror",
+    "$fflush",
+    "$fgetc",
+    "$fgets",
+    "$fmonitor",
+    "$fmonitorb",
+    "$fmonitorh",
+    "$fmonitoro",
+    "$fopen",
+    "$fread",
+    "$fscanf",
+    "$fseek",
+    "$fstrobe",
+    "$fstrobeb",
+    "$fstrobeh",
+    "$fstrobeo",
+    "$ftell",
+    "$fwrite",
+    "$fwriteb",
+    "$fwriteh",
+    "$fwriteo",
+    "$get_coverage",
+    "$load_coverage_db",
+    "$printtimescale",
+    "$q_add",
+    "$q_exam",
+    "$q_full",
+    "$q_initialize",
+    "$q_remove",
+    "$random",
+    "$realtime",
+    "$rewind",
+    "$set_coverage_db_name",
+    "$sformat",
+    "$sformatf",
+    "$sscanf",
+    "$stime",
+    "$swrite",
+    "$swriteb",
+    "$swriteh",
+    "$swriteo",
+    "$system",
+    "$test$plusargs",
+    "$time",
+    "$timeformat",
+    "$typename",
+    "$ungetc",
+    "$value$plusargs",
+    "$writememb",
+    "$writememh",
+];
+
+pub struct CheckSynthesis<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    synthesis_subset: SynthesisSubset,
+    in_testbench: bool,
+    in_parameter: bool,
+}
+
+impl<'a> CheckSynthesis<'a> {
+    pub fn new(text: &'a str, lint_opt: &'a Lint) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+            synthesis_subset: lint_opt.synthesis_subset,
+            in_testbench: false,
+            in_parameter: false,
+        }
+    }
+
+    fn enabled(&self) -> bool {
+        self.synthesis_subset != SynthesisSubset::Off && !self.in_testbench
+    }
+
+    fn check_system_function(&mut self, arg: &ExpressionIdentifier) {
+        if !self.enabled() || !arg.scoped_identifier.scoped_identifier_list.is_empty() {
+            return;
+        }
+        let ScopedIdentifierGroup::DollarIdentifier(ref x) =
+            *arg.scoped_identifier.scoped_identifier_group
+        else {
+            return;
+        };
+
+        let token = &x.dollar_identifier.dollar_identifier_token;
+        let name = token.to_string();
+        let restricted = SIMULATION_ONLY.contains(&name.as_str())
+            || (self.synthesis_subset == SynthesisSubset::Strict
+                && SIMULATION_MESSAGES.contains(&name.as_str()));
+        if restricted {
+            self.errors.push(AnalyzerError::non_synthesizable(
+                &format!("system function \"{name}\""),
+                self.text,
+                &token.token.into(),
+            ));
+        }
+    }
+}
+
+impl Handler for CheckSynthesis<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+fn is_testbench(arg: &ModuleDeclaration) -> bool {
+    attribute_table::get(&arg.module.module_token.token)
+        .iter()
+        .any(|x| matches!(x, Attr::Testbench | Attr::Test(..)))
+}
+
+impl VerylGrammarTrait for CheckSynthesis<'_> {
+    fn strin(&mut self, arg: &Strin) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            // string parameters are resolved at elaboration, so they are permitted in relaxed mode
+            let exempted = self.in_parameter && self.synthesis_subset == SynthesisSubset::Relaxed;
+            if self.enabled() && !exempted {
+                self.errors.push(AnalyzerError::non_synthesizable(
+                    "string type",
+                    self.text,
+                    &arg.string_token.token.into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.function_call().is_some() {
+                self.check_system_function(&arg.expression_identifier);
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let IdentifierStatementGroup::FunctionCall(_) = *arg.identifier_statement_group {
+                self.check_system_function(&arg.expression_identifier);
+            }
+        }
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, _arg: &WithParameterItem) -> Result<(), ParolError> {
+        self.in_parameter = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, _arg: &ConstDeclaration) -> Result<(), ParolError> {
+        self.in_parameter = matches!(self.point, HandlerPoint::Before);
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        self.in_testbench = match self.point {
+            HandlerPoint::Before => is_testbench(arg),
+            HandlerPoint::After => false,
+        };
+        Ok(())
+    }
+}
@@ -1,14 +1,15 @@
-use crate::analyzer_error::AnalyzerError;
-use crate::attribute::AllowItem;
-use crate::attribute::Attribute as Attr;
+use crate::analyzer_error::{AnalyzerError, AnalyzerErrorCode};
+use crate::attribute::most_similar;
 use crate::attribute_table;
+use crate::evaluator::{Evaluated, Evaluator};
 use crate::namespace::Namespace;
 use crate::namespace_table;
-use crate::symbol::{GenericBoundKind, Symbol, SymbolKind, TypeKind};
+use crate::symbol::{GenericBoundKind, Parameter, Port, Symbol, SymbolKind, TypeKind};
 use crate::symbol_path::GenericSymbolPath;
 use crate::symbol_table;
-use veryl_parser::resource_table;
+use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
 use veryl_parser::{ParolError, Stringifier};
 
@@ -33,6 +34,225 @@ impl<'a> CheckType<'a> {
     }
 }
 
+fn evaluate_array(array: &[Expression]) -> Vec<Option<isize>> {
+    array
+        .iter()
+        .map(|x| match Evaluator::new().expression(x) {
+            Evaluated::Fixed { value, .. } => Some(value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns parameters and ports of the module instantiated as `symbol`
+pub fn module_ports(symbol: &Symbol) -> Option<(Vec<Parameter>, Vec<Port>)> {
+    match symbol.kind {
+        SymbolKind::Module(ref x) => Some((x.parameters.clone(), x.ports.clone())),
+        SymbolKind::GenericInstance(ref x) => {
+            let base = symbol_table::get(x.base)?;
+            if let SymbolKind::Module(ref x) = base.kind {
+                Some((x.parameters.clone(), x.ports.clone()))
+            } else {
+                None
+            }
+        }
+        SymbolKind::GenericParameter(ref x) => {
+            let GenericBoundKind::Proto(ref x) = x.bound else {
+                return None;
+            };
+            let proto = symbol_table::resolve((x, &symbol.namespace)).ok()?;
+            if let SymbolKind::ProtoModule(x) = proto.found.kind {
+                Some((x.parameters, x.ports))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether `port` should be connected but isn't
+pub fn is_missing_port(port: &Port, connected: &[StrId]) -> bool {
+    !connected.contains(&port.name()) && port.property().default_value.is_none()
+}
+
+/// Returns ports of the module instantiated by `arg` which are required but not connected
+pub fn missing_ports(arg: &InstDeclaration) -> Vec<Port> {
+    if attribute_table::is_allowed(&arg.inst.inst_token.token, AnalyzerErrorCode::MissingPort) {
+        return Vec::new();
+    }
+
+    let mut connected = Vec::new();
+    if let Some(ref x) = arg.inst_declaration_opt1 {
+        if let Some(ref x) = x.inst_declaration_opt2 {
+            let items: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+            for item in items {
+                connected.push(item.identifier.identifier_token.token.text);
+            }
+        }
+    }
+
+    let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) else {
+        return Vec::new();
+    };
+    let Some((_, ports)) = module_ports(&symbol.found) else {
+        return Vec::new();
+    };
+    ports
+        .into_iter()
+        .filter(|x| is_missing_port(x, &connected))
+        .collect()
+}
+
+fn array_text(array: &[isize]) -> String {
+    if array.is_empty() {
+        "without array".to_string()
+    } else {
+        let array: Vec<_> = array.iter().map(|x| x.to_string()).collect();
+        format!("with array size [{}]", array.join(", "))
+    }
+}
+
+// Output port can be connected to a signal, a concatenation of signals or `_`
+fn is_output_connectable(arg: &Expression) -> bool {
+    if expression_identifier(arg).is_some() {
+        return true;
+    }
+    match expression_factor(arg) {
+        Some(Factor::LParenExpressionRParen(x)) => is_output_connectable(&x.expression),
+        Some(Factor::LBraceConcatenationListRBrace(x)) => {
+            let list = &x.concatenation_list;
+            let mut items = vec![list.concatenation_item.as_ref()];
+            for x in &list.concatenation_list_list {
+                items.push(x.concatenation_item.as_ref());
+            }
+            items.iter().all(|x| {
+                x.concatenation_item_opt.is_none()
+                    && is_output_connectable(&x.expression)
+                    && !is_anonymous_expression(&x.expression)
+            })
+        }
+        _ => false,
+    }
+}
+
+impl CheckType<'_> {
+    fn check_output_connection(&mut self, arg: &InstPortItem, port: Option<&Port>) {
+        let (Some(x), Some(port)) = (&arg.inst_port_item_opt, port) else {
+            return;
+        };
+        let property = port.property();
+        let connectable = match property.direction {
+            crate::symbol::Direction::Output
+            | crate::symbol::Direction::Inout
+            | crate::symbol::Direction::Ref => is_output_connectable(&x.expression),
+            // Modport and interface port need an interface instance
+            crate::symbol::Direction::Modport | crate::symbol::Direction::Interface => {
+                expression_identifier(&x.expression).is_some()
+            }
+            _ => true,
+        };
+        if connectable {
+            return;
+        }
+
+        // Declaration in other file can't be shown in the same source
+        let definition: TokenRange = property.token.into();
+        let definition = if definition.beg.source == arg.identifier.identifier_token.token.source {
+            Some(&definition)
+        } else {
+            None
+        };
+        self.errors.push(AnalyzerError::invalid_output_connection(
+            &port.token.to_string(),
+            &property.direction.to_string(),
+            self.text,
+            &x.expression.as_ref().into(),
+            definition,
+        ));
+    }
+
+    fn check_interface_array_connection(&mut self, arg: &InstPortItem, port: Option<&Port>) {
+        let Some(ref x) = arg.inst_port_item_opt else {
+            return;
+        };
+        let Some(identifier) = expression_identifier(&x.expression) else {
+            return;
+        };
+        let Ok(symbol) = symbol_table::resolve(identifier.scoped_identifier.as_ref()) else {
+            return;
+        };
+        let SymbolKind::Instance(ref instance) = symbol.found.kind else {
+            return;
+        };
+        if instance.array.is_empty() {
+            return;
+        }
+
+        let name = symbol.found.token.to_string();
+        let definition: TokenRange = symbol.found.token.into();
+        let size = evaluate_array(&instance.array);
+        let selects = &identifier.expression_identifier_list;
+
+        for (select, size) in selects.iter().zip(size.iter()) {
+            let select = &select.select;
+            if select.select_opt.is_some() {
+                return;
+            }
+            if let (Evaluated::Fixed { value, .. }, Some(size)) =
+                (Evaluator::new().expression(&select.expression), size)
+            {
+                if value < 0 || value >= *size {
+                    self.errors
+                        .push(AnalyzerError::interface_array_index_out_of_range(
+                            &name,
+                            value,
+                            *size,
+                            self.text,
+                            &select.expression.as_ref().into(),
+                            &definition,
+                        ));
+                }
+            }
+        }
+
+        // Member access of interface is not a connection of interface
+        if !identifier.expression_identifier_list0.is_empty() || selects.len() > size.len() {
+            return;
+        }
+
+        let Some(port) = port else {
+            return;
+        };
+        let property = port.property();
+        let Some(port_type) = property.r#type else {
+            return;
+        };
+        if property.direction != crate::symbol::Direction::Modport {
+            return;
+        }
+
+        let actual: Option<Vec<_>> = size[selects.len()..].iter().copied().collect();
+        let expected: Option<Vec<_>> = evaluate_array(&port_type.array).into_iter().collect();
+        if let (Some(actual), Some(expected)) = (actual, expected) {
+            if actual != expected {
+                let mut stringifier = Stringifier::new();
+                stringifier.expression(&x.expression);
+                self.errors
+                    .push(AnalyzerError::mismatch_interface_array_size(
+                        stringifier.as_str(),
+                        &port.token.to_string(),
+                        &array_text(&expected),
+                        &array_text(&actual),
+                        self.text,
+                        &x.expression.as_ref().into(),
+                        &definition,
+                    ));
+            }
+        }
+    }
+}
+
 impl Handler for CheckType<'_> {
     fn set_point(&mut self, p: HandlerPoint) {
         self.point = p;
@@ -56,6 +276,19 @@ fn is_variable_type(symbol: &Symbol) -> bool {
     }
 }
 
+fn is_value(symbol: &Symbol) -> bool {
+    match &symbol.kind {
+        SymbolKind::Variable(_)
+        | SymbolKind::EnumMember(_)
+        | SymbolKind::EnumMemberMangled
+        | SymbolKind::Genvar => true,
+        SymbolKind::Port(x) => x.direction != crate::symbol::Direction::Modport,
+        SymbolKind::Parameter(x) => x.r#type.kind != TypeKind::Type,
+        SymbolKind::GenericParameter(x) => x.bound == GenericBoundKind::Const,
+        _ => false,
+    }
+}
+
 fn is_casting_type(symbol: &Symbol) -> bool {
     match &symbol.kind {
         // U32/U64 can be used as casting type
@@ -222,22 +455,46 @@ impl VerylGrammarTrait for CheckType<'_> {
 
                     for (i, arg) in args.iter().enumerate() {
                         if let Some(param) = params.get(i) {
+                            let arg_symbol = if arg.is_resolvable() {
+                                symbol_table::resolve((&arg.generic_path(), &namespace)).ok()
+                            } else {
+                                None
+                            };
+                            let kind_mismatch = |expected: &str, actual: &str| {
+                                AnalyzerError::mismatch_generic_kind(
+                                    &resource_table::get_str_value(param.0).unwrap(),
+                                    &arg.to_string(),
+                                    expected,
+                                    actual,
+                                    self.text,
+                                    &arg.range,
+                                )
+                            };
+
                             match &param.1.bound {
-                                GenericBoundKind::Const => (),
+                                GenericBoundKind::Const => {
+                                    let is_type = arg_symbol
+                                        .as_ref()
+                                        .map(|x| is_variable_type(&x.found))
+                                        .unwrap_or(false);
+
+                                    if is_type {
+                                        self.errors.push(kind_mismatch("value", "type"));
+                                    }
+                                }
                                 GenericBoundKind::Type => {
-                                    let is_type = if arg.is_resolvable() {
-                                        if let Ok(symbol) =
-                                            symbol_table::resolve((&arg.generic_path(), &namespace))
-                                        {
-                                            is_variable_type(&symbol.found)
-                                        } else {
-                                            false
-                                        }
-                                    } else {
-                                        false
-                                    };
+                                    let is_value = arg_symbol
+                                        .as_ref()
+                                        .map(|x| is_value(&x.found))
+                                        .unwrap_or(!arg.is_resolvable());
+                                    let is_type = arg_symbol
+                                        .as_ref()
+                                        .map(|x| is_variable_type(&x.found))
+                                        .unwrap_or(false);
 
-                                    if !is_type {
+                                    if is_value {
+                                        self.errors.push(kind_mismatch("type", "value"));
+                                    } else if !is_type {
                                         self.errors.push(AnalyzerError::mismatch_type(
                                             &symbol.found.token.to_string(),
                                             "enum or union or struct",
@@ -313,17 +570,19 @@ impl VerylGrammarTrait for CheckType<'_> {
                 if let Some(ref x) = x.inst_parameter.inst_parameter_opt {
                     let items: Vec<InstParameterItem> = x.inst_parameter_list.as_ref().into();
                     for item in items {
-                        connected_params.push(item.identifier.identifier_token.token.text);
+                        connected_params.push(item.identifier.identifier_token.token);
                     }
                 }
             }
 
             let mut connected_ports = Vec::new();
+            let mut port_items = Vec::new();
             if let Some(ref x) = arg.inst_declaration_opt1 {
                 if let Some(ref x) = x.inst_declaration_opt2 {
                     let items: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
                     for item in items {
                         connected_ports.push(item.identifier.identifier_token.token.text);
+                        port_items.push(item);
                     }
                 }
             }
@@ -338,22 +597,12 @@ impl VerylGrammarTrait for CheckType<'_> {
                 let mut check_port_connection = false;
 
                 let type_expected = match symbol.found.kind {
-                    SymbolKind::Module(ref x) if self.in_module => {
-                        params.append(&mut x.parameters.clone());
-                        ports.append(&mut x.ports.clone());
-                        check_port_connection = true;
-                        None
-                    }
+                    SymbolKind::Module(_) if self.in_module => None,
                     SymbolKind::Interface(_) | SymbolKind::SystemVerilog => None,
                     SymbolKind::GenericInstance(ref x) => {
                         let base = symbol_table::get(x.base).unwrap();
                         match base.kind {
-                            SymbolKind::Module(ref x) if self.in_module => {
-                                params.append(&mut x.parameters.clone());
-                                ports.append(&mut x.ports.clone());
-                                check_port_connection = true;
-                                None
-                            }
+                            SymbolKind::Module(_) if self.in_module => None,
                             SymbolKind::Interface(_) | SymbolKind::SystemVerilog => None,
                             _ => {
                                 if self.in_module {
@@ -368,10 +617,7 @@ impl VerylGrammarTrait for CheckType<'_> {
                         if let GenericBoundKind::Proto(ref x) = x.bound {
                             if let Ok(symbol) = symbol_table::resolve((x, &symbol.found.namespace))
                             {
-                                if let SymbolKind::ProtoModule(x) = symbol.found.kind {
-                                    params.append(&mut x.parameters.clone());
-                                    ports.append(&mut x.ports.clone());
-                                    check_port_connection = true;
+                                if let SymbolKind::ProtoModule(_) = symbol.found.kind {
                                     None
                                 } else {
                                     Some("module or interface")
@@ -392,6 +638,14 @@ impl VerylGrammarTrait for CheckType<'_> {
                     }
                 };
 
+                if type_expected.is_none() {
+                    if let Some((x, y)) = module_ports(&symbol.found) {
+                        params = x;
+                        ports = y;
+                        check_port_connection = true;
+                    }
+                }
+
                 if let Some(expected) = type_expected {
                     self.errors.push(AnalyzerError::mismatch_type(
                         name,
@@ -404,11 +658,10 @@ impl VerylGrammarTrait for CheckType<'_> {
 
                 if check_port_connection {
                     for port in &ports {
-                        if !connected_ports.contains(&port.name())
-                            && port.property().default_value.is_none()
-                            && !attribute_table::contains(
+                        if is_missing_port(port, &connected_ports)
+                            && !attribute_table::is_allowed(
                                 &arg.inst.inst_token.token,
-                                Attr::Allow(AllowItem::MissingPort),
+                                AnalyzerErrorCode::MissingPort,
                             )
                         {
                             let port = resource_table::get_str_value(port.name()).unwrap();
@@ -421,13 +674,18 @@ impl VerylGrammarTrait for CheckType<'_> {
                         }
                     }
                     for param in &connected_params {
-                        if !params.iter().any(|x| &x.name == param) {
-                            let param = resource_table::get_str_value(*param).unwrap();
+                        if !params.iter().any(|x| x.name == param.text) {
+                            let candidates: Vec<_> = params
+                                .iter()
+                                .map(|x| resource_table::get_str_value(x.name).unwrap())
+                                .collect();
+                            let similar = most_similar(&param.to_string(), &candidates);
                             self.errors.push(AnalyzerError::unknown_param(
                                 name,
-                                &param,
+                                &param.to_string(),
+                                similar.map(|x| x.as_str()),
                                 self.text,
-                                &arg.identifier.as_ref().into(),
+                                &param.into(),
                             ));
                         }
                     }
@@ -443,6 +701,14 @@ impl VerylGrammarTrait for CheckType<'_> {
                         }
                     }
                 }
+
+                for item in &port_items {
+                    let port = ports
+                        .iter()
+                        .find(|x| x.name() == item.identifier.identifier_token.token.text);
+                    self.check_interface_array_connection(item, port);
+                    self.check_output_connection(item, port);
+                }
             }
         }
         Ok(())
@@ -0,0 +1,786 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::attribute::Attribute;
+use crate::attribute_table;
+use crate::cast::{self, CastLowering};
+use crate::evaluator::Evaluator;
+use crate::handlers::check_type::module_ports;
+use crate::handlers::check_width_value::evaluate;
+use crate::symbol::{
+    Direction, Parameter, ParameterKind, Port, Symbol, SymbolId, SymbolKind, Type, TypeKind,
+    TypeModifier,
+};
+use crate::symbol_path::SymbolPathNamespace;
+use crate::symbol_table;
+use crate::width;
+use std::collections::HashSet;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::{ParolError, Stringifier};
+
+#[derive(Default)]
+pub struct CheckTypeCompatibility<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+}
+
+impl<'a> CheckTypeCompatibility<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+}
+
+impl Handler for CheckTypeCompatibility<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ResolvedKind {
+    Enum(SymbolId),
+    /// struct or union
+    Struct(SymbolId),
+    /// logic, bit, numeric types and expressions with operators
+    Raw,
+}
+
+#[derive(Clone, Debug)]
+struct ResolvedType {
+    kind: ResolvedKind,
+    name: String,
+}
+
+impl ResolvedType {
+    fn raw() -> Self {
+        Self {
+            kind: ResolvedKind::Raw,
+            name: "logic".to_string(),
+        }
+    }
+}
+
+/// Returns the type if it is a single enum, struct, union or raw type
+fn resolve_type(arg: &Type) -> Option<ResolvedType> {
+    if !arg.array.is_empty() {
+        return None;
+    }
+    let name = arg.to_string();
+    match &arg.kind {
+        TypeKind::UserDefined(x) => {
+            if !arg.width.is_empty() {
+                return None;
+            }
+            let symbol = symbol_table::get(x.symbol?)?;
+            let kind = match symbol.kind {
+                SymbolKind::Enum(_) => ResolvedKind::Enum(symbol.id),
+                SymbolKind::Struct(_) | SymbolKind::Union(_) => ResolvedKind::Struct(symbol.id),
+                SymbolKind::TypeDef(ref x) => resolve_type(&x.r#type)?.kind,
+                _ => return None,
+            };
+            Some(ResolvedType { kind, name })
+        }
+        TypeKind::Bit
+        | TypeKind::Logic
+        | TypeKind::U32
+        | TypeKind::U64
+        | TypeKind::I32
+        | TypeKind::I64 => Some(ResolvedType {
+            kind: ResolvedKind::Raw,
+            name,
+        }),
+        _ => None,
+    }
+}
+
+fn symbol_type(id: SymbolId) -> Option<ResolvedType> {
+    let symbol = symbol_table::get(id)?;
+    match symbol.kind {
+        SymbolKind::Enum(_) => Some(ResolvedType {
+            kind: ResolvedKind::Enum(id),
+            name: symbol.token.to_string(),
+        }),
+        SymbolKind::Struct(_) | SymbolKind::Union(_) => Some(ResolvedType {
+            kind: ResolvedKind::Struct(id),
+            name: symbol.token.to_string(),
+        }),
+        SymbolKind::TypeDef(ref x) => {
+            let kind = resolve_type(&x.r#type)?.kind;
+            Some(ResolvedType {
+                kind,
+                name: symbol.token.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the struct which `arg` refers to through type aliases
+fn struct_symbol(arg: &Symbol) -> Option<Symbol> {
+    match arg.kind {
+        SymbolKind::Struct(_) => Some(arg.clone()),
+        SymbolKind::TypeDef(ref x) => {
+            if !x.r#type.width.is_empty() || !x.r#type.array.is_empty() {
+                return None;
+            }
+            let TypeKind::UserDefined(ref x) = x.r#type.kind else {
+                return None;
+            };
+            struct_symbol(&symbol_table::get(x.symbol?)?)
+        }
+        _ => None,
+    }
+}
+
+fn enum_width(id: SymbolId) -> Option<usize> {
+    if let SymbolKind::Enum(x) = symbol_table::get(id)?.kind {
+        Some(x.width)
+    } else {
+        None
+    }
+}
+
+/// Returns the type of the expression if it can be determined
+fn expression_type(arg: &Expression) -> Option<ResolvedType> {
+    let Some(x) = expression_operand(arg) else {
+        return Some(ResolvedType::raw());
+    };
+    if let Some(ref cast) = x.expression11_opt {
+        casting_type(&cast.casting_type)
+    } else {
+        expression12_type(&x.expression12)
+    }
+}
+
+fn expression12_type(arg: &Expression12) -> Option<ResolvedType> {
+    if !arg.expression12_list.is_empty() {
+        return Some(ResolvedType::raw());
+    }
+    match arg.factor.as_ref() {
+        Factor::Number(x) => match x.number.as_ref() {
+            // all-bit literals like '0 can fill any type
+            Number::IntegralNumber(x) => match x.integral_number.as_ref() {
+                IntegralNumber::AllBit(_) => None,
+                _ => Some(ResolvedType::raw()),
+            },
+            Number::RealNumber(_) => None,
+        },
+        Factor::IdentifierFactor(x) => identifier_factor_type(&x.identifier_factor),
+        Factor::LParenExpressionRParen(x) => expression_type(&x.expression),
+        Factor::LBraceConcatenationListRBrace(_)
+        | Factor::InsideExpression(_)
+        | Factor::OutsideExpression(_) => Some(ResolvedType::raw()),
+        Factor::IfExpression(x) => {
+            let x = &x.if_expression;
+            let mut branches = vec![x.expression0.as_ref(), x.expression1.as_ref()];
+            branches.extend(x.if_expression_list.iter().map(|x| x.expression0.as_ref()));
+            branches_type(&branches)
+        }
+        Factor::CaseExpression(x) => {
+            let x = &x.case_expression;
+            let mut branches = vec![x.expression0.as_ref(), x.expression1.as_ref()];
+            branches.extend(x.case_expression_list.iter().map(|x| x.expression.as_ref()));
+            branches_type(&branches)
+        }
+        Factor::SwitchExpression(x) => {
+            let x = &x.switch_expression;
+            let mut branches = vec![x.expression.as_ref(), x.expression0.as_ref()];
+            branches.extend(
+                x.switch_expression_list
+                    .iter()
+                    .map(|x| x.expression.as_ref()),
+            );
+            branches_type(&branches)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the type if all branches have the same type
+fn branches_type(arg: &[&Expression]) -> Option<ResolvedType> {
+    let mut ret: Option<ResolvedType> = None;
+    for x in arg {
+        let x = expression_type(x)?;
+        if let Some(ref ret) = ret {
+            if ret.kind != x.kind {
+                return None;
+            }
+        } else {
+            ret = Some(x);
+        }
+    }
+    ret
+}
+
+fn identifier_factor_type(arg: &IdentifierFactor) -> Option<ResolvedType> {
+    let identifier = arg.expression_identifier.as_ref();
+    let symbol = symbol_table::resolve(identifier).ok()?;
+
+    if arg.struct_constructor().is_some() {
+        return symbol_type(symbol.found.id);
+    }
+
+    if arg.function_call().is_some() {
+        return match symbol.found.kind {
+            SymbolKind::Function(x) => resolve_type(x.ret.as_ref()?),
+            _ => None,
+        };
+    }
+
+    if is_selected(identifier) {
+        return None;
+    }
+
+    match symbol.found.kind {
+        SymbolKind::EnumMember(_) => symbol_type(symbol.found.get_parent()?.id),
+        SymbolKind::Variable(_)
+        | SymbolKind::Port(_)
+        | SymbolKind::Parameter(_)
+        | SymbolKind::StructMember(_)
+        | SymbolKind::UnionMember(_) => resolve_type(symbol.found.kind.get_type()?),
+        _ => None,
+    }
+}
+
+fn is_selected(arg: &ExpressionIdentifier) -> bool {
+    arg.expression_identifier_opt.is_some()
+        || !arg.expression_identifier_list.is_empty()
+        || arg
+            .expression_identifier_list0
+            .iter()
+            .any(|x| !x.expression_identifier_list0_list.is_empty())
+}
+
+fn casting_type(arg: &CastingType) -> Option<ResolvedType> {
+    match arg {
+        CastingType::UserDefinedType(x) => {
+            let symbol =
+                symbol_table::resolve(x.user_defined_type.scoped_identifier.as_ref()).ok()?;
+            symbol_type(symbol.found.id)
+        }
+        CastingType::U32(_)
+        | CastingType::U64(_)
+        | CastingType::I32(_)
+        | CastingType::I64(_)
+        | CastingType::Bit(_)
+        | CastingType::Logic(_)
+        | CastingType::Based(_) => Some(ResolvedType::raw()),
+        _ => None,
+    }
+}
+
+/// Returns the type of the assignment destination if it is not selected
+fn destination_type<T: Into<SymbolPathNamespace>>(arg: T) -> Option<ResolvedType> {
+    let symbol = symbol_table::resolve(arg).ok()?;
+    match symbol.found.kind {
+        SymbolKind::Variable(_)
+        | SymbolKind::Port(_)
+        | SymbolKind::Parameter(_)
+        | SymbolKind::StructMember(_)
+        | SymbolKind::UnionMember(_) => resolve_type(symbol.found.kind.get_type()?),
+        _ => None,
+    }
+}
+
+/// Returns the width of the assignment destination if it is not selected
+fn destination_width<T: Into<SymbolPathNamespace>>(arg: T) -> Option<usize> {
+    let symbol = symbol_table::resolve(arg).ok()?;
+    match symbol.found.kind {
+        SymbolKind::Variable(_)
+        | SymbolKind::Port(_)
+        | SymbolKind::Parameter(_)
+        | SymbolKind::StructMember(_)
+        | SymbolKind::UnionMember(_) => width::type_width(symbol.found.kind.get_type()?),
+        _ => None,
+    }
+}
+
+fn port_type(arg: &Port) -> Option<ResolvedType> {
+    resolve_type(arg.property().r#type.as_ref()?)
+}
+
+/// Returns the range of values which can be assigned to the parameter,
+/// by `#[range]` attribute or the width of unsigned type
+fn parameter_range(arg: &Parameter) -> Option<(isize, isize)> {
+    let property = arg.property();
+    for x in attribute_table::get(&property.token) {
+        if let Attribute::Range(min, max) = x {
+            return Some((min, max));
+        }
+    }
+
+    let r#type = &property.r#type;
+    let unsigned = matches!(
+        r#type.kind,
+        TypeKind::Bit | TypeKind::Logic | TypeKind::U32 | TypeKind::U64
+    ) && !r#type.modifier.contains(&TypeModifier::Signed);
+    if !unsigned || !r#type.array.is_empty() {
+        return None;
+    }
+    match Evaluator::new().total_width(r#type)? {
+        x @ 1..=62 => Some((0, (1 << x) - 1)),
+        _ => None,
+    }
+}
+
+fn is_compatible(dst: &ResolvedKind, src: &ResolvedKind) -> bool {
+    match (dst, src) {
+        (ResolvedKind::Enum(x), ResolvedKind::Enum(y)) => x == y,
+        (ResolvedKind::Enum(_), _) => false,
+        (ResolvedKind::Struct(x), ResolvedKind::Struct(y)) => x == y,
+        (ResolvedKind::Struct(_), ResolvedKind::Enum(_)) => false,
+        _ => true,
+    }
+}
+
+impl CheckTypeCompatibility<'_> {
+    fn check(&mut self, dst: Option<ResolvedType>, src: Option<ResolvedType>, arg: &Expression) {
+        let mut stringifier = Stringifier::new();
+        stringifier.expression(arg);
+        self.check_range(dst, src, stringifier.as_str(), &arg.into());
+    }
+
+    fn check_range(
+        &mut self,
+        dst: Option<ResolvedType>,
+        src: Option<ResolvedType>,
+        name: &str,
+        range: &TokenRange,
+    ) {
+        let (Some(dst), Some(src)) = (dst, src) else {
+            return;
+        };
+        if !is_compatible(&dst.kind, &src.kind) {
+            self.errors.push(AnalyzerError::mismatch_type(
+                name, &dst.name, &src.name, self.text, range,
+            ));
+        }
+    }
+
+    /// Checks widths of branches and concatenations in the expression
+    /// which is assigned to `target` of `expected` width
+    fn check_width(&mut self, expected: Option<usize>, target: &str, arg: &Expression) {
+        let Some(x) = expression_operand(arg) else {
+            return;
+        };
+        if x.expression11_opt.is_some() || !x.expression12.expression12_list.is_empty() {
+            return;
+        }
+        match x.expression12.factor.as_ref() {
+            Factor::LParenExpressionRParen(x) => self.check_width(expected, target, &x.expression),
+            Factor::IfExpression(x) => {
+                let branches = width::if_expression_branches(&x.if_expression);
+                let widths: Option<Vec<_>> = branches
+                    .iter()
+                    .map(|x| width::expression_width(x))
+                    .collect();
+                if let Some(widths) = widths {
+                    let max = widths.iter().max().copied().unwrap_or(0);
+                    if let Some((branch, width)) =
+                        branches.iter().zip(&widths).find(|(_, x)| **x != max)
+                    {
+                        self.errors.push(AnalyzerError::mismatch_branch_width(
+                            *width,
+                            max,
+                            self.text,
+                            &(*branch).into(),
+                        ));
+                    }
+                }
+                for x in branches {
+                    self.check_width(expected, target, x);
+                }
+            }
+            Factor::LBraceConcatenationListRBrace(x) => {
+                let list = &x.concatenation_list;
+                let width = width::concatenation_width(list);
+                if let (Some(expected), Some(width)) = (expected, width) {
+                    if expected != width {
+                        self.errors
+                            .push(AnalyzerError::mismatch_concatenation_width(
+                                width,
+                                target,
+                                expected,
+                                self.text,
+                                &arg.into(),
+                            ));
+                    }
+                }
+
+                // elements of concatenation are self-determined
+                self.check_width(None, target, &list.concatenation_item.expression);
+                for x in &list.concatenation_list_list {
+                    self.check_width(None, target, &x.concatenation_item.expression);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn check_connection(&mut self, port: &Port, arg: &Expression) {
+        let expected = port.property().r#type.as_ref().and_then(width::type_width);
+        self.check_width(expected, &format!("port \"{}\"", port.token), arg);
+
+        match port.property().direction {
+            Direction::Input => self.check(port_type(port), expression_type(arg), arg),
+            Direction::Output => {
+                let dst = expression_identifier(arg)
+                    .filter(|x| !is_selected(x))
+                    .and_then(destination_type);
+                self.check(dst, port_type(port), arg);
+            }
+            _ => (),
+        }
+    }
+
+    fn check_parameter(&mut self, param: &Parameter, arg: &InstParameterItem) {
+        let dst = resolve_type(&param.property().r#type);
+        let value = if let Some(ref x) = arg.inst_parameter_item_opt {
+            self.check(dst, expression_type(&x.expression), &x.expression);
+            evaluate(&x.expression)
+        } else {
+            // implicit override by the parameter of the same name
+            let name = arg.identifier.identifier_token.to_string();
+            let src = destination_type(arg.identifier.as_ref());
+            self.check_range(dst, src, &name, &arg.identifier.as_ref().into());
+            match symbol_table::resolve(arg.identifier.as_ref()) {
+                Ok(x) => match x.found.kind {
+                    SymbolKind::Parameter(ref x) if matches!(x.kind, ParameterKind::Const) => {
+                        evaluate(&x.value)
+                    }
+                    _ => None,
+                },
+                Err(_) => None,
+            }
+        };
+
+        // the value depending on overridable parameters is not fixed
+        let Some((value, None)) = value else {
+            return;
+        };
+        let Some((min, max)) = parameter_range(param) else {
+            return;
+        };
+        if value < min || value > max {
+            let range: TokenRange = match arg.inst_parameter_item_opt {
+                Some(ref x) => x.expression.as_ref().into(),
+                None => arg.identifier.as_ref().into(),
+            };
+            self.errors.push(AnalyzerError::invalid_parameter_value(
+                &arg.identifier.identifier_token.to_string(),
+                value,
+                &format!("[{min}, {max}]"),
+                self.text,
+                &range,
+            ));
+        }
+    }
+
+    fn check_struct_constructor(
+        &mut self,
+        identifier: &ExpressionIdentifier,
+        arg: &StructConstructor,
+    ) {
+        let Ok(symbol) = symbol_table::resolve(identifier) else {
+            return;
+        };
+        let name = symbol.found.token.to_string();
+        let Some(SymbolKind::Struct(x)) = struct_symbol(&symbol.found).map(|x| x.kind) else {
+            self.errors.push(AnalyzerError::mismatch_type(
+                &name,
+                "struct",
+                &symbol.found.kind.to_kind_name(),
+                self.text,
+                &identifier.into(),
+            ));
+            return;
+        };
+        let members: Vec<_> = x
+            .members
+            .iter()
+            .filter_map(|x| symbol_table::get(*x))
+            .collect();
+
+        let list = &arg.struct_constructor_list;
+        let mut items = vec![list.struct_constructor_item.as_ref()];
+        items.extend(
+            list.struct_constructor_list_list
+                .iter()
+                .map(|x| x.struct_constructor_item.as_ref()),
+        );
+
+        let mut initialized = HashSet::new();
+        for item in items {
+            let member_name = item.identifier.identifier_token.token.text;
+            let Some(member) = members.iter().find(|x| x.token.text == member_name) else {
+                self.errors.push(AnalyzerError::unknown_member(
+                    &name,
+                    &item.identifier.identifier_token.to_string(),
+                    self.text,
+                    &item.identifier.as_ref().into(),
+                ));
+                continue;
+            };
+            if !initialized.insert(member_name) {
+                self.errors.push(AnalyzerError::duplicated_struct_member(
+                    &name,
+                    &item.identifier.identifier_token.to_string(),
+                    self.text,
+                    &item.identifier.as_ref().into(),
+                ));
+                continue;
+            }
+            if let SymbolKind::StructMember(ref x) = member.kind {
+                self.check(
+                    resolve_type(&x.r#type),
+                    expression_type(&item.expression),
+                    &item.expression,
+                );
+            }
+        }
+
+        if arg.struct_constructor_opt.is_none() {
+            let missing: Vec<_> = members
+                .iter()
+                .filter(|x| !initialized.contains(&x.token.text))
+                .map(|x| format!("\"{}\"", x.token))
+                .collect();
+            if !missing.is_empty() {
+                self.errors.push(AnalyzerError::missing_struct_member(
+                    &name,
+                    &missing.join(", "),
+                    self.text,
+                    &identifier.into(),
+                ));
+            }
+        }
+    }
+
+    fn check_function_call(&mut self, identifier: &ExpressionIdentifier, call: &FunctionCall) {
+        let Ok(symbol) = symbol_table::resolve(identifier) else {
+            return;
+        };
+        let SymbolKind::Function(x) = symbol.found.kind else {
+            return;
+        };
+        let Some(ref list) = call.function_call_opt else {
+            return;
+        };
+        let list = &list.argument_list;
+        let mut args = vec![list.argument_item.expression.as_ref()];
+        args.extend(
+            list.argument_list_list
+                .iter()
+                .map(|x| x.argument_item.expression.as_ref()),
+        );
+
+        for (port, arg) in x.ports.iter().zip(args) {
+            self.check_connection(port, arg);
+        }
+    }
+}
+
+impl VerylGrammarTrait for CheckTypeCompatibility<'_> {
+    fn expression11(&mut self, arg: &Expression11) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let Some(ref cast) = arg.expression11_opt else {
+                return Ok(());
+            };
+            if let (Some(src), Some(dst)) = (
+                cast::source_type(&arg.expression12),
+                cast::target_type(&cast.casting_type),
+            ) {
+                if let (CastLowering::Invalid, Some(from_width), Some(to_width)) =
+                    (cast::lowering(&src, &dst), src.width, dst.width)
+                {
+                    self.errors.push(AnalyzerError::invalid_cast(
+                        &format!("{} ({} bits)", src.name, from_width),
+                        &format!("{} ({} bits)", dst.name, to_width),
+                        self.text,
+                        &arg.into(),
+                    ));
+                }
+            }
+            let (Some(dst), Some(src)) = (
+                casting_type(&cast.casting_type),
+                expression12_type(&arg.expression12),
+            ) else {
+                return Ok(());
+            };
+            if let (ResolvedKind::Enum(x), ResolvedKind::Enum(y)) = (&dst.kind, &src.kind) {
+                if let (Some(to_width), Some(from_width)) = (enum_width(*x), enum_width(*y)) {
+                    if from_width > to_width {
+                        self.errors.push(AnalyzerError::truncated_enum_cast(
+                            &src.name,
+                            from_width,
+                            &dst.name,
+                            to_width,
+                            self.text,
+                            &arg.into(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(x) = arg.function_call() {
+                self.check_function_call(&arg.expression_identifier, x);
+            } else if let Some(x) = arg.struct_constructor() {
+                self.check_struct_constructor(&arg.expression_identifier, x);
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.identifier_statement_group.as_ref() {
+                IdentifierStatementGroup::FunctionCall(x) => {
+                    self.check_function_call(&arg.expression_identifier, &x.function_call);
+                }
+                IdentifierStatementGroup::Assignment(x) => {
+                    let x = &x.assignment;
+                    let identifier = arg.expression_identifier.as_ref();
+                    if matches!(x.assignment_group.as_ref(), AssignmentGroup::Equ(_)) {
+                        if !is_selected(identifier) {
+                            let dst = destination_type(identifier);
+                            self.check(dst, expression_type(&x.expression), &x.expression);
+                        }
+
+                        let mut stringifier = Stringifier::new();
+                        stringifier.expression_identifier(identifier);
+                        self.check_width(
+                            width::identifier_width(identifier),
+                            &format!("signal \"{}\"", stringifier.as_str()),
+                            &x.expression,
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn let_statement(&mut self, arg: &LetStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let dst = destination_type(arg.identifier.as_ref());
+            self.check(dst, expression_type(&arg.expression), &arg.expression);
+            self.check_width(
+                destination_width(arg.identifier.as_ref()),
+                &format!("signal \"{}\"", arg.identifier.identifier_token),
+                &arg.expression,
+            );
+        }
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let dst = destination_type(arg.identifier.as_ref());
+            self.check(dst, expression_type(&arg.expression), &arg.expression);
+            self.check_width(
+                destination_width(arg.identifier.as_ref()),
+                &format!("signal \"{}\"", arg.identifier.identifier_token),
+                &arg.expression,
+            );
+        }
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, arg: &ConstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let dst = destination_type(arg.identifier.as_ref());
+            self.check(dst, expression_type(&arg.expression), &arg.expression);
+            self.check_width(
+                destination_width(arg.identifier.as_ref()),
+                &format!("signal \"{}\"", arg.identifier.identifier_token),
+                &arg.expression,
+            );
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let identifier = arg.hierarchical_identifier.as_ref();
+            let selected = !identifier.hierarchical_identifier_list.is_empty()
+                || identifier
+                    .hierarchical_identifier_list0
+                    .iter()
+                    .any(|x| !x.hierarchical_identifier_list0_list.is_empty());
+            if !selected {
+                let dst = destination_type(identifier);
+                self.check(dst, expression_type(&arg.expression), &arg.expression);
+
+                let mut stringifier = Stringifier::new();
+                stringifier.hierarchical_identifier(identifier);
+                self.check_width(
+                    destination_width(identifier),
+                    &format!("signal \"{}\"", stringifier.as_str()),
+                    &arg.expression,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) else {
+                return Ok(());
+            };
+            let Some((params, ports)) = module_ports(&symbol.found) else {
+                return Ok(());
+            };
+
+            if let Some(ref x) = arg.inst_declaration_opt0 {
+                if let Some(ref x) = x.inst_parameter.inst_parameter_opt {
+                    let items: Vec<InstParameterItem> = x.inst_parameter_list.as_ref().into();
+                    for item in items {
+                        let name = item.identifier.identifier_token.token.text;
+                        if let Some(param) = params.iter().find(|x| x.name == name) {
+                            self.check_parameter(param, &item);
+                        }
+                    }
+                }
+            }
+
+            let Some(ref x) = arg.inst_declaration_opt1 else {
+                return Ok(());
+            };
+            let Some(ref x) = x.inst_declaration_opt2 else {
+                return Ok(());
+            };
+
+            let items: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+            for item in items {
+                let name = item.identifier.identifier_token.token.text;
+                let Some(port) = ports.iter().find(|x| x.name() == name) else {
+                    continue;
+                };
+                if let Some(ref x) = item.inst_port_item_opt {
+                    self.check_connection(port, &x.expression);
+                } else {
+                    // implicit connection to the signal of the same name
+                    let signal = destination_type(item.identifier.as_ref());
+                    let (dst, src) = match port.property().direction {
+                        Direction::Input => (port_type(port), signal),
+                        Direction::Output => (signal, port_type(port)),
+                        _ => continue,
+                    };
+                    let name = item.identifier.identifier_token.to_string();
+                    self.check_range(dst, src, &name, &item.identifier.as_ref().into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
-use crate::analyzer_error::AnalyzerError;
+use crate::analyzer_error::{AnalyzerError, AnalyzerErrorCode};
 use crate::attribute::Attribute as Attr;
-use crate::attribute::{AllowItem, CondTypeItem};
+use crate::attribute::CondTypeItem;
 use crate::attribute_table;
 use crate::symbol::{Direction, Symbol, SymbolId, SymbolKind, TypeKind};
 use crate::symbol_table;
@@ -30,6 +30,7 @@ pub struct CheckVarRef<'a> {
     in_if_expression: Vec<()>,
     function_call: Vec<Option<FunctionCallContext>>,
     branch_index: usize,
+    in_assertion: bool,
 }
 
 impl<'a> CheckVarRef<'a> {
@@ -44,6 +45,7 @@ impl<'a> CheckVarRef<'a> {
             in_if_expression: Vec::new(),
             function_call: Vec::new(),
             branch_index: 0,
+            in_assertion: false,
         }
     }
 
@@ -142,6 +144,33 @@ fn can_assign(full_path: &[SymbolId]) -> bool {
     false
 }
 
+// Members accessed through a modport port resolve to ModportVariableMember,
+// so the selected modport's direction can be checked on each access.
+// Accesses inside functions declared in the interface itself resolve to the
+// original variables and are not restricted by any modport.
+// Output members can be read inside the module as output ports,
+// so only writes to input members are rejected.
+fn input_modport_member(full_path: &[SymbolId]) -> Option<Symbol> {
+    full_path
+        .iter()
+        .filter_map(|x| symbol_table::get(*x))
+        .find(|x| {
+            matches!(
+                &x.kind,
+                SymbolKind::ModportVariableMember(x) if x.direction == Direction::Input
+            )
+        })
+}
+
+fn input_port(full_path: &[SymbolId]) -> Option<Symbol> {
+    let symbol = symbol_table::get(*full_path.first()?)?;
+    if matches!(&symbol.kind, SymbolKind::Port(x) if x.direction == Direction::Input) {
+        Some(symbol)
+    } else {
+        None
+    }
+}
+
 fn has_cond_type(token: &Token) -> bool {
     let mut attrs = attribute_table::get(token);
     attrs.reverse();
@@ -242,7 +271,8 @@ fn map_assignable_factor(arg: &Expression) -> Option<VarRefPath> {
 impl VerylGrammarTrait for CheckVarRef<'_> {
     fn r#else(&mut self, arg: &Else) -> Result<(), ParolError> {
         if let HandlerPoint::Before = self.point {
-            if self.in_if_expression.is_empty() {
+            // else of assertion is not a branch
+            if self.in_if_expression.is_empty() && !self.in_assertion {
                 let position = if let AssignPositionType::StatementBranchItem { .. } =
                     self.assign_position.0.last().unwrap()
                 {
@@ -264,6 +294,22 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
         Ok(())
     }
 
+    fn assertion_statement(&mut self, _arg: &AssertionStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_assertion = true,
+            HandlerPoint::After => self.in_assertion = false,
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, _arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_assertion = true,
+            HandlerPoint::After => self.in_assertion = false,
+        }
+        Ok(())
+    }
+
     fn if_expression(&mut self, _arg: &IfExpression) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
@@ -289,7 +335,7 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
     }
 
     fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
-        if arg.identifier_factor_opt.is_some() {
+        if arg.function_call().is_some() {
             match self.point {
                 HandlerPoint::Before => self.push_function_call(&arg.expression_identifier),
                 HandlerPoint::After => {
@@ -403,6 +449,21 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
                                     resettable: true,
                                 });
                                 self.add_assign(&path);
+                            } else if let Some(member) = input_modport_member(full_path) {
+                                self.errors.push(AnalyzerError::invalid_modport_access(
+                                    &member.token.to_string(),
+                                    "assigned",
+                                    "input",
+                                    self.text,
+                                    &arg.expression_identifier.as_ref().into(),
+                                ));
+                            } else if let Some(port) = input_port(full_path) {
+                                self.errors.push(AnalyzerError::invalid_assignment_to_input(
+                                    &port.token.to_string(),
+                                    self.text,
+                                    &arg.expression_identifier.as_ref().into(),
+                                    &port.token.into(),
+                                ));
                             } else {
                                 let token = arg.expression_identifier.identifier().token;
                                 self.errors.push(AnalyzerError::invalid_assignment(
@@ -477,9 +538,9 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
                 let has_explicit_default = arg.if_reset_statement_opt.is_some();
                 let has_cond_type = has_cond_type(&arg.if_reset.if_reset_token.token);
                 let has_default = has_explicit_default | has_cond_type;
-                let allow_missing_reset_statement = attribute_table::contains(
+                let allow_missing_reset_statement = attribute_table::is_allowed(
                     &arg.if_reset.if_reset_token.token,
-                    Attr::Allow(AllowItem::MissingResetStatement),
+                    AnalyzerErrorCode::MissingResetStatement,
                 );
                 self.assign_position
                     .push(AssignPositionType::StatementBranch {
@@ -626,6 +687,21 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
                         r#type: AssignDeclarationType::Assign,
                     });
                     self.add_assign(&path);
+                } else if let Some(member) = input_modport_member(full_path) {
+                    self.errors.push(AnalyzerError::invalid_modport_access(
+                        &member.token.to_string(),
+                        "assigned",
+                        "input",
+                        self.text,
+                        &arg.hierarchical_identifier.as_ref().into(),
+                    ));
+                } else if let Some(port) = input_port(full_path) {
+                    self.errors.push(AnalyzerError::invalid_assignment_to_input(
+                        &port.token.to_string(),
+                        self.text,
+                        &arg.hierarchical_identifier.as_ref().into(),
+                        &port.token.into(),
+                    ));
                 } else {
                     let token = &arg
                         .hierarchical_identifier
@@ -705,6 +781,32 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
                                     self.add_assign(&path);
                                 }
 
+                                // Check output connection to input member of modport
+                                if dir_output {
+                                    if let Some(member) = input_modport_member(full_path) {
+                                        self.errors.push(AnalyzerError::invalid_modport_access(
+                                            &member.token.to_string(),
+                                            "assigned",
+                                            "input",
+                                            self.text,
+                                            &token.into(),
+                                        ));
+                                    } else if let Some(port) = input_port(full_path).filter(|_| {
+                                        // Inout port can be connected to input port net
+                                        ports.get(&token.text).map(|x| x.direction)
+                                            == Some(Direction::Output)
+                                    }) {
+                                        self.errors.push(
+                                            AnalyzerError::invalid_assignment_to_input(
+                                                &port.token.to_string(),
+                                                self.text,
+                                                &token.into(),
+                                                &port.token.into(),
+                                            ),
+                                        );
+                                    }
+                                }
+
                                 // Check assignment of clock/reset type
                                 let (is_clock, is_reset) =
                                     if let Some(port) = ports.get(&token.text) {
@@ -759,6 +861,8 @@ impl VerylGrammarTrait for CheckVarRef<'_> {
                             }
                         }
                     }
+
+                    self.assign_position.pop();
                 }
             }
         }
@@ -0,0 +1,129 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::symbol::{Direction, SymbolKind};
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::TokenRange;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+use veryl_parser::ParolError;
+
+/// System functions which can be evaluated at elaboration
+const CONSTANT_FUNCTIONS: &[&str] = &["$clog2", "$bits", "$size"];
+
+/// System functions whose arguments are not required to be constant
+const TYPE_QUERY_FUNCTIONS: &[&str] = &["$bits", "$size"];
+
+#[derive(Default)]
+pub struct CheckWidthExpression<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    width_depth: usize,
+    ignore_depth: usize,
+    ignore_factors: Vec<bool>,
+}
+
+impl<'a> CheckWidthExpression<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+
+    fn enter(&mut self) {
+        match self.point {
+            HandlerPoint::Before => self.width_depth += 1,
+            HandlerPoint::After => self.width_depth -= 1,
+        }
+    }
+
+    /// Checks the factor and returns whether identifiers in it should be ignored
+    fn check_factor(&mut self, arg: &IdentifierFactor) -> bool {
+        let Ok(symbol) = symbol_table::resolve(arg.expression_identifier.as_ref()) else {
+            return false;
+        };
+        let token: TokenRange = arg.expression_identifier.as_ref().into();
+
+        if arg.function_call().is_some() {
+            let name = symbol.found.token.to_string();
+            let constant = matches!(symbol.found.kind, SymbolKind::SystemFunction)
+                && CONSTANT_FUNCTIONS.contains(&name.as_str());
+            if !constant {
+                self.errors.push(AnalyzerError::invalid_width_expression(
+                    &name,
+                    &symbol.found.kind.to_kind_name(),
+                    self.text,
+                    &token,
+                ));
+                return true;
+            }
+            return TYPE_QUERY_FUNCTIONS.contains(&name.as_str());
+        }
+
+        // member of variable like `a.b` is not constant
+        let head = symbol_table::get(symbol.full_path[0]).unwrap();
+        let target = match head.kind {
+            SymbolKind::Port(ref x)
+                if matches!(x.direction, Direction::Interface | Direction::Modport) =>
+            {
+                symbol.found
+            }
+            SymbolKind::Variable(_) | SymbolKind::Port(_) => head,
+            _ => symbol.found,
+        };
+
+        if matches!(target.kind, SymbolKind::Variable(_) | SymbolKind::Port(_)) {
+            self.errors.push(AnalyzerError::invalid_width_expression(
+                &target.token.to_string(),
+                &target.kind.to_kind_name(),
+                self.text,
+                &token,
+            ));
+        }
+        false
+    }
+}
+
+impl Handler for CheckWidthExpression<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckWidthExpression<'_> {
+    fn width(&mut self, _arg: &Width) -> Result<(), ParolError> {
+        self.enter();
+        Ok(())
+    }
+
+    fn array(&mut self, _arg: &Array) -> Result<(), ParolError> {
+        self.enter();
+        Ok(())
+    }
+
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Result<(), ParolError> {
+        if self.width_depth == 0 {
+            return Ok(());
+        }
+
+        match self.point {
+            HandlerPoint::Before => {
+                let ignore = if self.ignore_depth == 0 {
+                    self.check_factor(arg)
+                } else {
+                    false
+                };
+                if ignore {
+                    self.ignore_depth += 1;
+                }
+                self.ignore_factors.push(ignore);
+            }
+            HandlerPoint::After => {
+                if self.ignore_factors.pop().unwrap() {
+                    self.ignore_depth -= 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
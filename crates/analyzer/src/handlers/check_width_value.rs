@@ -0,0 +1,151 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::{ParameterKind, SymbolId, SymbolKind};
+use crate::symbol_table;
+use std::collections::HashSet;
+use veryl_metadata::Lint;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenRange};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+use veryl_parser::ParolError;
+
+/// Collects the first overridable parameter referred directly or through constants
+#[derive(Default)]
+struct ParameterCollector {
+    parameter: Option<Token>,
+    visited: HashSet<SymbolId>,
+}
+
+impl VerylWalker for ParameterCollector {
+    /// Semantic action for non-terminal 'ExpressionIdentifier'
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        if self.parameter.is_some() {
+            return;
+        }
+        let Ok(symbol) = symbol_table::resolve(arg) else {
+            return;
+        };
+        if let SymbolKind::Parameter(ref x) = symbol.found.kind {
+            match x.kind {
+                ParameterKind::Param => self.parameter = Some(symbol.found.token),
+                ParameterKind::Const => {
+                    if self.visited.insert(symbol.found.id) {
+                        self.expression(&x.value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the value evaluated by default values of parameters,
+/// and the parameter on which the value depends
+pub(crate) fn evaluate(arg: &Expression) -> Option<(isize, Option<Token>)> {
+    let mut evaluator = Evaluator::new();
+    let Evaluated::Fixed { value, .. } = evaluator.expression(arg) else {
+        return None;
+    };
+
+    let mut collector = ParameterCollector::default();
+    collector.expression(arg);
+    Some((value, collector.parameter))
+}
+
+pub struct CheckWidthValue<'a> {
+    pub errors: Vec<AnalyzerError>,
+    text: &'a str,
+    point: HandlerPoint,
+    redundant_width: bool,
+}
+
+impl<'a> CheckWidthValue<'a> {
+    pub fn new(text: &'a str, lint_opt: &Lint) -> Self {
+        Self {
+            errors: Vec::new(),
+            text,
+            point: HandlerPoint::Before,
+            redundant_width: lint_opt.redundant_width,
+        }
+    }
+
+    fn check_zero(&mut self, arg: &Expression, context: &str) {
+        let Some((0, parameter)) = evaluate(arg) else {
+            return;
+        };
+
+        let token: TokenRange = arg.into();
+        if let Some(parameter) = parameter {
+            self.errors.push(AnalyzerError::zero_by_default_parameter(
+                context,
+                &parameter.to_string(),
+                self.text,
+                &token,
+            ));
+        } else if context == "width" {
+            self.errors
+                .push(AnalyzerError::zero_width(self.text, &token));
+        } else {
+            self.errors
+                .push(AnalyzerError::zero_repetition(self.text, &token));
+        }
+    }
+}
+
+impl Handler for CheckWidthValue<'_> {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckWidthValue<'_> {
+    fn width(&mut self, arg: &Width) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_zero(&arg.expression, "width");
+            for x in &arg.width_list {
+                self.check_zero(&x.expression, "width");
+            }
+        }
+        Ok(())
+    }
+
+    fn concatenation_item(&mut self, arg: &ConcatenationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if let Some(ref x) = arg.concatenation_item_opt {
+                self.check_zero(&x.expression, "repetition count");
+            }
+        }
+        Ok(())
+    }
+
+    fn factor_type(&mut self, arg: &FactorType) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.redundant_width {
+                return Ok(());
+            }
+
+            let FactorTypeGroup::VariableTypeFactorTypeOpt(ref x) = *arg.factor_type_group else {
+                return Ok(());
+            };
+            let kind = match *x.variable_type {
+                VariableType::Logic(_) => "logic",
+                VariableType::Bit(_) => "bit",
+                _ => return Ok(()),
+            };
+            let Some(ref width) = x.factor_type_opt else {
+                return Ok(());
+            };
+
+            // parameterized width may be changed by overriding
+            if width.width.width_list.is_empty()
+                && matches!(evaluate(&width.width.expression), Some((1, None)))
+            {
+                self.errors.push(AnalyzerError::redundant_width(
+                    kind,
+                    self.text,
+                    &width.width.as_ref().into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ pub struct CreateReference<'a> {
     inst_sv_module: bool,
     is_anonymous_identifier: bool,
     port_direction: Option<Direction>,
+    in_modport_port: bool,
     dag_scope_parent: Vec<u32>,
     dag_scope_context: Vec<Context>,
     dag_type_parent: Vec<u32>,
@@ -55,8 +56,30 @@ impl<'a> CreateReference<'a> {
                         false
                     };
 
-                    if !is_generic_if {
-                        let member = format!("{}", not_found);
+                    if is_generic_if {
+                        return;
+                    }
+
+                    let member = format!("{}", not_found);
+                    let interface = if let SymbolKind::GenericInstance(ref x) = last_found.kind {
+                        symbol_table::get(x.base)
+                    } else {
+                        Some(last_found.clone())
+                    };
+                    let interface = interface.filter(|x| {
+                        self.in_modport_port && matches!(x.kind, SymbolKind::Interface(_))
+                    });
+
+                    if let Some(interface) = interface {
+                        let candidates = symbol_table::modport_names(&interface);
+                        self.errors.push(AnalyzerError::unknown_modport(
+                            &interface.token.to_string(),
+                            &member,
+                            &candidates,
+                            self.text,
+                            token,
+                        ));
+                    } else {
                         self.errors.push(AnalyzerError::unknown_member(
                             &name, &member, self.text, token,
                         ));
@@ -81,8 +104,17 @@ impl<'a> CreateReference<'a> {
                 self.errors
                     .push(AnalyzerError::anonymous_identifier_usage(self.text, token));
             } else {
-                self.errors
-                    .push(AnalyzerError::undefined_identifier(&name, self.text, token));
+                let namespace = namespace_table::get(token.beg.id).unwrap_or_default();
+                let similar = symbol_table::similar_names(not_found, &namespace);
+                let packages = symbol_table::packages_containing(not_found, &namespace);
+                let package = if let [x] = packages.as_slice() {
+                    Some(x.as_str())
+                } else {
+                    None
+                };
+                self.errors.push(AnalyzerError::undefined_identifier(
+                    &name, &similar, package, self.text, token,
+                ));
             }
         } else {
             unreachable!();
@@ -99,6 +131,8 @@ impl<'a> CreateReference<'a> {
             return;
         }
 
+        // The reference should point the original token even if the path is expanded by import
+        let reference = path.paths[0].base;
         let mut path = path.clone();
         path.resolve_imported(namespace);
 
@@ -108,7 +142,8 @@ impl<'a> CreateReference<'a> {
             match symbol_table::resolve((&base_path, namespace)) {
                 Ok(symbol) => {
                     self.check_pacakge_reference(&symbol.found, &path.range);
-                    symbol_table::add_reference(symbol.found.id, &path.paths[0].base);
+                    self.check_private_reference(&symbol.found, namespace, &path.range);
+                    symbol_table::add_reference(symbol.found.id, &reference);
 
                     // Check number of arguments
                     let params = symbol.found.generic_parameters();
@@ -119,7 +154,16 @@ impl<'a> CreateReference<'a> {
                         params.len() == n_args
                     };
 
-                    if !match_artiy {
+                    if n_args > params.len() {
+                        self.errors.push(AnalyzerError::too_many_generic_arguments(
+                            &path.paths[i].base.to_string(),
+                            params.len(),
+                            n_args,
+                            self.text,
+                            &path.paths[i].arguments[params.len()].range,
+                        ));
+                        continue;
+                    } else if !match_artiy {
                         self.errors.push(AnalyzerError::mismatch_generics_arity(
                             &path.paths[i].base.to_string(),
                             params.len(),
@@ -207,6 +251,29 @@ impl<'a> CreateReference<'a> {
         }
     }
 
+    fn check_private_reference(
+        &mut self,
+        symbol: &Symbol,
+        namespace: &Namespace,
+        token_range: &TokenRange,
+    ) {
+        if !symbol.is_private() || symbol.namespace.depth() < 2 {
+            return;
+        }
+
+        // package is placed at the top level, so the first two paths are project and package
+        let package = Namespace::from(&symbol.namespace.paths[..2]);
+        if !namespace.included(&package) {
+            self.errors.push(AnalyzerError::private_symbol_access(
+                &symbol.token.to_string(),
+                &package.paths[1].to_string(),
+                self.text,
+                token_range,
+                &symbol.token.into(),
+            ));
+        }
+    }
+
     fn insert_declaration_dag_node(&mut self, symbol: &Symbol) -> Option<u32> {
         if let Some(child) = self.insert_dag_node(symbol) {
             if let Some(parent) = self.dag_scope_parent.last().cloned() {
@@ -296,6 +363,26 @@ impl<'a> CreateReference<'a> {
         }
     }
 
+    fn insert_dag_instance(&mut self, symbol: &Symbol, token: &Token) {
+        // instance of generic module is treated as the base module
+        let symbol = match symbol.kind {
+            SymbolKind::GenericInstance(ref x) => symbol_table::get(x.base),
+            _ => Some(symbol.clone()),
+        };
+        let Some(symbol) = symbol.filter(|x| matches!(x.kind, SymbolKind::Module(_))) else {
+            return;
+        };
+        if !matches!(self.dag_scope_context.last(), Some(Context::Module)) {
+            return;
+        }
+
+        if let Some(parent) = self.dag_scope_parent.last().cloned() {
+            if let Some(child) = self.insert_dag_node(&symbol) {
+                type_dag::insert_instance(parent, child, *token);
+            }
+        }
+    }
+
     fn insert_dag_edge(&mut self, start: u32, end: u32, edge: Context) {
         // Reversing this order to make traversal work
         match type_dag::insert_edge(end, start, edge) {
@@ -512,6 +599,10 @@ impl VerylGrammarTrait for CreateReference<'_> {
                 }
             }
             HandlerPoint::After => {
+                // generic instance is resolvable after visiting the generic arguments
+                if let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+                    self.insert_dag_instance(&symbol.found, &arg.identifier.identifier_token.token);
+                }
                 self.inst_ports.clear();
                 self.inst_sv_module = false;
             }
@@ -562,11 +653,16 @@ impl VerylGrammarTrait for CreateReference<'_> {
     fn port_type_concrete(&mut self, arg: &PortTypeConcrete) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => {
+                let direction: Direction = arg.direction.as_ref().into();
+                self.in_modport_port = direction == Direction::Modport;
                 if arg.port_type_concrete_opt0.is_some() {
-                    self.port_direction = Some(arg.direction.as_ref().into());
+                    self.port_direction = Some(direction);
                 }
             }
-            HandlerPoint::After => self.port_direction = None,
+            HandlerPoint::After => {
+                self.port_direction = None;
+                self.in_modport_port = false;
+            }
         }
         Ok(())
     }
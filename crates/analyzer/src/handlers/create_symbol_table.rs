@@ -1,6 +1,6 @@
-use crate::analyzer_error::AnalyzerError;
+use crate::analyzer_error::{AnalyzerError, AnalyzerErrorCode};
 use crate::attribute::Attribute as Attr;
-use crate::attribute::{AllowItem, EnumEncodingItem};
+use crate::attribute::EnumEncodingItem;
 use crate::attribute_table;
 use crate::evaluator::Evaluated;
 use crate::evaluator::Evaluator;
@@ -142,14 +142,18 @@ impl<'a> CreateSymbolTable<'a> {
         };
         let mut symbol = Symbol::new(token, kind, &self.namespace, public, doc_comment);
 
-        if attribute_table::contains(token, Attr::Allow(AllowItem::UnusedVariable)) {
+        if attribute_table::is_allowed(token, AnalyzerErrorCode::UnusedVariable) {
             symbol.allow_unused = true;
         }
 
         let id = symbol_table::insert(token, symbol);
         if id.is_none() {
+            let declared = symbol_table::resolve((token, &self.namespace))
+                .ok()
+                .map(|x| x.found.token);
             self.errors.push(AnalyzerError::duplicated_identifier(
                 &token.to_string(),
+                declared.as_ref(),
                 self.text,
                 &token.into(),
             ));
@@ -671,7 +675,13 @@ impl VerylGrammarTrait for CreateSymbolTable<'_> {
             self.namespace
                 .push(arg.identifier.identifier_token.token.text);
 
+            // duplicated items are reported by check_modport
+            let mut inserted = HashSet::new();
             for item in items {
+                if !inserted.insert(item.identifier.identifier_token.token.text) {
+                    continue;
+                }
+
                 let kind = match &*item.direction {
                     Direction::Ref(_) | Direction::Modport(_) => {
                         continue;
@@ -868,9 +878,18 @@ impl VerylGrammarTrait for CreateSymbolTable<'_> {
     fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
         if let HandlerPoint::After = self.point {
             let type_name: GenericSymbolPath = arg.scoped_identifier.as_ref().into();
+            let mut array = Vec::new();
+            if let Some(ref x) = arg.inst_declaration_opt {
+                let x = &x.array;
+                array.push(*x.expression.clone());
+                for x in &x.array_list {
+                    array.push(*x.expression.clone());
+                }
+            }
             let connects = self.connects.drain().collect();
             let property = InstanceProperty {
                 type_name,
+                array,
                 connects,
             };
             let kind = SymbolKind::Instance(property);
@@ -0,0 +1,191 @@
+use crate::namespace::Namespace;
+use crate::namespace_table;
+use crate::symbol::{Symbol, SymbolKind};
+use crate::symbol_path::SymbolPath;
+use crate::symbol_table;
+use std::collections::HashSet;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{TokenRange, TokenSource};
+use veryl_parser::veryl_walker::VerylWalker;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnusedImportKind {
+    /// No identifier is resolved through the import
+    Unused,
+    /// The same package or item is imported in the same scope before
+    Duplicated,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnusedImport {
+    pub kind: UnusedImportKind,
+    /// `Package::item` or `Package::*`
+    pub name: String,
+    /// From `import` to `;`
+    pub range: TokenRange,
+}
+
+/// Returns imports in `veryl` which can be removed.
+///
+/// A wildcard import is used only if at least one identifier is resolved through it.
+/// File scope imports are used if any module, interface or package in the file uses them.
+/// Imports re-exported by the package are treated as used.
+/// The symbol table must be built from `veryl`.
+pub fn unused_imports(veryl: &Veryl) -> Vec<UnusedImport> {
+    let mut collector = ImportCollector::default();
+    collector.veryl(veryl);
+
+    if collector.imports.is_empty() {
+        return Vec::new();
+    }
+
+    let symbols = symbol_table::get_all();
+    let exports: Vec<_> = collector
+        .exports
+        .iter()
+        .filter_map(|x| export_target(x, &symbols))
+        .collect();
+
+    let mut ret = Vec::new();
+    let mut imported = HashSet::new();
+    for declaration in &collector.imports {
+        let identifier = declaration.scoped_identifier.identifier().token;
+        let Some(namespace) = namespace_table::get(identifier.id) else {
+            continue;
+        };
+        let Ok(symbol) = symbol_table::resolve(declaration.scoped_identifier.as_ref()) else {
+            continue;
+        };
+        let symbol = symbol.found;
+        let wildcard = declaration.import_declaration_opt.is_some();
+
+        let package = match symbol.kind {
+            SymbolKind::SystemVerilog => continue,
+            SymbolKind::Package(_) if wildcard => Some(symbol.inner_namespace()),
+            // Error is reported at create_reference
+            _ if wildcard => continue,
+            _ => None,
+        };
+
+        let path: SymbolPath = declaration.scoped_identifier.as_ref().into();
+        let mut name: Vec<_> = path.as_slice().iter().map(|x| x.to_string()).collect();
+        if wildcard {
+            name.push("*".to_string());
+        }
+        let name = name.join("::");
+        let range = TokenRange {
+            beg: declaration.import.import_token.token,
+            end: declaration.semicolon.semicolon_token.token,
+        };
+
+        if !imported.insert((namespace.clone(), symbol.id, wildcard)) {
+            ret.push(UnusedImport {
+                kind: UnusedImportKind::Duplicated,
+                name,
+                range,
+            });
+            continue;
+        }
+
+        // File scope imports are applied to each top level declaration in the file
+        let scopes: Vec<Namespace> = if namespace.depth() <= 1 {
+            symbols
+                .iter()
+                .filter(|x| {
+                    x.token.source == identifier.source
+                        && x.namespace.matched(&namespace)
+                        && matches!(
+                            x.kind,
+                            SymbolKind::Module(_)
+                                | SymbolKind::Interface(_)
+                                | SymbolKind::Package(_)
+                        )
+                })
+                .map(|x| x.inner_namespace())
+                .collect()
+        } else {
+            vec![namespace.clone()]
+        };
+
+        let used = scopes.iter().any(|scope| {
+            symbol_table::get_import_usage(scope).into_iter().any(|id| {
+                let Some(used) = symbol_table::get(id) else {
+                    return false;
+                };
+                if let Some(ref package) = package {
+                    used.namespace.matched(package)
+                } else {
+                    used.token.id == symbol.token.id
+                }
+            })
+        });
+
+        let exported = exports.iter().any(|(scope, target)| {
+            if !scope.matched(&namespace) {
+                return false;
+            }
+            match (target, &package) {
+                // export *;
+                (None, _) => true,
+                (Some((x, true)), Some(package)) => x.inner_namespace().matched(package),
+                (Some((x, true)), None) => symbol.namespace.matched(&x.inner_namespace()),
+                (Some((x, false)), Some(package)) => x.namespace.matched(package),
+                (Some((x, false)), None) => x.token.id == symbol.token.id,
+            }
+        });
+
+        if !used && !exported {
+            ret.push(UnusedImport {
+                kind: UnusedImportKind::Unused,
+                name,
+                range,
+            });
+        }
+    }
+
+    ret
+}
+
+/// Returns the namespace of the package which has the export declaration,
+/// and the exported symbol with whether it is wildcard. The symbol is `None` for `export *`.
+fn export_target(
+    arg: &ExportDeclaration,
+    symbols: &[Symbol],
+) -> Option<(Namespace, Option<(Symbol, bool)>)> {
+    let token = arg.export.export_token.token;
+    let TokenSource::File(path) = token.source else {
+        return None;
+    };
+    let package = symbols.iter().find(|x| {
+        if let SymbolKind::Package(ref x) = x.kind {
+            x.range.include(path, token.line, token.column)
+        } else {
+            false
+        }
+    })?;
+
+    let target = match arg.export_declaration_group.as_ref() {
+        ExportDeclarationGroup::Star(_) => None,
+        ExportDeclarationGroup::ScopedIdentifierExportDeclarationOpt(x) => {
+            let symbol = symbol_table::resolve(x.scoped_identifier.as_ref()).ok()?;
+            Some((symbol.found, x.export_declaration_opt.is_some()))
+        }
+    };
+    Some((package.inner_namespace(), target))
+}
+
+#[derive(Default)]
+struct ImportCollector {
+    imports: Vec<ImportDeclaration>,
+    exports: Vec<ExportDeclaration>,
+}
+
+impl VerylWalker for ImportCollector {
+    fn import_declaration(&mut self, arg: &ImportDeclaration) {
+        self.imports.push(arg.clone());
+    }
+
+    fn export_declaration(&mut self, arg: &ExportDeclaration) {
+        self.exports.push(arg.clone());
+    }
+}
@@ -2,12 +2,18 @@ pub mod analyzer;
 pub mod analyzer_error;
 pub mod attribute;
 pub mod attribute_table;
+pub mod cast;
+pub mod cdc_table;
 pub mod evaluator;
 pub mod handlers;
+pub mod import_usage;
 pub mod msb_table;
 pub mod namespace;
 pub mod namespace_table;
 pub mod range_table;
+pub mod refactor;
+pub mod snapshot;
+pub mod struct_port;
 pub mod symbol;
 pub mod symbol_path;
 pub mod symbol_table;
@@ -15,7 +21,8 @@ pub mod type_dag;
 pub mod r#unsafe;
 pub mod unsafe_table;
 pub mod var_ref;
+pub mod width;
 pub use analyzer::Analyzer;
-pub use analyzer_error::AnalyzerError;
+pub use analyzer_error::{AnalyzerError, AnalyzerErrorCode, AnalyzerErrorLocation};
 #[cfg(test)]
 mod tests;
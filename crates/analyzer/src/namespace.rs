@@ -1,10 +1,11 @@
 use crate::namespace_table;
 use crate::symbol_path::SymbolPath;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use veryl_parser::resource_table::StrId;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Namespace {
     pub paths: Vec<StrId>,
 }
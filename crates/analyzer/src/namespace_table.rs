@@ -27,6 +27,13 @@ impl NamespaceTable {
         self.table.retain(|_, x| x.1 != file_path);
     }
 
+    pub fn get_all(&self) -> Vec<(TokenId, Namespace, PathId)> {
+        self.table
+            .iter()
+            .map(|(id, x)| (*id, x.0.clone(), x.1))
+            .collect()
+    }
+
     pub fn set_default(&mut self, id: &[StrId]) {
         let mut namespace = Namespace::new();
         for id in id {
@@ -98,6 +105,10 @@ pub fn drop(file_path: PathId) {
     NAMESPACE_TABLE.with(|f| f.borrow_mut().drop(file_path))
 }
 
+pub fn get_all() -> Vec<(TokenId, Namespace, PathId)> {
+    NAMESPACE_TABLE.with(|f| f.borrow().get_all())
+}
+
 pub fn set_default(id: &[StrId]) {
     NAMESPACE_TABLE.with(|f| f.borrow_mut().set_default(id))
 }
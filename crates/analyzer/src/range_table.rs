@@ -68,6 +68,32 @@ where
         ret
     }
 
+    /// Returns the value of the innermost range including the token which satisfies `f`
+    pub fn get_innermost<F: Fn(&T) -> bool>(&self, token: &Token, f: F) -> Option<T> {
+        let mut ret: Option<(&Token, &T)> = None;
+
+        if let TokenSource::File(path) = token.source {
+            if let Some(values) = self.table.get(&path) {
+                for (range, value) in values {
+                    if range.include(path, token.line, token.column) && f(value) {
+                        ret = inner(ret, (&range.beg, value));
+                    }
+                }
+            }
+        }
+
+        // Values which are not closed include the token
+        for (beg, t) in &self.temporary {
+            if let Some(t) = t {
+                if f(t) {
+                    ret = inner(ret, (beg, t));
+                }
+            }
+        }
+
+        ret.map(|(_, x)| x.clone())
+    }
+
     pub fn contains(&self, token: &Token, value: &T) -> bool {
         let attrs = self.get(token);
         attrs.contains(value)
@@ -86,6 +112,22 @@ where
     }
 }
 
+/// Ranges are nested, so the range which begins later is inner
+fn inner<'a, T>(
+    x: Option<(&'a Token, &'a T)>,
+    y: (&'a Token, &'a T),
+) -> Option<(&'a Token, &'a T)> {
+    if let Some(x) = x {
+        if (y.0.line, y.0.column) >= (x.0.line, x.0.column) {
+            Some(y)
+        } else {
+            Some(x)
+        }
+    } else {
+        Some(y)
+    }
+}
+
 impl<T> fmt::Display for RangeTable<T>
 where
     T: std::fmt::Display,
@@ -0,0 +1,734 @@
+use crate::handlers::check_type;
+use crate::import_usage;
+use crate::namespace::Namespace;
+use crate::namespace_table;
+use crate::symbol::{ParameterKind, Symbol, SymbolId, SymbolKind};
+use crate::symbol_path::SymbolPath;
+use crate::symbol_table;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+use veryl_parser::resource_table::{self, PathId, StrId};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenRange, TokenSource, VerylToken};
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Parser;
+
+/// Position in source text. Both of line and column are 1-origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Position {
+    pub fn new(line: u32, column: u32) -> Self {
+        Self { line, column }
+    }
+}
+
+/// Replacement of text between `beg` and `end` by `text`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    pub path: PathId,
+    pub beg: Position,
+    pub end: Position,
+    pub text: String,
+}
+
+impl TextEdit {
+    fn replace(token: &Token, path: PathId, text: &str) -> Self {
+        Self {
+            path,
+            beg: Position::new(token.line, token.column),
+            end: Position::new(token.line, token.column + token.length),
+            text: text.to_string(),
+        }
+    }
+
+    fn insert(path: PathId, pos: Position, text: &str) -> Self {
+        Self {
+            path,
+            beg: pos,
+            end: pos,
+            text: text.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RefactorError {
+    #[error("\"{name}\" is not a struct, union, enum, type or const declared in a package")]
+    NotMovable { name: String },
+
+    #[error("\"{name}\" is not a package")]
+    NotPackage { name: String },
+
+    #[error("generic package \"{name}\" is not supported")]
+    GenericPackage { name: String },
+
+    #[error("\"{name}\" is already declared in package \"{package}\"")]
+    Conflict { name: String, package: String },
+
+    #[error("source of \"{path}\" is not available")]
+    SourceUnavailable { path: String },
+
+    #[error("declaration of \"{name}\" is not found in \"{path}\"")]
+    DeclarationNotFound { name: String, path: String },
+}
+
+/// Moves a struct/union/enum/type/const declaration to `target` package,
+/// and returns text edits which update the declaration and all references to it.
+///
+/// `source` provides the current text of each file.
+/// The symbol table must be built from the same text.
+pub fn move_symbol<F>(
+    symbol: SymbolId,
+    target: SymbolId,
+    source: F,
+) -> Result<Vec<TextEdit>, RefactorError>
+where
+    F: Fn(PathId) -> Option<String>,
+{
+    let symbol = symbol_table::get(symbol).unwrap();
+    let target = symbol_table::get(target).unwrap();
+    let name = symbol.token.to_string();
+
+    let movable = match &symbol.kind {
+        SymbolKind::Struct(_)
+        | SymbolKind::Union(_)
+        | SymbolKind::Enum(_)
+        | SymbolKind::TypeDef(_) => true,
+        SymbolKind::Parameter(x) => matches!(x.kind, ParameterKind::Const),
+        _ => false,
+    };
+    let package = parent_package(&symbol);
+    let (Some(package), true) = (package, movable) else {
+        return Err(RefactorError::NotMovable { name });
+    };
+
+    let SymbolKind::Package(ref target_property) = target.kind else {
+        return Err(RefactorError::NotPackage {
+            name: target.token.to_string(),
+        });
+    };
+    for x in [&package, &target] {
+        if let SymbolKind::Package(ref property) = x.kind {
+            if !property.generic_parameters.is_empty() {
+                return Err(RefactorError::GenericPackage {
+                    name: x.token.to_string(),
+                });
+            }
+        }
+    }
+
+    let target_namespace = target.inner_namespace();
+    let path = SymbolPath::new(&[symbol.token.text]);
+    if let Ok(x) = symbol_table::resolve((&path, &target_namespace)) {
+        if x.found.namespace.matched(&target_namespace) {
+            return Err(RefactorError::Conflict {
+                name,
+                package: target.token.to_string(),
+            });
+        }
+    }
+
+    let mut sources = Sources::new(source);
+    let TokenSource::File(path) = symbol.token.source else {
+        return Err(RefactorError::NotMovable { name });
+    };
+
+    let declaration = {
+        let text = sources.get(path)?;
+        find_declaration(&symbol.token, path, text).ok_or_else(|| {
+            RefactorError::DeclarationNotFound {
+                name: name.clone(),
+                path: path.to_string(),
+            }
+        })?
+    };
+
+    let package_name = package.token.to_string();
+    let target_name = target.token.to_string();
+    let qualified_name = format!("{target_name}::{name}");
+    let mut ret = Vec::new();
+
+    // Move declaration
+    let text = sources.get(path)?;
+    let mut declaration_text = declaration.text(text);
+    qualify_package_members(
+        &mut declaration_text,
+        &declaration,
+        &symbol,
+        &package,
+        &package_name,
+    );
+    ret.push(declaration.remove(path, text));
+
+    let TokenSource::File(target_path) = target_property.range.end.source else {
+        return Err(RefactorError::NotPackage { name: target_name });
+    };
+    let text = sources.get(target_path)?;
+    ret.push(insert_declaration(
+        target_path,
+        text,
+        &target_property.range.end,
+        &declaration_text,
+    ));
+
+    // Update references
+    let package_range = match &package.kind {
+        SymbolKind::Package(x) => x.range,
+        _ => unreachable!(),
+    };
+    let mut imported_paths = Vec::new();
+    let mut visited = HashSet::new();
+    for reference in &symbol.references {
+        let TokenSource::File(ref_path) = reference.source else {
+            continue;
+        };
+        if !visited.insert((ref_path, reference.pos)) {
+            continue;
+        }
+        if ref_path == path && declaration.include(reference) {
+            continue;
+        }
+
+        if reference.text == package.token.text {
+            // Qualified reference like `Package::Name`
+            ret.push(TextEdit::replace(reference, ref_path, &target_name));
+        } else if reference.text == symbol.token.text {
+            if package_range.include(ref_path, reference.line, reference.column) {
+                ret.push(TextEdit::replace(reference, ref_path, &qualified_name));
+            } else if !imported_paths.contains(&ref_path) {
+                imported_paths.push(ref_path);
+            }
+        }
+    }
+
+    // Add wildcard import of the target package next to the one of the source package
+    for ref_path in imported_paths {
+        let text = sources.get(ref_path)?;
+        let import = format!("import {target_name}::*;");
+        if text.contains(&import) {
+            continue;
+        }
+
+        let mut visited = HashSet::new();
+        for reference in &package.references {
+            if reference.source != ref_path || !visited.insert(reference.pos) {
+                continue;
+            }
+            if let Some(pos) = wildcard_import_end(text, reference) {
+                let indent = line_indent(text, pos);
+                let edit = format!("\n{indent}{import}");
+                ret.push(TextEdit::insert(ref_path, position(text, pos), &edit));
+            }
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Style of port connections added by `connect_ports`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortConnection {
+    /// `port: ,` to be filled, or `port,` if a signal of the same name exists
+    Explicit,
+    /// `port,` for all ports
+    Implicit,
+}
+
+/// Adds missing port connections to the instance declaration at `pos`,
+/// and returns text edits. `None` is returned if no port is missing.
+///
+/// `veryl` and `text` must be the ones from which the symbol table is built.
+pub fn connect_ports(
+    veryl: &Veryl,
+    text: &str,
+    pos: Position,
+    style: PortConnection,
+) -> Option<Vec<TextEdit>> {
+    let mut finder = InstDeclarationFinder { pos, inst: None };
+    finder.veryl(veryl);
+    let inst = finder.inst?;
+
+    let missing = check_type::missing_ports(&inst);
+    if missing.is_empty() {
+        return None;
+    }
+
+    let inst_token = inst.inst.inst_token.token;
+    let TokenSource::File(path) = inst_token.source else {
+        return None;
+    };
+    let namespace = namespace_table::get(inst.identifier.identifier_token.token.id)?;
+    let names: Vec<_> = missing
+        .iter()
+        .map(|x| {
+            let name = x.name();
+            let implicit = style == PortConnection::Implicit || is_signal(name, &namespace);
+            (resource_table::get_str_value(name).unwrap(), implicit)
+        })
+        .collect();
+
+    // Widths of existing connections to align new ones
+    let items: Vec<InstPortItem> = match &inst.inst_declaration_opt1 {
+        Some(InstDeclarationOpt1 {
+            inst_declaration_opt2: Some(x),
+            ..
+        }) => x.inst_port_list.as_ref().into(),
+        _ => Vec::new(),
+    };
+    let mut name_width = 0;
+    let mut entry_width = 0;
+    for item in &items {
+        let identifier = item.identifier.identifier_token.token;
+        name_width = name_width.max(identifier.length as usize);
+        let mut tokens = TokenCollector::default();
+        tokens.inst_port_item(item);
+        if let Some(last) = tokens.last {
+            if last.line == identifier.line {
+                entry_width =
+                    entry_width.max((last.column + last.length - identifier.column) as usize);
+            }
+        }
+    }
+    for (name, _) in &names {
+        name_width = name_width.max(name.chars().count());
+    }
+    entry_width = entry_width.max(name_width);
+
+    let inst_indent = line_indent(text, inst_token.pos as usize);
+    let entry = |name: &str, implicit: bool, multiline: bool| match (implicit, multiline) {
+        (true, true) => format!("{name:entry_width$}"),
+        (false, true) => format!("{name:name_width$}:"),
+        (true, false) => name.to_string(),
+        (false, false) => format!("{name}: "),
+    };
+    let lines = |indent: &str, trailing_comma: bool| {
+        let mut ret = String::new();
+        for (i, (name, implicit)) in names.iter().enumerate() {
+            let last = i + 1 == names.len();
+            let entry = entry(name, *implicit, true);
+            if !last || trailing_comma {
+                let comma = if *implicit { "," } else { " ," };
+                ret.push_str(&format!("{indent}{entry}{comma}\n"));
+            } else {
+                ret.push_str(&format!("{indent}{}\n", entry.trim_end()));
+            }
+        }
+        ret
+    };
+
+    let edit = match &inst.inst_declaration_opt1 {
+        None => {
+            let semicolon = inst.semicolon.semicolon_token.token;
+            let text = format!(
+                " (\n{}{inst_indent})",
+                lines(&format!("{inst_indent}    "), true)
+            );
+            vec![TextEdit::insert(
+                path,
+                Position::new(semicolon.line, semicolon.column),
+                &text,
+            )]
+        }
+        Some(x) => {
+            let l_paren = x.l_paren.l_paren_token.token;
+            let r_paren = x.r_paren.r_paren_token.token;
+            if let Some(ref list) = x.inst_declaration_opt2 {
+                let mut tokens = TokenCollector::default();
+                tokens.inst_port_list(&list.inst_port_list);
+                let first = tokens.first.unwrap();
+                let last = tokens.last.unwrap();
+                let trailing_comma = list.inst_port_list.inst_port_list_opt.is_some();
+
+                if r_paren.line > last.line {
+                    let indent = if first.line > l_paren.line {
+                        line_indent(text, first.pos as usize).to_string()
+                    } else {
+                        format!("{inst_indent}    ")
+                    };
+                    let mut ret = Vec::new();
+                    if !trailing_comma {
+                        let pos = Position::new(last.line, last.column + last.length);
+                        ret.push(TextEdit::insert(path, pos, ","));
+                    }
+                    ret.push(TextEdit::insert(
+                        path,
+                        Position::new(r_paren.line, 1),
+                        &lines(&indent, trailing_comma),
+                    ));
+                    ret
+                } else {
+                    let entries: Vec<_> = names
+                        .iter()
+                        .map(|(name, implicit)| entry(name, *implicit, false))
+                        .collect();
+                    let text = if trailing_comma {
+                        format!(" {},", entries.join(", "))
+                    } else {
+                        format!(", {}", entries.join(", "))
+                    };
+                    vec![TextEdit::insert(
+                        path,
+                        Position::new(r_paren.line, r_paren.column),
+                        &text,
+                    )]
+                }
+            } else {
+                let text = format!(
+                    "\n{}{inst_indent}",
+                    lines(&format!("{inst_indent}    "), true)
+                );
+                vec![TextEdit {
+                    path,
+                    beg: Position::new(l_paren.line, l_paren.column + l_paren.length),
+                    end: Position::new(r_paren.line, r_paren.column),
+                    text,
+                }]
+            }
+        }
+    };
+
+    Some(edit)
+}
+
+/// Removes unused and duplicated imports in `veryl`, and returns text edits.
+/// `None` is returned if there is no import to be removed.
+///
+/// `veryl` and `text` must be the ones from which the symbol table is built.
+pub fn remove_unused_imports(veryl: &Veryl, text: &str) -> Option<Vec<TextEdit>> {
+    let lines: Vec<_> = text.lines().collect();
+    let mut ret = Vec::new();
+    for import in import_usage::unused_imports(veryl) {
+        let beg = import.range.beg;
+        let end = import.range.end;
+        let TokenSource::File(path) = beg.source else {
+            continue;
+        };
+
+        // Remove the whole lines if nothing other than the import is on them
+        let line = |line: u32| lines.get((line - 1) as usize).copied().unwrap_or("");
+        let head: String = line(beg.line)
+            .chars()
+            .take((beg.column - 1) as usize)
+            .collect();
+        let tail: String = line(end.line)
+            .chars()
+            .skip((end.column + end.length - 1) as usize)
+            .collect();
+        let alone = head.trim().is_empty() && tail.trim().is_empty();
+
+        let edit = if alone {
+            let declaration = Declaration {
+                beg_line: beg.line,
+                end_line: end.line,
+                pos: beg.pos,
+            };
+            declaration.remove(path, text)
+        } else {
+            TextEdit {
+                path,
+                beg: Position::new(beg.line, beg.column),
+                end: Position::new(end.line, end.column + end.length),
+                text: String::new(),
+            }
+        };
+        ret.push(edit);
+    }
+
+    if ret.is_empty() {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
+/// Returns whether a signal named `name` can be referred in `namespace`
+fn is_signal(name: StrId, namespace: &Namespace) -> bool {
+    let path = SymbolPath::new(&[name]);
+    if let Ok(x) = symbol_table::resolve((&path, namespace)) {
+        matches!(
+            x.found.kind,
+            SymbolKind::Variable(_)
+                | SymbolKind::Port(_)
+                | SymbolKind::Parameter(_)
+                | SymbolKind::Instance(_)
+        )
+    } else {
+        false
+    }
+}
+
+fn parent_package(symbol: &Symbol) -> Option<Symbol> {
+    let mut namespace = symbol.namespace.clone();
+    let name = namespace.pop()?;
+    if namespace.depth() == 0 {
+        return None;
+    }
+    let path = SymbolPath::new(&[name]);
+    let package = symbol_table::resolve((&path, &namespace)).ok()?.found;
+    if matches!(package.kind, SymbolKind::Package(_))
+        && package.inner_namespace() == symbol.namespace
+    {
+        Some(package)
+    } else {
+        None
+    }
+}
+
+/// Qualifies references to other members of the source package in the moved declaration
+fn qualify_package_members(
+    text: &mut String,
+    declaration: &Declaration,
+    symbol: &Symbol,
+    package: &Symbol,
+    package_name: &str,
+) {
+    let namespace: Namespace = package.inner_namespace();
+    let mut edits = Vec::new();
+    for member in symbol_table::get_all() {
+        if member.id == symbol.id || !member.namespace.matched(&namespace) {
+            continue;
+        }
+        for reference in &member.references {
+            if reference.source == symbol.token.source
+                && reference.text == member.token.text
+                && declaration.include(reference)
+            {
+                let pos = (reference.pos - declaration.pos) as usize;
+                edits.push((pos, reference.length as usize, member.token.to_string()));
+            }
+        }
+    }
+
+    edits.sort_by_key(|x| std::cmp::Reverse(x.0));
+    edits.dedup_by(|a, b| a.0 == b.0);
+    for (pos, length, name) in edits {
+        text.replace_range(pos..pos + length, &format!("{package_name}::{name}"));
+    }
+}
+
+fn insert_declaration(path: PathId, text: &str, r_brace: &Token, declaration: &str) -> TextEdit {
+    let lines: Vec<_> = text.lines().collect();
+    let line = lines[(r_brace.line - 1) as usize];
+    let head: String = line.chars().take((r_brace.column - 1) as usize).collect();
+
+    if head.trim().is_empty() {
+        let prev = lines[(r_brace.line - 2) as usize].trim_end();
+        let text = if prev.ends_with('{') {
+            declaration.to_string()
+        } else {
+            format!("\n{declaration}")
+        };
+        TextEdit::insert(path, Position::new(r_brace.line, 1), &text)
+    } else {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let text = format!("\n{declaration}{indent}");
+        TextEdit::insert(path, Position::new(r_brace.line, r_brace.column), &text)
+    }
+}
+
+/// Returns the byte position after `import Package::*;` if `token` is `Package` of it
+fn wildcard_import_end(text: &str, token: &Token) -> Option<usize> {
+    let head = text[..token.pos as usize].trim_end();
+    if !head.ends_with("import") {
+        return None;
+    }
+    let tail = &text[(token.pos + token.length) as usize..];
+    let rest = tail.trim_start().strip_prefix("::")?.trim_start();
+    let rest = rest.strip_prefix('*')?.trim_start();
+    rest.strip_prefix(';')?;
+    Some(text.len() - rest.len() + 1)
+}
+
+fn line_indent(text: &str, pos: usize) -> &str {
+    let beg = text[..pos].rfind('\n').map(|x| x + 1).unwrap_or(0);
+    let line = &text[beg..pos];
+    let end = line.len() - line.trim_start().len();
+    &line[..end]
+}
+
+fn position(text: &str, pos: usize) -> Position {
+    let head = &text[..pos];
+    let line = head.matches('\n').count() as u32 + 1;
+    let beg = head.rfind('\n').map(|x| x + 1).unwrap_or(0);
+    let column = head[beg..].chars().count() as u32 + 1;
+    Position::new(line, column)
+}
+
+struct Sources<F> {
+    source: F,
+    cache: HashMap<PathId, String>,
+}
+
+impl<F> Sources<F>
+where
+    F: Fn(PathId) -> Option<String>,
+{
+    fn new(source: F) -> Self {
+        Self {
+            source,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, path: PathId) -> Result<&str, RefactorError> {
+        if !self.cache.contains_key(&path) {
+            let text = (self.source)(path).ok_or_else(|| RefactorError::SourceUnavailable {
+                path: path.to_string(),
+            })?;
+            self.cache.insert(path, text);
+        }
+        Ok(self.cache.get(&path).unwrap())
+    }
+}
+
+/// Lines of declaration including attributes and doc comments
+struct Declaration {
+    beg_line: u32,
+    end_line: u32,
+    pos: u32,
+}
+
+impl Declaration {
+    fn include(&self, token: &Token) -> bool {
+        self.beg_line <= token.line && token.line <= self.end_line
+    }
+
+    fn text(&self, text: &str) -> String {
+        let lines: Vec<_> = text
+            .lines()
+            .skip((self.beg_line - 1) as usize)
+            .take((self.end_line - self.beg_line + 1) as usize)
+            .collect();
+        format!("{}\n", lines.join("\n"))
+    }
+
+    fn remove(&self, path: PathId, text: &str) -> TextEdit {
+        let lines: Vec<_> = text.lines().collect();
+        let blank = |line: u32| {
+            lines
+                .get((line - 1) as usize)
+                .map(|x| x.trim().is_empty())
+                .unwrap_or(false)
+        };
+        let closing = |line: u32| {
+            lines
+                .get((line - 1) as usize)
+                .map(|x| x.trim_start().starts_with('}'))
+                .unwrap_or(false)
+        };
+
+        // Remove a blank line before the declaration to avoid consecutive blank lines
+        let next = self.end_line + 1;
+        let beg_line =
+            if self.beg_line > 1 && blank(self.beg_line - 1) && (blank(next) || closing(next)) {
+                self.beg_line - 1
+            } else {
+                self.beg_line
+            };
+
+        TextEdit {
+            path,
+            beg: Position::new(beg_line, 1),
+            end: Position::new(next, 1),
+            text: String::new(),
+        }
+    }
+}
+
+fn find_declaration(token: &Token, path: PathId, text: &str) -> Option<Declaration> {
+    let file = resource_table::get_path_value(path)?;
+    let parser = Parser::parse(text, &file).ok()?;
+    let mut finder = DeclarationFinder {
+        token: *token,
+        range: None,
+    };
+    finder.veryl(&parser.veryl);
+    let range = finder.range?;
+
+    // Include doc comments before the declaration
+    let lines: Vec<_> = text.lines().collect();
+    let mut beg_line = range.beg.line;
+    while beg_line > 1
+        && lines[(beg_line - 2) as usize]
+            .trim_start()
+            .starts_with("///")
+    {
+        beg_line -= 1;
+    }
+
+    let pos = text
+        .split_inclusive('\n')
+        .take((beg_line - 1) as usize)
+        .map(|x| x.len())
+        .sum::<usize>() as u32;
+
+    Some(Declaration {
+        beg_line,
+        end_line: range.end.line,
+        pos,
+    })
+}
+
+struct DeclarationFinder {
+    token: Token,
+    range: Option<TokenRange>,
+}
+
+impl VerylWalker for DeclarationFinder {
+    fn package_group(&mut self, arg: &PackageGroup) {
+        let PackageGroupGroup::PackageItem(x) = arg.package_group_group.as_ref() else {
+            return;
+        };
+        let identifier = match x.package_item.as_ref() {
+            PackageItem::ConstDeclaration(x) => &x.const_declaration.identifier,
+            PackageItem::TypeDefDeclaration(x) => &x.type_def_declaration.identifier,
+            PackageItem::EnumDeclaration(x) => &x.enum_declaration.identifier,
+            PackageItem::StructUnionDeclaration(x) => &x.struct_union_declaration.identifier,
+            _ => return,
+        };
+        let identifier = identifier.identifier_token.token;
+        if identifier.line == self.token.line && identifier.column == self.token.column {
+            let mut tokens = TokenCollector::default();
+            tokens.package_group(arg);
+            if let (Some(beg), Some(end)) = (tokens.first, tokens.last) {
+                self.range = Some(TokenRange { beg, end });
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct TokenCollector {
+    first: Option<Token>,
+    last: Option<Token>,
+}
+
+impl VerylWalker for TokenCollector {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        if self.first.is_none() {
+            self.first = Some(arg.token);
+        }
+        self.last = Some(arg.token);
+    }
+}
+
+struct InstDeclarationFinder {
+    pos: Position,
+    inst: Option<InstDeclaration>,
+}
+
+impl VerylWalker for InstDeclarationFinder {
+    fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        let beg = arg.inst.inst_token.token;
+        let end = arg.semicolon.semicolon_token.token;
+        let beg = Position::new(beg.line, beg.column);
+        let end = Position::new(end.line, end.column + end.length);
+        if beg <= self.pos && self.pos <= end {
+            self.inst = Some(arg.clone());
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::attribute::Attribute;
+use crate::attribute_table;
+use crate::namespace::Namespace;
+use crate::namespace_table;
+use crate::symbol::{self, Symbol, SymbolId};
+use crate::symbol_table::{self, Import};
+use crate::type_dag::{self, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use veryl_parser::resource_table::{self, PathId, StrId, TokenId};
+use veryl_parser::veryl_token::{Token, TokenRange, TokenSource};
+
+/// Contributions of a source file to the symbol table, the type DAG and other tables.
+/// Snapshots of unchanged files are restored instead of parsing and analyzing them again.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Snapshot {
+    path: PathBuf,
+    /// Symbols whose tokens are placed in the file
+    symbols: Vec<Symbol>,
+    /// Imports declared in items of the file
+    imports: Vec<Import>,
+    namespaces: Vec<(TokenId, Namespace)>,
+    attributes: Vec<(TokenRange, Attribute)>,
+    /// Type DAG nodes of symbols in the file
+    dag_nodes: Vec<SymbolId>,
+    /// Type DAG edges to symbols in the file
+    dag_edges: Vec<(SymbolId, SymbolId, Context)>,
+    /// Instances in modules of the file
+    dag_instances: Vec<(SymbolId, SymbolId, Token)>,
+}
+
+/// Symbol which is not placed in any source file like builtin functions.
+/// It is not included in snapshots, so references to it are resolved by name on restoring.
+#[derive(Serialize, Deserialize)]
+pub struct ExternalSymbol {
+    id: usize,
+    name: StrId,
+    namespace: Namespace,
+}
+
+fn file_path(token: &Token) -> Option<PathId> {
+    if let TokenSource::File(x) = token.source {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Takes snapshots of `paths` from the current tables
+pub fn take(paths: &[PathBuf]) -> Vec<Snapshot> {
+    let mut ret: Vec<_> = paths
+        .iter()
+        .map(|x| Snapshot {
+            path: x.clone(),
+            ..Default::default()
+        })
+        .collect();
+    let index: HashMap<_, _> = paths
+        .iter()
+        .enumerate()
+        .filter_map(|(i, x)| resource_table::get_path_id(x.clone()).map(|x| (x, i)))
+        .collect();
+
+    let mut symbol_files = HashMap::new();
+    let mut items = Vec::new();
+    for symbol in symbol_table::get_all() {
+        let Some(i) = file_path(&symbol.token).and_then(|x| index.get(&x)) else {
+            continue;
+        };
+        symbol_files.insert(symbol.id, *i);
+        if symbol.namespace.depth() == 1 {
+            items.push((symbol.inner_namespace(), *i));
+        }
+        ret[*i].symbols.push(symbol);
+    }
+
+    for import in symbol_table::get_imports() {
+        if let Some((_, i)) = items.iter().find(|(x, _)| import.namespace.included(x)) {
+            ret[*i].imports.push(import);
+        }
+    }
+
+    for (id, namespace, path) in namespace_table::get_all() {
+        if let Some(i) = index.get(&path) {
+            ret[*i].namespaces.push((id, namespace));
+        }
+    }
+
+    for (range, attribute) in attribute_table::get_all() {
+        if let Some(i) = file_path(&range.beg).and_then(|x| index.get(&x)) {
+            ret[*i].attributes.push((range, attribute));
+        }
+    }
+
+    for id in type_dag::nodes() {
+        if let Some(i) = symbol_files.get(&id) {
+            ret[*i].dag_nodes.push(id);
+        }
+    }
+
+    // Edges are directed from the dependency to the dependent which is analyzed
+    for (start, end, context) in type_dag::edges() {
+        if let Some(i) = symbol_files.get(&end) {
+            ret[*i].dag_edges.push((start, end, context));
+        }
+    }
+
+    for (parent, child, token) in type_dag::instances() {
+        let parent = type_dag::get_symbol(parent).id;
+        let child = type_dag::get_symbol(child).id;
+        if let Some(i) = symbol_files.get(&parent) {
+            ret[*i].dag_instances.push((parent, child, token));
+        }
+    }
+
+    for x in &mut ret {
+        x.namespaces.sort_by_key(|x| x.0);
+    }
+
+    ret
+}
+
+/// Returns symbols which are not placed in any source file
+pub fn externals() -> Vec<ExternalSymbol> {
+    symbol_table::get_all()
+        .into_iter()
+        .filter(|x| file_path(&x.token).is_none())
+        .map(|x| ExternalSymbol {
+            id: x.id.0,
+            name: x.token.text,
+            namespace: x.namespace,
+        })
+        .collect()
+}
+
+/// Prepares to deserialize snapshots taken with `externals`.
+/// This should be called after external symbols like namespaces of dependencies are registered.
+pub fn begin_restore(externals: &[ExternalSymbol]) {
+    symbol::clear_symbol_id_map();
+    resource_table::clear_token_id_map();
+
+    let table: HashMap<_, _> = symbol_table::get_all()
+        .into_iter()
+        .filter(|x| file_path(&x.token).is_none())
+        .map(|x| ((x.token.text, x.namespace), x.id))
+        .collect();
+    for x in externals {
+        if let Some(id) = table.get(&(x.name, x.namespace.clone())) {
+            symbol::map_symbol_id(x.id, *id);
+        }
+    }
+}
+
+/// Restores snapshots deserialized after `begin_restore`.
+/// Returns false without any change if the snapshots refer symbols which are not restored,
+/// then all files should be analyzed.
+pub fn restore(snapshots: Vec<Snapshot>) -> bool {
+    let defined: HashSet<_> = snapshots
+        .iter()
+        .flat_map(|x| x.symbols.iter().map(|x| x.id))
+        .collect();
+    let dangling = symbol::mapped_symbol_ids()
+        .into_iter()
+        .any(|x| !defined.contains(&x) && symbol_table::get(x).is_none());
+    if dangling {
+        return false;
+    }
+
+    // References from other files are collected again by analysis of them
+    let paths: HashSet<_> = snapshots
+        .iter()
+        .filter_map(|x| resource_table::get_path_id(x.path.clone()))
+        .collect();
+
+    let mut dag_nodes = Vec::new();
+    let mut dag_edges = Vec::new();
+    let mut dag_instances = Vec::new();
+    for snapshot in snapshots {
+        let path = resource_table::insert_path(&snapshot.path);
+
+        for mut symbol in snapshot.symbols {
+            // Imports are applied again with imports of all files
+            symbol.imported.clear();
+            symbol
+                .references
+                .retain(|x| file_path(x).map(|x| paths.contains(&x)).unwrap_or(true));
+            let token = symbol.token;
+            symbol_table::insert(&token, symbol);
+        }
+        for import in snapshot.imports {
+            symbol_table::add_import(import);
+        }
+        for (id, namespace) in &snapshot.namespaces {
+            namespace_table::insert(*id, path, namespace);
+        }
+        for (range, attribute) in snapshot.attributes {
+            attribute_table::insert(range, attribute);
+        }
+
+        dag_nodes.extend(snapshot.dag_nodes);
+        dag_edges.extend(snapshot.dag_edges);
+        dag_instances.extend(snapshot.dag_instances);
+    }
+
+    // DAG is restored after all symbols are restored because nodes refer symbols
+    let mut nodes = HashMap::new();
+    let mut insert_node = |id: SymbolId| -> Option<u32> {
+        if let Some(x) = nodes.get(&id) {
+            return Some(*x);
+        }
+        let symbol = symbol_table::get(id)?;
+        let node = type_dag::insert_node(id, &symbol.token.to_string()).ok()?;
+        nodes.insert(id, node);
+        Some(node)
+    };
+    for id in dag_nodes {
+        insert_node(id);
+    }
+    for (start, end, context) in dag_edges {
+        if let (Some(start), Some(end)) = (insert_node(start), insert_node(end)) {
+            // Cyclic dependency has been reported at the original analysis
+            let _ = type_dag::insert_edge(start, end, context);
+        }
+    }
+    for (parent, child, token) in dag_instances {
+        if let (Some(parent), Some(child)) = (insert_node(parent), insert_node(child)) {
+            type_dag::insert_instance(parent, child, token);
+        }
+    }
+
+    true
+}
@@ -0,0 +1,186 @@
+use crate::evaluator::{Evaluated, Evaluator};
+use crate::symbol::{Direction, Port, Symbol, SymbolKind, Type, TypeKind};
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::Expression;
+
+/// Leaf member of a struct-typed port, which is emitted as an individual port
+#[derive(Clone, Debug)]
+pub struct FlattenedMember {
+    /// Name of the port emitted for the member
+    pub name: String,
+    /// Selects to access the member from the element, like `[1].a`
+    pub select: String,
+    pub width: usize,
+    pub is_bit: bool,
+}
+
+/// Element of a struct-typed port, which is an unpacked array element or the port itself
+#[derive(Clone, Debug)]
+pub struct FlattenedElement {
+    /// Selects to access the element from the port, like `[0]`
+    pub select: String,
+    /// Leaf members in order from MSB
+    pub members: Vec<FlattenedMember>,
+}
+
+fn strip_raw(x: String) -> String {
+    x.strip_prefix("r#").map(|x| x.to_string()).unwrap_or(x)
+}
+
+/// Returns the struct or union which the type refers to through typedefs,
+/// and the packed dimensions of the type
+fn resolve_struct(arg: &Type) -> Option<(Symbol, Vec<Expression>)> {
+    let mut r#type = arg.clone();
+    let mut dims = Vec::new();
+    loop {
+        dims.append(&mut r#type.width);
+        let TypeKind::UserDefined(ref x) = r#type.kind else {
+            return None;
+        };
+        let symbol = symbol_table::get(x.symbol?)?;
+        match symbol.kind {
+            SymbolKind::Struct(_) | SymbolKind::Union(_) => return Some((symbol, dims)),
+            SymbolKind::GenericInstance(ref x) => {
+                let base = symbol_table::get(x.base)?;
+                return match base.kind {
+                    SymbolKind::Struct(_) | SymbolKind::Union(_) => Some((symbol, dims)),
+                    _ => None,
+                };
+            }
+            SymbolKind::TypeDef(ref x) => r#type = x.r#type.clone(),
+            _ => return None,
+        }
+    }
+}
+
+/// Returns whether the type is a struct or union, which is flattened by `flatten_struct_ports`
+pub fn is_struct_type(arg: &Type) -> bool {
+    resolve_struct(arg).is_some()
+}
+
+fn fixed_dims(dims: &[Expression], path: &str) -> Result<Vec<usize>, String> {
+    let mut evaluator = Evaluator::new();
+    dims.iter()
+        .map(|x| match evaluator.expression(x) {
+            Evaluated::Fixed { value, .. } => usize::try_from(value).ok(),
+            _ => None,
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(|| format!("the array size of `{path}` can't be determined"))
+}
+
+/// Returns all indices of the array in descending order for packed dimensions,
+/// or ascending order for unpacked ones
+fn indices(dims: &[usize], descending: bool) -> Vec<Vec<usize>> {
+    let mut ret = vec![vec![]];
+    for dim in dims {
+        let mut next = Vec::new();
+        for x in &ret {
+            for i in 0..*dim {
+                let i = if descending { dim - 1 - i } else { i };
+                let mut x = x.clone();
+                x.push(i);
+                next.push(x);
+            }
+        }
+        ret = next;
+    }
+    ret
+}
+
+fn index_name(index: &[usize]) -> String {
+    index.iter().map(|x| format!("_{x}")).collect()
+}
+
+fn index_select(index: &[usize]) -> String {
+    index.iter().map(|x| format!("[{x}]")).collect()
+}
+
+fn flatten_type(
+    arg: &Type,
+    name: &str,
+    select: &str,
+    base: &str,
+    ret: &mut Vec<FlattenedMember>,
+) -> Result<(), String> {
+    // path of the member shown in error messages
+    let path = format!("{base}{select}");
+    let Some((symbol, dims)) = resolve_struct(arg) else {
+        let width = Evaluator::new()
+            .total_width(arg)
+            .ok_or_else(|| format!("the width of `{path}` can't be determined"))?;
+        ret.push(FlattenedMember {
+            name: name.to_string(),
+            select: select.to_string(),
+            width,
+            is_bit: arg.kind == TypeKind::Bit,
+        });
+        return Ok(());
+    };
+    let members = match symbol.kind {
+        SymbolKind::Struct(ref x) => &x.members,
+        SymbolKind::Union(_) => return Err(format!("`{path}` is a union")),
+        _ => return Err(format!("`{path}` is a generic struct")),
+    };
+
+    let dims = fixed_dims(&dims, &path)?;
+    for index in indices(&dims, true) {
+        let name = format!("{name}{}", index_name(&index));
+        let select = format!("{select}{}", index_select(&index));
+        for member in members {
+            let member = symbol_table::get(*member).unwrap();
+            let SymbolKind::StructMember(ref x) = member.kind else {
+                unreachable!();
+            };
+            let member = strip_raw(member.token.to_string());
+            flatten_type(
+                &x.r#type,
+                &format!("{name}_{member}"),
+                &format!("{select}.{member}"),
+                base,
+                ret,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns elements of the struct-typed port whose leaf members are emitted as individual ports,
+/// or the reason why the port can't be flattened
+pub fn flatten_struct_port(port: &Port) -> Result<Vec<FlattenedElement>, String> {
+    let property = port.property();
+    let Some(r#type) = property.r#type else {
+        unreachable!();
+    };
+    match property.direction {
+        Direction::Input | Direction::Output => (),
+        x => return Err(format!("its direction is {x}")),
+    }
+    if property.default_value.is_some() {
+        return Err("it has a default value".to_string());
+    }
+
+    let name = strip_raw(port.token.to_string());
+    let dims = fixed_dims(&r#type.array, &name)?;
+    let element = Type {
+        array: Vec::new(),
+        ..r#type
+    };
+
+    let mut ret = Vec::new();
+    for index in indices(&dims, false) {
+        let mut members = Vec::new();
+        flatten_type(
+            &element,
+            &format!("{name}{}", index_name(&index)),
+            "",
+            &format!("{name}{}", index_select(&index)),
+            &mut members,
+        )?;
+        ret.push(FlattenedElement {
+            select: index_select(&index),
+            members,
+        });
+    }
+    Ok(ret)
+}
@@ -1,10 +1,13 @@
-use crate::attribute::EnumEncodingItem;
+use crate::attribute::{Attribute, EnumEncodingItem};
+use crate::attribute_table;
 use crate::evaluator::{Evaluated, Evaluator};
 use crate::namespace::Namespace;
+use crate::struct_port;
 use crate::symbol_path::{GenericSymbolPath, SymbolPath};
 use crate::symbol_table;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use veryl_parser::resource_table::{PathId, StrId};
 use veryl_parser::veryl_grammar_trait as syntax_tree;
@@ -25,7 +28,43 @@ pub fn new_symbol_id() -> SymbolId {
     })
 }
 
-#[derive(Debug, Default, Clone)]
+// Map from ids in the build cache to ids of the current process
+thread_local!(static SYMBOL_ID_MAP: RefCell<HashMap<usize, SymbolId>> = RefCell::new(HashMap::new()));
+
+/// Maps the id `from` in the build cache to the existing symbol `to`
+pub fn map_symbol_id(from: usize, to: SymbolId) {
+    SYMBOL_ID_MAP.with(|f| f.borrow_mut().insert(from, to));
+}
+
+/// Returns ids mapped from ids in the build cache
+pub fn mapped_symbol_ids() -> Vec<SymbolId> {
+    SYMBOL_ID_MAP.with(|f| f.borrow().values().copied().collect())
+}
+
+pub fn clear_symbol_id_map() {
+    SYMBOL_ID_MAP.with(|f| f.borrow_mut().clear());
+}
+
+impl Serialize for SymbolId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Symbol ids are renumbered on deserialization not to conflict with ids of the current process.
+/// The same id is mapped to the same new id until `clear_symbol_id_map` is called.
+impl<'de> Deserialize<'de> for SymbolId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = usize::deserialize(deserializer)?;
+        let id = SYMBOL_ID_MAP.with(|f| *f.borrow_mut().entry(value).or_insert_with(new_symbol_id));
+        Ok(id)
+    }
+}
+
+// Parameters under evaluation to stop evaluation of cyclic dependency
+thread_local!(static EVALUATING: RefCell<HashSet<SymbolId>> = RefCell::new(HashSet::new()));
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct DocComment(pub Vec<StrId>);
 
 impl DocComment {
@@ -47,7 +86,7 @@ impl DocComment {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GenericMap {
     pub name: String,
     pub map: HashMap<StrId, GenericSymbolPath>,
@@ -59,7 +98,7 @@ impl GenericMap {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Symbol {
     pub token: Token,
     pub id: SymbolId,
@@ -68,6 +107,7 @@ pub struct Symbol {
     pub references: Vec<Token>,
     pub generic_instances: Vec<SymbolId>,
     pub imported: Vec<Namespace>,
+    #[serde(skip)]
     pub evaluated: Cell<Option<Evaluated>>,
     pub allow_unused: bool,
     pub public: bool,
@@ -158,11 +198,17 @@ impl Symbol {
                     }
                 }
                 SymbolKind::Parameter(x) => {
+                    // cyclic dependency is reported as CyclicTypeDependency
+                    if !EVALUATING.with(|f| f.borrow_mut().insert(self.id)) {
+                        return Evaluated::Unknown;
+                    }
                     let mut evaluator = Evaluator::new();
                     if let Some(width) = evaluator.type_width(x.r#type.clone()) {
                         evaluator.context_width.push(width);
                     }
-                    evaluator.expression(&x.value)
+                    let ret = evaluator.expression(&x.value);
+                    EVALUATING.with(|f| f.borrow_mut().remove(&self.id));
+                    ret
                 }
                 SymbolKind::EnumMember(_) => {
                     // TODO: Actually Evaluate its Width
@@ -182,6 +228,11 @@ impl Symbol {
         ret
     }
 
+    /// Returns whether this symbol is declared in a package item with `private` attribute
+    pub fn is_private(&self) -> bool {
+        attribute_table::contains(&self.token, Attribute::Private)
+    }
+
     pub fn generic_maps(&self) -> Vec<GenericMap> {
         let mut ret = Vec::new();
 
@@ -308,9 +359,46 @@ impl Symbol {
             _ => None,
         }
     }
+
+    /// Returns inout ports expanded into input, output and output enable signals.
+    /// Inout ports of modules with `#[pad]` attribute are not expanded.
+    pub fn expanded_inout_ports(&self, expand_inout: bool) -> Vec<Port> {
+        match &self.kind {
+            SymbolKind::Module(x)
+                if expand_inout && !attribute_table::contains(&self.token, Attribute::Pad) =>
+            {
+                x.ports
+                    .iter()
+                    .filter(|x| {
+                        let property = x.property();
+                        property.direction == Direction::Inout && property.r#type.is_some()
+                    })
+                    .cloned()
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns struct-typed ports flattened into ports of their members
+    pub fn flattened_struct_ports(&self, flatten_struct_ports: bool) -> Vec<Port> {
+        match &self.kind {
+            SymbolKind::Module(x) if flatten_struct_ports => x
+                .ports
+                .iter()
+                .filter(|x| {
+                    x.property()
+                        .r#type
+                        .is_some_and(|x| struct_port::is_struct_type(&x))
+                })
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SymbolKind {
     Port(PortProperty),
     Variable(VariableProperty),
@@ -564,7 +652,7 @@ impl fmt::Display for SymbolKind {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Direction {
     Input,
     Output,
@@ -603,7 +691,7 @@ impl From<&syntax_tree::Direction> for Direction {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Type {
     pub modifier: Vec<TypeModifier>,
     pub kind: TypeKind,
@@ -612,7 +700,7 @@ pub struct Type {
     pub is_const: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TypeKind {
     Clock,
     ClockPosedge,
@@ -655,7 +743,7 @@ impl TypeKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserDefinedType {
     pub path: Vec<StrId>,
     pub symbol: Option<SymbolId>,
@@ -667,7 +755,7 @@ impl UserDefinedType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TypeModifier {
     Tri,
     Signed,
@@ -990,7 +1078,7 @@ impl From<&syntax_tree::ArrayType> for Type {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ClockDomain {
     Explicit(SymbolId),
     Implicit,
@@ -1018,7 +1106,7 @@ impl fmt::Display for ClockDomain {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VariableProperty {
     pub r#type: Type,
     pub affiliation: VariableAffiliation,
@@ -1028,7 +1116,7 @@ pub struct VariableProperty {
     pub loop_variable: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VariableAffiliation {
     Module,
     Intarface,
@@ -1037,7 +1125,7 @@ pub enum VariableAffiliation {
     Function,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortProperty {
     pub token: Token,
     pub r#type: Option<Type>,
@@ -1049,7 +1137,7 @@ pub struct PortProperty {
     pub is_proto: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Port {
     pub token: VerylToken,
     pub symbol: SymbolId,
@@ -1076,13 +1164,13 @@ impl Port {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ParameterKind {
     Param,
     Const,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParameterProperty {
     pub token: Token,
     pub r#type: Type,
@@ -1090,7 +1178,7 @@ pub struct ParameterProperty {
     pub value: syntax_tree::Expression,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: StrId,
     pub symbol: SymbolId,
@@ -1113,7 +1201,7 @@ impl Parameter {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleProperty {
     pub range: TokenRange,
     pub proto: Option<SymbolPath>,
@@ -1125,7 +1213,7 @@ pub struct ModuleProperty {
     pub default_reset: Option<SymbolId>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProtoModuleProperty {
     pub range: TokenRange,
     pub parameters: Vec<Parameter>,
@@ -1195,7 +1283,7 @@ impl ProtoModuleProperty {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterfaceProperty {
     pub range: TokenRange,
     pub generic_parameters: Vec<SymbolId>,
@@ -1203,7 +1291,7 @@ pub struct InterfaceProperty {
     pub parameters: Vec<Parameter>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionProperty {
     pub range: TokenRange,
     pub generic_parameters: Vec<SymbolId>,
@@ -1212,7 +1300,7 @@ pub struct FunctionProperty {
     pub ret: Option<Type>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectTarget {
     pub path: Vec<(StrId, Vec<syntax_tree::Expression>)>,
 }
@@ -1262,49 +1350,74 @@ impl From<&syntax_tree::ExpressionIdentifier> for ConnectTarget {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstanceProperty {
     pub type_name: GenericSymbolPath,
+    pub array: Vec<syntax_tree::Expression>,
+    #[serde(with = "connects_serde")]
     pub connects: HashMap<Token, Vec<ConnectTarget>>,
 }
 
-#[derive(Debug, Clone)]
+/// Connections are serialized as a list because JSON keys can't be tokens
+mod connects_serde {
+    use super::ConnectTarget;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+    use veryl_parser::veryl_token::Token;
+
+    pub fn serialize<S: Serializer>(
+        value: &HashMap<Token, Vec<ConnectTarget>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let list: Vec<_> = value.iter().collect();
+        list.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Token, Vec<ConnectTarget>>, D::Error> {
+        let list: Vec<(Token, Vec<ConnectTarget>)> = Vec::deserialize(deserializer)?;
+        Ok(list.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PackageProperty {
     pub range: TokenRange,
     pub generic_parameters: Vec<SymbolId>,
     pub generic_references: Vec<GenericSymbolPath>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StructProperty {
     pub members: Vec<SymbolId>,
     pub generic_parameters: Vec<SymbolId>,
     pub generic_references: Vec<GenericSymbolPath>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StructMemberProperty {
     pub r#type: Type,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnionProperty {
     pub members: Vec<SymbolId>,
     pub generic_parameters: Vec<SymbolId>,
     pub generic_references: Vec<GenericSymbolPath>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnionMemberProperty {
     pub r#type: Type,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeDefProperty {
     pub r#type: Type,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnumProperty {
     pub r#type: Option<Type>,
     pub width: usize,
@@ -1312,7 +1425,7 @@ pub struct EnumProperty {
     pub encoding: EnumEncodingItem,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EnumMemberValue {
     ImplicitValue(usize),
     ExplicitValue(syntax_tree::Expression, Option<usize>),
@@ -1329,28 +1442,28 @@ impl EnumMemberValue {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnumMemberProperty {
     pub value: EnumMemberValue,
     pub prefix: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModportProperty {
     pub members: Vec<SymbolId>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModportVariableMemberProperty {
     pub direction: Direction,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModportFunctionMemberProperty {
     pub function: SymbolId,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GenericBoundKind {
     Const,
     Type,
@@ -1370,26 +1483,26 @@ impl fmt::Display for GenericBoundKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericParameterProperty {
     pub bound: GenericBoundKind,
     pub default_value: Option<GenericSymbolPath>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericInstanceProperty {
     pub base: SymbolId,
     pub arguments: Vec<GenericSymbolPath>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TestType {
     Inline,
     CocotbEmbed(StrId),
     CocotbInclude(StrId),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestProperty {
     pub r#type: TestType,
     pub path: PathId,
@@ -2,13 +2,14 @@ use crate::namespace::Namespace;
 use crate::namespace_table;
 use crate::symbol::{DocComment, GenericInstanceProperty, GenericMap, Symbol, SymbolKind};
 use crate::symbol_table;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::veryl_grammar_trait as syntax_tree;
 use veryl_parser::veryl_token::{Token, TokenRange, TokenSource};
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SymbolPath(pub Vec<StrId>);
 
 impl SymbolPath {
@@ -109,7 +110,7 @@ impl From<&syntax_tree::ExpressionIdentifier> for SymbolPath {
     }
 }
 
-#[derive(Clone, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SymbolPathNamespace(pub SymbolPath, pub Namespace);
 
 impl From<&Token> for SymbolPathNamespace {
@@ -180,7 +181,7 @@ impl From<&syntax_tree::ExpressionIdentifier> for SymbolPathNamespace {
     }
 }
 
-#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GenericSymbolPathKind {
     Identifier,
     IntegerBased,
@@ -204,14 +205,14 @@ impl fmt::Display for GenericSymbolPathKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GenericSymbolPath {
     pub paths: Vec<GenericSymbol>,
     pub kind: GenericSymbolPathKind,
     pub range: TokenRange,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GenericSymbol {
     pub base: Token,
     pub arguments: Vec<GenericSymbolPath>,
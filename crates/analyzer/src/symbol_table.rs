@@ -1,10 +1,12 @@
+use crate::attribute::edit_distance;
 use crate::evaluator::Evaluated;
 use crate::namespace::Namespace;
 use crate::symbol::{DocComment, GenericBoundKind, Symbol, SymbolId, SymbolKind, TypeKind};
 use crate::symbol_path::{SymbolPath, SymbolPathNamespace};
 use crate::var_ref::{Assign, VarRef, VarRefAffiliation};
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use veryl_parser::resource_table::{PathId, StrId, TokenId};
 use veryl_parser::veryl_token::{Token, TokenSource};
@@ -37,7 +39,10 @@ impl ResolveError {
     }
 }
 
-#[derive(Clone, Debug)]
+/// Maximum number of names suggested for an unresolved identifier
+const MAX_SUGGESTIONS: usize = 3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Import {
     pub path: SymbolPathNamespace,
     pub namespace: Namespace,
@@ -51,6 +56,10 @@ pub struct SymbolTable {
     project_local_table: HashMap<StrId, HashMap<StrId, StrId>>,
     var_ref_list: HashMap<VarRefAffiliation, Vec<VarRef>>,
     import_list: Vec<Import>,
+    // Imports which have been applied, which are kept to be stored in the build cache
+    applied_import_list: Vec<Import>,
+    // Pairs of the namespace where import is declared and the symbol resolved through it
+    import_usage: RefCell<HashSet<(Namespace, SymbolId)>>,
 }
 
 impl SymbolTable {
@@ -200,6 +209,9 @@ impl SymbolTable {
 
                 if let Some(found) = context.found {
                     context.full_path.push(found.id);
+                    if context.imported {
+                        self.add_import_usage(found, &context.namespace);
+                    }
                     match &found.kind {
                         SymbolKind::Variable(x) => {
                             context = self.trace_user_defined(context, &x.r#type.kind)?;
@@ -325,12 +337,14 @@ impl SymbolTable {
         }
     }
 
+    /// Returns all symbols in the order of creation to keep outputs deterministic
     pub fn get_all(&self) -> Vec<Symbol> {
         let mut ret = Vec::new();
         for symbol in self.symbol_table.values() {
             symbol.evaluate();
             ret.push(symbol.clone());
         }
+        ret.sort_by_key(|x| x.id);
         ret
     }
 
@@ -379,6 +393,24 @@ impl SymbolTable {
             .map(|x| *x.0)
             .collect();
 
+        // Usages in the dropped namespaces are collected again by analysis of the file
+        let dropped_namespaces: Vec<_> = drop_list
+            .iter()
+            .filter_map(|x| self.symbol_table.get(x))
+            .filter(|x| {
+                matches!(
+                    x.kind,
+                    SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_)
+                )
+            })
+            .map(|x| x.inner_namespace())
+            .collect();
+        self.import_usage.borrow_mut().retain(|(namespace, id)| {
+            !drop_list.contains(id) && !dropped_namespaces.iter().any(|x| namespace.included(x))
+        });
+        self.applied_import_list
+            .retain(|x| !dropped_namespaces.iter().any(|y| x.namespace.included(y)));
+
         for id in &drop_list {
             self.symbol_table.remove(id);
         }
@@ -420,18 +452,47 @@ impl SymbolTable {
 
     fn add_imported_package(&mut self, target: &Namespace, namespace: &Namespace) {
         for (_, symbol) in self.symbol_table.iter_mut() {
-            if symbol.namespace.matched(target) {
+            // private items are not imported by wildcard
+            if symbol.namespace.matched(target) && !symbol.is_private() {
                 symbol.imported.push(namespace.to_owned());
             }
         }
     }
 
+    fn add_import_usage(&self, symbol: &Symbol, namespace: &Namespace) {
+        let mut import_usage = self.import_usage.borrow_mut();
+        for x in &symbol.imported {
+            if namespace.included(x) {
+                import_usage.insert((x.clone(), symbol.id));
+            }
+        }
+    }
+
+    pub fn get_import_usage(&self, namespace: &Namespace) -> Vec<SymbolId> {
+        let mut ret: Vec<_> = self
+            .import_usage
+            .borrow()
+            .iter()
+            .filter(|(x, _)| x.matched(namespace))
+            .map(|(_, x)| *x)
+            .collect();
+        ret.sort();
+        ret
+    }
+
     pub fn add_import(&mut self, import: Import) {
         self.import_list.push(import);
     }
 
+    pub fn get_imports(&self) -> Vec<Import> {
+        let mut ret = self.applied_import_list.clone();
+        ret.extend(self.import_list.iter().cloned());
+        ret
+    }
+
     pub fn apply_import(&mut self) {
         let import_list: Vec<_> = self.import_list.drain(0..).collect();
+        self.applied_import_list.extend(import_list.iter().cloned());
         for import in import_list {
             if let Ok(symbol) = self.resolve(&import.path.0, &import.path.1) {
                 let symbol = symbol.found;
@@ -511,6 +572,76 @@ impl SymbolTable {
             .collect()
     }
 
+    /// Returns names visible from `namespace` which are similar to `name`, nearest first
+    pub fn similar_names(&self, name: StrId, namespace: &Namespace) -> Vec<String> {
+        let text = name.to_string();
+        let threshold = (text.len() / 3).max(1);
+
+        let mut ret = Vec::new();
+        for (x, ids) in &self.name_table {
+            let candidate = x.to_string();
+            if *x == name || candidate.len().abs_diff(text.len()) > threshold {
+                continue;
+            }
+            // builtin symbols are placed at the empty namespace
+            let visible = ids
+                .iter()
+                .filter_map(|id| self.symbol_table.get(id))
+                .any(|x| !x.namespace.paths.is_empty() && namespace.included(&x.namespace));
+            if !visible {
+                continue;
+            }
+            let distance = edit_distance(&text, &candidate);
+            if distance <= threshold {
+                ret.push((distance, candidate));
+            }
+        }
+        ret.sort();
+        ret.into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, x)| x)
+            .collect()
+    }
+
+    /// Returns names of modports defined in `interface` in the order of definition
+    pub fn modport_names(&self, interface: &Symbol) -> Vec<String> {
+        let namespace = interface.inner_namespace();
+        let mut ret: Vec<_> = self
+            .symbol_table
+            .values()
+            .filter(|x| matches!(x.kind, SymbolKind::Modport(_)) && x.namespace == namespace)
+            .map(|x| (x.id, x.token.to_string()))
+            .collect();
+        ret.sort();
+        ret.into_iter().map(|(_, x)| x).collect()
+    }
+
+    /// Returns packages in the project of `namespace` which have a member named `name`
+    pub fn packages_containing(&self, name: StrId, namespace: &Namespace) -> Vec<String> {
+        let Some(prj) = namespace.paths.first() else {
+            return Vec::new();
+        };
+        let is_package = |x: &StrId| {
+            self.name_table.get(x).into_iter().flatten().any(|id| {
+                let symbol = &self.symbol_table[id];
+                matches!(symbol.kind, SymbolKind::Package(_)) && symbol.namespace.paths == [*prj]
+            })
+        };
+
+        let mut ret = Vec::new();
+        for id in self.name_table.get(&name).into_iter().flatten() {
+            let symbol = &self.symbol_table[id];
+            if let [x, package] = symbol.namespace.paths[..] {
+                if x == *prj && is_package(&package) {
+                    ret.push(package.to_string());
+                }
+            }
+        }
+        ret.sort();
+        ret.dedup();
+        ret
+    }
+
     pub fn clear(&mut self) {
         self.clone_from(&Self::new());
     }
@@ -1077,6 +1208,18 @@ pub fn get_all() -> Vec<Symbol> {
     SYMBOL_TABLE.with(|f| f.borrow().get_all())
 }
 
+pub fn similar_names(name: StrId, namespace: &Namespace) -> Vec<String> {
+    SYMBOL_TABLE.with(|f| f.borrow().similar_names(name, namespace))
+}
+
+pub fn modport_names(interface: &Symbol) -> Vec<String> {
+    SYMBOL_TABLE.with(|f| f.borrow().modport_names(interface))
+}
+
+pub fn packages_containing(name: StrId, namespace: &Namespace) -> Vec<String> {
+    SYMBOL_TABLE.with(|f| f.borrow().packages_containing(name, namespace))
+}
+
 pub fn dump() -> String {
     SYMBOL_TABLE.with(|f| f.borrow().dump())
 }
@@ -1101,10 +1244,19 @@ pub fn add_import(import: Import) {
     SYMBOL_TABLE.with(|f| f.borrow_mut().add_import(import))
 }
 
+pub fn get_imports() -> Vec<Import> {
+    SYMBOL_TABLE.with(|f| f.borrow().get_imports())
+}
+
 pub fn apply_import() {
     SYMBOL_TABLE.with(|f| f.borrow_mut().apply_import())
 }
 
+/// Returns symbols resolved through imports declared at `namespace`
+pub fn get_import_usage(namespace: &Namespace) -> Vec<SymbolId> {
+    SYMBOL_TABLE.with(|f| f.borrow().get_import_usage(namespace))
+}
+
 pub fn resolve_user_defined() {
     let resolved = SYMBOL_TABLE.with(|f| f.borrow().get_user_defined());
     SYMBOL_TABLE.with(|f| f.borrow_mut().set_user_defined(resolved))
@@ -1,9 +1,11 @@
-use crate::symbol::{Symbol, SymbolId};
+use crate::symbol::{Symbol, SymbolId, SymbolKind};
 use crate::symbol_table;
 use bimap::BiMap;
 use daggy::petgraph::visit::Dfs;
 use daggy::{petgraph::algo, Dag, Walker};
+use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, collections::HashMap, collections::HashSet};
+use veryl_parser::timing;
 use veryl_parser::veryl_token::Token;
 
 #[derive(Clone, Default)]
@@ -14,6 +16,8 @@ pub struct TypeDag {
     /// Map between NodeIdx and Symbol Resolve Information
     paths: HashMap<u32, TypeResolveInfo>,
     symbols: HashMap<u32, Symbol>,
+    /// Instantiation relation from parent module to (child module, instance name)
+    instances: HashMap<u32, Vec<(u32, Token)>>,
     source: u32,
 }
 
@@ -24,7 +28,7 @@ pub struct TypeResolveInfo {
     pub token: Token,
 }
 
-#[derive(Default, Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(Default, Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Context {
     #[default]
     Irrelevant,
@@ -56,6 +60,7 @@ impl TypeDag {
             nodes: BiMap::new(),
             paths: HashMap::new(),
             symbols: HashMap::new(),
+            instances: HashMap::new(),
             source,
         }
     }
@@ -94,7 +99,12 @@ impl TypeDag {
             Err(_) => {
                 // Direct recursion of module/interface is allowed
                 let is_direct_recursion = start == end;
-                if matches!(edge, Context::Module | Context::Interface) && is_direct_recursion {
+                // Recursion between modules is reported as recursive instantiation
+                let is_module_recursion =
+                    matches!(edge, Context::Module) && self.is_module(start) && self.is_module(end);
+                if matches!(edge, Context::Module | Context::Interface)
+                    && (is_direct_recursion || is_module_recursion)
+                {
                     Ok(())
                 } else {
                     let ssym = self.get_symbol(start);
@@ -105,6 +115,94 @@ impl TypeDag {
         }
     }
 
+    fn is_module(&self, node: u32) -> bool {
+        self.symbols
+            .get(&node)
+            .map(|x| matches!(x.kind, SymbolKind::Module(_)))
+            .unwrap_or(false)
+    }
+
+    /// Symbols of dropped files remain in DAG, so they should be ignored
+    fn is_alive(&self, node: u32) -> bool {
+        self.symbols
+            .get(&node)
+            .map(|x| symbol_table::get(x.id).is_some())
+            .unwrap_or(false)
+    }
+
+    fn insert_instance(&mut self, parent: u32, child: u32, token: Token) {
+        self.instances
+            .entry(parent)
+            .or_default()
+            .push((child, token));
+    }
+
+    fn instances(&self) -> Vec<(u32, u32, Token)> {
+        let mut ret = Vec::new();
+        for (parent, children) in &self.instances {
+            if !self.is_alive(*parent) {
+                continue;
+            }
+            for (child, token) in children {
+                if self.is_alive(*child) {
+                    ret.push((*parent, *child, *token));
+                }
+            }
+        }
+        ret.sort_by_key(|(_, _, token)| token.id);
+        ret
+    }
+
+    /// Returns ids of symbols inserted as nodes
+    fn nodes(&self) -> Vec<SymbolId> {
+        let mut ret: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| self.is_alive(**node))
+            .map(|(id, node)| (*node, *id))
+            .collect();
+        ret.sort();
+        ret.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Returns edges between symbols, which exclude edges from the source node
+    fn edges(&self) -> Vec<(SymbolId, SymbolId, Context)> {
+        let mut ret = Vec::new();
+        for edge in self.dag.raw_edges() {
+            let start = edge.source().index() as u32;
+            let end = edge.target().index() as u32;
+            if !self.is_alive(start) || !self.is_alive(end) {
+                continue;
+            }
+            let start = self.nodes.get_by_right(&start).unwrap();
+            let end = self.nodes.get_by_right(&end).unwrap();
+            ret.push((*start, *end, edge.weight));
+        }
+        ret
+    }
+
+    /// Returns the instantiation path from `start` to `end` if exists
+    fn instance_path(&self, start: u32, end: u32) -> Option<Vec<Symbol>> {
+        let mut stack = vec![(start, vec![start])];
+        let mut visited = HashSet::new();
+        while let Some((node, path)) = stack.pop() {
+            if node == end {
+                return Some(path.iter().map(|x| self.get_symbol(*x)).collect());
+            }
+            if !visited.insert(node) {
+                continue;
+            }
+            if let Some(children) = self.instances.get(&node) {
+                for (child, _) in children.iter().filter(|(x, _)| self.is_alive(*x)) {
+                    let mut path = path.clone();
+                    path.push(*child);
+                    stack.push((*child, path));
+                }
+            }
+        }
+        None
+    }
+
     fn exist_edge(&self, start: u32, end: u32) -> bool {
         self.dag.find_edge(start.into(), end.into()).is_some()
     }
@@ -153,6 +251,23 @@ impl TypeDag {
         ret
     }
 
+    fn dependencies(&self, symbol_id: SymbolId) -> Vec<Symbol> {
+        let mut ret = Vec::new();
+        if let Some(node) = self.nodes.get_by_left(&symbol_id) {
+            let mut set = HashSet::new();
+            // Edges are reversed from the dependent to the dependency
+            for parent in self.dag.parents((*node).into()).iter(&self.dag) {
+                let node = parent.1.index() as u32;
+                if set.insert(node) {
+                    if let Some(symbol) = self.symbols.get(&node) {
+                        ret.push(symbol.clone());
+                    }
+                }
+            }
+        }
+        ret
+    }
+
     fn dump(&self) -> String {
         let nodes = algo::toposort(self.dag.graph(), None).unwrap();
         let mut ret = "".to_string();
@@ -210,7 +325,15 @@ impl TypeDag {
 thread_local!(static TYPE_DAG: RefCell<TypeDag> = RefCell::new(TypeDag::new()));
 
 pub fn insert_edge(start: u32, end: u32, context: Context) -> Result<(), DagError> {
-    TYPE_DAG.with(|f| f.borrow_mut().insert_edge(start, end, context))
+    // error is boxed not to move the large error through closures
+    timing::measure("type_dag", None, || {
+        TYPE_DAG.with(|f| {
+            f.borrow_mut()
+                .insert_edge(start, end, context)
+                .map_err(Box::new)
+        })
+    })
+    .map_err(|x| *x)
 }
 
 pub fn exist_edge(start: u32, end: u32) -> bool {
@@ -222,7 +345,37 @@ pub fn remove_edge(start: u32, end: u32) {
 }
 
 pub fn insert_node(symbol_id: SymbolId, name: &str) -> Result<u32, DagError> {
-    TYPE_DAG.with(|f| f.borrow_mut().insert_node(symbol_id, name))
+    // error is boxed not to move the large error through closures
+    timing::measure("type_dag", None, || {
+        TYPE_DAG.with(|f| {
+            f.borrow_mut()
+                .insert_node(symbol_id, name)
+                .map_err(Box::new)
+        })
+    })
+    .map_err(|x| *x)
+}
+
+pub fn insert_instance(parent: u32, child: u32, token: Token) {
+    timing::measure("type_dag", None, || {
+        TYPE_DAG.with(|f| f.borrow_mut().insert_instance(parent, child, token))
+    })
+}
+
+pub fn instances() -> Vec<(u32, u32, Token)> {
+    TYPE_DAG.with(|f| f.borrow().instances())
+}
+
+pub fn nodes() -> Vec<SymbolId> {
+    TYPE_DAG.with(|f| f.borrow().nodes())
+}
+
+pub fn edges() -> Vec<(SymbolId, SymbolId, Context)> {
+    TYPE_DAG.with(|f| f.borrow().edges())
+}
+
+pub fn instance_path(start: u32, end: u32) -> Option<Vec<Symbol>> {
+    TYPE_DAG.with(|f| f.borrow().instance_path(start, end))
 }
 
 pub fn get_symbol(node: u32) -> Symbol {
@@ -237,6 +390,10 @@ pub fn connected_components() -> Vec<Vec<Symbol>> {
     TYPE_DAG.with(|f| f.borrow().connected_components())
 }
 
+pub fn dependencies(symbol_id: SymbolId) -> Vec<Symbol> {
+    TYPE_DAG.with(|f| f.borrow().dependencies(symbol_id))
+}
+
 pub fn dump() -> String {
     TYPE_DAG.with(|f| f.borrow().dump())
 }
@@ -541,6 +541,16 @@ impl AssignPositionType {
             _ => false,
         }
     }
+
+    pub fn is_let(&self) -> bool {
+        matches!(
+            self,
+            AssignPositionType::Declaration {
+                r#type: AssignDeclarationType::Let,
+                ..
+            }
+        )
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -690,6 +700,51 @@ impl AssignPositionTree {
         None
     }
 
+    /// Returns the token of if_reset if the variable is assigned in an always_ff
+    /// only through the reset branch of the if_reset statement.
+    pub fn check_always_ff_assigned_only_in_reset(&self) -> Option<Token> {
+        if let Some(AssignPositionType::Declaration { ref r#type, .. }) = self.r#type {
+            if *r#type == AssignDeclarationType::AlwaysFF {
+                if self.children.iter().any(|x| x.has_non_reset_assign()) {
+                    return None;
+                } else {
+                    return self.children.iter().find_map(|x| x.reset_branch());
+                }
+            }
+        }
+
+        for child in &self.children {
+            let ret = child.check_always_ff_assigned_only_in_reset();
+            if ret.is_some() {
+                return ret;
+            }
+        }
+
+        None
+    }
+
+    fn has_non_reset_assign(&self) -> bool {
+        match self.r#type {
+            Some(AssignPositionType::StatementBranchItem {
+                r#type: AssignStatementBranchItemType::IfReset,
+                ..
+            }) => false,
+            Some(AssignPositionType::Statement { .. }) => true,
+            _ => self.children.iter().any(|x| x.has_non_reset_assign()),
+        }
+    }
+
+    fn reset_branch(&self) -> Option<Token> {
+        match self.r#type {
+            Some(AssignPositionType::StatementBranchItem {
+                r#type: AssignStatementBranchItemType::IfReset,
+                token,
+                ..
+            }) => Some(token),
+            _ => self.children.iter().find_map(|x| x.reset_branch()),
+        }
+    }
+
     fn is_resettable(&self) -> bool {
         if let Some(AssignPositionType::Statement { resettable, .. }) = self.r#type {
             resettable
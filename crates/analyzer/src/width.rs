@@ -0,0 +1,242 @@
+use crate::cast::{self, CastClass};
+use crate::evaluator::Evaluator;
+use crate::handlers::check_width_value::evaluate;
+use crate::symbol::{SymbolKind, Type, TypeKind};
+use crate::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+
+/// Returns the value of the expression if it doesn't depend on overridable parameters
+fn fixed_value(arg: &Expression) -> Option<usize> {
+    match evaluate(arg)? {
+        (value, None) => value.try_into().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the width of the type without packed and unpacked dimensions,
+/// and the packed dimensions
+fn element_width(arg: &Type) -> Option<(usize, Vec<Option<usize>>)> {
+    let mut dims: Vec<_> = arg.width.iter().map(fixed_value).collect();
+    let width = match arg.kind {
+        TypeKind::Bit | TypeKind::Logic => {
+            if dims.is_empty() {
+                dims.push(Some(1));
+            }
+            1
+        }
+        TypeKind::U32 | TypeKind::I32 => {
+            dims.push(Some(32));
+            1
+        }
+        TypeKind::U64 | TypeKind::I64 => {
+            dims.push(Some(64));
+            1
+        }
+        TypeKind::UserDefined(_) => {
+            let r#type = Type {
+                width: vec![],
+                array: vec![],
+                ..arg.clone()
+            };
+            Evaluator::new().total_width(&r#type)?
+        }
+        _ => return None,
+    };
+    Some((width, dims))
+}
+
+/// Returns the total width of the packed type.
+/// Returns `None` if the width depends on overridable parameters.
+pub fn type_width(arg: &Type) -> Option<usize> {
+    if !arg.array.is_empty() {
+        return None;
+    }
+    let (width, dims) = element_width(arg)?;
+    dims.into_iter().try_fold(width, |acc, x| Some(acc * x?))
+}
+
+/// Returns the number of elements selected by the select, or `None` for single index
+fn select_width(arg: &Select) -> Option<Option<usize>> {
+    let Some(ref x) = arg.select_opt else {
+        return Some(None);
+    };
+    let width = match x.select_operator.as_ref() {
+        SelectOperator::Colon(_) => {
+            let msb = fixed_value(&arg.expression)?;
+            let lsb = fixed_value(&x.expression)?;
+            msb.abs_diff(lsb) + 1
+        }
+        SelectOperator::PlusColon(_) | SelectOperator::MinusColon(_) | SelectOperator::Step(_) => {
+            fixed_value(&x.expression)?
+        }
+    };
+    Some(Some(width))
+}
+
+/// Returns the width of the signal which the identifier refers to including selects
+pub fn identifier_width(arg: &ExpressionIdentifier) -> Option<usize> {
+    if arg.expression_identifier_opt.is_some() {
+        return None;
+    }
+    let symbol = symbol_table::resolve(arg).ok()?;
+    let r#type = match symbol.found.kind {
+        SymbolKind::Variable(_)
+        | SymbolKind::Port(_)
+        | SymbolKind::Parameter(_)
+        | SymbolKind::StructMember(_)
+        | SymbolKind::UnionMember(_) => symbol.found.kind.get_type()?.clone(),
+        _ => return None,
+    };
+
+    // selects are applied to the last member
+    let selects: Vec<_> = if let Some(x) = arg.expression_identifier_list0.last() {
+        x.expression_identifier_list0_list
+            .iter()
+            .map(|x| x.select.as_ref())
+            .collect()
+    } else {
+        arg.expression_identifier_list
+            .iter()
+            .map(|x| x.select.as_ref())
+            .collect()
+    };
+
+    // unpacked dimensions should be selected by single index
+    let array = r#type.array.len();
+    if selects.len() < array {
+        return None;
+    }
+    for x in &selects[0..array] {
+        if x.select_opt.is_some() {
+            return None;
+        }
+    }
+
+    let (width, dims) = element_width(&r#type)?;
+    let selects = &selects[array..];
+    if selects.len() > dims.len() {
+        return None;
+    }
+
+    let mut ret = width;
+    for x in &dims[selects.len()..] {
+        ret *= (*x)?;
+    }
+    if let Some((last, rest)) = selects.split_last() {
+        // part-select can't be followed by other selects
+        for x in rest {
+            if x.select_opt.is_some() {
+                return None;
+            }
+        }
+        if let Some(x) = select_width(last)? {
+            ret *= x;
+        }
+    }
+    Some(ret)
+}
+
+/// Returns the width of the expression if it can be determined
+/// without the context of the expression
+pub fn expression_width(arg: &Expression) -> Option<usize> {
+    let x = expression_operand(arg)?;
+    if let Some(ref x) = x.expression11_opt {
+        let x = cast::target_type(&x.casting_type)?;
+        return match x.class {
+            CastClass::Float | CastClass::Clock | CastClass::Reset => None,
+            _ => x.width,
+        };
+    }
+
+    let x = x.expression12.as_ref();
+    let mut ret = factor_width(&x.factor);
+    for x in x.expression12_list.iter().rev() {
+        let operator = match x.expression12_list_group.as_ref() {
+            Expression12ListGroup::UnaryOperator(x) => {
+                x.unary_operator.unary_operator_token.to_string()
+            }
+            Expression12ListGroup::Operator03(x) => x.operator03.operator03_token.to_string(),
+            Expression12ListGroup::Operator04(x) => x.operator04.operator04_token.to_string(),
+            Expression12ListGroup::Operator05(x) => x.operator05.operator05_token.to_string(),
+            Expression12ListGroup::Operator09(x) => x.operator09.operator09_token.to_string(),
+        };
+        // reduction and logical operators return 1-bit
+        if !matches!(operator.as_str(), "~" | "+" | "-") {
+            ret = Some(1);
+        }
+    }
+    ret
+}
+
+fn factor_width(arg: &Factor) -> Option<usize> {
+    match arg {
+        Factor::Number(x) => match x.number.as_ref() {
+            Number::IntegralNumber(x) => match x.integral_number.as_ref() {
+                IntegralNumber::Based(x) => {
+                    let text = x.based.based_token.to_string().replace('_', "");
+                    let (width, _) = text.split_once('\'')?;
+                    width.parse().ok()
+                }
+                _ => None,
+            },
+            Number::RealNumber(_) => None,
+        },
+        Factor::IdentifierFactor(x) => {
+            let x = &x.identifier_factor;
+            if x.struct_constructor().is_some() {
+                None
+            } else if x.function_call().is_some() {
+                let symbol = symbol_table::resolve(x.expression_identifier.as_ref()).ok()?;
+                match symbol.found.kind {
+                    SymbolKind::Function(ref x) => type_width(x.ret.as_ref()?),
+                    _ => None,
+                }
+            } else {
+                identifier_width(&x.expression_identifier)
+            }
+        }
+        Factor::LParenExpressionRParen(x) => expression_width(&x.expression),
+        Factor::LBraceConcatenationListRBrace(x) => concatenation_width(&x.concatenation_list),
+        Factor::IfExpression(x) => {
+            // the narrower branch is extended to the wider one
+            let mut ret = 0;
+            for x in if_expression_branches(&x.if_expression) {
+                ret = ret.max(expression_width(x)?);
+            }
+            Some(ret)
+        }
+        _ => None,
+    }
+}
+
+pub fn concatenation_width(arg: &ConcatenationList) -> Option<usize> {
+    let mut items = vec![arg.concatenation_item.as_ref()];
+    items.extend(
+        arg.concatenation_list_list
+            .iter()
+            .map(|x| x.concatenation_item.as_ref()),
+    );
+
+    let mut ret = 0;
+    for x in items {
+        let width = expression_width(&x.expression)?;
+        let count = if let Some(ref x) = x.concatenation_item_opt {
+            fixed_value(&x.expression)?
+        } else {
+            1
+        };
+        ret += width * count;
+    }
+    Some(ret)
+}
+
+pub fn if_expression_branches(arg: &IfExpression) -> Vec<&Expression> {
+    let mut ret = vec![arg.expression0.as_ref()];
+    ret.extend(
+        arg.if_expression_list
+            .iter()
+            .map(|x| x.expression0.as_ref()),
+    );
+    ret.push(arg.expression1.as_ref());
+    ret
+}
@@ -0,0 +1,151 @@
+use miette::Diagnostic as MietteDiagnostic;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use thiserror::Error;
+use veryl_analyzer::{Analyzer, AnalyzerError};
+use veryl_emitter::Emitter;
+use veryl_formatter::Formatter;
+use veryl_metadata::Metadata;
+use veryl_parser::veryl_grammar_trait::Veryl;
+use veryl_parser::{Parser, ParserError};
+
+#[derive(Debug, Error, MietteDiagnostic)]
+pub enum Diagnostic {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parser(#[from] ParserError),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Analyzer(#[from] AnalyzerError),
+}
+
+static COMPILER_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Compiler which owns the analyzer tables of the current thread
+thread_local!(static TABLE_OWNER: Cell<Option<usize>> = const { Cell::new(None) });
+
+struct Source {
+    path: PathBuf,
+    text: String,
+}
+
+/// Compiler runs parse, analysis, emission and formatting of in-memory sources.
+///
+/// The analyzer stores symbols in thread-local tables.
+/// So each `Compiler` resets the tables of the current thread at `analyze`,
+/// and `emit` re-runs the analysis if the tables have been used by another `Compiler` since then.
+/// This means that multiple compilers can be used in a thread alternately,
+/// and compilers in different threads are independent of each other.
+/// Files referred by `embed` and `include` declarations are still read from the filesystem.
+pub struct Compiler {
+    id: usize,
+    metadata: Metadata,
+    sources: Vec<Source>,
+    parsed: HashMap<PathBuf, Veryl>,
+    analyzed: bool,
+}
+
+impl Compiler {
+    pub fn new(metadata: Metadata) -> Self {
+        Self {
+            id: COMPILER_ID.fetch_add(1, Ordering::Relaxed),
+            metadata,
+            sources: Vec::new(),
+            parsed: HashMap::new(),
+            analyzed: false,
+        }
+    }
+
+    /// Adds a source. If the path has been added already, the source text is replaced.
+    pub fn add_source<T: AsRef<Path>>(&mut self, path: T, text: &str) {
+        let path = path.as_ref().to_path_buf();
+        if let Some(x) = self.sources.iter_mut().find(|x| x.path == path) {
+            x.text = text.to_string();
+        } else {
+            self.sources.push(Source {
+                path,
+                text: text.to_string(),
+            });
+        }
+        self.analyzed = false;
+    }
+
+    pub fn analyze(&mut self) -> Vec<Diagnostic> {
+        let mut ret = Vec::new();
+
+        // namespaces of dependencies are inserted by Analyzer::new, so it should be after clear
+        Analyzer::new(&self.metadata).clear();
+        let analyzer = Analyzer::new(&self.metadata);
+        TABLE_OWNER.with(|f| f.set(Some(self.id)));
+
+        self.parsed.clear();
+        for source in &self.sources {
+            match Parser::parse(&source.text, &source.path) {
+                Ok(x) => {
+                    self.parsed.insert(source.path.clone(), x.veryl);
+                }
+                Err(x) => ret.push(x.into()),
+            }
+        }
+
+        let prj = &self.metadata.project.name;
+        let sources: Vec<_> = self
+            .sources
+            .iter()
+            .filter_map(|x| self.parsed.get(&x.path).map(|veryl| (x, veryl)))
+            .collect();
+
+        for (source, veryl) in &sources {
+            let errors = analyzer.analyze_pass1(prj, &source.text, &source.path, veryl);
+            ret.extend(errors.into_iter().map(Diagnostic::from));
+        }
+
+        Analyzer::analyze_post_pass1();
+
+        for (source, veryl) in &sources {
+            let errors = analyzer.analyze_pass2(prj, &source.text, &source.path, veryl);
+            ret.extend(errors.into_iter().map(Diagnostic::from));
+        }
+
+        for (source, veryl) in &sources {
+            let errors = analyzer.analyze_pass3(prj, &source.text, &source.path, veryl);
+            ret.extend(errors.into_iter().map(Diagnostic::from));
+        }
+
+        self.analyzed = true;
+        ret
+    }
+
+    /// Returns SystemVerilog code of the source.
+    /// `None` is returned if the path is not added or the source can't be parsed.
+    pub fn emit<T: AsRef<Path>>(&mut self, path: T) -> Option<String> {
+        let is_owner = TABLE_OWNER.with(|f| f.get()) == Some(self.id);
+        if !self.analyzed || !is_owner {
+            self.analyze();
+        }
+
+        let path = path.as_ref();
+        let veryl = self.parsed.get(path)?;
+        let dst = path.with_extension("sv");
+        let map = path.with_extension("sv.map");
+
+        let mut emitter = Emitter::new(&self.metadata, path, &dst, &map);
+        emitter.emit(&self.metadata.project.name, veryl);
+        Some(emitter.as_str().to_string())
+    }
+
+    /// Returns formatted code of the source.
+    /// `None` is returned if the path is not added or the source can't be parsed.
+    pub fn format<T: AsRef<Path>>(&self, path: T) -> Option<String> {
+        let path = path.as_ref();
+        let source = self.sources.iter().find(|x| x.path == path)?;
+        let parser = Parser::parse(&source.text, &source.path).ok()?;
+
+        let mut formatter = Formatter::new(&self.metadata);
+        formatter.format(&parser.veryl);
+        Some(formatter.as_str().to_string())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod compiler;
+pub use compiler::{Compiler, Diagnostic};
+#[cfg(test)]
+mod tests;
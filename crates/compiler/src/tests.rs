@@ -0,0 +1,103 @@
+use crate::{Compiler, Diagnostic};
+use std::str::FromStr;
+use std::thread;
+use veryl_analyzer::AnalyzerError;
+use veryl_metadata::Metadata;
+
+fn create_metadata(name: &str) -> Metadata {
+    let text = Metadata::create_default_toml(name).unwrap();
+    Metadata::from_str(&text).unwrap()
+}
+
+const CODE_A: &str = r#"module ModuleA (
+    i_a: input  logic,
+    o_a: output logic,
+) {
+    inst u: ModuleB (
+        i_b: i_a,
+        o_b: o_a,
+    );
+}
+"#;
+
+const CODE_B: &str = r#"module ModuleB (
+    i_b: input  logic,
+    o_b: output logic,
+) {
+    assign o_b = i_b;
+}
+"#;
+
+const CODE_C: &str = r#"module ModuleC {
+    var a: logic;
+    assign a = b;
+}
+"#;
+
+#[test]
+fn round_trip() {
+    let mut compiler = Compiler::new(create_metadata("prj"));
+    compiler.add_source("a.veryl", CODE_A);
+    compiler.add_source("b.veryl", CODE_B);
+
+    assert!(compiler.analyze().is_empty());
+
+    let emitted = compiler.emit("a.veryl").unwrap();
+    assert!(emitted.contains("module prj_ModuleA"));
+    assert!(emitted.contains("prj_ModuleB u ("));
+
+    assert_eq!(compiler.format("b.veryl").unwrap(), CODE_B);
+    assert!(compiler.emit("c.veryl").is_none());
+}
+
+#[test]
+fn diagnostics() {
+    let mut compiler = Compiler::new(create_metadata("prj"));
+    compiler.add_source("c.veryl", CODE_C);
+
+    let errors = compiler.analyze();
+    assert!(matches!(
+        errors[0],
+        Diagnostic::Analyzer(AnalyzerError::UndefinedIdentifier { .. })
+    ));
+
+    compiler.add_source("c.veryl", "module ModuleC {");
+    let errors = compiler.analyze();
+    assert!(matches!(errors[0], Diagnostic::Parser(_)));
+    assert!(compiler.emit("c.veryl").is_none());
+    assert!(compiler.format("c.veryl").is_none());
+}
+
+#[test]
+fn independent_compilations() {
+    let mut compiler0 = Compiler::new(create_metadata("prj0"));
+    compiler0.add_source("a.veryl", CODE_A);
+    compiler0.add_source("b.veryl", CODE_B);
+    assert!(compiler0.analyze().is_empty());
+    let emitted0 = compiler0.emit("a.veryl").unwrap();
+
+    // symbols of compiler0 should not be visible from compiler1
+    let mut compiler1 = Compiler::new(create_metadata("prj1"));
+    compiler1.add_source("a.veryl", CODE_A);
+    let errors = compiler1.analyze();
+    assert!(matches!(
+        errors[0],
+        Diagnostic::Analyzer(AnalyzerError::UndefinedIdentifier { .. })
+    ));
+    compiler1.add_source("b.veryl", CODE_B);
+    assert!(compiler1.analyze().is_empty());
+    let emitted1 = compiler1.emit("a.veryl").unwrap();
+    assert!(emitted1.contains("module prj1_ModuleA"));
+
+    // compiler0 can be used again after compiler1
+    assert_eq!(compiler0.emit("a.veryl").unwrap(), emitted0);
+
+    let handle = thread::spawn(|| {
+        let mut compiler = Compiler::new(create_metadata("prj0"));
+        compiler.add_source("a.veryl", CODE_A);
+        compiler.add_source("b.veryl", CODE_B);
+        assert!(compiler.analyze().is_empty());
+        compiler.emit("a.veryl").unwrap()
+    });
+    assert_eq!(handle.join().unwrap(), emitted0);
+}
@@ -1,20 +1,27 @@
+use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use veryl_aligner::{align_kind, Aligner, Location};
 use veryl_analyzer::attribute::Attribute as Attr;
-use veryl_analyzer::attribute::{AllowItem, CondTypeItem, EnumEncodingItem};
+use veryl_analyzer::attribute::{CondTypeItem, EnumEncodingItem};
 use veryl_analyzer::attribute_table;
+use veryl_analyzer::cast::{self, CastLowering};
 use veryl_analyzer::evaluator::{Evaluated, Evaluator};
 use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::struct_port::{flatten_struct_port, FlattenedElement};
 use veryl_analyzer::symbol::TypeModifier as SymTypeModifier;
 use veryl_analyzer::symbol::{
     GenericMap, Port, Symbol, SymbolId, SymbolKind, TypeKind, VariableAffiliation,
 };
 use veryl_analyzer::symbol_path::{GenericSymbolPath, SymbolPath};
 use veryl_analyzer::symbol_table::{self, ResolveError, ResolveResult};
-use veryl_analyzer::{msb_table, namespace_table};
-use veryl_metadata::{Build, BuiltinType, ClockType, Format, Metadata, ResetType, SourceMapTarget};
-use veryl_parser::resource_table::{self, StrId};
+use veryl_analyzer::{msb_table, namespace_table, AnalyzerErrorCode};
+use veryl_metadata::{
+    Build, BuiltinType, ClockType, EnumStyle, Format, Metadata, NameOverride, ResetType,
+    SourceMapTarget,
+};
+use veryl_parser::resource_table::{self, StrId, TokenId};
+use veryl_parser::timing;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{is_anonymous_token, Token, TokenSource, VerylToken};
 use veryl_parser::veryl_walker::VerylWalker;
@@ -64,14 +71,26 @@ pub struct Emitter {
     default_reset: Option<SymbolId>,
     reset_signal: Option<String>,
     default_block: Option<String>,
+    in_generate_region: bool,
     enum_width: usize,
+    enum_name: String,
     emit_enum_implicit_valiant: bool,
     file_scope_import: Vec<String>,
     attribute: Vec<AttributeType>,
     assignment_lefthand_side: Option<ExpressionIdentifier>,
     generic_map: Vec<Vec<GenericMap>>,
     source_map: Option<SourceMap>,
+    line_mapping: Option<(u32, u32)>,
     resolved_identifier: Vec<String>,
+    inout_ports: Vec<SymbolId>,
+    in_pad_module: bool,
+    inst_inout_ports: Vec<StrId>,
+    inst_identifier: String,
+    struct_ports: Vec<(SymbolId, Vec<FlattenedElement>)>,
+    struct_port_variables: Vec<String>,
+    inst_struct_ports: Vec<(StrId, Vec<FlattenedElement>)>,
+    init_files: Vec<PathBuf>,
+    target_groups: Option<Vec<usize>>,
 }
 
 impl Default for Emitter {
@@ -102,18 +121,54 @@ impl Default for Emitter {
             default_reset: None,
             reset_signal: None,
             default_block: None,
+            in_generate_region: false,
             enum_width: 0,
+            enum_name: String::new(),
             emit_enum_implicit_valiant: false,
             file_scope_import: Vec::new(),
             attribute: Vec::new(),
             assignment_lefthand_side: None,
             generic_map: Vec::new(),
             source_map: None,
+            line_mapping: None,
             resolved_identifier: Vec::new(),
+            inout_ports: Vec::new(),
+            in_pad_module: false,
+            inst_inout_ports: Vec::new(),
+            inst_identifier: String::new(),
+            struct_ports: Vec::new(),
+            struct_port_variables: Vec::new(),
+            inst_struct_ports: Vec::new(),
+            init_files: Vec::new(),
+            target_groups: None,
         }
     }
 }
 
+/// Returns identifier of module, interface or package declaration
+fn declaration_identifier(x: &DescriptionItem) -> Option<Box<Identifier>> {
+    match x {
+        DescriptionItem::ModuleDeclaration(x) => Some(x.module_declaration.identifier.clone()),
+        DescriptionItem::InterfaceDeclaration(x) => {
+            Some(x.interface_declaration.identifier.clone())
+        }
+        DescriptionItem::PackageDeclaration(x) => Some(x.package_declaration.identifier.clone()),
+        _ => None,
+    }
+}
+
+/// Converts doc comment to normal SystemVerilog comment
+fn normal_comment(x: &Token) -> Token {
+    let text = x.text.to_string();
+    if let Some(text) = text.strip_prefix("///") {
+        let mut ret = *x;
+        ret.text = resource_table::insert_str(&format!("//{text}"));
+        ret
+    } else {
+        *x
+    }
+}
+
 fn is_ifdef_attribute(arg: &Attribute) -> bool {
     matches!(
         arg.identifier.identifier_token.token.to_string().as_str(),
@@ -138,13 +193,61 @@ impl Emitter {
     pub fn emit(&mut self, project_name: &str, input: &Veryl) {
         namespace_table::set_default(&[project_name.into()]);
         self.mode = Mode::Align;
-        self.veryl(input);
-        self.aligner.finish_group();
-        self.aligner.gather_additions();
+        timing::measure("align", None, || {
+            self.veryl(input);
+            self.aligner.finish_group();
+            self.aligner.gather_additions();
+        });
         self.mode = Mode::Emit;
         self.veryl(input);
     }
 
+    /// Emits the specified elements of `veryl_list` only
+    pub fn emit_groups(&mut self, project_name: &str, input: &Veryl, groups: &[usize]) {
+        self.target_groups = Some(groups.to_vec());
+        self.emit(project_name, input);
+    }
+
+    /// Returns emitted names of modules, interfaces and packages with elements of `veryl_list` which should be emitted together.
+    /// Embed and include declarations are emitted with the following declaration.
+    pub fn declaration_groups(&mut self, input: &Veryl) -> Vec<(String, Vec<usize>)> {
+        let mut ret: Vec<(String, Vec<usize>)> = Vec::new();
+        let mut pending = Vec::new();
+        for (i, x) in input.veryl_list.iter().enumerate() {
+            let items: Vec<DescriptionItem> = x.description_group.as_ref().into();
+            let identifier = items.iter().find_map(declaration_identifier);
+
+            if let Some(identifier) = identifier {
+                let name = if let Ok(symbol) = symbol_table::resolve(identifier.as_ref()) {
+                    let context: SymbolContext = (&mut *self).into();
+                    symbol_string(&identifier.identifier_token, &symbol.found, &context)
+                } else {
+                    identifier.identifier_token.to_string()
+                };
+                pending.push(i);
+                ret.push((name, std::mem::take(&mut pending)));
+            } else if items.iter().any(|x| {
+                matches!(
+                    x,
+                    DescriptionItem::EmbedDeclaration(_) | DescriptionItem::IncludeDeclaration(_)
+                )
+            }) {
+                pending.push(i);
+            }
+        }
+        if let Some(last) = ret.last_mut() {
+            last.1.append(&mut pending);
+        }
+        ret
+    }
+
+    fn is_target_group(&self, index: usize) -> bool {
+        self.target_groups
+            .as_ref()
+            .map(|x| x.contains(&index))
+            .unwrap_or(true)
+    }
+
     pub fn as_str(&self) -> &str {
         &self.string
     }
@@ -153,6 +256,11 @@ impl Emitter {
         self.source_map.as_mut().unwrap()
     }
 
+    /// Returns init files which should be copied to the output directory
+    pub fn init_files(&self) -> &[PathBuf] {
+        &self.init_files
+    }
+
     fn str(&mut self, x: &str) {
         match self.mode {
             Mode::Emit => {
@@ -320,15 +428,95 @@ impl Emitter {
         self.src_line = x.line + newlines_in_text;
     }
 
+    /// Emits `line directive before the construct beginning with `x`
+    /// if its source line isn't continuous from the last directive
+    fn line_directive(&mut self, x: &Token) {
+        if self.mode != Mode::Emit || !self.build_opt.emit_line_directives {
+            return;
+        }
+        // directive should occupy its own line
+        if self.single_line || !self.in_expression.is_empty() || self.case_item_indent.is_some() {
+            return;
+        }
+        if self.source_map.is_none() {
+            return;
+        }
+
+        self.consume_adjust_line(x);
+        if let Some((dst_line, src_line)) = self.line_mapping {
+            if self.dst_line.checked_sub(dst_line).map(|x| src_line + x) == Some(x.line) {
+                return;
+            }
+        }
+
+        let directive = self.source_map().get_line_directive(x.line);
+        self.unindent();
+        self.str(&directive);
+        self.str(NEWLINE);
+        self.indent();
+        self.line_mapping = Some((self.dst_line, x.line));
+    }
+
+    fn push_comments(&mut self, comments: &[Token], will_push: bool) {
+        // temporary indent to adjust indent of comments with the next push
+        if will_push {
+            self.indent += 1;
+        }
+        // detect line comment newline which will consume the next newline
+        self.consumed_next_newline = false;
+        for x in comments {
+            // insert space between comments in the same line
+            if x.line == self.src_line && !self.in_start_token {
+                self.space(1);
+            }
+            for _ in 0..x.line - self.src_line {
+                self.unindent();
+                self.str(NEWLINE);
+                self.indent();
+            }
+
+            self.push_token(&normal_comment(x));
+        }
+        if will_push {
+            self.indent -= 1;
+        }
+        if self.consumed_next_newline {
+            self.unindent();
+            self.str(NEWLINE);
+            self.indent();
+        }
+    }
+
+    /// Emits trailing doc comments at the line above the item
+    fn leading_doc_comments(&mut self, comments: &[Token]) {
+        if self.mode != Mode::Emit || self.build_opt.strip_comments {
+            return;
+        }
+
+        for x in comments {
+            self.push_token(&normal_comment(x));
+            self.consumed_next_newline = false;
+            self.newline();
+        }
+    }
+
     fn process_token(&mut self, x: &VerylToken, will_push: bool, duplicated: Option<usize>) {
         match self.mode {
             Mode::Emit => {
                 self.push_token(&x.token);
 
+                // alignment is not required if the following comment begins at the next line
+                let comment_newline = x
+                    .comments
+                    .first()
+                    .map(|c| c.line > self.src_line)
+                    .unwrap_or(false);
                 let mut loc: Location = x.token.into();
                 loc.duplicated = duplicated;
                 if let Some(width) = self.aligner.additions.get(&loc) {
-                    self.space(*width as usize);
+                    if !comment_newline || self.build_opt.strip_comments {
+                        self.space(*width as usize);
+                    }
                 }
 
                 // skip to emit comments
@@ -336,32 +524,7 @@ impl Emitter {
                     return;
                 }
 
-                // temporary indent to adjust indent of comments with the next push
-                if will_push {
-                    self.indent += 1;
-                }
-                // detect line comment newline which will consume the next newline
-                self.consumed_next_newline = false;
-                for x in &x.comments {
-                    // insert space between comments in the same line
-                    if x.line == self.src_line && !self.in_start_token {
-                        self.space(1);
-                    }
-                    for _ in 0..x.line - self.src_line {
-                        self.unindent();
-                        self.str(NEWLINE);
-                        self.indent();
-                    }
-                    self.push_token(x);
-                }
-                if will_push {
-                    self.indent -= 1;
-                }
-                if self.consumed_next_newline {
-                    self.unindent();
-                    self.str(NEWLINE);
-                    self.indent();
-                }
+                self.push_comments(&x.comments, will_push);
             }
             Mode::Align => {
                 self.aligner.token(x);
@@ -642,7 +805,7 @@ impl Emitter {
         self.space(1);
 
         if prefix.is_some() || suffix.is_some() {
-            let token = VerylToken::new(symbol.token).append(&prefix, &suffix);
+            let token = escape_sv_keyword(VerylToken::new(symbol.token).append(&prefix, &suffix));
             self.str(&token.token.to_string());
         } else {
             self.str(&symbol.token.to_string());
@@ -662,7 +825,7 @@ impl Emitter {
         }
     }
 
-    fn always_ff_implicit_reset_event(&mut self) {
+    fn default_reset_signal(&self) -> (ResetType, Token) {
         let symbol = symbol_table::get(self.default_reset.unwrap()).unwrap();
         let (reset_kind, prefix, suffix) = match symbol.kind {
             SymbolKind::Port(x) => (
@@ -683,11 +846,17 @@ impl Emitter {
         };
 
         let token = if prefix.is_some() || suffix.is_some() {
-            VerylToken::new(symbol.token).append(&prefix, &suffix).token
+            escape_sv_keyword(VerylToken::new(symbol.token).append(&prefix, &suffix)).token
         } else {
             symbol.token
         };
 
+        (reset_type, token)
+    }
+
+    fn always_ff_implicit_reset_event(&mut self) {
+        let (reset_type, token) = self.default_reset_signal();
+
         let prefix_op = match reset_type {
             ResetType::AsyncHigh => {
                 self.str(",");
@@ -712,6 +881,95 @@ impl Emitter {
         self.reset_signal = Some(format!("{}{}", prefix_op, token));
     }
 
+    fn assertion_implicit_clocking(&mut self) {
+        if self.default_clock.is_none() {
+            return;
+        }
+
+        self.str("@(");
+        self.always_ff_implicit_clock_event();
+        self.str(")");
+        self.space(1);
+
+        if self.default_reset.is_some() {
+            let (reset_type, token) = self.default_reset_signal();
+            let prefix_op = match reset_type {
+                ResetType::AsyncHigh | ResetType::SyncHigh => "",
+                ResetType::AsyncLow | ResetType::SyncLow => "!",
+            };
+            self.str("disable iff");
+            self.space(1);
+            self.str(&format!("({}{})", prefix_op, token));
+            self.space(1);
+        }
+    }
+
+    fn assertion_message(&mut self, kind: &AssertionKind, r#else: &Else, message: &StringLiteral) {
+        self.space(1);
+        if matches!(kind, AssertionKind::Cover(_)) {
+            self.str("$info(");
+        } else {
+            self.r#else(r#else);
+            self.space(1);
+            self.str("$error(");
+        }
+        self.string_literal(message);
+        self.str(")");
+    }
+
+    /// Returns the last token of the item if it is omitted by strip_assertions
+    fn stripped_generate_item(&self, arg: &GenerateItem) -> Option<Token> {
+        match arg {
+            GenerateItem::AssertionDeclaration(x) if self.build_opt.strip_assertions => {
+                Some(x.assertion_declaration.semicolon.semicolon_token.token)
+            }
+            _ => None,
+        }
+    }
+
+    fn stripped_module_group(&self, arg: &ModuleGroup) -> Option<Token> {
+        if let ModuleGroupGroup::ModuleItem(x) = &*arg.module_group_group {
+            self.stripped_generate_item(&x.module_item.generate_item)
+        } else {
+            None
+        }
+    }
+
+    fn stripped_interface_group(&self, arg: &InterfaceGroup) -> Option<Token> {
+        if let InterfaceGroupGroup::InterfaceItem(x) = &*arg.interface_group_group {
+            if let InterfaceItem::GenerateItem(x) = &*x.interface_item {
+                return self.stripped_generate_item(&x.generate_item);
+            }
+        }
+        None
+    }
+
+    fn stripped_generate_group(&self, arg: &GenerateGroup) -> Option<Token> {
+        if let GenerateGroupGroup::GenerateItem(x) = &*arg.generate_group_group {
+            self.stripped_generate_item(&x.generate_item)
+        } else {
+            None
+        }
+    }
+
+    fn stripped_statement(&self, arg: &StatementBlockItem) -> Option<Token> {
+        if let StatementBlockItem::Statement(x) = arg {
+            match &*x.statement {
+                Statement::AssertionStatement(x) if self.build_opt.strip_assertions => {
+                    Some(x.assertion_statement.semicolon.semicolon_token.token)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    fn skip_stripped(&mut self, last: &Token) {
+        // skipped lines should not be counted as blank lines
+        self.src_line = last.line;
+    }
+
     fn always_ff_reset_exist_in_sensitivity_list(&mut self, arg: &AlwaysFfReset) -> bool {
         if let Ok(found) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
             let reset_kind = match found.found.kind {
@@ -775,6 +1033,30 @@ impl Emitter {
             .contains(&BuiltinType::Type)
     }
 
+    /// Emits `generate` keyword if the generate region is the outermost one and `emit_generate_keyword` is enabled.
+    /// Returns whether `generate_region_end` should emit `endgenerate`.
+    fn generate_region_begin(&mut self, first: &Token) -> bool {
+        if self.build_opt.emit_generate_keyword && !self.in_generate_region {
+            self.in_generate_region = true;
+            // blank line before the region is kept before `generate`
+            self.consume_adjust_line(first);
+            self.str("generate");
+            self.newline_push();
+            self.clear_adjust_line();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn generate_region_end(&mut self, outermost: bool) {
+        if outermost {
+            self.newline_pop();
+            self.str("endgenerate");
+            self.in_generate_region = false;
+        }
+    }
+
     fn emit_generate_named_block(&mut self, arg: &GenerateNamedBlock, prefix: &str) {
         self.default_block = Some(emitting_identifier(arg.identifier.as_ref()).to_string());
         self.token_will_push(
@@ -785,11 +1067,17 @@ impl Emitter {
         self.space(1);
         self.colon(&arg.colon);
         self.identifier(&arg.identifier);
-        for (i, x) in arg.generate_named_block_list.iter().enumerate() {
-            self.newline_list(i);
+        let mut n = 0;
+        for x in &arg.generate_named_block_list {
+            if let Some(last) = self.stripped_generate_group(&x.generate_group) {
+                self.skip_stripped(&last);
+                continue;
+            }
+            self.newline_list(n);
             self.generate_group(&x.generate_group);
+            n += 1;
         }
-        self.newline_list_post(arg.generate_named_block_list.is_empty());
+        self.newline_list_post(n == 0);
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
@@ -832,7 +1120,9 @@ impl Emitter {
                     self.attribute(&x.attribute);
                 }
 
-                if !is_var_declaration(x) {
+                if let Some(last) = self.stripped_statement(x) {
+                    self.skip_stripped(&last);
+                } else if !is_var_declaration(x) {
                     if i != 0 || ifdef_attributes.is_empty() {
                         self.newline_list(base + n_newlines);
                         n_newlines += 1;
@@ -850,7 +1140,7 @@ impl Emitter {
                 }
             }
         }
-        self.newline_list_post(arg.statement_block_list.is_empty());
+        self.newline_list_post(base + n_newlines == 0);
         self.token(&arg.r_brace.r_brace_token.replace(end_kw));
     }
 
@@ -877,6 +1167,38 @@ impl Emitter {
         }
     }
 
+    /// Returns the path of init file referred from `$readmemh`
+    fn init_file_path(&mut self, token: &Token) -> Option<String> {
+        let path = attribute_table::get(token)
+            .iter()
+            .rev()
+            .find_map(|x| match x {
+                Attr::InitFile(x) => Some(PathBuf::from(x.to_string())),
+                _ => None,
+            })?;
+
+        // Relative path is resolved from the directory of the source file
+        let source = match token.source {
+            TokenSource::File(x) => resource_table::get_path_value(x),
+            _ => None,
+        };
+        let path = match source.as_ref().and_then(|x| x.parent()) {
+            Some(dir) if path.is_relative() => dir.join(&path),
+            _ => path,
+        };
+        let path = fs::canonicalize(&path).unwrap_or(path);
+
+        let ret = if self.build_opt.copy_init_files {
+            if self.mode == Mode::Emit {
+                self.init_files.push(path.clone());
+            }
+            PathBuf::from(path.file_name()?)
+        } else {
+            path
+        };
+        Some(ret.to_string_lossy().replace('\\', "/"))
+    }
+
     fn cond_type_prefix(&self, token: &Token) -> (Option<String>, bool) {
         fn prefix(token: &Token) -> Option<String> {
             let mut attrs = attribute_table::get(token);
@@ -942,6 +1264,386 @@ impl Emitter {
         self.generic_map.pop();
     }
 
+    fn emit_expanded_inout_port(&mut self, arg: &PortDeclarationItem, x: &PortTypeConcrete) {
+        let (input, output, enable) = self.build_opt.inout_suffixes();
+        let Direction::Inout(ref inout) = *x.direction else {
+            unreachable!()
+        };
+        let inout = &inout.inout.inout_token;
+        let identifier = emitting_identifier(&arg.identifier);
+
+        self.align_start(align_kind::DIRECTION);
+        self.token(&inout.replace("input"));
+        self.align_finish(align_kind::DIRECTION);
+        self.space(1);
+        self.scalar_type(&x.array_type.scalar_type);
+        self.space(1);
+        self.align_start(align_kind::IDENTIFIER);
+        self.token(&identifier.append(&None, &Some(input)));
+        self.align_finish(align_kind::IDENTIFIER);
+        self.emit_expanded_inout_port_array(x);
+        self.str(",");
+        self.newline();
+
+        let direction = inout.replace("output");
+        self.align_start(align_kind::DIRECTION);
+        self.align_duplicated_token(align_kind::DIRECTION, &direction, 1);
+        self.duplicated_token(&direction, 1);
+        self.align_finish(align_kind::DIRECTION);
+        self.space(1);
+        self.scalar_type(&x.array_type.scalar_type);
+        self.space(1);
+        let token = identifier.append(&None, &Some(output));
+        self.align_start(align_kind::IDENTIFIER);
+        self.align_duplicated_token(align_kind::IDENTIFIER, &token, 1);
+        self.duplicated_token(&token, 1);
+        self.align_finish(align_kind::IDENTIFIER);
+        self.emit_expanded_inout_port_array(x);
+        self.str(",");
+        self.newline();
+
+        // output enable is a single bit signal
+        self.align_start(align_kind::DIRECTION);
+        self.align_duplicated_token(align_kind::DIRECTION, &direction, 2);
+        self.duplicated_token(&direction, 2);
+        self.align_finish(align_kind::DIRECTION);
+        self.space(1);
+        let logic = inout.replace("logic");
+        self.align_start(align_kind::TYPE);
+        if self.mode == Mode::Align {
+            // dummy space for implicit type
+            self.space(1);
+        }
+        self.align_duplicated_token(align_kind::TYPE, &logic, 2);
+        self.duplicated_token(&logic, 2);
+        self.align_finish(align_kind::TYPE);
+        self.align_start(align_kind::WIDTH);
+        let loc = self.align_last_location(align_kind::TYPE);
+        self.align_dummy_location(align_kind::WIDTH, loc);
+        self.align_finish(align_kind::WIDTH);
+        self.space(1);
+        let token = identifier.append(&None, &Some(enable));
+        self.align_start(align_kind::IDENTIFIER);
+        self.align_duplicated_token(align_kind::IDENTIFIER, &token, 2);
+        self.duplicated_token(&token, 2);
+        self.align_finish(align_kind::IDENTIFIER);
+        self.align_start(align_kind::ARRAY);
+        let loc = self.align_last_location(align_kind::IDENTIFIER);
+        self.align_dummy_location(align_kind::ARRAY, loc);
+        self.align_finish(align_kind::ARRAY);
+    }
+
+    fn emit_expanded_inout_port_array(&mut self, x: &PortTypeConcrete) {
+        self.align_start(align_kind::ARRAY);
+        if let Some(ref x) = x.array_type.array_type_opt {
+            self.space(1);
+            self.array(&x.array);
+        } else {
+            let loc = self.align_last_location(align_kind::IDENTIFIER);
+            self.align_dummy_location(align_kind::ARRAY, loc);
+        }
+        self.align_finish(align_kind::ARRAY);
+    }
+
+    /// Emits `assign pad = if enable { value } else { 'z };` as assignments to the expanded signals
+    fn emit_expanded_inout_assign(&mut self, arg: &AssignDeclaration) {
+        let (_, output, enable) = self.build_opt.inout_suffixes();
+        let (enable_expression, value_expression) = tristate_expression(&arg.expression).unwrap();
+        let identifier = emitting_identifier(&arg.hierarchical_identifier.identifier);
+
+        self.token(&arg.assign.assign_token.replace("always_comb"));
+        self.space(1);
+        self.align_start(align_kind::IDENTIFIER);
+        self.token(&identifier.append(&None, &Some(enable)));
+        self.align_finish(align_kind::IDENTIFIER);
+        self.space(1);
+        self.equ(&arg.equ);
+        self.space(1);
+        self.expression(enable_expression);
+        self.str(";");
+        self.newline();
+
+        self.duplicated_token(&arg.assign.assign_token.replace("always_comb"), 1);
+        self.space(1);
+        let token = identifier.append(&None, &Some(output));
+        self.align_start(align_kind::IDENTIFIER);
+        self.align_duplicated_token(align_kind::IDENTIFIER, &token, 1);
+        self.duplicated_token(&token, 1);
+        self.align_finish(align_kind::IDENTIFIER);
+        self.space(1);
+        self.str("=");
+        self.space(1);
+        self.expression(value_expression);
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Emits tri-state drivers of inout ports of the instance connected in `#[pad]` module
+    fn emit_pad_inout_driver(&mut self, arg: &InstDeclaration) {
+        let Some(ref x) = arg.inst_declaration_opt1 else {
+            return;
+        };
+        let Some(ref x) = x.inst_declaration_opt2 else {
+            return;
+        };
+        let (_, output, enable) = self.build_opt.inout_suffixes();
+        let ports: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+        for port in ports {
+            if !self
+                .inst_inout_ports
+                .contains(&port.identifier.identifier_token.token.text)
+            {
+                continue;
+            }
+            let pad = if let Some(ref x) = port.inst_port_item_opt {
+                expression_identifier(&x.expression)
+                    .unwrap()
+                    .scoped_identifier
+                    .identifier()
+                    .to_string()
+            } else {
+                emitting_identifier(&port.identifier).to_string()
+            };
+            let wire = format!(
+                "__{}_{}",
+                self.inst_identifier, port.identifier.identifier_token
+            );
+
+            self.str(&format!("var type({pad}) {wire}{output};"));
+            self.newline();
+            self.str(&format!("logic {wire}{enable};"));
+            self.newline();
+            self.str(&format!(
+                "assign {pad} = {wire}{enable} ? {wire}{output} : 'z;"
+            ));
+            self.newline();
+        }
+    }
+
+    /// Emits connections of an inout port expanded into input, output and output enable signals
+    fn emit_expanded_inout_connection(&mut self, arg: &InstPortItem) {
+        let (input, output, enable) = self.build_opt.inout_suffixes();
+        let port = arg.identifier.identifier_token.clone();
+        let connected = if let Some(ref x) = arg.inst_port_item_opt {
+            expression_identifier(&x.expression)
+                .unwrap()
+                .scoped_identifier
+                .identifier()
+                .clone()
+        } else {
+            emitting_identifier(arg.identifier.as_ref())
+        };
+
+        self.str(".");
+        self.align_start(align_kind::IDENTIFIER);
+        self.token(&port.append(&None, &Some(input.clone())));
+        self.align_finish(align_kind::IDENTIFIER);
+        self.space(1);
+        self.str("(");
+        self.align_start(align_kind::EXPRESSION);
+        if let Some(ref x) = arg.inst_port_item_opt {
+            self.token(&x.colon.colon_token.replace(""));
+            self.expression(&x.expression);
+        } else {
+            let token = if self.in_pad_module {
+                connected.clone()
+            } else {
+                connected.append(&None, &Some(input))
+            };
+            self.align_duplicated_token(align_kind::EXPRESSION, &token, 0);
+            self.duplicated_token(&token, 0);
+        }
+        self.align_finish(align_kind::EXPRESSION);
+        self.str(")");
+
+        for (i, suffix) in [(1, output), (2, enable)] {
+            let connected = if self.in_pad_module {
+                connected.replace(&format!("__{}_{}{}", self.inst_identifier, port, suffix))
+            } else {
+                connected.append(&None, &Some(suffix.clone()))
+            };
+            let port = port.append(&None, &Some(suffix));
+
+            self.str(",");
+            self.newline();
+            self.str(".");
+            self.align_start(align_kind::IDENTIFIER);
+            self.align_duplicated_token(align_kind::IDENTIFIER, &port, i);
+            self.duplicated_token(&port, i);
+            self.align_finish(align_kind::IDENTIFIER);
+            self.space(1);
+            self.str("(");
+            self.align_start(align_kind::EXPRESSION);
+            self.align_duplicated_token(align_kind::EXPRESSION, &connected, i);
+            self.duplicated_token(&connected, i);
+            self.align_finish(align_kind::EXPRESSION);
+            self.str(")");
+        }
+    }
+
+    /// Emits the token as the `i`-th duplicated token, or the token itself if `i` is 0
+    fn aligned_duplicated_token(&mut self, kind: usize, x: &VerylToken, i: usize) {
+        self.align_start(kind);
+        if i == 0 {
+            self.token(x);
+        } else {
+            self.align_duplicated_token(kind, x, i);
+            self.duplicated_token(x, i);
+        }
+        self.align_finish(kind);
+    }
+
+    /// Emits leaf members of a struct-typed port as individual ports.
+    /// The variable of the port and the assignments from/to the members are emitted in the module.
+    fn emit_flattened_struct_port(
+        &mut self,
+        arg: &PortDeclarationItem,
+        x: &PortTypeConcrete,
+        elements: &[FlattenedElement],
+    ) {
+        let (direction, is_input) = match x.direction.as_ref() {
+            Direction::Input(x) => (&x.input.input_token, true),
+            Direction::Output(x) => (&x.output.output_token, false),
+            _ => unreachable!(),
+        };
+        let identifier = emitting_identifier(&arg.identifier);
+
+        let members = elements.iter().flat_map(|x| x.members.iter());
+        for (i, member) in members.enumerate() {
+            if i != 0 {
+                self.str(",");
+                self.newline();
+            }
+            self.aligned_duplicated_token(align_kind::DIRECTION, direction, i);
+            self.space(1);
+
+            // identifier token is duplicated as type, width and identifier of each member
+            let r#type = identifier.replace(if member.is_bit { "bit" } else { "logic" });
+            self.align_start(align_kind::TYPE);
+            if self.mode == Mode::Align {
+                // dummy space for implicit type
+                self.space(1);
+            }
+            self.align_duplicated_token(align_kind::TYPE, &r#type, i * 3 + 1);
+            self.duplicated_token(&r#type, i * 3 + 1);
+            self.align_finish(align_kind::TYPE);
+            self.align_start(align_kind::WIDTH);
+            if member.width > 1 {
+                self.space(1);
+                let width = identifier.replace(&format!("[{}-1:0]", member.width));
+                self.align_duplicated_token(align_kind::WIDTH, &width, i * 3 + 2);
+                self.duplicated_token(&width, i * 3 + 2);
+            } else {
+                let loc = self.align_last_location(align_kind::TYPE);
+                self.align_dummy_location(align_kind::WIDTH, loc);
+            }
+            self.align_finish(align_kind::WIDTH);
+            self.space(1);
+            self.aligned_duplicated_token(
+                align_kind::IDENTIFIER,
+                &identifier.replace(&member.name),
+                i * 3,
+            );
+            self.align_start(align_kind::ARRAY);
+            let loc = self.align_last_location(align_kind::IDENTIFIER);
+            self.align_dummy_location(align_kind::ARRAY, loc);
+            self.align_finish(align_kind::ARRAY);
+        }
+
+        let mut emitter = Emitter {
+            project_name: self.project_name,
+            build_opt: self.build_opt.clone(),
+            format_opt: self.format_opt.clone(),
+            generic_map: self.generic_map.clone(),
+            ..Default::default()
+        };
+        emitter.scalar_type(&x.array_type.scalar_type);
+        emitter.space(1);
+        emitter.str(&identifier.to_string());
+        if let Some(ref x) = x.array_type.array_type_opt {
+            emitter.space(1);
+            emitter.array(&x.array);
+        }
+        emitter.str(";");
+        self.struct_port_variables
+            .push(emitter.as_str().to_string());
+
+        for element in elements {
+            let members: Vec<_> = element.members.iter().map(|x| x.name.as_str()).collect();
+            let members = members.join(", ");
+            let assign = if is_input {
+                format!("assign {identifier}{} = {{{members}}};", element.select)
+            } else {
+                format!("assign {{{members}}} = {identifier}{};", element.select)
+            };
+            self.struct_port_variables.push(assign);
+        }
+    }
+
+    /// Emits connections of a struct-typed port flattened into ports of its leaf members
+    fn emit_flattened_struct_connection(
+        &mut self,
+        arg: &InstPortItem,
+        elements: &[FlattenedElement],
+    ) {
+        let port = arg.identifier.identifier_token.clone();
+        let (connected, text) = if let Some(ref x) = arg.inst_port_item_opt {
+            let mut emitter = Emitter {
+                project_name: self.project_name,
+                build_opt: self.build_opt.clone(),
+                format_opt: self.format_opt.clone(),
+                generic_map: self.generic_map.clone(),
+                ..Default::default()
+            };
+            emitter.expression(&x.expression);
+            let connected = expression_identifier(&x.expression)
+                .unwrap()
+                .scoped_identifier
+                .identifier()
+                .clone();
+            (connected, emitter.as_str().to_string())
+        } else {
+            let connected = emitting_identifier(arg.identifier.as_ref());
+            let text = connected.to_string();
+            (connected, text)
+        };
+
+        let members = elements
+            .iter()
+            .flat_map(|x| x.members.iter().map(move |y| (&x.select, y)));
+        for (i, (select, member)) in members.enumerate() {
+            if i != 0 {
+                self.str(",");
+                self.newline();
+            }
+            self.str(".");
+            self.aligned_duplicated_token(
+                align_kind::IDENTIFIER,
+                &port.replace(&member.name),
+                i * 2,
+            );
+            self.space(1);
+            self.str("(");
+            if i == 0 {
+                if let Some(ref x) = arg.inst_port_item_opt {
+                    self.token(&x.colon.colon_token.replace(""));
+                }
+            }
+            // anonymous identifier is emitted as empty
+            let expression = if text.is_empty() {
+                text.clone()
+            } else {
+                format!("{text}{select}{}", member.select)
+            };
+            // connected token may be the port token in case of implicit connection
+            let expression = connected.replace(&expression);
+            self.align_start(align_kind::EXPRESSION);
+            self.align_duplicated_token(align_kind::EXPRESSION, &expression, i * 2 + 1);
+            self.duplicated_token(&expression, i * 2 + 1);
+            self.align_finish(align_kind::EXPRESSION);
+            self.str(")");
+        }
+    }
+
     fn emit_function_call(
         &mut self,
         identifier: &ExpressionIdentifier,
@@ -1028,6 +1730,132 @@ impl Emitter {
         }
     }
 
+    /// Emits the name of top-level declaration including the project prefix
+    fn top_level_name(&mut self, identifier: &Identifier, map: &GenericMap) {
+        let context: SymbolContext = self.into();
+        let Ok(symbol) = symbol_table::resolve(identifier) else {
+            if map.generic() {
+                self.str(&map.name.clone());
+            } else {
+                self.identifier(identifier);
+            }
+            return;
+        };
+        let namespace = &symbol.found.namespace;
+
+        if map.generic() {
+            let project_prefix = format!("{}_", namespace);
+            let prefix = name_override(namespace, &context).and_then(|x| x.prefix.as_ref());
+            let name = match (prefix, map.name.strip_prefix(&project_prefix)) {
+                (Some(prefix), Some(name)) => format!("{prefix}{name}"),
+                _ => map.name.clone(),
+            };
+            self.str(&name);
+        } else {
+            self.str(&namespace_string(namespace, &context));
+            self.identifier(identifier);
+        }
+        let suffix = name_suffix(namespace, &context);
+        if !suffix.is_empty() {
+            self.str(suffix);
+        }
+    }
+
+    /// Emits the pragma specified by enum_keep_pragma if the variable is enum type
+    fn enum_keep_pragma(&mut self, identifier: &Identifier) {
+        if self.build_opt.enum_style != EnumStyle::Typedef {
+            return;
+        }
+        let Some(template) = self.build_opt.enum_keep_pragma.clone() else {
+            return;
+        };
+        let Ok(symbol) = symbol_table::resolve(identifier) else {
+            return;
+        };
+        let SymbolKind::Variable(ref x) = symbol.found.kind else {
+            return;
+        };
+        let TypeKind::UserDefined(ref x) = x.r#type.kind else {
+            return;
+        };
+        let Some(symbol) = x.symbol.and_then(symbol_table::get) else {
+            return;
+        };
+        if matches!(symbol.kind, SymbolKind::Enum(_)) {
+            self.space(1);
+            self.str(&template.replace("{enum}", &symbol.token.to_string()));
+        }
+    }
+
+    /// Returns the emitting order of items.
+    /// If reorder_consts is enabled, const declarations and functions referred from them
+    /// are moved before their first uses, and the other items keep the source order.
+    fn declaration_order<T>(
+        &self,
+        items: &[T],
+        declared: impl Fn(&T) -> Option<(&Identifier, bool)>,
+        walk: impl Fn(&mut TokenIdCollector, &T),
+    ) -> Vec<usize> {
+        if !self.build_opt.reorder_consts {
+            return (0..items.len()).collect();
+        }
+
+        let tokens: Vec<_> = items
+            .iter()
+            .map(|x| {
+                let mut collector = TokenIdCollector::default();
+                walk(&mut collector, x);
+                collector.ids
+            })
+            .collect();
+
+        let mut dependencies = vec![HashSet::new(); items.len()];
+        for (j, item) in items.iter().enumerate() {
+            let Some((identifier, is_const)) = declared(item) else {
+                continue;
+            };
+            let Ok(symbol) = symbol_table::resolve(identifier) else {
+                continue;
+            };
+            for reference in &symbol.found.references {
+                for (i, ids) in tokens.iter().enumerate() {
+                    let is_const_user = matches!(declared(&items[i]), Some((_, true)));
+                    if i != j && (is_const || is_const_user) && ids.contains(&reference.id) {
+                        dependencies[i].insert(j);
+                    }
+                }
+            }
+        }
+
+        // dependencies are pulled before each item in the source order.
+        // items in cyclic dependency are emitted in the source order
+        // because the cycle is reported by analyzer.
+        fn visit(
+            i: usize,
+            dependencies: &[HashSet<usize>],
+            visited: &mut [bool],
+            ret: &mut Vec<usize>,
+        ) {
+            if visited[i] {
+                return;
+            }
+            visited[i] = true;
+            let mut deps: Vec<_> = dependencies[i].iter().copied().collect();
+            deps.sort();
+            for j in deps {
+                visit(j, dependencies, visited, ret);
+            }
+            ret.push(i);
+        }
+
+        let mut visited = vec![false; items.len()];
+        let mut ret = Vec::new();
+        for i in 0..items.len() {
+            visit(i, &dependencies, &mut visited, &mut ret);
+        }
+        ret
+    }
+
     fn push_generic_map(&mut self, map: GenericMap) {
         if let Some(maps) = self.generic_map.last_mut() {
             maps.push(map);
@@ -1074,7 +1902,14 @@ impl VerylWalker for Emitter {
                 _ => unreachable!(),
             };
 
-            if let Some(actual_width) = strnum_bitwidth::bitwidth(number, base_num) {
+            // zero can't be represented by zero-width number
+            let actual_width = if number.chars().all(|x| x == '0' || x == '_') {
+                Some(1)
+            } else {
+                strnum_bitwidth::bitwidth(number, base_num)
+            };
+
+            if let Some(actual_width) = actual_width {
                 let text = format!("{actual_width}'{base}{number}");
                 self.veryl_token(&arg.based_token.replace(&text));
             } else {
@@ -1115,12 +1950,18 @@ impl VerylWalker for Emitter {
                 additional_endif += 1;
             }
 
-            self.veryl_token(&arg.comma_token);
+            // comments after comma should be placed after `endif
+            let mut comma = arg.comma_token.clone();
+            let comments = std::mem::take(&mut comma.comments);
+            self.veryl_token(&comma);
             self.str("`endif");
             for _ in 0..additional_endif {
                 self.newline();
                 self.str("`endif");
             }
+            if self.mode == Mode::Emit && !self.build_opt.strip_comments {
+                self.push_comments(&comments, false);
+            }
         } else {
             self.veryl_token(&arg.comma_token);
         }
@@ -1297,7 +2138,11 @@ impl VerylWalker for Emitter {
             match self.resolve_symbol_with_generics(arg) {
                 (Ok(symbol), _) => {
                     let context: SymbolContext = self.into();
-                    let text = symbol_string(arg.identifier(), &symbol.found, &context);
+                    let mut text = symbol_string(arg.identifier(), &symbol.found, &context);
+                    // inout port is read through the expanded input signal
+                    if self.inout_ports.contains(&symbol.found.id) {
+                        text.push_str(&self.build_opt.inout_suffixes().0);
+                    }
                     self.veryl_token(&arg.identifier().replace(&text));
                     self.push_resolved_identifier(&text);
                 }
@@ -1478,31 +2323,78 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'Expression11'
     #[inline(never)]
     fn expression11(&mut self, arg: &Expression11) {
+        let lowering = arg.expression11_opt.as_ref().and_then(|x| {
+            cast::source_type(&arg.expression12)
+                .zip(cast::target_type(&x.casting_type))
+                .map(|(src, dst)| cast::lowering(&src, &dst))
+        });
+        let mut suffix = String::new();
         if let Some(x) = &arg.expression11_opt {
+            let integer = match x.casting_type.as_ref() {
+                CastingType::U32(_) => Some((false, "int")),
+                CastingType::U64(_) => Some((false, "longint")),
+                CastingType::I32(_) => Some((true, "int")),
+                CastingType::I64(_) => Some((true, "longint")),
+                _ => None,
+            };
+            let sign = match (lowering, integer) {
+                (Some(CastLowering::Signed), _) => "$signed(",
+                (Some(CastLowering::Unsigned), _) => "$unsigned(",
+                // int and longint are signed, so signedness is required unless the source is
+                // known to be signed
+                (Some(_), Some((true, _))) => "",
+                (None, Some((true, _))) => "signed'(",
+                (_, Some((false, _))) => "unsigned'(",
+                _ => "",
+            };
+            if !sign.is_empty() {
+                self.str(sign);
+                suffix.push(')');
+            }
+
             match x.casting_type.as_ref() {
-                CastingType::U32(_) => self.str("unsigned'(int'("),
-                CastingType::U64(_) => self.str("unsigned'(longint'("),
-                CastingType::I32(_) => self.str("signed'(int'("),
-                CastingType::I64(_) => self.str("signed'(longint'("),
+                CastingType::U32(_)
+                | CastingType::U64(_)
+                | CastingType::I32(_)
+                | CastingType::I64(_) => {
+                    self.str(integer.unwrap().1);
+                    self.str("'(");
+                    suffix.push(')');
+                }
                 CastingType::F32(x) => {
                     self.f32(&x.f32);
                     self.str("'(");
+                    suffix.push(')');
+                }
+                CastingType::F64(x) => {
+                    self.f64(&x.f64);
+                    self.str("'(");
+                    suffix.push(')');
+                }
+                CastingType::Bit(x) => {
+                    self.bit(&x.bit);
+                    self.str("'(");
+                    suffix.push(')');
                 }
-                CastingType::F64(x) => {
-                    self.f64(&x.f64);
+                CastingType::Logic(x) => {
+                    self.logic(&x.logic);
                     self.str("'(");
+                    suffix.push(')');
                 }
                 CastingType::UserDefinedType(x) => {
                     self.user_defined_type(&x.user_defined_type);
                     self.str("'(");
+                    suffix.push(')');
                 }
                 CastingType::Based(x) => {
                     self.based(&x.based);
                     self.str("'(");
+                    suffix.push(')');
                 }
                 CastingType::BaseLess(x) => {
                     self.base_less(&x.base_less);
                     self.str("'(");
+                    suffix.push(')');
                 }
                 // casting to clock type doesn't change polarity
                 CastingType::Clock(_)
@@ -1555,30 +2447,59 @@ impl VerylWalker for Emitter {
             }
         }
         self.expression12(&arg.expression12);
-        if let Some(x) = &arg.expression11_opt {
-            match x.casting_type.as_ref() {
-                CastingType::U32(_)
-                | CastingType::U64(_)
-                | CastingType::I32(_)
-                | CastingType::I64(_) => self.str("))"),
-                CastingType::F32(_)
-                | CastingType::F64(_)
-                | CastingType::UserDefinedType(_)
-                | CastingType::Based(_)
-                | CastingType::BaseLess(_) => self.str(")"),
-                _ => (),
-            }
+        self.str(&suffix);
+        if let Some(CastLowering::Truncate { from, to }) = lowering {
+            self.str(&format!(" /* truncated from {from}-bit to {to}-bit */"));
         }
     }
 
     /// Semantic action for non-terminal 'IdentifierFactor'
     fn identifier_factor(&mut self, arg: &IdentifierFactor) {
         self.expression_identifier(&arg.expression_identifier);
-        if let Some(ref x) = arg.identifier_factor_opt {
-            self.emit_function_call(&arg.expression_identifier, &x.function_call);
+        if let Some(x) = arg.function_call() {
+            self.emit_function_call(&arg.expression_identifier, x);
+        } else if let Some(x) = arg.struct_constructor() {
+            self.struct_constructor(x);
+        }
+    }
+
+    /// Semantic action for non-terminal 'StructConstructor'
+    fn struct_constructor(&mut self, arg: &StructConstructor) {
+        self.quote_l_brace(&arg.quote_l_brace);
+        self.struct_constructor_list(&arg.struct_constructor_list);
+        if let Some(ref x) = arg.struct_constructor_opt {
+            self.token(&x.dot_dot.dot_dot_token.replace(","));
+            self.space(1);
+            self.defaul(&x.defaul);
+            self.token(&x.l_paren.l_paren_token.replace(":"));
+            self.space(1);
+            self.expression(&x.expression);
+            self.token(&x.r_paren.r_paren_token.replace(""));
+        }
+        self.r_brace(&arg.r_brace);
+    }
+
+    /// Semantic action for non-terminal 'StructConstructorList'
+    fn struct_constructor_list(&mut self, arg: &StructConstructorList) {
+        self.struct_constructor_item(&arg.struct_constructor_item);
+        for x in &arg.struct_constructor_list_list {
+            self.comma(&x.comma);
+            self.space(1);
+            self.struct_constructor_item(&x.struct_constructor_item);
+        }
+        if let Some(ref x) = arg.struct_constructor_list_opt {
+            self.token(&x.comma.comma_token.replace(""));
         }
     }
 
+    /// Semantic action for non-terminal 'StructConstructorItem'
+    fn struct_constructor_item(&mut self, arg: &StructConstructorItem) {
+        self.identifier(&arg.identifier);
+        self.colon(&arg.colon);
+        self.space(1);
+        self.expression(&arg.expression);
+    }
+
     /// Semantic action for non-terminal 'ArgumentList'
     fn argument_list(&mut self, arg: &ArgumentList) {
         self.argument_item(&arg.argument_item);
@@ -2272,6 +3193,24 @@ impl VerylWalker for Emitter {
         self.case_item_indent_pop();
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        if self.build_opt.strip_assertions {
+            // only reached as the direct statement of case/switch item
+            self.str(";");
+            return;
+        }
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.expression(&arg.expression);
+        self.r_paren(&arg.r_paren);
+        if let Some(ref x) = arg.assertion_statement_opt {
+            self.assertion_message(&arg.assertion_kind, &x.r#else, &x.string_literal);
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, arg: &Attribute) {
         let identifier = arg.identifier.identifier_token.to_string();
@@ -2382,6 +3321,7 @@ impl VerylWalker for Emitter {
             self.align_dummy_location(align_kind::ARRAY, loc);
         }
         self.align_finish(align_kind::ARRAY);
+        self.enum_keep_pragma(&arg.identifier);
         self.str(";");
         self.newline();
         if is_tri {
@@ -2414,6 +3354,16 @@ impl VerylWalker for Emitter {
             self.align_dummy_location(align_kind::ARRAY, loc);
         }
         self.align_finish(align_kind::ARRAY);
+        self.enum_keep_pragma(&arg.identifier);
+        if let Some(path) = self.init_file_path(&arg.var.var_token.token) {
+            self.str(";");
+            self.newline();
+            self.str(&format!(
+                "initial $readmemh(\"{}\", {})",
+                path,
+                emitting_identifier(arg.identifier.as_ref())
+            ));
+        }
         self.semicolon(&arg.semicolon);
     }
 
@@ -2491,6 +3441,7 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
+        self.line_directive(&arg.always_ff.always_ff_token.token);
         self.in_always_ff = true;
         self.always_ff(&arg.always_ff);
         self.space(1);
@@ -2569,12 +3520,15 @@ impl VerylWalker for Emitter {
                 ResetType::SyncLow => "!",
             };
 
+            let x = &arg.hierarchical_identifier;
+            let identifier = x
+                .hierarchical_identifier_list0
+                .last()
+                .map(|x| &x.identifier)
+                .unwrap_or(&x.identifier);
+            let (prefix, suffix) = escaping_prefix_suffix(identifier, prefix, suffix);
             let mut stringifier = Stringifier::new();
-            stringifier.hierarchical_identifier_with_prefix_suffix(
-                &arg.hierarchical_identifier,
-                &prefix,
-                &suffix,
-            );
+            stringifier.hierarchical_identifier_with_prefix_suffix(x, &prefix, &suffix);
             self.reset_signal = Some(format!("{}{}", prefix_op, stringifier.as_str()));
         } else {
             unreachable!()
@@ -2583,6 +3537,7 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'AlwaysCombDeclaration'
     fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) {
+        self.line_directive(&arg.always_comb.always_comb_token.token);
         self.always_comb(&arg.always_comb);
         self.space(1);
         self.statement_block(&arg.statement_block);
@@ -2590,6 +3545,12 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
+        if let Ok(symbol) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
+            if self.inout_ports.contains(&symbol.found.id) {
+                self.emit_expanded_inout_assign(arg);
+                return;
+            }
+        }
         let emit_assign =
             if let Ok(symbol) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
                 match &symbol.found.kind {
@@ -2686,6 +3647,25 @@ impl VerylWalker for Emitter {
             );
         }
 
+        if self.build_opt.enum_style == EnumStyle::Localparam {
+            self.enum_name = emitting_identifier(&arg.identifier).to_string();
+            self.token(&arg.r#enum.enum_token.replace("typedef"));
+            self.space(1);
+            if let Some(ref x) = arg.enum_declaration_opt {
+                self.scalar_type(&x.scalar_type);
+            } else {
+                self.str(&format!("logic [{}-1:0]", self.enum_width));
+            }
+            self.space(1);
+            self.identifier(&arg.identifier);
+            self.str(";");
+            self.token(&arg.l_brace.l_brace_token.replace(""));
+            self.newline();
+            self.enum_list(&arg.enum_list);
+            self.token(&arg.r_brace.r_brace_token.replace(""));
+            return;
+        }
+
         self.token(
             &arg.r#enum
                 .enum_token
@@ -2713,7 +3693,11 @@ impl VerylWalker for Emitter {
     fn enum_list(&mut self, arg: &EnumList) {
         self.enum_group(&arg.enum_group);
         for x in &arg.enum_list_list {
-            self.comma(&x.comma);
+            if self.build_opt.enum_style == EnumStyle::Localparam {
+                self.token(&x.comma.comma_token.replace(""));
+            } else {
+                self.comma(&x.comma);
+            }
             self.newline();
             self.enum_group(&x.enum_group);
         }
@@ -2747,6 +3731,10 @@ impl VerylWalker for Emitter {
             unreachable!();
         };
 
+        let localparam = self.build_opt.enum_style == EnumStyle::Localparam;
+        if localparam {
+            self.str(&format!("localparam {} ", self.enum_name));
+        }
         self.token(&identifier_with_prefix_suffix(
             &arg.identifier,
             &Some(format!("{}_", prefix)),
@@ -2757,13 +3745,16 @@ impl VerylWalker for Emitter {
             self.equ(&x.equ);
             self.space(1);
             self.expression(&x.expression);
-        } else if self.emit_enum_implicit_valiant {
+        } else if self.emit_enum_implicit_valiant || localparam {
             self.str(&format!(
                 " = {}'d{}",
                 self.enum_width,
                 value.value().unwrap_or(0),
             ));
         }
+        if localparam {
+            self.str(";");
+        }
     }
 
     /// Semantic action for non-terminal 'StructUnionDeclaration'
@@ -2852,6 +3843,7 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InitialDeclaration'
     fn initial_declaration(&mut self, arg: &InitialDeclaration) {
+        self.line_directive(&arg.initial.initial_token.token);
         self.initial(&arg.initial);
         self.space(1);
         self.statement_block(&arg.statement_block);
@@ -2859,17 +3851,32 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'FinalDeclaration'
     fn final_declaration(&mut self, arg: &FinalDeclaration) {
+        self.line_directive(&arg.r#final.final_token.token);
         self.r#final(&arg.r#final);
         self.space(1);
         self.statement_block(&arg.statement_block);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.property(&arg.property);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.assertion_implicit_clocking();
+        self.expression(&arg.expression);
+        self.r_paren(&arg.r_paren);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.assertion_message(&arg.assertion_kind, &x.r#else, &x.string_literal);
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'InstDeclaration'
     fn inst_declaration(&mut self, arg: &InstDeclaration) {
-        let allow_missing_port = attribute_table::contains(
-            &arg.inst.inst_token.token,
-            Attr::Allow(AllowItem::MissingPort),
-        );
+        let allow_missing_port =
+            attribute_table::is_allowed(&arg.inst.inst_token.token, AnalyzerErrorCode::MissingPort);
         let (defined_ports, generic_map) = if allow_missing_port {
             (Vec::new(), Vec::new())
         } else if let (Ok(symbol), _) = self.resolve_symbol_with_generics(&arg.scoped_identifier) {
@@ -2890,6 +3897,39 @@ impl VerylWalker for Emitter {
             unreachable!()
         };
 
+        self.inst_inout_ports =
+            if let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+                let symbol = match symbol.found.kind {
+                    SymbolKind::GenericInstance(ref x) => symbol_table::get(x.base).unwrap(),
+                    _ => symbol.found,
+                };
+                symbol
+                    .expanded_inout_ports(self.build_opt.expand_inout)
+                    .iter()
+                    .map(|x| x.name())
+                    .collect()
+            } else {
+                Vec::new()
+            };
+        self.inst_struct_ports =
+            if let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+                let symbol = match symbol.found.kind {
+                    SymbolKind::GenericInstance(ref x) => symbol_table::get(x.base).unwrap(),
+                    _ => symbol.found,
+                };
+                symbol
+                    .flattened_struct_ports(self.build_opt.flatten_struct_ports)
+                    .iter()
+                    .filter_map(|x| Some((x.name(), flatten_struct_port(x).ok()?)))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+        self.inst_identifier = arg.identifier.identifier_token.to_string();
+        if self.in_pad_module {
+            self.emit_pad_inout_driver(arg);
+        }
+
         self.single_line = arg.inst_declaration_opt1.is_none() && defined_ports.is_empty();
         self.token(&arg.inst.inst_token.replace(""));
         self.scoped_identifier(&arg.scoped_identifier);
@@ -2916,16 +3956,18 @@ impl VerylWalker for Emitter {
 
         if let Some(ref x) = arg.inst_declaration_opt1 {
             self.token_will_push(&x.l_paren.l_paren_token.replace("("));
-            self.newline_push();
             if let Some(ref x) = x.inst_declaration_opt2 {
+                self.newline_push();
                 self.inst_port_list(&x.inst_port_list);
 
                 let connected_ports: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
                 self.emit_inst_unconnected_port(&defined_ports, &connected_ports, &generic_map);
-            } else {
+                self.newline_pop();
+            } else if !defined_ports.is_empty() {
+                self.newline_push();
                 self.emit_inst_unconnected_port(&defined_ports, &Vec::new(), &generic_map);
+                self.newline_pop();
             }
-            self.newline_pop();
             self.token(&x.r_paren.r_paren_token.replace(")"));
         } else if !defined_ports.is_empty() {
             self.str("(");
@@ -2938,22 +3980,26 @@ impl VerylWalker for Emitter {
         }
         self.semicolon(&arg.semicolon);
         self.single_line = false;
+        self.inst_inout_ports.clear();
+        self.inst_struct_ports.clear();
     }
 
     /// Semantic action for non-terminal 'InstParameter'
     fn inst_parameter(&mut self, arg: &InstParameter) {
         self.hash(&arg.hash);
-        if self.single_line {
-            self.l_paren(&arg.l_paren);
+        if let Some(ref x) = arg.inst_parameter_opt {
+            if self.single_line {
+                self.l_paren(&arg.l_paren);
+                self.inst_parameter_list(&x.inst_parameter_list);
+            } else {
+                self.token_will_push(&arg.l_paren.l_paren_token);
+                self.newline_push();
+                self.inst_parameter_list(&x.inst_parameter_list);
+                self.newline_pop();
+            }
         } else {
+            // comments in empty parens are indented as list items
             self.token_will_push(&arg.l_paren.l_paren_token);
-            self.newline_push();
-        }
-        if let Some(ref x) = arg.inst_parameter_opt {
-            self.inst_parameter_list(&x.inst_parameter_list);
-        }
-        if !self.single_line {
-            self.newline_pop();
         }
         self.r_paren(&arg.r_paren);
     }
@@ -3050,6 +4096,22 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InstPortItem'
     fn inst_port_item(&mut self, arg: &InstPortItem) {
+        if self
+            .inst_inout_ports
+            .contains(&arg.identifier.identifier_token.token.text)
+        {
+            self.emit_expanded_inout_connection(arg);
+            return;
+        }
+        let elements = self
+            .inst_struct_ports
+            .iter()
+            .find(|(x, _)| *x == arg.identifier.identifier_token.token.text)
+            .map(|(_, x)| x.clone());
+        if let Some(elements) = elements {
+            self.emit_flattened_struct_connection(arg, &elements);
+            return;
+        }
         self.str(".");
         self.align_start(align_kind::IDENTIFIER);
         self.identifier(&arg.identifier);
@@ -3082,7 +4144,7 @@ impl VerylWalker for Emitter {
             self.r_paren(&arg.r_paren);
         } else {
             self.hash(&arg.hash);
-            self.l_paren(&arg.l_paren);
+            self.token_will_push(&arg.l_paren.l_paren_token);
             self.r_paren(&arg.r_paren);
         }
     }
@@ -3183,21 +4245,53 @@ impl VerylWalker for Emitter {
             self.newline_pop();
             self.r_paren(&arg.r_paren);
         } else {
-            self.l_paren(&arg.l_paren);
+            self.token_will_push(&arg.l_paren.l_paren_token);
             self.r_paren(&arg.r_paren);
         }
     }
 
     /// Semantic action for non-terminal 'PortDeclarationList'
     fn port_declaration_list(&mut self, arg: &PortDeclarationList) {
-        self.port_declaration_group(&arg.port_declaration_group);
+        let mut groups = vec![arg.port_declaration_group.as_ref()];
+        let mut commas = Vec::new();
         for x in &arg.port_declaration_list_list {
-            self.comma(&x.comma);
-            self.newline();
-            self.port_declaration_group(&x.port_declaration_group);
+            commas.push(x.comma.as_ref());
+            groups.push(x.port_declaration_group.as_ref());
         }
         if let Some(ref x) = arg.port_declaration_list_opt {
-            self.token(&x.comma.comma_token.replace(""));
+            commas.push(x.comma.as_ref());
+        }
+
+        for (i, group) in groups.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+
+            // Doc comment following the port in the same line is moved to the line above
+            let mut comma = commas.get(i).map(|x| (*x).clone());
+            if let Some(ref mut comma) = comma {
+                if let PortDeclarationGroupGroup::PortDeclarationItem(_) =
+                    group.port_declaration_group_group.as_ref()
+                {
+                    let line = comma.comma_token.token.line;
+                    let (docs, comments): (Vec<_>, Vec<_>) = comma
+                        .comma_token
+                        .comments
+                        .iter()
+                        .partition(|x| x.line == line && x.text.to_string().starts_with("///"));
+                    self.leading_doc_comments(&docs);
+                    comma.comma_token.comments = comments;
+                }
+            }
+
+            self.port_declaration_group(group);
+            if let Some(comma) = comma {
+                if i + 1 < groups.len() {
+                    self.comma(&comma);
+                } else {
+                    self.token(&comma.comma_token.replace(""));
+                }
+            }
         }
     }
 
@@ -3224,6 +4318,21 @@ impl VerylWalker for Emitter {
         match &*arg.port_declaration_item_group {
             PortDeclarationItemGroup::PortTypeConcrete(x) => {
                 let x = x.port_type_concrete.as_ref();
+                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+                    if self.inout_ports.contains(&symbol.found.id) {
+                        self.emit_expanded_inout_port(arg, x);
+                        return;
+                    }
+                    let elements = self
+                        .struct_ports
+                        .iter()
+                        .find(|(id, _)| *id == symbol.found.id)
+                        .map(|(_, x)| x.clone());
+                    if let Some(elements) = elements {
+                        self.emit_flattened_struct_port(arg, x, &elements);
+                        return;
+                    }
+                }
                 self.direction(&x.direction);
                 if let Direction::Modport(_) = *x.direction {
                     self.in_direction_modport = true;
@@ -3292,6 +4401,8 @@ impl VerylWalker for Emitter {
     fn function_declaration(&mut self, arg: &FunctionDeclaration) {
         let symbol = symbol_table::resolve(arg.identifier.as_ref()).unwrap();
         let maps = symbol.found.generic_maps();
+        let dpi_import = attribute_table::contains(&symbol.found.token, Attr::DpiImport);
+        let dpi_export = attribute_table::contains(&symbol.found.token, Attr::DpiExport);
 
         for (i, map) in maps.iter().enumerate() {
             if i != 0 {
@@ -3299,9 +4410,18 @@ impl VerylWalker for Emitter {
             }
             self.push_generic_map(map.clone());
 
-            self.function(&arg.function);
-            self.space(1);
-            self.str("automatic");
+            self.line_directive(&arg.function.function_token.token);
+            if dpi_import {
+                self.token(
+                    &arg.function
+                        .function_token
+                        .replace("import \"DPI-C\" context function"),
+                );
+            } else {
+                self.function(&arg.function);
+                self.space(1);
+                self.str("automatic");
+            }
             self.space(1);
             if let Some(ref x) = arg.function_declaration_opt1 {
                 self.scalar_type(&x.scalar_type);
@@ -3322,7 +4442,21 @@ impl VerylWalker for Emitter {
                 self.token(&x.minus_g_t.minus_g_t_token.replace(""));
             }
             self.str(";");
-            self.emit_statement_block(&arg.statement_block, "", "endfunction");
+            if dpi_import {
+                // DPI imported function has no body
+                self.token(&arg.statement_block.l_brace.l_brace_token.replace(""));
+                self.token(&arg.statement_block.r_brace.r_brace_token.replace(""));
+            } else {
+                self.emit_statement_block(&arg.statement_block, "", "endfunction");
+            }
+            if dpi_export {
+                self.newline();
+                self.str("export \"DPI-C\" function");
+                self.space(1);
+                let name = arg.identifier.identifier_token.strip_prefix("r#");
+                self.str(&name.to_string());
+                self.str(";");
+            }
 
             self.pop_generic_map();
         }
@@ -3361,11 +4495,17 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'UnsafeBlock'
     fn unsafe_block(&mut self, arg: &UnsafeBlock) {
-        for (i, x) in arg.unsafe_block_list.iter().enumerate() {
-            if i != 0 {
+        let mut n = 0;
+        for x in &arg.unsafe_block_list {
+            if let Some(last) = self.stripped_generate_group(&x.generate_group) {
+                self.skip_stripped(&last);
+                continue;
+            }
+            if n != 0 {
                 self.newline();
             }
             self.generate_group(&x.generate_group);
+            n += 1;
         }
     }
 
@@ -3376,6 +4516,20 @@ impl VerylWalker for Emitter {
             self.default_clock = x.default_clock;
             self.default_reset = x.default_reset;
         }
+        self.inout_ports = symbol
+            .found
+            .expanded_inout_ports(self.build_opt.expand_inout)
+            .iter()
+            .map(|x| x.symbol)
+            .collect();
+        self.in_pad_module = self.build_opt.expand_inout
+            && attribute_table::contains(&symbol.found.token, Attr::Pad);
+        self.struct_ports = symbol
+            .found
+            .flattened_struct_ports(self.build_opt.flatten_struct_ports)
+            .iter()
+            .filter_map(|x| Some((x.symbol, flatten_struct_port(x).ok()?)))
+            .collect();
 
         let maps = symbol.found.generic_maps();
         for (i, map) in maps.iter().enumerate() {
@@ -3384,17 +4538,10 @@ impl VerylWalker for Emitter {
             }
             self.push_generic_map(map.clone());
 
+            self.line_directive(&arg.module.module_token.token);
             self.module(&arg.module);
             self.space(1);
-            if map.generic() {
-                self.str(&map.name.clone());
-            } else {
-                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-                    let context: SymbolContext = self.into();
-                    self.str(&namespace_string(&symbol.found.namespace, &context));
-                }
-                self.identifier(&arg.identifier);
-            }
+            self.top_level_name(&arg.identifier, map);
             let file_scope_import = self.file_scope_import.clone();
             if !file_scope_import.is_empty() {
                 self.newline_push();
@@ -3412,16 +4559,34 @@ impl VerylWalker for Emitter {
                 self.space(1);
                 self.with_parameter(&x.with_parameter);
             }
+            self.struct_port_variables.clear();
             if let Some(ref x) = arg.module_declaration_opt3 {
                 self.space(1);
                 self.port_declaration(&x.port_declaration);
             }
             self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-            for (i, x) in arg.module_declaration_list.iter().enumerate() {
-                self.newline_list(i);
+            let order = self.declaration_order(
+                &arg.module_declaration_list,
+                |x| module_group_declaration(&x.module_group),
+                |c, x| c.module_group(&x.module_group),
+            );
+            let mut n = 0;
+            // variables of flattened struct-typed ports precede the module items
+            for x in std::mem::take(&mut self.struct_port_variables) {
+                self.newline_list(n);
+                self.str(&x);
+                n += 1;
+            }
+            for x in order.iter().map(|i| &arg.module_declaration_list[*i]) {
+                if let Some(last) = self.stripped_module_group(&x.module_group) {
+                    self.skip_stripped(&last);
+                    continue;
+                }
+                self.newline_list(n);
                 self.module_group(&x.module_group);
+                n += 1;
             }
-            self.newline_list_post(arg.module_declaration_list.is_empty());
+            self.newline_list_post(n == 0);
             self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
 
             self.pop_generic_map();
@@ -3429,6 +4594,9 @@ impl VerylWalker for Emitter {
 
         self.default_clock = None;
         self.default_reset = None;
+        self.inout_ports.clear();
+        self.in_pad_module = false;
+        self.struct_ports.clear();
     }
 
     /// Semantic action for non-terminal 'ModuleGroup'
@@ -3438,11 +4606,17 @@ impl VerylWalker for Emitter {
         }
         match &*arg.module_group_group {
             ModuleGroupGroup::LBraceModuleGroupGroupListRBrace(x) => {
-                for (i, x) in x.module_group_group_list.iter().enumerate() {
-                    if i != 0 {
+                let mut n = 0;
+                for x in &x.module_group_group_list {
+                    if let Some(last) = self.stripped_module_group(&x.module_group) {
+                        self.skip_stripped(&last);
+                        continue;
+                    }
+                    if n != 0 {
                         self.newline();
                     }
                     self.module_group(&x.module_group);
+                    n += 1;
                 }
             }
             ModuleGroupGroup::ModuleItem(x) => self.module_item(&x.module_item),
@@ -3463,17 +4637,10 @@ impl VerylWalker for Emitter {
             }
             self.push_generic_map(map.clone());
 
+            self.line_directive(&arg.interface.interface_token.token);
             self.interface(&arg.interface);
             self.space(1);
-            if map.generic() {
-                self.str(&map.name.clone());
-            } else {
-                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-                    let context: SymbolContext = self.into();
-                    self.str(&namespace_string(&symbol.found.namespace, &context));
-                }
-                self.identifier(&arg.identifier);
-            }
+            self.top_level_name(&arg.identifier, map);
             let file_scope_import = self.file_scope_import.clone();
             if !file_scope_import.is_empty() {
                 self.newline_push();
@@ -3492,11 +4659,22 @@ impl VerylWalker for Emitter {
                 self.with_parameter(&x.with_parameter);
             }
             self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-            for (i, x) in arg.interface_declaration_list.iter().enumerate() {
-                self.newline_list(i);
+            let order = self.declaration_order(
+                &arg.interface_declaration_list,
+                |x| interface_group_declaration(&x.interface_group),
+                |c, x| c.interface_group(&x.interface_group),
+            );
+            let mut n = 0;
+            for x in order.iter().map(|i| &arg.interface_declaration_list[*i]) {
+                if let Some(last) = self.stripped_interface_group(&x.interface_group) {
+                    self.skip_stripped(&last);
+                    continue;
+                }
+                self.newline_list(n);
                 self.interface_group(&x.interface_group);
+                n += 1;
             }
-            self.newline_list_post(arg.interface_declaration_list.is_empty());
+            self.newline_list_post(n == 0);
             self.token(&arg.r_brace.r_brace_token.replace("endinterface"));
 
             self.pop_generic_map();
@@ -3510,11 +4688,17 @@ impl VerylWalker for Emitter {
         }
         match &*arg.interface_group_group {
             InterfaceGroupGroup::LBraceInterfaceGroupGroupListRBrace(x) => {
-                for (i, x) in x.interface_group_group_list.iter().enumerate() {
-                    if i != 0 {
+                let mut n = 0;
+                for x in &x.interface_group_group_list {
+                    if let Some(last) = self.stripped_interface_group(&x.interface_group) {
+                        self.skip_stripped(&last);
+                        continue;
+                    }
+                    if n != 0 {
                         self.newline();
                     }
                     self.interface_group(&x.interface_group);
+                    n += 1;
                 }
             }
             InterfaceGroupGroup::InterfaceItem(x) => self.interface_item(&x.interface_item),
@@ -3526,6 +4710,7 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'GenerateIfDeclaration'
     fn generate_if_declaration(&mut self, arg: &GenerateIfDeclaration) {
+        let outermost = self.generate_region_begin(&arg.r#if.if_token.token);
         self.r#if(&arg.r#if);
         self.space(1);
         self.str("(");
@@ -3551,10 +4736,12 @@ impl VerylWalker for Emitter {
             self.space(1);
             self.generate_optional_named_block(&x.generate_optional_named_block);
         }
+        self.generate_region_end(outermost);
     }
 
     /// Semantic action for non-terminal 'GenerateForDeclaration'
     fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) {
+        let outermost = self.generate_region_begin(&arg.r#for.for_token.token);
         self.r#for(&arg.r#for);
         self.space(1);
         self.str("(");
@@ -3598,11 +4785,15 @@ impl VerylWalker for Emitter {
         self.str(")");
         self.space(1);
         self.generate_named_block(&arg.generate_named_block);
+        self.generate_region_end(outermost);
     }
 
     /// Semantic action for non-terminal 'GenerateBlockDeclaration'
     fn generate_block_declaration(&mut self, arg: &GenerateBlockDeclaration) {
+        let outermost =
+            self.generate_region_begin(&arg.generate_named_block.colon.colon_token.token);
         self.emit_generate_named_block(&arg.generate_named_block, "if (1) ");
+        self.generate_region_end(outermost);
     }
 
     /// Semantic action for non-terminal 'GenerateNamedBlock'
@@ -3624,11 +4815,17 @@ impl VerylWalker for Emitter {
             self.str(&name);
         }
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
-        for (i, x) in arg.generate_optional_named_block_list.iter().enumerate() {
-            self.newline_list(i);
+        let mut n = 0;
+        for x in &arg.generate_optional_named_block_list {
+            if let Some(last) = self.stripped_generate_group(&x.generate_group) {
+                self.skip_stripped(&last);
+                continue;
+            }
+            self.newline_list(n);
             self.generate_group(&x.generate_group);
+            n += 1;
         }
-        self.newline_list_post(arg.generate_optional_named_block_list.is_empty());
+        self.newline_list_post(n == 0);
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
@@ -3639,11 +4836,17 @@ impl VerylWalker for Emitter {
         }
         match &*arg.generate_group_group {
             GenerateGroupGroup::LBraceGenerateGroupGroupListRBrace(x) => {
-                for (i, x) in x.generate_group_group_list.iter().enumerate() {
-                    if i != 0 {
+                let mut n = 0;
+                for x in &x.generate_group_group_list {
+                    if let Some(last) = self.stripped_generate_group(&x.generate_group) {
+                        self.skip_stripped(&last);
+                        continue;
+                    }
+                    if n != 0 {
                         self.newline();
                     }
                     self.generate_group(&x.generate_group);
+                    n += 1;
                 }
             }
             GenerateGroupGroup::GenerateItem(x) => self.generate_item(&x.generate_item),
@@ -3664,19 +4867,24 @@ impl VerylWalker for Emitter {
             }
             self.push_generic_map(map.clone());
 
+            self.line_directive(&arg.package.package_token.token);
             self.package(&arg.package);
             self.space(1);
-            if map.generic() {
-                self.str(&map.name.clone());
-            } else {
-                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
-                    let context: SymbolContext = self.into();
-                    self.str(&namespace_string(&symbol.found.namespace, &context));
-                }
-                self.identifier(&arg.identifier);
-            }
+            self.top_level_name(&arg.identifier, map);
             self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-            for (i, x) in arg.package_declaration_list.iter().enumerate() {
+            let mut order = self.declaration_order(
+                &arg.package_declaration_list,
+                |x| package_group_declaration(&x.package_group),
+                |c, x| c.package_group(&x.package_group),
+            );
+            order.retain(|i| {
+                !is_unused_private_item(&arg.package_declaration_list[*i].package_group)
+            });
+            for (i, x) in order
+                .iter()
+                .map(|i| &arg.package_declaration_list[*i])
+                .enumerate()
+            {
                 self.newline_list(i);
                 if i == 0 {
                     let file_scope_import = self.file_scope_import.clone();
@@ -3687,7 +4895,7 @@ impl VerylWalker for Emitter {
                 }
                 self.package_group(&x.package_group);
             }
-            self.newline_list_post(arg.package_declaration_list.is_empty());
+            self.newline_list_post(order.is_empty());
             self.token(&arg.r_brace.r_brace_token.replace("endpackage"));
 
             self.pop_generic_map();
@@ -3809,7 +5017,41 @@ impl VerylWalker for Emitter {
                         }
                     }
                 }
-                for (i, x) in arg.veryl_list.iter().enumerate() {
+                let groups: Vec<_> = arg
+                    .veryl_list
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| self.is_target_group(*i))
+                    .map(|(_, x)| x)
+                    .collect();
+
+                // preamble follows the leading comments of the file in order of timescale and default_nettype
+                let top = groups.iter().find_map(|x| {
+                    let items: Vec<DescriptionItem> = x.description_group.as_ref().into();
+                    items.iter().find_map(declaration_identifier)
+                });
+                let preamble = !groups.is_empty()
+                    && !top.is_some_and(|x| {
+                        attribute_table::contains(&x.identifier_token.token, Attr::NoPreamble)
+                    });
+                if preamble {
+                    let mut emitted = false;
+                    if let Some(x) = self.build_opt.timescale.clone() {
+                        self.str(&format!("`timescale {x}"));
+                        self.newline();
+                        emitted = true;
+                    }
+                    if let Some(x) = self.build_opt.default_nettype.clone() {
+                        self.str(&format!("`default_nettype {x}"));
+                        self.newline();
+                        emitted = true;
+                    }
+                    if emitted {
+                        self.newline();
+                    }
+                }
+
+                for (i, x) in groups.iter().enumerate() {
                     if i != 0 {
                         self.newline();
                     }
@@ -3817,6 +5059,12 @@ impl VerylWalker for Emitter {
                 }
                 self.newline();
 
+                if preamble && self.build_opt.default_nettype.is_some() {
+                    self.newline();
+                    self.str("`default_nettype wire");
+                    self.newline();
+                }
+
                 // build map and insert link to map
                 if self.build_opt.sourcemap_target != SourceMapTarget::None {
                     self.source_map.as_mut().unwrap().build();
@@ -3826,8 +5074,10 @@ impl VerylWalker for Emitter {
             }
             Mode::Align => {
                 self.start(&arg.start);
-                for x in &arg.veryl_list {
-                    self.description_group(&x.description_group);
+                for (i, x) in arg.veryl_list.iter().enumerate() {
+                    if self.is_target_group(i) {
+                        self.description_group(&x.description_group);
+                    }
                 }
             }
         }
@@ -3857,10 +5107,98 @@ impl From<&mut Emitter> for SymbolContext {
     }
 }
 
+#[derive(Default)]
+struct TokenIdCollector {
+    ids: HashSet<TokenId>,
+}
+
+impl VerylWalker for TokenIdCollector {
+    /// Semantic action for non-terminal 'VerylToken'
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.ids.insert(arg.token.id);
+    }
+}
+
+/// Returns the identifier declared by the item and whether it is const declaration
+fn generate_item_declaration(arg: &GenerateItem) -> Option<(&Identifier, bool)> {
+    match arg {
+        GenerateItem::ConstDeclaration(x) => Some((&x.const_declaration.identifier, true)),
+        GenerateItem::FunctionDeclaration(x) => Some((&x.function_declaration.identifier, false)),
+        _ => None,
+    }
+}
+
+fn module_group_declaration(arg: &ModuleGroup) -> Option<(&Identifier, bool)> {
+    match &*arg.module_group_group {
+        ModuleGroupGroup::ModuleItem(x) => generate_item_declaration(&x.module_item.generate_item),
+        _ => None,
+    }
+}
+
+fn interface_group_declaration(arg: &InterfaceGroup) -> Option<(&Identifier, bool)> {
+    match &*arg.interface_group_group {
+        InterfaceGroupGroup::InterfaceItem(x) => match &*x.interface_item {
+            InterfaceItem::GenerateItem(x) => generate_item_declaration(&x.generate_item),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn package_group_declaration(arg: &PackageGroup) -> Option<(&Identifier, bool)> {
+    match &*arg.package_group_group {
+        PackageGroupGroup::PackageItem(x) => match &*x.package_item {
+            PackageItem::ConstDeclaration(x) => Some((&x.const_declaration.identifier, true)),
+            PackageItem::FunctionDeclaration(x) => {
+                Some((&x.function_declaration.identifier, false))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether the package item has `private` attribute and is not referred from anywhere
+fn is_unused_private_item(arg: &PackageGroup) -> bool {
+    let PackageGroupGroup::PackageItem(x) = &*arg.package_group_group else {
+        return false;
+    };
+    let identifier = match &*x.package_item {
+        PackageItem::VarDeclaration(x) => &x.var_declaration.identifier,
+        PackageItem::ConstDeclaration(x) => &x.const_declaration.identifier,
+        PackageItem::TypeDefDeclaration(x) => &x.type_def_declaration.identifier,
+        PackageItem::EnumDeclaration(x) => &x.enum_declaration.identifier,
+        PackageItem::StructUnionDeclaration(x) => &x.struct_union_declaration.identifier,
+        PackageItem::FunctionDeclaration(x) => &x.function_declaration.identifier,
+        PackageItem::ImportDeclaration(_) | PackageItem::ExportDeclaration(_) => return false,
+    };
+    symbol_table::resolve(identifier.as_ref())
+        .map(|x| x.found.is_private() && x.found.references.is_empty())
+        .unwrap_or(false)
+}
+
+/// Returns the prefix and suffix overridden for the project of the namespace
+fn name_override<'a>(
+    namespace: &Namespace,
+    context: &'a SymbolContext,
+) -> Option<&'a NameOverride> {
+    let project = namespace.paths.first()?;
+    context.build_opt.name_override(&project.to_string())
+}
+
+/// Returns the suffix of top-level names overridden for the project of the namespace
+fn name_suffix<'a>(namespace: &Namespace, context: &'a SymbolContext) -> &'a str {
+    name_override(namespace, context)
+        .and_then(|x| x.suffix.as_deref())
+        .unwrap_or("")
+}
+
 fn namespace_string(namespace: &Namespace, context: &SymbolContext) -> String {
     let mut ret = String::from("");
     let mut resolve_namespace = Namespace::new();
     let mut in_sv_namespace = false;
+    let prefix = name_override(namespace, context).and_then(|x| x.prefix.as_deref());
+    let suffix = name_suffix(namespace, context);
     for (i, path) in namespace.paths.iter().enumerate() {
         if i == 0 {
             // top level namespace is always `_`
@@ -3875,6 +5213,8 @@ fn namespace_string(namespace: &Namespace, context: &SymbolContext) -> String {
             // "$sv" namespace should be removed
             if text == "$sv_" {
                 in_sv_namespace = true;
+            } else if let Some(prefix) = prefix {
+                ret.push_str(prefix);
             } else {
                 let emit_prj_prefix = if context.build_opt.omit_project_prefix {
                     context.project_name != Some(*path)
@@ -3902,7 +5242,8 @@ fn namespace_string(namespace: &Namespace, context: &SymbolContext) -> String {
                     _ if in_sv_namespace => "::",
                     _ => "_",
                 };
-                ret.push_str(&format!("{}{}", path, separator));
+                let suffix = if i == 1 { suffix } else { "" };
+                ret.push_str(&format!("{}{}{}", path, suffix, separator));
             } else {
                 return format!("{}", namespace);
             }
@@ -3928,6 +5269,7 @@ pub fn symbol_string(token: &VerylToken, symbol: &Symbol, context: &SymbolContex
         SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_) => {
             ret.push_str(&namespace_string(&symbol.namespace, context));
             ret.push_str(&token_text);
+            ret.push_str(name_suffix(&symbol.namespace, context));
         }
         SymbolKind::Parameter(_)
         | SymbolKind::Function(_)
@@ -3976,6 +5318,9 @@ pub fn symbol_string(token: &VerylToken, symbol: &Symbol, context: &SymbolContex
                 ret.push_str(&namespace_string(&base.namespace, context));
             }
             ret.push_str(&token_text);
+            if top_level {
+                ret.push_str(name_suffix(&base.namespace, context));
+            }
         }
         SymbolKind::GenericParameter(_) | SymbolKind::ProtoModule(_) => (),
         SymbolKind::Port(x) => {
@@ -4012,6 +5357,36 @@ pub fn symbol_string(token: &VerylToken, symbol: &Symbol, context: &SymbolContex
     ret
 }
 
+/// Escapes the name rewritten by prefix and suffix if it collides with SystemVerilog keywords
+fn escape_sv_keyword(token: VerylToken) -> VerylToken {
+    let text = token.to_string();
+    if symbol_table::is_sv_keyword(&text) {
+        token.replace(&format!("\\{text} "))
+    } else {
+        token
+    }
+}
+
+/// Returns the prefix and suffix which also escape the rewritten name
+/// if it collides with SystemVerilog keywords
+fn escaping_prefix_suffix(
+    identifier: &Identifier,
+    prefix: Option<String>,
+    suffix: Option<String>,
+) -> (Option<String>, Option<String>) {
+    if identifier_with_prefix_suffix(identifier, &prefix, &suffix)
+        .to_string()
+        .starts_with('\\')
+    {
+        (
+            Some(format!("\\{}", prefix.unwrap_or_default())),
+            Some(format!("{} ", suffix.unwrap_or_default())),
+        )
+    } else {
+        (prefix, suffix)
+    }
+}
+
 pub fn identifier_with_prefix_suffix(
     identifier: &Identifier,
     prefix: &Option<String>,
@@ -4019,7 +5394,7 @@ pub fn identifier_with_prefix_suffix(
 ) -> VerylToken {
     if prefix.is_some() || suffix.is_some() {
         let token = &identifier.identifier_token.strip_prefix("r#");
-        token.append(prefix, suffix)
+        escape_sv_keyword(token.append(prefix, suffix))
     } else {
         identifier.identifier_token.strip_prefix("r#")
     }
@@ -1,7 +1,10 @@
 use crate::Emitter;
 use std::path::PathBuf;
-use veryl_analyzer::Analyzer;
-use veryl_metadata::{ClockType, Metadata, ResetType};
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{DocComment, Symbol, SymbolKind};
+use veryl_analyzer::{symbol_table, Analyzer};
+use veryl_metadata::{ClockType, EnumStyle, Metadata, NameOverride, ResetType};
+use veryl_parser::veryl_token::{Token, TokenSource};
 use veryl_parser::Parser;
 
 #[track_caller]
@@ -23,6 +26,54 @@ fn emit(metadata: &Metadata, code: &str) -> String {
     emitter.as_str().to_string()
 }
 
+#[track_caller]
+fn emit_projects(metadata: &Metadata, codes: &[(&str, &str)]) -> Vec<String> {
+    // register project namespaces as dependencies in lockfile
+    for (prj, _) in codes {
+        let token = Token::new(prj, 0, 0, 0, 0, TokenSource::External);
+        let symbol = Symbol::new(
+            &token,
+            SymbolKind::Namespace,
+            &Namespace::new(),
+            false,
+            DocComment::default(),
+        );
+        symbol_table::insert(&token, symbol);
+    }
+
+    let analyzer = Analyzer::new(metadata);
+    let parsers: Vec<_> = codes
+        .iter()
+        .map(|(prj, code)| Parser::parse(code, &format!("{prj}.veryl")).unwrap())
+        .collect();
+
+    for ((prj, code), parser) in codes.iter().zip(&parsers) {
+        analyzer.analyze_pass1(prj, code, &format!("{prj}.veryl"), &parser.veryl);
+    }
+    Analyzer::analyze_post_pass1();
+    for ((prj, code), parser) in codes.iter().zip(&parsers) {
+        analyzer.analyze_pass2(prj, code, &format!("{prj}.veryl"), &parser.veryl);
+    }
+
+    let mut ret = Vec::new();
+    for ((prj, _), parser) in codes.iter().zip(&parsers) {
+        let mut emitter = Emitter::new(
+            metadata,
+            &PathBuf::from(format!("{prj}.veryl")),
+            &PathBuf::from(format!("{prj}.sv")),
+            &PathBuf::from(format!("{prj}.sv.map")),
+        );
+        emitter.emit(prj, &parser.veryl);
+        let text = emitter.as_str().to_string();
+        if cfg!(windows) {
+            ret.push(text.replace("\r\n", "\n"));
+        } else {
+            ret.push(text);
+        }
+    }
+    ret
+}
+
 #[test]
 fn prefix_suffix_clock_posedge_reset_high() {
     let code = r#"module ModuleA (
@@ -185,6 +236,75 @@ endmodule
     assert_eq!(ret, expect);
 }
 
+#[test]
+fn prefix_suffix_sv_keyword() {
+    let code = r#"module ModuleA (
+    u: input clock,
+    r: input reset,
+) {
+    var a: logic;
+    always_ff (u, r) {
+        if_reset {
+            a = 0;
+        } else {
+            a = 1;
+        }
+    }
+
+    var b: logic;
+    always_ff {
+        if_reset {
+            b = 0;
+        } else {
+            b = a;
+        }
+    }
+}
+"#;
+
+    let expect = r#"module prj_ModuleA (
+    input logic \uwire ,
+    input logic \rand  
+);
+    logic a;
+    always_ff @ (posedge \uwire , posedge \rand ) begin
+        if (\rand ) begin
+            a <= 0;
+        end else begin
+            a <= 1;
+        end
+    end
+
+    logic b;
+    always_ff @ (posedge \uwire , posedge \rand ) begin
+        if (\rand ) begin
+            b <= 0;
+        end else begin
+            b <= a;
+        end
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.clock_type = ClockType::PosEdge;
+    metadata.build.reset_type = ResetType::AsyncHigh;
+    metadata.build.clock_posedge_suffix = Some("wire".to_string());
+    metadata.build.reset_high_suffix = Some("and".to_string());
+    metadata.build.escape_sv_keyword = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
 #[test]
 fn omit_project_prefix() {
     let code = r#"module ModuleA {
@@ -223,151 +343,178 @@ endinterface
 }
 
 #[test]
-fn expand_case_statement() {
-    let code = r#"module ModuleA {
-    const y: bit = 1;
+fn dependency_name_override() {
+    let dep = r#"package PackageA {
+    const WIDTH: u32 = 8;
+}
 
-    var a: logic;
-    let x: logic = 1;
+module Fifo #(
+    param N: u32 = PackageA::WIDTH,
+) (
+    i_d: input  logic<N>,
+    o_d: output logic<N>,
+) {
+    assign o_d = i_d;
+}
 
-    always_comb {
-        case x {
-            0: a = 1;
-            1: a = 1;
-            2: {
-                   a = 1;
-                   a = 1;
-                   a = 1;
-               }
-            3, 4   : a = 1;
-            5..=7  : a = 1;
-            y - 1  : a = 1;
-            default: a = 1;
-        }
-    }
+module Wrap::<W: const> {
+    var d: logic<W>;
+    inst u: Fifo #( N: W ) (
+        i_d: d,
+        o_d: _,
+    );
 }
 "#;
 
-    let expect = r#"module ModuleA;
-    localparam bit y = 1;
+    let code = r#"module Top {
+    var a: logic<8>;
+    var b: logic<8>;
+    var c: logic<8>;
 
-    logic a;
-    logic x;
-    always_comb x = 1;
+    inst u0: fifo_v1::Fifo (
+        i_d: a,
+        o_d: b,
+    );
 
-    always_comb begin
-        case (1'b1)
-            (x) ==? (0): a = 1;
-            (x) ==? (1): a = 1;
-            (x) ==? (2): begin
-                             a = 1;
-                             a = 1;
-                             a = 1;
-                         end
-            (x) ==? (3), (x) ==? (4    ): a = 1;
-            ((x) >= (5)) && ((x) <= (7)): a = 1;
-            (x) ==? (y - 1             ): a = 1;
-            default                     : a = 1;
-        endcase
-    end
-endmodule
-//# sourceMappingURL=test.sv.map
+    inst u1: fifo_v2::Fifo (
+        i_d: b,
+        o_d: c,
+    );
+
+    inst u2: fifo_v2::Wrap::<fifo_v2::PackageA::WIDTH>;
+}
 "#;
 
-    let mut metadata: Metadata =
-        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    let expect_v1 = r#"package v1_PackageA;
+    localparam int unsigned WIDTH = 8;
+endpackage
 
-    metadata.build.omit_project_prefix = true;
-    metadata.build.expand_inside_operation = true;
+module v1_Fifo #(
+    parameter int unsigned N = v1_PackageA::WIDTH
+) (
+    input  logic [N-1:0] i_d,
+    output logic [N-1:0] o_d
+);
+    always_comb o_d = i_d;
+endmodule
 
-    let ret = if cfg!(windows) {
-        emit(&metadata, code).replace("\r\n", "\n")
-    } else {
-        emit(&metadata, code)
-    };
+//# sourceMappingURL=fifo_v1.sv.map
+"#;
 
-    assert_eq!(ret, expect);
-}
+    let expect_v2 = r#"package fifo_v2_PackageA_x;
+    localparam int unsigned WIDTH = 8;
+endpackage
 
-#[test]
-fn expand_inside_operator() {
-    let code = r#"module ModuleA {
-    var a: logic;
-    var b: logic;
+module fifo_v2_Fifo_x #(
+    parameter int unsigned N = fifo_v2_PackageA_x::WIDTH
+) (
+    input  logic [N-1:0] i_d,
+    output logic [N-1:0] o_d
+);
+    always_comb o_d = i_d;
+endmodule
 
-    assign a = inside 1 + 2 / 3 {0, 0..10, 1..=10};
-    assign b = outside 1 * 2 - 1 {0, 0..10, 1..=10};
-    }
+module fifo_v2___Wrap__fifo_v2_PackageA_WIDTH_x;
+    logic [fifo_v2_PackageA_x::WIDTH-1:0] d  ;
+    fifo_v2_Fifo_x #(
+        .N   (fifo_v2_PackageA_x::WIDTH)
+    ) u (
+        .i_d (d                        ),
+        .o_d (                         )
+    );
+endmodule
+//# sourceMappingURL=fifo_v2.sv.map
 "#;
 
-    let expect = r#"module ModuleA;
-    logic a;
-    logic b;
+    let expect = r#"module prj_Top;
+    logic [8-1:0] a;
+    logic [8-1:0] b;
+    logic [8-1:0] c;
 
-    always_comb a = ((1 + 2 / 3) ==? (0) || ((1 + 2 / 3) >= (0)) && ((1 + 2 / 3) < (10)) || ((1 + 2 / 3) >= (1)) && ((1 + 2 / 3) <= (10)));
-    always_comb b = !((1 * 2 - 1) ==? (0) || ((1 * 2 - 1) >= (0)) && ((1 * 2 - 1) < (10)) || ((1 * 2 - 1) >= (1)) && ((1 * 2 - 1) <= (10)));
+    v1_Fifo u0 (
+        .i_d (a),
+        .o_d (b)
+    );
+
+    fifo_v2_Fifo_x u1 (
+        .i_d (b),
+        .o_d (c)
+    );
+
+    fifo_v2___Wrap__fifo_v2_PackageA_WIDTH_x u2 ();
 endmodule
-//# sourceMappingURL=test.sv.map
+//# sourceMappingURL=prj.sv.map
 "#;
 
     let mut metadata: Metadata =
         toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.name_overrides.insert(
+        "fifo_v1".to_string(),
+        NameOverride {
+            prefix: Some("v1_".to_string()),
+            suffix: None,
+        },
+    );
+    metadata.build.name_overrides.insert(
+        "fifo_v2".to_string(),
+        NameOverride {
+            prefix: None,
+            suffix: Some("_x".to_string()),
+        },
+    );
 
-    metadata.build.omit_project_prefix = true;
-    metadata.build.expand_inside_operation = true;
-
-    let ret = if cfg!(windows) {
-        emit(&metadata, code).replace("\r\n", "\n")
-    } else {
-        emit(&metadata, code)
-    };
-
-    assert_eq!(ret, expect);
+    let ret = emit_projects(
+        &metadata,
+        &[("fifo_v1", dep), ("fifo_v2", dep), ("prj", code)],
+    );
+    assert_eq!(ret[0], expect_v1);
+    assert_eq!(ret[1], expect_v2);
+    assert_eq!(ret[2], expect);
 }
 
 #[test]
-fn expand_case_expression() {
+fn reorder_consts() {
     let code = r#"module ModuleA {
-    let a: logic = 1;
-    var b: logic;
+    var a: logic<W>;
+    assign a = 0;
+    function FuncA -> u32 {
+        return V;
+    }
+    const W: u32 = FuncA() + 1;
+    const V: u32 = 7;
+}
 
-    assign b = case a {
-        1      : 0,
-        2      : 1,
-        3, 4   : 2,
-        5..=7  : 3,
-        default: 4,
-    };
+package PackageA {
+    const X: u32 = Y * 2;
+    const Y: u32 = 3;
 }
 "#;
 
-    let expect = r#"module ModuleA;
-    logic a;
-    always_comb a = 1;
-    logic b;
+    let expect = r#"module prj_ModuleA;
+
+    localparam int unsigned V = 7;
+    function automatic int unsigned FuncA;
+        return V;
+    endfunction
+    localparam int unsigned W = FuncA() + 1;
+    logic [W-1:0] a;
+    always_comb a = 0;
 
-    always_comb b = (((a) ==? (1)) ? (
-        0
-    ) : ((a) ==? (2)) ? (
-        1
-    ) : ((a) ==? (3)) ? (
-        2
-    ) : ((a) ==? (4)) ? (
-        2
-    ) : (((a) >= (5)) && ((a) <= (7))) ? (
-        3
-    ) : (
-        4
-    ));
 endmodule
+
+package prj_PackageA;
+
+    localparam int unsigned Y = 3;
+    localparam int unsigned X = Y * 2;
+
+endpackage
 //# sourceMappingURL=test.sv.map
 "#;
 
     let mut metadata: Metadata =
         toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
 
-    metadata.build.omit_project_prefix = true;
-    metadata.build.expand_inside_operation = true;
+    metadata.build.reorder_consts = true;
 
     let ret = if cfg!(windows) {
         emit(&metadata, code).replace("\r\n", "\n")
@@ -379,32 +526,40 @@ endmodule
 }
 
 #[test]
-fn async_reset_cast() {
+fn enum_style_typedef() {
     let code = r#"module ModuleA {
-    var a: reset;
-    var b: reset_async_high;
-    var c: reset_async_low;
-
-    let d: reset_async_high = a as reset_async_high;
-    let e: reset_async_low  = a as reset_async_low ;
-    let f: reset            = b as reset           ;
-    let g: reset            = c as reset           ;
+    enum EnumA {
+        A,
+        B, // comment
+        C = 3,
+    }
+    #[enum_encoding(onehot)]
+    enum EnumB {
+        X,
+        Y,
+    }
+    var a: EnumA;
+    var b: EnumB;
+    assign a = EnumA::B;
+    assign b = EnumB::Y;
 }
 "#;
 
     let expect = r#"module prj_ModuleA;
-    logic a;
-    logic b;
-    logic c;
-
-    logic d;
-    always_comb d = a;
-    logic e;
-    always_comb e = ~a;
-    logic f;
-    always_comb f = b;
-    logic g;
-    always_comb g = ~c;
+    typedef enum logic [2-1:0] {
+        EnumA_A,
+        EnumA_B, // comment
+        EnumA_C = 3
+    } EnumA;
+
+    typedef enum logic [2-1:0] {
+        EnumB_X = 2'd1,
+        EnumB_Y = 2'd2
+    } EnumB;
+    EnumA a /* synthesis keep */;
+    EnumB b /* synthesis keep */;
+    always_comb a = EnumA_B;
+    always_comb b = EnumB_Y;
 endmodule
 //# sourceMappingURL=test.sv.map
 "#;
@@ -412,7 +567,7 @@ endmodule
     let mut metadata: Metadata =
         toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
 
-    metadata.build.reset_type = ResetType::AsyncHigh;
+    metadata.build.enum_keep_pragma = Some("/* synthesis keep */".to_string());
 
     let ret = if cfg!(windows) {
         emit(&metadata, code).replace("\r\n", "\n")
@@ -424,32 +579,38 @@ endmodule
 }
 
 #[test]
-fn sync_reset_cast() {
+fn enum_style_localparam() {
     let code = r#"module ModuleA {
-    var a: reset;
-    var b: reset_sync_high;
-    var c: reset_sync_low;
-
-    let d: reset_sync_high = a as reset_sync_high;
-    let e: reset_sync_low  = a as reset_sync_low ;
-    let f: reset           = b as reset          ;
-    let g: reset           = c as reset          ;
+    enum EnumA {
+        A,
+        B, // comment
+        C = 3,
+    }
+    #[enum_encoding(onehot)]
+    enum EnumB {
+        X,
+        Y,
+    }
+    var a: EnumA;
+    var b: EnumB;
+    assign a = EnumA::B;
+    assign b = EnumB::Y;
 }
 "#;
 
     let expect = r#"module prj_ModuleA;
-    logic a;
-    logic b;
-    logic c;
-
-    logic d;
-    always_comb d = ~a;
-    logic e;
-    always_comb e = a;
-    logic f;
-    always_comb f = ~b;
-    logic g;
-    always_comb g = c;
+    typedef logic [2-1:0] EnumA;
+    localparam EnumA EnumA_A = 2'd0;
+    localparam EnumA EnumA_B = 2'd1; // comment
+    localparam EnumA EnumA_C = 3;
+
+    typedef logic [2-1:0] EnumB;
+    localparam EnumB EnumB_X = 2'd1;
+    localparam EnumB EnumB_Y = 2'd2;
+    EnumA a;
+    EnumB b;
+    always_comb a = EnumA_B;
+    always_comb b = EnumB_Y;
 endmodule
 //# sourceMappingURL=test.sv.map
 "#;
@@ -457,7 +618,8 @@ endmodule
     let mut metadata: Metadata =
         toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
 
-    metadata.build.reset_type = ResetType::SyncLow;
+    metadata.build.enum_keep_pragma = Some("/* synthesis keep */".to_string());
+    metadata.build.enum_style = EnumStyle::Localparam;
 
     let ret = if cfg!(windows) {
         emit(&metadata, code).replace("\r\n", "\n")
@@ -469,15 +631,409 @@ endmodule
 }
 
 #[test]
-fn emit_cond_type() {
+fn line_directives() {
     let code = r#"module ModuleA (
-    i_clk: input clock,
-    i_rst: input reset,
+    a: input  logic,
+    b: output logic,
+    c: output logic,
 ) {
-    let x: logic = 1;
-    var a: logic;
-    var b: logic;
-    var c: logic;
+    function f::<W: const> (
+        x: input logic,
+    ) -> logic {
+        return x;
+    }
+
+    always_comb {
+        b = f::<1>(a);
+        c = f::<2>(a);
+    }
+}
+"#;
+
+    let expect = r#"`line 1 "test.veryl" 0
+module prj_ModuleA (
+    input  logic a,
+    output logic b,
+    output logic c
+);
+    function automatic logic __f__1(
+        input logic x
+    ) ;
+        return x;
+    endfunction
+`line 6 "test.veryl" 0
+    function automatic logic __f__2(
+        input logic x
+    ) ;
+        return x;
+    endfunction
+
+    always_comb begin
+        b = __f__1(a);
+        c = __f__2(a);
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.emit_line_directives = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn preamble() {
+    let code = r#"// header
+module ModuleA {}
+"#;
+
+    let expect = r#"// header
+`timescale 1ns/1ps
+`default_nettype none
+
+module prj_ModuleA;
+endmodule
+
+`default_nettype wire
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.default_nettype = Some("none".to_string());
+    metadata.build.timescale = Some("1ns/1ps".to_string());
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn no_preamble() {
+    let code = r#"#[no_preamble]
+module ModuleA {}
+module ModuleB {}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+endmodule
+module prj_ModuleB;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.default_nettype = Some("none".to_string());
+    metadata.build.timescale = Some("1ns/1ps".to_string());
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn widthless_zero() {
+    let code = r#"module ModuleA {
+    const a: u32 = 'd0;
+    const b: u32 = 'h0_0;
+}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+    localparam int unsigned a = 1'd0;
+    localparam int unsigned b = 1'h0_0;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn expand_case_statement() {
+    let code = r#"module ModuleA {
+    const y: bit = 1;
+
+    var a: logic;
+    let x: logic = 1;
+
+    always_comb {
+        case x {
+            0: a = 1;
+            1: a = 1;
+            2: {
+                   a = 1;
+                   a = 1;
+                   a = 1;
+               }
+            3, 4   : a = 1;
+            5..=7  : a = 1;
+            y - 1  : a = 1;
+            default: a = 1;
+        }
+    }
+}
+"#;
+
+    let expect = r#"module ModuleA;
+    localparam bit y = 1;
+
+    logic a;
+    logic x;
+    always_comb x = 1;
+
+    always_comb begin
+        case (1'b1)
+            (x) ==? (0): a = 1;
+            (x) ==? (1): a = 1;
+            (x) ==? (2): begin
+                             a = 1;
+                             a = 1;
+                             a = 1;
+                         end
+            (x) ==? (3), (x) ==? (4    ): a = 1;
+            ((x) >= (5)) && ((x) <= (7)): a = 1;
+            (x) ==? (y - 1             ): a = 1;
+            default                     : a = 1;
+        endcase
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.omit_project_prefix = true;
+    metadata.build.expand_inside_operation = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn expand_inside_operator() {
+    let code = r#"module ModuleA {
+    var a: logic;
+    var b: logic;
+
+    assign a = inside 1 + 2 / 3 {0, 0..10, 1..=10};
+    assign b = outside 1 * 2 - 1 {0, 0..10, 1..=10};
+    }
+"#;
+
+    let expect = r#"module ModuleA;
+    logic a;
+    logic b;
+
+    always_comb a = ((1 + 2 / 3) ==? (0) || ((1 + 2 / 3) >= (0)) && ((1 + 2 / 3) < (10)) || ((1 + 2 / 3) >= (1)) && ((1 + 2 / 3) <= (10)));
+    always_comb b = !((1 * 2 - 1) ==? (0) || ((1 * 2 - 1) >= (0)) && ((1 * 2 - 1) < (10)) || ((1 * 2 - 1) >= (1)) && ((1 * 2 - 1) <= (10)));
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.omit_project_prefix = true;
+    metadata.build.expand_inside_operation = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn expand_case_expression() {
+    let code = r#"module ModuleA {
+    let a: logic = 1;
+    var b: logic;
+
+    assign b = case a {
+        1      : 0,
+        2      : 1,
+        3, 4   : 2,
+        5..=7  : 3,
+        default: 4,
+    };
+}
+"#;
+
+    let expect = r#"module ModuleA;
+    logic a;
+    always_comb a = 1;
+    logic b;
+
+    always_comb b = (((a) ==? (1)) ? (
+        0
+    ) : ((a) ==? (2)) ? (
+        1
+    ) : ((a) ==? (3)) ? (
+        2
+    ) : ((a) ==? (4)) ? (
+        2
+    ) : (((a) >= (5)) && ((a) <= (7))) ? (
+        3
+    ) : (
+        4
+    ));
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.omit_project_prefix = true;
+    metadata.build.expand_inside_operation = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn async_reset_cast() {
+    let code = r#"module ModuleA {
+    var a: reset;
+    var b: reset_async_high;
+    var c: reset_async_low;
+
+    let d: reset_async_high = a as reset_async_high;
+    let e: reset_async_low  = a as reset_async_low ;
+    let f: reset            = b as reset           ;
+    let g: reset            = c as reset           ;
+}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+    logic a;
+    logic b;
+    logic c;
+
+    logic d;
+    always_comb d = a;
+    logic e;
+    always_comb e = ~a;
+    logic f;
+    always_comb f = b;
+    logic g;
+    always_comb g = ~c;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.reset_type = ResetType::AsyncHigh;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn sync_reset_cast() {
+    let code = r#"module ModuleA {
+    var a: reset;
+    var b: reset_sync_high;
+    var c: reset_sync_low;
+
+    let d: reset_sync_high = a as reset_sync_high;
+    let e: reset_sync_low  = a as reset_sync_low ;
+    let f: reset           = b as reset          ;
+    let g: reset           = c as reset          ;
+}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+    logic a;
+    logic b;
+    logic c;
+
+    logic d;
+    always_comb d = ~a;
+    logic e;
+    always_comb e = a;
+    logic f;
+    always_comb f = ~b;
+    logic g;
+    always_comb g = c;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.reset_type = ResetType::SyncLow;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn emit_cond_type() {
+    let code = r#"module ModuleA (
+    i_clk: input clock,
+    i_rst: input reset,
+) {
+    let x: logic = 1;
+    var a: logic;
+    var b: logic;
+    var c: logic;
     var d: logic;
     var e: logic;
     var f: logic;
@@ -553,91 +1109,717 @@ fn emit_cond_type() {
 
     let expect = r#"module prj_ModuleA (
     input logic i_clk,
-    input logic i_rst
+    input logic i_rst
+);
+    logic x;
+    always_comb x = 1;
+    logic a;
+    logic b;
+    logic c;
+    logic d;
+    logic e;
+    logic f;
+    logic g;
+    logic h;
+    logic i;
+
+    always_comb begin
+
+        unique case (x) inside
+            0: a = 1;
+            1: a = 1;
+        endcase
+
+        unique0 case (x) inside
+            0: b = 1;
+            1: b = 1;
+        endcase
+
+        priority case (x) inside
+            0: c = 1;
+            1: c = 1;
+        endcase
+    end
+
+    always_comb begin
+
+        unique if (x == 0) begin
+            d = 1;
+        end else if (x == 1) begin
+            d = 1;
+        end
+
+        unique0 if (x == 0) begin
+            e = 1;
+        end else if (x == 1) begin
+            e = 1;
+        end
+
+        priority if (x == 0) begin
+            f = 1;
+        end else if (x == 1) begin
+            f = 1;
+        end
+    end
+
+    always_ff @ (posedge i_clk, negedge i_rst) begin
+
+        unique if (!i_rst) begin
+            g <= 1;
+        end else if (x == 1) begin
+            g <= 1;
+        end
+    end
+    always_ff @ (posedge i_clk, negedge i_rst) begin
+
+        unique0 if (!i_rst) begin
+            h <= 1;
+        end else if (x == 1) begin
+            h <= 1;
+        end
+    end
+    always_ff @ (posedge i_clk, negedge i_rst) begin
+
+        priority if (!i_rst) begin
+            i <= 1;
+        end else if (x == 1) begin
+            i <= 1;
+        end
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.emit_cond_type = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn emit_generate_keyword() {
+    let code = r#"module ModuleA #(
+    param N: u32 = 2,
+) {
+    var a: logic<N>;
+
+    if N >: 1 :g_if {
+        for i in 0..N :g_for {
+            assign a[i] = 0;
+        }
+    } else :g_else {
+        assign a = 0;
+    }
+}
+"#;
+
+    let expect = r#"module prj_ModuleA #(
+    parameter int unsigned N = 2
+);
+    logic [N-1:0] a;
+
+    generate
+        if (N > 1) begin :g_if
+            for (genvar i = 0; i < N; i++) begin :g_for
+                always_comb a[i] = 0;
+            end
+        end else begin :g_else
+            always_comb a = 0;
+        end
+    endgenerate
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    metadata.build.emit_generate_keyword = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn doc_comment() {
+    let code = r#"/// Module doc
+module ModuleA (
+    /// Clock
+    i_clk: input clock,
+    i_a  : input logic<2>, /// Input A
+    /// Debug
+    #[ifdef(DEBUG)]
+    o_dbg: output logic,
+    /// Output B
+    o_b: output logic,
+) {
+    assign o_b   = 0;
+    assign o_dbg = 0;
+}
+"#;
+
+    let expect = r#"// Module doc
+module prj_ModuleA (
+    // Clock
+    input logic         i_clk,
+    // Input A
+    input logic [2-1:0] i_a  ,
+    // Debug
+    `ifdef DEBUG
+    output logic o_dbg
+    ,`endif
+    // Output B
+    output logic o_b
+);
+    always_comb o_b   = 0;
+    always_comb o_dbg = 0;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn assertion() {
+    let code = r#"module ModuleA (
+    i_clk: input clock,
+    i_rst: input reset,
+    i_a  : input logic,
+) {
+    var a: logic;
+    always_comb {
+        a = i_a;
+        assert (a == i_a) else "mismatch";
+    }
+
+    assert property (a) else "a is not set";
+    cover property (a);
+}
+"#;
+
+    let expect = r#"module prj_ModuleA (
+    input logic i_clk,
+    input logic i_rst,
+    input logic i_a  
 );
-    logic x;
-    always_comb x = 1;
     logic a;
-    logic b;
-    logic c;
-    logic d;
-    logic e;
-    logic f;
-    logic g;
-    logic h;
-    logic i;
-
     always_comb begin
+        a = i_a;
+        assert (a == i_a) else $error("mismatch");
+    end
 
-        unique case (x) inside
-            0: a = 1;
-            1: a = 1;
-        endcase
+    assert property (@(posedge i_clk) disable iff (!i_rst) a) else $error("a is not set");
+    cover property (@(posedge i_clk) disable iff (!i_rst) a);
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
 
-        unique0 case (x) inside
-            0: b = 1;
-            1: b = 1;
-        endcase
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
 
-        priority case (x) inside
-            0: c = 1;
-            1: c = 1;
-        endcase
-    end
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
 
+    assert_eq!(ret, expect);
+
+    let expect = r#"module prj_ModuleA (
+    input logic i_clk,
+    input logic i_rst,
+    input logic i_a  
+);
+    logic a;
     always_comb begin
+        a = i_a;
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
 
-        unique if (x == 0) begin
-            d = 1;
-        end else if (x == 1) begin
-            d = 1;
-        end
+    metadata.build.strip_assertions = true;
 
-        unique0 if (x == 0) begin
-            e = 1;
-        end else if (x == 1) begin
-            e = 1;
-        end
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
 
-        priority if (x == 0) begin
-            f = 1;
-        end else if (x == 1) begin
-            f = 1;
-        end
-    end
+    assert_eq!(ret, expect);
+}
 
-    always_ff @ (posedge i_clk, negedge i_rst) begin
+#[test]
+fn expand_inout() {
+    let code = r#"#[pad]
+module ModuleA (
+    pad_a: inout tri logic<2>,
+    pad_b: inout tri logic   ,
+    en   : input logic       ,
+) {
+    inst u: ModuleB (
+        a: pad_a,
+        pad_b,
+        en,
+    );
+}
 
-        unique if (!i_rst) begin
-            g <= 1;
-        end else if (x == 1) begin
-            g <= 1;
-        end
-    end
-    always_ff @ (posedge i_clk, negedge i_rst) begin
+module ModuleB (
+    en   : input logic       ,
+    a    : inout tri logic<2>,
+    pad_b: inout tri logic   ,
+) {
+    assign a = if en { 2'b01 } else { 'z };
 
-        unique0 if (!i_rst) begin
-            h <= 1;
-        end else if (x == 1) begin
-            h <= 1;
-        end
-    end
-    always_ff @ (posedge i_clk, negedge i_rst) begin
+    let _x: logic<2> = a;
 
-        priority if (!i_rst) begin
-            i <= 1;
-        end else if (x == 1) begin
-            i <= 1;
+    inst u: ModuleC (
+        c: pad_b,
+    );
+}
+
+module ModuleC (
+    c: inout tri logic,
+) {
+    assign c = if c { 1 } else { 'z };
+}
+"#;
+
+    let expect = r#"module prj_ModuleA (
+    inout tri logic [2-1:0] pad_a,
+    inout tri logic         pad_b,
+    input logic             en   
+);
+    var type(pad_a) __u_a_o;
+    logic __u_a_oe;
+    assign pad_a = __u_a_oe ? __u_a_o : 'z;
+    var type(pad_b) __u_pad_b_o;
+    logic __u_pad_b_oe;
+    assign pad_b = __u_pad_b_oe ? __u_pad_b_o : 'z;
+    prj_ModuleB u (
+        .a_i      (pad_a       ),
+        .a_o      (__u_a_o     ),
+        .a_oe     (__u_a_oe    ),
+        .pad_b_i  (pad_b       ),
+        .pad_b_o  (__u_pad_b_o ),
+        .pad_b_oe (__u_pad_b_oe),
+        .en       (en          )
+    );
+endmodule
+
+module prj_ModuleB (
+    input  logic             en      ,
+    input  tri logic [2-1:0] a_i     ,
+    output tri logic [2-1:0] a_o     ,
+    output logic             a_oe    ,
+    input  tri logic         pad_b_i ,
+    output tri logic         pad_b_o ,
+    output logic             pad_b_oe
+);
+    always_comb a_oe = en;
+    always_comb a_o  = 2'b01;
+
+    logic [2-1:0] _x;
+    always_comb _x = a_i;
+
+    prj_ModuleC u (
+        .c_i  (pad_b_i ),
+        .c_o  (pad_b_o ),
+        .c_oe (pad_b_oe)
+    );
+endmodule
+
+module prj_ModuleC (
+    input  tri logic c_i ,
+    output tri logic c_o ,
+    output logic     c_oe
+);
+    always_comb c_oe = c_i;
+    always_comb c_o  = 1;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.expand_inout = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn flatten_struct_ports() {
+    let code = r#"package PackageA {
+    enum EnumA: logic<2> {
+        A,
+        B,
+    }
+
+    struct StructA {
+        a: logic<8>,
+        b: EnumA   ,
+    }
+
+    struct StructB {
+        c: StructA<2>,
+        d: bit       ,
+    }
+}
+
+module ModuleA (
+    i_a: input  PackageA::StructA    ,
+    i_b: input  PackageA::StructB [2],
+    i_c: input  logic                ,
+    o_a: output PackageA::StructA    ,
+) {
+    always_comb {
+        o_a = i_a;
+        if i_c {
+            o_a = i_b[0].c[1];
+        }
+    }
+}
+
+module ModuleB (
+    i_a: input  PackageA::StructA,
+    o_a: output PackageA::StructA,
+) {
+    var b: PackageA::StructB [2];
+
+    assign b = '{default: 0};
+
+    inst u: ModuleA (
+        i_a    ,
+        i_b: b ,
+        i_c: 1 ,
+        o_a    ,
+    );
+}
+"#;
+
+    let expect = r#"package prj_PackageA;
+    typedef enum logic [2-1:0] {
+        EnumA_A,
+        EnumA_B
+    } EnumA;
+
+    typedef struct packed {
+        logic [8-1:0] a;
+        EnumA         b;
+    } StructA;
+
+    typedef struct packed {
+        StructA [2-1:0] c;
+        bit             d;
+    } StructB;
+endpackage
+
+module prj_ModuleA (
+    input  logic [8-1:0] i_a_a      ,
+    input  logic [2-1:0] i_a_b      ,
+    input  logic [8-1:0] i_b_0_c_1_a,
+    input  logic [2-1:0] i_b_0_c_1_b,
+    input  logic [8-1:0] i_b_0_c_0_a,
+    input  logic [2-1:0] i_b_0_c_0_b,
+    input  bit           i_b_0_d    ,
+    input  logic [8-1:0] i_b_1_c_1_a,
+    input  logic [2-1:0] i_b_1_c_1_b,
+    input  logic [8-1:0] i_b_1_c_0_a,
+    input  logic [2-1:0] i_b_1_c_0_b,
+    input  bit           i_b_1_d    ,
+    input  logic         i_c        ,
+    output logic [8-1:0] o_a_a      ,
+    output logic [2-1:0] o_a_b  
+);
+    prj_PackageA::StructA i_a;
+    assign i_a = {i_a_a, i_a_b};
+    prj_PackageA::StructB i_b [0:2-1];
+    assign i_b[0] = {i_b_0_c_1_a, i_b_0_c_1_b, i_b_0_c_0_a, i_b_0_c_0_b, i_b_0_d};
+    assign i_b[1] = {i_b_1_c_1_a, i_b_1_c_1_b, i_b_1_c_0_a, i_b_1_c_0_b, i_b_1_d};
+    prj_PackageA::StructA o_a;
+    assign {o_a_a, o_a_b} = o_a;
+    always_comb begin
+        o_a = i_a;
+        if (i_c) begin
+            o_a = i_b[0].c[1];
         end
     end
 endmodule
+
+module prj_ModuleB (
+    input  logic [8-1:0] i_a_a,
+    input  logic [2-1:0] i_a_b,
+    output logic [8-1:0] o_a_a,
+    output logic [2-1:0] o_a_b
+);
+    prj_PackageA::StructA i_a;
+    assign i_a = {i_a_a, i_a_b};
+    prj_PackageA::StructA o_a;
+    assign {o_a_a, o_a_b} = o_a;
+    prj_PackageA::StructB b [0:2-1];
+
+    always_comb b = '{default: 0};
+
+    prj_ModuleA u (
+        .i_a_a       (i_a.a      ),
+        .i_a_b       (i_a.b      ),
+        .i_b_0_c_1_a (b[0].c[1].a),
+        .i_b_0_c_1_b (b[0].c[1].b),
+        .i_b_0_c_0_a (b[0].c[0].a),
+        .i_b_0_c_0_b (b[0].c[0].b),
+        .i_b_0_d     (b[0].d     ),
+        .i_b_1_c_1_a (b[1].c[1].a),
+        .i_b_1_c_1_b (b[1].c[1].b),
+        .i_b_1_c_0_a (b[1].c[0].a),
+        .i_b_1_c_0_b (b[1].c[0].b),
+        .i_b_1_d     (b[1].d     ),
+        .i_c         (1          ),
+        .o_a_a       (o_a.a      ),
+        .o_a_b       (o_a.b      )
+    );
+endmodule
 //# sourceMappingURL=test.sv.map
 "#;
 
     let mut metadata: Metadata =
         toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.flatten_struct_ports = true;
 
-    metadata.build.emit_cond_type = true;
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn init_file() {
+    let code = r#"module ModuleA {
+    #[init_file("rom.hex")]
+    var a: logic<8> [16];
+
+    assign a = '{default: 0};
+}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+
+    logic [8-1:0] a [0:16-1];
+    initial $readmemh("rom.hex", a);
+
+    always_comb a = '{default: 0};
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.copy_init_files = true;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn dpi() {
+    let code = r#"module ModuleA {
+    #[dpi_import]
+    function FuncA (
+        a: input  u32,
+        b: output u32,
+    ) -> u32 {}
+
+    #[dpi_export]
+    function FuncB (
+        a: input u32,
+    ) -> u32 {
+        return a + 1;
+    }
+}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+
+    import "DPI-C" context function int unsigned FuncA(
+        input  int unsigned a,
+        output int unsigned b
+    ) ;
+
+    function automatic int unsigned FuncB(
+        input int unsigned a
+    ) ;
+        return a + 1;
+    endfunction
+    export "DPI-C" function FuncB;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn cast_lowering() {
+    let code = r#"module ModuleA {
+    enum EnumA: logic<2> {
+        A,
+        B,
+    }
+    enum EnumB: logic<4> {
+        C,
+        D,
+    }
+    struct StructA {
+        a: logic<4>,
+        b: logic<4>,
+    }
+
+    var u: logic<8>       ;
+    var s: signed logic<8>;
+    var e: EnumB          ;
+    var t: StructA        ;
+
+    // unsigned source
+    let _a0: u32      = u as u32;
+    let _a1: logic    = u as logic;
+    let _a2: i32      = u as i32;
+    let _a3: logic<4> = u as 4;
+    let _a4: logic<8> = u as 8;
+    let _a5: EnumA    = u as EnumA;
+    let _a6: StructA  = u as StructA;
+
+    // signed source
+    let _b0: u32      = s as u32;
+    let _b1: i32      = s as i32;
+    let _b2: logic<4> = s as 4;
+    let _b3: EnumA    = s as EnumA;
+
+    // enum source
+    let _c0: logic<2> = e as 2;
+    let _c1: i32      = e as i32;
+    let _c2: EnumA    = e as EnumA;
+    let _c3: StructA  = e as StructA;
+
+    // struct source
+    let _d0: logic<8> = t as 8;
+    let _d1: EnumB    = t as EnumB;
+    let _d2: EnumA    = (t as EnumB) as EnumA;
+}
+"#;
+
+    let expect = r#"module prj_ModuleA;
+    typedef enum logic [2-1:0] {
+        EnumA_A,
+        EnumA_B
+    } EnumA;
+    typedef enum logic [4-1:0] {
+        EnumB_C,
+        EnumB_D
+    } EnumB;
+    typedef struct packed {
+        logic [4-1:0] a;
+        logic [4-1:0] b;
+    } StructA;
+
+    logic        [8-1:0] u;
+    logic signed [8-1:0] s;
+    EnumB                e;
+    StructA              t;
+
+    // unsigned source
+    int unsigned         _a0;
+    always_comb _a0 = unsigned'(int'(u));
+    logic                _a1;
+    always_comb _a1 = logic'(u) /* truncated from 8-bit to 1-bit */;
+    int signed           _a2;
+    always_comb _a2 = $signed(int'(u));
+    logic        [4-1:0] _a3;
+    always_comb _a3 = 4'(u) /* truncated from 8-bit to 4-bit */;
+    logic        [8-1:0] _a4;
+    always_comb _a4 = 8'(u);
+    EnumA                _a5;
+    always_comb _a5 = EnumA'(u) /* truncated from 8-bit to 2-bit */;
+    StructA              _a6;
+    always_comb _a6 = StructA'(u);
+
+    // signed source
+    int unsigned         _b0;
+    always_comb _b0 = $unsigned(int'(s));
+    int signed           _b1;
+    always_comb _b1 = int'(s);
+    logic        [4-1:0] _b2;
+    always_comb _b2 = 4'(s) /* truncated from 8-bit to 4-bit */;
+    EnumA                _b3;
+    always_comb _b3 = EnumA'(s) /* truncated from 8-bit to 2-bit */;
+
+    // enum source
+    logic      [2-1:0] _c0;
+    always_comb _c0 = 2'(e) /* truncated from 4-bit to 2-bit */;
+    int signed         _c1;
+    always_comb _c1 = $signed(int'(e));
+    EnumA              _c2;
+    always_comb _c2 = EnumA'(e) /* truncated from 4-bit to 2-bit */;
+    StructA            _c3;
+    always_comb _c3 = StructA'(e);
+
+    // struct source
+    logic [8-1:0] _d0;
+    always_comb _d0 = 8'(t);
+    EnumB         _d1;
+    always_comb _d1 = EnumB'(t) /* truncated from 8-bit to 4-bit */;
+    EnumA         _d2;
+    always_comb _d2 = EnumA'((EnumB'(t) /* truncated from 8-bit to 4-bit */)) /* truncated from 4-bit to 2-bit */;
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
 
     let ret = if cfg!(windows) {
         emit(&metadata, code).replace("\r\n", "\n")
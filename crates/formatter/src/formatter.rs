@@ -1,8 +1,9 @@
+use crate::sorter::Sorter;
 use veryl_aligner::{align_kind, Aligner, Location};
-use veryl_metadata::{Format, Metadata};
+use veryl_metadata::{BraceStyle, ContinuationStyle, ElseStyle, Format, InstPortStyle, Metadata};
 use veryl_parser::resource_table;
 use veryl_parser::veryl_grammar_trait::*;
-use veryl_parser::veryl_token::{Token, VerylToken};
+use veryl_parser::veryl_token::{Token, TokenRange, VerylToken};
 use veryl_parser::veryl_walker::VerylWalker;
 
 #[cfg(target_os = "windows")]
@@ -30,6 +31,9 @@ pub struct Formatter {
     case_item_indent: Vec<usize>,
     in_scalar_type: bool,
     in_expression: Vec<()>,
+    in_wrap_measure: bool,
+    wrap_indented: bool,
+    lhs_unaligned: bool,
 }
 
 impl Default for Formatter {
@@ -48,19 +52,44 @@ impl Default for Formatter {
             case_item_indent: Vec::new(),
             in_scalar_type: false,
             in_expression: Vec::new(),
+            in_wrap_measure: false,
+            wrap_indented: false,
+            lhs_unaligned: false,
         }
     }
 }
 
 impl Formatter {
     pub fn new(metadata: &Metadata) -> Self {
+        Self::with_format(&metadata.format)
+    }
+
+    /// Creates a formatter from format options without the whole metadata
+    pub fn with_format(format: &Format) -> Self {
+        let mut aligner = Aligner::new();
+        aligner.skip_comment_line = !format.comment_breaks_align_group;
         Self {
-            format_opt: metadata.format.clone(),
+            format_opt: format.clone(),
+            aligner,
             ..Default::default()
         }
     }
 
     pub fn format(&mut self, input: &Veryl) {
+        if self.format_opt.sort_ports
+            || self.format_opt.sort_inst_ports
+            || self.format_opt.inst_port_style != InstPortStyle::Keep
+        {
+            let mut sorter = Sorter::new(&self.format_opt);
+            if let Some(x) = sorter.sort(input) {
+                self.format_veryl(&x);
+                return;
+            }
+        }
+        self.format_veryl(input);
+    }
+
+    fn format_veryl(&mut self, input: &Veryl) {
         self.mode = Mode::Align;
         self.veryl(input);
         self.aligner.finish_group();
@@ -182,10 +211,210 @@ impl Formatter {
         }
     }
 
+    /// Emits the separator between `}` and `else` according to `else_style`
+    fn else_separator(&mut self) {
+        match self.format_opt.else_style {
+            ElseStyle::Cuddled => self.space(1),
+            ElseStyle::Newline => self.break_line(),
+        }
+    }
+
+    /// Emits the separator before the opening brace of declaration bodies according to `brace_style`
+    fn brace_separator(&mut self) {
+        match self.format_opt.brace_style {
+            BraceStyle::SameLine => self.space(1),
+            BraceStyle::NextLine => self.break_line(),
+        }
+    }
+
+    fn break_line(&mut self) {
+        // alignment groups should not span the token moved to its own line
+        self.align_reset();
+        self.newline();
+        // blank lines before the moved token are not kept
+        self.adjust_line = false;
+    }
+
     fn space(&mut self, repeat: usize) {
         self.str(&" ".repeat(repeat));
     }
 
+    /// Returns the width of `f` output if it is emitted in a single line
+    fn measure<F: FnOnce(&mut Self)>(&mut self, f: F) -> Option<usize> {
+        let len = self.string.len();
+        let line = self.line;
+        let adjust_line = self.adjust_line;
+        let consumed_next_newline = self.consumed_next_newline;
+
+        self.in_wrap_measure = true;
+        f(self);
+        self.in_wrap_measure = false;
+
+        let text = self.string.split_off(len);
+        self.line = line;
+        self.adjust_line = adjust_line;
+        self.consumed_next_newline = consumed_next_newline;
+
+        if text.contains('\n') {
+            None
+        } else {
+            Some(text.len())
+        }
+    }
+
+    fn wrap_required<F: FnOnce(&mut Self)>(&mut self, f: F) -> bool {
+        if self.mode == Mode::Align || self.format_opt.max_width == 0 || self.in_wrap_measure {
+            return false;
+        }
+
+        let column = self.string.len() - self.string.rfind('\n').map(|x| x + 1).unwrap_or(0);
+        match self.measure(f) {
+            Some(width) => column + width > self.format_opt.max_width,
+            // expression including newline (e.g. comments) is kept as is
+            None => false,
+        }
+    }
+
+    fn binary_operator<F: FnOnce(&mut Self)>(&mut self, wrap: bool, f: F) {
+        if !wrap {
+            self.space(1);
+            f(self);
+            self.space(1);
+            return;
+        }
+
+        // continuation lines are indented one level deeper than the statement
+        if !self.wrap_indented {
+            self.indent += 1;
+            self.wrap_indented = true;
+        }
+        if self.format_opt.break_before_operator {
+            self.newline();
+            f(self);
+            self.space(1);
+        } else {
+            self.space(1);
+            f(self);
+            self.newline();
+        }
+    }
+
+    /// Breaks line and starts the next line at `column`
+    fn newline_at(&mut self, column: usize) {
+        if self.mode == Mode::Align {
+            return;
+        }
+
+        self.unindent();
+        if !self.consumed_next_newline {
+            self.str(NEWLINE);
+        } else {
+            self.consumed_next_newline = false;
+        }
+        self.space(column);
+        self.adjust_line = true;
+    }
+
+    /// Emits items of concatenation or array literal.
+    /// Line breaks between items in the source are kept,
+    /// and commas are aligned if each line holds a single item.
+    /// Returns the column where broken lines start.
+    fn brace_list<T>(
+        &mut self,
+        items: &[(&T, Token)],
+        commas: &[&Comma],
+        f: fn(&mut Self, &T),
+    ) -> usize {
+        let breaks: Vec<_> = items
+            .iter()
+            .skip(1)
+            .zip(commas)
+            .map(|((_, beg), comma)| beg.line > comma.comma_token.token.line)
+            .collect();
+
+        let column = match self.format_opt.continuation_style {
+            ContinuationStyle::Brace => {
+                self.string.len() - self.string.rfind('\n').map(|x| x + 1).unwrap_or(0)
+            }
+            ContinuationStyle::Indent => {
+                (self.indent + 1) * self.format_opt.indent_width
+                    + self.case_item_indent.last().unwrap_or(&0)
+            }
+        };
+
+        let single_per_line = !breaks.is_empty() && breaks.iter().all(|x| *x);
+        let widths: Vec<_> = if single_per_line && self.mode == Mode::Emit {
+            items
+                .iter()
+                .map(|(x, _)| self.measure(|s| f(s, x)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let max_width = if widths.iter().all(|x| x.is_some()) {
+            widths.iter().flatten().max().copied()
+        } else {
+            None
+        };
+
+        for (i, (item, _)) in items.iter().enumerate() {
+            if i > 0 {
+                if breaks[i - 1] {
+                    self.newline_at(column);
+                } else {
+                    self.space(1);
+                }
+            }
+            f(self, item);
+            if let Some(comma) = commas.get(i) {
+                if let (Some(max_width), Some(Some(width))) = (max_width, widths.get(i)) {
+                    self.space(max_width - width);
+                }
+                self.comma(comma);
+            }
+        }
+        column
+    }
+
+    /// Emits attributes before an item.
+    /// Attributes placed in a single line are kept in the line if it fits in `max_width`,
+    /// otherwise each attribute is placed in its own line.
+    fn attributes<T>(&mut self, list: &[T], f: fn(&T) -> &Attribute) {
+        let attrs: Vec<_> = list.iter().map(f).collect();
+        let same_line = attrs
+            .windows(2)
+            .all(|x| x[0].r_bracket.r_bracket_token.token.line == x[1].hash.hash_token.token.line);
+        let single_line = if same_line && attrs.len() > 1 && self.mode == Mode::Emit {
+            // blank lines before attributes should be inserted before measurement
+            self.consume_adjust_line(&attrs[0].hash.hash_token.token);
+            let column = self.string.len() - self.string.rfind('\n').map(|x| x + 1).unwrap_or(0);
+            let width = self.measure(|s| {
+                for (i, x) in attrs.iter().enumerate() {
+                    if i > 0 {
+                        s.space(1);
+                    }
+                    s.attribute(x);
+                }
+            });
+            // attributes including comments are not joined
+            width.is_some_and(|x| {
+                self.format_opt.max_width == 0 || column + x <= self.format_opt.max_width
+            })
+        } else {
+            same_line
+        };
+
+        for (i, x) in attrs.iter().enumerate() {
+            if i > 0 && single_line {
+                self.space(1);
+            }
+            self.attribute(x);
+            if !single_line || i + 1 == attrs.len() {
+                self.newline();
+            }
+        }
+    }
+
     fn consume_adjust_line(&mut self, x: &Token) {
         if self.adjust_line && x.line > self.line + 1 {
             self.newline();
@@ -270,6 +499,21 @@ impl Formatter {
         }
     }
 
+    /// Finishes the left-hand side of an assignment.
+    /// Too wide one is excluded from the group to avoid pushing others far right.
+    fn align_finish_lhs(&mut self) {
+        if self.mode == Mode::Align {
+            let max = self.format_opt.assignment_align_max_lhs;
+            let align = &mut self.aligner.aligns[align_kind::IDENTIFIER];
+            self.lhs_unaligned = max != 0 && align.width() as usize > max;
+            if self.lhs_unaligned {
+                align.discard_item();
+            } else {
+                align.finish_item();
+            }
+        }
+    }
+
     fn align_last_location(&mut self, kind: usize) -> Option<Location> {
         self.aligner.aligns[kind].last_location
     }
@@ -315,25 +559,28 @@ impl VerylWalker for Formatter {
     // https://github.com/rust-lang/rust/issues/106211
     #[inline(never)]
     fn expression(&mut self, arg: &Expression) {
+        let is_top = self.in_expression.is_empty();
         self.in_expression.push(());
+        let wrap = !arg.expression_list.is_empty() && self.wrap_required(|s| s.expression(arg));
         self.expression01(&arg.expression01);
         for x in &arg.expression_list {
-            self.space(1);
-            self.operator01(&x.operator01);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator01(&x.operator01));
             self.expression01(&x.expression01);
         }
         self.in_expression.pop();
+        if is_top && self.wrap_indented {
+            self.indent -= 1;
+            self.wrap_indented = false;
+        }
     }
 
     /// Semantic action for non-terminal 'Expression01'
     #[inline(never)]
     fn expression01(&mut self, arg: &Expression01) {
+        let wrap = !arg.expression01_list.is_empty() && self.wrap_required(|s| s.expression01(arg));
         self.expression02(&arg.expression02);
         for x in &arg.expression01_list {
-            self.space(1);
-            self.operator02(&x.operator02);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator02(&x.operator02));
             self.expression02(&x.expression02);
         }
     }
@@ -341,11 +588,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression02'
     #[inline(never)]
     fn expression02(&mut self, arg: &Expression02) {
+        let wrap = !arg.expression02_list.is_empty() && self.wrap_required(|s| s.expression02(arg));
         self.expression03(&arg.expression03);
         for x in &arg.expression02_list {
-            self.space(1);
-            self.operator03(&x.operator03);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator03(&x.operator03));
             self.expression03(&x.expression03);
         }
     }
@@ -353,11 +599,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression03'
     #[inline(never)]
     fn expression03(&mut self, arg: &Expression03) {
+        let wrap = !arg.expression03_list.is_empty() && self.wrap_required(|s| s.expression03(arg));
         self.expression04(&arg.expression04);
         for x in &arg.expression03_list {
-            self.space(1);
-            self.operator04(&x.operator04);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator04(&x.operator04));
             self.expression04(&x.expression04);
         }
     }
@@ -365,11 +610,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression04'
     #[inline(never)]
     fn expression04(&mut self, arg: &Expression04) {
+        let wrap = !arg.expression04_list.is_empty() && self.wrap_required(|s| s.expression04(arg));
         self.expression05(&arg.expression05);
         for x in &arg.expression04_list {
-            self.space(1);
-            self.operator05(&x.operator05);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator05(&x.operator05));
             self.expression05(&x.expression05);
         }
     }
@@ -377,11 +621,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression05'
     #[inline(never)]
     fn expression05(&mut self, arg: &Expression05) {
+        let wrap = !arg.expression05_list.is_empty() && self.wrap_required(|s| s.expression05(arg));
         self.expression06(&arg.expression06);
         for x in &arg.expression05_list {
-            self.space(1);
-            self.operator06(&x.operator06);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator06(&x.operator06));
             self.expression06(&x.expression06);
         }
     }
@@ -389,11 +632,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression06'
     #[inline(never)]
     fn expression06(&mut self, arg: &Expression06) {
+        let wrap = !arg.expression06_list.is_empty() && self.wrap_required(|s| s.expression06(arg));
         self.expression07(&arg.expression07);
         for x in &arg.expression06_list {
-            self.space(1);
-            self.operator07(&x.operator07);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator07(&x.operator07));
             self.expression07(&x.expression07);
         }
     }
@@ -401,11 +643,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression07'
     #[inline(never)]
     fn expression07(&mut self, arg: &Expression07) {
+        let wrap = !arg.expression07_list.is_empty() && self.wrap_required(|s| s.expression07(arg));
         self.expression08(&arg.expression08);
         for x in &arg.expression07_list {
-            self.space(1);
-            self.operator08(&x.operator08);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator08(&x.operator08));
             self.expression08(&x.expression08);
         }
     }
@@ -413,11 +654,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression08'
     #[inline(never)]
     fn expression08(&mut self, arg: &Expression08) {
+        let wrap = !arg.expression08_list.is_empty() && self.wrap_required(|s| s.expression08(arg));
         self.expression09(&arg.expression09);
         for x in &arg.expression08_list {
-            self.space(1);
-            self.operator09(&x.operator09);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator09(&x.operator09));
             self.expression09(&x.expression09);
         }
     }
@@ -425,14 +665,13 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression09'
     #[inline(never)]
     fn expression09(&mut self, arg: &Expression09) {
+        let wrap = !arg.expression09_list.is_empty() && self.wrap_required(|s| s.expression09(arg));
         self.expression10(&arg.expression10);
         for x in &arg.expression09_list {
-            self.space(1);
-            match &*x.expression09_list_group {
-                Expression09ListGroup::Operator10(x) => self.operator10(&x.operator10),
-                Expression09ListGroup::Star(x) => self.star(&x.star),
-            }
-            self.space(1);
+            self.binary_operator(wrap, |s| match &*x.expression09_list_group {
+                Expression09ListGroup::Operator10(x) => s.operator10(&x.operator10),
+                Expression09ListGroup::Star(x) => s.star(&x.star),
+            });
             self.expression10(&x.expression10);
         }
     }
@@ -440,11 +679,10 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'Expression10'
     #[inline(never)]
     fn expression10(&mut self, arg: &Expression10) {
+        let wrap = !arg.expression10_list.is_empty() && self.wrap_required(|s| s.expression10(arg));
         self.expression11(&arg.expression11);
         for x in &arg.expression10_list {
-            self.space(1);
-            self.operator11(&x.operator11);
-            self.space(1);
+            self.binary_operator(wrap, |s| s.operator11(&x.operator11));
             self.expression11(&x.expression11);
         }
     }
@@ -477,15 +715,23 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'ConcatenationList'
     fn concatenation_list(&mut self, arg: &ConcatenationList) {
-        self.concatenation_item(&arg.concatenation_item);
+        let mut items = vec![arg.concatenation_item.as_ref()];
+        let mut commas = Vec::new();
         for x in &arg.concatenation_list_list {
-            self.comma(&x.comma);
-            self.space(1);
-            self.concatenation_item(&x.concatenation_item);
+            commas.push(x.comma.as_ref());
+            items.push(x.concatenation_item.as_ref());
         }
         if let Some(ref x) = arg.concatenation_list_opt {
-            self.comma(&x.comma);
+            commas.push(x.comma.as_ref());
         }
+        let items: Vec<_> = items
+            .into_iter()
+            .map(|x| {
+                let range: TokenRange = x.expression.as_ref().into();
+                (x, range.beg)
+            })
+            .collect();
+        self.brace_list(&items, &commas, Self::concatenation_item);
     }
 
     /// Semantic action for non-terminal 'ConcatenationItem'
@@ -501,15 +747,29 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'ArrayLiteralList'
     fn array_literal_list(&mut self, arg: &ArrayLiteralList) {
-        self.array_literal_item(&arg.array_literal_item);
+        let mut items = vec![arg.array_literal_item.as_ref()];
+        let mut commas = Vec::new();
         for x in &arg.array_literal_list_list {
-            self.comma(&x.comma);
-            self.space(1);
-            self.array_literal_item(&x.array_literal_item);
+            commas.push(x.comma.as_ref());
+            items.push(x.array_literal_item.as_ref());
         }
         if let Some(ref x) = arg.array_literal_list_opt {
-            self.comma(&x.comma);
-        }
+            commas.push(x.comma.as_ref());
+        }
+        let items: Vec<_> = items
+            .into_iter()
+            .map(|x| {
+                let beg = match x.array_literal_item_group.as_ref() {
+                    ArrayLiteralItemGroup::ExpressionArrayLiteralItemOpt(x) => {
+                        let range: TokenRange = x.expression.as_ref().into();
+                        range.beg
+                    }
+                    ArrayLiteralItemGroup::DefaulColonExpression(x) => x.defaul.default_token.token,
+                };
+                (x, beg)
+            })
+            .collect();
+        self.brace_list(&items, &commas, Self::array_literal_item);
     }
 
     /// Semantic action for non-terminal 'ArrayLiteralItem'
@@ -533,6 +793,53 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'StructConstructor'
+    fn struct_constructor(&mut self, arg: &StructConstructor) {
+        self.quote_l_brace(&arg.quote_l_brace);
+        let list = &arg.struct_constructor_list;
+        let mut items = vec![list.struct_constructor_item.as_ref()];
+        let mut commas = Vec::new();
+        for x in &list.struct_constructor_list_list {
+            commas.push(x.comma.as_ref());
+            items.push(x.struct_constructor_item.as_ref());
+        }
+        if let Some(ref x) = list.struct_constructor_list_opt {
+            commas.push(x.comma.as_ref());
+        }
+        let last_line = if let Some(x) = commas.get(items.len() - 1) {
+            x.comma_token.token.line
+        } else {
+            let range: TokenRange = items.last().unwrap().expression.as_ref().into();
+            range.end.line
+        };
+        let items: Vec<_> = items
+            .into_iter()
+            .map(|x| (x, x.identifier.identifier_token.token))
+            .collect();
+        let column = self.brace_list(&items, &commas, Self::struct_constructor_item);
+        if let Some(ref x) = arg.struct_constructor_opt {
+            if x.dot_dot.dot_dot_token.token.line > last_line {
+                self.newline_at(column);
+            } else {
+                self.space(1);
+            }
+            self.dot_dot(&x.dot_dot);
+            self.defaul(&x.defaul);
+            self.l_paren(&x.l_paren);
+            self.expression(&x.expression);
+            self.r_paren(&x.r_paren);
+        }
+        self.r_brace(&arg.r_brace);
+    }
+
+    /// Semantic action for non-terminal 'StructConstructorItem'
+    fn struct_constructor_item(&mut self, arg: &StructConstructorItem) {
+        self.identifier(&arg.identifier);
+        self.colon(&arg.colon);
+        self.space(1);
+        self.expression(&arg.expression);
+    }
+
     /// Semantic action for non-terminal 'IfExpression'
     fn if_expression(&mut self, arg: &IfExpression) {
         self.r#if(&arg.r#if);
@@ -833,7 +1140,7 @@ impl VerylWalker for Formatter {
     fn identifier_statement(&mut self, arg: &IdentifierStatement) {
         self.align_start(align_kind::IDENTIFIER);
         self.expression_identifier(&arg.expression_identifier);
-        self.align_finish(align_kind::IDENTIFIER);
+        self.align_finish_lhs();
         match &*arg.identifier_statement_group {
             IdentifierStatementGroup::FunctionCall(x) => {
                 self.function_call(&x.function_call);
@@ -855,7 +1162,13 @@ impl VerylWalker for Formatter {
                 self.assignment_operator(&x.assignment_operator)
             }
         }
-        self.align_finish(align_kind::ASSIGNMENT);
+        if self.lhs_unaligned {
+            if self.mode == Mode::Align {
+                self.aligner.aligns[align_kind::ASSIGNMENT].discard_item();
+            }
+        } else {
+            self.align_finish(align_kind::ASSIGNMENT);
+        }
         self.space(1);
         self.expression(&arg.expression);
     }
@@ -873,10 +1186,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'StatementBlockGroup'
     fn statement_block_group(&mut self, arg: &StatementBlockGroup) {
-        for x in &arg.statement_block_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.statement_block_group_list, |x| &x.attribute);
         match arg.statement_block_group_group.as_ref() {
             StatementBlockGroupGroup::LBraceStatementBlockGroupGroupListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -901,7 +1211,7 @@ impl VerylWalker for Formatter {
         self.space(1);
         self.statement_block(&arg.statement_block);
         for x in &arg.if_statement_list {
-            self.space(1);
+            self.else_separator();
             self.r#else(&x.r#else);
             self.space(1);
             self.r#if(&x.r#if);
@@ -911,7 +1221,7 @@ impl VerylWalker for Formatter {
             self.statement_block(&x.statement_block);
         }
         if let Some(ref x) = arg.if_statement_opt {
-            self.space(1);
+            self.else_separator();
             self.r#else(&x.r#else);
             self.space(1);
             self.statement_block(&x.statement_block);
@@ -924,7 +1234,7 @@ impl VerylWalker for Formatter {
         self.space(1);
         self.statement_block(&arg.statement_block);
         for x in &arg.if_reset_statement_list {
-            self.space(1);
+            self.else_separator();
             self.r#else(&x.r#else);
             self.space(1);
             self.r#if(&x.r#if);
@@ -934,7 +1244,7 @@ impl VerylWalker for Formatter {
             self.statement_block(&x.statement_block);
         }
         if let Some(ref x) = arg.if_reset_statement_opt {
-            self.space(1);
+            self.else_separator();
             self.r#else(&x.r#else);
             self.space(1);
             self.statement_block(&x.statement_block);
@@ -1063,6 +1373,43 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.expression(&arg.expression);
+        self.r_paren(&arg.r_paren);
+        if let Some(ref x) = arg.assertion_statement_opt {
+            self.space(1);
+            self.r#else(&x.r#else);
+            self.space(1);
+            self.string_literal(&x.string_literal);
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'Attribute'
+    fn attribute(&mut self, arg: &Attribute) {
+        // attribute lines don't split alignment groups
+        if self.mode == Mode::Align {
+            let beg = arg.hash.hash_token.token.line;
+            let end = arg.r_bracket.r_bracket_token.token.line;
+            for line in beg..=end {
+                self.aligner.skip_line(line);
+            }
+        }
+        self.hash(&arg.hash);
+        self.l_bracket(&arg.l_bracket);
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.attribute_opt {
+            self.l_paren(&x.l_paren);
+            self.attribute_list(&x.attribute_list);
+            self.r_paren(&x.r_paren);
+        }
+        self.r_bracket(&arg.r_bracket);
+    }
+
     /// Semantic action for non-terminal 'AttributeList'
     fn attribute_list(&mut self, arg: &AttributeList) {
         self.attribute_item(&arg.attribute_item);
@@ -1212,7 +1559,7 @@ impl VerylWalker for Formatter {
         self.space(1);
         self.align_start(align_kind::IDENTIFIER);
         self.hierarchical_identifier(&arg.hierarchical_identifier);
-        self.align_finish(align_kind::IDENTIFIER);
+        self.align_finish_lhs();
         self.space(1);
         self.equ(&arg.equ);
         self.space(1);
@@ -1250,10 +1597,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'ModportGroup'
     fn modport_group(&mut self, arg: &ModportGroup) {
-        for x in &arg.modport_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.modport_group_list, |x| &x.attribute);
         match &*arg.modport_group_group {
             ModportGroupGroup::LBraceModportListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1311,10 +1655,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'EnumGroup'
     fn enum_group(&mut self, arg: &EnumGroup) {
-        for x in &arg.enum_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.enum_group_list, |x| &x.attribute);
         match &*arg.enum_group_group {
             EnumGroupGroup::LBraceEnumListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1371,10 +1712,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'StructUnionGroup'
     fn struct_union_group(&mut self, arg: &StructUnionGroup) {
-        for x in &arg.struct_union_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.struct_union_group_list, |x| &x.attribute);
         match &*arg.struct_union_group_group {
             StructUnionGroupGroup::LBraceStructUnionListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1413,6 +1751,24 @@ impl VerylWalker for Formatter {
         self.statement_block(&arg.statement_block);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        self.assertion_kind(&arg.assertion_kind);
+        self.space(1);
+        self.property(&arg.property);
+        self.space(1);
+        self.l_paren(&arg.l_paren);
+        self.expression(&arg.expression);
+        self.r_paren(&arg.r_paren);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.space(1);
+            self.r#else(&x.r#else);
+            self.space(1);
+            self.string_literal(&x.string_literal);
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'InstDeclaration'
     fn inst_declaration(&mut self, arg: &InstDeclaration) {
         self.single_line = arg.inst_declaration_opt1.is_none();
@@ -1443,11 +1799,11 @@ impl VerylWalker for Formatter {
         if let Some(ref x) = arg.inst_declaration_opt1 {
             self.space(1);
             self.token_will_push(&x.l_paren.l_paren_token);
-            self.newline_push();
             if let Some(ref x) = x.inst_declaration_opt2 {
+                self.newline_push();
                 self.inst_port_list(&x.inst_port_list);
+                self.newline_pop();
             }
-            self.newline_pop();
             self.r_paren(&x.r_paren);
         }
         self.semicolon(&arg.semicolon);
@@ -1457,17 +1813,19 @@ impl VerylWalker for Formatter {
     /// Semantic action for non-terminal 'InstParameter'
     fn inst_parameter(&mut self, arg: &InstParameter) {
         self.hash(&arg.hash);
-        if self.single_line {
-            self.l_paren(&arg.l_paren);
+        if let Some(ref x) = arg.inst_parameter_opt {
+            if self.single_line {
+                self.l_paren(&arg.l_paren);
+                self.inst_parameter_list(&x.inst_parameter_list);
+            } else {
+                self.token_will_push(&arg.l_paren.l_paren_token);
+                self.newline_push();
+                self.inst_parameter_list(&x.inst_parameter_list);
+                self.newline_pop();
+            }
         } else {
+            // comments in empty parens are indented as list items
             self.token_will_push(&arg.l_paren.l_paren_token);
-            self.newline_push();
-        }
-        if let Some(ref x) = arg.inst_parameter_opt {
-            self.inst_parameter_list(&x.inst_parameter_list);
-        }
-        if !self.single_line {
-            self.newline_pop();
         }
         self.r_paren(&arg.r_paren);
     }
@@ -1493,10 +1851,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'InstParameterGroup'
     fn inst_parameter_group(&mut self, arg: &InstParameterGroup) {
-        for x in &arg.inst_parameter_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.inst_parameter_group_list, |x| &x.attribute);
         match &*arg.inst_parameter_group_group {
             InstParameterGroupGroup::LBraceInstParameterListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1547,10 +1902,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'InstPortGroup'
     fn inst_port_group(&mut self, arg: &InstPortGroup) {
-        for x in &arg.inst_port_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.inst_port_group_list, |x| &x.attribute);
         match &*arg.inst_port_group_group {
             InstPortGroupGroup::LBraceInstPortListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1593,7 +1945,7 @@ impl VerylWalker for Formatter {
             self.r_paren(&arg.r_paren);
         } else {
             self.hash(&arg.hash);
-            self.l_paren(&arg.l_paren);
+            self.token_will_push(&arg.l_paren.l_paren_token);
             self.r_paren(&arg.r_paren);
         }
     }
@@ -1615,10 +1967,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'WithParameterGroup'
     fn with_parameter_group(&mut self, arg: &WithParameterGroup) {
-        for x in &arg.with_parameter_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.with_parameter_group_list, |x| &x.attribute);
         match &*arg.with_parameter_group_group {
             WithParameterGroupGroup::LBraceWithParameterListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1728,7 +2077,7 @@ impl VerylWalker for Formatter {
             self.newline_pop();
             self.r_paren(&arg.r_paren);
         } else {
-            self.l_paren(&arg.l_paren);
+            self.token_will_push(&arg.l_paren.l_paren_token);
             self.r_paren(&arg.r_paren);
         }
     }
@@ -1750,10 +2099,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'PortDeclarationGroup'
     fn port_declaration_group(&mut self, arg: &PortDeclarationGroup) {
-        for x in &arg.port_declaration_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.port_declaration_group_list, |x| &x.attribute);
         match &*arg.port_declaration_group_group {
             PortDeclarationGroupGroup::LBracePortDeclarationListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1858,19 +2204,19 @@ impl VerylWalker for Formatter {
         if let Some(ref x) = arg.function_declaration_opt {
             self.with_generic_parameter(&x.with_generic_parameter);
         }
-        self.space(1);
         if let Some(ref x) = arg.function_declaration_opt0 {
-            self.port_declaration(&x.port_declaration);
             self.space(1);
+            self.port_declaration(&x.port_declaration);
         }
         if let Some(ref x) = arg.function_declaration_opt1 {
+            self.space(1);
             self.align_reset();
             self.minus_g_t(&x.minus_g_t);
             self.space(1);
             self.scalar_type(&x.scalar_type);
-            self.space(1);
             self.align_reset();
         }
+        self.brace_separator();
         self.statement_block(&arg.statement_block);
     }
 
@@ -1932,21 +2278,21 @@ impl VerylWalker for Formatter {
         if let Some(ref x) = arg.module_declaration_opt0 {
             self.with_generic_parameter(&x.with_generic_parameter);
         }
-        self.space(1);
         if let Some(ref x) = arg.module_declaration_opt1 {
+            self.space(1);
             self.r#for(&x.r#for);
             self.space(1);
             self.scoped_identifier(&x.scoped_identifier);
-            self.space(1);
         }
         if let Some(ref x) = arg.module_declaration_opt2 {
-            self.with_parameter(&x.with_parameter);
             self.space(1);
+            self.with_parameter(&x.with_parameter);
         }
         if let Some(ref x) = arg.module_declaration_opt3 {
-            self.port_declaration(&x.port_declaration);
             self.space(1);
+            self.port_declaration(&x.port_declaration);
         }
+        self.brace_separator();
         self.token_will_push(&arg.l_brace.l_brace_token);
         for (i, x) in arg.module_declaration_list.iter().enumerate() {
             self.newline_list(i);
@@ -1958,10 +2304,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'ModuleGroup'
     fn module_group(&mut self, arg: &ModuleGroup) {
-        for x in &arg.module_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.module_group_list, |x| &x.attribute);
         match &*arg.module_group_group {
             ModuleGroupGroup::LBraceModuleGroupGroupListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -1988,11 +2331,11 @@ impl VerylWalker for Formatter {
         if let Some(ref x) = arg.interface_declaration_opt0 {
             self.with_generic_parameter(&x.with_generic_parameter);
         }
-        self.space(1);
         if let Some(ref x) = arg.interface_declaration_opt1 {
-            self.with_parameter(&x.with_parameter);
             self.space(1);
+            self.with_parameter(&x.with_parameter);
         }
+        self.brace_separator();
         self.token_will_push(&arg.l_brace.l_brace_token);
         for (i, x) in arg.interface_declaration_list.iter().enumerate() {
             self.newline_list(i);
@@ -2004,10 +2347,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'InterfaceGroup'
     fn interface_group(&mut self, arg: &InterfaceGroup) {
-        for x in &arg.interface_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.interface_group_list, |x| &x.attribute);
         match &*arg.interface_group_group {
             InterfaceGroupGroup::LBraceInterfaceGroupGroupListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -2030,7 +2370,7 @@ impl VerylWalker for Formatter {
         self.space(1);
         self.generate_named_block(&arg.generate_named_block);
         for x in &arg.generate_if_declaration_list {
-            self.space(1);
+            self.else_separator();
             self.r#else(&x.r#else);
             self.space(1);
             self.r#if(&x.r#if);
@@ -2040,7 +2380,7 @@ impl VerylWalker for Formatter {
             self.generate_optional_named_block(&x.generate_optional_named_block);
         }
         if let Some(ref x) = arg.generate_if_declaration_opt {
-            self.space(1);
+            self.else_separator();
             self.r#else(&x.r#else);
             self.space(1);
             self.generate_optional_named_block(&x.generate_optional_named_block);
@@ -2100,10 +2440,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'GenerateGroup'
     fn generate_group(&mut self, arg: &GenerateGroup) {
-        for x in &arg.generate_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.generate_group_list, |x| &x.attribute);
         match &*arg.generate_group_group {
             GenerateGroupGroup::LBraceGenerateGroupGroupListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -2130,7 +2467,7 @@ impl VerylWalker for Formatter {
         if let Some(ref x) = arg.package_declaration_opt0 {
             self.with_generic_parameter(&x.with_generic_parameter);
         }
-        self.space(1);
+        self.brace_separator();
         self.token_will_push(&arg.l_brace.l_brace_token);
         for (i, x) in arg.package_declaration_list.iter().enumerate() {
             self.newline_list(i);
@@ -2142,10 +2479,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'PackageGroup'
     fn package_group(&mut self, arg: &PackageGroup) {
-        for x in &arg.package_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.package_group_list, |x| &x.attribute);
         match &*arg.package_group_group {
             PackageGroupGroup::LBracePackageGroupGroupListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
@@ -2210,10 +2544,7 @@ impl VerylWalker for Formatter {
 
     /// Semantic action for non-terminal 'DescriptionGroup'
     fn description_group(&mut self, arg: &DescriptionGroup) {
-        for x in &arg.description_group_list {
-            self.attribute(&x.attribute);
-            self.newline();
-        }
+        self.attributes(&arg.description_group_list, |x| &x.attribute);
         match &*arg.description_group_group {
             DescriptionGroupGroup::LBraceDescriptionGroupGroupListRBrace(x) => {
                 self.token_will_push(&x.l_brace.l_brace_token);
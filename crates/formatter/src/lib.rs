@@ -1,2 +1,5 @@
 pub mod formatter;
+pub mod sorter;
 pub use formatter::Formatter;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,424 @@
+use std::path::PathBuf;
+use veryl_metadata::{Format, InstPortStyle};
+use veryl_parser::resource_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenSource, VerylToken};
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Parser;
+
+#[derive(Clone)]
+struct Element {
+    token: Token,
+    comment: bool,
+}
+
+impl Element {
+    fn end_line(&self) -> u32 {
+        let text = resource_table::get_str_value(self.token.text).unwrap();
+        self.token.line + text.trim_end().matches('\n').count() as u32
+    }
+
+    fn shift(&self, delta: i64) -> Self {
+        let mut ret = self.clone();
+        ret.token.line = (ret.token.line as i64 + delta) as u32;
+        ret
+    }
+}
+
+#[derive(Default)]
+struct Segment {
+    leading: Vec<Element>,
+    body: Vec<Element>,
+    trailing: Vec<Element>,
+}
+
+impl Segment {
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.leading
+            .iter()
+            .chain(self.body.iter())
+            .chain(self.trailing.iter())
+    }
+
+    fn start_line(&self) -> u32 {
+        self.elements().map(|x| x.token.line).min().unwrap()
+    }
+
+    fn end_line(&self) -> u32 {
+        self.elements().map(|x| x.end_line()).max().unwrap()
+    }
+}
+
+/// Sorter reorders port and instance connection lists according to format options.
+/// It also rewrites instance port connections between implicit and explicit forms.
+///
+/// Reordered or inserted items break the line order of tokens which is assumed by Aligner,
+/// so the reordered token stream is rebuilt as source text and parsed again.
+pub struct Sorter<'a> {
+    format_opt: &'a Format,
+    elements: Vec<Element>,
+    changed: bool,
+}
+
+impl<'a> Sorter<'a> {
+    pub fn new(format_opt: &'a Format) -> Self {
+        Self {
+            format_opt,
+            elements: Vec::new(),
+            changed: false,
+        }
+    }
+
+    /// Returns the rebuilt syntax tree if any list is reordered or rewritten
+    pub fn sort(&mut self, input: &Veryl) -> Option<Veryl> {
+        self.veryl(input);
+        if !self.changed {
+            return None;
+        }
+
+        let path = if let TokenSource::File(x) = input.start.start_token.token.source {
+            resource_table::get_path_value(x).unwrap_or_default()
+        } else {
+            PathBuf::new()
+        };
+        let text = self.rebuild();
+        Parser::parse(&text, &path).ok().map(|x| x.veryl)
+    }
+
+    fn rebuild(&self) -> String {
+        let mut ret = String::new();
+        let mut line = 1;
+        let mut column = 1;
+        for x in &self.elements {
+            let text = resource_table::get_str_value(x.token.text).unwrap();
+            if x.token.line > line {
+                for _ in line..x.token.line {
+                    ret.push('\n');
+                }
+                line = x.token.line;
+                column = 1;
+            }
+            if x.token.column > column {
+                ret.push_str(&" ".repeat((x.token.column - column) as usize));
+            } else if !ret.is_empty() && !ret.ends_with([' ', '\n']) {
+                ret.push(' ');
+            }
+            ret.push_str(&text);
+
+            if let Some(pos) = text.rfind('\n') {
+                line += text.matches('\n').count() as u32;
+                column = (text.len() - pos) as u32;
+            } else {
+                column = x.token.column.max(column) + text.chars().count() as u32;
+            }
+        }
+        ret
+    }
+
+    fn walk<T>(&mut self, arg: &T, f: fn(&mut Self, &T)) -> Vec<Element> {
+        let start = self.elements.len();
+        f(self, arg);
+        self.elements.split_off(start)
+    }
+
+    /// Reorders groups by keys with attached attributes and comments.
+    /// Lists including nested groups (`{ ... }`) are kept in the original order.
+    fn sort_list<T, K: Ord + Clone>(
+        &mut self,
+        groups: &[(&T, Option<K>)],
+        commas: &[&Comma],
+        f: fn(&mut Self, &T),
+    ) {
+        let sortable = groups.len() > 1 && groups.iter().all(|(_, x)| x.is_some());
+        if !sortable {
+            for (i, (group, _)) in groups.iter().enumerate() {
+                f(self, group);
+                if let Some(comma) = commas.get(i) {
+                    self.comma(comma);
+                }
+            }
+            return;
+        }
+
+        let mut segments: Vec<Segment> = groups.iter().map(|_| Segment::default()).collect();
+
+        // Comments in the lines after the previous token are attached to the first group
+        let last_line = self
+            .elements
+            .iter()
+            .rev()
+            .find(|x| !x.comment)
+            .map(|x| x.token.line)
+            .unwrap_or(0);
+        while let Some(x) = self.elements.last() {
+            if x.comment && x.token.line > last_line {
+                segments[0].leading.insert(0, self.elements.pop().unwrap());
+            } else {
+                break;
+            }
+        }
+
+        let mut tail = Vec::new();
+        for (i, (group, _)) in groups.iter().enumerate() {
+            segments[i].body = self.walk(*group, f);
+            // Comments following the last token of the group are placed after comma
+            while segments[i].body.last().map(|x| x.comment).unwrap_or(false) {
+                let x = segments[i].body.pop().unwrap();
+                segments[i].trailing.insert(0, x);
+            }
+            if let Some(comma) = commas.get(i) {
+                let mut elements = self.walk(*comma, Self::comma);
+                let comma = elements.remove(0);
+                for x in elements {
+                    if x.token.line == comma.token.line {
+                        segments[i].trailing.push(x);
+                    } else if let Some(next) = segments.get_mut(i + 1) {
+                        next.leading.push(x);
+                    } else {
+                        tail.push(x);
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<_> = (0..groups.len()).collect();
+        order.sort_by_key(|i| groups[*i].1.clone());
+        if order.iter().enumerate().any(|(i, x)| i != *x) {
+            self.changed = true;
+        }
+
+        // Each segment keeps its own height and each gap between slots is kept,
+        // so tokens after this list keep their line numbers.
+        let comma = commas[0].comma_token.token;
+        let mut line = segments[0].start_line();
+        for (slot, index) in order.iter().enumerate() {
+            let segment = &segments[*index];
+            let delta = line as i64 - segment.start_line() as i64;
+
+            self.elements
+                .extend(segment.leading.iter().map(|x| x.shift(delta)));
+            self.elements
+                .extend(segment.body.iter().map(|x| x.shift(delta)));
+
+            let last = self.elements.last().unwrap().token;
+            let mut comma = comma;
+            comma.line = last.line;
+            comma.column = last.column + last.length;
+            self.elements.push(Element {
+                token: comma,
+                comment: false,
+            });
+
+            self.elements
+                .extend(segment.trailing.iter().map(|x| x.shift(delta)));
+
+            let end_line = line + (segment.end_line() - segment.start_line());
+            if let Some(next) = segments.get(slot + 1) {
+                let gap = next.start_line().saturating_sub(segments[slot].end_line());
+                line = end_line + gap;
+            }
+        }
+        self.elements.extend(tail);
+    }
+}
+
+fn port_key(arg: &PortDeclarationGroup) -> Option<(usize, String)> {
+    let item = match &*arg.port_declaration_group_group {
+        PortDeclarationGroupGroup::PortDeclarationItem(x) => &x.port_declaration_item,
+        PortDeclarationGroupGroup::LBracePortDeclarationListRBrace(_) => return None,
+    };
+
+    let rank = match &*item.port_declaration_item_group {
+        PortDeclarationItemGroup::PortTypeConcrete(x) => {
+            let x = &x.port_type_concrete;
+            let variable_type = match &*x.array_type.scalar_type.scalar_type_group {
+                ScalarTypeGroup::FactorType(x) => match &*x.factor_type.factor_type_group {
+                    FactorTypeGroup::VariableTypeFactorTypeOpt(x) => Some(&*x.variable_type),
+                    FactorTypeGroup::FixedType(_) => None,
+                },
+                ScalarTypeGroup::UserDefinedTypeScalarTypeOpt(_) => None,
+            };
+            match variable_type {
+                Some(
+                    VariableType::Clock(_)
+                    | VariableType::ClockPosedge(_)
+                    | VariableType::ClockNegedge(_),
+                ) => 0,
+                Some(
+                    VariableType::Reset(_)
+                    | VariableType::ResetAsyncHigh(_)
+                    | VariableType::ResetAsyncLow(_)
+                    | VariableType::ResetSyncHigh(_)
+                    | VariableType::ResetSyncLow(_),
+                ) => 1,
+                _ => match &*x.direction {
+                    Direction::Input(_) => 2,
+                    Direction::Output(_) => 3,
+                    Direction::Inout(_) => 4,
+                    Direction::Ref(_) => 5,
+                    Direction::Modport(_) => 6,
+                    Direction::Import(_) => 7,
+                },
+            }
+        }
+        PortDeclarationItemGroup::PortTypeAbstract(_) => 6,
+    };
+
+    Some((
+        rank,
+        item.identifier.identifier_token.token.text.to_string(),
+    ))
+}
+
+fn inst_port_key(arg: &InstPortGroup) -> Option<String> {
+    match &*arg.inst_port_group_group {
+        InstPortGroupGroup::InstPortItem(x) => Some(
+            x.inst_port_item
+                .identifier
+                .identifier_token
+                .token
+                .text
+                .to_string(),
+        ),
+        InstPortGroupGroup::LBraceInstPortListRBrace(_) => None,
+    }
+}
+
+fn inst_parameter_key(arg: &InstParameterGroup) -> Option<String> {
+    match &*arg.inst_parameter_group_group {
+        InstParameterGroupGroup::InstParameterItem(x) => Some(
+            x.inst_parameter_item
+                .identifier
+                .identifier_token
+                .token
+                .text
+                .to_string(),
+        ),
+        InstParameterGroupGroup::LBraceInstParameterListRBrace(_) => None,
+    }
+}
+
+impl VerylWalker for Sorter<'_> {
+    /// Semantic action for non-terminal 'VerylToken'
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.elements.push(Element {
+            token: arg.token,
+            comment: false,
+        });
+        for x in &arg.comments {
+            self.elements.push(Element {
+                token: *x,
+                comment: true,
+            });
+        }
+    }
+
+    /// Semantic action for non-terminal 'PortDeclarationList'
+    fn port_declaration_list(&mut self, arg: &PortDeclarationList) {
+        let mut groups = vec![&*arg.port_declaration_group];
+        let mut commas = Vec::new();
+        for x in &arg.port_declaration_list_list {
+            commas.push(&*x.comma);
+            groups.push(&*x.port_declaration_group);
+        }
+        if let Some(ref x) = arg.port_declaration_list_opt {
+            commas.push(&*x.comma);
+        }
+
+        let sort = self.format_opt.sort_ports;
+        let groups: Vec<_> = groups
+            .into_iter()
+            .map(|x| (x, if sort { port_key(x) } else { None }))
+            .collect();
+        self.sort_list(&groups, &commas, Self::port_declaration_group);
+    }
+
+    /// Semantic action for non-terminal 'InstPortList'
+    fn inst_port_list(&mut self, arg: &InstPortList) {
+        let mut groups = vec![&*arg.inst_port_group];
+        let mut commas = Vec::new();
+        for x in &arg.inst_port_list_list {
+            commas.push(&*x.comma);
+            groups.push(&*x.inst_port_group);
+        }
+        if let Some(ref x) = arg.inst_port_list_opt {
+            commas.push(&*x.comma);
+        }
+
+        let sort = self.format_opt.sort_inst_ports;
+        let groups: Vec<_> = groups
+            .into_iter()
+            .map(|x| (x, if sort { inst_port_key(x) } else { None }))
+            .collect();
+        self.sort_list(&groups, &commas, Self::inst_port_group);
+    }
+
+    /// Semantic action for non-terminal 'InstPortItem'
+    fn inst_port_item(&mut self, arg: &InstPortItem) {
+        let start = self.elements.len();
+        self.identifier(&arg.identifier);
+
+        match (&arg.inst_port_item_opt, self.format_opt.inst_port_style) {
+            (None, InstPortStyle::Explicit) => {
+                // `a` -> `a: a`
+                let identifier = arg.identifier.identifier_token.token;
+                let mut colon = identifier;
+                colon.text = resource_table::insert_str(":");
+                colon.length = 1;
+                colon.column = identifier.column + identifier.length;
+                let mut expression = identifier;
+                expression.column = colon.column + 2;
+
+                let elements = [colon, expression].map(|token| Element {
+                    token,
+                    comment: false,
+                });
+                self.elements.splice(start + 1..start + 1, elements);
+                self.changed = true;
+            }
+            (Some(x), InstPortStyle::Implicit) => {
+                // `a: a` -> `a`, comments around the removed tokens are kept
+                let colon = self.walk(&*x.colon, Self::colon);
+                let expression = self.walk(&*x.expression, Self::expression);
+                let mut tokens = expression.iter().filter(|x| !x.comment);
+                let same = matches!(
+                    (tokens.next(), tokens.next()),
+                    (Some(x), None) if x.token.text == arg.identifier.identifier_token.token.text
+                );
+                if same {
+                    self.elements
+                        .extend(colon.into_iter().chain(expression).filter(|x| x.comment));
+                    self.changed = true;
+                } else {
+                    self.elements.extend(colon);
+                    self.elements.extend(expression);
+                }
+            }
+            (Some(x), _) => {
+                self.colon(&x.colon);
+                self.expression(&x.expression);
+            }
+            (None, _) => (),
+        }
+    }
+
+    /// Semantic action for non-terminal 'InstParameterList'
+    fn inst_parameter_list(&mut self, arg: &InstParameterList) {
+        let mut groups = vec![&*arg.inst_parameter_group];
+        let mut commas = Vec::new();
+        for x in &arg.inst_parameter_list_list {
+            commas.push(&*x.comma);
+            groups.push(&*x.inst_parameter_group);
+        }
+        if let Some(ref x) = arg.inst_parameter_list_opt {
+            commas.push(&*x.comma);
+        }
+
+        let sort = self.format_opt.sort_inst_ports;
+        let groups: Vec<_> = groups
+            .into_iter()
+            .map(|x| (x, if sort { inst_parameter_key(x) } else { None }))
+            .collect();
+        self.sort_list(&groups, &commas, Self::inst_parameter_group);
+    }
+}
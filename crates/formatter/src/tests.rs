@@ -0,0 +1,773 @@
+use crate::Formatter;
+use veryl_metadata::{BraceStyle, ContinuationStyle, ElseStyle, InstPortStyle, Metadata};
+use veryl_parser::Parser;
+
+#[track_caller]
+fn format(metadata: &Metadata, code: &str) -> String {
+    let parser = Parser::parse(&code, &"").unwrap();
+    let mut formatter = Formatter::new(metadata);
+    formatter.format(&parser.veryl);
+    formatter.as_str().to_string()
+}
+
+fn create_metadata(sort_ports: bool, sort_inst_ports: bool) -> Metadata {
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.format.sort_ports = sort_ports;
+    metadata.format.sort_inst_ports = sort_inst_ports;
+    metadata
+}
+
+#[test]
+fn sort_ports() {
+    let code = r#"module ModuleA (
+    o_b    : output logic   ,
+    // comment for i_b
+    i_b    : input  logic<2>, // trailing comment
+    #[ifdef(A)]
+    o_a    : output logic   ,
+    i_rst_n: input  reset   ,
+    i_a    : input  logic   ,
+    i_clk  : input  clock   ,
+) {
+    inst u: ModuleB #(
+        Y: 1,
+        X: 2,
+    ) (
+        b: o_b,
+        a: o_a,
+    );
+}
+"#;
+
+    let expect = r#"module ModuleA (
+    i_clk  : input  clock   ,
+    i_rst_n: input  reset   ,
+    i_a    : input  logic   ,
+    // comment for i_b
+    i_b    : input  logic<2>, // trailing comment
+    #[ifdef(A)]
+    o_a    : output logic   ,
+    o_b    : output logic   ,
+) {
+    inst u: ModuleB #(
+        Y: 1,
+        X: 2,
+    ) (
+        b: o_b,
+        a: o_a,
+    );
+}
+"#;
+
+    let metadata = create_metadata(true, false);
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+
+    let metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), code);
+}
+
+#[test]
+fn sort_inst_ports() {
+    let code = r#"module ModuleA {
+    inst u: ModuleB #(
+        Y: 1,
+        X: 2,
+    ) (
+        b: o_b,
+        // comment for a
+        a,
+    );
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    inst u: ModuleB #(
+        X: 2,
+        Y: 1,
+    ) (
+        // comment for a
+        a     ,
+        b: o_b,
+    );
+}
+"#;
+
+    let metadata = create_metadata(false, true);
+    assert_eq!(format(&metadata, code), expect);
+}
+
+#[test]
+fn break_before_operator() {
+    let code = r#"module ModuleA {
+    assign a = (aaaaaaaa && bbbbbbbb) || (cccccccc && dddddddd) || eeeeeeee && ffffffff && gggggggg;
+    assign b = aaaaaaaa + bbbbbbbb;
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    assign a = (aaaaaaaa && bbbbbbbb)
+        || (cccccccc && dddddddd)
+        || eeeeeeee && ffffffff && gggggggg;
+    assign b = aaaaaaaa + bbbbbbbb;
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    metadata.format.max_width = 50;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+
+    let expect = r#"module ModuleA {
+    assign a = (aaaaaaaa && bbbbbbbb) ||
+        (cccccccc && dddddddd) ||
+        eeeeeeee && ffffffff && gggggggg;
+    assign b = aaaaaaaa + bbbbbbbb;
+}
+"#;
+
+    metadata.format.break_before_operator = false;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+
+    let code = r#"module ModuleA {
+    always_comb {
+        if aaaaaaaa && bbbbbbbb && (cccccccc || dddddddd || eeeeeeee || ffffffff) {
+            a = 1;
+        }
+    }
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    always_comb {
+        if aaaaaaaa
+            && bbbbbbbb
+            && (cccccccc
+            || dddddddd
+            || eeeeeeee
+            || ffffffff) {
+            a = 1;
+        }
+    }
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    metadata.format.max_width = 40;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn else_style() {
+    let code = r#"module ModuleA (
+    i_clk: input clock,
+    i_rst: input reset,
+) {
+    var a  : logic;
+    var bbb: logic;
+
+    always_ff {
+        if_reset {
+            a = 0;
+        } else if a {
+            a = 1;
+        } else {
+            a = 0;
+        }
+    }
+
+    always_comb {
+        if a {
+            bbb = 1;
+        } else if bbb {
+            bbb = 0;
+        } else {
+            bbb = 1;
+        }
+        case a {
+            0: {
+                   bbb = 1;
+               }
+            default: {
+                         bbb = 0;
+                     }
+        }
+    }
+
+    if X :g {
+        assign c = 1;
+    } else {
+        assign c = 0;
+    }
+}
+"#;
+
+    let expect = r#"module ModuleA (
+    i_clk: input clock,
+    i_rst: input reset,
+) {
+    var a  : logic;
+    var bbb: logic;
+
+    always_ff {
+        if_reset {
+            a = 0;
+        }
+        else if a {
+            a = 1;
+        }
+        else {
+            a = 0;
+        }
+    }
+
+    always_comb {
+        if a {
+            bbb = 1;
+        }
+        else if bbb {
+            bbb = 0;
+        }
+        else {
+            bbb = 1;
+        }
+        case a {
+            0: {
+                   bbb = 1;
+               }
+            default: {
+                         bbb = 0;
+                     }
+        }
+    }
+
+    if X :g {
+        assign c = 1;
+    }
+    else {
+        assign c = 0;
+    }
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), code);
+    assert_eq!(format(&metadata, expect), code);
+
+    metadata.format.else_style = ElseStyle::Newline;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn brace_style() {
+    let code = r#"module ModuleA #(
+    param A: u32 = 1,
+) (
+    i_a: input  logic,
+    o_b: output logic,
+) {
+    var cc: logic;
+    let d : logic = i_a;
+
+    function FuncA (
+        a: input logic,
+    ) -> logic {
+        return a;
+    }
+}
+
+interface InterfaceA {
+    var a: logic;
+}
+
+package PackageA {
+    function FuncB -> logic {
+        return 1;
+    }
+}
+"#;
+
+    let expect = r#"module ModuleA #(
+    param A: u32 = 1,
+) (
+    i_a: input  logic,
+    o_b: output logic,
+)
+{
+    var cc: logic;
+    let d : logic = i_a;
+
+    function FuncA (
+        a: input logic,
+    ) -> logic
+    {
+        return a;
+    }
+}
+
+interface InterfaceA
+{
+    var a: logic;
+}
+
+package PackageA
+{
+    function FuncB -> logic
+    {
+        return 1;
+    }
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), code);
+    assert_eq!(format(&metadata, expect), code);
+
+    metadata.format.brace_style = BraceStyle::NextLine;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn continuation_style() {
+    let code = r#"module ModuleA {
+    let a: logic<8> = {1'b0, 1'b1,
+    2'b11, 4'h0};
+    let b: logic<8> = {1'b0,
+   2'b1,
+         5'h1f};
+    let c: logic<2, 3> = '{1, 2,
+    default: 3};
+    let d: logic<4> = {a[0], b[0], c[0][0], c[1][0]};
+}
+"#;
+
+    let brace = r#"module ModuleA {
+    let a: logic<8> = {1'b0, 1'b1,
+                       2'b11, 4'h0};
+    let b: logic<8> = {1'b0 ,
+                       2'b1 ,
+                       5'h1f};
+    let c: logic<2, 3> = '{1, 2,
+                           default: 3};
+    let d: logic<4> = {a[0], b[0], c[0][0], c[1][0]};
+}
+"#;
+
+    let indent = r#"module ModuleA {
+    let a: logic<8> = {1'b0, 1'b1,
+        2'b11, 4'h0};
+    let b: logic<8> = {1'b0 ,
+        2'b1 ,
+        5'h1f};
+    let c: logic<2, 3> = '{1, 2,
+        default: 3};
+    let d: logic<4> = {a[0], b[0], c[0][0], c[1][0]};
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), brace);
+    assert_eq!(format(&metadata, brace), brace);
+    assert_eq!(format(&metadata, indent), brace);
+
+    metadata.format.continuation_style = ContinuationStyle::Indent;
+    assert_eq!(format(&metadata, code), indent);
+    assert_eq!(format(&metadata, indent), indent);
+    assert_eq!(format(&metadata, brace), indent);
+}
+
+#[test]
+fn align_attribute() {
+    let code = r#"module ModuleA {
+    #[allow(unused_variable)]
+    var a: logic;
+    var bbbb: logic<2>;
+
+    var c: logic;
+    #[sv("keep=\"true\"")]
+    var dddd: logic<2>;
+    var ee: logic;
+
+    var f: logic;
+    var gggg: logic<2>;
+    #[allow(unused_variable)]
+    var hh: logic;
+
+    var i: logic;
+    #[allow(unused_variable)] var jjjj: logic<2>;
+
+    #[allow(unused_variable)]
+    var k: logic<2>;
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    #[allow(unused_variable)]
+    var a   : logic   ;
+    var bbbb: logic<2>;
+
+    var c   : logic   ;
+    #[sv("keep=\"true\"")]
+    var dddd: logic<2>;
+    var ee  : logic   ;
+
+    var f   : logic   ;
+    var gggg: logic<2>;
+    #[allow(unused_variable)]
+    var hh  : logic   ;
+
+    var i   : logic   ;
+    #[allow(unused_variable)]
+    var jjjj: logic<2>;
+
+    #[allow(unused_variable)]
+    var k: logic<2>;
+}
+"#;
+
+    let metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn attribute_wrapping() {
+    let code = r#"module ModuleA {
+    #[ sv ( "keep = \"true\" (* keep *)" ) ]   #[ allow ( unused_variable , missing_port ) ]
+    var a: logic;
+
+    #[ifdef(DEFINE_A)] #[sv("ram_style=\"block\"")] #[allow(unused_variable, missing_reset_statement)]
+    var b: logic;
+
+    #[ifdef(DEFINE_A)]
+    #[allow(unused_variable)]
+    var c: logic;
+
+    always_comb {
+        #[ifdef(DEFINE_A)]   #[ifndef(DEFINE_B)]
+        {
+            a = 1;
+        }
+    }
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    #[sv("keep = \"true\" (* keep *)")] #[allow(unused_variable, missing_port)]
+    var a: logic;
+
+    #[ifdef(DEFINE_A)]
+    #[sv("ram_style=\"block\"")]
+    #[allow(unused_variable, missing_reset_statement)]
+    var b: logic;
+
+    #[ifdef(DEFINE_A)]
+    #[allow(unused_variable)]
+    var c: logic;
+
+    always_comb {
+        #[ifdef(DEFINE_A)] #[ifndef(DEFINE_B)]
+        {
+            a = 1;
+        }
+    }
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    metadata.format.max_width = 80;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn align_comment() {
+    let code = r#"module ModuleA {
+    var a: logic;
+    // section
+    var bbbb: logic<2>;
+
+    var c: logic;
+    // first line
+    /* second line */
+    #[allow(unused_variable)]
+    var dddd: logic<2>;
+    var ee: logic; // trailing comment
+    var f: logic<3>;
+
+    // section after blank line
+    var g: logic;
+    /*
+     * block
+     */
+    var hhhh: logic<2>;
+    /// doc comment
+    var i: logic;
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    var a   : logic   ;
+    // section
+    var bbbb: logic<2>;
+
+    var c   : logic   ;
+    // first line
+    /* second line */
+    #[allow(unused_variable)]
+    var dddd: logic<2>;
+    var ee  : logic   ; // trailing comment
+    var f   : logic<3>;
+
+    // section after blank line
+    var g   : logic   ;
+    /*
+     * block
+     */
+    var hhhh: logic<2>;
+    /// doc comment
+    var i: logic;
+}
+"#;
+
+    let metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+
+    let expect = r#"module ModuleA {
+    var a: logic;
+    // section
+    var bbbb: logic<2>;
+
+    var c: logic;
+    // first line
+    /* second line */
+    #[allow(unused_variable)]
+    var dddd: logic<2>;
+    var ee  : logic   ; // trailing comment
+    var f   : logic<3>;
+
+    // section after blank line
+    var g: logic;
+    /*
+     * block
+     */
+    var hhhh: logic<2>;
+    /// doc comment
+    var i: logic;
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    metadata.format.comment_breaks_align_group = true;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn inst_port_style() {
+    let code = r#"module ModuleA {
+    inst u: ModuleB (
+        i_clk,
+        // comment for i_a
+        i_a: i_a, // trailing comment
+        i_b: i_c,
+        o_d: o_d[0],
+        o_e /* inline */,
+    );
+}
+"#;
+
+    let explicit = r#"module ModuleA {
+    inst u: ModuleB (
+        i_clk: i_clk ,
+        // comment for i_a
+        i_a  : i_a   , // trailing comment
+        i_b  : i_c   ,
+        o_d  : o_d[0],
+        o_e  : o_e    /* inline */,
+    );
+}
+"#;
+
+    let implicit = r#"module ModuleA {
+    inst u: ModuleB (
+        i_clk        ,
+        // comment for i_a
+        i_a          , // trailing comment
+        i_b  : i_c   ,
+        o_d  : o_d[0],
+        o_e           /* inline */,
+    );
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    metadata.format.inst_port_style = InstPortStyle::Explicit;
+    assert_eq!(format(&metadata, code), explicit);
+    assert_eq!(format(&metadata, implicit), explicit);
+
+    metadata.format.inst_port_style = InstPortStyle::Implicit;
+    assert_eq!(format(&metadata, code), implicit);
+    assert_eq!(format(&metadata, explicit), implicit);
+}
+
+#[test]
+fn empty_construct() {
+    let code = r#"module ModuleA (
+) {
+}
+module ModuleB #( ) ( ) {   }
+module ModuleC (
+    // port comment
+) {
+    // body comment
+}
+module ModuleD {
+    inst u0: ModuleA (
+    );
+    inst u1: ModuleA (
+        // instance comment
+    );
+    inst u2: ModuleB #(
+    ) ( );
+    inst u3: ModuleB #(
+        // parameter comment
+    ) ();
+    inst u4: ModuleA;
+}
+interface InterfaceA #(
+) {
+}
+package PackageA {
+}
+"#;
+
+    let expect = r#"module ModuleA () {}
+module ModuleB #() () {}
+module ModuleC (
+    // port comment
+) {
+    // body comment
+}
+module ModuleD {
+    inst u0: ModuleA ();
+    inst u1: ModuleA (
+        // instance comment
+    );
+    inst u2: ModuleB #() ();
+    inst u3: ModuleB #(
+        // parameter comment
+    ) ();
+    inst u4: ModuleA;
+}
+interface InterfaceA #() {}
+package PackageA {}
+"#;
+
+    let metadata = create_metadata(false, false);
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+}
+
+#[test]
+fn assignment_align_max_lhs() {
+    let code = r#"module ModuleA {
+    assign a = 1;
+    assign bb = 1;
+    assign mem[write_ptr].valid = 1;
+    assign ccc = 1;
+
+    always_comb {
+        x = 1;
+        mem[write_ptr].data += 1;
+        yy -= 1;
+    }
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    assign a   = 1;
+    assign bb  = 1;
+    assign mem[write_ptr].valid = 1;
+    assign ccc = 1;
+
+    always_comb {
+        x  =  1;
+        mem[write_ptr].data += 1;
+        yy -= 1;
+    }
+}
+"#;
+
+    let mut metadata = create_metadata(false, false);
+    metadata.format.assignment_align_max_lhs = 10;
+    assert_eq!(format(&metadata, code), expect);
+    assert_eq!(format(&metadata, expect), expect);
+
+    let expect = r#"module ModuleA {
+    assign a                    = 1;
+    assign bb                   = 1;
+    assign mem[write_ptr].valid = 1;
+    assign ccc                  = 1;
+
+    always_comb {
+        x                   =  1;
+        mem[write_ptr].data += 1;
+        yy                  -= 1;
+    }
+}
+"#;
+
+    metadata.format.assignment_align_max_lhs = 0;
+    assert_eq!(format(&metadata, code), expect);
+}
+
+#[test]
+fn split_joined_items() {
+    let code = r#"module ModuleA {
+    var a: logic; /* comment for a */ var bbbb: logic<2>; // comment for bbbb
+    let c: logic = 1; assign a = 1;
+
+    always_comb {
+        a = 1; bbbb = 2; // trailing comment
+    }
+    always_ff { if c { a = 1; } }
+}
+package PkgA {
+    const A: u32 = 1; const BB: u32 = 2;
+}
+"#;
+
+    let expect = r#"module ModuleA {
+    var a   : logic   ; /* comment for a */
+    var bbbb: logic<2>; // comment for bbbb
+    let c   : logic    = 1;
+    assign a    = 1;
+
+    always_comb {
+        a    = 1;
+        bbbb = 2; // trailing comment
+    }
+    always_ff {
+        if c {
+            a = 1;
+        }
+    }
+}
+package PkgA {
+    const A : u32 = 1;
+    const BB: u32 = 2;
+}
+"#;
+
+    let metadata = create_metadata(false, false);
+    let ret = format(&metadata, code);
+    assert_eq!(ret, expect);
+    assert_eq!(format(&metadata, &ret), expect);
+}
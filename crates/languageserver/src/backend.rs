@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use crate::code_lens::RUN_TEST;
 use crate::server::{semantic_legend, MsgFromServer, MsgToServer, Server, ServerConfigItem};
 use async_channel::{unbounded, Receiver, Sender};
 use serde_json::Value;
@@ -49,7 +50,16 @@ impl Backend {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _params: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let code_lens_refresh = params
+            .capabilities
+            .workspace
+            .and_then(|x| x.code_lens)
+            .and_then(|x| x.refresh_support)
+            .unwrap_or(false);
+        let x = ServerConfigItem::CodeLensRefresh(code_lens_refresh);
+        self.send(MsgToServer::DidChangeConfiguration(x)).await;
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
@@ -116,6 +126,15 @@ impl LanguageServer for Backend {
                         },
                     ),
                 ),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![RUN_TEST.to_string()],
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
                     trigger_characters: Some(
@@ -171,6 +190,11 @@ impl LanguageServer for Backend {
                     format!("did_change_watched_files: {change:?}"),
                 )
                 .await;
+
+            if change.typ != FileChangeType::DELETED {
+                let url = change.uri;
+                self.send(MsgToServer::DidChangeWatchedFile { url }).await;
+            }
         }
     }
 
@@ -181,6 +205,18 @@ impl LanguageServer for Backend {
                     let x = ServerConfigItem::UseOperatorCompletion(*x);
                     self.send(MsgToServer::DidChangeConfiguration(x)).await;
                 }
+                if let Some(Value::Bool(x)) = x.get("inlayHintImplicitPort") {
+                    let x = ServerConfigItem::InlayHintImplicitPort(*x);
+                    self.send(MsgToServer::DidChangeConfiguration(x)).await;
+                }
+                if let Some(Value::Bool(x)) = x.get("inlayHintWidth") {
+                    let x = ServerConfigItem::InlayHintWidth(*x);
+                    self.send(MsgToServer::DidChangeConfiguration(x)).await;
+                }
+                if let Some(Value::Bool(x)) = x.get("inlayHintParameterDefault") {
+                    let x = ServerConfigItem::InlayHintParameterDefault(*x);
+                    self.send(MsgToServer::DidChangeConfiguration(x)).await;
+                }
             }
         }
     }
@@ -336,6 +372,64 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let url = params.text_document.uri;
+        let start_line = params.range.start.line as usize + 1;
+        let end_line = params.range.end.line as usize + 1;
+
+        self.send(MsgToServer::InlayHint {
+            url,
+            start_line,
+            end_line,
+        })
+        .await;
+
+        if let Some(MsgFromServer::InlayHint(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let url = params.text_document.uri;
+        let line = params.range.start.line as usize + 1;
+        let column = params.range.start.character as usize + 1;
+
+        self.send(MsgToServer::CodeAction { url, line, column })
+            .await;
+
+        if let Some(MsgFromServer::CodeAction(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let url = params.text_document.uri;
+
+        self.send(MsgToServer::CodeLens { url }).await;
+
+        if let Some(MsgFromServer::CodeLens(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
+        if params.command == RUN_TEST {
+            if let [Value::String(url), Value::String(name)] = params.arguments.as_slice() {
+                if let Ok(url) = Url::from_str(url) {
+                    let name = name.clone();
+                    self.send(MsgToServer::RunTest { url, name }).await;
+                }
+            }
+        }
+        Ok(None)
+    }
+
     async fn shutdown(&self) -> Result<()> {
         Ok(())
     }
@@ -0,0 +1,58 @@
+use crate::server::to_location;
+use serde_json::json;
+use tower_lsp::lsp_types::{CodeLens, Command};
+use veryl_analyzer::symbol::SymbolKind;
+use veryl_analyzer::symbol_table;
+use veryl_parser::resource_table::PathId;
+
+pub const SHOW_REFERENCES: &str = "editor.action.showReferences";
+pub const RUN_TEST: &str = "veryl.runTest";
+
+/// Returns code lenses of the file.
+///
+/// The following lenses are collected:
+/// * the number of instantiations above each module, which shows the instances by click
+/// * "Run test" above each test, which runs the test through `veryl.runTest` command
+pub fn code_lenses(path: PathId) -> Vec<CodeLens> {
+    let mut symbols: Vec<_> = symbol_table::get_all()
+        .into_iter()
+        .filter(|x| x.token.source == path)
+        .collect();
+    symbols.sort_by_key(|x| x.token.id);
+
+    let mut ret = Vec::new();
+    for symbol in symbols {
+        let location = to_location(&symbol.token);
+        let command = match symbol.kind {
+            SymbolKind::Module(_) => {
+                // instantiations are collected as references like find-references
+                let references: Vec<_> = symbol.references.iter().map(to_location).collect();
+                let title = match references.len() {
+                    1 => "1 instantiation".to_string(),
+                    x => format!("{x} instantiations"),
+                };
+                Command {
+                    title,
+                    command: SHOW_REFERENCES.to_string(),
+                    arguments: Some(vec![
+                        json!(location.uri),
+                        json!(location.range.start),
+                        json!(references),
+                    ]),
+                }
+            }
+            SymbolKind::Test(_) => Command {
+                title: "Run test".to_string(),
+                command: RUN_TEST.to_string(),
+                arguments: Some(vec![json!(location.uri), json!(symbol.token.to_string())]),
+            },
+            _ => continue,
+        };
+        ret.push(CodeLens {
+            range: location.range,
+            command: Some(command),
+            data: None,
+        });
+    }
+    ret
+}
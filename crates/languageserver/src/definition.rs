@@ -0,0 +1,47 @@
+use veryl_analyzer::namespace_table;
+use veryl_analyzer::symbol::{Symbol, SymbolKind};
+use veryl_analyzer::symbol_path::SymbolPath;
+use veryl_analyzer::symbol_table;
+use veryl_parser::veryl_grammar_trait::Veryl;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Finder;
+
+/// Returns the declaration token of the identifier at the specified position.
+///
+/// A hierarchical or scoped identifier is resolved up to the element under the cursor,
+/// so `bus` and `awvalid` in `bus.awvalid` jump to the different declarations.
+pub fn find_definition(veryl: &Veryl, line: u32, column: u32) -> Option<Token> {
+    let mut finder = Finder::new();
+    finder.line = line;
+    finder.column = column;
+    finder.veryl(veryl);
+
+    let token = finder.token?;
+    let namespace = namespace_table::get(token.id)?;
+
+    let path = if finder.token_group.is_empty() {
+        SymbolPath::new(&[token.text])
+    } else {
+        let group = &finder.token_group;
+        let len = group
+            .iter()
+            .position(|x| x.id == token.id)
+            .map(|x| x + 1)
+            .unwrap_or(group.len());
+        SymbolPath::from(&group[..len])
+    };
+
+    let symbol = symbol_table::resolve((&path, &namespace)).ok()?;
+    Some(definition_token(&symbol.found))
+}
+
+fn definition_token(symbol: &Symbol) -> Token {
+    match &symbol.kind {
+        // Jump to the generic declaration instead of the specialized copy
+        SymbolKind::GenericInstance(x) => symbol_table::get(x.base)
+            .map(|x| definition_token(&x))
+            .unwrap_or(symbol.token),
+        _ => symbol.token,
+    }
+}
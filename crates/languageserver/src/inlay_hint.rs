@@ -0,0 +1,206 @@
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position};
+use veryl_analyzer::evaluator::{Evaluated, Evaluator};
+use veryl_analyzer::symbol::{ParameterKind, SymbolKind};
+use veryl_analyzer::symbol_table;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenRange};
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::Stringifier;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InlayHintConfig {
+    pub implicit_port: bool,
+    pub width: bool,
+    pub parameter_default: bool,
+}
+
+impl Default for InlayHintConfig {
+    fn default() -> Self {
+        Self {
+            implicit_port: true,
+            width: true,
+            parameter_default: true,
+        }
+    }
+}
+
+/// InlayHintFinder collects inlay hints in the specified line range.
+///
+/// The following hints are collected:
+/// * the signal bound by an implicit port connection like `clk,`
+/// * the evaluated value of a width including non-literal expressions like `logic<W>`
+/// * the default value of a parameter omitted at an instantiation
+pub struct InlayHintFinder {
+    pub hints: Vec<InlayHint>,
+    start_line: u32,
+    end_line: u32,
+    config: InlayHintConfig,
+}
+
+impl InlayHintFinder {
+    /// `start_line` and `end_line` are 1-based and inclusive
+    pub fn new(start_line: u32, end_line: u32, config: InlayHintConfig) -> Self {
+        Self {
+            hints: Vec::new(),
+            start_line,
+            end_line,
+            config,
+        }
+    }
+
+    fn in_range(&self, token: &Token) -> bool {
+        self.start_line <= token.line && token.line <= self.end_line
+    }
+
+    fn push(&mut self, position: Position, label: String, kind: InlayHintKind) {
+        self.hints.push(InlayHint {
+            position,
+            label: InlayHintLabel::String(label),
+            kind: Some(kind),
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(kind == InlayHintKind::PARAMETER),
+            padding_right: None,
+            data: None,
+        });
+    }
+
+    fn implicit_port(&mut self, arg: &InstPortItem) {
+        let token = &arg.identifier.identifier_token.token;
+        if arg.inst_port_item_opt.is_some() || !self.in_range(token) {
+            return;
+        }
+
+        if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+            let (prefix, suffix) = match symbol.found.kind {
+                SymbolKind::Port(ref x) => (x.prefix.clone(), x.suffix.clone()),
+                SymbolKind::Variable(ref x) => (x.prefix.clone(), x.suffix.clone()),
+                _ => return,
+            };
+            let label = format!(
+                ": {}{}{}",
+                prefix.unwrap_or_default(),
+                symbol.found.token,
+                suffix.unwrap_or_default()
+            );
+            self.push(end_position(token), label, InlayHintKind::PARAMETER);
+        }
+    }
+
+    fn parameter_default(&mut self, arg: &InstDeclaration) {
+        let (position, connected) = if let Some(ref x) = arg.inst_declaration_opt0 {
+            let x = &x.inst_parameter;
+            let connected: Vec<InstParameterItem> = x
+                .inst_parameter_opt
+                .as_ref()
+                .map(|x| x.inst_parameter_list.as_ref().into())
+                .unwrap_or_default();
+            let connected: Vec<_> = connected
+                .iter()
+                .map(|x| x.identifier.identifier_token.token.text)
+                .collect();
+            (x.r_paren.r_paren_token.token, connected)
+        } else {
+            let range: TokenRange = arg.scoped_identifier.as_ref().into();
+            (range.end, Vec::new())
+        };
+        if !self.in_range(&position) {
+            return;
+        }
+        let position = if arg.inst_declaration_opt0.is_some() {
+            start_position(&position)
+        } else {
+            end_position(&position)
+        };
+
+        let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) else {
+            return;
+        };
+        let SymbolKind::Module(ref module) = symbol.found.kind else {
+            return;
+        };
+
+        for parameter in &module.parameters {
+            let property = parameter.property();
+            if matches!(property.kind, ParameterKind::Const) || connected.contains(&parameter.name)
+            {
+                continue;
+            }
+
+            // the evaluated default may refer other parameters overridden at the instantiation
+            let evaluated = if connected.is_empty() || is_literal(&property.value) {
+                Evaluator::new().expression(&property.value)
+            } else {
+                Evaluated::Unknown
+            };
+            let value = match evaluated {
+                Evaluated::Fixed { value, .. } => value.to_string(),
+                _ => {
+                    let mut stringifier = Stringifier::new();
+                    stringifier.expression(&property.value);
+                    stringifier.as_str().to_string()
+                }
+            };
+            let label = format!("{}: {}", parameter.name, value);
+            self.push(position, label, InlayHintKind::PARAMETER);
+        }
+    }
+}
+
+fn start_position(token: &Token) -> Position {
+    Position::new(token.line - 1, token.column - 1)
+}
+
+fn end_position(token: &Token) -> Position {
+    Position::new(token.line - 1, token.column - 1 + token.length)
+}
+
+fn is_literal(arg: &Expression) -> bool {
+    matches!(expression_factor(arg), Some(Factor::Number(_)))
+}
+
+impl VerylWalker for InlayHintFinder {
+    /// Semantic action for non-terminal 'Width'
+    fn width(&mut self, arg: &Width) {
+        let token = &arg.r_angle.r_angle_token.token;
+        if !self.config.width || !self.in_range(token) {
+            return;
+        }
+
+        let mut expressions = vec![arg.expression.as_ref()];
+        for x in &arg.width_list {
+            expressions.push(x.expression.as_ref());
+        }
+        if expressions.iter().all(|x| is_literal(x)) {
+            return;
+        }
+
+        let mut values = Vec::new();
+        for x in expressions {
+            if let Evaluated::Fixed { value, .. } = Evaluator::new().expression(x) {
+                values.push(value.to_string());
+            } else {
+                return;
+            }
+        }
+        let label = format!("= {}", values.join(", "));
+        self.push(end_position(token), label, InlayHintKind::TYPE);
+    }
+
+    /// Semantic action for non-terminal 'InstDeclaration'
+    fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        if self.config.parameter_default {
+            self.parameter_default(arg);
+        }
+        if self.config.implicit_port {
+            if let Some(ref x) = arg.inst_declaration_opt1 {
+                if let Some(ref x) = x.inst_declaration_opt2 {
+                    let ports: Vec<InstPortItem> = x.inst_port_list.as_ref().into();
+                    for port in &ports {
+                        self.implicit_port(port);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::VerylWalker;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstListKind {
+    Parameter,
+    Port,
+}
+
+#[derive(Clone, Debug)]
+pub struct InstContext {
+    pub kind: InstListKind,
+    pub identifier: Token,
+    pub scoped_identifier: ScopedIdentifier,
+    pub connected: Vec<Token>,
+}
+
+#[derive(Clone, Copy)]
+enum Event {
+    Identifier,
+    Colon,
+    Comma,
+}
+
+/// InstFinder finds the parameter or port list of an instance declaration at the cursor.
+///
+/// The context is found only if the cursor is at the position of a connection name,
+/// and the connection under the cursor is excluded from the connected list.
+#[derive(Default)]
+pub struct InstFinder {
+    pub line: u32,
+    pub column: u32,
+    pub context: Option<InstContext>,
+    events: Vec<(Token, Event)>,
+    in_list: bool,
+}
+
+impl InstFinder {
+    pub fn new(line: u32, column: u32) -> Self {
+        Self {
+            line,
+            column,
+            ..Default::default()
+        }
+    }
+
+    fn is_after(&self, token: &Token) -> bool {
+        (token.line, token.column) < (self.line, self.column)
+    }
+
+    fn is_before(&self, token: &Token) -> bool {
+        (self.line, self.column) <= (token.line, token.column)
+    }
+
+    fn is_touched(&self, token: &Token) -> bool {
+        token.line == self.line
+            && token.column <= self.column
+            && self.column <= token.column + token.length
+    }
+
+    fn push_event(&mut self, token: &Token, event: Event) {
+        if self.in_list {
+            self.events.push((*token, event));
+        }
+    }
+
+    fn set_context<T>(
+        &mut self,
+        kind: InstListKind,
+        arg: &InstDeclaration,
+        list: &T,
+        f: fn(&mut Self, &T),
+    ) {
+        self.events.clear();
+        self.in_list = true;
+        f(self, list);
+        self.in_list = false;
+
+        let mut connected = Vec::new();
+        let mut name_position = true;
+        for (token, event) in &self.events {
+            let after = self.is_after(token);
+            match event {
+                Event::Identifier => {
+                    if after {
+                        name_position = true;
+                    }
+                    if !self.is_touched(token) {
+                        connected.push(*token);
+                    }
+                }
+                Event::Colon if after => name_position = false,
+                Event::Comma if after => name_position = true,
+                _ => (),
+            }
+        }
+
+        if name_position {
+            self.context = Some(InstContext {
+                kind,
+                identifier: arg.identifier.identifier_token.token,
+                scoped_identifier: arg.scoped_identifier.as_ref().clone(),
+                connected,
+            });
+        }
+    }
+}
+
+impl VerylWalker for InstFinder {
+    /// Semantic action for non-terminal 'InstDeclaration'
+    fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        if let Some(ref x) = arg.inst_declaration_opt0 {
+            let x = &x.inst_parameter;
+            if self.is_after(&x.l_paren.l_paren_token.token)
+                && self.is_before(&x.r_paren.r_paren_token.token)
+            {
+                match x.inst_parameter_opt {
+                    Some(ref x) => self.set_context(
+                        InstListKind::Parameter,
+                        arg,
+                        x.inst_parameter_list.as_ref(),
+                        Self::inst_parameter_list,
+                    ),
+                    None => self.set_context(InstListKind::Parameter, arg, &(), |_, _| ()),
+                }
+            }
+        }
+        if let Some(ref x) = arg.inst_declaration_opt1 {
+            if self.is_after(&x.l_paren.l_paren_token.token)
+                && self.is_before(&x.r_paren.r_paren_token.token)
+            {
+                match x.inst_declaration_opt2 {
+                    Some(ref x) => self.set_context(
+                        InstListKind::Port,
+                        arg,
+                        x.inst_port_list.as_ref(),
+                        Self::inst_port_list,
+                    ),
+                    None => self.set_context(InstListKind::Port, arg, &(), |_, _| ()),
+                }
+            }
+        }
+    }
+
+    /// Semantic action for non-terminal 'InstParameterItem'
+    fn inst_parameter_item(&mut self, arg: &InstParameterItem) {
+        let token = arg.identifier.identifier_token.token;
+        self.push_event(&token, Event::Identifier);
+        if let Some(ref x) = arg.inst_parameter_item_opt {
+            self.push_event(&x.colon.colon_token.token, Event::Colon);
+        }
+    }
+
+    /// Semantic action for non-terminal 'InstPortItem'
+    fn inst_port_item(&mut self, arg: &InstPortItem) {
+        let token = arg.identifier.identifier_token.token;
+        self.push_event(&token, Event::Identifier);
+        if let Some(ref x) = arg.inst_port_item_opt {
+            self.push_event(&x.colon.colon_token.token, Event::Colon);
+        }
+    }
+
+    /// Semantic action for non-terminal 'Comma'
+    fn comma(&mut self, arg: &Comma) {
+        self.push_event(&arg.comma_token.token, Event::Comma);
+    }
+}
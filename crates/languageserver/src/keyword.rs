@@ -2,12 +2,15 @@ pub const KEYWORDS: &[&str] = &[
     "always_comb",
     "always_ff",
     "assign",
+    "assert",
+    "assume",
     "as",
     "bit",
     "case",
     "clock",
     "clock_posedge",
     "clock_negedge",
+    "cover",
     "const",
     "default",
     "else",
@@ -42,6 +45,7 @@ pub const KEYWORDS: &[&str] = &[
     "outside",
     "package",
     "param",
+    "property",
     "proto",
     "pub",
     "ref",
@@ -1,7 +1,12 @@
 #![recursion_limit = "256"]
 
 mod backend;
+mod code_lens;
+mod definition;
+mod inlay_hint;
+mod inst_finder;
 mod keyword;
+mod manifest;
 mod server;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,443 @@
+use ropey::Rope;
+use std::path::Path;
+use std::str::FromStr;
+use tower_lsp::lsp_types::*;
+use veryl_metadata::{Metadata, MetadataError, Workspace};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValueKind {
+    Bool,
+    Enum(&'static [&'static str]),
+    Other,
+}
+
+const CASE: &[&str] = &["snake", "screaming_snake", "upper_camel", "lower_camel"];
+const LINT_SEVERITY: &[&str] = &["allow", "warn", "deny"];
+
+const NAMING_TARGETS: &[&str] = &[
+    "clock_domain",
+    "enum",
+    "enum_member",
+    "function",
+    "function_inout",
+    "function_input",
+    "function_output",
+    "function_ref",
+    "instance",
+    "interface",
+    "modport",
+    "module",
+    "package",
+    "parameter",
+    "port_clock",
+    "port_inout",
+    "port_input",
+    "port_modport",
+    "port_output",
+    "port_reset",
+    "reg",
+    "struct",
+    "union",
+    "var",
+    "wire",
+];
+
+const PROJECT: &[(&str, ValueKind)] = &[
+    ("name", ValueKind::Other),
+    ("version", ValueKind::Other),
+    ("authors", ValueKind::Other),
+    ("description", ValueKind::Other),
+    ("license", ValueKind::Other),
+    ("repository", ValueKind::Other),
+];
+
+const BUILD: &[(&str, ValueKind)] = &[
+    ("clock_type", ValueKind::Enum(&["posedge", "negedge"])),
+    (
+        "reset_type",
+        ValueKind::Enum(&["async_low", "async_high", "sync_low", "sync_high"]),
+    ),
+    ("clock_posedge_prefix", ValueKind::Other),
+    ("clock_posedge_suffix", ValueKind::Other),
+    ("clock_negedge_prefix", ValueKind::Other),
+    ("clock_negedge_suffix", ValueKind::Other),
+    ("reset_high_prefix", ValueKind::Other),
+    ("reset_high_suffix", ValueKind::Other),
+    ("reset_low_prefix", ValueKind::Other),
+    ("reset_low_suffix", ValueKind::Other),
+    ("escape_sv_keyword", ValueKind::Bool),
+    (
+        "filelist_type",
+        ValueKind::Enum(&["absolute", "relative", "flgen"]),
+    ),
+    ("target", ValueKind::Other),
+    (
+        "output_layout",
+        ValueKind::Enum(&["flat", "mirror", "per_module"]),
+    ),
+    (
+        "implicit_parameter_types",
+        ValueKind::Enum(&["u32", "u64", "i32", "i64", "f32", "f64", "string", "type"]),
+    ),
+    ("omit_project_prefix", ValueKind::Bool),
+    ("strip_comments", ValueKind::Bool),
+    ("strip_assertions", ValueKind::Bool),
+    ("sourcemap_target", ValueKind::Other),
+    ("expand_inside_operation", ValueKind::Bool),
+    ("exclude_std", ValueKind::Bool),
+    ("emit_cond_type", ValueKind::Bool),
+    ("emit_generate_keyword", ValueKind::Bool),
+    ("expand_inout", ValueKind::Bool),
+    ("inout_input_suffix", ValueKind::Other),
+    ("inout_output_suffix", ValueKind::Other),
+    ("inout_enable_suffix", ValueKind::Other),
+    ("flatten_struct_ports", ValueKind::Bool),
+    ("copy_init_files", ValueKind::Bool),
+    ("reorder_consts", ValueKind::Bool),
+    ("enum_style", ValueKind::Enum(&["typedef", "localparam"])),
+    ("enum_keep_pragma", ValueKind::Other),
+    ("emit_line_directives", ValueKind::Bool),
+    ("default_nettype", ValueKind::Other),
+    ("timescale", ValueKind::Other),
+    ("headers", ValueKind::Enum(&["c", "rust"])),
+    ("header_path", ValueKind::Other),
+    ("top", ValueKind::Other),
+    ("defines", ValueKind::Other),
+    ("profiles", ValueKind::Other),
+];
+
+const BUILD_TARGET: &[(&str, ValueKind)] = &[
+    ("type", ValueKind::Enum(&["source", "directory", "bundle"])),
+    ("path", ValueKind::Other),
+];
+
+const BUILD_HOOKS: &[(&str, ValueKind)] = &[
+    ("pre_build", ValueKind::Other),
+    ("post_emit", ValueKind::Other),
+    ("post_build", ValueKind::Other),
+];
+
+const BUILD_SOURCEMAP_TARGET: &[(&str, ValueKind)] = &[
+    ("type", ValueKind::Enum(&["target", "directory", "none"])),
+    ("path", ValueKind::Other),
+];
+
+const SOURCES: &[(&str, ValueKind)] = &[("exclude", ValueKind::Other)];
+
+const FORMAT: &[(&str, ValueKind)] = &[
+    ("indent_width", ValueKind::Other),
+    ("sort_ports", ValueKind::Bool),
+    ("sort_inst_ports", ValueKind::Bool),
+    ("max_width", ValueKind::Other),
+    ("break_before_operator", ValueKind::Bool),
+    ("else_style", ValueKind::Enum(&["cuddled", "newline"])),
+    ("brace_style", ValueKind::Enum(&["same_line", "next_line"])),
+    ("comment_breaks_align_group", ValueKind::Bool),
+    ("assignment_align_max_lhs", ValueKind::Other),
+    ("continuation_style", ValueKind::Enum(&["brace", "indent"])),
+    (
+        "inst_port_style",
+        ValueKind::Enum(&["keep", "explicit", "implicit"]),
+    ),
+    ("exclude", ValueKind::Other),
+];
+
+const LINT: &[(&str, ValueKind)] = &[
+    ("allow", ValueKind::Other),
+    ("warn", ValueKind::Other),
+    ("deny", ValueKind::Other),
+    ("max_comb_depth", ValueKind::Other),
+    ("max_for_iterations", ValueKind::Other),
+    (
+        "require_reset",
+        ValueKind::Enum(&["none", "control", "all"]),
+    ),
+    (
+        "synthesis_subset",
+        ValueKind::Enum(&["strict", "relaxed", "off"]),
+    ),
+    ("redundant_width", ValueKind::Bool),
+    ("prefer_let", ValueKind::Bool),
+    ("unused_module", ValueKind::Bool),
+];
+
+const LINT_MAGIC_NUMBER: &[(&str, ValueKind)] = &[
+    ("enable", ValueKind::Bool),
+    ("allow_values", ValueKind::Other),
+    ("allow_contexts", ValueKind::Other),
+];
+
+const PUBLISH: &[(&str, ValueKind)] = &[
+    ("bump_commit", ValueKind::Bool),
+    ("publish_commit", ValueKind::Bool),
+    ("bump_commit_message", ValueKind::Other),
+    ("publish_commit_message", ValueKind::Other),
+];
+
+const DOC: &[(&str, ValueKind)] = &[("path", ValueKind::Other)];
+
+const TEST: &[(&str, ValueKind)] = &[
+    (
+        "simulator",
+        ValueKind::Enum(&["verilator", "vcs", "vivado", "questa"]),
+    ),
+    ("waveform_target", ValueKind::Other),
+];
+
+const TEST_WAVEFORM_TARGET: &[(&str, ValueKind)] = &[
+    ("type", ValueKind::Enum(&["target", "directory"])),
+    ("path", ValueKind::Other),
+];
+
+const TEST_SIMULATOR: &[(&str, ValueKind)] = &[
+    ("compile_args", ValueKind::Other),
+    ("simulate_args", ValueKind::Other),
+];
+
+const TEST_VIVADO: &[(&str, ValueKind)] = &[
+    ("compile_args", ValueKind::Other),
+    ("elaborate_args", ValueKind::Other),
+    ("simulate_args", ValueKind::Other),
+];
+
+const FEATURES: &[(&str, ValueKind)] = &[("default", ValueKind::Other)];
+
+const TABLES: &[&str] = &[
+    "project",
+    "sources",
+    "build",
+    "build.hooks",
+    "format",
+    "lint",
+    "lint.naming",
+    "lint.severity",
+    "lint.magic_number",
+    "publish",
+    "doc",
+    "test",
+    "test.questa",
+    "test.vcs",
+    "test.verilator",
+    "test.vivado",
+    "features",
+    "dependencies",
+];
+
+pub fn is_manifest(path: &Path) -> bool {
+    path.file_name().is_some_and(|x| x == "Veryl.toml")
+}
+
+/// Returns known keys of the table specified by `path` like `lint.naming`
+fn keys(path: &str) -> Vec<(String, ValueKind)> {
+    let table = match path {
+        "project" => PROJECT,
+        "sources" => SOURCES,
+        "build" => BUILD,
+        "build.target" => BUILD_TARGET,
+        "build.hooks" => BUILD_HOOKS,
+        "build.sourcemap_target" => BUILD_SOURCEMAP_TARGET,
+        x if x.starts_with("build.profiles.") => BUILD,
+        "format" => FORMAT,
+        "lint" => LINT,
+        "lint.magic_number" => LINT_MAGIC_NUMBER,
+        "lint.naming" => {
+            let mut ret = Vec::new();
+            for target in NAMING_TARGETS {
+                ret.push((format!("case_{target}"), ValueKind::Enum(CASE)));
+            }
+            for prefix in ["prefix", "suffix", "re_forbidden", "re_required"] {
+                for target in NAMING_TARGETS {
+                    ret.push((format!("{prefix}_{target}"), ValueKind::Other));
+                }
+            }
+            return ret;
+        }
+        "publish" => PUBLISH,
+        "doc" => DOC,
+        "test" => TEST,
+        "test.waveform_target" => TEST_WAVEFORM_TARGET,
+        "test.questa" | "test.vcs" | "test.verilator" => TEST_SIMULATOR,
+        "test.vivado" => TEST_VIVADO,
+        "features" => FEATURES,
+        _ => &[],
+    };
+    table.iter().map(|(x, y)| (x.to_string(), *y)).collect()
+}
+
+fn value_kind(table: &str, key: &str) -> ValueKind {
+    if table == "lint.severity" {
+        return ValueKind::Enum(LINT_SEVERITY);
+    }
+    keys(table)
+        .into_iter()
+        .find(|(x, _)| x == key)
+        .map(|(_, x)| x)
+        .unwrap_or(ValueKind::Other)
+}
+
+fn to_position(rope: &Rope, offset: usize) -> Position {
+    let offset = offset.min(rope.len_bytes());
+    let line = rope.byte_to_line(offset);
+    let column = offset - rope.line_to_byte(line);
+    Position::new(line as u32, column as u32)
+}
+
+/// Returns the range of the value assigned to `key`
+fn find_value(text: &str, rope: &Rope, key: &str) -> Option<Range> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if let Some(rest) = line.trim_start().strip_prefix(key) {
+            if let Some(value) = rest.trim_start().strip_prefix('=') {
+                let value = value.trim();
+                let beg = offset + line.find(value).unwrap_or(0);
+                let end = beg + value.len();
+                return Some(Range::new(to_position(rope, beg), to_position(rope, end)));
+            }
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Loads the manifest and returns diagnostics of it
+pub fn check(path: &Path, text: &str) -> (Option<Metadata>, Vec<Diagnostic>) {
+    let rope = Rope::from_str(text);
+
+    let ret = if Workspace::is_manifest(text) {
+        // Workspace manifest is checked only its syntax because it has no project
+        Workspace::from_str(text).map(|_| None)
+    } else {
+        Metadata::load_from_str(path, text).map(Some)
+    };
+    let err = match ret {
+        Ok(x) => return (x, vec![]),
+        Err(x) => x,
+    };
+
+    let (range, message) = match &err {
+        MetadataError::Deserialize(x) => {
+            let range = x.span().map_or(Range::default(), |x| {
+                Range::new(to_position(&rope, x.start), to_position(&rope, x.end))
+            });
+            (range, x.message().to_string())
+        }
+        MetadataError::InvalidProjectName(_) => (
+            find_value(text, &rope, "name").unwrap_or_default(),
+            err.to_string(),
+        ),
+        MetadataError::InvalidLicense(x) => (
+            find_value(text, &rope, "license").unwrap_or_default(),
+            format!("{err}: {x}"),
+        ),
+        _ => (Range::default(), err.to_string()),
+    };
+
+    let code = miette::Diagnostic::code(&err).map(|x| NumberOrString::String(format!("{x}")));
+    let diag = Diagnostic::new(
+        range,
+        Some(DiagnosticSeverity::ERROR),
+        code,
+        Some(String::from("veryl-ls")),
+        format!("Manifest Error: {message}"),
+        None,
+        None,
+    );
+
+    (None, vec![diag])
+}
+
+/// Returns the table path which the line belongs to
+fn current_table(lines: &[&str]) -> String {
+    for line in lines.iter().rev() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            let table = line.trim_start_matches('[');
+            let table = table.split(']').next().unwrap_or("");
+            return table
+                .split('.')
+                .map(|x| x.trim())
+                .collect::<Vec<_>>()
+                .join(".");
+        }
+    }
+    String::new()
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+pub fn completion(text: &str, line: usize, column: usize) -> Vec<CompletionItem> {
+    let lines: Vec<_> = text.lines().collect();
+    let current = lines.get(line - 1).copied().unwrap_or("");
+    let prefix: String = current.chars().take(column - 1).collect();
+    let mut table = current_table(&lines[..(line - 1).min(lines.len())]);
+
+    let trimmed = prefix.trim_start();
+    if trimmed.starts_with('[') {
+        return TABLES
+            .iter()
+            .map(|x| CompletionItem {
+                label: x.to_string(),
+                kind: Some(CompletionItemKind::MODULE),
+                ..Default::default()
+            })
+            .collect();
+    }
+
+    if let Some(eq) = prefix.rfind('=') {
+        // inline tables like `target = {type = "directory"}`
+        let key_part = &prefix[..eq];
+        let mut path: Vec<_> = key_part
+            .split('{')
+            .map(|x| {
+                let x = x.trim_end().trim_end_matches('=').trim_end();
+                let x = x.rsplit(|c| !is_key_char(c)).next().unwrap_or("");
+                x.to_string()
+            })
+            .collect();
+        let key = path.pop().unwrap_or_default();
+        for x in path.into_iter().filter(|x| !x.is_empty()) {
+            if !table.is_empty() {
+                table.push('.');
+            }
+            table.push_str(&x);
+        }
+
+        let quoted = prefix[eq..].matches('"').count() % 2 == 1;
+        let kind = value_kind(&table, &key);
+        let values: Vec<&str> = match kind {
+            ValueKind::Bool => vec!["true", "false"],
+            ValueKind::Enum(x) => x.to_vec(),
+            ValueKind::Other => vec![],
+        };
+
+        values
+            .into_iter()
+            .map(|x| {
+                let insert_text = if kind == ValueKind::Bool || quoted {
+                    x.to_string()
+                } else {
+                    format!("\"{x}\"")
+                };
+                CompletionItem {
+                    label: x.to_string(),
+                    kind: Some(CompletionItemKind::ENUM_MEMBER),
+                    insert_text: Some(insert_text),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    } else {
+        keys(&table)
+            .into_iter()
+            .map(|(x, _)| CompletionItem {
+                label: x.clone(),
+                kind: Some(CompletionItemKind::PROPERTY),
+                detail: Some(format!("[{table}]")),
+                insert_text: Some(format!("{x} = ")),
+                ..Default::default()
+            })
+            .collect()
+    }
+}
@@ -1,19 +1,29 @@
+use crate::code_lens::code_lenses;
+use crate::definition::find_definition;
+use crate::inlay_hint::{InlayHintConfig, InlayHintFinder};
+use crate::inst_finder::{InstContext, InstFinder, InstListKind};
 use crate::keyword::KEYWORDS;
+use crate::manifest;
 use async_channel::{Receiver, Sender};
 use dashmap::DashMap;
 use futures::executor::block_on;
 use ropey::Rope;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
 use tower_lsp::lsp_types::*;
 use tower_lsp::Client;
 use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::refactor::{self, PortConnection, RefactorError};
 use veryl_analyzer::symbol::SymbolKind as VerylSymbolKind;
-use veryl_analyzer::symbol::{Symbol, TypeKind};
+use veryl_analyzer::symbol::{ParameterKind, Symbol, TypeKind};
 use veryl_analyzer::symbol_path::SymbolPath;
-use veryl_analyzer::{namespace_table, symbol_table, Analyzer, AnalyzerError};
+use veryl_analyzer::{cdc_table, namespace_table, symbol_table, Analyzer, AnalyzerError};
 use veryl_formatter::Formatter;
 use veryl_metadata::Metadata;
+use veryl_parser::resource_table::StrId;
 use veryl_parser::veryl_token::Token;
 use veryl_parser::veryl_walker::VerylWalker;
 use veryl_parser::{resource_table, Finder, Parser, ParserError};
@@ -31,6 +41,9 @@ pub enum MsgToServer {
         version: i32,
     },
     DidChangeConfiguration(ServerConfigItem),
+    DidChangeWatchedFile {
+        url: Url,
+    },
     WillRenameFile {
         old_url: Url,
         // new_uri: String, // This is not used currently
@@ -71,6 +84,23 @@ pub enum MsgToServer {
     Formatting {
         url: Url,
     },
+    InlayHint {
+        url: Url,
+        start_line: usize,
+        end_line: usize,
+    },
+    CodeAction {
+        url: Url,
+        line: usize,
+        column: usize,
+    },
+    CodeLens {
+        url: Url,
+    },
+    RunTest {
+        url: Url,
+        name: String,
+    },
 }
 
 pub enum MsgFromServer {
@@ -81,6 +111,9 @@ pub enum MsgFromServer {
     References(Vec<Location>),
     SemanticTokens(Option<SemanticTokensResult>),
     Formatting(Option<Vec<TextEdit>>),
+    InlayHint(Option<Vec<InlayHint>>),
+    CodeAction(Option<CodeActionResponse>),
+    CodeLens(Option<Vec<CodeLens>>),
 }
 
 pub struct BackgroundTask {
@@ -92,17 +125,28 @@ pub struct BackgroundTask {
 
 pub enum ServerConfigItem {
     UseOperatorCompletion(bool),
+    InlayHintImplicitPort(bool),
+    InlayHintWidth(bool),
+    InlayHintParameterDefault(bool),
+    CodeLensRefresh(bool),
 }
 
 #[derive(Default)]
 pub struct ServerConfig {
     use_operator_completion: bool,
+    inlay_hint: InlayHintConfig,
+    /// Whether the client supports `workspace/codeLens/refresh`
+    code_lens_refresh: bool,
 }
 
 impl ServerConfig {
     pub fn set(&mut self, item: ServerConfigItem) {
         match item {
             ServerConfigItem::UseOperatorCompletion(x) => self.use_operator_completion = x,
+            ServerConfigItem::InlayHintImplicitPort(x) => self.inlay_hint.implicit_port = x,
+            ServerConfigItem::InlayHintWidth(x) => self.inlay_hint.width = x,
+            ServerConfigItem::InlayHintParameterDefault(x) => self.inlay_hint.parameter_default = x,
+            ServerConfigItem::CodeLensRefresh(x) => self.code_lens_refresh = x,
         }
     }
 }
@@ -114,6 +158,7 @@ pub struct Server {
     document_map: DashMap<PathBuf, Rope>,
     parser_map: DashMap<PathBuf, Parser>,
     metadata_map: DashMap<PathBuf, Metadata>,
+    manifest_map: DashMap<PathBuf, Metadata>,
     cache_dir: PathBuf,
     lsp_token: i32,
     background_tasks: VecDeque<BackgroundTask>,
@@ -131,6 +176,7 @@ impl Server {
             document_map: DashMap::new(),
             parser_map: DashMap::new(),
             metadata_map: DashMap::new(),
+            manifest_map: DashMap::new(),
             cache_dir: veryl_path::cache_path(),
             lsp_token: 0,
             background_tasks: VecDeque::new(),
@@ -153,6 +199,7 @@ impl Server {
                         self.latest_change = Some((url, text, version));
                     }
                     MsgToServer::DidChangeConfiguration(x) => self.config.set(x),
+                    MsgToServer::DidChangeWatchedFile { url } => self.did_change_watched_file(&url),
                     MsgToServer::WillRenameFile { old_url } => self.on_remove(old_url),
                     MsgToServer::DidRenameFile { new_url } => self.did_rename_files(new_url),
                     MsgToServer::WillDeleteFile { url } => self.on_remove(url),
@@ -172,6 +219,16 @@ impl Server {
                     }
                     MsgToServer::SemanticTokens { url } => self.semantic_tokens(&url),
                     MsgToServer::Formatting { url } => self.formatting(&url),
+                    MsgToServer::InlayHint {
+                        url,
+                        start_line,
+                        end_line,
+                    } => self.inlay_hint(&url, start_line, end_line),
+                    MsgToServer::CodeAction { url, line, column } => {
+                        self.code_action(&url, line, column)
+                    }
+                    MsgToServer::CodeLens { url } => self.code_lens(&url),
+                    MsgToServer::RunTest { url, name } => self.run_test(&url, &name),
                 }
             }
 
@@ -211,9 +268,14 @@ impl Server {
 
 impl Server {
     fn did_open(&mut self, url: &Url, text: &str, version: i32) {
+        if is_manifest(url) {
+            self.on_manifest_change(url, text, Some(version));
+            return;
+        }
+
         if let Some(mut metadata) = self.get_metadata(url) {
             self.background_done = false;
-            self.on_change(&metadata.project.name, url, text, version);
+            self.on_change(&metadata.project.name, url, text, Some(version));
 
             if let Ok(path) = url.to_file_path() {
                 if !path.starts_with(&self.cache_dir) {
@@ -236,15 +298,28 @@ impl Server {
                 self.background_done = true;
             }
         } else {
-            self.on_change("", url, text, version);
+            self.on_change("", url, text, Some(version));
         }
     }
 
     fn did_change(&mut self, url: &Url, text: &str, version: i32) {
-        if let Some(metadata) = self.get_metadata(url) {
-            self.on_change(&metadata.project.name, url, text, version);
+        if is_manifest(url) {
+            self.on_manifest_change(url, text, Some(version));
+        } else if let Some(metadata) = self.get_metadata(url) {
+            self.on_change(&metadata.project.name, url, text, Some(version));
         } else {
-            self.on_change("", url, text, version);
+            self.on_change("", url, text, Some(version));
+        }
+    }
+
+    fn did_change_watched_file(&mut self, url: &Url) {
+        // Opened manifests are tracked through did_change
+        if let Ok(path) = url.to_file_path() {
+            if is_manifest(url) && !self.document_map.contains_key(&path) {
+                if let Ok(text) = std::fs::read_to_string(&path) {
+                    self.on_manifest_change(url, &text, None);
+                }
+            }
         }
     }
 
@@ -291,7 +366,13 @@ impl Server {
         column: usize,
         context: &Option<CompletionContext>,
     ) {
-        let ret = if let Some(context) = context {
+        let ret = if is_manifest(url) {
+            let path = url.to_file_path().unwrap();
+            self.document_map.get(&path).map(|rope| {
+                let items = manifest::completion(&rope.to_string(), line, column);
+                CompletionResponse::Array(items)
+            })
+        } else if let Some(context) = context {
             match context.trigger_kind {
                 CompletionTriggerKind::TRIGGER_CHARACTER => {
                     let trigger = context.trigger_character.as_ref().unwrap();
@@ -311,13 +392,17 @@ impl Server {
                     }
                 }
                 CompletionTriggerKind::INVOKED => {
-                    let mut items = if let Some(metadata) = self.get_metadata(url) {
-                        completion_symbol(&metadata, url, line, column)
+                    if let Some(items) = self.completion_inst(url, line, column) {
+                        Some(CompletionResponse::Array(items))
                     } else {
-                        vec![]
-                    };
-                    items.append(&mut completion_keyword(line, column));
-                    Some(CompletionResponse::Array(items))
+                        let mut items = if let Some(metadata) = self.get_metadata(url) {
+                            completion_symbol(&metadata, url, line, column)
+                        } else {
+                            vec![]
+                        };
+                        items.append(&mut completion_keyword(line, column));
+                        Some(CompletionResponse::Array(items))
+                    }
                 }
                 _ => None,
             }
@@ -330,29 +415,28 @@ impl Server {
             .unwrap();
     }
 
+    fn completion_inst(
+        &mut self,
+        url: &Url,
+        line: usize,
+        column: usize,
+    ) -> Option<Vec<CompletionItem>> {
+        let path = url.to_file_path().ok()?;
+        let parser = self.parser_map.get(&path)?;
+        let mut finder = InstFinder::new(line as u32, column as u32);
+        finder.veryl(&parser.veryl);
+        finder.context.map(|x| completion_inst_item(&x))
+    }
+
     fn goto_definition(&mut self, url: &Url, line: usize, column: usize) {
         if let Ok(path) = url.to_file_path() {
             if let Some(parser) = self.parser_map.get(&path) {
-                let mut finder = Finder::new();
-                finder.line = line as u32;
-                finder.column = column as u32;
-                finder.veryl(&parser.veryl);
-
-                if let Some(token) = finder.token {
-                    if let Some(namespace) = namespace_table::get(token.id) {
-                        let path = if finder.token_group.is_empty() {
-                            SymbolPath::new(&[token.text])
-                        } else {
-                            SymbolPath::from(finder.token_group.as_slice())
-                        };
-                        if let Ok(symbol) = symbol_table::resolve((&path, &namespace)) {
-                            let location = to_location(&symbol.found.token);
-                            self.snd
-                                .send_blocking(MsgFromServer::GotoDefinition(Some(location)))
-                                .unwrap();
-                            return;
-                        }
-                    }
+                if let Some(token) = find_definition(&parser.veryl, line as u32, column as u32) {
+                    let location = to_location(&token);
+                    self.snd
+                        .send_blocking(MsgFromServer::GotoDefinition(Some(location)))
+                        .unwrap();
+                    return;
                 }
             }
         }
@@ -570,6 +654,75 @@ impl Server {
             .send_blocking(MsgFromServer::Formatting(None))
             .unwrap();
     }
+
+    fn inlay_hint(&mut self, url: &Url, start_line: usize, end_line: usize) {
+        let mut ret = None;
+
+        if let Ok(path) = url.to_file_path() {
+            if let Some(parser) = self.parser_map.get(&path) {
+                let mut finder = InlayHintFinder::new(
+                    start_line as u32,
+                    end_line as u32,
+                    self.config.inlay_hint,
+                );
+                finder.veryl(&parser.veryl);
+                ret = Some(finder.hints);
+            }
+        }
+
+        self.snd
+            .send_blocking(MsgFromServer::InlayHint(ret))
+            .unwrap();
+    }
+
+    fn code_lens(&mut self, url: &Url) {
+        let ret = url
+            .to_file_path()
+            .ok()
+            .and_then(resource_table::get_path_id)
+            .map(code_lenses);
+
+        self.snd
+            .send_blocking(MsgFromServer::CodeLens(ret))
+            .unwrap();
+    }
+
+    /// Runs `veryl test` for the test in background, and streams its output as progress
+    fn run_test(&mut self, url: &Url, name: &str) {
+        let Some(metadata) = self.get_metadata(url) else {
+            return;
+        };
+
+        self.progress_start(&format!("test {name}"));
+        let token = self.lsp_token;
+        let client = self.client.clone();
+        let mut command = veryl_command();
+        command
+            .args(["test", "--filter", name])
+            .current_dir(metadata.project_path());
+        let name = name.to_string();
+
+        std::thread::spawn(move || {
+            let message = match run_command(command, |x| {
+                let report = WorkDoneProgressReport {
+                    cancellable: Some(false),
+                    message: Some(x.to_string()),
+                    percentage: None,
+                };
+                send_progress(&client, token, WorkDoneProgress::Report(report));
+            }) {
+                Ok(true) => (MessageType::INFO, format!("test {name} passed")),
+                Ok(false) => (MessageType::ERROR, format!("test {name} failed")),
+                Err(x) => (MessageType::ERROR, format!("test {name} can't be run: {x}")),
+            };
+
+            let end = WorkDoneProgressEnd {
+                message: Some(message.1.clone()),
+            };
+            send_progress(&client, token, WorkDoneProgress::End(end));
+            block_on(client.show_message(message.0, message.1));
+        });
+    }
 }
 
 impl Server {
@@ -600,35 +753,132 @@ impl Server {
     }
 
     fn progress_report(&self, msg: &str, pcnt: u32) {
-        let token = NumberOrString::Number(self.lsp_token);
         let report = WorkDoneProgressReport {
             cancellable: Some(false),
             message: Some(msg.to_string()),
             percentage: Some(pcnt),
         };
-
-        block_on(
-            self.client
-                .send_notification::<notification::Progress>(ProgressParams {
-                    token,
-                    value: ProgressParamsValue::WorkDone(WorkDoneProgress::Report(report)),
-                }),
+        send_progress(
+            &self.client,
+            self.lsp_token,
+            WorkDoneProgress::Report(report),
         );
     }
 
     fn progress_done(&self, msg: &str) {
-        let token = NumberOrString::Number(self.lsp_token);
         let end = WorkDoneProgressEnd {
             message: Some(msg.to_string()),
         };
+        send_progress(&self.client, self.lsp_token, WorkDoneProgress::End(end));
+    }
 
-        block_on(
-            self.client
-                .send_notification::<notification::Progress>(ProgressParams {
-                    token,
-                    value: ProgressParamsValue::WorkDone(WorkDoneProgress::End(end)),
-                }),
-        );
+    fn code_action(&mut self, url: &Url, line: usize, column: usize) {
+        let mut ret = Vec::new();
+
+        if let Some(symbol) = self.declaration_at(url, line, column) {
+            let packages = symbol_table::get_all().into_iter().filter(|x| {
+                matches!(x.kind, VerylSymbolKind::Package(_))
+                    && x.namespace.paths.first() == symbol.namespace.paths.first()
+                    && x.inner_namespace() != symbol.namespace
+            });
+
+            for package in packages {
+                let title = format!("Move \"{}\" to package \"{}\"", symbol.token, package.token);
+                let action =
+                    match refactor::move_symbol(symbol.id, package.id, |x| self.get_source(x)) {
+                        Ok(edits) => CodeAction {
+                            title,
+                            kind: Some(CodeActionKind::new("refactor.move")),
+                            edit: Some(workspace_edit(edits)),
+                            ..Default::default()
+                        },
+                        Err(RefactorError::NotMovable { .. }) => break,
+                        Err(err) => CodeAction {
+                            title,
+                            kind: Some(CodeActionKind::new("refactor.move")),
+                            disabled: Some(CodeActionDisabled {
+                                reason: err.to_string(),
+                            }),
+                            ..Default::default()
+                        },
+                    };
+                ret.push(CodeActionOrCommand::CodeAction(action));
+            }
+        }
+
+        let path = url.to_file_path().unwrap();
+        if let (Some(parser), Some(rope)) =
+            (self.parser_map.get(&path), self.document_map.get(&path))
+        {
+            let text = rope.to_string();
+            let pos = refactor::Position::new(line as u32, column as u32);
+            let actions = [
+                (
+                    "Add missing port connections",
+                    CodeActionKind::QUICKFIX,
+                    PortConnection::Explicit,
+                ),
+                (
+                    "Connect all missing ports to signals of same name",
+                    CodeActionKind::REFACTOR_REWRITE,
+                    PortConnection::Implicit,
+                ),
+            ];
+            for (title, kind, style) in actions {
+                if let Some(edits) = refactor::connect_ports(&parser.veryl, &text, pos, style) {
+                    let action = CodeAction {
+                        title: title.to_string(),
+                        kind: Some(kind),
+                        edit: Some(workspace_edit(edits)),
+                        ..Default::default()
+                    };
+                    ret.push(CodeActionOrCommand::CodeAction(action));
+                }
+            }
+
+            if let Some(edits) = refactor::remove_unused_imports(&parser.veryl, &text) {
+                let action = CodeAction {
+                    title: "Remove unused imports".to_string(),
+                    kind: Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS),
+                    edit: Some(workspace_edit(edits)),
+                    ..Default::default()
+                };
+                ret.push(CodeActionOrCommand::CodeAction(action));
+            }
+        }
+
+        let ret = if ret.is_empty() { None } else { Some(ret) };
+        self.snd
+            .send_blocking(MsgFromServer::CodeAction(ret))
+            .unwrap();
+    }
+
+    /// Returns the symbol whose declaration is at the position
+    fn declaration_at(&self, url: &Url, line: usize, column: usize) -> Option<Symbol> {
+        let path = url.to_file_path().ok()?;
+        let parser = self.parser_map.get(&path)?;
+        let mut finder = Finder::new();
+        finder.line = line as u32;
+        finder.column = column as u32;
+        finder.veryl(&parser.veryl);
+        let token = finder.token?;
+        let namespace = namespace_table::get(token.id)?;
+        let path = SymbolPath::new(&[token.text]);
+        let symbol = symbol_table::resolve((&path, &namespace)).ok()?.found;
+        if symbol.token.source == token.source && symbol.token.pos == token.pos {
+            Some(symbol)
+        } else {
+            None
+        }
+    }
+
+    fn get_source(&self, path: veryl_parser::resource_table::PathId) -> Option<String> {
+        let path = resource_table::get_path_value(path)?;
+        if let Some(rope) = self.document_map.get(&path) {
+            Some(rope.to_string())
+        } else {
+            std::fs::read_to_string(&path).ok()
+        }
     }
 
     fn background_analyze(&self, path: &PathSet, metadata: &Metadata) {
@@ -658,7 +908,11 @@ impl Server {
             if let Some(metadata) = self.metadata_map.get(&path) {
                 return Some(metadata.to_owned());
             } else if let Ok(metadata_path) = Metadata::search_from(&path) {
-                if let Ok(metadata) = Metadata::load(metadata_path) {
+                let metadata_path = metadata_path.canonicalize().unwrap_or(metadata_path);
+                if let Some(metadata) = self.manifest_map.get(&metadata_path) {
+                    self.metadata_map.insert(path, metadata.clone());
+                    return Some(metadata.to_owned());
+                } else if let Ok(metadata) = Metadata::load(metadata_path) {
                     self.metadata_map.insert(path, metadata.clone());
                     return Some(metadata);
                 }
@@ -667,7 +921,7 @@ impl Server {
         None
     }
 
-    fn on_change(&mut self, prj: &str, url: &Url, text: &str, version: i32) {
+    fn on_change(&mut self, prj: &str, url: &Url, text: &str, version: Option<i32>) {
         if let Ok(path) = url.to_file_path() {
             let rope = Rope::from_str(text);
 
@@ -683,6 +937,7 @@ impl Server {
                         {
                             symbol_table::drop(path);
                             namespace_table::drop(path);
+                            cdc_table::drop(path);
                         }
                         let analyzer = Analyzer::new(&metadata);
                         let mut errors = analyzer.analyze_pass1(prj, text, &path, &x.veryl);
@@ -718,10 +973,13 @@ impl Server {
                     }
                 };
 
-                block_on(
-                    self.client
-                        .publish_diagnostics(url.clone(), diag, Some(version)),
-                );
+                block_on(self.client.publish_diagnostics(url.clone(), diag, version));
+
+                // instantiation counts may be changed by the edit
+                if self.config.code_lens_refresh {
+                    let client = self.client.clone();
+                    std::thread::spawn(move || block_on(client.code_lens_refresh()));
+                }
             } else {
                 block_on(
                     self.client
@@ -733,16 +991,77 @@ impl Server {
         }
     }
 
+    fn on_manifest_change(&mut self, url: &Url, text: &str, version: Option<i32>) {
+        let Ok(path) = url.to_file_path() else {
+            return;
+        };
+        let metadata_path = path.canonicalize().unwrap_or(path.clone());
+
+        let (metadata, diag) = manifest::check(&metadata_path, text);
+        block_on(self.client.publish_diagnostics(url.clone(), diag, version));
+        self.document_map.insert(path, Rope::from_str(text));
+
+        let Some(mut metadata) = metadata else {
+            return;
+        };
+
+        // Skip re-analysis if the change doesn't affect any option (e.g. comments)
+        let updated = serde_json::to_value(&metadata).ok();
+        let current = self
+            .manifest_map
+            .insert(metadata_path.clone(), metadata.clone())
+            .and_then(|x| serde_json::to_value(&x).ok());
+        if current.is_some() && current == updated {
+            return;
+        }
+
+        self.metadata_map
+            .retain(|_, x| x.metadata_path != metadata_path);
+
+        if let Ok(paths) = metadata.paths::<&str>(&[], true) {
+            let total = paths.len();
+            let task = BackgroundTask {
+                metadata: metadata.clone(),
+                paths,
+                total,
+                progress: false,
+            };
+            self.background_tasks.push_back(task);
+            self.background_done = false;
+        }
+
+        let project_path = metadata.project_path();
+        let documents: Vec<_> = self
+            .document_map
+            .iter()
+            .filter(|x| {
+                x.key().starts_with(&project_path)
+                    && x.key().extension().is_some_and(|x| x == "veryl")
+            })
+            .map(|x| (x.key().clone(), x.value().to_string()))
+            .collect();
+        for (path, text) in documents {
+            if let Ok(url) = Url::from_file_path(path) {
+                self.on_change(&metadata.project.name, &url, &text, None);
+            }
+        }
+    }
+
     fn on_remove(&mut self, path: Url) {
         if let Ok(path) = path.to_file_path() {
             if let Some(path_id) = resource_table::get_path_id(Path::new(&path).to_path_buf()) {
                 symbol_table::drop(path_id);
                 namespace_table::drop(path_id);
+                cdc_table::drop(path_id);
             }
         }
     }
 }
 
+fn is_manifest(url: &Url) -> bool {
+    url.to_file_path().is_ok_and(|x| manifest::is_manifest(&x))
+}
+
 fn to_diag(err: miette::ErrReport, rope: &Rope) -> Diagnostic {
     let miette_diag: &dyn miette::Diagnostic = err.as_ref();
 
@@ -803,7 +1122,52 @@ fn to_diag(err: miette::ErrReport, rope: &Rope) -> Diagnostic {
     )
 }
 
-fn to_location(token: &Token) -> Location {
+fn send_progress(client: &Client, token: i32, value: WorkDoneProgress) {
+    block_on(
+        client.send_notification::<notification::Progress>(ProgressParams {
+            token: NumberOrString::Number(token),
+            value: ProgressParamsValue::WorkDone(value),
+        }),
+    );
+}
+
+/// Returns `veryl` command placed with `veryl-ls`, or the one in PATH
+fn veryl_command() -> Command {
+    let name = format!("veryl{}", std::env::consts::EXE_SUFFIX);
+    let path = std::env::current_exe()
+        .ok()
+        .map(|x| x.with_file_name(&name))
+        .filter(|x| x.exists())
+        .unwrap_or_else(|| PathBuf::from(name));
+    Command::new(path)
+}
+
+/// Runs the command and calls `f` for each line of stdout and stderr.
+/// Returns whether the command succeeded.
+fn run_command<F: FnMut(&str)>(mut command: Command, mut f: F) -> std::io::Result<bool> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let (tx, rx) = mpsc::channel();
+    let forward = |x: Box<dyn Read + Send>, tx: mpsc::Sender<String>| {
+        std::thread::spawn(move || {
+            for line in BufReader::new(x).lines().map_while(Result::ok) {
+                let _ = tx.send(line);
+            }
+        })
+    };
+    forward(Box::new(child.stdout.take().unwrap()), tx.clone());
+    forward(Box::new(child.stderr.take().unwrap()), tx);
+
+    for line in rx {
+        f(&line);
+    }
+    Ok(child.wait()?.success())
+}
+
+pub fn to_location(token: &Token) -> Location {
     let line = token.line - 1;
     let column = token.column - 1;
     let length = token.length;
@@ -815,6 +1179,23 @@ fn to_location(token: &Token) -> Location {
     Location { uri, range }
 }
 
+fn workspace_edit(edits: Vec<refactor::TextEdit>) -> WorkspaceEdit {
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for edit in edits {
+        let path = resource_table::get_path_value(edit.path).unwrap();
+        let url = Url::from_file_path(path).unwrap();
+        let range = Range::new(
+            Position::new(edit.beg.line - 1, edit.beg.column - 1),
+            Position::new(edit.end.line - 1, edit.end.column - 1),
+        );
+        changes
+            .entry(url)
+            .or_default()
+            .push(TextEdit::new(range, edit.text));
+    }
+    WorkspaceEdit::new(changes)
+}
+
 fn completion_item_operator(
     line: usize,
     column: usize,
@@ -982,6 +1363,83 @@ fn completion_member(url: &Url, line: usize, column: usize, text: &str) -> Vec<C
     items
 }
 
+fn completion_inst_item(context: &InstContext) -> Vec<CompletionItem> {
+    let mut items = Vec::new();
+
+    let symbol = if let Ok(x) = symbol_table::resolve(&context.scoped_identifier) {
+        x.found
+    } else {
+        return items;
+    };
+    let symbol = if let VerylSymbolKind::GenericInstance(ref x) = symbol.kind {
+        symbol_table::get(x.base).unwrap()
+    } else {
+        symbol
+    };
+    let (parameters, ports) = match symbol.kind {
+        VerylSymbolKind::Module(ref x) => (x.parameters.clone(), x.ports.clone()),
+        VerylSymbolKind::ProtoModule(ref x) => (x.parameters.clone(), x.ports.clone()),
+        VerylSymbolKind::Interface(ref x) => (x.parameters.clone(), vec![]),
+        _ => return items,
+    };
+
+    let connected: Vec<_> = context.connected.iter().map(|x| x.text).collect();
+    let namespace = namespace_table::get(context.identifier.id).unwrap();
+    let implicit = |name: StrId| symbol_table::resolve((&vec![name], &namespace)).is_ok();
+
+    // (name, symbol, required)
+    let candidates: Vec<_> = match context.kind {
+        InstListKind::Parameter => parameters
+            .iter()
+            .filter(|x| matches!(x.property().kind, ParameterKind::Param))
+            .map(|x| (x.name, x.symbol, false))
+            .collect(),
+        InstListKind::Port => ports
+            .iter()
+            .map(|x| (x.name(), x.symbol, x.property().default_value.is_none()))
+            .collect(),
+    };
+
+    for (i, (name, id, required)) in candidates.into_iter().enumerate() {
+        if connected.contains(&name) {
+            continue;
+        }
+
+        let symbol = symbol_table::get(id).unwrap();
+        let insert_text = if implicit(name) {
+            format!("{name}, ")
+        } else {
+            format!("{name}: ")
+        };
+        let kind = match context.kind {
+            InstListKind::Parameter => CompletionItemKind::CONSTANT,
+            InstListKind::Port => CompletionItemKind::FIELD,
+        };
+        let documentation = if !symbol.doc_comment.is_empty() {
+            let content = MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: symbol.doc_comment.format(false),
+            };
+            Some(Documentation::MarkupContent(content))
+        } else {
+            None
+        };
+
+        let item = CompletionItem {
+            label: name.to_string(),
+            kind: Some(kind),
+            detail: Some(format!("{}", symbol.kind)),
+            documentation,
+            sort_text: Some(format!("{}_{:04}", if required { 0 } else { 1 }, i)),
+            insert_text: Some(insert_text),
+            ..Default::default()
+        };
+        items.push(item);
+    }
+
+    items
+}
+
 fn completion_symbol(
     metadata: &Metadata,
     url: &Url,
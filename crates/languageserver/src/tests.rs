@@ -1,3 +1,8 @@
+use crate::code_lens::{code_lenses, RUN_TEST, SHOW_REFERENCES};
+use crate::definition::find_definition;
+use crate::inlay_hint::{InlayHintConfig, InlayHintFinder};
+use crate::inst_finder::{InstContext, InstFinder, InstListKind};
+use crate::manifest;
 use crate::Backend;
 use serde_json::{json, Value};
 use std::collections::VecDeque;
@@ -7,11 +12,18 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
 use tower_lsp::jsonrpc::{Id, Request, Response};
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
+use veryl_analyzer::{symbol_table, Analyzer};
+use veryl_metadata::Metadata;
+use veryl_parser::veryl_token::TokenSource;
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_parser::{resource_table, Parser};
 
 struct TestServer {
     req_stream: DuplexStream,
     res_stream: DuplexStream,
     responses: VecDeque<String>,
+    /// Received text which doesn't form a complete message yet
+    pending: String,
 }
 
 impl TestServer {
@@ -31,6 +43,7 @@ impl TestServer {
             req_stream: req_client,
             res_stream: res_client,
             responses: VecDeque::new(),
+            pending: String::new(),
         }
     }
 
@@ -38,12 +51,12 @@ impl TestServer {
         format!("Content-Length: {}\r\n\r\n{}", payload.len(), payload)
     }
 
-    fn decode(text: &str) -> Vec<String> {
+    /// Returns complete messages in `text` and the rest
+    fn decode(text: &str) -> (Vec<String>, &str) {
         let mut ret = Vec::new();
         let mut temp = text;
 
-        while !temp.is_empty() {
-            let p = temp.find("\r\n\r\n").unwrap();
+        while let Some(p) = temp.find("\r\n\r\n") {
             let (header, body) = temp.split_at(p + 4);
             let len = header
                 .strip_prefix("Content-Length: ")
@@ -51,12 +64,30 @@ impl TestServer {
                 .strip_suffix("\r\n\r\n")
                 .unwrap();
             let len: usize = len.parse().unwrap();
+            if body.len() < len {
+                break;
+            }
             let (body, rest) = body.split_at(len);
             ret.push(body.to_string());
             temp = rest;
         }
 
-        ret
+        (ret, temp)
+    }
+
+    async fn recv_message(&mut self) -> String {
+        while self.responses.is_empty() {
+            let mut buf = vec![0; 1024];
+            let n = self.res_stream.read(&mut buf).await.unwrap();
+            self.pending
+                .push_str(std::str::from_utf8(&buf[..n]).unwrap());
+            let (messages, rest) = Self::decode(&self.pending);
+            self.pending = rest.to_string();
+            for x in messages {
+                self.responses.push_front(x);
+            }
+        }
+        self.responses.pop_back().unwrap()
     }
 
     async fn send_request(&mut self, req: Request) {
@@ -73,28 +104,12 @@ impl TestServer {
     }
 
     async fn recv_response(&mut self) -> Response {
-        if self.responses.is_empty() {
-            let mut buf = vec![0; 1024];
-            let n = self.res_stream.read(&mut buf).await.unwrap();
-            let ret = String::from_utf8(buf[..n].to_vec()).unwrap();
-            for x in Self::decode(&ret) {
-                self.responses.push_front(x);
-            }
-        }
-        let res = self.responses.pop_back().unwrap();
+        let res = self.recv_message().await;
         serde_json::from_str(&res).unwrap()
     }
 
     async fn recv_notification(&mut self) -> Request {
-        if self.responses.is_empty() {
-            let mut buf = vec![0; 1024];
-            let n = self.res_stream.read(&mut buf).await.unwrap();
-            let ret = String::from_utf8(buf[..n].to_vec()).unwrap();
-            for x in Self::decode(&ret) {
-                self.responses.push_front(x);
-            }
-        }
-        let res = self.responses.pop_back().unwrap();
+        let res = self.recv_message().await;
         serde_json::from_str(&res).unwrap()
     }
 }
@@ -113,14 +128,18 @@ fn build_initialized() -> Request {
 }
 
 fn build_did_open(text: &str) -> Request {
+    build_did_open_file("test.veryl", "veryl", text)
+}
+
+fn build_did_open_file(name: &str, language_id: &str, text: &str) -> Request {
     let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
     path.pop();
     path.pop();
-    path.push("test.veryl");
+    path.push(name);
     let uri = Url::from_file_path(path).unwrap();
     let text_document = TextDocumentItem {
         uri,
-        language_id: "veryl".to_string(),
+        language_id: language_id.to_string(),
         version: 0,
         text: text.to_string(),
     };
@@ -244,3 +263,344 @@ async fn progress() {
     }
     assert_eq!(percentage, 100);
 }
+
+#[tokio::test]
+async fn manifest_diagnostics() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let res = server.recv_response().await;
+    assert!(res.is_ok());
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let text = r#"[project]
+name = "a"
+version = "0.1.0"
+
+[build]
+implicit_parameter_types = ["u32", "bool"]
+"#;
+    let req = build_did_open_file("Veryl.toml", "toml", text);
+    server.send_request(req).await;
+
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+    assert_eq!(res.params().unwrap()["message"], "did_open");
+
+    let res = server.recv_notification().await;
+    dbg!(&res);
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+    let diags = res.params().unwrap()["diagnostics"].as_array().unwrap();
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0]["range"]["start"]["line"], Value::from(5));
+    assert_eq!(diags[0]["range"]["start"]["character"], Value::from(35));
+    assert_eq!(diags[0]["range"]["end"]["line"], Value::from(5));
+    assert_eq!(diags[0]["range"]["end"]["character"], Value::from(41));
+}
+
+fn manifest_completion(text: &str, line: usize, column: usize) -> Vec<(String, String)> {
+    manifest::completion(text, line, column)
+        .into_iter()
+        .map(|x| (x.label, x.insert_text.unwrap_or_default()))
+        .collect()
+}
+
+#[test]
+fn manifest_completion_key_value() {
+    let code = r#"[build]
+clock_type = "posedge"
+
+[lint.naming]
+"#;
+
+    let items = manifest_completion(code, 2, 1);
+    assert!(items.contains(&("reset_type".to_string(), "reset_type = ".to_string())));
+    assert!(!items.contains(&("case_enum".to_string(), "case_enum = ".to_string())));
+
+    let items = manifest_completion(code, 5, 1);
+    assert!(items.contains(&("case_enum".to_string(), "case_enum = ".to_string())));
+
+    let code = r#"[build]
+implicit_parameter_types = ["u32", "
+target = {type = 
+exclude_std = 
+"#;
+
+    let items = manifest_completion(code, 2, 38);
+    assert!(items.contains(&("string".to_string(), "string".to_string())));
+    assert!(items.contains(&("type".to_string(), "type".to_string())));
+
+    let items = manifest_completion(code, 3, 18);
+    assert_eq!(
+        items,
+        vec![
+            ("source".to_string(), "\"source\"".to_string()),
+            ("directory".to_string(), "\"directory\"".to_string()),
+            ("bundle".to_string(), "\"bundle\"".to_string()),
+        ]
+    );
+
+    let items = manifest_completion(code, 4, 15);
+    assert_eq!(
+        items,
+        vec![
+            ("true".to_string(), "true".to_string()),
+            ("false".to_string(), "false".to_string()),
+        ]
+    );
+}
+
+fn find_inst(code: &str, line: u32, column: u32) -> Option<InstContext> {
+    let parser = Parser::parse(code, &"").unwrap();
+    let mut finder = InstFinder::new(line, column);
+    finder.veryl(&parser.veryl);
+    finder.context
+}
+
+fn connected(context: &InstContext) -> Vec<String> {
+    context.connected.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn inst_finder() {
+    let code = "module A {\n    inst u: B #(X: 1, ) (a: x, b, c);\n}";
+
+    let context = find_inst(code, 2, 22).unwrap();
+    assert_eq!(context.kind, InstListKind::Parameter);
+    assert_eq!(connected(&context), vec!["X"]);
+
+    assert!(find_inst(code, 2, 29).is_none());
+
+    let context = find_inst(code, 2, 33).unwrap();
+    assert_eq!(context.kind, InstListKind::Port);
+    assert_eq!(connected(&context), vec!["a", "c"]);
+
+    let context = find_inst(code, 2, 34).unwrap();
+    assert_eq!(context.kind, InstListKind::Port);
+    assert_eq!(connected(&context), vec!["a", "b", "c"]);
+
+    assert!(find_inst(code, 2, 10).is_none());
+}
+
+fn find_inlay_hint(code: &str, config: InlayHintConfig) -> Vec<(u32, u32, String)> {
+    symbol_table::clear();
+
+    let metadata: Metadata = Metadata::create_default_toml("prj")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let parser = Parser::parse(code, &"").unwrap();
+    let analyzer = Analyzer::new(&metadata);
+    analyzer.analyze_pass1(&"prj", code, &"", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+
+    let mut finder = InlayHintFinder::new(1, u32::MAX, config);
+    finder.veryl(&parser.veryl);
+    finder
+        .hints
+        .iter()
+        .map(|x| {
+            let InlayHintLabel::String(ref label) = x.label else {
+                unreachable!()
+            };
+            (x.position.line, x.position.character, label.clone())
+        })
+        .collect()
+}
+
+#[test]
+fn inlay_hint() {
+    let code = r#"module A #(
+    param W: u32 = 8,
+) (
+    clk: input clock,
+) {
+    var a: logic<W * 2>;
+    var b: logic<2>;
+    inst u: B (clk);
+    inst v: B #(X: 2) (clk: clk);
+}
+module B #(
+    param X: u32 = 4,
+    param Y: u32 = X + 1,
+) (
+    clk: input clock,
+) {}"#;
+
+    let hints = find_inlay_hint(code, InlayHintConfig::default());
+    assert_eq!(
+        hints,
+        vec![
+            (5, 23, "= 16".to_string()),
+            (7, 13, "X: 4".to_string()),
+            (7, 13, "Y: 5".to_string()),
+            (7, 18, ": clk".to_string()),
+            (8, 20, "Y: X+1".to_string()),
+        ]
+    );
+
+    let config = InlayHintConfig {
+        implicit_port: false,
+        width: true,
+        parameter_default: false,
+    };
+    let hints = find_inlay_hint(code, config);
+    assert_eq!(hints, vec![(5, 23, "= 16".to_string())]);
+}
+
+#[test]
+fn code_lens() {
+    symbol_table::clear();
+
+    let code = r#"module Top {
+    inst u0: Sub;
+    inst u1: Sub;
+}
+module Sub {}
+#[test(test1)]
+embed (inline) sv{{{
+    module test1;
+    endmodule
+}}}"#;
+
+    let metadata: Metadata = Metadata::create_default_toml("prj")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let path = PathBuf::from("/code_lens.veryl");
+    let parser = Parser::parse(code, &path).unwrap();
+    let analyzer = Analyzer::new(&metadata);
+    analyzer.analyze_pass1("prj", code, &path, &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", code, &path, &parser.veryl);
+
+    let lenses: Vec<_> = code_lenses(resource_table::get_path_id(&path).unwrap())
+        .into_iter()
+        .map(|x| {
+            let command = x.command.unwrap();
+            (
+                x.range.start.line,
+                command.title,
+                command.command,
+                command.arguments.unwrap(),
+            )
+        })
+        .collect();
+
+    assert_eq!(lenses.len(), 3);
+    assert_eq!(lenses[0].0, 0);
+    assert_eq!(lenses[0].1, "0 instantiations");
+    assert_eq!(lenses[1].0, 4);
+    assert_eq!(lenses[1].1, "2 instantiations");
+    assert_eq!(lenses[1].2, SHOW_REFERENCES);
+    assert_eq!(lenses[1].3[2].as_array().unwrap().len(), 2);
+    assert_eq!(lenses[2].0, 5);
+    assert_eq!(lenses[2].1, "Run test");
+    assert_eq!(lenses[2].2, RUN_TEST);
+    assert_eq!(lenses[2].3[1], json!("test1"));
+}
+
+fn goto_definition(files: &[(&str, &str)], line: u32, column: u32) -> Option<(String, u32, u32)> {
+    symbol_table::clear();
+
+    let metadata: Metadata = Metadata::create_default_toml("prj")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let analyzer = Analyzer::new(&metadata);
+    let parsers: Vec<_> = files
+        .iter()
+        .map(|(path, code)| {
+            let parser = Parser::parse(code, path).unwrap();
+            analyzer.analyze_pass1(&"prj", code, path, &parser.veryl);
+            parser
+        })
+        .collect();
+    Analyzer::analyze_post_pass1();
+
+    // The cursor is placed at the last file
+    let token = find_definition(&parsers.last().unwrap().veryl, line, column)?;
+    let TokenSource::File(path) = token.source else {
+        unreachable!()
+    };
+    let path = resource_table::get_path_value(path).unwrap();
+    Some((path.to_string_lossy().to_string(), token.line, token.column))
+}
+
+const DEFINITION_BUS: &str = r#"interface BusIf {
+    var awvalid: logic;
+    modport master {
+        awvalid: output,
+    }
+}"#;
+
+const DEFINITION_PKG: &str = r#"package PkgA {
+    enum EnumA: logic<2> {
+        Idle,
+        Busy,
+    }
+    type State = EnumA;
+}"#;
+
+const DEFINITION_MODULE: &str = r#"module ModuleA::<W: const> {
+    var _a: logic<W>;
+}
+package PkgB {
+    const X: u32 = 8;
+}"#;
+
+#[test]
+fn definition_hierarchical_identifier() {
+    let code = r#"module Top {
+    inst bus: BusIf;
+    let _a: logic = bus.awvalid;
+}"#;
+    let files = [("bus.veryl", DEFINITION_BUS), ("top.veryl", code)];
+
+    let ret = goto_definition(&files, 3, 25);
+    assert_eq!(ret, Some(("bus.veryl".to_string(), 2, 9)));
+
+    let ret = goto_definition(&files, 3, 21);
+    assert_eq!(ret, Some(("top.veryl".to_string(), 2, 10)));
+}
+
+#[test]
+fn definition_typedef_enum_member() {
+    let code = r#"module Top {
+    let _s: PkgA::State = PkgA::State::Idle;
+}"#;
+    let files = [("pkg.veryl", DEFINITION_PKG), ("top.veryl", code)];
+
+    let ret = goto_definition(&files, 2, 40);
+    assert_eq!(ret, Some(("pkg.veryl".to_string(), 3, 9)));
+
+    let ret = goto_definition(&files, 2, 33);
+    assert_eq!(ret, Some(("pkg.veryl".to_string(), 6, 10)));
+}
+
+#[test]
+fn definition_generic_instance() {
+    let code = r#"module Top {
+    inst u0: ModuleA::<2>;
+    inst u1: ModuleA::<4>;
+    inst u2: ModuleA::<PkgB::X>;
+}"#;
+    let files = [("module.veryl", DEFINITION_MODULE), ("top.veryl", code)];
+
+    let ret = goto_definition(&files, 2, 14);
+    assert_eq!(ret, Some(("module.veryl".to_string(), 1, 8)));
+
+    let ret = goto_definition(&files, 3, 14);
+    assert_eq!(ret, Some(("module.veryl".to_string(), 1, 8)));
+
+    let ret = goto_definition(&files, 4, 14);
+    assert_eq!(ret, Some(("module.veryl".to_string(), 1, 8)));
+
+    let ret = goto_definition(&files, 4, 30);
+    assert_eq!(ret, Some(("module.veryl".to_string(), 5, 11)));
+}
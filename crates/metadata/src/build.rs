@@ -1,6 +1,10 @@
+use crate::MetadataError;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
+pub const DEFAULT_PROFILE: &str = "default";
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Build {
@@ -17,16 +21,22 @@ pub struct Build {
     pub reset_low_prefix: Option<String>,
     pub reset_low_suffix: Option<String>,
     #[serde(default)]
+    pub escape_sv_keyword: bool,
+    #[serde(default)]
     pub filelist_type: FilelistType,
     #[serde(default)]
     pub target: Target,
     #[serde(default)]
+    pub output_layout: OutputLayout,
+    #[serde(default)]
     pub implicit_parameter_types: Vec<BuiltinType>,
     #[serde(default)]
     pub omit_project_prefix: bool,
     #[serde(default)]
     pub strip_comments: bool,
     #[serde(default)]
+    pub strip_assertions: bool,
+    #[serde(default)]
     pub sourcemap_target: SourceMapTarget,
     #[serde(default)]
     pub expand_inside_operation: bool,
@@ -34,6 +44,117 @@ pub struct Build {
     pub exclude_std: bool,
     #[serde(default)]
     pub emit_cond_type: bool,
+    #[serde(default)]
+    pub emit_generate_keyword: bool,
+    #[serde(default)]
+    pub expand_inout: bool,
+    pub inout_input_suffix: Option<String>,
+    pub inout_output_suffix: Option<String>,
+    pub inout_enable_suffix: Option<String>,
+    #[serde(default)]
+    pub flatten_struct_ports: bool,
+    #[serde(default)]
+    pub copy_init_files: bool,
+    #[serde(default)]
+    pub reorder_consts: bool,
+    #[serde(default)]
+    pub enum_style: EnumStyle,
+    pub enum_keep_pragma: Option<String>,
+    #[serde(default)]
+    pub emit_line_directives: bool,
+    pub default_nettype: Option<String>,
+    pub timescale: Option<String>,
+    #[serde(default)]
+    pub headers: Vec<HeaderLang>,
+    pub header_path: Option<PathBuf>,
+    #[serde(default)]
+    pub top: Vec<String>,
+    #[serde(default)]
+    pub hooks: BuildHooks,
+    /// Macros defined for `ifdef` and `ifndef` attributes through filelist
+    #[serde(default)]
+    pub defines: Vec<String>,
+    /// Options overriding the above ones, selected by `--profile`
+    #[serde(default, skip_serializing)]
+    pub profiles: BTreeMap<String, toml::Table>,
+    #[serde(skip)]
+    pub name_overrides: HashMap<String, NameOverride>,
+}
+
+impl Build {
+    /// Returns the prefix and suffix overridden for the project
+    pub fn name_override(&self, project: &str) -> Option<&NameOverride> {
+        self.name_overrides.get(project)
+    }
+
+    /// Returns options overridden by `[build.profiles.<name>]`.
+    /// `default` means `[build]` itself unless it is defined explicitly.
+    pub fn with_profile(&self, name: &str) -> Result<Build, MetadataError> {
+        let Some(profile) = self.profiles.get(name) else {
+            if name == DEFAULT_PROFILE {
+                return Ok(self.clone());
+            }
+            let available: Vec<_> = self.profiles.keys().map(|x| x.as_str()).collect();
+            return Err(MetadataError::UnknownProfile {
+                name: name.to_string(),
+                available: available.join(", "),
+            });
+        };
+
+        let mut table = toml::Table::try_from(self)?;
+        for (key, value) in profile {
+            table.insert(key.clone(), value.clone());
+        }
+
+        let mut ret: Build = table.try_into()?;
+        ret.profiles = self.profiles.clone();
+        ret.name_overrides = self.name_overrides.clone();
+        Ok(ret)
+    }
+
+    /// Returns suffixes of input, output and output enable signals expanded from inout port
+    pub fn inout_suffixes(&self) -> (String, String, String) {
+        (
+            self.inout_input_suffix.clone().unwrap_or("_i".to_string()),
+            self.inout_output_suffix.clone().unwrap_or("_o".to_string()),
+            self.inout_enable_suffix
+                .clone()
+                .unwrap_or("_oe".to_string()),
+        )
+    }
+}
+
+/// External commands executed by build.
+///
+/// Each command is executed by the shell after `{output}`, `{project}` and `{target_dir}`
+/// are replaced by quoted values. `{output}` is available in `post_emit` only.
+/// The values are also given as environment variables `VERYL_OUTPUT`, `VERYL_PROJECT`
+/// and `VERYL_TARGET_DIR`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct BuildHooks {
+    /// Commands executed before analysis
+    #[serde(default)]
+    pub pre_build: Vec<String>,
+    /// Commands executed for each emitted file
+    #[serde(default)]
+    pub post_emit: Vec<String>,
+    /// Commands executed after all files are written
+    #[serde(default)]
+    pub post_build: Vec<String>,
+}
+
+impl BuildHooks {
+    pub fn is_empty(&self) -> bool {
+        self.pre_build.is_empty() && self.post_emit.is_empty() && self.post_build.is_empty()
+    }
+}
+
+/// Prefix and suffix of emitted names specified by a dependency entry
+#[derive(Clone, Debug, Default, Serialize, PartialEq, Eq)]
+pub struct NameOverride {
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -81,6 +202,34 @@ pub enum Target {
     Bundle { path: PathBuf },
 }
 
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EnumStyle {
+    #[default]
+    #[serde(rename = "typedef")]
+    Typedef,
+    #[serde(rename = "localparam")]
+    Localparam,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HeaderLang {
+    #[serde(rename = "c")]
+    C,
+    #[serde(rename = "rust")]
+    Rust,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OutputLayout {
+    #[default]
+    #[serde(rename = "flat")]
+    Flat,
+    #[serde(rename = "mirror")]
+    Mirror,
+    #[serde(rename = "per_module")]
+    PerModule,
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type")]
 pub enum SourceMapTarget {
@@ -6,12 +6,16 @@ use std::path::PathBuf;
 pub struct Doc {
     #[serde(default = "default_path")]
     pub path: PathBuf,
+    /// URL of published documents, which documents of dependent projects link to
+    #[serde(default)]
+    pub url: Option<String>,
 }
 
 impl Default for Doc {
     fn default() -> Self {
         Self {
             path: default_path(),
+            url: None,
         }
     }
 }
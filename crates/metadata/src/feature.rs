@@ -0,0 +1,158 @@
+use crate::metadata_error::MetadataError;
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Features {
+    #[serde(default)]
+    pub default: Vec<String>,
+    #[serde(flatten)]
+    pub items: BTreeMap<String, Feature>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Feature {
+    /// Source files which are built only if the feature is enabled
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Features which are enabled together.
+    /// `dependency/feature` enables the feature of the dependency.
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+/// Features requested to the root project
+#[derive(Clone, Debug)]
+pub struct FeatureSelection {
+    pub features: Vec<String>,
+    pub default_features: bool,
+}
+
+impl Default for FeatureSelection {
+    fn default() -> Self {
+        Self {
+            features: Vec::new(),
+            default_features: true,
+        }
+    }
+}
+
+impl Features {
+    /// Returns the requested features and all features enabled by them
+    pub fn resolve(
+        &self,
+        project: &str,
+        features: &[String],
+        default_features: bool,
+    ) -> Result<BTreeSet<String>, MetadataError> {
+        let mut ret = BTreeSet::new();
+        let mut queue: Vec<&String> = features.iter().collect();
+        if default_features {
+            queue.extend(self.default.iter());
+        }
+
+        while let Some(name) = queue.pop() {
+            if name.contains('/') {
+                // `dependency/feature` is resolved by the dependency through `dependency_features`
+                continue;
+            }
+            let Some(feature) = self.items.get(name) else {
+                return Err(MetadataError::UnknownFeature {
+                    name: name.clone(),
+                    project: project.to_string(),
+                    available: self.available(),
+                });
+            };
+            if ret.insert(name.clone()) {
+                queue.extend(feature.features.iter());
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Returns features of each dependency enabled by `dependency/feature`
+    /// in the requested features or the enabled features
+    pub fn dependency_features(
+        &self,
+        features: &[String],
+        default_features: bool,
+        enabled: &BTreeSet<String>,
+    ) -> BTreeMap<String, Vec<String>> {
+        let mut requested: Vec<&String> = features.iter().collect();
+        if default_features {
+            requested.extend(self.default.iter());
+        }
+        for name in enabled {
+            if let Some(feature) = self.items.get(name) {
+                requested.extend(feature.features.iter());
+            }
+        }
+
+        let mut ret: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for x in requested {
+            if let Some((dependency, x)) = x.split_once('/') {
+                ret.entry(dependency.to_string())
+                    .or_default()
+                    .push(x.to_string());
+            }
+        }
+        ret
+    }
+
+    pub fn available(&self) -> String {
+        if self.items.is_empty() {
+            "none".to_string()
+        } else {
+            let names: Vec<_> = self.items.keys().map(|x| x.as_str()).collect();
+            names.join(", ")
+        }
+    }
+}
+
+/// Enabled features of a project resolved from the root project
+#[derive(Clone, Debug, Default)]
+pub struct EnabledFeatures {
+    pub enabled: BTreeSet<String>,
+    pub available: Vec<String>,
+    enabled_files: Vec<Pattern>,
+    disabled_files: Vec<Pattern>,
+}
+
+impl EnabledFeatures {
+    pub fn new(
+        project_path: &Path,
+        features: &Features,
+        enabled: BTreeSet<String>,
+    ) -> Result<Self, MetadataError> {
+        let base = Pattern::escape(&project_path.to_string_lossy());
+        let mut enabled_files = Vec::new();
+        let mut disabled_files = Vec::new();
+        for (name, feature) in &features.items {
+            for file in &feature.files {
+                let pattern = Pattern::new(&format!("{base}/{file}"))?;
+                if enabled.contains(name) {
+                    enabled_files.push(pattern);
+                } else {
+                    disabled_files.push(pattern);
+                }
+            }
+        }
+
+        Ok(Self {
+            enabled,
+            available: features.items.keys().cloned().collect(),
+            enabled_files,
+            disabled_files,
+        })
+    }
+
+    /// A file belonging to any enabled feature is built
+    pub fn is_enabled_file(&self, path: &Path) -> bool {
+        self.enabled_files.iter().any(|x| x.matches_path(path))
+            || !self.disabled_files.iter().any(|x| x.matches_path(path))
+    }
+}
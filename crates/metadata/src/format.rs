@@ -5,14 +5,93 @@ use serde::{Deserialize, Serialize};
 pub struct Format {
     #[serde(default = "default_indent_width")]
     pub indent_width: usize,
+    #[serde(default)]
+    pub sort_ports: bool,
+    #[serde(default)]
+    pub sort_inst_ports: bool,
+    #[serde(default)]
+    pub max_width: usize,
+    #[serde(default = "default_break_before_operator")]
+    pub break_before_operator: bool,
+    #[serde(default)]
+    pub else_style: ElseStyle,
+    #[serde(default)]
+    pub brace_style: BraceStyle,
+    #[serde(default)]
+    pub comment_breaks_align_group: bool,
+    #[serde(default)]
+    pub continuation_style: ContinuationStyle,
+    #[serde(default)]
+    pub inst_port_style: InstPortStyle,
+    /// Assignments whose left-hand side is wider than this are not aligned. 0 means no limit
+    #[serde(default = "default_assignment_align_max_lhs")]
+    pub assignment_align_max_lhs: usize,
+    /// Glob patterns of source files which are not formatted in addition to `[sources] exclude`
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ElseStyle {
+    #[default]
+    #[serde(rename = "cuddled")]
+    Cuddled,
+    #[serde(rename = "newline")]
+    Newline,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BraceStyle {
+    #[default]
+    #[serde(rename = "same_line")]
+    SameLine,
+    #[serde(rename = "next_line")]
+    NextLine,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContinuationStyle {
+    /// Continuation lines start one column past the opening brace
+    #[default]
+    #[serde(rename = "brace")]
+    Brace,
+    /// Continuation lines are indented one level deeper
+    #[serde(rename = "indent")]
+    Indent,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstPortStyle {
+    /// Port connections are kept as written
+    #[default]
+    #[serde(rename = "keep")]
+    Keep,
+    /// Implicit connections `a` are expanded to `a: a`
+    #[serde(rename = "explicit")]
+    Explicit,
+    /// Explicit connections `a: a` are shortened to `a`
+    #[serde(rename = "implicit")]
+    Implicit,
 }
 
 const DEFAULT_INDENT_WIDTH: usize = 4;
+const DEFAULT_ASSIGNMENT_ALIGN_MAX_LHS: usize = 40;
 
 impl Default for Format {
     fn default() -> Self {
         Self {
             indent_width: default_indent_width(),
+            sort_ports: false,
+            sort_inst_ports: false,
+            max_width: 0,
+            break_before_operator: default_break_before_operator(),
+            else_style: ElseStyle::default(),
+            brace_style: BraceStyle::default(),
+            comment_breaks_align_group: false,
+            continuation_style: ContinuationStyle::default(),
+            inst_port_style: InstPortStyle::default(),
+            assignment_align_max_lhs: default_assignment_align_max_lhs(),
+            exclude: Vec::new(),
         }
     }
 }
@@ -20,3 +99,11 @@ impl Default for Format {
 fn default_indent_width() -> usize {
     DEFAULT_INDENT_WIDTH
 }
+
+fn default_break_before_operator() -> bool {
+    true
+}
+
+fn default_assignment_align_max_lhs() -> usize {
+    DEFAULT_ASSIGNMENT_ALIGN_MAX_LHS
+}
@@ -1,5 +1,6 @@
 mod build;
 mod doc;
+mod feature;
 mod format;
 mod git;
 mod lint;
@@ -9,18 +10,28 @@ mod metadata_error;
 mod project;
 mod pubfile;
 mod publish;
+mod sources;
 mod test;
 #[cfg(test)]
 mod tests;
-pub use build::{Build, BuiltinType, ClockType, FilelistType, ResetType, SourceMapTarget, Target};
+mod workspace;
+pub use build::{
+    Build, BuildHooks, BuiltinType, ClockType, EnumStyle, FilelistType, HeaderLang, NameOverride,
+    OutputLayout, ResetType, SourceMapTarget, Target,
+};
 pub use doc::Doc;
-pub use format::Format;
-pub use lint::{Case, Lint};
-pub use lockfile::Lockfile;
+pub use feature::{EnabledFeatures, Feature, FeatureSelection, Features};
+pub use format::{BraceStyle, ContinuationStyle, ElseStyle, Format, InstPortStyle};
+pub use lint::{
+    Case, Lint, LintMagicNumber, LintSeverity, MagicNumberContext, RequireReset, SynthesisSubset,
+};
+pub use lockfile::{Lock, LockDependency, Lockfile};
 pub use metadata::{BumpKind, Metadata, UrlPath};
 pub use metadata_error::MetadataError;
 pub use project::Project;
 pub use pubfile::{Pubfile, Release};
 pub use publish::Publish;
 pub use semver;
+pub use sources::{ExcludePatterns, Sources};
 pub use test::{SimType, Test, WaveFormTarget};
+pub use workspace::Workspace;
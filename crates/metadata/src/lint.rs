@@ -1,5 +1,6 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -7,6 +8,42 @@ use std::fmt;
 pub struct Lint {
     #[serde(default)]
     pub naming: LintNaming,
+    /// Codes suppressed in the whole project
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Codes reported as warning in the whole project
+    #[serde(default)]
+    pub warn: Vec<String>,
+    /// Codes reported as error in the whole project
+    #[serde(default)]
+    pub deny: Vec<String>,
+    /// Lint level of each code in the whole project, which takes precedence over the above lists
+    #[serde(default)]
+    pub severity: BTreeMap<String, LintSeverity>,
+    /// Maximum depth of combinational path. The check is disabled if not specified
+    #[serde(default)]
+    pub max_comb_depth: Option<usize>,
+    /// Maximum iteration count of generate for. 100000 if not specified
+    #[serde(default)]
+    pub max_for_iterations: Option<usize>,
+    /// Registers required to be reset in always_ff
+    #[serde(default)]
+    pub require_reset: RequireReset,
+    /// Constructs restricted outside testbench modules
+    #[serde(default)]
+    pub synthesis_subset: SynthesisSubset,
+    /// Warn width 1 of logic and bit which is redundant
+    #[serde(default)]
+    pub redundant_width: bool,
+    /// Suggest let for var assigned only once by assign declaration
+    #[serde(default)]
+    pub prefer_let: bool,
+    /// Report modules, interfaces and packages unreachable from the top modules
+    #[serde(default)]
+    pub unused_module: bool,
+    /// Bare numeric literals which should be named consts
+    #[serde(default)]
+    pub magic_number: LintMagicNumber,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -17,6 +54,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub case_enum: Option<Case>,
     #[serde(default)]
+    pub case_enum_member: Option<Case>,
+    #[serde(default)]
     pub case_function: Option<Case>,
     #[serde(default)]
     pub case_function_inout: Option<Case>,
@@ -39,6 +78,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub case_parameter: Option<Case>,
     #[serde(default)]
+    pub case_port_clock: Option<Case>,
+    #[serde(default)]
     pub case_port_inout: Option<Case>,
     #[serde(default)]
     pub case_port_input: Option<Case>,
@@ -47,6 +88,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub case_port_output: Option<Case>,
     #[serde(default)]
+    pub case_port_reset: Option<Case>,
+    #[serde(default)]
     pub case_reg: Option<Case>,
     #[serde(default)]
     pub case_struct: Option<Case>,
@@ -61,6 +104,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub prefix_enum: Option<String>,
     #[serde(default)]
+    pub prefix_enum_member: Option<String>,
+    #[serde(default)]
     pub prefix_function: Option<String>,
     #[serde(default)]
     pub prefix_function_inout: Option<String>,
@@ -83,6 +128,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub prefix_parameter: Option<String>,
     #[serde(default)]
+    pub prefix_port_clock: Option<String>,
+    #[serde(default)]
     pub prefix_port_inout: Option<String>,
     #[serde(default)]
     pub prefix_port_input: Option<String>,
@@ -91,6 +138,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub prefix_port_output: Option<String>,
     #[serde(default)]
+    pub prefix_port_reset: Option<String>,
+    #[serde(default)]
     pub prefix_reg: Option<String>,
     #[serde(default)]
     pub prefix_struct: Option<String>,
@@ -105,6 +154,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub suffix_enum: Option<String>,
     #[serde(default)]
+    pub suffix_enum_member: Option<String>,
+    #[serde(default)]
     pub suffix_function: Option<String>,
     #[serde(default)]
     pub suffix_function_inout: Option<String>,
@@ -127,6 +178,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub suffix_parameter: Option<String>,
     #[serde(default)]
+    pub suffix_port_clock: Option<String>,
+    #[serde(default)]
     pub suffix_port_inout: Option<String>,
     #[serde(default)]
     pub suffix_port_input: Option<String>,
@@ -135,6 +188,8 @@ pub struct LintNaming {
     #[serde(default)]
     pub suffix_port_output: Option<String>,
     #[serde(default)]
+    pub suffix_port_reset: Option<String>,
+    #[serde(default)]
     pub suffix_reg: Option<String>,
     #[serde(default)]
     pub suffix_struct: Option<String>,
@@ -149,6 +204,8 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_enum: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_enum_member: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_function: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_function_inout: Option<Regex>,
@@ -171,6 +228,8 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_parameter: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_port_clock: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_port_inout: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_port_input: Option<Regex>,
@@ -179,6 +238,8 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_port_output: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_forbidden_port_reset: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_forbidden_reg: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_forbidden_struct: Option<Regex>,
@@ -193,6 +254,8 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_required_enum: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_enum_member: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_function: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_required_function_inout: Option<Regex>,
@@ -215,6 +278,8 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_required_parameter: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_port_clock: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_port_inout: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_required_port_input: Option<Regex>,
@@ -223,6 +288,8 @@ pub struct LintNaming {
     #[serde(default, with = "serde_regex")]
     pub re_required_port_output: Option<Regex>,
     #[serde(default, with = "serde_regex")]
+    pub re_required_port_reset: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
     pub re_required_reg: Option<Regex>,
     #[serde(default, with = "serde_regex")]
     pub re_required_struct: Option<Regex>,
@@ -234,6 +301,86 @@ pub struct LintNaming {
     pub re_required_wire: Option<Regex>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintSeverity {
+    #[serde(rename = "allow")]
+    Allow,
+    #[serde(rename = "warn")]
+    Warn,
+    #[serde(rename = "deny")]
+    Deny,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RequireReset {
+    #[default]
+    #[serde(rename = "none")]
+    None,
+    /// Registers holding enum values or driving output ports
+    #[serde(rename = "control")]
+    Control,
+    #[serde(rename = "all")]
+    All,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintMagicNumber {
+    /// Report numeric literals other than 0, 1 and all-ones in comparisons and assignments
+    #[serde(default)]
+    pub enable: bool,
+    /// Values which are not reported
+    #[serde(default)]
+    pub allow_values: Vec<u64>,
+    /// Contexts in which literals are not reported
+    #[serde(default = "default_magic_number_allow_contexts")]
+    pub allow_contexts: Vec<MagicNumberContext>,
+}
+
+impl Default for LintMagicNumber {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            allow_values: Vec::new(),
+            allow_contexts: default_magic_number_allow_contexts(),
+        }
+    }
+}
+
+fn default_magic_number_allow_contexts() -> Vec<MagicNumberContext> {
+    vec![MagicNumberContext::Index, MagicNumberContext::Width]
+}
+
+/// The innermost context of a numeric literal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MagicNumberContext {
+    /// Right-hand side of assignments and let declarations
+    #[serde(rename = "assignment")]
+    Assignment,
+    /// Operands of equality and relational operators
+    #[serde(rename = "comparison")]
+    Comparison,
+    /// Bit and array selects
+    #[serde(rename = "index")]
+    Index,
+    /// Widths and array sizes
+    #[serde(rename = "width")]
+    Width,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SynthesisSubset {
+    /// string types and all simulation-only system functions
+    #[serde(rename = "strict")]
+    Strict,
+    /// string variables and ports, and system functions other than messages and assertions
+    #[serde(rename = "relaxed")]
+    Relaxed,
+    #[default]
+    #[serde(rename = "off")]
+    Off,
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub enum Case {
     #[default]
@@ -241,7 +388,7 @@ pub enum Case {
     Snake,
     #[serde(rename = "screaming_snake")]
     ScreamingSnake,
-    #[serde(rename = "upper_camel")]
+    #[serde(rename = "upper_camel", alias = "pascal")]
     UpperCamel,
     #[serde(rename = "lower_camel")]
     LowerCamel,
@@ -1,3 +1,5 @@
+use crate::build::NameOverride;
+use crate::feature::{EnabledFeatures, FeatureSelection};
 use crate::git::Git;
 use crate::metadata::{Dependency, Metadata, UrlPath};
 use crate::metadata_error::MetadataError;
@@ -5,7 +7,7 @@ use crate::pubfile::{Pubfile, Release};
 use log::info;
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -22,6 +24,10 @@ pub struct Lockfile {
     force_update: bool,
     #[serde(skip)]
     pub metadata_path: PathBuf,
+    #[serde(skip)]
+    pub vendor_path: PathBuf,
+    #[serde(skip)]
+    offline: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -33,6 +39,9 @@ pub struct Lock {
     pub url: UrlPath,
     pub revision: String,
     pub path: Option<PathBuf>,
+    /// Checksum of the locked revision which vendored copies are checked against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
     pub dependencies: Vec<LockDependency>,
     #[serde(skip)]
     used: bool,
@@ -59,6 +68,8 @@ impl Lockfile {
         let text = fs::read_to_string(path)?;
         let mut ret = Self::from_str(&text)?;
         ret.metadata_path = metadata.metadata_path.clone();
+        ret.vendor_path = metadata.vendor_path();
+        ret.offline = metadata.offline;
 
         let mut locks = Vec::new();
         locks.append(&mut ret.projects);
@@ -94,6 +105,8 @@ impl Lockfile {
     pub fn new(metadata: &Metadata) -> Result<Self, MetadataError> {
         let mut ret = Lockfile {
             metadata_path: metadata.metadata_path.clone(),
+            vendor_path: metadata.vendor_path(),
+            offline: metadata.offline,
             ..Default::default()
         };
 
@@ -173,7 +186,7 @@ impl Lockfile {
                 let metadata = self.get_metadata(&lock.url, &lock.revision, &lock.path)?;
                 let path = metadata.project_path();
 
-                for src in &veryl_path::gather_files_with_extension(&path, "veryl", false)? {
+                for src in &veryl_path::gather_files_with_extension(&path, "veryl", false, false)? {
                     let rel = src.strip_prefix(&path)?;
                     let mut dst = base_dst.join(&lock.name);
                     dst.push(rel);
@@ -193,6 +206,150 @@ impl Lockfile {
         Ok(ret)
     }
 
+    /// Returns locked projects and the root path of each project
+    pub fn project_paths(&self) -> Result<Vec<(&Lock, PathBuf)>, MetadataError> {
+        let mut ret = Vec::new();
+
+        for locks in self.lock_table.values() {
+            for lock in locks {
+                let metadata = self.get_metadata(&lock.url, &lock.revision, &lock.path)?;
+                ret.push((lock, metadata.project_path()));
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Resolves enabled features of each project transitively from the root project
+    pub fn feature_table(
+        &self,
+        metadata: &Metadata,
+        enabled: BTreeSet<String>,
+    ) -> Result<HashMap<String, EnabledFeatures>, MetadataError> {
+        let mut table: HashMap<String, (Metadata, BTreeSet<String>)> = HashMap::new();
+
+        let mut queue = vec![(
+            metadata.clone(),
+            metadata.feature_selection.clone(),
+            enabled.clone(),
+        )];
+        while let Some((metadata, selection, enabled)) = queue.pop() {
+            let dependency_features = metadata.features.dependency_features(
+                &selection.features,
+                selection.default_features,
+                &enabled,
+            );
+            let mut dependencies = BTreeSet::new();
+
+            for (url, dep) in &metadata.dependencies {
+                for entry in dep.entries() {
+                    let Some(lock) = self
+                        .lock_table
+                        .get(url)
+                        .and_then(|x| x.iter().find(|x| entry.version.matches(&x.version)))
+                    else {
+                        continue;
+                    };
+
+                    let dep_metadata = self.get_metadata(url, &lock.revision, &lock.path)?;
+                    let local_name = entry
+                        .name
+                        .clone()
+                        .unwrap_or(dep_metadata.project.name.clone());
+                    let mut features = entry.features.clone();
+                    if let Some(x) = dependency_features.get(&local_name) {
+                        features.extend(x.iter().cloned());
+                    }
+                    let selection = FeatureSelection {
+                        features,
+                        default_features: entry.default_features,
+                    };
+                    let requested = dep_metadata.features.resolve(
+                        &lock.name,
+                        &selection.features,
+                        selection.default_features,
+                    )?;
+                    dependencies.insert(local_name);
+
+                    // Features requested from multiple dependents are unified
+                    let updated = if let Some((_, enabled)) = table.get_mut(&lock.name) {
+                        let updated = !requested.is_subset(enabled);
+                        enabled.extend(requested);
+                        updated
+                    } else {
+                        table.insert(lock.name.clone(), (dep_metadata.clone(), requested));
+                        true
+                    };
+
+                    if updated {
+                        let enabled = table[&lock.name].1.clone();
+                        queue.push((dep_metadata, selection, enabled));
+                    }
+                }
+            }
+
+            for (name, features) in &dependency_features {
+                if !dependencies.contains(name) {
+                    return Err(MetadataError::UnknownFeatureDependency {
+                        name: name.clone(),
+                        feature: features[0].clone(),
+                        project: metadata.project.name.clone(),
+                        available: if dependencies.is_empty() {
+                            "none".to_string()
+                        } else {
+                            let names: Vec<_> = dependencies.iter().map(|x| x.as_str()).collect();
+                            names.join(", ")
+                        },
+                    });
+                }
+            }
+        }
+
+        let mut ret = HashMap::new();
+        ret.insert(
+            metadata.project.name.clone(),
+            EnabledFeatures::new(&metadata.project_path(), &metadata.features, enabled)?,
+        );
+        for (name, (metadata, enabled)) in table {
+            let features =
+                EnabledFeatures::new(&metadata.project_path(), &metadata.features, enabled)?;
+            ret.insert(name, features);
+        }
+
+        Ok(ret)
+    }
+
+    /// Returns prefix and suffix of emitted names overridden by dependency entries of the root project
+    pub fn name_overrides(&self, metadata: &Metadata) -> HashMap<String, NameOverride> {
+        let mut ret = HashMap::new();
+
+        for (url, dep) in &metadata.dependencies {
+            for entry in dep.entries() {
+                if entry.prefix.is_none() && entry.suffix.is_none() {
+                    continue;
+                }
+
+                let Some(lock) = self
+                    .lock_table
+                    .get(url)
+                    .and_then(|x| x.iter().find(|x| entry.version.matches(&x.version)))
+                else {
+                    continue;
+                };
+
+                ret.insert(
+                    lock.name.clone(),
+                    NameOverride {
+                        prefix: entry.prefix.clone(),
+                        suffix: entry.suffix.clone(),
+                    },
+                );
+            }
+        }
+
+        ret
+    }
+
     pub fn clear_cache(&self) -> Result<(), MetadataError> {
         for locks in self.lock_table.values() {
             for lock in locks {
@@ -209,6 +366,174 @@ impl Lockfile {
         Ok(())
     }
 
+    /// Copies locked dependencies into the vendor directory and records their checksums.
+    /// Checksums are taken from the upstream checkout of the locked revision,
+    /// and copies which don't match them are replaced.
+    /// Copies of removed dependencies are pruned.
+    pub fn vendor(&mut self) -> Result<(), MetadataError> {
+        if !self.vendor_path.exists() {
+            fs::create_dir_all(&self.vendor_path)?;
+        }
+
+        let mut checksums = HashMap::new();
+        for locks in self.lock_table.values() {
+            for lock in locks {
+                if self.local_path(&lock.path).is_some() {
+                    continue;
+                }
+
+                let metadata = self.get_metadata(&lock.url, &lock.revision, &lock.path)?;
+                let upstream = metadata.project_path();
+                let checksum = Self::checksum(&upstream)?;
+
+                let path = self.vendored_path(&lock.url, &lock.revision)?;
+                if !path.exists() || Self::checksum(&path)? != checksum {
+                    info!("Vendoring dependency ({} @ {})", lock.url, lock.version);
+                    if path.exists() {
+                        fs::remove_dir_all(&path)?;
+                    }
+                    Self::copy_dir(&upstream, &path)?;
+                    if Self::checksum(&path)? != checksum {
+                        return Err(MetadataError::ChecksumMismatch {
+                            url: lock.url.clone(),
+                            version: lock.version.to_string(),
+                        });
+                    }
+                }
+                checksums.insert(lock.uuid, checksum);
+            }
+        }
+
+        for locks in self.lock_table.values_mut() {
+            for lock in locks {
+                lock.checksum = checksums.get(&lock.uuid).cloned();
+            }
+        }
+
+        for entry in fs::read_dir(&self.vendor_path)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+            let used = Uuid::parse_str(&name).is_ok_and(|x| checksums.contains_key(&x));
+            if !used {
+                info!("Removing vendored dependency ({})", name);
+                if entry.file_type()?.is_dir() {
+                    fs::remove_dir_all(entry.path())?;
+                } else {
+                    fs::remove_file(entry.path())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that all locked dependencies are vendored and not modified
+    pub fn verify_vendor(&self) -> Result<(), MetadataError> {
+        for locks in self.lock_table.values() {
+            for lock in locks {
+                if self.local_path(&lock.path).is_some() {
+                    continue;
+                }
+                self.verify_vendored(&lock.url, &lock.revision)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the vendored copy after checking it against the checksum in Veryl.lock
+    fn verify_vendored(&self, url: &UrlPath, revision: &str) -> Result<PathBuf, MetadataError> {
+        let lock = self
+            .lock_table
+            .get(url)
+            .and_then(|x| x.iter().find(|x| x.revision == revision));
+        let version = lock
+            .map(|x| x.version.to_string())
+            .unwrap_or(revision.to_string());
+
+        let path = self.vendored_path(url, revision)?;
+        let checksum = lock.and_then(|x| x.checksum.as_ref());
+        let Some(checksum) = checksum.filter(|_| path.join("Veryl.toml").exists()) else {
+            return Err(MetadataError::NotVendored {
+                url: url.clone(),
+                version,
+            });
+        };
+        if *checksum != Self::checksum(&path)? {
+            return Err(MetadataError::ChecksumMismatch {
+                url: url.clone(),
+                version,
+            });
+        }
+        Ok(path)
+    }
+
+    fn vendored_path(&self, url: &UrlPath, revision: &str) -> Result<PathBuf, MetadataError> {
+        let uuid = Self::gen_uuid(url, revision)?;
+        Ok(self
+            .vendor_path
+            .join(uuid.simple().encode_lower(&mut Uuid::encode_buffer())))
+    }
+
+    /// Returns checksum of files under `path` including their relative paths
+    fn checksum(path: &Path) -> Result<String, MetadataError> {
+        let mut files = Vec::new();
+        Self::gather_files(path, path, &mut files)?;
+        files.sort();
+
+        let mut hasher = blake3::Hasher::new();
+        for (name, file) in files {
+            let data = fs::read(file)?;
+            hasher.update(name.as_bytes());
+            hasher.update(&[0]);
+            hasher.update(&(data.len() as u64).to_le_bytes());
+            hasher.update(&data);
+        }
+        Ok(hasher.finalize().to_hex().to_string())
+    }
+
+    /// Gathers files except git directories with their relative paths separated by '/'
+    fn gather_files(
+        base: &Path,
+        dir: &Path,
+        files: &mut Vec<(String, PathBuf)>,
+    ) -> Result<(), MetadataError> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_name() == ".git" {
+                continue;
+            }
+            if entry.file_type()?.is_dir() {
+                Self::gather_files(base, &path, files)?;
+            } else {
+                let rel = path.strip_prefix(base)?;
+                let name: Vec<_> = rel
+                    .components()
+                    .map(|x| x.as_os_str().to_string_lossy())
+                    .collect();
+                files.push((name.join("/"), path));
+            }
+        }
+        Ok(())
+    }
+
+    fn copy_dir(src: &Path, dst: &Path) -> Result<(), MetadataError> {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            if entry.file_name() == ".git" {
+                continue;
+            }
+            let dst = dst.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                Self::copy_dir(&entry.path(), &dst)?;
+            } else {
+                fs::copy(entry.path(), &dst)?;
+            }
+        }
+        Ok(())
+    }
+
     fn git_clone(&self, url: &UrlPath, path: &Path) -> Result<Git, MetadataError> {
         let url = match url {
             UrlPath::Url(x) => UrlPath::Url(x.clone()),
@@ -289,6 +614,12 @@ impl Lockfile {
 
                 let uuid = Self::gen_uuid(url, &x.release.revision)?;
                 if !uuid_table.contains(&uuid) {
+                    let checksum = self
+                        .lock_table
+                        .values()
+                        .flatten()
+                        .find(|x| x.uuid == uuid)
+                        .and_then(|x| x.checksum.clone());
                     let lock = Lock {
                         name: name.clone(),
                         uuid,
@@ -296,6 +627,7 @@ impl Lockfile {
                         url: url.clone(),
                         revision: x.release.revision,
                         path: x.path,
+                        checksum,
                         dependencies,
                         used: true,
                     };
@@ -401,6 +733,13 @@ impl Lockfile {
         url: &UrlPath,
         version_req: &VersionReq,
     ) -> Result<Release, MetadataError> {
+        if self.offline {
+            return Err(MetadataError::Offline {
+                url: url.clone(),
+                version: version_req.to_string(),
+            });
+        }
+
         let resolve_dir = veryl_path::cache_path().join("resolve");
 
         if !resolve_dir.exists() {
@@ -437,14 +776,9 @@ impl Lockfile {
         Ok(dependencies_dir.join(uuid.simple().encode_lower(&mut Uuid::encode_buffer())))
     }
 
-    fn get_metadata(
-        &self,
-        url: &UrlPath,
-        revision: &str,
-        path: &Option<PathBuf>,
-    ) -> Result<Metadata, MetadataError> {
-        // Get metadata from local path
-        let path = path.as_ref().and_then(|x| {
+    /// Returns the manifest path of the dependency placed at local path
+    fn local_path(&self, path: &Option<PathBuf>) -> Option<PathBuf> {
+        path.as_ref().and_then(|x| {
             let path = self.metadata_path.parent().unwrap().join(x);
             let path = path.join("Veryl.toml");
             if path.exists() {
@@ -452,11 +786,23 @@ impl Lockfile {
             } else {
                 None
             }
-        });
+        })
+    }
 
-        if let Some(path) = path {
+    fn get_metadata(
+        &self,
+        url: &UrlPath,
+        revision: &str,
+        path: &Option<PathBuf>,
+    ) -> Result<Metadata, MetadataError> {
+        // Get metadata from local path
+        if let Some(path) = self.local_path(path) {
             let metadata = Metadata::load(path)?;
             Ok(metadata)
+        } else if self.offline {
+            let toml = self.verify_vendored(url, revision)?.join("Veryl.toml");
+            let metadata = Metadata::load(toml)?;
+            Ok(metadata)
         } else {
             let dependencies_dir = veryl_path::cache_path().join("dependencies");
 
@@ -1,5 +1,6 @@
-use crate::build::{Build, Target};
+use crate::build::{Build, OutputLayout, Target};
 use crate::doc::Doc;
+use crate::feature::{EnabledFeatures, FeatureSelection, Features};
 use crate::format::Format;
 use crate::git::Git;
 use crate::lint::Lint;
@@ -7,9 +8,11 @@ use crate::lockfile::Lockfile;
 use crate::project::Project;
 use crate::pubfile::{Pubfile, Release};
 use crate::publish::Publish;
+use crate::sources::{ExcludePatterns, Sources};
 use crate::test::Test;
+use crate::workspace::Workspace;
 use crate::{FilelistType, MetadataError, SourceMapTarget};
-use log::{debug, info};
+use log::{debug, info, warn};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use semver::VersionReq;
@@ -36,6 +39,8 @@ pub enum BumpKind {
 pub struct Metadata {
     pub project: Project,
     #[serde(default)]
+    pub sources: Sources,
+    #[serde(default)]
     pub build: Build,
     #[serde(default)]
     pub format: Format,
@@ -48,6 +53,8 @@ pub struct Metadata {
     #[serde(default)]
     pub test: Test,
     #[serde(default)]
+    pub features: Features,
+    #[serde(default)]
     pub dependencies: HashMap<UrlPath, Dependency>,
     #[serde(skip)]
     pub metadata_path: PathBuf,
@@ -59,6 +66,22 @@ pub struct Metadata {
     pub lockfile_path: PathBuf,
     #[serde(skip)]
     pub lockfile: Lockfile,
+    #[serde(skip)]
+    pub feature_selection: FeatureSelection,
+    /// Resolve dependencies from vendored copies without network access
+    #[serde(skip)]
+    pub offline: bool,
+    /// Gather source files ignored by `.gitignore`
+    #[serde(skip)]
+    pub no_ignore: bool,
+    #[serde(skip)]
+    pub feature_table: HashMap<String, EnabledFeatures>,
+    /// Workspace members depended on by local path, from local name to project name
+    #[serde(skip)]
+    pub workspace_dependencies: HashMap<String, String>,
+    /// Build profile selected by `apply_profile`
+    #[serde(skip)]
+    pub profile: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -102,8 +125,17 @@ impl Metadata {
     pub fn load<T: AsRef<Path>>(path: T) -> Result<Self, MetadataError> {
         let path = path.as_ref().canonicalize()?;
         let text = fs::read_to_string(&path)?;
-        let mut metadata: Metadata = Self::from_str(&text)?;
-        metadata.metadata_path.clone_from(&path);
+        Self::load_from_str(path, &text)
+    }
+
+    /// Loads metadata from `text` as if it is the content of the manifest at `path`
+    pub fn load_from_str<T: AsRef<Path>>(path: T, text: &str) -> Result<Self, MetadataError> {
+        let path = path.as_ref();
+        if Workspace::is_manifest(text) {
+            return Err(MetadataError::WorkspaceManifest(path.to_path_buf()));
+        }
+        let mut metadata: Metadata = Self::from_str(text)?;
+        metadata.metadata_path = path.to_path_buf();
         metadata.pubfile_path = path.with_file_name("Veryl.pub");
         metadata.lockfile_path = path.with_file_name("Veryl.lock");
         metadata.check()?;
@@ -167,6 +199,20 @@ impl Metadata {
             let _ = Expression::parse(license)?;
         }
 
+        for name in self.build.profiles.keys() {
+            let _ = self.build.with_profile(name)?;
+        }
+
+        let _ = ExcludePatterns::new(Path::new(""), &self.sources.exclude)?;
+        let _ = ExcludePatterns::new(Path::new(""), &self.format.exclude)?;
+
+        Ok(())
+    }
+
+    /// Overrides build options by `[build.profiles.<name>]`
+    pub fn apply_profile(&mut self, name: &str) -> Result<(), MetadataError> {
+        self.build = self.build.with_profile(name)?;
+        self.profile = Some(name.to_string());
         Ok(())
     }
 
@@ -232,6 +278,17 @@ impl Metadata {
         if modified {
             self.lockfile.save(&self.lockfile_path)?;
         }
+        if self.offline {
+            self.lockfile.verify_vendor()?;
+        }
+        Ok(())
+    }
+
+    /// Copies dependencies into the vendor directory and records their checksums in Veryl.lock
+    pub fn vendor(&mut self) -> Result<(), MetadataError> {
+        self.update_lockfile()?;
+        self.lockfile.vendor()?;
+        self.lockfile.save(&self.lockfile_path)?;
         Ok(())
     }
 
@@ -241,9 +298,10 @@ impl Metadata {
         symlink: bool,
     ) -> Result<Vec<PathSet>, MetadataError> {
         let base = self.project_path();
+        let exclude = ExcludePatterns::new(&base, &self.sources.exclude)?;
 
         let src_files = if files.is_empty() {
-            veryl_path::gather_files_with_extension(&base, "veryl", symlink)?
+            veryl_path::gather_files_with_extension(&base, "veryl", symlink, !self.no_ignore)?
         } else {
             let mut ret = Vec::new();
             for file in files {
@@ -251,17 +309,40 @@ impl Metadata {
             }
             ret
         };
+        let src_files = Self::filter_excluded(src_files, &exclude, !files.is_empty(), "sources");
+
+        // The same file may be found through multiple paths by symlinks
+        let mut found: HashMap<PathBuf, PathBuf> = HashMap::new();
+        let src_files: Vec<_> = src_files
+            .into_iter()
+            .filter(|src| {
+                let canonical = fs::canonicalize(src).unwrap_or_else(|_| src.clone());
+                if let Some(first) = found.get(&canonical) {
+                    warn!(
+                        "Skipped {} because it is the same file as {}",
+                        src.to_string_lossy(),
+                        first.to_string_lossy()
+                    );
+                    false
+                } else {
+                    found.insert(canonical, src.clone());
+                    true
+                }
+            })
+            .collect();
 
         let mut ret = Vec::new();
         for src in src_files {
+            let dst_name = match self.build.output_layout {
+                OutputLayout::Mirror => {
+                    src.strip_prefix(&base).unwrap_or(&src).with_extension("sv")
+                }
+                _ => PathBuf::from(src.with_extension("sv").file_name().unwrap()),
+            };
             let dst = match self.build.target {
                 Target::Source => src.with_extension("sv"),
-                Target::Directory { ref path } => {
-                    base.join(path.join(src.with_extension("sv").file_name().unwrap()))
-                }
-                Target::Bundle { .. } => base.join(
-                    PathBuf::from("target").join(src.with_extension("sv").file_name().unwrap()),
-                ),
+                Target::Directory { ref path } => base.join(path.join(dst_name)),
+                Target::Bundle { .. } => base.join(PathBuf::from("target").join(dst_name)),
             };
             let map = match &self.build.sourcemap_target {
                 SourceMapTarget::Directory { ref path } => {
@@ -282,6 +363,19 @@ impl Metadata {
             });
         }
 
+        if self.build.output_layout == OutputLayout::Flat {
+            let mut outputs: HashMap<&Path, &Path> = HashMap::new();
+            for path in &ret {
+                if let Some(src) = outputs.insert(&path.dst, &path.src) {
+                    return Err(MetadataError::OutputCollision {
+                        dst: path.dst.clone(),
+                        src0: src.to_path_buf(),
+                        src1: path.src.clone(),
+                    });
+                }
+            }
+        }
+
         let base_dst = self.project_dependencies_path();
         if !base_dst.exists() {
             fs::create_dir(&base_dst)?;
@@ -293,6 +387,8 @@ impl Metadata {
         }
 
         self.update_lockfile()?;
+        self.resolve_features()?;
+        self.build.name_overrides = self.lockfile.name_overrides(self);
 
         let mut deps = self.lockfile.paths(&base_dst)?;
         ret.append(&mut deps);
@@ -300,6 +396,66 @@ impl Metadata {
         Ok(ret)
     }
 
+    pub fn resolve_features(&mut self) -> Result<(), MetadataError> {
+        let enabled = self.features.resolve(
+            &self.project.name,
+            &self.feature_selection.features,
+            self.feature_selection.default_features,
+        )?;
+        self.feature_table = self.lockfile.feature_table(self, enabled)?;
+        Ok(())
+    }
+
+    /// Returns paths of source files to be formatted
+    pub fn format_paths<T: AsRef<Path>>(
+        &mut self,
+        files: &[T],
+        symlink: bool,
+    ) -> Result<Vec<PathSet>, MetadataError> {
+        let exclude = ExcludePatterns::new(&self.project_path(), &self.format.exclude)?;
+        let paths = self.paths(files, symlink)?;
+        let src_files = paths.iter().map(|x| x.src.clone()).collect();
+        let src_files = Self::filter_excluded(src_files, &exclude, !files.is_empty(), "format");
+        Ok(paths
+            .into_iter()
+            .filter(|x| src_files.contains(&x.src))
+            .collect())
+    }
+
+    /// Removes excluded files, but files specified explicitly are kept with warning
+    fn filter_excluded(
+        files: Vec<PathBuf>,
+        exclude: &ExcludePatterns,
+        explicit: bool,
+        table: &str,
+    ) -> Vec<PathBuf> {
+        files
+            .into_iter()
+            .filter(|x| {
+                if !exclude.is_excluded(x) {
+                    true
+                } else if explicit {
+                    warn!(
+                        "{} is excluded by `[{table}] exclude`, but processed because it is specified explicitly",
+                        x.to_string_lossy()
+                    );
+                    true
+                } else {
+                    debug!("Excluded file ({})", x.to_string_lossy());
+                    false
+                }
+            })
+            .collect()
+    }
+
+    /// Returns whether the source file is built with the enabled features
+    pub fn is_enabled_path(&self, path: &PathSet) -> bool {
+        self.feature_table
+            .get(&path.prj)
+            .map(|x| x.is_enabled_file(&path.src))
+            .unwrap_or(true)
+    }
+
     pub fn create_default_toml(name: &str) -> Result<String, MetadataError> {
         if !VALID_PROJECT_NAME.is_match(name) {
             return Err(MetadataError::InvalidProjectName(name.to_string()));
@@ -320,6 +476,10 @@ version = "0.1.0""###
         self.project_path().join("dependencies")
     }
 
+    pub fn vendor_path(&self) -> PathBuf {
+        self.project_path().join("vendor")
+    }
+
     pub fn filelist_path(&self) -> PathBuf {
         let filelist_name = match self.build.filelist_type {
             FilelistType::Absolute => format!("{}.f", self.project.name),
@@ -333,6 +493,10 @@ version = "0.1.0""###
     pub fn doc_path(&self) -> PathBuf {
         self.metadata_path.parent().unwrap().join(&self.doc.path)
     }
+
+    pub fn build_cache_path(&self) -> PathBuf {
+        self.project_path().join("target").join("cache")
+    }
 }
 
 impl FromStr for Metadata {
@@ -353,10 +517,38 @@ pub enum Dependency {
     Multi(Vec<DependencyEntry>),
 }
 
+impl Dependency {
+    pub fn entries(&self) -> Vec<DependencyEntry> {
+        match self {
+            Dependency::Version(x) => vec![DependencyEntry {
+                version: x.clone(),
+                name: None,
+                path: None,
+                features: Vec::new(),
+                default_features: true,
+                prefix: None,
+                suffix: None,
+            }],
+            Dependency::Single(x) => vec![x.clone()],
+            Dependency::Multi(x) => x.clone(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct DependencyEntry {
     pub version: VersionReq,
     pub name: Option<String>,
     pub path: Option<PathBuf>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default = "default_true")]
+    pub default_features: bool,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
 }
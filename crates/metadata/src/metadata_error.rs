@@ -58,6 +58,27 @@ pub enum MetadataError {
     #[error("{version} @ {url} is not found")]
     VersionNotFound { url: UrlPath, version: String },
 
+    #[diagnostic(
+        code(MetadataError::Offline),
+        help("run \"veryl vendor\" with network access")
+    )]
+    #[error("{version} @ {url} can't be resolved because it is not locked in offline mode")]
+    Offline { url: UrlPath, version: String },
+
+    #[diagnostic(
+        code(MetadataError::NotVendored),
+        help("run \"veryl vendor\" with network access")
+    )]
+    #[error("{version} @ {url} is not vendored")]
+    NotVendored { url: UrlPath, version: String },
+
+    #[diagnostic(
+        code(MetadataError::ChecksumMismatch),
+        help("run \"veryl vendor\" to restore vendored dependencies")
+    )]
+    #[error("checksum of vendored {version} @ {url} doesn't match Veryl.lock")]
+    ChecksumMismatch { url: UrlPath, version: String },
+
     #[diagnostic(code(MetadataError::GitSpec), help(""))]
     #[error("no version/rev/tag/branch specification of {0}")]
     GitSpec(Url),
@@ -66,6 +87,76 @@ pub enum MetadataError {
     #[error("project name \"{0}\" is used multiply in dependencies")]
     NameConflict(String),
 
+    #[diagnostic(
+        code(MetadataError::UnknownFeature),
+        help("available features: {available}")
+    )]
+    #[error("feature \"{name}\" is not defined in project \"{project}\"")]
+    UnknownFeature {
+        name: String,
+        project: String,
+        available: String,
+    },
+
+    #[diagnostic(
+        code(MetadataError::UnknownFeatureDependency),
+        help("available dependencies: {available}")
+    )]
+    #[error("dependency \"{name}\" of feature \"{name}/{feature}\" is not found in project \"{project}\"")]
+    UnknownFeatureDependency {
+        name: String,
+        feature: String,
+        project: String,
+        available: String,
+    },
+
+    #[diagnostic(code(MetadataError::InvalidFeaturePattern), help(""))]
+    #[error("feature file pattern is invalid")]
+    InvalidFeaturePattern(#[from] glob::PatternError),
+
+    #[diagnostic(code(MetadataError::InvalidExcludePattern), help(""))]
+    #[error("exclude pattern \"{pattern}\" is invalid: {cause}")]
+    InvalidExcludePattern {
+        pattern: String,
+        cause: glob::PatternError,
+    },
+
+    #[diagnostic(
+        code(MetadataError::OutputCollision),
+        help("rename either source or change output_layout to \"mirror\"")
+    )]
+    #[error("output file \"{dst}\" is emitted from both \"{src0}\" and \"{src1}\"")]
+    OutputCollision {
+        dst: PathBuf,
+        src0: PathBuf,
+        src1: PathBuf,
+    },
+
+    #[diagnostic(
+        code(MetadataError::WorkspaceManifest),
+        help("run the command in a member directory")
+    )]
+    #[error("\"{0}\" is a workspace manifest")]
+    WorkspaceManifest(PathBuf),
+
+    #[diagnostic(code(MetadataError::MemberNotFound), help(""))]
+    #[error("workspace member \"{0}\" has no Veryl.toml")]
+    MemberNotFound(PathBuf),
+
+    #[diagnostic(
+        code(MetadataError::UnknownMember),
+        help("available members: {available}")
+    )]
+    #[error("project \"{name}\" is not a member of the workspace")]
+    UnknownMember { name: String, available: String },
+
+    #[diagnostic(
+        code(MetadataError::UnknownProfile),
+        help("available profiles: {available}")
+    )]
+    #[error("build profile \"{name}\" is not defined")]
+    UnknownProfile { name: String, available: String },
+
     #[diagnostic(code(MetadataError::Path), help(""))]
     #[error("path error")]
     Path(#[from] PathError),
@@ -0,0 +1,48 @@
+use crate::metadata_error::MetadataError;
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Sources {
+    /// Glob patterns of source files which are not formatted, analyzed nor built
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Glob patterns relative to the project directory
+#[derive(Clone, Debug, Default)]
+pub struct ExcludePatterns {
+    base: PathBuf,
+    patterns: Vec<Pattern>,
+}
+
+impl ExcludePatterns {
+    pub fn new(project_path: &Path, exclude: &[String]) -> Result<Self, MetadataError> {
+        let base = Pattern::escape(&project_path.to_string_lossy());
+        let mut patterns = Vec::new();
+        for x in exclude {
+            let pattern =
+                Pattern::new(&format!("{base}/{}", x.trim_end_matches('/'))).map_err(|cause| {
+                    MetadataError::InvalidExcludePattern {
+                        pattern: x.clone(),
+                        cause,
+                    }
+                })?;
+            patterns.push(pattern);
+        }
+
+        Ok(Self {
+            base: project_path.to_path_buf(),
+            patterns,
+        })
+    }
+
+    /// A file is excluded if the file or any directory containing it matches the patterns
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        path.ancestors()
+            .take_while(|x| x.starts_with(&self.base) && *x != self.base)
+            .any(|x| self.patterns.iter().any(|y| y.matches_path(x)))
+    }
+}
@@ -7,6 +7,8 @@ pub struct Test {
     #[serde(default)]
     pub simulator: SimType,
     #[serde(default)]
+    pub questa: QuestaProperty,
+    #[serde(default)]
     pub vcs: VcsProperty,
     #[serde(default)]
     pub verilator: VerilatorProperty,
@@ -25,6 +27,17 @@ pub enum SimType {
     Vcs,
     #[serde(rename = "vivado")]
     Vivado,
+    #[serde(rename = "questa")]
+    Questa,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuestaProperty {
+    #[serde(default)]
+    pub compile_args: Vec<String>,
+    #[serde(default)]
+    pub simulate_args: Vec<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
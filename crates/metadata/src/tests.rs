@@ -4,6 +4,7 @@ use semver::Version;
 use std::fs;
 use std::path::Path;
 use tempfile::TempDir;
+use veryl_path::PathSet;
 
 const GIT_IGNORE: &'static str = r#"
 Veryl.lock
@@ -25,6 +26,57 @@ target = {type = "source"}
 indent_width = 4
 "#;
 
+const LAYOUT_TOML: &'static str = r#"
+[project]
+name = "layout"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+target = {type = "directory", path = "out"}
+"#;
+
+const EXCLUDE_TOML: &'static str = r#"
+[project]
+name = "exclude"
+version = "0.1.0"
+
+[sources]
+exclude = ["third_party", "gen/*.veryl"]
+
+[build]
+exclude_std = true
+target = {type = "directory", path = "target"}
+
+[format]
+exclude = ["skip.veryl"]
+"#;
+
+const HOOKS_TOML: &'static str = r#"
+[project]
+name = "hooks"
+version = "0.1.0"
+
+[build.hooks]
+pre_build = ["make prepare"]
+post_emit = ["lint {output}", "echo {project}"]
+"#;
+
+const PROFILES_TOML: &'static str = r#"
+[project]
+name = "profiles"
+version = "0.1.0"
+
+[build]
+reset_type = "async_low"
+defines = ["SIM"]
+
+[build.profiles.synth]
+reset_type = "sync_high"
+strip_assertions = true
+defines = ["FPGA"]
+"#;
+
 const MAIN_TOML: &'static str = r#"
 [project]
 name = "main"
@@ -75,6 +127,48 @@ publish_commit = true
 "file://{}/sub1" = "0.1.0"
 "#;
 
+const FEATURE_MAIN_TOML: &'static str = r#"
+[project]
+name = "main"
+version = "0.1.0"
+
+[features]
+default = ["a"]
+a = {features = ["sub/x"]}
+b = {files = ["b/*.veryl"]}
+
+[dependencies]
+"file://{}/sub" = {version = "0.1.0", features = ["y"], default_features = false}
+"#;
+
+const FEATURE_SUB_TOML: &'static str = r#"
+[project]
+name = "sub"
+version = "0.1.0"
+
+[publish]
+bump_commit = true
+publish_commit = true
+
+[features]
+default = ["z"]
+x = {features = []}
+y = {features = []}
+z = {features = []}
+"#;
+
+const OVERRIDE_MAIN_TOML: &'static str = r#"
+[project]
+name = "main"
+version = "0.1.0"
+
+[dependencies]
+"file://{}/sub" = [
+    {version = "0.1.0", name = "sub_v0", prefix = "v0_"},
+    {version = "1.0.0", name = "sub_v1", suffix = "_v1"},
+]
+"#;
+
 fn create_metadata_simple() -> (Metadata, TempDir) {
     let tempdir = tempfile::tempdir().unwrap();
     let metadata = create_project(tempdir.path(), "test", TEST_TOML, false);
@@ -141,6 +235,16 @@ fn check_toml() {
     assert!(metadata.build.reset_low_prefix.is_none());
     assert_eq!(metadata.build.reset_low_suffix.unwrap(), "_n");
     assert_eq!(metadata.format.indent_width, 4);
+    assert!(!metadata.format.sort_ports);
+    assert!(!metadata.format.sort_inst_ports);
+    assert_eq!(metadata.format.max_width, 0);
+    assert!(metadata.format.break_before_operator);
+    assert_eq!(metadata.format.else_style, ElseStyle::Cuddled);
+    assert_eq!(metadata.format.brace_style, BraceStyle::SameLine);
+    assert!(!metadata.format.comment_breaks_align_group);
+    assert_eq!(metadata.format.continuation_style, ContinuationStyle::Brace);
+    assert_eq!(metadata.format.inst_port_style, InstPortStyle::Keep);
+    assert_eq!(metadata.format.assignment_align_max_lhs, 40);
 }
 
 #[test]
@@ -266,3 +370,377 @@ fn lockfile() {
 
     let _ = lockfile.clear_cache();
 }
+
+#[test]
+fn vendor() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut metadata = create_project(tempdir.path(), "main", OVERRIDE_MAIN_TOML, false);
+    create_project(tempdir.path(), "sub", FEATURE_SUB_TOML, true);
+
+    let vendor_path = metadata.vendor_path();
+    let stale = vendor_path.join("stale");
+    fs::create_dir_all(&stale).unwrap();
+
+    metadata.vendor().unwrap();
+
+    let locks: Vec<_> = metadata.lockfile.lock_table.values().flatten().collect();
+    assert_eq!(locks.len(), 2);
+    for lock in &locks {
+        assert!(lock.checksum.is_some());
+    }
+    assert_eq!(fs::read_dir(&vendor_path).unwrap().count(), 2);
+    assert!(!stale.exists());
+    let checksums: Vec<_> = locks.iter().map(|x| x.checksum.clone()).collect();
+
+    // checksums are taken from upstream, so modified copies are restored
+    let dirs: Vec<_> = fs::read_dir(&vendor_path)
+        .unwrap()
+        .map(|x| x.unwrap().path())
+        .collect();
+    for dir in &dirs {
+        fs::write(dir.join("Veryl.toml"), format!("{FEATURE_SUB_TOML}\n")).unwrap();
+    }
+    for lock in metadata.lockfile.lock_table.values_mut().flatten() {
+        lock.checksum = Some("modified".to_string());
+    }
+    metadata.vendor().unwrap();
+    let locks: Vec<_> = metadata.lockfile.lock_table.values().flatten().collect();
+    assert_eq!(
+        locks.iter().map(|x| x.checksum.clone()).collect::<Vec<_>>(),
+        checksums
+    );
+    for dir in &dirs {
+        let toml = fs::read_to_string(dir.join("Veryl.toml")).unwrap();
+        assert_ne!(toml, format!("{FEATURE_SUB_TOML}\n"));
+    }
+
+    // vendored dependencies are resolved without cache
+    let _ = metadata.lockfile.clear_cache();
+    let mut metadata = Metadata::load(&metadata.metadata_path).unwrap();
+    metadata.offline = true;
+    metadata.update_lockfile().unwrap();
+    let paths: Vec<_> = metadata
+        .lockfile
+        .project_paths()
+        .unwrap()
+        .into_iter()
+        .map(|(_, x)| x)
+        .collect();
+    for path in &paths {
+        assert!(path.starts_with(&vendor_path));
+    }
+
+    // modified copy is detected on load
+    let path = &paths[0];
+    fs::write(path.join("Veryl.toml"), format!("{FEATURE_SUB_TOML}\n")).unwrap();
+    assert!(matches!(
+        metadata.lockfile.project_paths(),
+        Err(MetadataError::ChecksumMismatch { .. })
+    ));
+    assert!(matches!(
+        metadata.update_lockfile(),
+        Err(MetadataError::ChecksumMismatch { .. })
+    ));
+
+    // missing copy is not fetched
+    fs::remove_dir_all(path).unwrap();
+    assert!(matches!(
+        metadata.update_lockfile(),
+        Err(MetadataError::NotVendored { .. })
+    ));
+
+    let _ = metadata.lockfile.clear_cache();
+}
+
+#[test]
+fn features() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut metadata = create_project(tempdir.path(), "main", FEATURE_MAIN_TOML, false);
+    create_project(tempdir.path(), "sub", FEATURE_SUB_TOML, true);
+
+    metadata.update_lockfile().unwrap();
+    metadata.resolve_features().unwrap();
+
+    let main = &metadata.feature_table["main"];
+    let sub = &metadata.feature_table["sub"];
+    assert_eq!(main.enabled.iter().collect::<Vec<_>>(), ["a"]);
+    assert_eq!(sub.enabled.iter().collect::<Vec<_>>(), ["x", "y"]);
+
+    let project_path = metadata.project_path();
+    assert!(main.is_enabled_file(&project_path.join("a/x.veryl")));
+    assert!(!main.is_enabled_file(&project_path.join("b/x.veryl")));
+
+    metadata.feature_selection = FeatureSelection {
+        features: vec!["b".to_string()],
+        default_features: false,
+    };
+    metadata.resolve_features().unwrap();
+
+    let main = &metadata.feature_table["main"];
+    let sub = &metadata.feature_table["sub"];
+    assert_eq!(main.enabled.iter().collect::<Vec<_>>(), ["b"]);
+    assert_eq!(sub.enabled.iter().collect::<Vec<_>>(), ["y"]);
+    assert!(main.is_enabled_file(&project_path.join("b/x.veryl")));
+
+    metadata.feature_selection.features = vec!["c".to_string()];
+    assert!(matches!(
+        metadata.resolve_features(),
+        Err(MetadataError::UnknownFeature { .. })
+    ));
+
+    // `dependency/feature` from command line is passed to the dependency
+    metadata.feature_selection.features = vec!["sub/x".to_string()];
+    metadata.resolve_features().unwrap();
+
+    let main = &metadata.feature_table["main"];
+    let sub = &metadata.feature_table["sub"];
+    assert!(main.enabled.is_empty());
+    assert_eq!(sub.enabled.iter().collect::<Vec<_>>(), ["x", "y"]);
+
+    metadata.feature_selection.features = vec!["sub/w".to_string()];
+    assert!(matches!(
+        metadata.resolve_features(),
+        Err(MetadataError::UnknownFeature { .. })
+    ));
+
+    metadata.feature_selection.features = vec!["other/x".to_string()];
+    match metadata.resolve_features() {
+        Err(MetadataError::UnknownFeatureDependency {
+            name, available, ..
+        }) => {
+            assert_eq!(name, "other");
+            assert_eq!(available, "sub");
+        }
+        x => panic!("unexpected result: {x:?}"),
+    }
+
+    let _ = metadata.lockfile.clear_cache();
+}
+
+#[test]
+fn name_overrides() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut metadata = create_project(tempdir.path(), "main", OVERRIDE_MAIN_TOML, false);
+    create_project(tempdir.path(), "sub", FEATURE_SUB_TOML, true);
+
+    metadata.update_lockfile().unwrap();
+    let name_overrides = metadata.lockfile.name_overrides(&metadata);
+
+    assert_eq!(name_overrides.len(), 2);
+    assert_eq!(
+        name_overrides["sub_v0"],
+        NameOverride {
+            prefix: Some("v0_".to_string()),
+            suffix: None,
+        }
+    );
+    assert_eq!(
+        name_overrides["sub_v1"],
+        NameOverride {
+            prefix: None,
+            suffix: Some("_v1".to_string()),
+        }
+    );
+
+    let _ = metadata.lockfile.clear_cache();
+}
+
+#[test]
+fn build_hooks() {
+    let metadata: Metadata = toml::from_str(HOOKS_TOML).unwrap();
+    assert_eq!(
+        metadata.build.hooks,
+        BuildHooks {
+            pre_build: vec!["make prepare".to_string()],
+            post_emit: vec!["lint {output}".to_string(), "echo {project}".to_string()],
+            post_build: vec![],
+        }
+    );
+
+    let metadata: Metadata = toml::from_str(TEST_TOML).unwrap();
+    assert!(metadata.build.hooks.is_empty());
+
+    let toml = HOOKS_TOML.replace("pre_build", "pre_emit");
+    assert!(toml::from_str::<Metadata>(&toml).is_err());
+}
+
+#[test]
+fn build_profiles() {
+    let path = Path::new("Veryl.toml");
+    let mut metadata = Metadata::load_from_str(path, PROFILES_TOML).unwrap();
+    let build = metadata.build.with_profile("default").unwrap();
+    assert_eq!(build.reset_type, ResetType::AsyncLow);
+    assert_eq!(build.defines, ["SIM"]);
+
+    metadata.apply_profile("synth").unwrap();
+    assert_eq!(metadata.profile.as_deref(), Some("synth"));
+    assert_eq!(metadata.build.reset_type, ResetType::SyncHigh);
+    assert!(metadata.build.strip_assertions);
+    assert_eq!(metadata.build.defines, ["FPGA"]);
+    assert!(metadata.build.profiles.contains_key("synth"));
+
+    assert!(matches!(
+        metadata.apply_profile("sim"),
+        Err(MetadataError::UnknownProfile { ref available, .. }) if available == "synth"
+    ));
+
+    let toml = PROFILES_TOML.replace("strip_assertions", "strip_assertion");
+    assert!(matches!(
+        Metadata::load_from_str(path, &toml),
+        Err(MetadataError::Deserialize(_))
+    ));
+}
+
+#[test]
+fn output_layout() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut metadata = create_project(tempdir.path(), "layout", LAYOUT_TOML, false);
+
+    let base = metadata.project_path();
+    for dir in ["a", "b"] {
+        fs::create_dir(base.join(dir)).unwrap();
+        fs::write(base.join(dir).join("top.veryl"), "").unwrap();
+    }
+
+    assert!(matches!(
+        metadata.paths::<&str>(&[], false),
+        Err(MetadataError::OutputCollision { .. })
+    ));
+
+    metadata.build.output_layout = OutputLayout::Mirror;
+    let mut dst: Vec<_> = metadata
+        .paths::<&str>(&[], false)
+        .unwrap()
+        .into_iter()
+        .map(|x| x.dst)
+        .collect();
+    dst.sort();
+    assert_eq!(
+        dst,
+        [
+            base.join("out").join("a").join("top.sv"),
+            base.join("out").join("b").join("top.sv"),
+        ]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_sources() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut metadata = create_project(tempdir.path(), "layout", LAYOUT_TOML, false);
+
+    let base = metadata.project_path();
+    fs::create_dir(base.join("src")).unwrap();
+    fs::write(base.join("src").join("fifo.veryl"), "").unwrap();
+    std::os::unix::fs::symlink(base.join("src"), base.join("link")).unwrap();
+
+    let src: Vec<_> = metadata
+        .paths::<&str>(&[], true)
+        .unwrap()
+        .into_iter()
+        .map(|x| x.src)
+        .collect();
+    assert_eq!(src, [base.join("link").join("fifo.veryl")]);
+
+    let files = [
+        base.join("src").join("fifo.veryl"),
+        base.join("link").join("fifo.veryl"),
+    ];
+    let src: Vec<_> = metadata
+        .paths(&files, false)
+        .unwrap()
+        .into_iter()
+        .map(|x| x.src)
+        .collect();
+    assert_eq!(src.len(), 1);
+}
+
+#[test]
+fn excluded_sources() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut metadata = create_project(tempdir.path(), "exclude", EXCLUDE_TOML, false);
+
+    let base = metadata.project_path();
+    fs::write(base.join(".gitignore"), "target/\n").unwrap();
+    for dir in ["gen", "target", "third_party"] {
+        fs::create_dir(base.join(dir)).unwrap();
+        fs::write(base.join(dir).join(format!("{dir}.veryl")), "").unwrap();
+    }
+    fs::write(base.join("skip.veryl"), "").unwrap();
+    fs::write(base.join("top.veryl"), "").unwrap();
+
+    let src = |paths: Vec<PathSet>| -> Vec<_> { paths.into_iter().map(|x| x.src).collect() };
+
+    let paths = metadata.paths::<&str>(&[], false).unwrap();
+    assert_eq!(
+        src(paths),
+        [base.join("skip.veryl"), base.join("top.veryl")]
+    );
+
+    let paths = metadata.format_paths::<&str>(&[], false).unwrap();
+    assert_eq!(src(paths), [base.join("top.veryl")]);
+
+    metadata.no_ignore = true;
+    let paths = metadata.paths::<&str>(&[], false).unwrap();
+    assert_eq!(
+        src(paths),
+        [
+            base.join("skip.veryl"),
+            base.join("target").join("target.veryl"),
+            base.join("top.veryl"),
+        ]
+    );
+
+    // explicitly specified files are not excluded
+    let files = [base.join("third_party").join("third_party.veryl")];
+    let paths = metadata.format_paths(&files, false).unwrap();
+    assert_eq!(src(paths), files);
+
+    let toml = EXCLUDE_TOML.replace("third_party", "[third_party");
+    assert!(matches!(
+        Metadata::load_from_str(base.join("Veryl.toml"), &toml),
+        Err(MetadataError::InvalidExcludePattern { .. })
+    ));
+}
+
+#[test]
+fn workspace() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let root = tempdir.path();
+    fs::write(
+        root.join("Veryl.toml"),
+        "[workspace]\nmembers = [\"common\", \"chip\"]\n",
+    )
+    .unwrap();
+    for (name, toml) in [
+        ("common", "[project]\nname = \"common\"\nversion = \"0.1.0\"\n"),
+        (
+            "chip",
+            "[project]\nname = \"chip\"\nversion = \"0.1.0\"\n\n[dependencies]\n\"../common\" = {version = \"0.1\", name = \"lib\"}\n",
+        ),
+    ] {
+        fs::create_dir(root.join(name)).unwrap();
+        fs::write(root.join(name).join("Veryl.toml"), toml).unwrap();
+    }
+
+    assert!(Workspace::search_from(root.join("chip")).unwrap().is_none());
+    assert!(matches!(
+        Metadata::load(root.join("Veryl.toml")),
+        Err(MetadataError::WorkspaceManifest(_))
+    ));
+
+    let workspace = Workspace::search_from(root).unwrap().unwrap();
+    let members = workspace.load_members().unwrap();
+    assert_eq!(members.len(), 2);
+    assert!(members[1].dependencies.is_empty());
+    assert_eq!(members[1].workspace_dependencies["lib"], "common");
+
+    let toml = fs::read_to_string(root.join("chip").join("Veryl.toml")).unwrap();
+    let toml = toml.replace("0.1", "0.2");
+    fs::write(root.join("chip").join("Veryl.toml"), toml).unwrap();
+    assert!(matches!(
+        workspace.load_members(),
+        Err(MetadataError::VersionNotFound { .. })
+    ));
+}
@@ -0,0 +1,140 @@
+use crate::metadata::{Metadata, UrlPath};
+use crate::metadata_error::MetadataError;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    workspace: Workspace,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Workspace {
+    #[serde(default)]
+    pub members: Vec<PathBuf>,
+    #[serde(skip)]
+    pub metadata_path: PathBuf,
+}
+
+impl Workspace {
+    /// Returns the workspace if the nearest Veryl.toml from the current directory is a workspace manifest
+    pub fn search_from_current() -> Result<Option<Self>, MetadataError> {
+        Workspace::search_from(env::current_dir()?)
+    }
+
+    pub fn search_from<T: AsRef<Path>>(from: T) -> Result<Option<Self>, MetadataError> {
+        let path = Metadata::search_from(from)?;
+        let text = fs::read_to_string(&path)?;
+        if Workspace::is_manifest(&text) {
+            Ok(Some(Workspace::load(path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns whether `text` is a workspace manifest which has `[workspace]` table
+    pub fn is_manifest(text: &str) -> bool {
+        toml::from_str::<toml::Table>(text).is_ok_and(|x| x.contains_key("workspace"))
+    }
+
+    pub fn load<T: AsRef<Path>>(path: T) -> Result<Self, MetadataError> {
+        let path = path.as_ref().canonicalize()?;
+        let text = fs::read_to_string(&path)?;
+        let mut workspace = Self::from_str(&text)?;
+        workspace.metadata_path = path;
+
+        debug!(
+            "Loaded workspace ({})",
+            workspace.metadata_path.to_string_lossy()
+        );
+        Ok(workspace)
+    }
+
+    pub fn workspace_path(&self) -> PathBuf {
+        self.metadata_path.parent().unwrap().to_path_buf()
+    }
+
+    /// Loads metadata of all members.
+    ///
+    /// Dependencies which refer a member by local path are removed from `dependencies`
+    /// and registered to `workspace_dependencies` because they are built together.
+    pub fn load_members(&self) -> Result<Vec<Metadata>, MetadataError> {
+        let base = self.workspace_path();
+
+        let mut ret = Vec::new();
+        let mut names = HashSet::new();
+        for member in &self.members {
+            let path = base.join(member).join("Veryl.toml");
+            if !path.is_file() {
+                return Err(MetadataError::MemberNotFound(base.join(member)));
+            }
+            let metadata = Metadata::load(path)?;
+            if !names.insert(metadata.project.name.clone()) {
+                return Err(MetadataError::NameConflict(metadata.project.name));
+            }
+            ret.push(metadata);
+        }
+
+        let members: Vec<_> = ret
+            .iter()
+            .map(|x| {
+                (
+                    x.project_path(),
+                    x.project.name.clone(),
+                    x.project.version.clone(),
+                )
+            })
+            .collect();
+
+        for metadata in &mut ret {
+            let base = metadata.project_path();
+            let mut linked = Vec::new();
+            metadata.dependencies.retain(|url, dep| {
+                let UrlPath::Path(path) = url else {
+                    return true;
+                };
+                let Ok(path) = base.join(path).canonicalize() else {
+                    return true;
+                };
+                let Some(member) = members.iter().find(|x| x.0 == path) else {
+                    return true;
+                };
+                for entry in dep.entries() {
+                    linked.push((url.clone(), entry, member));
+                }
+                false
+            });
+
+            for (url, entry, (_, name, version)) in linked {
+                if !entry.version.matches(version) {
+                    return Err(MetadataError::VersionNotFound {
+                        url,
+                        version: entry.version.to_string(),
+                    });
+                }
+                let local_name = entry.name.unwrap_or(name.clone());
+                metadata
+                    .workspace_dependencies
+                    .insert(local_name, name.clone());
+            }
+        }
+
+        Ok(ret)
+    }
+}
+
+impl FromStr for Workspace {
+    type Err = MetadataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let manifest: Manifest = toml::from_str(s)?;
+        Ok(manifest.workspace)
+    }
+}
@@ -49,5 +49,30 @@ fn main() {
             "cargo:warning=parol build time: {} milliseconds",
             elapsed_time.as_millis()
         );
+
+        add_serde_derives("src/generated/veryl_grammar_trait.rs");
+    }
+}
+
+/// Adds serde derives to the generated syntax tree,
+/// because expressions in it are stored in the build cache as a part of symbols.
+fn add_serde_derives(path: &str) {
+    let text = fs::read_to_string(path).unwrap();
+    let mut ret = String::new();
+    let mut lines = text.lines().peekable();
+    while let Some(line) = lines.next() {
+        let is_ast_type = lines
+            .peek()
+            .map(|x| x.starts_with("pub enum ASTType"))
+            .unwrap_or(false);
+        let is_derive =
+            line == "#[derive(Builder, Debug, Clone)]" || line == "#[derive(Debug, Clone)]";
+        if is_derive && !is_ast_type {
+            ret.push_str(&line.replace("Clone)]", "Clone, serde::Serialize, serde::Deserialize)]"));
+        } else {
+            ret.push_str(line);
+        }
+        ret.push('\n');
     }
+    fs::write(path, ret).unwrap();
 }
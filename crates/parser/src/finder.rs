@@ -18,6 +18,24 @@ impl Finder {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Generic arguments are not a part of the outer identifier group,
+    /// and the group of an argument is kept if the argument is hit.
+    fn generic_argument(&mut self, arg: &WithGenericArgument) {
+        let token_group = std::mem::take(&mut self.token_group);
+        let group_hit = self.group_hit;
+        let in_group = self.in_group;
+        let lock_group = self.lock_group;
+
+        self.in_group = false;
+        self.with_generic_argument(arg);
+
+        if lock_group || !self.lock_group {
+            self.token_group = token_group;
+        }
+        self.group_hit = group_hit;
+        self.in_group = in_group;
+    }
 }
 
 impl VerylWalker for Finder {
@@ -69,7 +87,7 @@ impl VerylWalker for Finder {
             ScopedIdentifierGroup::IdentifierScopedIdentifierOpt(x) => {
                 self.identifier(&x.identifier);
                 if let Some(ref x) = x.scoped_identifier_opt {
-                    self.with_generic_argument(&x.with_generic_argument);
+                    self.generic_argument(&x.with_generic_argument);
                 }
             }
             ScopedIdentifierGroup::DollarIdentifier(x) => {
@@ -83,7 +101,7 @@ impl VerylWalker for Finder {
             self.identifier(&x.identifier);
             self.in_group = false;
             if let Some(ref x) = x.scoped_identifier_opt0 {
-                self.with_generic_argument(&x.with_generic_argument);
+                self.generic_argument(&x.with_generic_argument);
             }
         }
         if self.group_hit {
@@ -102,7 +120,7 @@ impl VerylWalker for Finder {
             ScopedIdentifierGroup::IdentifierScopedIdentifierOpt(x) => {
                 self.identifier(&x.identifier);
                 if let Some(ref x) = x.scoped_identifier_opt {
-                    self.with_generic_argument(&x.with_generic_argument);
+                    self.generic_argument(&x.with_generic_argument);
                 }
             }
             ScopedIdentifierGroup::DollarIdentifier(x) => {
@@ -116,7 +134,7 @@ impl VerylWalker for Finder {
             self.identifier(&x.identifier);
             self.in_group = false;
             if let Some(ref x) = x.scoped_identifier_opt0 {
-                self.with_generic_argument(&x.with_generic_argument);
+                self.generic_argument(&x.with_generic_argument);
             }
         }
         if let Some(ref x) = arg.expression_identifier_opt {
@@ -255,6 +255,16 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertTerm'
+    fn assert_term(&mut self, _arg: &AssertTerm) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AssumeTerm'
+    fn assume_term(&mut self, _arg: &AssumeTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AsTerm'
     fn as_term(&mut self, _arg: &AsTerm) -> Result<()> {
         Ok(())
@@ -285,6 +295,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CoverTerm'
+    fn cover_term(&mut self, _arg: &CoverTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ConstTerm'
     fn const_term(&mut self, _arg: &ConstTerm) -> Result<()> {
         Ok(())
@@ -455,6 +470,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'PropertyTerm'
+    fn property_term(&mut self, _arg: &PropertyTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ProtoTerm'
     fn proto_term(&mut self, _arg: &ProtoTerm) -> Result<()> {
         Ok(())
@@ -825,11 +845,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertToken'
+    fn assert_token(&mut self, _arg: &AssertToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignToken'
     fn assign_token(&mut self, _arg: &AssignToken) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssumeToken'
+    fn assume_token(&mut self, _arg: &AssumeToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'BitToken'
     fn bit_token(&mut self, _arg: &BitToken) -> Result<()> {
         Ok(())
@@ -855,6 +885,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CoverToken'
+    fn cover_token(&mut self, _arg: &CoverToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ConstToken'
     fn const_token(&mut self, _arg: &ConstToken) -> Result<()> {
         Ok(())
@@ -1025,6 +1060,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'PropertyToken'
+    fn property_token(&mut self, _arg: &PropertyToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ProtoToken'
     fn proto_token(&mut self, _arg: &ProtoToken) -> Result<()> {
         Ok(())
@@ -1385,11 +1425,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Assert'
+    fn assert(&mut self, _arg: &Assert) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Assign'
     fn assign(&mut self, _arg: &Assign) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Assume'
+    fn assume(&mut self, _arg: &Assume) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Bit'
     fn bit(&mut self, _arg: &Bit) -> Result<()> {
         Ok(())
@@ -1420,6 +1470,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Cover'
+    fn cover(&mut self, _arg: &Cover) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Const'
     fn r#const(&mut self, _arg: &Const) -> Result<()> {
         Ok(())
@@ -1590,6 +1645,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Property'
+    fn property(&mut self, _arg: &Property) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Proto'
     fn proto(&mut self, _arg: &Proto) -> Result<()> {
         Ok(())
@@ -1850,6 +1910,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'StructConstructor'
+    fn struct_constructor(&mut self, _arg: &StructConstructor) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'StructConstructorList'
+    fn struct_constructor_list(&mut self, _arg: &StructConstructorList) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'StructConstructorItem'
+    fn struct_constructor_item(&mut self, _arg: &StructConstructorItem) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'IfExpression'
     fn if_expression(&mut self, _arg: &IfExpression) -> Result<()> {
         Ok(())
@@ -2055,6 +2130,16 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertionKind'
+    fn assertion_kind(&mut self, _arg: &AssertionKind) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, _arg: &AssertionStatement) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, _arg: &Attribute) -> Result<()> {
         Ok(())
@@ -2195,6 +2280,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, _arg: &AssertionDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'InstDeclaration'
     fn inst_declaration(&mut self, _arg: &InstDeclaration) -> Result<()> {
         Ok(())
@@ -2486,108 +2576,108 @@ pub trait VerylGrammarTrait {
 //
 
 ///
-/// Type derived for production 340
+/// Type derived for production 352
 ///
 /// `Number: IntegralNumber;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct NumberIntegralNumber {
     pub integral_number: Box<IntegralNumber>,
 }
 
 ///
-/// Type derived for production 341
+/// Type derived for production 353
 ///
 /// `Number: RealNumber;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct NumberRealNumber {
     pub real_number: Box<RealNumber>,
 }
 
 ///
-/// Type derived for production 342
+/// Type derived for production 354
 ///
 /// `IntegralNumber: Based;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IntegralNumberBased {
     pub based: Box<Based>,
 }
 
 ///
-/// Type derived for production 343
+/// Type derived for production 355
 ///
 /// `IntegralNumber: BaseLess;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IntegralNumberBaseLess {
     pub base_less: Box<BaseLess>,
 }
 
 ///
-/// Type derived for production 344
+/// Type derived for production 356
 ///
 /// `IntegralNumber: AllBit;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IntegralNumberAllBit {
     pub all_bit: Box<AllBit>,
 }
 
 ///
-/// Type derived for production 345
+/// Type derived for production 357
 ///
 /// `RealNumber: FixedPoint;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RealNumberFixedPoint {
     pub fixed_point: Box<FixedPoint>,
 }
 
 ///
-/// Type derived for production 346
+/// Type derived for production 358
 ///
 /// `RealNumber: Exponent;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RealNumberExponent {
     pub exponent: Box<Exponent>,
 }
 
 ///
-/// Type derived for production 355
+/// Type derived for production 367
 ///
 /// `ScopedIdentifierGroup: DollarIdentifier;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifierGroupDollarIdentifier {
     pub dollar_identifier: Box<DollarIdentifier>,
 }
 
 ///
-/// Type derived for production 356
+/// Type derived for production 368
 ///
 /// `ScopedIdentifierGroup: Identifier ScopedIdentifierOpt /* Option */;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifierGroupIdentifierScopedIdentifierOpt {
     pub identifier: Box<Identifier>,
@@ -2595,120 +2685,120 @@ pub struct ScopedIdentifierGroupIdentifierScopedIdentifierOpt {
 }
 
 ///
-/// Type derived for production 401
+/// Type derived for production 413
 ///
 /// `Expression09ListGroup: Operator10;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression09ListGroupOperator10 {
     pub operator10: Box<Operator10>,
 }
 
 ///
-/// Type derived for production 402
+/// Type derived for production 414
 ///
 /// `Expression09ListGroup: Star;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression09ListGroupStar {
     pub star: Box<Star>,
 }
 
 ///
-/// Type derived for production 412
+/// Type derived for production 424
 ///
 /// `Expression12ListGroup: UnaryOperator;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12ListGroupUnaryOperator {
     pub unary_operator: Box<UnaryOperator>,
 }
 
 ///
-/// Type derived for production 413
+/// Type derived for production 425
 ///
 /// `Expression12ListGroup: Operator09;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12ListGroupOperator09 {
     pub operator09: Box<Operator09>,
 }
 
 ///
-/// Type derived for production 414
+/// Type derived for production 426
 ///
 /// `Expression12ListGroup: Operator05;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12ListGroupOperator05 {
     pub operator05: Box<Operator05>,
 }
 
 ///
-/// Type derived for production 415
+/// Type derived for production 427
 ///
 /// `Expression12ListGroup: Operator03;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12ListGroupOperator03 {
     pub operator03: Box<Operator03>,
 }
 
 ///
-/// Type derived for production 416
+/// Type derived for production 428
 ///
 /// `Expression12ListGroup: Operator04;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12ListGroupOperator04 {
     pub operator04: Box<Operator04>,
 }
 
 ///
-/// Type derived for production 418
+/// Type derived for production 430
 ///
 /// `Factor: Number;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorNumber {
     pub number: Box<Number>,
 }
 
 ///
-/// Type derived for production 419
+/// Type derived for production 431
 ///
 /// `Factor: IdentifierFactor;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorIdentifierFactor {
     pub identifier_factor: Box<IdentifierFactor>,
 }
 
 ///
-/// Type derived for production 420
+/// Type derived for production 432
 ///
 /// `Factor: LParen Expression RParen;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorLParenExpressionRParen {
     pub l_paren: Box<LParen>,
@@ -2717,12 +2807,12 @@ pub struct FactorLParenExpressionRParen {
 }
 
 ///
-/// Type derived for production 421
+/// Type derived for production 433
 ///
 /// `Factor: LBrace ConcatenationList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorLBraceConcatenationListRBrace {
     pub l_brace: Box<LBrace>,
@@ -2731,12 +2821,12 @@ pub struct FactorLBraceConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 422
+/// Type derived for production 434
 ///
 /// `Factor: QuoteLBrace ArrayLiteralList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorQuoteLBraceArrayLiteralListRBrace {
     pub quote_l_brace: Box<QuoteLBrace>,
@@ -2745,144 +2835,168 @@ pub struct FactorQuoteLBraceArrayLiteralListRBrace {
 }
 
 ///
-/// Type derived for production 423
+/// Type derived for production 435
 ///
 /// `Factor: IfExpression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorIfExpression {
     pub if_expression: Box<IfExpression>,
 }
 
 ///
-/// Type derived for production 424
+/// Type derived for production 436
 ///
 /// `Factor: CaseExpression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorCaseExpression {
     pub case_expression: Box<CaseExpression>,
 }
 
 ///
-/// Type derived for production 425
+/// Type derived for production 437
 ///
 /// `Factor: SwitchExpression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorSwitchExpression {
     pub switch_expression: Box<SwitchExpression>,
 }
 
 ///
-/// Type derived for production 426
+/// Type derived for production 438
 ///
 /// `Factor: StringLiteral;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorStringLiteral {
     pub string_literal: Box<StringLiteral>,
 }
 
 ///
-/// Type derived for production 427
+/// Type derived for production 439
 ///
 /// `Factor: FactorGroup;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorFactorGroup {
     pub factor_group: Box<FactorGroup>,
 }
 
 ///
-/// Type derived for production 428
+/// Type derived for production 440
 ///
 /// `FactorGroup: Msb;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorGroupMsb {
     pub msb: Box<Msb>,
 }
 
 ///
-/// Type derived for production 429
+/// Type derived for production 441
 ///
 /// `FactorGroup: Lsb;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorGroupLsb {
     pub lsb: Box<Lsb>,
 }
 
 ///
-/// Type derived for production 430
+/// Type derived for production 442
 ///
 /// `Factor: InsideExpression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorInsideExpression {
     pub inside_expression: Box<InsideExpression>,
 }
 
 ///
-/// Type derived for production 431
+/// Type derived for production 443
 ///
 /// `Factor: OutsideExpression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorOutsideExpression {
     pub outside_expression: Box<OutsideExpression>,
 }
 
 ///
-/// Type derived for production 432
+/// Type derived for production 444
 ///
 /// `Factor: TypeExpression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorTypeExpression {
     pub type_expression: Box<TypeExpression>,
 }
 
 ///
-/// Type derived for production 433
+/// Type derived for production 445
 ///
 /// `Factor: FactorType;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorFactorType {
     pub factor_type: Box<FactorType>,
 }
 
 ///
-/// Type derived for production 460
+/// Type derived for production 448
+///
+/// `IdentifierFactorOptGroup: FunctionCall;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IdentifierFactorOptGroupFunctionCall {
+    pub function_call: Box<FunctionCall>,
+}
+
+///
+/// Type derived for production 449
+///
+/// `IdentifierFactorOptGroup: StructConstructor;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct IdentifierFactorOptGroupStructConstructor {
+    pub struct_constructor: Box<StructConstructor>,
+}
+
+///
+/// Type derived for production 474
 ///
 /// `ArrayLiteralItemGroup: Expression ArrayLiteralItemOpt /* Option */;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralItemGroupExpressionArrayLiteralItemOpt {
     pub expression: Box<Expression>,
@@ -2890,12 +3004,12 @@ pub struct ArrayLiteralItemGroupExpressionArrayLiteralItemOpt {
 }
 
 ///
-/// Type derived for production 461
+/// Type derived for production 475
 ///
 /// `ArrayLiteralItemGroup: Defaul Colon Expression;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralItemGroupDefaulColonExpression {
     pub defaul: Box<Defaul>,
@@ -2904,312 +3018,312 @@ pub struct ArrayLiteralItemGroupDefaulColonExpression {
 }
 
 ///
-/// Type derived for production 489
+/// Type derived for production 512
 ///
 /// `SelectOperator: Colon;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SelectOperatorColon {
     pub colon: Box<Colon>,
 }
 
 ///
-/// Type derived for production 490
+/// Type derived for production 513
 ///
 /// `SelectOperator: PlusColon;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SelectOperatorPlusColon {
     pub plus_colon: Box<PlusColon>,
 }
 
 ///
-/// Type derived for production 491
+/// Type derived for production 514
 ///
 /// `SelectOperator: MinusColon;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SelectOperatorMinusColon {
     pub minus_colon: Box<MinusColon>,
 }
 
 ///
-/// Type derived for production 492
+/// Type derived for production 515
 ///
 /// `SelectOperator: Step;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SelectOperatorStep {
     pub step: Box<Step>,
 }
 
 ///
-/// Type derived for production 502
+/// Type derived for production 525
 ///
 /// `RangeOperator: DotDot;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeOperatorDotDot {
     pub dot_dot: Box<DotDot>,
 }
 
 ///
-/// Type derived for production 503
+/// Type derived for production 526
 ///
 /// `RangeOperator: DotDotEqu;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeOperatorDotDotEqu {
     pub dot_dot_equ: Box<DotDotEqu>,
 }
 
 ///
-/// Type derived for production 504
+/// Type derived for production 527
 ///
 /// `FixedType: U32;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeU32 {
     pub u32: Box<U32>,
 }
 
 ///
-/// Type derived for production 505
+/// Type derived for production 528
 ///
 /// `FixedType: U64;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeU64 {
     pub u64: Box<U64>,
 }
 
 ///
-/// Type derived for production 506
+/// Type derived for production 529
 ///
 /// `FixedType: I32;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeI32 {
     pub i32: Box<I32>,
 }
 
 ///
-/// Type derived for production 507
+/// Type derived for production 530
 ///
 /// `FixedType: I64;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeI64 {
     pub i64: Box<I64>,
 }
 
 ///
-/// Type derived for production 508
+/// Type derived for production 531
 ///
 /// `FixedType: F32;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeF32 {
     pub f32: Box<F32>,
 }
 
 ///
-/// Type derived for production 509
+/// Type derived for production 532
 ///
 /// `FixedType: F64;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeF64 {
     pub f64: Box<F64>,
 }
 
 ///
-/// Type derived for production 510
+/// Type derived for production 533
 ///
 /// `FixedType: Strin;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedTypeStrin {
     pub strin: Box<Strin>,
 }
 
 ///
-/// Type derived for production 511
+/// Type derived for production 534
 ///
 /// `VariableType: Clock;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeClock {
     pub clock: Box<Clock>,
 }
 
 ///
-/// Type derived for production 512
+/// Type derived for production 535
 ///
 /// `VariableType: ClockPosedge;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeClockPosedge {
     pub clock_posedge: Box<ClockPosedge>,
 }
 
 ///
-/// Type derived for production 513
+/// Type derived for production 536
 ///
 /// `VariableType: ClockNegedge;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeClockNegedge {
     pub clock_negedge: Box<ClockNegedge>,
 }
 
 ///
-/// Type derived for production 514
+/// Type derived for production 537
 ///
 /// `VariableType: Reset;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeReset {
     pub reset: Box<Reset>,
 }
 
 ///
-/// Type derived for production 515
+/// Type derived for production 538
 ///
 /// `VariableType: ResetAsyncHigh;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeResetAsyncHigh {
     pub reset_async_high: Box<ResetAsyncHigh>,
 }
 
 ///
-/// Type derived for production 516
+/// Type derived for production 539
 ///
 /// `VariableType: ResetAsyncLow;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeResetAsyncLow {
     pub reset_async_low: Box<ResetAsyncLow>,
 }
 
 ///
-/// Type derived for production 517
+/// Type derived for production 540
 ///
 /// `VariableType: ResetSyncHigh;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeResetSyncHigh {
     pub reset_sync_high: Box<ResetSyncHigh>,
 }
 
 ///
-/// Type derived for production 518
+/// Type derived for production 541
 ///
 /// `VariableType: ResetSyncLow;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeResetSyncLow {
     pub reset_sync_low: Box<ResetSyncLow>,
 }
 
 ///
-/// Type derived for production 519
+/// Type derived for production 542
 ///
 /// `VariableType: Logic;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeLogic {
     pub logic: Box<Logic>,
 }
 
 ///
-/// Type derived for production 520
+/// Type derived for production 543
 ///
 /// `VariableType: Bit;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VariableTypeBit {
     pub bit: Box<Bit>,
 }
 
 ///
-/// Type derived for production 522
+/// Type derived for production 545
 ///
 /// `TypeModifier: Tri;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeModifierTri {
     pub tri: Box<Tri>,
 }
 
 ///
-/// Type derived for production 523
+/// Type derived for production 546
 ///
 /// `TypeModifier: Signed;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeModifierSigned {
     pub signed: Box<Signed>,
 }
 
 ///
-/// Type derived for production 525
+/// Type derived for production 548
 ///
 /// `FactorTypeGroup: VariableType FactorTypeOpt /* Option */;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorTypeGroupVariableTypeFactorTypeOpt {
     pub variable_type: Box<VariableType>,
@@ -3217,24 +3331,24 @@ pub struct FactorTypeGroupVariableTypeFactorTypeOpt {
 }
 
 ///
-/// Type derived for production 526
+/// Type derived for production 549
 ///
 /// `FactorTypeGroup: FixedType;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorTypeGroupFixedType {
     pub fixed_type: Box<FixedType>,
 }
 
 ///
-/// Type derived for production 530
+/// Type derived for production 553
 ///
 /// `ScalarTypeGroup: UserDefinedType ScalarTypeOpt /* Option */;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScalarTypeGroupUserDefinedTypeScalarTypeOpt {
     pub user_defined_type: Box<UserDefinedType>,
@@ -3242,228 +3356,252 @@ pub struct ScalarTypeGroupUserDefinedTypeScalarTypeOpt {
 }
 
 ///
-/// Type derived for production 531
+/// Type derived for production 554
 ///
 /// `ScalarTypeGroup: FactorType;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScalarTypeGroupFactorType {
     pub factor_type: Box<FactorType>,
 }
 
 ///
-/// Type derived for production 539
+/// Type derived for production 562
 ///
 /// `CastingType: U32;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeU32 {
     pub u32: Box<U32>,
 }
 
 ///
-/// Type derived for production 540
+/// Type derived for production 563
 ///
 /// `CastingType: U64;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeU64 {
     pub u64: Box<U64>,
 }
 
 ///
-/// Type derived for production 541
+/// Type derived for production 564
 ///
 /// `CastingType: I32;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeI32 {
     pub i32: Box<I32>,
 }
 
 ///
-/// Type derived for production 542
+/// Type derived for production 565
 ///
 /// `CastingType: I64;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeI64 {
     pub i64: Box<I64>,
 }
 
 ///
-/// Type derived for production 543
+/// Type derived for production 566
 ///
 /// `CastingType: F32;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeF32 {
     pub f32: Box<F32>,
 }
 
 ///
-/// Type derived for production 544
+/// Type derived for production 567
 ///
 /// `CastingType: F64;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeF64 {
     pub f64: Box<F64>,
 }
 
 ///
-/// Type derived for production 545
+/// Type derived for production 568
+///
+/// `CastingType: Bit;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CastingTypeBit {
+    pub bit: Box<Bit>,
+}
+
+///
+/// Type derived for production 569
+///
+/// `CastingType: Logic;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CastingTypeLogic {
+    pub logic: Box<Logic>,
+}
+
+///
+/// Type derived for production 570
 ///
 /// `CastingType: Clock;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeClock {
     pub clock: Box<Clock>,
 }
 
 ///
-/// Type derived for production 546
+/// Type derived for production 571
 ///
 /// `CastingType: ClockPosedge;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeClockPosedge {
     pub clock_posedge: Box<ClockPosedge>,
 }
 
 ///
-/// Type derived for production 547
+/// Type derived for production 572
 ///
 /// `CastingType: ClockNegedge;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeClockNegedge {
     pub clock_negedge: Box<ClockNegedge>,
 }
 
 ///
-/// Type derived for production 548
+/// Type derived for production 573
 ///
 /// `CastingType: Reset;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeReset {
     pub reset: Box<Reset>,
 }
 
 ///
-/// Type derived for production 549
+/// Type derived for production 574
 ///
 /// `CastingType: ResetAsyncHigh;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeResetAsyncHigh {
     pub reset_async_high: Box<ResetAsyncHigh>,
 }
 
 ///
-/// Type derived for production 550
+/// Type derived for production 575
 ///
 /// `CastingType: ResetAsyncLow;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeResetAsyncLow {
     pub reset_async_low: Box<ResetAsyncLow>,
 }
 
 ///
-/// Type derived for production 551
+/// Type derived for production 576
 ///
 /// `CastingType: ResetSyncHigh;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeResetSyncHigh {
     pub reset_sync_high: Box<ResetSyncHigh>,
 }
 
 ///
-/// Type derived for production 552
+/// Type derived for production 577
 ///
 /// `CastingType: ResetSyncLow;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeResetSyncLow {
     pub reset_sync_low: Box<ResetSyncLow>,
 }
 
 ///
-/// Type derived for production 553
+/// Type derived for production 578
 ///
 /// `CastingType: UserDefinedType;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeUserDefinedType {
     pub user_defined_type: Box<UserDefinedType>,
 }
 
 ///
-/// Type derived for production 554
+/// Type derived for production 579
 ///
 /// `CastingType: Based;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeBased {
     pub based: Box<Based>,
 }
 
 ///
-/// Type derived for production 555
+/// Type derived for production 580
 ///
 /// `CastingType: BaseLess;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CastingTypeBaseLess {
     pub base_less: Box<BaseLess>,
 }
 
 ///
-/// Type derived for production 561
+/// Type derived for production 586
 ///
 /// `StatementBlockGroupGroup: LBrace StatementBlockGroupGroupList /* Vec */ RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockGroupGroupLBraceStatementBlockGroupGroupListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3472,348 +3610,396 @@ pub struct StatementBlockGroupGroupLBraceStatementBlockGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 564
+/// Type derived for production 589
 ///
 /// `StatementBlockGroupGroup: StatementBlockItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockGroupGroupStatementBlockItem {
     pub statement_block_item: Box<StatementBlockItem>,
 }
 
 ///
-/// Type derived for production 567
+/// Type derived for production 592
 ///
 /// `StatementBlockItem: VarDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockItemVarDeclaration {
     pub var_declaration: Box<VarDeclaration>,
 }
 
 ///
-/// Type derived for production 568
+/// Type derived for production 593
 ///
 /// `StatementBlockItem: LetStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockItemLetStatement {
     pub let_statement: Box<LetStatement>,
 }
 
 ///
-/// Type derived for production 569
+/// Type derived for production 594
 ///
 /// `StatementBlockItem: Statement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockItemStatement {
     pub statement: Box<Statement>,
 }
 
 ///
-/// Type derived for production 570
+/// Type derived for production 595
 ///
 /// `Statement: IdentifierStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementIdentifierStatement {
     pub identifier_statement: Box<IdentifierStatement>,
 }
 
 ///
-/// Type derived for production 571
+/// Type derived for production 596
 ///
 /// `Statement: IfStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementIfStatement {
     pub if_statement: Box<IfStatement>,
 }
 
 ///
-/// Type derived for production 572
+/// Type derived for production 597
 ///
 /// `Statement: IfResetStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementIfResetStatement {
     pub if_reset_statement: Box<IfResetStatement>,
 }
 
 ///
-/// Type derived for production 573
+/// Type derived for production 598
 ///
 /// `Statement: ReturnStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementReturnStatement {
     pub return_statement: Box<ReturnStatement>,
 }
 
 ///
-/// Type derived for production 574
+/// Type derived for production 599
 ///
 /// `Statement: BreakStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBreakStatement {
     pub break_statement: Box<BreakStatement>,
 }
 
 ///
-/// Type derived for production 575
+/// Type derived for production 600
 ///
 /// `Statement: ForStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementForStatement {
     pub for_statement: Box<ForStatement>,
 }
 
 ///
-/// Type derived for production 576
+/// Type derived for production 601
 ///
 /// `Statement: CaseStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementCaseStatement {
     pub case_statement: Box<CaseStatement>,
 }
 
 ///
-/// Type derived for production 577
+/// Type derived for production 602
 ///
 /// `Statement: SwitchStatement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementSwitchStatement {
     pub switch_statement: Box<SwitchStatement>,
 }
 
 ///
-/// Type derived for production 582
+/// Type derived for production 603
+///
+/// `Statement: AssertionStatement;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StatementAssertionStatement {
+    pub assertion_statement: Box<AssertionStatement>,
+}
+
+///
+/// Type derived for production 608
 ///
 /// `IdentifierStatementGroup: FunctionCall;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierStatementGroupFunctionCall {
     pub function_call: Box<FunctionCall>,
 }
 
 ///
-/// Type derived for production 583
+/// Type derived for production 609
 ///
 /// `IdentifierStatementGroup: Assignment;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierStatementGroupAssignment {
     pub assignment: Box<Assignment>,
 }
 
 ///
-/// Type derived for production 585
+/// Type derived for production 611
 ///
 /// `AssignmentGroup: Equ;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignmentGroupEqu {
     pub equ: Box<Equ>,
 }
 
 ///
-/// Type derived for production 586
+/// Type derived for production 612
 ///
 /// `AssignmentGroup: AssignmentOperator;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignmentGroupAssignmentOperator {
     pub assignment_operator: Box<AssignmentOperator>,
 }
 
 ///
-/// Type derived for production 606
+/// Type derived for production 632
 ///
 /// `CaseItemGroup0: Statement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseItemGroup0Statement {
     pub statement: Box<Statement>,
 }
 
 ///
-/// Type derived for production 607
+/// Type derived for production 633
 ///
 /// `CaseItemGroup0: StatementBlock;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseItemGroup0StatementBlock {
     pub statement_block: Box<StatementBlock>,
 }
 
 ///
-/// Type derived for production 608
+/// Type derived for production 634
 ///
 /// `CaseItemGroup: CaseCondition;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseItemGroupCaseCondition {
     pub case_condition: Box<CaseCondition>,
 }
 
 ///
-/// Type derived for production 609
+/// Type derived for production 635
 ///
 /// `CaseItemGroup: Defaul;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseItemGroupDefaul {
     pub defaul: Box<Defaul>,
 }
 
 ///
-/// Type derived for production 617
+/// Type derived for production 643
 ///
 /// `SwitchItemGroup0: Statement;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchItemGroup0Statement {
     pub statement: Box<Statement>,
 }
 
 ///
-/// Type derived for production 618
+/// Type derived for production 644
 ///
 /// `SwitchItemGroup0: StatementBlock;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchItemGroup0StatementBlock {
     pub statement_block: Box<StatementBlock>,
 }
 
 ///
-/// Type derived for production 619
+/// Type derived for production 645
 ///
 /// `SwitchItemGroup: SwitchCondition;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchItemGroupSwitchCondition {
     pub switch_condition: Box<SwitchCondition>,
 }
 
 ///
-/// Type derived for production 620
+/// Type derived for production 646
 ///
 /// `SwitchItemGroup: Defaul;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchItemGroupDefaul {
     pub defaul: Box<Defaul>,
 }
 
 ///
-/// Type derived for production 632
+/// Type derived for production 650
+///
+/// `AssertionKind: Assert;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKindAssert {
+    pub assert: Box<Assert>,
+}
+
+///
+/// Type derived for production 651
+///
+/// `AssertionKind: Assume;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKindAssume {
+    pub assume: Box<Assume>,
+}
+
+///
+/// Type derived for production 652
+///
+/// `AssertionKind: Cover;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKindCover {
+    pub cover: Box<Cover>,
+}
+
+///
+/// Type derived for production 664
 ///
 /// `AttributeItem: Identifier;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AttributeItemIdentifier {
     pub identifier: Box<Identifier>,
 }
 
 ///
-/// Type derived for production 633
+/// Type derived for production 665
 ///
 /// `AttributeItem: StringLiteral;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AttributeItemStringLiteral {
     pub string_literal: Box<StringLiteral>,
 }
 
 ///
-/// Type derived for production 641
+/// Type derived for production 673
 ///
 /// `ConstDeclarationGroup: ArrayType;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConstDeclarationGroupArrayType {
     pub array_type: Box<ArrayType>,
 }
 
 ///
-/// Type derived for production 642
+/// Type derived for production 674
 ///
 /// `ConstDeclarationGroup: Type;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConstDeclarationGroupType {
     pub r#type: Box<Type>,
 }
 
 ///
-/// Type derived for production 661
+/// Type derived for production 693
 ///
 /// `ModportGroupGroup: LBrace ModportList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportGroupGroupLBraceModportListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3822,24 +4008,24 @@ pub struct ModportGroupGroupLBraceModportListRBrace {
 }
 
 ///
-/// Type derived for production 662
+/// Type derived for production 694
 ///
 /// `ModportGroupGroup: ModportItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportGroupGroupModportItem {
     pub modport_item: Box<ModportItem>,
 }
 
 ///
-/// Type derived for production 675
+/// Type derived for production 707
 ///
 /// `EnumGroupGroup: LBrace EnumList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumGroupGroupLBraceEnumListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3848,48 +4034,48 @@ pub struct EnumGroupGroupLBraceEnumListRBrace {
 }
 
 ///
-/// Type derived for production 676
+/// Type derived for production 708
 ///
 /// `EnumGroupGroup: EnumItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumGroupGroupEnumItem {
     pub enum_item: Box<EnumItem>,
 }
 
 ///
-/// Type derived for production 682
+/// Type derived for production 714
 ///
 /// `StructUnion: Struct;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionStruct {
     pub r#struct: Box<Struct>,
 }
 
 ///
-/// Type derived for production 683
+/// Type derived for production 715
 ///
 /// `StructUnion: Union;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionUnion {
     pub r#union: Box<Union>,
 }
 
 ///
-/// Type derived for production 693
+/// Type derived for production 725
 ///
 /// `StructUnionGroupGroup: LBrace StructUnionList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionGroupGroupLBraceStructUnionListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3898,24 +4084,24 @@ pub struct StructUnionGroupGroupLBraceStructUnionListRBrace {
 }
 
 ///
-/// Type derived for production 694
+/// Type derived for production 726
 ///
 /// `StructUnionGroupGroup: StructUnionItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionGroupGroupStructUnionItem {
     pub struct_union_item: Box<StructUnionItem>,
 }
 
 ///
-/// Type derived for production 718
+/// Type derived for production 753
 ///
 /// `InstParameterGroupGroup: LBrace InstParameterList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterGroupGroupLBraceInstParameterListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3924,24 +4110,24 @@ pub struct InstParameterGroupGroupLBraceInstParameterListRBrace {
 }
 
 ///
-/// Type derived for production 719
+/// Type derived for production 754
 ///
 /// `InstParameterGroupGroup: InstParameterItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterGroupGroupInstParameterItem {
     pub inst_parameter_item: Box<InstParameterItem>,
 }
 
 ///
-/// Type derived for production 731
+/// Type derived for production 766
 ///
 /// `InstPortGroupGroup: LBrace InstPortList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortGroupGroupLBraceInstPortListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3950,24 +4136,24 @@ pub struct InstPortGroupGroupLBraceInstPortListRBrace {
 }
 
 ///
-/// Type derived for production 732
+/// Type derived for production 767
 ///
 /// `InstPortGroupGroup: InstPortItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortGroupGroupInstPortItem {
     pub inst_port_item: Box<InstPortItem>,
 }
 
 ///
-/// Type derived for production 747
+/// Type derived for production 782
 ///
 /// `WithParameterGroupGroup: LBrace WithParameterList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterGroupGroupLBraceWithParameterListRBrace {
     pub l_brace: Box<LBrace>,
@@ -3976,96 +4162,96 @@ pub struct WithParameterGroupGroupLBraceWithParameterListRBrace {
 }
 
 ///
-/// Type derived for production 748
+/// Type derived for production 783
 ///
 /// `WithParameterGroupGroup: WithParameterItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterGroupGroupWithParameterItem {
     pub with_parameter_item: Box<WithParameterItem>,
 }
 
 ///
-/// Type derived for production 752
+/// Type derived for production 787
 ///
 /// `WithParameterItemGroup0: ArrayType;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterItemGroup0ArrayType {
     pub array_type: Box<ArrayType>,
 }
 
 ///
-/// Type derived for production 753
+/// Type derived for production 788
 ///
 /// `WithParameterItemGroup0: Type;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterItemGroup0Type {
     pub r#type: Box<Type>,
 }
 
 ///
-/// Type derived for production 754
+/// Type derived for production 789
 ///
 /// `WithParameterItemGroup: Param;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterItemGroupParam {
     pub param: Box<Param>,
 }
 
 ///
-/// Type derived for production 755
+/// Type derived for production 790
 ///
 /// `WithParameterItemGroup: Const;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterItemGroupConst {
     pub r#const: Box<Const>,
 }
 
 ///
-/// Type derived for production 756
+/// Type derived for production 791
 ///
 /// `GenericBound: Const;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenericBoundConst {
     pub r#const: Box<Const>,
 }
 
 ///
-/// Type derived for production 757
+/// Type derived for production 792
 ///
 /// `GenericBound: Type;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenericBoundType {
     pub r#type: Box<Type>,
 }
 
 ///
-/// Type derived for production 758
+/// Type derived for production 793
 ///
 /// `GenericBound: Inst ScopedIdentifier;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenericBoundInstScopedIdentifier {
     pub inst: Box<Inst>,
@@ -4073,48 +4259,48 @@ pub struct GenericBoundInstScopedIdentifier {
 }
 
 ///
-/// Type derived for production 759
+/// Type derived for production 794
 ///
 /// `GenericBound: ScopedIdentifier;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenericBoundScopedIdentifier {
     pub scoped_identifier: Box<ScopedIdentifier>,
 }
 
 ///
-/// Type derived for production 777
+/// Type derived for production 812
 ///
 /// `WithGenericArgumentItem: ScopedIdentifier;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgumentItemScopedIdentifier {
     pub scoped_identifier: Box<ScopedIdentifier>,
 }
 
 ///
-/// Type derived for production 778
+/// Type derived for production 813
 ///
 /// `WithGenericArgumentItem: Number;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgumentItemNumber {
     pub number: Box<Number>,
 }
 
 ///
-/// Type derived for production 788
+/// Type derived for production 823
 ///
 /// `PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationGroupGroupLBracePortDeclarationListRBrace {
     pub l_brace: Box<LBrace>,
@@ -4123,132 +4309,132 @@ pub struct PortDeclarationGroupGroupLBracePortDeclarationListRBrace {
 }
 
 ///
-/// Type derived for production 789
+/// Type derived for production 824
 ///
 /// `PortDeclarationGroupGroup: PortDeclarationItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationGroupGroupPortDeclarationItem {
     pub port_declaration_item: Box<PortDeclarationItem>,
 }
 
 ///
-/// Type derived for production 793
+/// Type derived for production 828
 ///
 /// `PortDeclarationItemGroup: PortTypeConcrete;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationItemGroupPortTypeConcrete {
     pub port_type_concrete: Box<PortTypeConcrete>,
 }
 
 ///
-/// Type derived for production 794
+/// Type derived for production 829
 ///
 /// `PortDeclarationItemGroup: PortTypeAbstract;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationItemGroupPortTypeAbstract {
     pub port_type_abstract: Box<PortTypeAbstract>,
 }
 
 ///
-/// Type derived for production 808
+/// Type derived for production 843
 ///
 /// `Direction: Input;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DirectionInput {
     pub input: Box<Input>,
 }
 
 ///
-/// Type derived for production 809
+/// Type derived for production 844
 ///
 /// `Direction: Output;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DirectionOutput {
     pub output: Box<Output>,
 }
 
 ///
-/// Type derived for production 810
+/// Type derived for production 845
 ///
 /// `Direction: Inout;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DirectionInout {
     pub inout: Box<Inout>,
 }
 
 ///
-/// Type derived for production 811
+/// Type derived for production 846
 ///
 /// `Direction: Ref;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DirectionRef {
     pub r#ref: Box<Ref>,
 }
 
 ///
-/// Type derived for production 812
+/// Type derived for production 847
 ///
 /// `Direction: Modport;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DirectionModport {
     pub modport: Box<Modport>,
 }
 
 ///
-/// Type derived for production 813
+/// Type derived for production 848
 ///
 /// `Direction: Import;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DirectionImport {
     pub import: Box<Import>,
 }
 
 ///
-/// Type derived for production 825
+/// Type derived for production 860
 ///
 /// `ExportDeclarationGroup: Star;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExportDeclarationGroupStar {
     pub star: Box<Star>,
 }
 
 ///
-/// Type derived for production 826
+/// Type derived for production 861
 ///
 /// `ExportDeclarationGroup: ScopedIdentifier ExportDeclarationOpt /* Option */;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExportDeclarationGroupScopedIdentifierExportDeclarationOpt {
     pub scoped_identifier: Box<ScopedIdentifier>,
@@ -4256,12 +4442,12 @@ pub struct ExportDeclarationGroupScopedIdentifierExportDeclarationOpt {
 }
 
 ///
-/// Type derived for production 846
+/// Type derived for production 881
 ///
 /// `ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleGroupGroupLBraceModuleGroupGroupListRBrace {
     pub l_brace: Box<LBrace>,
@@ -4270,24 +4456,24 @@ pub struct ModuleGroupGroupLBraceModuleGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 849
+/// Type derived for production 884
 ///
 /// `ModuleGroupGroup: ModuleItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleGroupGroupModuleItem {
     pub module_item: Box<ModuleItem>,
 }
 
 ///
-/// Type derived for production 863
+/// Type derived for production 898
 ///
 /// `InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceGroupGroupLBraceInterfaceGroupGroupListRBrace {
     pub l_brace: Box<LBrace>,
@@ -4296,48 +4482,48 @@ pub struct InterfaceGroupGroupLBraceInterfaceGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 866
+/// Type derived for production 901
 ///
 /// `InterfaceGroupGroup: InterfaceItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceGroupGroupInterfaceItem {
     pub interface_item: Box<InterfaceItem>,
 }
 
 ///
-/// Type derived for production 869
+/// Type derived for production 904
 ///
 /// `InterfaceItem: GenerateItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceItemGenerateItem {
     pub generate_item: Box<GenerateItem>,
 }
 
 ///
-/// Type derived for production 870
+/// Type derived for production 905
 ///
 /// `InterfaceItem: ModportDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceItemModportDeclaration {
     pub modport_declaration: Box<ModportDeclaration>,
 }
 
 ///
-/// Type derived for production 889
+/// Type derived for production 924
 ///
 /// `GenerateGroupGroup: LBrace GenerateGroupGroupList /* Vec */ RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateGroupGroupLBraceGenerateGroupGroupListRBrace {
     pub l_brace: Box<LBrace>,
@@ -4346,240 +4532,252 @@ pub struct GenerateGroupGroupLBraceGenerateGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 892
+/// Type derived for production 927
 ///
 /// `GenerateGroupGroup: GenerateItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateGroupGroupGenerateItem {
     pub generate_item: Box<GenerateItem>,
 }
 
 ///
-/// Type derived for production 895
+/// Type derived for production 930
 ///
 /// `GenerateItem: LetDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemLetDeclaration {
     pub let_declaration: Box<LetDeclaration>,
 }
 
 ///
-/// Type derived for production 896
+/// Type derived for production 931
 ///
 /// `GenerateItem: VarDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemVarDeclaration {
     pub var_declaration: Box<VarDeclaration>,
 }
 
 ///
-/// Type derived for production 897
+/// Type derived for production 932
 ///
 /// `GenerateItem: InstDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemInstDeclaration {
     pub inst_declaration: Box<InstDeclaration>,
 }
 
 ///
-/// Type derived for production 898
+/// Type derived for production 933
 ///
 /// `GenerateItem: ConstDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemConstDeclaration {
     pub const_declaration: Box<ConstDeclaration>,
 }
 
 ///
-/// Type derived for production 899
+/// Type derived for production 934
 ///
 /// `GenerateItem: AlwaysFfDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemAlwaysFfDeclaration {
     pub always_ff_declaration: Box<AlwaysFfDeclaration>,
 }
 
 ///
-/// Type derived for production 900
+/// Type derived for production 935
 ///
 /// `GenerateItem: AlwaysCombDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemAlwaysCombDeclaration {
     pub always_comb_declaration: Box<AlwaysCombDeclaration>,
 }
 
 ///
-/// Type derived for production 901
+/// Type derived for production 936
 ///
 /// `GenerateItem: AssignDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemAssignDeclaration {
     pub assign_declaration: Box<AssignDeclaration>,
 }
 
 ///
-/// Type derived for production 902
+/// Type derived for production 937
 ///
 /// `GenerateItem: FunctionDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemFunctionDeclaration {
     pub function_declaration: Box<FunctionDeclaration>,
 }
 
 ///
-/// Type derived for production 903
+/// Type derived for production 938
 ///
 /// `GenerateItem: GenerateIfDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemGenerateIfDeclaration {
     pub generate_if_declaration: Box<GenerateIfDeclaration>,
 }
 
 ///
-/// Type derived for production 904
+/// Type derived for production 939
 ///
 /// `GenerateItem: GenerateForDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemGenerateForDeclaration {
     pub generate_for_declaration: Box<GenerateForDeclaration>,
 }
 
 ///
-/// Type derived for production 905
+/// Type derived for production 940
 ///
 /// `GenerateItem: GenerateBlockDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemGenerateBlockDeclaration {
     pub generate_block_declaration: Box<GenerateBlockDeclaration>,
 }
 
 ///
-/// Type derived for production 906
+/// Type derived for production 941
 ///
 /// `GenerateItem: TypeDefDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemTypeDefDeclaration {
     pub type_def_declaration: Box<TypeDefDeclaration>,
 }
 
 ///
-/// Type derived for production 907
+/// Type derived for production 942
 ///
 /// `GenerateItem: EnumDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemEnumDeclaration {
     pub enum_declaration: Box<EnumDeclaration>,
 }
 
 ///
-/// Type derived for production 908
+/// Type derived for production 943
 ///
 /// `GenerateItem: StructUnionDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemStructUnionDeclaration {
     pub struct_union_declaration: Box<StructUnionDeclaration>,
 }
 
 ///
-/// Type derived for production 909
+/// Type derived for production 944
 ///
 /// `GenerateItem: ImportDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemImportDeclaration {
     pub import_declaration: Box<ImportDeclaration>,
 }
 
 ///
-/// Type derived for production 910
+/// Type derived for production 945
 ///
 /// `GenerateItem: InitialDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemInitialDeclaration {
     pub initial_declaration: Box<InitialDeclaration>,
 }
 
 ///
-/// Type derived for production 911
+/// Type derived for production 946
 ///
 /// `GenerateItem: FinalDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemFinalDeclaration {
     pub final_declaration: Box<FinalDeclaration>,
 }
 
 ///
-/// Type derived for production 912
+/// Type derived for production 947
+///
+/// `GenerateItem: AssertionDeclaration;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct GenerateItemAssertionDeclaration {
+    pub assertion_declaration: Box<AssertionDeclaration>,
+}
+
+///
+/// Type derived for production 948
 ///
 /// `GenerateItem: UnsafeBlock;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateItemUnsafeBlock {
     pub unsafe_block: Box<UnsafeBlock>,
 }
 
 ///
-/// Type derived for production 921
+/// Type derived for production 957
 ///
 /// `PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageGroupGroupLBracePackageGroupGroupListRBrace {
     pub l_brace: Box<LBrace>,
@@ -4588,120 +4786,120 @@ pub struct PackageGroupGroupLBracePackageGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 924
+/// Type derived for production 960
 ///
 /// `PackageGroupGroup: PackageItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageGroupGroupPackageItem {
     pub package_item: Box<PackageItem>,
 }
 
 ///
-/// Type derived for production 927
+/// Type derived for production 963
 ///
 /// `PackageItem: VarDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemVarDeclaration {
     pub var_declaration: Box<VarDeclaration>,
 }
 
 ///
-/// Type derived for production 928
+/// Type derived for production 964
 ///
 /// `PackageItem: ConstDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemConstDeclaration {
     pub const_declaration: Box<ConstDeclaration>,
 }
 
 ///
-/// Type derived for production 929
+/// Type derived for production 965
 ///
 /// `PackageItem: TypeDefDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemTypeDefDeclaration {
     pub type_def_declaration: Box<TypeDefDeclaration>,
 }
 
 ///
-/// Type derived for production 930
+/// Type derived for production 966
 ///
 /// `PackageItem: EnumDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemEnumDeclaration {
     pub enum_declaration: Box<EnumDeclaration>,
 }
 
 ///
-/// Type derived for production 931
+/// Type derived for production 967
 ///
 /// `PackageItem: StructUnionDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemStructUnionDeclaration {
     pub struct_union_declaration: Box<StructUnionDeclaration>,
 }
 
 ///
-/// Type derived for production 932
+/// Type derived for production 968
 ///
 /// `PackageItem: FunctionDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemFunctionDeclaration {
     pub function_declaration: Box<FunctionDeclaration>,
 }
 
 ///
-/// Type derived for production 933
+/// Type derived for production 969
 ///
 /// `PackageItem: ImportDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemImportDeclaration {
     pub import_declaration: Box<ImportDeclaration>,
 }
 
 ///
-/// Type derived for production 934
+/// Type derived for production 970
 ///
 /// `PackageItem: ExportDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageItemExportDeclaration {
     pub export_declaration: Box<ExportDeclaration>,
 }
 
 ///
-/// Type derived for production 947
+/// Type derived for production 983
 ///
 /// `EmbedItem: LBraceTerm EmbedItemList /* Vec */ RBraceTerm;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedItemLBraceTermEmbedItemListRBraceTerm {
     pub l_brace_term: Box<LBraceTerm>,
@@ -4710,24 +4908,24 @@ pub struct EmbedItemLBraceTermEmbedItemListRBraceTerm {
 }
 
 ///
-/// Type derived for production 950
+/// Type derived for production 986
 ///
 /// `EmbedItem: AnyTerm;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedItemAnyTerm {
     pub any_term: Box<AnyTerm>,
 }
 
 ///
-/// Type derived for production 953
+/// Type derived for production 989
 ///
 /// `DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionGroupGroupLBraceDescriptionGroupGroupListRBrace {
     pub l_brace: Box<LBrace>,
@@ -4736,96 +4934,96 @@ pub struct DescriptionGroupGroupLBraceDescriptionGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 956
+/// Type derived for production 992
 ///
 /// `DescriptionGroupGroup: DescriptionItem;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionGroupGroupDescriptionItem {
     pub description_item: Box<DescriptionItem>,
 }
 
 ///
-/// Type derived for production 959
+/// Type derived for production 995
 ///
 /// `DescriptionItem: ModuleDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemModuleDeclaration {
     pub module_declaration: Box<ModuleDeclaration>,
 }
 
 ///
-/// Type derived for production 960
+/// Type derived for production 996
 ///
 /// `DescriptionItem: InterfaceDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemInterfaceDeclaration {
     pub interface_declaration: Box<InterfaceDeclaration>,
 }
 
 ///
-/// Type derived for production 961
+/// Type derived for production 997
 ///
 /// `DescriptionItem: PackageDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemPackageDeclaration {
     pub package_declaration: Box<PackageDeclaration>,
 }
 
 ///
-/// Type derived for production 962
+/// Type derived for production 998
 ///
 /// `DescriptionItem: ProtoModuleDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemProtoModuleDeclaration {
     pub proto_module_declaration: Box<ProtoModuleDeclaration>,
 }
 
 ///
-/// Type derived for production 963
+/// Type derived for production 999
 ///
 /// `DescriptionItem: ImportDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemImportDeclaration {
     pub import_declaration: Box<ImportDeclaration>,
 }
 
 ///
-/// Type derived for production 964
+/// Type derived for production 1000
 ///
 /// `DescriptionItem: EmbedDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemEmbedDeclaration {
     pub embed_declaration: Box<EmbedDeclaration>,
 }
 
 ///
-/// Type derived for production 965
+/// Type derived for production 1001
 ///
 /// `DescriptionItem: IncludeDeclaration;`
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionItemIncludeDeclaration {
     pub include_declaration: Box<IncludeDeclaration>,
@@ -4840,7 +5038,7 @@ pub struct DescriptionItemIncludeDeclaration {
 /// Type derived for non-terminal AllBit
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AllBit {
     pub all_bit_token: crate::veryl_token::VerylToken,
@@ -4850,7 +5048,7 @@ pub struct AllBit {
 /// Type derived for non-terminal AllBitTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AllBitTerm {
     pub all_bit_term: crate::veryl_token::Token, /* (?:[0-9]+(?:_[0-9]+)*)?'[01xzXZ] */
@@ -4860,7 +5058,7 @@ pub struct AllBitTerm {
 /// Type derived for non-terminal AllBitToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AllBitToken {
     pub all_bit_term: crate::veryl_token::Token,
@@ -4871,7 +5069,7 @@ pub struct AllBitToken {
 /// Type derived for non-terminal AlwaysComb
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysComb {
     pub always_comb_token: crate::veryl_token::VerylToken,
@@ -4881,7 +5079,7 @@ pub struct AlwaysComb {
 /// Type derived for non-terminal AlwaysCombDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysCombDeclaration {
     pub always_comb: Box<AlwaysComb>,
@@ -4892,7 +5090,7 @@ pub struct AlwaysCombDeclaration {
 /// Type derived for non-terminal AlwaysCombTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysCombTerm {
     pub always_comb_term: crate::veryl_token::Token, /* (?-u:\b)always_comb(?-u:\b) */
@@ -4902,7 +5100,7 @@ pub struct AlwaysCombTerm {
 /// Type derived for non-terminal AlwaysCombToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysCombToken {
     pub always_comb_term: crate::veryl_token::Token,
@@ -4913,7 +5111,7 @@ pub struct AlwaysCombToken {
 /// Type derived for non-terminal AlwaysFf
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFf {
     pub always_ff_token: crate::veryl_token::VerylToken,
@@ -4923,7 +5121,7 @@ pub struct AlwaysFf {
 /// Type derived for non-terminal AlwaysFfClock
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfClock {
     pub hierarchical_identifier: Box<HierarchicalIdentifier>,
@@ -4933,7 +5131,7 @@ pub struct AlwaysFfClock {
 /// Type derived for non-terminal AlwaysFfDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfDeclaration {
     pub always_ff: Box<AlwaysFf>,
@@ -4945,7 +5143,7 @@ pub struct AlwaysFfDeclaration {
 /// Type derived for non-terminal AlwaysFfDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfDeclarationOpt {
     pub always_ff_event_list: Box<AlwaysFfEventList>,
@@ -4955,7 +5153,7 @@ pub struct AlwaysFfDeclarationOpt {
 /// Type derived for non-terminal AlwaysFfEventList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfEventList {
     pub l_paren: Box<LParen>,
@@ -4968,7 +5166,7 @@ pub struct AlwaysFfEventList {
 /// Type derived for non-terminal AlwaysFfEventListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfEventListOpt {
     pub comma: Box<Comma>,
@@ -4979,7 +5177,7 @@ pub struct AlwaysFfEventListOpt {
 /// Type derived for non-terminal AlwaysFfReset
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfReset {
     pub hierarchical_identifier: Box<HierarchicalIdentifier>,
@@ -4989,7 +5187,7 @@ pub struct AlwaysFfReset {
 /// Type derived for non-terminal AlwaysFfTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfTerm {
     pub always_ff_term: crate::veryl_token::Token, /* (?-u:\b)always_ff(?-u:\b) */
@@ -4999,7 +5197,7 @@ pub struct AlwaysFfTerm {
 /// Type derived for non-terminal AlwaysFfToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AlwaysFfToken {
     pub always_ff_term: crate::veryl_token::Token,
@@ -5010,7 +5208,7 @@ pub struct AlwaysFfToken {
 /// Type derived for non-terminal AnyTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AnyTerm {
     pub any_term: crate::veryl_token::Token, /* [^{}]* */
@@ -5020,7 +5218,7 @@ pub struct AnyTerm {
 /// Type derived for non-terminal ArgumentItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArgumentItem {
     pub expression: Box<Expression>,
@@ -5030,7 +5228,7 @@ pub struct ArgumentItem {
 /// Type derived for non-terminal ArgumentList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArgumentList {
     pub argument_item: Box<ArgumentItem>,
@@ -5042,7 +5240,7 @@ pub struct ArgumentList {
 /// Type derived for non-terminal ArgumentListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArgumentListList {
     pub comma: Box<Comma>,
@@ -5053,7 +5251,7 @@ pub struct ArgumentListList {
 /// Type derived for non-terminal ArgumentListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArgumentListOpt {
     pub comma: Box<Comma>,
@@ -5063,7 +5261,7 @@ pub struct ArgumentListOpt {
 /// Type derived for non-terminal Array
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Array {
     pub l_bracket: Box<LBracket>,
@@ -5076,7 +5274,7 @@ pub struct Array {
 /// Type derived for non-terminal ArrayList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayList {
     pub comma: Box<Comma>,
@@ -5087,7 +5285,7 @@ pub struct ArrayList {
 /// Type derived for non-terminal ArrayLiteralItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralItem {
     pub array_literal_item_group: Box<ArrayLiteralItemGroup>,
@@ -5097,7 +5295,7 @@ pub struct ArrayLiteralItem {
 /// Type derived for non-terminal ArrayLiteralItemGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ArrayLiteralItemGroup {
     ExpressionArrayLiteralItemOpt(ArrayLiteralItemGroupExpressionArrayLiteralItemOpt),
     DefaulColonExpression(ArrayLiteralItemGroupDefaulColonExpression),
@@ -5107,7 +5305,7 @@ pub enum ArrayLiteralItemGroup {
 /// Type derived for non-terminal ArrayLiteralItemOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralItemOpt {
     pub repeat: Box<Repeat>,
@@ -5118,7 +5316,7 @@ pub struct ArrayLiteralItemOpt {
 /// Type derived for non-terminal ArrayLiteralList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralList {
     pub array_literal_item: Box<ArrayLiteralItem>,
@@ -5130,7 +5328,7 @@ pub struct ArrayLiteralList {
 /// Type derived for non-terminal ArrayLiteralListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralListList {
     pub comma: Box<Comma>,
@@ -5141,7 +5339,7 @@ pub struct ArrayLiteralListList {
 /// Type derived for non-terminal ArrayLiteralListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayLiteralListOpt {
     pub comma: Box<Comma>,
@@ -5151,7 +5349,7 @@ pub struct ArrayLiteralListOpt {
 /// Type derived for non-terminal ArrayType
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayType {
     pub scalar_type: Box<ScalarType>,
@@ -5162,7 +5360,7 @@ pub struct ArrayType {
 /// Type derived for non-terminal ArrayTypeOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ArrayTypeOpt {
     pub array: Box<Array>,
@@ -5172,7 +5370,7 @@ pub struct ArrayTypeOpt {
 /// Type derived for non-terminal As
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct As {
     pub as_token: crate::veryl_token::VerylToken,
@@ -5182,7 +5380,7 @@ pub struct As {
 /// Type derived for non-terminal AsTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AsTerm {
     pub as_term: crate::veryl_token::Token, /* (?-u:\b)as(?-u:\b) */
@@ -5192,18 +5390,113 @@ pub struct AsTerm {
 /// Type derived for non-terminal AsToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AsToken {
     pub as_term: crate::veryl_token::Token,
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Assert
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assert {
+    pub assert_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssertTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertTerm {
+    pub assert_term: crate::veryl_token::Token, /* (?-u:\b)assert(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal AssertToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertToken {
+    pub assert_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclaration {
+    pub assertion_kind: Box<AssertionKind>,
+    pub property: Box<Property>,
+    pub l_paren: Box<LParen>,
+    pub expression: Box<Expression>,
+    pub r_paren: Box<RParen>,
+    pub assertion_declaration_opt: Option<AssertionDeclarationOpt>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclarationOpt {
+    pub r#else: Box<Else>,
+    pub string_literal: Box<StringLiteral>,
+}
+
+///
+/// Type derived for non-terminal AssertionKind
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum AssertionKind {
+    Assert(AssertionKindAssert),
+    Assume(AssertionKindAssume),
+    Cover(AssertionKindCover),
+}
+
+///
+/// Type derived for non-terminal AssertionStatement
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionStatement {
+    pub assertion_kind: Box<AssertionKind>,
+    pub l_paren: Box<LParen>,
+    pub expression: Box<Expression>,
+    pub r_paren: Box<RParen>,
+    pub assertion_statement_opt: Option<AssertionStatementOpt>,
+    pub semicolon: Box<Semicolon>,
+}
+
+///
+/// Type derived for non-terminal AssertionStatementOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionStatementOpt {
+    pub r#else: Box<Else>,
+    pub string_literal: Box<StringLiteral>,
+}
+
 ///
 /// Type derived for non-terminal Assign
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Assign {
     pub assign_token: crate::veryl_token::VerylToken,
@@ -5213,7 +5506,7 @@ pub struct Assign {
 /// Type derived for non-terminal AssignDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignDeclaration {
     pub assign: Box<Assign>,
@@ -5227,7 +5520,7 @@ pub struct AssignDeclaration {
 /// Type derived for non-terminal AssignTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignTerm {
     pub assign_term: crate::veryl_token::Token, /* (?-u:\b)assign(?-u:\b) */
@@ -5237,7 +5530,7 @@ pub struct AssignTerm {
 /// Type derived for non-terminal AssignToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignToken {
     pub assign_term: crate::veryl_token::Token,
@@ -5248,7 +5541,7 @@ pub struct AssignToken {
 /// Type derived for non-terminal Assignment
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Assignment {
     pub assignment_group: Box<AssignmentGroup>,
@@ -5259,7 +5552,7 @@ pub struct Assignment {
 /// Type derived for non-terminal AssignmentGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum AssignmentGroup {
     Equ(AssignmentGroupEqu),
     AssignmentOperator(AssignmentGroupAssignmentOperator),
@@ -5269,7 +5562,7 @@ pub enum AssignmentGroup {
 /// Type derived for non-terminal AssignmentOperator
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignmentOperator {
     pub assignment_operator_token: crate::veryl_token::VerylToken,
@@ -5279,7 +5572,7 @@ pub struct AssignmentOperator {
 /// Type derived for non-terminal AssignmentOperatorTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignmentOperatorTerm {
     pub assignment_operator_term: crate::veryl_token::Token, /* \+=|-=|\*=|/=|%=|&=|\|=|\^=|<<=|>>=|<<<=|>>>= */
@@ -5289,18 +5582,49 @@ pub struct AssignmentOperatorTerm {
 /// Type derived for non-terminal AssignmentOperatorToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AssignmentOperatorToken {
     pub assignment_operator_term: crate::veryl_token::Token,
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Assume
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assume {
+    pub assume_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssumeTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssumeTerm {
+    pub assume_term: crate::veryl_token::Token, /* (?-u:\b)assume(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal AssumeToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssumeToken {
+    pub assume_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Attribute
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Attribute {
     pub hash: Box<Hash>,
@@ -5314,7 +5638,7 @@ pub struct Attribute {
 /// Type derived for non-terminal AttributeItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum AttributeItem {
     Identifier(AttributeItemIdentifier),
     StringLiteral(AttributeItemStringLiteral),
@@ -5324,7 +5648,7 @@ pub enum AttributeItem {
 /// Type derived for non-terminal AttributeList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AttributeList {
     pub attribute_item: Box<AttributeItem>,
@@ -5336,7 +5660,7 @@ pub struct AttributeList {
 /// Type derived for non-terminal AttributeListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AttributeListList {
     pub comma: Box<Comma>,
@@ -5347,7 +5671,7 @@ pub struct AttributeListList {
 /// Type derived for non-terminal AttributeListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AttributeListOpt {
     pub comma: Box<Comma>,
@@ -5357,7 +5681,7 @@ pub struct AttributeListOpt {
 /// Type derived for non-terminal AttributeOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct AttributeOpt {
     pub l_paren: Box<LParen>,
@@ -5369,7 +5693,7 @@ pub struct AttributeOpt {
 /// Type derived for non-terminal BackQuote
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BackQuote {
     pub back_quote_token: crate::veryl_token::VerylToken,
@@ -5379,7 +5703,7 @@ pub struct BackQuote {
 /// Type derived for non-terminal BackQuoteTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BackQuoteTerm {
     pub back_quote_term: crate::veryl_token::Token, /* ` */
@@ -5389,7 +5713,7 @@ pub struct BackQuoteTerm {
 /// Type derived for non-terminal BackQuoteToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BackQuoteToken {
     pub back_quote_term: crate::veryl_token::Token,
@@ -5400,7 +5724,7 @@ pub struct BackQuoteToken {
 /// Type derived for non-terminal BaseLess
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BaseLess {
     pub base_less_token: crate::veryl_token::VerylToken,
@@ -5410,7 +5734,7 @@ pub struct BaseLess {
 /// Type derived for non-terminal BaseLessTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BaseLessTerm {
     pub base_less_term: crate::veryl_token::Token, /* [0-9]+(?:_[0-9]+)* */
@@ -5420,7 +5744,7 @@ pub struct BaseLessTerm {
 /// Type derived for non-terminal BaseLessToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BaseLessToken {
     pub base_less_term: crate::veryl_token::Token,
@@ -5431,7 +5755,7 @@ pub struct BaseLessToken {
 /// Type derived for non-terminal Based
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Based {
     pub based_token: crate::veryl_token::VerylToken,
@@ -5441,7 +5765,7 @@ pub struct Based {
 /// Type derived for non-terminal BasedTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BasedTerm {
     pub based_term: crate::veryl_token::Token, /* (?:[0-9]+(?:_[0-9]+)*)?'s?[bodh][0-9a-fA-FxzXZ]+(?:_[0-9a-fA-FxzXZ]+)* */
@@ -5451,7 +5775,7 @@ pub struct BasedTerm {
 /// Type derived for non-terminal BasedToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BasedToken {
     pub based_term: crate::veryl_token::Token,
@@ -5462,7 +5786,7 @@ pub struct BasedToken {
 /// Type derived for non-terminal Bit
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Bit {
     pub bit_token: crate::veryl_token::VerylToken,
@@ -5472,7 +5796,7 @@ pub struct Bit {
 /// Type derived for non-terminal BitTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BitTerm {
     pub bit_term: crate::veryl_token::Token, /* (?-u:\b)bit(?-u:\b) */
@@ -5482,7 +5806,7 @@ pub struct BitTerm {
 /// Type derived for non-terminal BitToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BitToken {
     pub bit_term: crate::veryl_token::Token,
@@ -5493,7 +5817,7 @@ pub struct BitToken {
 /// Type derived for non-terminal Break
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Break {
     pub break_token: crate::veryl_token::VerylToken,
@@ -5503,7 +5827,7 @@ pub struct Break {
 /// Type derived for non-terminal BreakStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BreakStatement {
     pub r#break: Box<Break>,
@@ -5514,7 +5838,7 @@ pub struct BreakStatement {
 /// Type derived for non-terminal BreakTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BreakTerm {
     pub break_term: crate::veryl_token::Token, /* (?-u:\b)break(?-u:\b) */
@@ -5524,7 +5848,7 @@ pub struct BreakTerm {
 /// Type derived for non-terminal BreakToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct BreakToken {
     pub break_term: crate::veryl_token::Token,
@@ -5535,7 +5859,7 @@ pub struct BreakToken {
 /// Type derived for non-terminal Case
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Case {
     pub case_token: crate::veryl_token::VerylToken,
@@ -5545,7 +5869,7 @@ pub struct Case {
 /// Type derived for non-terminal CaseCondition
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseCondition {
     pub range_item: Box<RangeItem>,
@@ -5556,7 +5880,7 @@ pub struct CaseCondition {
 /// Type derived for non-terminal CaseConditionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseConditionList {
     pub comma: Box<Comma>,
@@ -5567,7 +5891,7 @@ pub struct CaseConditionList {
 /// Type derived for non-terminal CaseExpression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseExpression {
     pub case: Box<Case>,
@@ -5589,7 +5913,7 @@ pub struct CaseExpression {
 /// Type derived for non-terminal CaseExpressionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseExpressionList {
     pub case_condition: Box<CaseCondition>,
@@ -5602,7 +5926,7 @@ pub struct CaseExpressionList {
 /// Type derived for non-terminal CaseExpressionOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseExpressionOpt {
     pub comma: Box<Comma>,
@@ -5612,7 +5936,7 @@ pub struct CaseExpressionOpt {
 /// Type derived for non-terminal CaseItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseItem {
     pub case_item_group: Box<CaseItemGroup>,
@@ -5624,7 +5948,7 @@ pub struct CaseItem {
 /// Type derived for non-terminal CaseItemGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum CaseItemGroup {
     CaseCondition(CaseItemGroupCaseCondition),
     Defaul(CaseItemGroupDefaul),
@@ -5634,7 +5958,7 @@ pub enum CaseItemGroup {
 /// Type derived for non-terminal CaseItemGroup0
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum CaseItemGroup0 {
     Statement(CaseItemGroup0Statement),
     StatementBlock(CaseItemGroup0StatementBlock),
@@ -5644,7 +5968,7 @@ pub enum CaseItemGroup0 {
 /// Type derived for non-terminal CaseStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseStatement {
     pub case: Box<Case>,
@@ -5658,7 +5982,7 @@ pub struct CaseStatement {
 /// Type derived for non-terminal CaseStatementList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseStatementList {
     pub case_item: Box<CaseItem>,
@@ -5668,7 +5992,7 @@ pub struct CaseStatementList {
 /// Type derived for non-terminal CaseTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseTerm {
     pub case_term: crate::veryl_token::Token, /* (?-u:\b)case(?-u:\b) */
@@ -5678,7 +6002,7 @@ pub struct CaseTerm {
 /// Type derived for non-terminal CaseToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CaseToken {
     pub case_term: crate::veryl_token::Token,
@@ -5689,7 +6013,7 @@ pub struct CaseToken {
 /// Type derived for non-terminal CastingType
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum CastingType {
     U32(CastingTypeU32),
     U64(CastingTypeU64),
@@ -5697,6 +6021,8 @@ pub enum CastingType {
     I64(CastingTypeI64),
     F32(CastingTypeF32),
     F64(CastingTypeF64),
+    Bit(CastingTypeBit),
+    Logic(CastingTypeLogic),
     Clock(CastingTypeClock),
     ClockPosedge(CastingTypeClockPosedge),
     ClockNegedge(CastingTypeClockNegedge),
@@ -5714,7 +6040,7 @@ pub enum CastingType {
 /// Type derived for non-terminal Clock
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Clock {
     pub clock_token: crate::veryl_token::VerylToken,
@@ -5724,7 +6050,7 @@ pub struct Clock {
 /// Type derived for non-terminal ClockDomain
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockDomain {
     pub back_quote: Box<BackQuote>,
@@ -5735,7 +6061,7 @@ pub struct ClockDomain {
 /// Type derived for non-terminal ClockNegedge
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockNegedge {
     pub clock_negedge_token: crate::veryl_token::VerylToken,
@@ -5745,7 +6071,7 @@ pub struct ClockNegedge {
 /// Type derived for non-terminal ClockNegedgeTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockNegedgeTerm {
     pub clock_negedge_term: crate::veryl_token::Token, /* (?-u:\b)clock_negedge(?-u:\b) */
@@ -5755,7 +6081,7 @@ pub struct ClockNegedgeTerm {
 /// Type derived for non-terminal ClockNegedgeToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockNegedgeToken {
     pub clock_negedge_term: crate::veryl_token::Token,
@@ -5766,7 +6092,7 @@ pub struct ClockNegedgeToken {
 /// Type derived for non-terminal ClockPosedge
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockPosedge {
     pub clock_posedge_token: crate::veryl_token::VerylToken,
@@ -5776,7 +6102,7 @@ pub struct ClockPosedge {
 /// Type derived for non-terminal ClockPosedgeTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockPosedgeTerm {
     pub clock_posedge_term: crate::veryl_token::Token, /* (?-u:\b)clock_posedge(?-u:\b) */
@@ -5786,7 +6112,7 @@ pub struct ClockPosedgeTerm {
 /// Type derived for non-terminal ClockPosedgeToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockPosedgeToken {
     pub clock_posedge_term: crate::veryl_token::Token,
@@ -5797,7 +6123,7 @@ pub struct ClockPosedgeToken {
 /// Type derived for non-terminal ClockTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockTerm {
     pub clock_term: crate::veryl_token::Token, /* (?-u:\b)clock(?-u:\b) */
@@ -5807,7 +6133,7 @@ pub struct ClockTerm {
 /// Type derived for non-terminal ClockToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ClockToken {
     pub clock_term: crate::veryl_token::Token,
@@ -5818,7 +6144,7 @@ pub struct ClockToken {
 /// Type derived for non-terminal Colon
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Colon {
     pub colon_token: crate::veryl_token::VerylToken,
@@ -5828,7 +6154,7 @@ pub struct Colon {
 /// Type derived for non-terminal ColonColon
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonColon {
     pub colon_colon_token: crate::veryl_token::VerylToken,
@@ -5838,7 +6164,7 @@ pub struct ColonColon {
 /// Type derived for non-terminal ColonColonLAngle
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonColonLAngle {
     pub colon_colon_l_angle_token: crate::veryl_token::VerylToken,
@@ -5848,7 +6174,7 @@ pub struct ColonColonLAngle {
 /// Type derived for non-terminal ColonColonLAngleTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonColonLAngleTerm {
     pub colon_colon_l_angle_term: crate::veryl_token::Token, /* ::< */
@@ -5858,7 +6184,7 @@ pub struct ColonColonLAngleTerm {
 /// Type derived for non-terminal ColonColonLAngleToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonColonLAngleToken {
     pub colon_colon_l_angle_term: crate::veryl_token::Token,
@@ -5869,7 +6195,7 @@ pub struct ColonColonLAngleToken {
 /// Type derived for non-terminal ColonColonTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonColonTerm {
     pub colon_colon_term: crate::veryl_token::Token, /* :: */
@@ -5879,7 +6205,7 @@ pub struct ColonColonTerm {
 /// Type derived for non-terminal ColonColonToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonColonToken {
     pub colon_colon_term: crate::veryl_token::Token,
@@ -5890,7 +6216,7 @@ pub struct ColonColonToken {
 /// Type derived for non-terminal ColonTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonTerm {
     pub colon_term: crate::veryl_token::Token, /* : */
@@ -5900,7 +6226,7 @@ pub struct ColonTerm {
 /// Type derived for non-terminal ColonToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ColonToken {
     pub colon_term: crate::veryl_token::Token,
@@ -5911,7 +6237,7 @@ pub struct ColonToken {
 /// Type derived for non-terminal Comma
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Comma {
     pub comma_token: crate::veryl_token::VerylToken,
@@ -5921,7 +6247,7 @@ pub struct Comma {
 /// Type derived for non-terminal CommaTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CommaTerm {
     pub comma_term: crate::veryl_token::Token, /* , */
@@ -5931,7 +6257,7 @@ pub struct CommaTerm {
 /// Type derived for non-terminal CommaToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CommaToken {
     pub comma_term: crate::veryl_token::Token,
@@ -5942,7 +6268,7 @@ pub struct CommaToken {
 /// Type derived for non-terminal Comments
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Comments {
     pub comments_opt: Option<CommentsOpt>,
@@ -5952,7 +6278,7 @@ pub struct Comments {
 /// Type derived for non-terminal CommentsOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CommentsOpt {
     pub comments_term: Box<CommentsTerm>,
@@ -5962,7 +6288,7 @@ pub struct CommentsOpt {
 /// Type derived for non-terminal CommentsTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct CommentsTerm {
     pub comments_term: crate::veryl_token::Token, /* (?:(?:(?://.*(?:\r\n|\r|\n|$))|(?:(?ms)/\u{2a}.*?\u{2a}/))\s*)+ */
@@ -5972,7 +6298,7 @@ pub struct CommentsTerm {
 /// Type derived for non-terminal ConcatenationItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConcatenationItem {
     pub expression: Box<Expression>,
@@ -5983,7 +6309,7 @@ pub struct ConcatenationItem {
 /// Type derived for non-terminal ConcatenationItemOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConcatenationItemOpt {
     pub repeat: Box<Repeat>,
@@ -5994,7 +6320,7 @@ pub struct ConcatenationItemOpt {
 /// Type derived for non-terminal ConcatenationList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConcatenationList {
     pub concatenation_item: Box<ConcatenationItem>,
@@ -6006,7 +6332,7 @@ pub struct ConcatenationList {
 /// Type derived for non-terminal ConcatenationListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConcatenationListList {
     pub comma: Box<Comma>,
@@ -6017,7 +6343,7 @@ pub struct ConcatenationListList {
 /// Type derived for non-terminal ConcatenationListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConcatenationListOpt {
     pub comma: Box<Comma>,
@@ -6027,7 +6353,7 @@ pub struct ConcatenationListOpt {
 /// Type derived for non-terminal Const
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Const {
     pub const_token: crate::veryl_token::VerylToken,
@@ -6037,7 +6363,7 @@ pub struct Const {
 /// Type derived for non-terminal ConstDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConstDeclaration {
     pub r#const: Box<Const>,
@@ -6053,7 +6379,7 @@ pub struct ConstDeclaration {
 /// Type derived for non-terminal ConstDeclarationGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ConstDeclarationGroup {
     ArrayType(ConstDeclarationGroupArrayType),
     Type(ConstDeclarationGroupType),
@@ -6063,7 +6389,7 @@ pub enum ConstDeclarationGroup {
 /// Type derived for non-terminal ConstTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConstTerm {
     pub const_term: crate::veryl_token::Token, /* (?-u:\b)const(?-u:\b) */
@@ -6073,18 +6399,49 @@ pub struct ConstTerm {
 /// Type derived for non-terminal ConstToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ConstToken {
     pub const_term: crate::veryl_token::Token,
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Cover
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Cover {
+    pub cover_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal CoverTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CoverTerm {
+    pub cover_term: crate::veryl_token::Token, /* (?-u:\b)cover(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal CoverToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CoverToken {
+    pub cover_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Defaul
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Defaul {
     pub default_token: crate::veryl_token::VerylToken,
@@ -6094,7 +6451,7 @@ pub struct Defaul {
 /// Type derived for non-terminal DefaultTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DefaultTerm {
     pub default_term: crate::veryl_token::Token, /* (?-u:\b)default(?-u:\b) */
@@ -6104,7 +6461,7 @@ pub struct DefaultTerm {
 /// Type derived for non-terminal DefaultToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DefaultToken {
     pub default_term: crate::veryl_token::Token,
@@ -6115,7 +6472,7 @@ pub struct DefaultToken {
 /// Type derived for non-terminal DescriptionGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionGroup {
     pub description_group_list: Vec<DescriptionGroupList>,
@@ -6126,7 +6483,7 @@ pub struct DescriptionGroup {
 /// Type derived for non-terminal DescriptionGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DescriptionGroupGroup {
     LBraceDescriptionGroupGroupListRBrace(
         DescriptionGroupGroupLBraceDescriptionGroupGroupListRBrace,
@@ -6138,7 +6495,7 @@ pub enum DescriptionGroupGroup {
 /// Type derived for non-terminal DescriptionGroupGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionGroupGroupList {
     pub description_group: Box<DescriptionGroup>,
@@ -6148,7 +6505,7 @@ pub struct DescriptionGroupGroupList {
 /// Type derived for non-terminal DescriptionGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DescriptionGroupList {
     pub attribute: Box<Attribute>,
@@ -6158,7 +6515,7 @@ pub struct DescriptionGroupList {
 /// Type derived for non-terminal DescriptionItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DescriptionItem {
     ModuleDeclaration(DescriptionItemModuleDeclaration),
     InterfaceDeclaration(DescriptionItemInterfaceDeclaration),
@@ -6173,7 +6530,7 @@ pub enum DescriptionItem {
 /// Type derived for non-terminal Direction
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Direction {
     Input(DirectionInput),
     Output(DirectionOutput),
@@ -6187,7 +6544,7 @@ pub enum Direction {
 /// Type derived for non-terminal DollarIdentifier
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DollarIdentifier {
     pub dollar_identifier_token: crate::veryl_token::VerylToken,
@@ -6197,7 +6554,7 @@ pub struct DollarIdentifier {
 /// Type derived for non-terminal DollarIdentifierTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DollarIdentifierTerm {
     pub dollar_identifier_term: crate::veryl_token::Token, /* \$[a-zA-Z_][0-9a-zA-Z_$]* */
@@ -6207,7 +6564,7 @@ pub struct DollarIdentifierTerm {
 /// Type derived for non-terminal DollarIdentifierToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DollarIdentifierToken {
     pub dollar_identifier_term: crate::veryl_token::Token,
@@ -6218,7 +6575,7 @@ pub struct DollarIdentifierToken {
 /// Type derived for non-terminal Dot
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Dot {
     pub dot_token: crate::veryl_token::VerylToken,
@@ -6228,7 +6585,7 @@ pub struct Dot {
 /// Type derived for non-terminal DotDot
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotDot {
     pub dot_dot_token: crate::veryl_token::VerylToken,
@@ -6238,7 +6595,7 @@ pub struct DotDot {
 /// Type derived for non-terminal DotDotEqu
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotDotEqu {
     pub dot_dot_equ_token: crate::veryl_token::VerylToken,
@@ -6248,7 +6605,7 @@ pub struct DotDotEqu {
 /// Type derived for non-terminal DotDotEquTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotDotEquTerm {
     pub dot_dot_equ_term: crate::veryl_token::Token, /* ..= */
@@ -6258,7 +6615,7 @@ pub struct DotDotEquTerm {
 /// Type derived for non-terminal DotDotEquToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotDotEquToken {
     pub dot_dot_equ_term: crate::veryl_token::Token,
@@ -6269,7 +6626,7 @@ pub struct DotDotEquToken {
 /// Type derived for non-terminal DotDotTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotDotTerm {
     pub dot_dot_term: crate::veryl_token::Token, /* .. */
@@ -6279,7 +6636,7 @@ pub struct DotDotTerm {
 /// Type derived for non-terminal DotDotToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotDotToken {
     pub dot_dot_term: crate::veryl_token::Token,
@@ -6290,7 +6647,7 @@ pub struct DotDotToken {
 /// Type derived for non-terminal DotTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotTerm {
     pub dot_term: crate::veryl_token::Token, /* . */
@@ -6300,7 +6657,7 @@ pub struct DotTerm {
 /// Type derived for non-terminal DotToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct DotToken {
     pub dot_term: crate::veryl_token::Token,
@@ -6311,7 +6668,7 @@ pub struct DotToken {
 /// Type derived for non-terminal Else
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Else {
     pub else_token: crate::veryl_token::VerylToken,
@@ -6321,7 +6678,7 @@ pub struct Else {
 /// Type derived for non-terminal ElseTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ElseTerm {
     pub else_term: crate::veryl_token::Token, /* (?-u:\b)else(?-u:\b) */
@@ -6331,7 +6688,7 @@ pub struct ElseTerm {
 /// Type derived for non-terminal ElseToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ElseToken {
     pub else_term: crate::veryl_token::Token,
@@ -6342,7 +6699,7 @@ pub struct ElseToken {
 /// Type derived for non-terminal Embed
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Embed {
     pub embed_token: crate::veryl_token::VerylToken,
@@ -6352,7 +6709,7 @@ pub struct Embed {
 /// Type derived for non-terminal EmbedContent
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedContent {
     pub embed_content_token: crate::veryl_token::VerylToken,
@@ -6362,7 +6719,7 @@ pub struct EmbedContent {
 /// Type derived for non-terminal EmbedContentToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedContentToken {
     pub l_brace_term: Box<LBraceTerm>,
@@ -6379,7 +6736,7 @@ pub struct EmbedContentToken {
 /// Type derived for non-terminal EmbedContentTokenList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedContentTokenList {
     pub embed_item: Box<EmbedItem>,
@@ -6389,7 +6746,7 @@ pub struct EmbedContentTokenList {
 /// Type derived for non-terminal EmbedDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedDeclaration {
     pub embed: Box<Embed>,
@@ -6404,7 +6761,7 @@ pub struct EmbedDeclaration {
 /// Type derived for non-terminal EmbedItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum EmbedItem {
     LBraceTermEmbedItemListRBraceTerm(EmbedItemLBraceTermEmbedItemListRBraceTerm),
     AnyTerm(EmbedItemAnyTerm),
@@ -6414,7 +6771,7 @@ pub enum EmbedItem {
 /// Type derived for non-terminal EmbedItemList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedItemList {
     pub embed_item: Box<EmbedItem>,
@@ -6424,7 +6781,7 @@ pub struct EmbedItemList {
 /// Type derived for non-terminal EmbedTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedTerm {
     pub embed_term: crate::veryl_token::Token, /* (?-u:\b)embed(?-u:\b) */
@@ -6434,7 +6791,7 @@ pub struct EmbedTerm {
 /// Type derived for non-terminal EmbedToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EmbedToken {
     pub embed_term: crate::veryl_token::Token,
@@ -6445,7 +6802,7 @@ pub struct EmbedToken {
 /// Type derived for non-terminal Enum
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Enum {
     pub enum_token: crate::veryl_token::VerylToken,
@@ -6455,7 +6812,7 @@ pub struct Enum {
 /// Type derived for non-terminal EnumDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumDeclaration {
     pub r#enum: Box<Enum>,
@@ -6470,7 +6827,7 @@ pub struct EnumDeclaration {
 /// Type derived for non-terminal EnumDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumDeclarationOpt {
     pub colon: Box<Colon>,
@@ -6481,7 +6838,7 @@ pub struct EnumDeclarationOpt {
 /// Type derived for non-terminal EnumGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumGroup {
     pub enum_group_list: Vec<EnumGroupList>,
@@ -6492,7 +6849,7 @@ pub struct EnumGroup {
 /// Type derived for non-terminal EnumGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum EnumGroupGroup {
     LBraceEnumListRBrace(EnumGroupGroupLBraceEnumListRBrace),
     EnumItem(EnumGroupGroupEnumItem),
@@ -6502,7 +6859,7 @@ pub enum EnumGroupGroup {
 /// Type derived for non-terminal EnumGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumGroupList {
     pub attribute: Box<Attribute>,
@@ -6512,7 +6869,7 @@ pub struct EnumGroupList {
 /// Type derived for non-terminal EnumItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumItem {
     pub identifier: Box<Identifier>,
@@ -6523,7 +6880,7 @@ pub struct EnumItem {
 /// Type derived for non-terminal EnumItemOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumItemOpt {
     pub equ: Box<Equ>,
@@ -6534,7 +6891,7 @@ pub struct EnumItemOpt {
 /// Type derived for non-terminal EnumList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumList {
     pub enum_group: Box<EnumGroup>,
@@ -6546,7 +6903,7 @@ pub struct EnumList {
 /// Type derived for non-terminal EnumListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumListList {
     pub comma: Box<Comma>,
@@ -6557,7 +6914,7 @@ pub struct EnumListList {
 /// Type derived for non-terminal EnumListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumListOpt {
     pub comma: Box<Comma>,
@@ -6567,7 +6924,7 @@ pub struct EnumListOpt {
 /// Type derived for non-terminal EnumTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumTerm {
     pub enum_term: crate::veryl_token::Token, /* (?-u:\b)enum(?-u:\b) */
@@ -6577,7 +6934,7 @@ pub struct EnumTerm {
 /// Type derived for non-terminal EnumToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EnumToken {
     pub enum_term: crate::veryl_token::Token,
@@ -6588,7 +6945,7 @@ pub struct EnumToken {
 /// Type derived for non-terminal Equ
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Equ {
     pub equ_token: crate::veryl_token::VerylToken,
@@ -6598,7 +6955,7 @@ pub struct Equ {
 /// Type derived for non-terminal EquTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EquTerm {
     pub equ_term: crate::veryl_token::Token, /* = */
@@ -6608,7 +6965,7 @@ pub struct EquTerm {
 /// Type derived for non-terminal EquToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct EquToken {
     pub equ_term: crate::veryl_token::Token,
@@ -6619,7 +6976,7 @@ pub struct EquToken {
 /// Type derived for non-terminal Exponent
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Exponent {
     pub exponent_token: crate::veryl_token::VerylToken,
@@ -6629,7 +6986,7 @@ pub struct Exponent {
 /// Type derived for non-terminal ExponentTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExponentTerm {
     pub exponent_term: crate::veryl_token::Token, /* [0-9]+(?:_[0-9]+)*\.[0-9]+(?:_[0-9]+)*[eE][+-]?[0-9]+(?:_[0-9]+)* */
@@ -6639,7 +6996,7 @@ pub struct ExponentTerm {
 /// Type derived for non-terminal ExponentToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExponentToken {
     pub exponent_term: crate::veryl_token::Token,
@@ -6650,7 +7007,7 @@ pub struct ExponentToken {
 /// Type derived for non-terminal Export
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Export {
     pub export_token: crate::veryl_token::VerylToken,
@@ -6660,7 +7017,7 @@ pub struct Export {
 /// Type derived for non-terminal ExportDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExportDeclaration {
     pub export: Box<Export>,
@@ -6672,7 +7029,7 @@ pub struct ExportDeclaration {
 /// Type derived for non-terminal ExportDeclarationGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ExportDeclarationGroup {
     Star(ExportDeclarationGroupStar),
     ScopedIdentifierExportDeclarationOpt(
@@ -6684,7 +7041,7 @@ pub enum ExportDeclarationGroup {
 /// Type derived for non-terminal ExportDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExportDeclarationOpt {
     pub colon_colon: Box<ColonColon>,
@@ -6695,7 +7052,7 @@ pub struct ExportDeclarationOpt {
 /// Type derived for non-terminal ExportTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExportTerm {
     pub export_term: crate::veryl_token::Token, /* (?-u:\b)export(?-u:\b) */
@@ -6705,7 +7062,7 @@ pub struct ExportTerm {
 /// Type derived for non-terminal ExportToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExportToken {
     pub export_term: crate::veryl_token::Token,
@@ -6716,7 +7073,7 @@ pub struct ExportToken {
 /// Type derived for non-terminal Expression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression {
     pub expression01: Box<Expression01>,
@@ -6727,7 +7084,7 @@ pub struct Expression {
 /// Type derived for non-terminal Expression01
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression01 {
     pub expression02: Box<Expression02>,
@@ -6738,7 +7095,7 @@ pub struct Expression01 {
 /// Type derived for non-terminal Expression01List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression01List {
     pub operator02: Box<Operator02>,
@@ -6749,7 +7106,7 @@ pub struct Expression01List {
 /// Type derived for non-terminal Expression02
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression02 {
     pub expression03: Box<Expression03>,
@@ -6760,7 +7117,7 @@ pub struct Expression02 {
 /// Type derived for non-terminal Expression02List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression02List {
     pub operator03: Box<Operator03>,
@@ -6771,7 +7128,7 @@ pub struct Expression02List {
 /// Type derived for non-terminal Expression03
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression03 {
     pub expression04: Box<Expression04>,
@@ -6782,7 +7139,7 @@ pub struct Expression03 {
 /// Type derived for non-terminal Expression03List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression03List {
     pub operator04: Box<Operator04>,
@@ -6793,7 +7150,7 @@ pub struct Expression03List {
 /// Type derived for non-terminal Expression04
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression04 {
     pub expression05: Box<Expression05>,
@@ -6804,7 +7161,7 @@ pub struct Expression04 {
 /// Type derived for non-terminal Expression04List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression04List {
     pub operator05: Box<Operator05>,
@@ -6815,7 +7172,7 @@ pub struct Expression04List {
 /// Type derived for non-terminal Expression05
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression05 {
     pub expression06: Box<Expression06>,
@@ -6826,7 +7183,7 @@ pub struct Expression05 {
 /// Type derived for non-terminal Expression05List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression05List {
     pub operator06: Box<Operator06>,
@@ -6837,7 +7194,7 @@ pub struct Expression05List {
 /// Type derived for non-terminal Expression06
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression06 {
     pub expression07: Box<Expression07>,
@@ -6848,7 +7205,7 @@ pub struct Expression06 {
 /// Type derived for non-terminal Expression06List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression06List {
     pub operator07: Box<Operator07>,
@@ -6859,7 +7216,7 @@ pub struct Expression06List {
 /// Type derived for non-terminal Expression07
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression07 {
     pub expression08: Box<Expression08>,
@@ -6870,7 +7227,7 @@ pub struct Expression07 {
 /// Type derived for non-terminal Expression07List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression07List {
     pub operator08: Box<Operator08>,
@@ -6881,7 +7238,7 @@ pub struct Expression07List {
 /// Type derived for non-terminal Expression08
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression08 {
     pub expression09: Box<Expression09>,
@@ -6892,7 +7249,7 @@ pub struct Expression08 {
 /// Type derived for non-terminal Expression08List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression08List {
     pub operator09: Box<Operator09>,
@@ -6903,7 +7260,7 @@ pub struct Expression08List {
 /// Type derived for non-terminal Expression09
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression09 {
     pub expression10: Box<Expression10>,
@@ -6914,7 +7271,7 @@ pub struct Expression09 {
 /// Type derived for non-terminal Expression09List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression09List {
     pub expression09_list_group: Box<Expression09ListGroup>,
@@ -6925,7 +7282,7 @@ pub struct Expression09List {
 /// Type derived for non-terminal Expression09ListGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Expression09ListGroup {
     Operator10(Expression09ListGroupOperator10),
     Star(Expression09ListGroupStar),
@@ -6935,7 +7292,7 @@ pub enum Expression09ListGroup {
 /// Type derived for non-terminal Expression10
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression10 {
     pub expression11: Box<Expression11>,
@@ -6946,7 +7303,7 @@ pub struct Expression10 {
 /// Type derived for non-terminal Expression10List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression10List {
     pub operator11: Box<Operator11>,
@@ -6957,7 +7314,7 @@ pub struct Expression10List {
 /// Type derived for non-terminal Expression11
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression11 {
     pub expression12: Box<Expression12>,
@@ -6968,7 +7325,7 @@ pub struct Expression11 {
 /// Type derived for non-terminal Expression11Opt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression11Opt {
     pub r#as: Box<As>,
@@ -6979,7 +7336,7 @@ pub struct Expression11Opt {
 /// Type derived for non-terminal Expression12
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12 {
     pub expression12_list: Vec<Expression12List>,
@@ -6990,7 +7347,7 @@ pub struct Expression12 {
 /// Type derived for non-terminal Expression12List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Expression12List {
     pub expression12_list_group: Box<Expression12ListGroup>,
@@ -7000,7 +7357,7 @@ pub struct Expression12List {
 /// Type derived for non-terminal Expression12ListGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Expression12ListGroup {
     UnaryOperator(Expression12ListGroupUnaryOperator),
     Operator09(Expression12ListGroupOperator09),
@@ -7013,7 +7370,7 @@ pub enum Expression12ListGroup {
 /// Type derived for non-terminal ExpressionIdentifier
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExpressionIdentifier {
     pub scoped_identifier: Box<ScopedIdentifier>,
@@ -7026,7 +7383,7 @@ pub struct ExpressionIdentifier {
 /// Type derived for non-terminal ExpressionIdentifierList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExpressionIdentifierList {
     pub select: Box<Select>,
@@ -7036,7 +7393,7 @@ pub struct ExpressionIdentifierList {
 /// Type derived for non-terminal ExpressionIdentifierList0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExpressionIdentifierList0 {
     pub dot: Box<Dot>,
@@ -7048,7 +7405,7 @@ pub struct ExpressionIdentifierList0 {
 /// Type derived for non-terminal ExpressionIdentifierList0List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExpressionIdentifierList0List {
     pub select: Box<Select>,
@@ -7058,7 +7415,7 @@ pub struct ExpressionIdentifierList0List {
 /// Type derived for non-terminal ExpressionIdentifierOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExpressionIdentifierOpt {
     pub width: Box<Width>,
@@ -7068,7 +7425,7 @@ pub struct ExpressionIdentifierOpt {
 /// Type derived for non-terminal ExpressionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ExpressionList {
     pub operator01: Box<Operator01>,
@@ -7079,7 +7436,7 @@ pub struct ExpressionList {
 /// Type derived for non-terminal F32
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct F32 {
     pub f32_token: crate::veryl_token::VerylToken,
@@ -7089,7 +7446,7 @@ pub struct F32 {
 /// Type derived for non-terminal F32Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct F32Term {
     pub f32_term: crate::veryl_token::Token, /* (?-u:\b)f32(?-u:\b) */
@@ -7099,7 +7456,7 @@ pub struct F32Term {
 /// Type derived for non-terminal F32Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct F32Token {
     pub f32_term: crate::veryl_token::Token,
@@ -7110,7 +7467,7 @@ pub struct F32Token {
 /// Type derived for non-terminal F64
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct F64 {
     pub f64_token: crate::veryl_token::VerylToken,
@@ -7120,7 +7477,7 @@ pub struct F64 {
 /// Type derived for non-terminal F64Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct F64Term {
     pub f64_term: crate::veryl_token::Token, /* (?-u:\b)f64(?-u:\b) */
@@ -7130,7 +7487,7 @@ pub struct F64Term {
 /// Type derived for non-terminal F64Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct F64Token {
     pub f64_term: crate::veryl_token::Token,
@@ -7141,7 +7498,7 @@ pub struct F64Token {
 /// Type derived for non-terminal Factor
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Factor {
     Number(FactorNumber),
     IdentifierFactor(FactorIdentifierFactor),
@@ -7163,7 +7520,7 @@ pub enum Factor {
 /// Type derived for non-terminal FactorGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum FactorGroup {
     Msb(FactorGroupMsb),
     Lsb(FactorGroupLsb),
@@ -7173,7 +7530,7 @@ pub enum FactorGroup {
 /// Type derived for non-terminal FactorType
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorType {
     pub factor_type_group: Box<FactorTypeGroup>,
@@ -7183,7 +7540,7 @@ pub struct FactorType {
 /// Type derived for non-terminal FactorTypeGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum FactorTypeGroup {
     VariableTypeFactorTypeOpt(FactorTypeGroupVariableTypeFactorTypeOpt),
     FixedType(FactorTypeGroupFixedType),
@@ -7193,7 +7550,7 @@ pub enum FactorTypeGroup {
 /// Type derived for non-terminal FactorTypeOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FactorTypeOpt {
     pub width: Box<Width>,
@@ -7203,7 +7560,7 @@ pub struct FactorTypeOpt {
 /// Type derived for non-terminal Final
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Final {
     pub final_token: crate::veryl_token::VerylToken,
@@ -7213,7 +7570,7 @@ pub struct Final {
 /// Type derived for non-terminal FinalDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FinalDeclaration {
     pub r#final: Box<Final>,
@@ -7224,7 +7581,7 @@ pub struct FinalDeclaration {
 /// Type derived for non-terminal FinalTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FinalTerm {
     pub final_term: crate::veryl_token::Token, /* (?-u:\b)final(?-u:\b) */
@@ -7234,7 +7591,7 @@ pub struct FinalTerm {
 /// Type derived for non-terminal FinalToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FinalToken {
     pub final_term: crate::veryl_token::Token,
@@ -7245,7 +7602,7 @@ pub struct FinalToken {
 /// Type derived for non-terminal FixedPoint
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedPoint {
     pub fixed_point_token: crate::veryl_token::VerylToken,
@@ -7255,7 +7612,7 @@ pub struct FixedPoint {
 /// Type derived for non-terminal FixedPointTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedPointTerm {
     pub fixed_point_term: crate::veryl_token::Token, /* [0-9]+(?:_[0-9]+)*\.[0-9]+(?:_[0-9]+)* */
@@ -7265,7 +7622,7 @@ pub struct FixedPointTerm {
 /// Type derived for non-terminal FixedPointToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FixedPointToken {
     pub fixed_point_term: crate::veryl_token::Token,
@@ -7276,7 +7633,7 @@ pub struct FixedPointToken {
 /// Type derived for non-terminal FixedType
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum FixedType {
     U32(FixedTypeU32),
     U64(FixedTypeU64),
@@ -7291,7 +7648,7 @@ pub enum FixedType {
 /// Type derived for non-terminal For
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct For {
     pub for_token: crate::veryl_token::VerylToken,
@@ -7301,7 +7658,7 @@ pub struct For {
 /// Type derived for non-terminal ForStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ForStatement {
     pub r#for: Box<For>,
@@ -7318,7 +7675,7 @@ pub struct ForStatement {
 /// Type derived for non-terminal ForStatementOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ForStatementOpt {
     pub step: Box<Step>,
@@ -7330,7 +7687,7 @@ pub struct ForStatementOpt {
 /// Type derived for non-terminal ForTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ForTerm {
     pub for_term: crate::veryl_token::Token, /* (?-u:\b)for(?-u:\b) */
@@ -7340,7 +7697,7 @@ pub struct ForTerm {
 /// Type derived for non-terminal ForToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ForToken {
     pub for_term: crate::veryl_token::Token,
@@ -7351,7 +7708,7 @@ pub struct ForToken {
 /// Type derived for non-terminal Function
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Function {
     pub function_token: crate::veryl_token::VerylToken,
@@ -7361,7 +7718,7 @@ pub struct Function {
 /// Type derived for non-terminal FunctionCall
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionCall {
     pub l_paren: Box<LParen>,
@@ -7373,7 +7730,7 @@ pub struct FunctionCall {
 /// Type derived for non-terminal FunctionCallOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionCallOpt {
     pub argument_list: Box<ArgumentList>,
@@ -7383,7 +7740,7 @@ pub struct FunctionCallOpt {
 /// Type derived for non-terminal FunctionDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionDeclaration {
     pub function: Box<Function>,
@@ -7398,7 +7755,7 @@ pub struct FunctionDeclaration {
 /// Type derived for non-terminal FunctionDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionDeclarationOpt {
     pub with_generic_parameter: Box<WithGenericParameter>,
@@ -7408,7 +7765,7 @@ pub struct FunctionDeclarationOpt {
 /// Type derived for non-terminal FunctionDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionDeclarationOpt0 {
     pub port_declaration: Box<PortDeclaration>,
@@ -7418,7 +7775,7 @@ pub struct FunctionDeclarationOpt0 {
 /// Type derived for non-terminal FunctionDeclarationOpt1
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionDeclarationOpt1 {
     pub minus_g_t: Box<MinusGT>,
@@ -7429,7 +7786,7 @@ pub struct FunctionDeclarationOpt1 {
 /// Type derived for non-terminal FunctionTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionTerm {
     pub function_term: crate::veryl_token::Token, /* (?-u:\b)function(?-u:\b) */
@@ -7439,7 +7796,7 @@ pub struct FunctionTerm {
 /// Type derived for non-terminal FunctionToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct FunctionToken {
     pub function_term: crate::veryl_token::Token,
@@ -7450,7 +7807,7 @@ pub struct FunctionToken {
 /// Type derived for non-terminal GenerateBlockDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateBlockDeclaration {
     pub generate_named_block: Box<GenerateNamedBlock>,
@@ -7460,7 +7817,7 @@ pub struct GenerateBlockDeclaration {
 /// Type derived for non-terminal GenerateForDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateForDeclaration {
     pub r#for: Box<For>,
@@ -7475,7 +7832,7 @@ pub struct GenerateForDeclaration {
 /// Type derived for non-terminal GenerateForDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateForDeclarationOpt {
     pub step: Box<Step>,
@@ -7487,7 +7844,7 @@ pub struct GenerateForDeclarationOpt {
 /// Type derived for non-terminal GenerateGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateGroup {
     pub generate_group_list: Vec<GenerateGroupList>,
@@ -7498,7 +7855,7 @@ pub struct GenerateGroup {
 /// Type derived for non-terminal GenerateGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum GenerateGroupGroup {
     LBraceGenerateGroupGroupListRBrace(GenerateGroupGroupLBraceGenerateGroupGroupListRBrace),
     GenerateItem(GenerateGroupGroupGenerateItem),
@@ -7508,7 +7865,7 @@ pub enum GenerateGroupGroup {
 /// Type derived for non-terminal GenerateGroupGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateGroupGroupList {
     pub generate_group: Box<GenerateGroup>,
@@ -7518,7 +7875,7 @@ pub struct GenerateGroupGroupList {
 /// Type derived for non-terminal GenerateGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateGroupList {
     pub attribute: Box<Attribute>,
@@ -7528,7 +7885,7 @@ pub struct GenerateGroupList {
 /// Type derived for non-terminal GenerateIfDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateIfDeclaration {
     pub r#if: Box<If>,
@@ -7542,7 +7899,7 @@ pub struct GenerateIfDeclaration {
 /// Type derived for non-terminal GenerateIfDeclarationList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateIfDeclarationList {
     pub r#else: Box<Else>,
@@ -7555,7 +7912,7 @@ pub struct GenerateIfDeclarationList {
 /// Type derived for non-terminal GenerateIfDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateIfDeclarationOpt {
     pub r#else: Box<Else>,
@@ -7566,7 +7923,7 @@ pub struct GenerateIfDeclarationOpt {
 /// Type derived for non-terminal GenerateItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum GenerateItem {
     LetDeclaration(GenerateItemLetDeclaration),
     VarDeclaration(GenerateItemVarDeclaration),
@@ -7585,6 +7942,7 @@ pub enum GenerateItem {
     ImportDeclaration(GenerateItemImportDeclaration),
     InitialDeclaration(GenerateItemInitialDeclaration),
     FinalDeclaration(GenerateItemFinalDeclaration),
+    AssertionDeclaration(GenerateItemAssertionDeclaration),
     UnsafeBlock(GenerateItemUnsafeBlock),
 }
 
@@ -7592,7 +7950,7 @@ pub enum GenerateItem {
 /// Type derived for non-terminal GenerateNamedBlock
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateNamedBlock {
     pub colon: Box<Colon>,
@@ -7606,7 +7964,7 @@ pub struct GenerateNamedBlock {
 /// Type derived for non-terminal GenerateNamedBlockList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateNamedBlockList {
     pub generate_group: Box<GenerateGroup>,
@@ -7616,7 +7974,7 @@ pub struct GenerateNamedBlockList {
 /// Type derived for non-terminal GenerateOptionalNamedBlock
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateOptionalNamedBlock {
     pub generate_optional_named_block_opt: Option<GenerateOptionalNamedBlockOpt>,
@@ -7629,7 +7987,7 @@ pub struct GenerateOptionalNamedBlock {
 /// Type derived for non-terminal GenerateOptionalNamedBlockList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateOptionalNamedBlockList {
     pub generate_group: Box<GenerateGroup>,
@@ -7639,7 +7997,7 @@ pub struct GenerateOptionalNamedBlockList {
 /// Type derived for non-terminal GenerateOptionalNamedBlockOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct GenerateOptionalNamedBlockOpt {
     pub colon: Box<Colon>,
@@ -7650,7 +8008,7 @@ pub struct GenerateOptionalNamedBlockOpt {
 /// Type derived for non-terminal GenericBound
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum GenericBound {
     Const(GenericBoundConst),
     Type(GenericBoundType),
@@ -7662,7 +8020,7 @@ pub enum GenericBound {
 /// Type derived for non-terminal Hash
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Hash {
     pub hash_token: crate::veryl_token::VerylToken,
@@ -7672,7 +8030,7 @@ pub struct Hash {
 /// Type derived for non-terminal HashTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct HashTerm {
     pub hash_term: crate::veryl_token::Token, /* # */
@@ -7682,7 +8040,7 @@ pub struct HashTerm {
 /// Type derived for non-terminal HashToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct HashToken {
     pub hash_term: crate::veryl_token::Token,
@@ -7693,7 +8051,7 @@ pub struct HashToken {
 /// Type derived for non-terminal HierarchicalIdentifier
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct HierarchicalIdentifier {
     pub identifier: Box<Identifier>,
@@ -7705,7 +8063,7 @@ pub struct HierarchicalIdentifier {
 /// Type derived for non-terminal HierarchicalIdentifierList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct HierarchicalIdentifierList {
     pub select: Box<Select>,
@@ -7715,7 +8073,7 @@ pub struct HierarchicalIdentifierList {
 /// Type derived for non-terminal HierarchicalIdentifierList0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct HierarchicalIdentifierList0 {
     pub dot: Box<Dot>,
@@ -7727,7 +8085,7 @@ pub struct HierarchicalIdentifierList0 {
 /// Type derived for non-terminal HierarchicalIdentifierList0List
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct HierarchicalIdentifierList0List {
     pub select: Box<Select>,
@@ -7737,7 +8095,7 @@ pub struct HierarchicalIdentifierList0List {
 /// Type derived for non-terminal I32
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct I32 {
     pub i32_token: crate::veryl_token::VerylToken,
@@ -7747,7 +8105,7 @@ pub struct I32 {
 /// Type derived for non-terminal I32Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct I32Term {
     pub i32_term: crate::veryl_token::Token, /* (?-u:\b)i32(?-u:\b) */
@@ -7757,7 +8115,7 @@ pub struct I32Term {
 /// Type derived for non-terminal I32Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct I32Token {
     pub i32_term: crate::veryl_token::Token,
@@ -7768,7 +8126,7 @@ pub struct I32Token {
 /// Type derived for non-terminal I64
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct I64 {
     pub i64_token: crate::veryl_token::VerylToken,
@@ -7778,7 +8136,7 @@ pub struct I64 {
 /// Type derived for non-terminal I64Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct I64Term {
     pub i64_term: crate::veryl_token::Token, /* (?-u:\b)i64(?-u:\b) */
@@ -7788,7 +8146,7 @@ pub struct I64Term {
 /// Type derived for non-terminal I64Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct I64Token {
     pub i64_term: crate::veryl_token::Token,
@@ -7799,7 +8157,7 @@ pub struct I64Token {
 /// Type derived for non-terminal Identifier
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Identifier {
     pub identifier_token: crate::veryl_token::VerylToken,
@@ -7809,7 +8167,7 @@ pub struct Identifier {
 /// Type derived for non-terminal IdentifierFactor
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierFactor {
     pub expression_identifier: Box<ExpressionIdentifier>,
@@ -7820,17 +8178,27 @@ pub struct IdentifierFactor {
 /// Type derived for non-terminal IdentifierFactorOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierFactorOpt {
-    pub function_call: Box<FunctionCall>,
+    pub identifier_factor_opt_group: Box<IdentifierFactorOptGroup>,
+}
+
+///
+/// Type derived for non-terminal IdentifierFactorOptGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum IdentifierFactorOptGroup {
+    FunctionCall(IdentifierFactorOptGroupFunctionCall),
+    StructConstructor(IdentifierFactorOptGroupStructConstructor),
 }
 
 ///
 /// Type derived for non-terminal IdentifierStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierStatement {
     pub expression_identifier: Box<ExpressionIdentifier>,
@@ -7842,7 +8210,7 @@ pub struct IdentifierStatement {
 /// Type derived for non-terminal IdentifierStatementGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum IdentifierStatementGroup {
     FunctionCall(IdentifierStatementGroupFunctionCall),
     Assignment(IdentifierStatementGroupAssignment),
@@ -7852,7 +8220,7 @@ pub enum IdentifierStatementGroup {
 /// Type derived for non-terminal IdentifierTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierTerm {
     pub identifier_term: crate::veryl_token::Token, /* (?:r#)?[a-zA-Z_][0-9a-zA-Z_$]* */
@@ -7862,7 +8230,7 @@ pub struct IdentifierTerm {
 /// Type derived for non-terminal IdentifierToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IdentifierToken {
     pub identifier_term: crate::veryl_token::Token,
@@ -7873,7 +8241,7 @@ pub struct IdentifierToken {
 /// Type derived for non-terminal If
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct If {
     pub if_token: crate::veryl_token::VerylToken,
@@ -7883,7 +8251,7 @@ pub struct If {
 /// Type derived for non-terminal IfExpression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfExpression {
     pub r#if: Box<If>,
@@ -7902,7 +8270,7 @@ pub struct IfExpression {
 /// Type derived for non-terminal IfExpressionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfExpressionList {
     pub r#else: Box<Else>,
@@ -7917,7 +8285,7 @@ pub struct IfExpressionList {
 /// Type derived for non-terminal IfReset
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfReset {
     pub if_reset_token: crate::veryl_token::VerylToken,
@@ -7927,7 +8295,7 @@ pub struct IfReset {
 /// Type derived for non-terminal IfResetStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfResetStatement {
     pub if_reset: Box<IfReset>,
@@ -7940,7 +8308,7 @@ pub struct IfResetStatement {
 /// Type derived for non-terminal IfResetStatementList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfResetStatementList {
     pub r#else: Box<Else>,
@@ -7953,7 +8321,7 @@ pub struct IfResetStatementList {
 /// Type derived for non-terminal IfResetStatementOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfResetStatementOpt {
     pub r#else: Box<Else>,
@@ -7964,7 +8332,7 @@ pub struct IfResetStatementOpt {
 /// Type derived for non-terminal IfResetTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfResetTerm {
     pub if_reset_term: crate::veryl_token::Token, /* (?-u:\b)if_reset(?-u:\b) */
@@ -7974,7 +8342,7 @@ pub struct IfResetTerm {
 /// Type derived for non-terminal IfResetToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfResetToken {
     pub if_reset_term: crate::veryl_token::Token,
@@ -7985,7 +8353,7 @@ pub struct IfResetToken {
 /// Type derived for non-terminal IfStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfStatement {
     pub r#if: Box<If>,
@@ -7999,7 +8367,7 @@ pub struct IfStatement {
 /// Type derived for non-terminal IfStatementList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfStatementList {
     pub r#else: Box<Else>,
@@ -8012,7 +8380,7 @@ pub struct IfStatementList {
 /// Type derived for non-terminal IfStatementOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfStatementOpt {
     pub r#else: Box<Else>,
@@ -8023,7 +8391,7 @@ pub struct IfStatementOpt {
 /// Type derived for non-terminal IfTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfTerm {
     pub if_term: crate::veryl_token::Token, /* (?-u:\b)if(?-u:\b) */
@@ -8033,7 +8401,7 @@ pub struct IfTerm {
 /// Type derived for non-terminal IfToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IfToken {
     pub if_term: crate::veryl_token::Token,
@@ -8044,7 +8412,7 @@ pub struct IfToken {
 /// Type derived for non-terminal Import
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Import {
     pub import_token: crate::veryl_token::VerylToken,
@@ -8054,7 +8422,7 @@ pub struct Import {
 /// Type derived for non-terminal ImportDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ImportDeclaration {
     pub import: Box<Import>,
@@ -8067,7 +8435,7 @@ pub struct ImportDeclaration {
 /// Type derived for non-terminal ImportDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ImportDeclarationOpt {
     pub colon_colon: Box<ColonColon>,
@@ -8078,7 +8446,7 @@ pub struct ImportDeclarationOpt {
 /// Type derived for non-terminal ImportTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ImportTerm {
     pub import_term: crate::veryl_token::Token, /* (?-u:\b)import(?-u:\b) */
@@ -8088,7 +8456,7 @@ pub struct ImportTerm {
 /// Type derived for non-terminal ImportToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ImportToken {
     pub import_term: crate::veryl_token::Token,
@@ -8099,7 +8467,7 @@ pub struct ImportToken {
 /// Type derived for non-terminal In
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct In {
     pub in_token: crate::veryl_token::VerylToken,
@@ -8109,7 +8477,7 @@ pub struct In {
 /// Type derived for non-terminal InTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InTerm {
     pub in_term: crate::veryl_token::Token, /* (?-u:\b)in(?-u:\b) */
@@ -8119,7 +8487,7 @@ pub struct InTerm {
 /// Type derived for non-terminal InToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InToken {
     pub in_term: crate::veryl_token::Token,
@@ -8130,7 +8498,7 @@ pub struct InToken {
 /// Type derived for non-terminal Include
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Include {
     pub include_token: crate::veryl_token::VerylToken,
@@ -8140,7 +8508,7 @@ pub struct Include {
 /// Type derived for non-terminal IncludeDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IncludeDeclaration {
     pub include: Box<Include>,
@@ -8156,7 +8524,7 @@ pub struct IncludeDeclaration {
 /// Type derived for non-terminal IncludeTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IncludeTerm {
     pub include_term: crate::veryl_token::Token, /* (?-u:\b)include(?-u:\b) */
@@ -8166,7 +8534,7 @@ pub struct IncludeTerm {
 /// Type derived for non-terminal IncludeToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct IncludeToken {
     pub include_term: crate::veryl_token::Token,
@@ -8177,7 +8545,7 @@ pub struct IncludeToken {
 /// Type derived for non-terminal Initial
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Initial {
     pub initial_token: crate::veryl_token::VerylToken,
@@ -8187,7 +8555,7 @@ pub struct Initial {
 /// Type derived for non-terminal InitialDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InitialDeclaration {
     pub initial: Box<Initial>,
@@ -8198,7 +8566,7 @@ pub struct InitialDeclaration {
 /// Type derived for non-terminal InitialTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InitialTerm {
     pub initial_term: crate::veryl_token::Token, /* (?-u:\b)initial(?-u:\b) */
@@ -8208,7 +8576,7 @@ pub struct InitialTerm {
 /// Type derived for non-terminal InitialToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InitialToken {
     pub initial_term: crate::veryl_token::Token,
@@ -8219,7 +8587,7 @@ pub struct InitialToken {
 /// Type derived for non-terminal Inout
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Inout {
     pub inout_token: crate::veryl_token::VerylToken,
@@ -8229,7 +8597,7 @@ pub struct Inout {
 /// Type derived for non-terminal InoutTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InoutTerm {
     pub inout_term: crate::veryl_token::Token, /* (?-u:\b)inout(?-u:\b) */
@@ -8239,7 +8607,7 @@ pub struct InoutTerm {
 /// Type derived for non-terminal InoutToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InoutToken {
     pub inout_term: crate::veryl_token::Token,
@@ -8250,7 +8618,7 @@ pub struct InoutToken {
 /// Type derived for non-terminal Input
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Input {
     pub input_token: crate::veryl_token::VerylToken,
@@ -8260,7 +8628,7 @@ pub struct Input {
 /// Type derived for non-terminal InputTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InputTerm {
     pub input_term: crate::veryl_token::Token, /* (?-u:\b)input(?-u:\b) */
@@ -8270,7 +8638,7 @@ pub struct InputTerm {
 /// Type derived for non-terminal InputToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InputToken {
     pub input_term: crate::veryl_token::Token,
@@ -8281,7 +8649,7 @@ pub struct InputToken {
 /// Type derived for non-terminal Inside
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Inside {
     pub inside_token: crate::veryl_token::VerylToken,
@@ -8291,7 +8659,7 @@ pub struct Inside {
 /// Type derived for non-terminal InsideExpression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InsideExpression {
     pub inside: Box<Inside>,
@@ -8305,7 +8673,7 @@ pub struct InsideExpression {
 /// Type derived for non-terminal InsideTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InsideTerm {
     pub inside_term: crate::veryl_token::Token, /* (?-u:\b)inside(?-u:\b) */
@@ -8315,7 +8683,7 @@ pub struct InsideTerm {
 /// Type derived for non-terminal InsideToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InsideToken {
     pub inside_term: crate::veryl_token::Token,
@@ -8326,7 +8694,7 @@ pub struct InsideToken {
 /// Type derived for non-terminal Inst
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Inst {
     pub inst_token: crate::veryl_token::VerylToken,
@@ -8336,7 +8704,7 @@ pub struct Inst {
 /// Type derived for non-terminal InstDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstDeclaration {
     pub inst: Box<Inst>,
@@ -8353,7 +8721,7 @@ pub struct InstDeclaration {
 /// Type derived for non-terminal InstDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstDeclarationOpt {
     pub array: Box<Array>,
@@ -8363,7 +8731,7 @@ pub struct InstDeclarationOpt {
 /// Type derived for non-terminal InstDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstDeclarationOpt0 {
     pub inst_parameter: Box<InstParameter>,
@@ -8373,7 +8741,7 @@ pub struct InstDeclarationOpt0 {
 /// Type derived for non-terminal InstDeclarationOpt1
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstDeclarationOpt1 {
     pub l_paren: Box<LParen>,
@@ -8385,7 +8753,7 @@ pub struct InstDeclarationOpt1 {
 /// Type derived for non-terminal InstDeclarationOpt2
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstDeclarationOpt2 {
     pub inst_port_list: Box<InstPortList>,
@@ -8395,7 +8763,7 @@ pub struct InstDeclarationOpt2 {
 /// Type derived for non-terminal InstParameter
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameter {
     pub hash: Box<Hash>,
@@ -8408,7 +8776,7 @@ pub struct InstParameter {
 /// Type derived for non-terminal InstParameterGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterGroup {
     pub inst_parameter_group_list: Vec<InstParameterGroupList>,
@@ -8419,7 +8787,7 @@ pub struct InstParameterGroup {
 /// Type derived for non-terminal InstParameterGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum InstParameterGroupGroup {
     LBraceInstParameterListRBrace(InstParameterGroupGroupLBraceInstParameterListRBrace),
     InstParameterItem(InstParameterGroupGroupInstParameterItem),
@@ -8429,7 +8797,7 @@ pub enum InstParameterGroupGroup {
 /// Type derived for non-terminal InstParameterGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterGroupList {
     pub attribute: Box<Attribute>,
@@ -8439,7 +8807,7 @@ pub struct InstParameterGroupList {
 /// Type derived for non-terminal InstParameterItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterItem {
     pub identifier: Box<Identifier>,
@@ -8450,7 +8818,7 @@ pub struct InstParameterItem {
 /// Type derived for non-terminal InstParameterItemOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterItemOpt {
     pub colon: Box<Colon>,
@@ -8461,7 +8829,7 @@ pub struct InstParameterItemOpt {
 /// Type derived for non-terminal InstParameterList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterList {
     pub inst_parameter_group: Box<InstParameterGroup>,
@@ -8473,7 +8841,7 @@ pub struct InstParameterList {
 /// Type derived for non-terminal InstParameterListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterListList {
     pub comma: Box<Comma>,
@@ -8484,7 +8852,7 @@ pub struct InstParameterListList {
 /// Type derived for non-terminal InstParameterListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterListOpt {
     pub comma: Box<Comma>,
@@ -8494,7 +8862,7 @@ pub struct InstParameterListOpt {
 /// Type derived for non-terminal InstParameterOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstParameterOpt {
     pub inst_parameter_list: Box<InstParameterList>,
@@ -8504,7 +8872,7 @@ pub struct InstParameterOpt {
 /// Type derived for non-terminal InstPortGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortGroup {
     pub inst_port_group_list: Vec<InstPortGroupList>,
@@ -8515,7 +8883,7 @@ pub struct InstPortGroup {
 /// Type derived for non-terminal InstPortGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum InstPortGroupGroup {
     LBraceInstPortListRBrace(InstPortGroupGroupLBraceInstPortListRBrace),
     InstPortItem(InstPortGroupGroupInstPortItem),
@@ -8525,7 +8893,7 @@ pub enum InstPortGroupGroup {
 /// Type derived for non-terminal InstPortGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortGroupList {
     pub attribute: Box<Attribute>,
@@ -8535,7 +8903,7 @@ pub struct InstPortGroupList {
 /// Type derived for non-terminal InstPortItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortItem {
     pub identifier: Box<Identifier>,
@@ -8546,7 +8914,7 @@ pub struct InstPortItem {
 /// Type derived for non-terminal InstPortItemOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortItemOpt {
     pub colon: Box<Colon>,
@@ -8557,7 +8925,7 @@ pub struct InstPortItemOpt {
 /// Type derived for non-terminal InstPortList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortList {
     pub inst_port_group: Box<InstPortGroup>,
@@ -8569,7 +8937,7 @@ pub struct InstPortList {
 /// Type derived for non-terminal InstPortListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortListList {
     pub comma: Box<Comma>,
@@ -8580,7 +8948,7 @@ pub struct InstPortListList {
 /// Type derived for non-terminal InstPortListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstPortListOpt {
     pub comma: Box<Comma>,
@@ -8590,7 +8958,7 @@ pub struct InstPortListOpt {
 /// Type derived for non-terminal InstTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstTerm {
     pub inst_term: crate::veryl_token::Token, /* (?-u:\b)inst(?-u:\b) */
@@ -8600,7 +8968,7 @@ pub struct InstTerm {
 /// Type derived for non-terminal InstToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InstToken {
     pub inst_term: crate::veryl_token::Token,
@@ -8611,7 +8979,7 @@ pub struct InstToken {
 /// Type derived for non-terminal IntegralNumber
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum IntegralNumber {
     Based(IntegralNumberBased),
     BaseLess(IntegralNumberBaseLess),
@@ -8622,7 +8990,7 @@ pub enum IntegralNumber {
 /// Type derived for non-terminal Interface
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Interface {
     pub interface_token: crate::veryl_token::VerylToken,
@@ -8632,7 +9000,7 @@ pub struct Interface {
 /// Type derived for non-terminal InterfaceDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceDeclaration {
     pub interface_declaration_opt: Option<InterfaceDeclarationOpt>,
@@ -8649,7 +9017,7 @@ pub struct InterfaceDeclaration {
 /// Type derived for non-terminal InterfaceDeclarationList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceDeclarationList {
     pub interface_group: Box<InterfaceGroup>,
@@ -8659,7 +9027,7 @@ pub struct InterfaceDeclarationList {
 /// Type derived for non-terminal InterfaceDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceDeclarationOpt {
     pub r#pub: Box<Pub>,
@@ -8669,7 +9037,7 @@ pub struct InterfaceDeclarationOpt {
 /// Type derived for non-terminal InterfaceDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceDeclarationOpt0 {
     pub with_generic_parameter: Box<WithGenericParameter>,
@@ -8679,7 +9047,7 @@ pub struct InterfaceDeclarationOpt0 {
 /// Type derived for non-terminal InterfaceDeclarationOpt1
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceDeclarationOpt1 {
     pub with_parameter: Box<WithParameter>,
@@ -8689,7 +9057,7 @@ pub struct InterfaceDeclarationOpt1 {
 /// Type derived for non-terminal InterfaceGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceGroup {
     pub interface_group_list: Vec<InterfaceGroupList>,
@@ -8700,7 +9068,7 @@ pub struct InterfaceGroup {
 /// Type derived for non-terminal InterfaceGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum InterfaceGroupGroup {
     LBraceInterfaceGroupGroupListRBrace(InterfaceGroupGroupLBraceInterfaceGroupGroupListRBrace),
     InterfaceItem(InterfaceGroupGroupInterfaceItem),
@@ -8710,7 +9078,7 @@ pub enum InterfaceGroupGroup {
 /// Type derived for non-terminal InterfaceGroupGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceGroupGroupList {
     pub interface_group: Box<InterfaceGroup>,
@@ -8720,7 +9088,7 @@ pub struct InterfaceGroupGroupList {
 /// Type derived for non-terminal InterfaceGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceGroupList {
     pub attribute: Box<Attribute>,
@@ -8730,7 +9098,7 @@ pub struct InterfaceGroupList {
 /// Type derived for non-terminal InterfaceItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum InterfaceItem {
     GenerateItem(InterfaceItemGenerateItem),
     ModportDeclaration(InterfaceItemModportDeclaration),
@@ -8740,7 +9108,7 @@ pub enum InterfaceItem {
 /// Type derived for non-terminal InterfaceTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceTerm {
     pub interface_term: crate::veryl_token::Token, /* (?-u:\b)interface(?-u:\b) */
@@ -8750,7 +9118,7 @@ pub struct InterfaceTerm {
 /// Type derived for non-terminal InterfaceToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct InterfaceToken {
     pub interface_term: crate::veryl_token::Token,
@@ -8761,7 +9129,7 @@ pub struct InterfaceToken {
 /// Type derived for non-terminal LAngle
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LAngle {
     pub l_angle_token: crate::veryl_token::VerylToken,
@@ -8771,7 +9139,7 @@ pub struct LAngle {
 /// Type derived for non-terminal LAngleTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LAngleTerm {
     pub l_angle_term: crate::veryl_token::Token, /* < */
@@ -8781,7 +9149,7 @@ pub struct LAngleTerm {
 /// Type derived for non-terminal LAngleToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LAngleToken {
     pub l_angle_term: crate::veryl_token::Token,
@@ -8792,7 +9160,7 @@ pub struct LAngleToken {
 /// Type derived for non-terminal LBrace
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LBrace {
     pub l_brace_token: crate::veryl_token::VerylToken,
@@ -8802,7 +9170,7 @@ pub struct LBrace {
 /// Type derived for non-terminal LBraceTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LBraceTerm {
     pub l_brace_term: crate::veryl_token::Token, /* { */
@@ -8812,7 +9180,7 @@ pub struct LBraceTerm {
 /// Type derived for non-terminal LBraceToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LBraceToken {
     pub l_brace_term: crate::veryl_token::Token,
@@ -8823,7 +9191,7 @@ pub struct LBraceToken {
 /// Type derived for non-terminal LBracket
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LBracket {
     pub l_bracket_token: crate::veryl_token::VerylToken,
@@ -8833,7 +9201,7 @@ pub struct LBracket {
 /// Type derived for non-terminal LBracketTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LBracketTerm {
     pub l_bracket_term: crate::veryl_token::Token, /* [ */
@@ -8843,7 +9211,7 @@ pub struct LBracketTerm {
 /// Type derived for non-terminal LBracketToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LBracketToken {
     pub l_bracket_term: crate::veryl_token::Token,
@@ -8854,7 +9222,7 @@ pub struct LBracketToken {
 /// Type derived for non-terminal LParen
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LParen {
     pub l_paren_token: crate::veryl_token::VerylToken,
@@ -8864,7 +9232,7 @@ pub struct LParen {
 /// Type derived for non-terminal LParenTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LParenTerm {
     pub l_paren_term: crate::veryl_token::Token, /* ( */
@@ -8874,7 +9242,7 @@ pub struct LParenTerm {
 /// Type derived for non-terminal LParenToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LParenToken {
     pub l_paren_term: crate::veryl_token::Token,
@@ -8885,7 +9253,7 @@ pub struct LParenToken {
 /// Type derived for non-terminal Let
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Let {
     pub let_token: crate::veryl_token::VerylToken,
@@ -8895,7 +9263,7 @@ pub struct Let {
 /// Type derived for non-terminal LetDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LetDeclaration {
     pub r#let: Box<Let>,
@@ -8912,7 +9280,7 @@ pub struct LetDeclaration {
 /// Type derived for non-terminal LetDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LetDeclarationOpt {
     pub clock_domain: Box<ClockDomain>,
@@ -8922,7 +9290,7 @@ pub struct LetDeclarationOpt {
 /// Type derived for non-terminal LetStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LetStatement {
     pub r#let: Box<Let>,
@@ -8939,7 +9307,7 @@ pub struct LetStatement {
 /// Type derived for non-terminal LetStatementOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LetStatementOpt {
     pub clock_domain: Box<ClockDomain>,
@@ -8949,7 +9317,7 @@ pub struct LetStatementOpt {
 /// Type derived for non-terminal LetTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LetTerm {
     pub let_term: crate::veryl_token::Token, /* (?-u:\b)let(?-u:\b) */
@@ -8959,7 +9327,7 @@ pub struct LetTerm {
 /// Type derived for non-terminal LetToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LetToken {
     pub let_term: crate::veryl_token::Token,
@@ -8970,7 +9338,7 @@ pub struct LetToken {
 /// Type derived for non-terminal Logic
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Logic {
     pub logic_token: crate::veryl_token::VerylToken,
@@ -8980,7 +9348,7 @@ pub struct Logic {
 /// Type derived for non-terminal LogicTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LogicTerm {
     pub logic_term: crate::veryl_token::Token, /* (?-u:\b)logic(?-u:\b) */
@@ -8990,7 +9358,7 @@ pub struct LogicTerm {
 /// Type derived for non-terminal LogicToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LogicToken {
     pub logic_term: crate::veryl_token::Token,
@@ -9001,7 +9369,7 @@ pub struct LogicToken {
 /// Type derived for non-terminal Lsb
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Lsb {
     pub lsb_token: crate::veryl_token::VerylToken,
@@ -9011,7 +9379,7 @@ pub struct Lsb {
 /// Type derived for non-terminal LsbTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LsbTerm {
     pub lsb_term: crate::veryl_token::Token, /* (?-u:\b)lsb(?-u:\b) */
@@ -9021,7 +9389,7 @@ pub struct LsbTerm {
 /// Type derived for non-terminal LsbToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct LsbToken {
     pub lsb_term: crate::veryl_token::Token,
@@ -9032,7 +9400,7 @@ pub struct LsbToken {
 /// Type derived for non-terminal MinusColon
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MinusColon {
     pub minus_colon_token: crate::veryl_token::VerylToken,
@@ -9042,7 +9410,7 @@ pub struct MinusColon {
 /// Type derived for non-terminal MinusColonTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MinusColonTerm {
     pub minus_colon_term: crate::veryl_token::Token, /* -: */
@@ -9052,7 +9420,7 @@ pub struct MinusColonTerm {
 /// Type derived for non-terminal MinusColonToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MinusColonToken {
     pub minus_colon_term: crate::veryl_token::Token,
@@ -9063,7 +9431,7 @@ pub struct MinusColonToken {
 /// Type derived for non-terminal MinusGT
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MinusGT {
     pub minus_g_t_token: crate::veryl_token::VerylToken,
@@ -9073,7 +9441,7 @@ pub struct MinusGT {
 /// Type derived for non-terminal MinusGTTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MinusGTTerm {
     pub minus_g_t_term: crate::veryl_token::Token, /* -> */
@@ -9083,7 +9451,7 @@ pub struct MinusGTTerm {
 /// Type derived for non-terminal MinusGTToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MinusGTToken {
     pub minus_g_t_term: crate::veryl_token::Token,
@@ -9094,7 +9462,7 @@ pub struct MinusGTToken {
 /// Type derived for non-terminal Modport
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Modport {
     pub modport_token: crate::veryl_token::VerylToken,
@@ -9104,7 +9472,7 @@ pub struct Modport {
 /// Type derived for non-terminal ModportDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportDeclaration {
     pub modport: Box<Modport>,
@@ -9118,7 +9486,7 @@ pub struct ModportDeclaration {
 /// Type derived for non-terminal ModportGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportGroup {
     pub modport_group_list: Vec<ModportGroupList>,
@@ -9129,7 +9497,7 @@ pub struct ModportGroup {
 /// Type derived for non-terminal ModportGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ModportGroupGroup {
     LBraceModportListRBrace(ModportGroupGroupLBraceModportListRBrace),
     ModportItem(ModportGroupGroupModportItem),
@@ -9139,7 +9507,7 @@ pub enum ModportGroupGroup {
 /// Type derived for non-terminal ModportGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportGroupList {
     pub attribute: Box<Attribute>,
@@ -9149,7 +9517,7 @@ pub struct ModportGroupList {
 /// Type derived for non-terminal ModportItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportItem {
     pub identifier: Box<Identifier>,
@@ -9161,7 +9529,7 @@ pub struct ModportItem {
 /// Type derived for non-terminal ModportList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportList {
     pub modport_group: Box<ModportGroup>,
@@ -9173,7 +9541,7 @@ pub struct ModportList {
 /// Type derived for non-terminal ModportListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportListList {
     pub comma: Box<Comma>,
@@ -9184,7 +9552,7 @@ pub struct ModportListList {
 /// Type derived for non-terminal ModportListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportListOpt {
     pub comma: Box<Comma>,
@@ -9194,7 +9562,7 @@ pub struct ModportListOpt {
 /// Type derived for non-terminal ModportTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportTerm {
     pub modport_term: crate::veryl_token::Token, /* (?-u:\b)modport(?-u:\b) */
@@ -9204,7 +9572,7 @@ pub struct ModportTerm {
 /// Type derived for non-terminal ModportToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModportToken {
     pub modport_term: crate::veryl_token::Token,
@@ -9215,7 +9583,7 @@ pub struct ModportToken {
 /// Type derived for non-terminal Module
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Module {
     pub module_token: crate::veryl_token::VerylToken,
@@ -9225,7 +9593,7 @@ pub struct Module {
 /// Type derived for non-terminal ModuleDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclaration {
     pub module_declaration_opt: Option<ModuleDeclarationOpt>,
@@ -9244,7 +9612,7 @@ pub struct ModuleDeclaration {
 /// Type derived for non-terminal ModuleDeclarationList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationList {
     pub module_group: Box<ModuleGroup>,
@@ -9254,7 +9622,7 @@ pub struct ModuleDeclarationList {
 /// Type derived for non-terminal ModuleDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt {
     pub r#pub: Box<Pub>,
@@ -9264,7 +9632,7 @@ pub struct ModuleDeclarationOpt {
 /// Type derived for non-terminal ModuleDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt0 {
     pub with_generic_parameter: Box<WithGenericParameter>,
@@ -9274,7 +9642,7 @@ pub struct ModuleDeclarationOpt0 {
 /// Type derived for non-terminal ModuleDeclarationOpt1
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt1 {
     pub r#for: Box<For>,
@@ -9285,7 +9653,7 @@ pub struct ModuleDeclarationOpt1 {
 /// Type derived for non-terminal ModuleDeclarationOpt2
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt2 {
     pub with_parameter: Box<WithParameter>,
@@ -9295,7 +9663,7 @@ pub struct ModuleDeclarationOpt2 {
 /// Type derived for non-terminal ModuleDeclarationOpt3
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleDeclarationOpt3 {
     pub port_declaration: Box<PortDeclaration>,
@@ -9305,7 +9673,7 @@ pub struct ModuleDeclarationOpt3 {
 /// Type derived for non-terminal ModuleGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleGroup {
     pub module_group_list: Vec<ModuleGroupList>,
@@ -9316,7 +9684,7 @@ pub struct ModuleGroup {
 /// Type derived for non-terminal ModuleGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ModuleGroupGroup {
     LBraceModuleGroupGroupListRBrace(ModuleGroupGroupLBraceModuleGroupGroupListRBrace),
     ModuleItem(ModuleGroupGroupModuleItem),
@@ -9326,7 +9694,7 @@ pub enum ModuleGroupGroup {
 /// Type derived for non-terminal ModuleGroupGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleGroupGroupList {
     pub module_group: Box<ModuleGroup>,
@@ -9336,7 +9704,7 @@ pub struct ModuleGroupGroupList {
 /// Type derived for non-terminal ModuleGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleGroupList {
     pub attribute: Box<Attribute>,
@@ -9346,7 +9714,7 @@ pub struct ModuleGroupList {
 /// Type derived for non-terminal ModuleItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleItem {
     pub generate_item: Box<GenerateItem>,
@@ -9356,7 +9724,7 @@ pub struct ModuleItem {
 /// Type derived for non-terminal ModuleTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleTerm {
     pub module_term: crate::veryl_token::Token, /* (?-u:\b)module(?-u:\b) */
@@ -9366,7 +9734,7 @@ pub struct ModuleTerm {
 /// Type derived for non-terminal ModuleToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ModuleToken {
     pub module_term: crate::veryl_token::Token,
@@ -9377,7 +9745,7 @@ pub struct ModuleToken {
 /// Type derived for non-terminal Msb
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Msb {
     pub msb_token: crate::veryl_token::VerylToken,
@@ -9387,7 +9755,7 @@ pub struct Msb {
 /// Type derived for non-terminal MsbTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MsbTerm {
     pub msb_term: crate::veryl_token::Token, /* (?-u:\b)msb(?-u:\b) */
@@ -9397,7 +9765,7 @@ pub struct MsbTerm {
 /// Type derived for non-terminal MsbToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct MsbToken {
     pub msb_term: crate::veryl_token::Token,
@@ -9408,7 +9776,7 @@ pub struct MsbToken {
 /// Type derived for non-terminal Number
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Number {
     IntegralNumber(NumberIntegralNumber),
     RealNumber(NumberRealNumber),
@@ -9418,7 +9786,7 @@ pub enum Number {
 /// Type derived for non-terminal Operator01
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator01 {
     pub operator01_token: crate::veryl_token::VerylToken,
@@ -9428,7 +9796,7 @@ pub struct Operator01 {
 /// Type derived for non-terminal Operator01Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator01Term {
     pub operator01_term: crate::veryl_token::Token, /* \|\| */
@@ -9438,7 +9806,7 @@ pub struct Operator01Term {
 /// Type derived for non-terminal Operator01Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator01Token {
     pub operator01_term: crate::veryl_token::Token,
@@ -9449,7 +9817,7 @@ pub struct Operator01Token {
 /// Type derived for non-terminal Operator02
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator02 {
     pub operator02_token: crate::veryl_token::VerylToken,
@@ -9459,7 +9827,7 @@ pub struct Operator02 {
 /// Type derived for non-terminal Operator02Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator02Term {
     pub operator02_term: crate::veryl_token::Token, /* && */
@@ -9469,7 +9837,7 @@ pub struct Operator02Term {
 /// Type derived for non-terminal Operator02Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator02Token {
     pub operator02_term: crate::veryl_token::Token,
@@ -9480,7 +9848,7 @@ pub struct Operator02Token {
 /// Type derived for non-terminal Operator03
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator03 {
     pub operator03_token: crate::veryl_token::VerylToken,
@@ -9490,7 +9858,7 @@ pub struct Operator03 {
 /// Type derived for non-terminal Operator03Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator03Term {
     pub operator03_term: crate::veryl_token::Token, /* \| */
@@ -9500,7 +9868,7 @@ pub struct Operator03Term {
 /// Type derived for non-terminal Operator03Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator03Token {
     pub operator03_term: crate::veryl_token::Token,
@@ -9511,7 +9879,7 @@ pub struct Operator03Token {
 /// Type derived for non-terminal Operator04
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator04 {
     pub operator04_token: crate::veryl_token::VerylToken,
@@ -9521,7 +9889,7 @@ pub struct Operator04 {
 /// Type derived for non-terminal Operator04Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator04Term {
     pub operator04_term: crate::veryl_token::Token, /* \^~|\^|~\^ */
@@ -9531,7 +9899,7 @@ pub struct Operator04Term {
 /// Type derived for non-terminal Operator04Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator04Token {
     pub operator04_term: crate::veryl_token::Token,
@@ -9542,7 +9910,7 @@ pub struct Operator04Token {
 /// Type derived for non-terminal Operator05
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator05 {
     pub operator05_token: crate::veryl_token::VerylToken,
@@ -9552,7 +9920,7 @@ pub struct Operator05 {
 /// Type derived for non-terminal Operator05Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator05Term {
     pub operator05_term: crate::veryl_token::Token, /* & */
@@ -9562,7 +9930,7 @@ pub struct Operator05Term {
 /// Type derived for non-terminal Operator05Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator05Token {
     pub operator05_term: crate::veryl_token::Token,
@@ -9573,7 +9941,7 @@ pub struct Operator05Token {
 /// Type derived for non-terminal Operator06
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator06 {
     pub operator06_token: crate::veryl_token::VerylToken,
@@ -9583,7 +9951,7 @@ pub struct Operator06 {
 /// Type derived for non-terminal Operator06Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator06Term {
     pub operator06_term: crate::veryl_token::Token, /* ===|==\?|!==|!=\?|==|!= */
@@ -9593,7 +9961,7 @@ pub struct Operator06Term {
 /// Type derived for non-terminal Operator06Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator06Token {
     pub operator06_term: crate::veryl_token::Token,
@@ -9604,7 +9972,7 @@ pub struct Operator06Token {
 /// Type derived for non-terminal Operator07
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator07 {
     pub operator07_token: crate::veryl_token::VerylToken,
@@ -9614,7 +9982,7 @@ pub struct Operator07 {
 /// Type derived for non-terminal Operator07Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator07Term {
     pub operator07_term: crate::veryl_token::Token, /* <=|>=|<:|>: */
@@ -9624,7 +9992,7 @@ pub struct Operator07Term {
 /// Type derived for non-terminal Operator07Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator07Token {
     pub operator07_term: crate::veryl_token::Token,
@@ -9635,7 +10003,7 @@ pub struct Operator07Token {
 /// Type derived for non-terminal Operator08
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator08 {
     pub operator08_token: crate::veryl_token::VerylToken,
@@ -9645,7 +10013,7 @@ pub struct Operator08 {
 /// Type derived for non-terminal Operator08Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator08Term {
     pub operator08_term: crate::veryl_token::Token, /* <<<|>>>|<<|>> */
@@ -9655,7 +10023,7 @@ pub struct Operator08Term {
 /// Type derived for non-terminal Operator08Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator08Token {
     pub operator08_term: crate::veryl_token::Token,
@@ -9666,7 +10034,7 @@ pub struct Operator08Token {
 /// Type derived for non-terminal Operator09
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator09 {
     pub operator09_token: crate::veryl_token::VerylToken,
@@ -9676,7 +10044,7 @@ pub struct Operator09 {
 /// Type derived for non-terminal Operator09Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator09Term {
     pub operator09_term: crate::veryl_token::Token, /* \+|- */
@@ -9686,7 +10054,7 @@ pub struct Operator09Term {
 /// Type derived for non-terminal Operator09Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator09Token {
     pub operator09_term: crate::veryl_token::Token,
@@ -9697,7 +10065,7 @@ pub struct Operator09Token {
 /// Type derived for non-terminal Operator10
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator10 {
     pub operator10_token: crate::veryl_token::VerylToken,
@@ -9707,7 +10075,7 @@ pub struct Operator10 {
 /// Type derived for non-terminal Operator10Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator10Term {
     pub operator10_term: crate::veryl_token::Token, /* /|% */
@@ -9717,7 +10085,7 @@ pub struct Operator10Term {
 /// Type derived for non-terminal Operator10Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator10Token {
     pub operator10_term: crate::veryl_token::Token,
@@ -9728,7 +10096,7 @@ pub struct Operator10Token {
 /// Type derived for non-terminal Operator11
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator11 {
     pub operator11_token: crate::veryl_token::VerylToken,
@@ -9738,7 +10106,7 @@ pub struct Operator11 {
 /// Type derived for non-terminal Operator11Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator11Term {
     pub operator11_term: crate::veryl_token::Token, /* \*\* */
@@ -9748,7 +10116,7 @@ pub struct Operator11Term {
 /// Type derived for non-terminal Operator11Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Operator11Token {
     pub operator11_term: crate::veryl_token::Token,
@@ -9759,7 +10127,7 @@ pub struct Operator11Token {
 /// Type derived for non-terminal Output
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Output {
     pub output_token: crate::veryl_token::VerylToken,
@@ -9769,7 +10137,7 @@ pub struct Output {
 /// Type derived for non-terminal OutputTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct OutputTerm {
     pub output_term: crate::veryl_token::Token, /* (?-u:\b)output(?-u:\b) */
@@ -9779,7 +10147,7 @@ pub struct OutputTerm {
 /// Type derived for non-terminal OutputToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct OutputToken {
     pub output_term: crate::veryl_token::Token,
@@ -9790,7 +10158,7 @@ pub struct OutputToken {
 /// Type derived for non-terminal Outside
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Outside {
     pub outside_token: crate::veryl_token::VerylToken,
@@ -9800,7 +10168,7 @@ pub struct Outside {
 /// Type derived for non-terminal OutsideExpression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct OutsideExpression {
     pub outside: Box<Outside>,
@@ -9814,7 +10182,7 @@ pub struct OutsideExpression {
 /// Type derived for non-terminal OutsideTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct OutsideTerm {
     pub outside_term: crate::veryl_token::Token, /* (?-u:\b)outside(?-u:\b) */
@@ -9824,7 +10192,7 @@ pub struct OutsideTerm {
 /// Type derived for non-terminal OutsideToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct OutsideToken {
     pub outside_term: crate::veryl_token::Token,
@@ -9835,7 +10203,7 @@ pub struct OutsideToken {
 /// Type derived for non-terminal Package
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Package {
     pub package_token: crate::veryl_token::VerylToken,
@@ -9845,7 +10213,7 @@ pub struct Package {
 /// Type derived for non-terminal PackageDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageDeclaration {
     pub package_declaration_opt: Option<PackageDeclarationOpt>,
@@ -9861,7 +10229,7 @@ pub struct PackageDeclaration {
 /// Type derived for non-terminal PackageDeclarationList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageDeclarationList {
     pub package_group: Box<PackageGroup>,
@@ -9871,7 +10239,7 @@ pub struct PackageDeclarationList {
 /// Type derived for non-terminal PackageDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageDeclarationOpt {
     pub r#pub: Box<Pub>,
@@ -9881,7 +10249,7 @@ pub struct PackageDeclarationOpt {
 /// Type derived for non-terminal PackageDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageDeclarationOpt0 {
     pub with_generic_parameter: Box<WithGenericParameter>,
@@ -9891,7 +10259,7 @@ pub struct PackageDeclarationOpt0 {
 /// Type derived for non-terminal PackageGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageGroup {
     pub package_group_list: Vec<PackageGroupList>,
@@ -9902,7 +10270,7 @@ pub struct PackageGroup {
 /// Type derived for non-terminal PackageGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum PackageGroupGroup {
     LBracePackageGroupGroupListRBrace(PackageGroupGroupLBracePackageGroupGroupListRBrace),
     PackageItem(PackageGroupGroupPackageItem),
@@ -9912,7 +10280,7 @@ pub enum PackageGroupGroup {
 /// Type derived for non-terminal PackageGroupGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageGroupGroupList {
     pub package_group: Box<PackageGroup>,
@@ -9922,7 +10290,7 @@ pub struct PackageGroupGroupList {
 /// Type derived for non-terminal PackageGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageGroupList {
     pub attribute: Box<Attribute>,
@@ -9932,7 +10300,7 @@ pub struct PackageGroupList {
 /// Type derived for non-terminal PackageItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum PackageItem {
     VarDeclaration(PackageItemVarDeclaration),
     ConstDeclaration(PackageItemConstDeclaration),
@@ -9948,7 +10316,7 @@ pub enum PackageItem {
 /// Type derived for non-terminal PackageTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageTerm {
     pub package_term: crate::veryl_token::Token, /* (?-u:\b)package(?-u:\b) */
@@ -9958,7 +10326,7 @@ pub struct PackageTerm {
 /// Type derived for non-terminal PackageToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PackageToken {
     pub package_term: crate::veryl_token::Token,
@@ -9969,7 +10337,7 @@ pub struct PackageToken {
 /// Type derived for non-terminal Param
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Param {
     pub param_token: crate::veryl_token::VerylToken,
@@ -9979,7 +10347,7 @@ pub struct Param {
 /// Type derived for non-terminal ParamTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ParamTerm {
     pub param_term: crate::veryl_token::Token, /* (?-u:\b)param(?-u:\b) */
@@ -9989,7 +10357,7 @@ pub struct ParamTerm {
 /// Type derived for non-terminal ParamToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ParamToken {
     pub param_term: crate::veryl_token::Token,
@@ -10000,7 +10368,7 @@ pub struct ParamToken {
 /// Type derived for non-terminal PlusColon
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PlusColon {
     pub plus_colon_token: crate::veryl_token::VerylToken,
@@ -10010,7 +10378,7 @@ pub struct PlusColon {
 /// Type derived for non-terminal PlusColonTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PlusColonTerm {
     pub plus_colon_term: crate::veryl_token::Token, /* +: */
@@ -10020,7 +10388,7 @@ pub struct PlusColonTerm {
 /// Type derived for non-terminal PlusColonToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PlusColonToken {
     pub plus_colon_term: crate::veryl_token::Token,
@@ -10031,7 +10399,7 @@ pub struct PlusColonToken {
 /// Type derived for non-terminal PortDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclaration {
     pub l_paren: Box<LParen>,
@@ -10043,7 +10411,7 @@ pub struct PortDeclaration {
 /// Type derived for non-terminal PortDeclarationGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationGroup {
     pub port_declaration_group_list: Vec<PortDeclarationGroupList>,
@@ -10054,7 +10422,7 @@ pub struct PortDeclarationGroup {
 /// Type derived for non-terminal PortDeclarationGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum PortDeclarationGroupGroup {
     LBracePortDeclarationListRBrace(PortDeclarationGroupGroupLBracePortDeclarationListRBrace),
     PortDeclarationItem(PortDeclarationGroupGroupPortDeclarationItem),
@@ -10064,7 +10432,7 @@ pub enum PortDeclarationGroupGroup {
 /// Type derived for non-terminal PortDeclarationGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationGroupList {
     pub attribute: Box<Attribute>,
@@ -10074,7 +10442,7 @@ pub struct PortDeclarationGroupList {
 /// Type derived for non-terminal PortDeclarationItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationItem {
     pub identifier: Box<Identifier>,
@@ -10086,7 +10454,7 @@ pub struct PortDeclarationItem {
 /// Type derived for non-terminal PortDeclarationItemGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum PortDeclarationItemGroup {
     PortTypeConcrete(PortDeclarationItemGroupPortTypeConcrete),
     PortTypeAbstract(PortDeclarationItemGroupPortTypeAbstract),
@@ -10096,7 +10464,7 @@ pub enum PortDeclarationItemGroup {
 /// Type derived for non-terminal PortDeclarationList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationList {
     pub port_declaration_group: Box<PortDeclarationGroup>,
@@ -10108,7 +10476,7 @@ pub struct PortDeclarationList {
 /// Type derived for non-terminal PortDeclarationListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationListList {
     pub comma: Box<Comma>,
@@ -10119,7 +10487,7 @@ pub struct PortDeclarationListList {
 /// Type derived for non-terminal PortDeclarationListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationListOpt {
     pub comma: Box<Comma>,
@@ -10129,7 +10497,7 @@ pub struct PortDeclarationListOpt {
 /// Type derived for non-terminal PortDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDeclarationOpt {
     pub port_declaration_list: Box<PortDeclarationList>,
@@ -10139,7 +10507,7 @@ pub struct PortDeclarationOpt {
 /// Type derived for non-terminal PortDefaultValue
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortDefaultValue {
     pub expression: Box<Expression>,
@@ -10149,7 +10517,7 @@ pub struct PortDefaultValue {
 /// Type derived for non-terminal PortTypeAbstract
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeAbstract {
     pub port_type_abstract_opt: Option<PortTypeAbstractOpt>,
@@ -10162,7 +10530,7 @@ pub struct PortTypeAbstract {
 /// Type derived for non-terminal PortTypeAbstractOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeAbstractOpt {
     pub clock_domain: Box<ClockDomain>,
@@ -10172,7 +10540,7 @@ pub struct PortTypeAbstractOpt {
 /// Type derived for non-terminal PortTypeAbstractOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeAbstractOpt0 {
     pub colon_colon: Box<ColonColon>,
@@ -10183,7 +10551,7 @@ pub struct PortTypeAbstractOpt0 {
 /// Type derived for non-terminal PortTypeAbstractOpt1
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeAbstractOpt1 {
     pub array: Box<Array>,
@@ -10193,7 +10561,7 @@ pub struct PortTypeAbstractOpt1 {
 /// Type derived for non-terminal PortTypeConcrete
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeConcrete {
     pub direction: Box<Direction>,
@@ -10206,7 +10574,7 @@ pub struct PortTypeConcrete {
 /// Type derived for non-terminal PortTypeConcreteOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeConcreteOpt {
     pub clock_domain: Box<ClockDomain>,
@@ -10216,18 +10584,49 @@ pub struct PortTypeConcreteOpt {
 /// Type derived for non-terminal PortTypeConcreteOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PortTypeConcreteOpt0 {
     pub equ: Box<Equ>,
     pub port_default_value: Box<PortDefaultValue>,
 }
 
+///
+/// Type derived for non-terminal Property
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Property {
+    pub property_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal PropertyTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PropertyTerm {
+    pub property_term: crate::veryl_token::Token, /* (?-u:\b)property(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal PropertyToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct PropertyToken {
+    pub property_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Proto
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Proto {
     pub proto_token: crate::veryl_token::VerylToken,
@@ -10237,7 +10636,7 @@ pub struct Proto {
 /// Type derived for non-terminal ProtoModuleDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProtoModuleDeclaration {
     pub proto_module_declaration_opt: Option<ProtoModuleDeclarationOpt>,
@@ -10253,7 +10652,7 @@ pub struct ProtoModuleDeclaration {
 /// Type derived for non-terminal ProtoModuleDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProtoModuleDeclarationOpt {
     pub r#pub: Box<Pub>,
@@ -10263,7 +10662,7 @@ pub struct ProtoModuleDeclarationOpt {
 /// Type derived for non-terminal ProtoModuleDeclarationOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProtoModuleDeclarationOpt0 {
     pub with_parameter: Box<WithParameter>,
@@ -10273,7 +10672,7 @@ pub struct ProtoModuleDeclarationOpt0 {
 /// Type derived for non-terminal ProtoModuleDeclarationOpt1
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProtoModuleDeclarationOpt1 {
     pub port_declaration: Box<PortDeclaration>,
@@ -10283,7 +10682,7 @@ pub struct ProtoModuleDeclarationOpt1 {
 /// Type derived for non-terminal ProtoTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProtoTerm {
     pub proto_term: crate::veryl_token::Token, /* (?-u:\b)proto(?-u:\b) */
@@ -10293,7 +10692,7 @@ pub struct ProtoTerm {
 /// Type derived for non-terminal ProtoToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ProtoToken {
     pub proto_term: crate::veryl_token::Token,
@@ -10304,7 +10703,7 @@ pub struct ProtoToken {
 /// Type derived for non-terminal Pub
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Pub {
     pub pub_token: crate::veryl_token::VerylToken,
@@ -10314,7 +10713,7 @@ pub struct Pub {
 /// Type derived for non-terminal PubTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PubTerm {
     pub pub_term: crate::veryl_token::Token, /* (?-u:\b)pub(?-u:\b) */
@@ -10324,7 +10723,7 @@ pub struct PubTerm {
 /// Type derived for non-terminal PubToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct PubToken {
     pub pub_term: crate::veryl_token::Token,
@@ -10335,7 +10734,7 @@ pub struct PubToken {
 /// Type derived for non-terminal QuoteLBrace
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct QuoteLBrace {
     pub quote_l_brace_token: crate::veryl_token::VerylToken,
@@ -10345,7 +10744,7 @@ pub struct QuoteLBrace {
 /// Type derived for non-terminal QuoteLBraceTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct QuoteLBraceTerm {
     pub quote_l_brace_term: crate::veryl_token::Token, /* '\{ */
@@ -10355,7 +10754,7 @@ pub struct QuoteLBraceTerm {
 /// Type derived for non-terminal QuoteLBraceToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct QuoteLBraceToken {
     pub quote_l_brace_term: crate::veryl_token::Token,
@@ -10366,7 +10765,7 @@ pub struct QuoteLBraceToken {
 /// Type derived for non-terminal RAngle
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RAngle {
     pub r_angle_token: crate::veryl_token::VerylToken,
@@ -10376,7 +10775,7 @@ pub struct RAngle {
 /// Type derived for non-terminal RAngleTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RAngleTerm {
     pub r_angle_term: crate::veryl_token::Token, /* > */
@@ -10386,7 +10785,7 @@ pub struct RAngleTerm {
 /// Type derived for non-terminal RAngleToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RAngleToken {
     pub r_angle_term: crate::veryl_token::Token,
@@ -10397,7 +10796,7 @@ pub struct RAngleToken {
 /// Type derived for non-terminal RBrace
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RBrace {
     pub r_brace_token: crate::veryl_token::VerylToken,
@@ -10407,7 +10806,7 @@ pub struct RBrace {
 /// Type derived for non-terminal RBraceTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RBraceTerm {
     pub r_brace_term: crate::veryl_token::Token, /* } */
@@ -10417,7 +10816,7 @@ pub struct RBraceTerm {
 /// Type derived for non-terminal RBraceToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RBraceToken {
     pub r_brace_term: crate::veryl_token::Token,
@@ -10428,7 +10827,7 @@ pub struct RBraceToken {
 /// Type derived for non-terminal RBracket
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RBracket {
     pub r_bracket_token: crate::veryl_token::VerylToken,
@@ -10438,7 +10837,7 @@ pub struct RBracket {
 /// Type derived for non-terminal RBracketTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RBracketTerm {
     pub r_bracket_term: crate::veryl_token::Token, /* ] */
@@ -10448,7 +10847,7 @@ pub struct RBracketTerm {
 /// Type derived for non-terminal RBracketToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RBracketToken {
     pub r_bracket_term: crate::veryl_token::Token,
@@ -10459,7 +10858,7 @@ pub struct RBracketToken {
 /// Type derived for non-terminal RParen
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RParen {
     pub r_paren_token: crate::veryl_token::VerylToken,
@@ -10469,7 +10868,7 @@ pub struct RParen {
 /// Type derived for non-terminal RParenTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RParenTerm {
     pub r_paren_term: crate::veryl_token::Token, /* ) */
@@ -10479,7 +10878,7 @@ pub struct RParenTerm {
 /// Type derived for non-terminal RParenToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RParenToken {
     pub r_paren_term: crate::veryl_token::Token,
@@ -10490,7 +10889,7 @@ pub struct RParenToken {
 /// Type derived for non-terminal Range
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Range {
     pub expression: Box<Expression>,
@@ -10501,7 +10900,7 @@ pub struct Range {
 /// Type derived for non-terminal RangeItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeItem {
     pub range: Box<Range>,
@@ -10511,7 +10910,7 @@ pub struct RangeItem {
 /// Type derived for non-terminal RangeList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeList {
     pub range_item: Box<RangeItem>,
@@ -10523,7 +10922,7 @@ pub struct RangeList {
 /// Type derived for non-terminal RangeListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeListList {
     pub comma: Box<Comma>,
@@ -10534,7 +10933,7 @@ pub struct RangeListList {
 /// Type derived for non-terminal RangeListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeListOpt {
     pub comma: Box<Comma>,
@@ -10544,7 +10943,7 @@ pub struct RangeListOpt {
 /// Type derived for non-terminal RangeOperator
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum RangeOperator {
     DotDot(RangeOperatorDotDot),
     DotDotEqu(RangeOperatorDotDotEqu),
@@ -10554,7 +10953,7 @@ pub enum RangeOperator {
 /// Type derived for non-terminal RangeOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RangeOpt {
     pub range_operator: Box<RangeOperator>,
@@ -10565,7 +10964,7 @@ pub struct RangeOpt {
 /// Type derived for non-terminal RealNumber
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum RealNumber {
     FixedPoint(RealNumberFixedPoint),
     Exponent(RealNumberExponent),
@@ -10575,7 +10974,7 @@ pub enum RealNumber {
 /// Type derived for non-terminal Ref
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Ref {
     pub ref_token: crate::veryl_token::VerylToken,
@@ -10585,7 +10984,7 @@ pub struct Ref {
 /// Type derived for non-terminal RefTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RefTerm {
     pub ref_term: crate::veryl_token::Token, /* (?-u:\b)ref(?-u:\b) */
@@ -10595,7 +10994,7 @@ pub struct RefTerm {
 /// Type derived for non-terminal RefToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RefToken {
     pub ref_term: crate::veryl_token::Token,
@@ -10606,7 +11005,7 @@ pub struct RefToken {
 /// Type derived for non-terminal Repeat
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Repeat {
     pub repeat_token: crate::veryl_token::VerylToken,
@@ -10616,7 +11015,7 @@ pub struct Repeat {
 /// Type derived for non-terminal RepeatTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RepeatTerm {
     pub repeat_term: crate::veryl_token::Token, /* (?-u:\b)repeat(?-u:\b) */
@@ -10626,7 +11025,7 @@ pub struct RepeatTerm {
 /// Type derived for non-terminal RepeatToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct RepeatToken {
     pub repeat_term: crate::veryl_token::Token,
@@ -10637,7 +11036,7 @@ pub struct RepeatToken {
 /// Type derived for non-terminal Reset
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Reset {
     pub reset_token: crate::veryl_token::VerylToken,
@@ -10647,7 +11046,7 @@ pub struct Reset {
 /// Type derived for non-terminal ResetAsyncHigh
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetAsyncHigh {
     pub reset_async_high_token: crate::veryl_token::VerylToken,
@@ -10657,7 +11056,7 @@ pub struct ResetAsyncHigh {
 /// Type derived for non-terminal ResetAsyncHighTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetAsyncHighTerm {
     pub reset_async_high_term: crate::veryl_token::Token, /* (?-u:\b)reset_async_high(?-u:\b) */
@@ -10667,7 +11066,7 @@ pub struct ResetAsyncHighTerm {
 /// Type derived for non-terminal ResetAsyncHighToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetAsyncHighToken {
     pub reset_async_high_term: crate::veryl_token::Token,
@@ -10678,7 +11077,7 @@ pub struct ResetAsyncHighToken {
 /// Type derived for non-terminal ResetAsyncLow
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetAsyncLow {
     pub reset_async_low_token: crate::veryl_token::VerylToken,
@@ -10688,7 +11087,7 @@ pub struct ResetAsyncLow {
 /// Type derived for non-terminal ResetAsyncLowTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetAsyncLowTerm {
     pub reset_async_low_term: crate::veryl_token::Token, /* (?-u:\b)reset_async_low(?-u:\b) */
@@ -10698,7 +11097,7 @@ pub struct ResetAsyncLowTerm {
 /// Type derived for non-terminal ResetAsyncLowToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetAsyncLowToken {
     pub reset_async_low_term: crate::veryl_token::Token,
@@ -10709,7 +11108,7 @@ pub struct ResetAsyncLowToken {
 /// Type derived for non-terminal ResetSyncHigh
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetSyncHigh {
     pub reset_sync_high_token: crate::veryl_token::VerylToken,
@@ -10719,7 +11118,7 @@ pub struct ResetSyncHigh {
 /// Type derived for non-terminal ResetSyncHighTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetSyncHighTerm {
     pub reset_sync_high_term: crate::veryl_token::Token, /* (?-u:\b)reset_sync_high(?-u:\b) */
@@ -10729,7 +11128,7 @@ pub struct ResetSyncHighTerm {
 /// Type derived for non-terminal ResetSyncHighToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetSyncHighToken {
     pub reset_sync_high_term: crate::veryl_token::Token,
@@ -10740,7 +11139,7 @@ pub struct ResetSyncHighToken {
 /// Type derived for non-terminal ResetSyncLow
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetSyncLow {
     pub reset_sync_low_token: crate::veryl_token::VerylToken,
@@ -10750,7 +11149,7 @@ pub struct ResetSyncLow {
 /// Type derived for non-terminal ResetSyncLowTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetSyncLowTerm {
     pub reset_sync_low_term: crate::veryl_token::Token, /* (?-u:\b)reset_sync_low(?-u:\b) */
@@ -10760,7 +11159,7 @@ pub struct ResetSyncLowTerm {
 /// Type derived for non-terminal ResetSyncLowToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetSyncLowToken {
     pub reset_sync_low_term: crate::veryl_token::Token,
@@ -10771,7 +11170,7 @@ pub struct ResetSyncLowToken {
 /// Type derived for non-terminal ResetTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetTerm {
     pub reset_term: crate::veryl_token::Token, /* (?-u:\b)reset(?-u:\b) */
@@ -10781,7 +11180,7 @@ pub struct ResetTerm {
 /// Type derived for non-terminal ResetToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ResetToken {
     pub reset_term: crate::veryl_token::Token,
@@ -10792,7 +11191,7 @@ pub struct ResetToken {
 /// Type derived for non-terminal Return
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Return {
     pub return_token: crate::veryl_token::VerylToken,
@@ -10802,7 +11201,7 @@ pub struct Return {
 /// Type derived for non-terminal ReturnStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ReturnStatement {
     pub r#return: Box<Return>,
@@ -10814,7 +11213,7 @@ pub struct ReturnStatement {
 /// Type derived for non-terminal ReturnTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ReturnTerm {
     pub return_term: crate::veryl_token::Token, /* (?-u:\b)return(?-u:\b) */
@@ -10824,7 +11223,7 @@ pub struct ReturnTerm {
 /// Type derived for non-terminal ReturnToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ReturnToken {
     pub return_term: crate::veryl_token::Token,
@@ -10835,7 +11234,7 @@ pub struct ReturnToken {
 /// Type derived for non-terminal ScalarType
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScalarType {
     pub scalar_type_list: Vec<ScalarTypeList>,
@@ -10846,7 +11245,7 @@ pub struct ScalarType {
 /// Type derived for non-terminal ScalarTypeGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ScalarTypeGroup {
     UserDefinedTypeScalarTypeOpt(ScalarTypeGroupUserDefinedTypeScalarTypeOpt),
     FactorType(ScalarTypeGroupFactorType),
@@ -10856,7 +11255,7 @@ pub enum ScalarTypeGroup {
 /// Type derived for non-terminal ScalarTypeList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScalarTypeList {
     pub type_modifier: Box<TypeModifier>,
@@ -10866,7 +11265,7 @@ pub struct ScalarTypeList {
 /// Type derived for non-terminal ScalarTypeOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScalarTypeOpt {
     pub width: Box<Width>,
@@ -10876,7 +11275,7 @@ pub struct ScalarTypeOpt {
 /// Type derived for non-terminal ScopedIdentifier
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifier {
     pub scoped_identifier_group: Box<ScopedIdentifierGroup>,
@@ -10887,7 +11286,7 @@ pub struct ScopedIdentifier {
 /// Type derived for non-terminal ScopedIdentifierGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ScopedIdentifierGroup {
     DollarIdentifier(ScopedIdentifierGroupDollarIdentifier),
     IdentifierScopedIdentifierOpt(ScopedIdentifierGroupIdentifierScopedIdentifierOpt),
@@ -10897,7 +11296,7 @@ pub enum ScopedIdentifierGroup {
 /// Type derived for non-terminal ScopedIdentifierList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifierList {
     pub colon_colon: Box<ColonColon>,
@@ -10909,7 +11308,7 @@ pub struct ScopedIdentifierList {
 /// Type derived for non-terminal ScopedIdentifierOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifierOpt {
     pub with_generic_argument: Box<WithGenericArgument>,
@@ -10919,7 +11318,7 @@ pub struct ScopedIdentifierOpt {
 /// Type derived for non-terminal ScopedIdentifierOpt0
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct ScopedIdentifierOpt0 {
     pub with_generic_argument: Box<WithGenericArgument>,
@@ -10929,7 +11328,7 @@ pub struct ScopedIdentifierOpt0 {
 /// Type derived for non-terminal Select
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Select {
     pub l_bracket: Box<LBracket>,
@@ -10942,7 +11341,7 @@ pub struct Select {
 /// Type derived for non-terminal SelectOperator
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum SelectOperator {
     Colon(SelectOperatorColon),
     PlusColon(SelectOperatorPlusColon),
@@ -10954,7 +11353,7 @@ pub enum SelectOperator {
 /// Type derived for non-terminal SelectOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SelectOpt {
     pub select_operator: Box<SelectOperator>,
@@ -10965,7 +11364,7 @@ pub struct SelectOpt {
 /// Type derived for non-terminal Semicolon
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Semicolon {
     pub semicolon_token: crate::veryl_token::VerylToken,
@@ -10975,7 +11374,7 @@ pub struct Semicolon {
 /// Type derived for non-terminal SemicolonTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SemicolonTerm {
     pub semicolon_term: crate::veryl_token::Token, /* ; */
@@ -10985,7 +11384,7 @@ pub struct SemicolonTerm {
 /// Type derived for non-terminal SemicolonToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SemicolonToken {
     pub semicolon_term: crate::veryl_token::Token,
@@ -10996,7 +11395,7 @@ pub struct SemicolonToken {
 /// Type derived for non-terminal Signed
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Signed {
     pub signed_token: crate::veryl_token::VerylToken,
@@ -11006,7 +11405,7 @@ pub struct Signed {
 /// Type derived for non-terminal SignedTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SignedTerm {
     pub signed_term: crate::veryl_token::Token, /* (?-u:\b)signed(?-u:\b) */
@@ -11016,7 +11415,7 @@ pub struct SignedTerm {
 /// Type derived for non-terminal SignedToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SignedToken {
     pub signed_term: crate::veryl_token::Token,
@@ -11027,7 +11426,7 @@ pub struct SignedToken {
 /// Type derived for non-terminal Star
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Star {
     pub star_token: crate::veryl_token::VerylToken,
@@ -11037,7 +11436,7 @@ pub struct Star {
 /// Type derived for non-terminal StarTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StarTerm {
     pub star_term: crate::veryl_token::Token, /* * */
@@ -11047,7 +11446,7 @@ pub struct StarTerm {
 /// Type derived for non-terminal StarToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StarToken {
     pub star_term: crate::veryl_token::Token,
@@ -11058,7 +11457,7 @@ pub struct StarToken {
 /// Type derived for non-terminal Start
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Start {
     pub start_token: crate::veryl_token::VerylToken,
@@ -11068,7 +11467,7 @@ pub struct Start {
 /// Type derived for non-terminal StartToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StartToken {
     pub comments: Box<Comments>,
@@ -11078,7 +11477,7 @@ pub struct StartToken {
 /// Type derived for non-terminal Statement
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Statement {
     IdentifierStatement(StatementIdentifierStatement),
     IfStatement(StatementIfStatement),
@@ -11088,13 +11487,14 @@ pub enum Statement {
     ForStatement(StatementForStatement),
     CaseStatement(StatementCaseStatement),
     SwitchStatement(StatementSwitchStatement),
+    AssertionStatement(StatementAssertionStatement),
 }
 
 ///
 /// Type derived for non-terminal StatementBlock
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlock {
     pub l_brace: Box<LBrace>,
@@ -11106,7 +11506,7 @@ pub struct StatementBlock {
 /// Type derived for non-terminal StatementBlockGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockGroup {
     pub statement_block_group_list: Vec<StatementBlockGroupList>,
@@ -11117,7 +11517,7 @@ pub struct StatementBlockGroup {
 /// Type derived for non-terminal StatementBlockGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum StatementBlockGroupGroup {
     LBraceStatementBlockGroupGroupListRBrace(
         StatementBlockGroupGroupLBraceStatementBlockGroupGroupListRBrace,
@@ -11129,7 +11529,7 @@ pub enum StatementBlockGroupGroup {
 /// Type derived for non-terminal StatementBlockGroupGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockGroupGroupList {
     pub statement_block_group: Box<StatementBlockGroup>,
@@ -11139,7 +11539,7 @@ pub struct StatementBlockGroupGroupList {
 /// Type derived for non-terminal StatementBlockGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockGroupList {
     pub attribute: Box<Attribute>,
@@ -11149,7 +11549,7 @@ pub struct StatementBlockGroupList {
 /// Type derived for non-terminal StatementBlockItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum StatementBlockItem {
     VarDeclaration(StatementBlockItemVarDeclaration),
     LetStatement(StatementBlockItemLetStatement),
@@ -11160,7 +11560,7 @@ pub enum StatementBlockItem {
 /// Type derived for non-terminal StatementBlockList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StatementBlockList {
     pub statement_block_group: Box<StatementBlockGroup>,
@@ -11170,7 +11570,7 @@ pub struct StatementBlockList {
 /// Type derived for non-terminal Step
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Step {
     pub step_token: crate::veryl_token::VerylToken,
@@ -11180,7 +11580,7 @@ pub struct Step {
 /// Type derived for non-terminal StepTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StepTerm {
     pub step_term: crate::veryl_token::Token, /* (?-u:\b)step(?-u:\b) */
@@ -11190,7 +11590,7 @@ pub struct StepTerm {
 /// Type derived for non-terminal StepToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StepToken {
     pub step_term: crate::veryl_token::Token,
@@ -11201,7 +11601,7 @@ pub struct StepToken {
 /// Type derived for non-terminal Strin
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Strin {
     pub string_token: crate::veryl_token::VerylToken,
@@ -11211,7 +11611,7 @@ pub struct Strin {
 /// Type derived for non-terminal StringLiteral
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StringLiteral {
     pub string_literal_token: crate::veryl_token::VerylToken,
@@ -11221,7 +11621,7 @@ pub struct StringLiteral {
 /// Type derived for non-terminal StringLiteralTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StringLiteralTerm {
     pub string_literal_term: crate::veryl_token::Token, /* \u{0022}(?:\\[\u{0022}\\/bfnrt]|u[0-9a-fA-F]{4}|[^\u{0022}\\\u0000-\u001F])*\u{0022} */
@@ -11231,7 +11631,7 @@ pub struct StringLiteralTerm {
 /// Type derived for non-terminal StringLiteralToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StringLiteralToken {
     pub string_literal_term: crate::veryl_token::Token,
@@ -11242,7 +11642,7 @@ pub struct StringLiteralToken {
 /// Type derived for non-terminal StringTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StringTerm {
     pub string_term: crate::veryl_token::Token, /* (?-u:\b)string(?-u:\b) */
@@ -11252,7 +11652,7 @@ pub struct StringTerm {
 /// Type derived for non-terminal StringToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StringToken {
     pub string_term: crate::veryl_token::Token,
@@ -11263,17 +11663,89 @@ pub struct StringToken {
 /// Type derived for non-terminal Struct
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Struct {
     pub struct_token: crate::veryl_token::VerylToken,
 }
 
+///
+/// Type derived for non-terminal StructConstructor
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructConstructor {
+    pub quote_l_brace: Box<QuoteLBrace>,
+    pub struct_constructor_list: Box<StructConstructorList>,
+    pub struct_constructor_opt: Option<StructConstructorOpt>,
+    pub r_brace: Box<RBrace>,
+}
+
+///
+/// Type derived for non-terminal StructConstructorItem
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructConstructorItem {
+    pub identifier: Box<Identifier>,
+    pub colon: Box<Colon>,
+    pub expression: Box<Expression>,
+}
+
+///
+/// Type derived for non-terminal StructConstructorList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructConstructorList {
+    pub struct_constructor_item: Box<StructConstructorItem>,
+    pub struct_constructor_list_list: Vec<StructConstructorListList>,
+    pub struct_constructor_list_opt: Option<StructConstructorListOpt>,
+}
+
+///
+/// Type derived for non-terminal StructConstructorListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructConstructorListList {
+    pub comma: Box<Comma>,
+    pub struct_constructor_item: Box<StructConstructorItem>,
+}
+
+///
+/// Type derived for non-terminal StructConstructorListOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructConstructorListOpt {
+    pub comma: Box<Comma>,
+}
+
+///
+/// Type derived for non-terminal StructConstructorOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StructConstructorOpt {
+    pub dot_dot: Box<DotDot>,
+    pub defaul: Box<Defaul>,
+    pub l_paren: Box<LParen>,
+    pub expression: Box<Expression>,
+    pub r_paren: Box<RParen>,
+}
+
 ///
 /// Type derived for non-terminal StructTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructTerm {
     pub struct_term: crate::veryl_token::Token, /* (?-u:\b)struct(?-u:\b) */
@@ -11283,7 +11755,7 @@ pub struct StructTerm {
 /// Type derived for non-terminal StructToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructToken {
     pub struct_term: crate::veryl_token::Token,
@@ -11294,7 +11766,7 @@ pub struct StructToken {
 /// Type derived for non-terminal StructUnion
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum StructUnion {
     Struct(StructUnionStruct),
     Union(StructUnionUnion),
@@ -11304,7 +11776,7 @@ pub enum StructUnion {
 /// Type derived for non-terminal StructUnionDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionDeclaration {
     pub struct_union: Box<StructUnion>,
@@ -11319,7 +11791,7 @@ pub struct StructUnionDeclaration {
 /// Type derived for non-terminal StructUnionDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionDeclarationOpt {
     pub with_generic_parameter: Box<WithGenericParameter>,
@@ -11329,7 +11801,7 @@ pub struct StructUnionDeclarationOpt {
 /// Type derived for non-terminal StructUnionGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionGroup {
     pub struct_union_group_list: Vec<StructUnionGroupList>,
@@ -11340,7 +11812,7 @@ pub struct StructUnionGroup {
 /// Type derived for non-terminal StructUnionGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum StructUnionGroupGroup {
     LBraceStructUnionListRBrace(StructUnionGroupGroupLBraceStructUnionListRBrace),
     StructUnionItem(StructUnionGroupGroupStructUnionItem),
@@ -11350,7 +11822,7 @@ pub enum StructUnionGroupGroup {
 /// Type derived for non-terminal StructUnionGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionGroupList {
     pub attribute: Box<Attribute>,
@@ -11360,7 +11832,7 @@ pub struct StructUnionGroupList {
 /// Type derived for non-terminal StructUnionItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionItem {
     pub identifier: Box<Identifier>,
@@ -11372,7 +11844,7 @@ pub struct StructUnionItem {
 /// Type derived for non-terminal StructUnionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionList {
     pub struct_union_group: Box<StructUnionGroup>,
@@ -11384,7 +11856,7 @@ pub struct StructUnionList {
 /// Type derived for non-terminal StructUnionListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionListList {
     pub comma: Box<Comma>,
@@ -11395,7 +11867,7 @@ pub struct StructUnionListList {
 /// Type derived for non-terminal StructUnionListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct StructUnionListOpt {
     pub comma: Box<Comma>,
@@ -11405,7 +11877,7 @@ pub struct StructUnionListOpt {
 /// Type derived for non-terminal Switch
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Switch {
     pub switch_token: crate::veryl_token::VerylToken,
@@ -11415,7 +11887,7 @@ pub struct Switch {
 /// Type derived for non-terminal SwitchCondition
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchCondition {
     pub expression: Box<Expression>,
@@ -11426,7 +11898,7 @@ pub struct SwitchCondition {
 /// Type derived for non-terminal SwitchConditionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchConditionList {
     pub comma: Box<Comma>,
@@ -11437,7 +11909,7 @@ pub struct SwitchConditionList {
 /// Type derived for non-terminal SwitchExpression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchExpression {
     pub switch: Box<Switch>,
@@ -11458,7 +11930,7 @@ pub struct SwitchExpression {
 /// Type derived for non-terminal SwitchExpressionList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchExpressionList {
     pub switch_condition: Box<SwitchCondition>,
@@ -11471,7 +11943,7 @@ pub struct SwitchExpressionList {
 /// Type derived for non-terminal SwitchExpressionOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchExpressionOpt {
     pub comma: Box<Comma>,
@@ -11481,7 +11953,7 @@ pub struct SwitchExpressionOpt {
 /// Type derived for non-terminal SwitchItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchItem {
     pub switch_item_group: Box<SwitchItemGroup>,
@@ -11493,7 +11965,7 @@ pub struct SwitchItem {
 /// Type derived for non-terminal SwitchItemGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum SwitchItemGroup {
     SwitchCondition(SwitchItemGroupSwitchCondition),
     Defaul(SwitchItemGroupDefaul),
@@ -11503,7 +11975,7 @@ pub enum SwitchItemGroup {
 /// Type derived for non-terminal SwitchItemGroup0
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum SwitchItemGroup0 {
     Statement(SwitchItemGroup0Statement),
     StatementBlock(SwitchItemGroup0StatementBlock),
@@ -11513,7 +11985,7 @@ pub enum SwitchItemGroup0 {
 /// Type derived for non-terminal SwitchStatement
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchStatement {
     pub switch: Box<Switch>,
@@ -11526,7 +11998,7 @@ pub struct SwitchStatement {
 /// Type derived for non-terminal SwitchStatementList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchStatementList {
     pub switch_item: Box<SwitchItem>,
@@ -11536,7 +12008,7 @@ pub struct SwitchStatementList {
 /// Type derived for non-terminal SwitchTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchTerm {
     pub switch_term: crate::veryl_token::Token, /* (?-u:\b)switch(?-u:\b) */
@@ -11546,7 +12018,7 @@ pub struct SwitchTerm {
 /// Type derived for non-terminal SwitchToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct SwitchToken {
     pub switch_term: crate::veryl_token::Token,
@@ -11557,7 +12029,7 @@ pub struct SwitchToken {
 /// Type derived for non-terminal Tri
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Tri {
     pub tri_token: crate::veryl_token::VerylToken,
@@ -11567,7 +12039,7 @@ pub struct Tri {
 /// Type derived for non-terminal TriTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TriTerm {
     pub tri_term: crate::veryl_token::Token, /* (?-u:\b)tri(?-u:\b) */
@@ -11577,7 +12049,7 @@ pub struct TriTerm {
 /// Type derived for non-terminal TriToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TriToken {
     pub tri_term: crate::veryl_token::Token,
@@ -11588,7 +12060,7 @@ pub struct TriToken {
 /// Type derived for non-terminal Type
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Type {
     pub type_token: crate::veryl_token::VerylToken,
@@ -11598,7 +12070,7 @@ pub struct Type {
 /// Type derived for non-terminal TypeDefDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeDefDeclaration {
     pub r#type: Box<Type>,
@@ -11612,7 +12084,7 @@ pub struct TypeDefDeclaration {
 /// Type derived for non-terminal TypeExpression
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeExpression {
     pub r#type: Box<Type>,
@@ -11625,7 +12097,7 @@ pub struct TypeExpression {
 /// Type derived for non-terminal TypeModifier
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum TypeModifier {
     Tri(TypeModifierTri),
     Signed(TypeModifierSigned),
@@ -11635,7 +12107,7 @@ pub enum TypeModifier {
 /// Type derived for non-terminal TypeTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeTerm {
     pub type_term: crate::veryl_token::Token, /* (?-u:\b)type(?-u:\b) */
@@ -11645,7 +12117,7 @@ pub struct TypeTerm {
 /// Type derived for non-terminal TypeToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct TypeToken {
     pub type_term: crate::veryl_token::Token,
@@ -11656,7 +12128,7 @@ pub struct TypeToken {
 /// Type derived for non-terminal U32
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct U32 {
     pub u32_token: crate::veryl_token::VerylToken,
@@ -11666,7 +12138,7 @@ pub struct U32 {
 /// Type derived for non-terminal U32Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct U32Term {
     pub u32_term: crate::veryl_token::Token, /* (?-u:\b)u32(?-u:\b) */
@@ -11676,7 +12148,7 @@ pub struct U32Term {
 /// Type derived for non-terminal U32Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct U32Token {
     pub u32_term: crate::veryl_token::Token,
@@ -11687,7 +12159,7 @@ pub struct U32Token {
 /// Type derived for non-terminal U64
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct U64 {
     pub u64_token: crate::veryl_token::VerylToken,
@@ -11697,7 +12169,7 @@ pub struct U64 {
 /// Type derived for non-terminal U64Term
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct U64Term {
     pub u64_term: crate::veryl_token::Token, /* (?-u:\b)u64(?-u:\b) */
@@ -11707,7 +12179,7 @@ pub struct U64Term {
 /// Type derived for non-terminal U64Token
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct U64Token {
     pub u64_term: crate::veryl_token::Token,
@@ -11718,7 +12190,7 @@ pub struct U64Token {
 /// Type derived for non-terminal UnaryOperator
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnaryOperator {
     pub unary_operator_token: crate::veryl_token::VerylToken,
@@ -11728,7 +12200,7 @@ pub struct UnaryOperator {
 /// Type derived for non-terminal UnaryOperatorTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnaryOperatorTerm {
     pub unary_operator_term: crate::veryl_token::Token, /* ~&|~\||!|~ */
@@ -11738,7 +12210,7 @@ pub struct UnaryOperatorTerm {
 /// Type derived for non-terminal UnaryOperatorToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnaryOperatorToken {
     pub unary_operator_term: crate::veryl_token::Token,
@@ -11749,7 +12221,7 @@ pub struct UnaryOperatorToken {
 /// Type derived for non-terminal Union
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Union {
     pub union_token: crate::veryl_token::VerylToken,
@@ -11759,7 +12231,7 @@ pub struct Union {
 /// Type derived for non-terminal UnionTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnionTerm {
     pub union_term: crate::veryl_token::Token, /* (?-u:\b)union(?-u:\b) */
@@ -11769,7 +12241,7 @@ pub struct UnionTerm {
 /// Type derived for non-terminal UnionToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnionToken {
     pub union_term: crate::veryl_token::Token,
@@ -11780,7 +12252,7 @@ pub struct UnionToken {
 /// Type derived for non-terminal Unsafe
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Unsafe {
     pub unsafe_token: crate::veryl_token::VerylToken,
@@ -11790,7 +12262,7 @@ pub struct Unsafe {
 /// Type derived for non-terminal UnsafeBlock
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnsafeBlock {
     pub r#unsafe: Box<Unsafe>,
@@ -11806,7 +12278,7 @@ pub struct UnsafeBlock {
 /// Type derived for non-terminal UnsafeBlockList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnsafeBlockList {
     pub generate_group: Box<GenerateGroup>,
@@ -11816,7 +12288,7 @@ pub struct UnsafeBlockList {
 /// Type derived for non-terminal UnsafeTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnsafeTerm {
     pub unsafe_term: crate::veryl_token::Token, /* (?-u:\b)unsafe(?-u:\b) */
@@ -11826,7 +12298,7 @@ pub struct UnsafeTerm {
 /// Type derived for non-terminal UnsafeToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UnsafeToken {
     pub unsafe_term: crate::veryl_token::Token,
@@ -11837,7 +12309,7 @@ pub struct UnsafeToken {
 /// Type derived for non-terminal UserDefinedType
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct UserDefinedType {
     pub scoped_identifier: Box<ScopedIdentifier>,
@@ -11847,7 +12319,7 @@ pub struct UserDefinedType {
 /// Type derived for non-terminal Var
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Var {
     pub var_token: crate::veryl_token::VerylToken,
@@ -11857,7 +12329,7 @@ pub struct Var {
 /// Type derived for non-terminal VarDeclaration
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VarDeclaration {
     pub var: Box<Var>,
@@ -11872,7 +12344,7 @@ pub struct VarDeclaration {
 /// Type derived for non-terminal VarDeclarationOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VarDeclarationOpt {
     pub clock_domain: Box<ClockDomain>,
@@ -11882,7 +12354,7 @@ pub struct VarDeclarationOpt {
 /// Type derived for non-terminal VarTerm
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VarTerm {
     pub var_term: crate::veryl_token::Token, /* (?-u:\b)var(?-u:\b) */
@@ -11892,7 +12364,7 @@ pub struct VarTerm {
 /// Type derived for non-terminal VarToken
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VarToken {
     pub var_term: crate::veryl_token::Token,
@@ -11903,7 +12375,7 @@ pub struct VarToken {
 /// Type derived for non-terminal VariableType
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum VariableType {
     Clock(VariableTypeClock),
     ClockPosedge(VariableTypeClockPosedge),
@@ -11921,7 +12393,7 @@ pub enum VariableType {
 /// Type derived for non-terminal Veryl
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Veryl {
     pub start: Box<Start>,
@@ -11932,7 +12404,7 @@ pub struct Veryl {
 /// Type derived for non-terminal VerylList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct VerylList {
     pub description_group: Box<DescriptionGroup>,
@@ -11942,7 +12414,7 @@ pub struct VerylList {
 /// Type derived for non-terminal Width
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct Width {
     pub l_angle: Box<LAngle>,
@@ -11955,7 +12427,7 @@ pub struct Width {
 /// Type derived for non-terminal WidthList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WidthList {
     pub comma: Box<Comma>,
@@ -11966,7 +12438,7 @@ pub struct WidthList {
 /// Type derived for non-terminal WithGenericArgument
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgument {
     pub colon_colon_l_angle: Box<ColonColonLAngle>,
@@ -11978,7 +12450,7 @@ pub struct WithGenericArgument {
 /// Type derived for non-terminal WithGenericArgumentItem
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum WithGenericArgumentItem {
     ScopedIdentifier(WithGenericArgumentItemScopedIdentifier),
     Number(WithGenericArgumentItemNumber),
@@ -11988,7 +12460,7 @@ pub enum WithGenericArgumentItem {
 /// Type derived for non-terminal WithGenericArgumentList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgumentList {
     pub with_generic_argument_item: Box<WithGenericArgumentItem>,
@@ -12000,7 +12472,7 @@ pub struct WithGenericArgumentList {
 /// Type derived for non-terminal WithGenericArgumentListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgumentListList {
     pub comma: Box<Comma>,
@@ -12011,7 +12483,7 @@ pub struct WithGenericArgumentListList {
 /// Type derived for non-terminal WithGenericArgumentListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgumentListOpt {
     pub comma: Box<Comma>,
@@ -12021,7 +12493,7 @@ pub struct WithGenericArgumentListOpt {
 /// Type derived for non-terminal WithGenericArgumentOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericArgumentOpt {
     pub with_generic_argument_list: Box<WithGenericArgumentList>,
@@ -12031,7 +12503,7 @@ pub struct WithGenericArgumentOpt {
 /// Type derived for non-terminal WithGenericParameter
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericParameter {
     pub colon_colon_l_angle: Box<ColonColonLAngle>,
@@ -12043,7 +12515,7 @@ pub struct WithGenericParameter {
 /// Type derived for non-terminal WithGenericParameterItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericParameterItem {
     pub identifier: Box<Identifier>,
@@ -12056,7 +12528,7 @@ pub struct WithGenericParameterItem {
 /// Type derived for non-terminal WithGenericParameterItemOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericParameterItemOpt {
     pub equ: Box<Equ>,
@@ -12067,7 +12539,7 @@ pub struct WithGenericParameterItemOpt {
 /// Type derived for non-terminal WithGenericParameterList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericParameterList {
     pub with_generic_parameter_item: Box<WithGenericParameterItem>,
@@ -12079,7 +12551,7 @@ pub struct WithGenericParameterList {
 /// Type derived for non-terminal WithGenericParameterListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericParameterListList {
     pub comma: Box<Comma>,
@@ -12090,7 +12562,7 @@ pub struct WithGenericParameterListList {
 /// Type derived for non-terminal WithGenericParameterListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithGenericParameterListOpt {
     pub comma: Box<Comma>,
@@ -12100,7 +12572,7 @@ pub struct WithGenericParameterListOpt {
 /// Type derived for non-terminal WithParameter
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameter {
     pub hash: Box<Hash>,
@@ -12113,7 +12585,7 @@ pub struct WithParameter {
 /// Type derived for non-terminal WithParameterGroup
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterGroup {
     pub with_parameter_group_list: Vec<WithParameterGroupList>,
@@ -12124,7 +12596,7 @@ pub struct WithParameterGroup {
 /// Type derived for non-terminal WithParameterGroupGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum WithParameterGroupGroup {
     LBraceWithParameterListRBrace(WithParameterGroupGroupLBraceWithParameterListRBrace),
     WithParameterItem(WithParameterGroupGroupWithParameterItem),
@@ -12134,7 +12606,7 @@ pub enum WithParameterGroupGroup {
 /// Type derived for non-terminal WithParameterGroupList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterGroupList {
     pub attribute: Box<Attribute>,
@@ -12144,7 +12616,7 @@ pub struct WithParameterGroupList {
 /// Type derived for non-terminal WithParameterItem
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterItem {
     pub with_parameter_item_group: Box<WithParameterItemGroup>,
@@ -12159,7 +12631,7 @@ pub struct WithParameterItem {
 /// Type derived for non-terminal WithParameterItemGroup
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum WithParameterItemGroup {
     Param(WithParameterItemGroupParam),
     Const(WithParameterItemGroupConst),
@@ -12169,7 +12641,7 @@ pub enum WithParameterItemGroup {
 /// Type derived for non-terminal WithParameterItemGroup0
 ///
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum WithParameterItemGroup0 {
     ArrayType(WithParameterItemGroup0ArrayType),
     Type(WithParameterItemGroup0Type),
@@ -12179,7 +12651,7 @@ pub enum WithParameterItemGroup0 {
 /// Type derived for non-terminal WithParameterList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterList {
     pub with_parameter_group: Box<WithParameterGroup>,
@@ -12191,7 +12663,7 @@ pub struct WithParameterList {
 /// Type derived for non-terminal WithParameterListList
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterListList {
     pub comma: Box<Comma>,
@@ -12202,7 +12674,7 @@ pub struct WithParameterListList {
 /// Type derived for non-terminal WithParameterListOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterListOpt {
     pub comma: Box<Comma>,
@@ -12212,7 +12684,7 @@ pub struct WithParameterListOpt {
 /// Type derived for non-terminal WithParameterOpt
 ///
 #[allow(dead_code)]
-#[derive(Builder, Debug, Clone)]
+#[derive(Builder, Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[builder(crate = "parol_runtime::derive_builder")]
 pub struct WithParameterOpt {
     pub with_parameter_list: Box<WithParameterList>,
@@ -12260,6 +12732,14 @@ pub enum ASTType {
     As(As),
     AsTerm(AsTerm),
     AsToken(AsToken),
+    Assert(Assert),
+    AssertTerm(AssertTerm),
+    AssertToken(AssertToken),
+    AssertionDeclaration(AssertionDeclaration),
+    AssertionDeclarationOpt(Option<AssertionDeclarationOpt>),
+    AssertionKind(AssertionKind),
+    AssertionStatement(AssertionStatement),
+    AssertionStatementOpt(Option<AssertionStatementOpt>),
     Assign(Assign),
     AssignDeclaration(AssignDeclaration),
     AssignTerm(AssignTerm),
@@ -12269,6 +12749,9 @@ pub enum ASTType {
     AssignmentOperator(AssignmentOperator),
     AssignmentOperatorTerm(AssignmentOperatorTerm),
     AssignmentOperatorToken(AssignmentOperatorToken),
+    Assume(Assume),
+    AssumeTerm(AssumeTerm),
+    AssumeToken(AssumeToken),
     Attribute(Attribute),
     AttributeItem(AttributeItem),
     AttributeList(AttributeList),
@@ -12340,6 +12823,9 @@ pub enum ASTType {
     ConstDeclarationGroup(ConstDeclarationGroup),
     ConstTerm(ConstTerm),
     ConstToken(ConstToken),
+    Cover(Cover),
+    CoverTerm(CoverTerm),
+    CoverToken(CoverToken),
     Defaul(Defaul),
     DefaultTerm(DefaultTerm),
     DefaultToken(DefaultToken),
@@ -12497,6 +12983,7 @@ pub enum ASTType {
     Identifier(Identifier),
     IdentifierFactor(IdentifierFactor),
     IdentifierFactorOpt(Option<IdentifierFactorOpt>),
+    IdentifierFactorOptGroup(IdentifierFactorOptGroup),
     IdentifierStatement(IdentifierStatement),
     IdentifierStatementGroup(IdentifierStatementGroup),
     IdentifierTerm(IdentifierTerm),
@@ -12718,6 +13205,9 @@ pub enum ASTType {
     PortTypeConcrete(PortTypeConcrete),
     PortTypeConcreteOpt(Option<PortTypeConcreteOpt>),
     PortTypeConcreteOpt0(Option<PortTypeConcreteOpt0>),
+    Property(Property),
+    PropertyTerm(PropertyTerm),
+    PropertyToken(PropertyToken),
     Proto(Proto),
     ProtoModuleDeclaration(ProtoModuleDeclaration),
     ProtoModuleDeclarationOpt(Option<ProtoModuleDeclarationOpt>),
@@ -12817,6 +13307,12 @@ pub enum ASTType {
     StringTerm(StringTerm),
     StringToken(StringToken),
     Struct(Struct),
+    StructConstructor(StructConstructor),
+    StructConstructorItem(StructConstructorItem),
+    StructConstructorList(StructConstructorList),
+    StructConstructorListList(Vec<StructConstructorListList>),
+    StructConstructorListOpt(Option<StructConstructorListOpt>),
+    StructConstructorOpt(Option<StructConstructorOpt>),
     StructTerm(StructTerm),
     StructToken(StructToken),
     StructUnion(StructUnion),
@@ -13897,6 +14393,44 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 47:
     ///
+    /// `AssertTerm: <INITIAL, Generic>/(?-u:\b)assert(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert_term(&mut self, assert_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert_term = assert_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let assert_term_built = AssertTerm { assert_term };
+        // Calling user action here
+        self.user_grammar.assert_term(&assert_term_built)?;
+        self.push(ASTType::AssertTerm(assert_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 48:
+    ///
+    /// `AssumeTerm: <INITIAL, Generic>/(?-u:\b)assume(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume_term(&mut self, assume_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume_term = assume_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let assume_term_built = AssumeTerm { assume_term };
+        // Calling user action here
+        self.user_grammar.assume_term(&assume_term_built)?;
+        self.push(ASTType::AssumeTerm(assume_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 49:
+    ///
     /// `AsTerm: <INITIAL, Generic>/(?-u:\b)as(?-u:\b)/ : Token;`
     ///
     #[parol_runtime::function_name::named]
@@ -13914,7 +14448,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 50:
     ///
     /// `BitTerm: <INITIAL, Generic>/(?-u:\b)bit(?-u:\b)/ : Token;`
     ///
@@ -13933,7 +14467,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 51:
     ///
     /// `CaseTerm: <INITIAL, Generic>/(?-u:\b)case(?-u:\b)/ : Token;`
     ///
@@ -13952,7 +14486,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 52:
     ///
     /// `ClockTerm: <INITIAL, Generic>/(?-u:\b)clock(?-u:\b)/ : Token;`
     ///
@@ -13971,7 +14505,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 53:
     ///
     /// `ClockPosedgeTerm: <INITIAL, Generic>/(?-u:\b)clock_posedge(?-u:\b)/ : Token;`
     ///
@@ -13991,7 +14525,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 54:
     ///
     /// `ClockNegedgeTerm: <INITIAL, Generic>/(?-u:\b)clock_negedge(?-u:\b)/ : Token;`
     ///
@@ -14011,7 +14545,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 55:
+    ///
+    /// `CoverTerm: <INITIAL, Generic>/(?-u:\b)cover(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover_term(&mut self, cover_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover_term = cover_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let cover_term_built = CoverTerm { cover_term };
+        // Calling user action here
+        self.user_grammar.cover_term(&cover_term_built)?;
+        self.push(ASTType::CoverTerm(cover_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 56:
     ///
     /// `ConstTerm: <INITIAL, Generic>/(?-u:\b)const(?-u:\b)/ : Token;`
     ///
@@ -14030,7 +14583,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 57:
     ///
     /// `DefaultTerm: <INITIAL, Generic>/(?-u:\b)default(?-u:\b)/ : Token;`
     ///
@@ -14049,7 +14602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 58:
     ///
     /// `ElseTerm: <INITIAL, Generic>/(?-u:\b)else(?-u:\b)/ : Token;`
     ///
@@ -14068,7 +14621,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 59:
     ///
     /// `EmbedTerm: <INITIAL, Generic>/(?-u:\b)embed(?-u:\b)/ : Token;`
     ///
@@ -14087,7 +14640,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 60:
     ///
     /// `EnumTerm: <INITIAL, Generic>/(?-u:\b)enum(?-u:\b)/ : Token;`
     ///
@@ -14106,7 +14659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 61:
     ///
     /// `ExportTerm: <INITIAL, Generic>/(?-u:\b)export(?-u:\b)/ : Token;`
     ///
@@ -14125,7 +14678,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 62:
     ///
     /// `F32Term: <INITIAL, Generic>/(?-u:\b)f32(?-u:\b)/ : Token;`
     ///
@@ -14144,7 +14697,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 63:
     ///
     /// `F64Term: <INITIAL, Generic>/(?-u:\b)f64(?-u:\b)/ : Token;`
     ///
@@ -14163,7 +14716,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 64:
     ///
     /// `FinalTerm: <INITIAL, Generic>/(?-u:\b)final(?-u:\b)/ : Token;`
     ///
@@ -14182,7 +14735,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 65:
     ///
     /// `ForTerm: <INITIAL, Generic>/(?-u:\b)for(?-u:\b)/ : Token;`
     ///
@@ -14201,7 +14754,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 66:
     ///
     /// `FunctionTerm: <INITIAL, Generic>/(?-u:\b)function(?-u:\b)/ : Token;`
     ///
@@ -14220,7 +14773,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 67:
     ///
     /// `I32Term: <INITIAL, Generic>/(?-u:\b)i32(?-u:\b)/ : Token;`
     ///
@@ -14239,7 +14792,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 68:
     ///
     /// `I64Term: <INITIAL, Generic>/(?-u:\b)i64(?-u:\b)/ : Token;`
     ///
@@ -14258,7 +14811,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 69:
     ///
     /// `IfResetTerm: <INITIAL, Generic>/(?-u:\b)if_reset(?-u:\b)/ : Token;`
     ///
@@ -14277,7 +14830,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 70:
     ///
     /// `IfTerm: <INITIAL, Generic>/(?-u:\b)if(?-u:\b)/ : Token;`
     ///
@@ -14296,7 +14849,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 71:
     ///
     /// `ImportTerm: <INITIAL, Generic>/(?-u:\b)import(?-u:\b)/ : Token;`
     ///
@@ -14315,7 +14868,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 72:
     ///
     /// `IncludeTerm: <INITIAL, Generic>/(?-u:\b)include(?-u:\b)/ : Token;`
     ///
@@ -14334,7 +14887,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 73:
     ///
     /// `InitialTerm: <INITIAL, Generic>/(?-u:\b)initial(?-u:\b)/ : Token;`
     ///
@@ -14353,7 +14906,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 74:
     ///
     /// `InoutTerm: <INITIAL, Generic>/(?-u:\b)inout(?-u:\b)/ : Token;`
     ///
@@ -14372,7 +14925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 75:
     ///
     /// `InputTerm: <INITIAL, Generic>/(?-u:\b)input(?-u:\b)/ : Token;`
     ///
@@ -14391,7 +14944,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 76:
     ///
     /// `InsideTerm: <INITIAL, Generic>/(?-u:\b)inside(?-u:\b)/ : Token;`
     ///
@@ -14410,7 +14963,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 77:
     ///
     /// `InstTerm: <INITIAL, Generic>/(?-u:\b)inst(?-u:\b)/ : Token;`
     ///
@@ -14429,7 +14982,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 78:
     ///
     /// `InterfaceTerm: <INITIAL, Generic>/(?-u:\b)interface(?-u:\b)/ : Token;`
     ///
@@ -14448,7 +15001,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 79:
     ///
     /// `InTerm: <INITIAL, Generic>/(?-u:\b)in(?-u:\b)/ : Token;`
     ///
@@ -14467,7 +15020,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 80:
     ///
     /// `LetTerm: <INITIAL, Generic>/(?-u:\b)let(?-u:\b)/ : Token;`
     ///
@@ -14486,7 +15039,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 81:
     ///
     /// `LogicTerm: <INITIAL, Generic>/(?-u:\b)logic(?-u:\b)/ : Token;`
     ///
@@ -14505,7 +15058,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 82:
     ///
     /// `LsbTerm: <INITIAL, Generic>/(?-u:\b)lsb(?-u:\b)/ : Token;`
     ///
@@ -14524,7 +15077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 83:
     ///
     /// `ModportTerm: <INITIAL, Generic>/(?-u:\b)modport(?-u:\b)/ : Token;`
     ///
@@ -14543,7 +15096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 84:
     ///
     /// `ModuleTerm: <INITIAL, Generic>/(?-u:\b)module(?-u:\b)/ : Token;`
     ///
@@ -14562,7 +15115,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 85:
     ///
     /// `MsbTerm: <INITIAL, Generic>/(?-u:\b)msb(?-u:\b)/ : Token;`
     ///
@@ -14581,7 +15134,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 86:
     ///
     /// `OutputTerm: <INITIAL, Generic>/(?-u:\b)output(?-u:\b)/ : Token;`
     ///
@@ -14600,7 +15153,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 87:
     ///
     /// `OutsideTerm: <INITIAL, Generic>/(?-u:\b)outside(?-u:\b)/ : Token;`
     ///
@@ -14619,7 +15172,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 88:
     ///
     /// `PackageTerm: <INITIAL, Generic>/(?-u:\b)package(?-u:\b)/ : Token;`
     ///
@@ -14638,7 +15191,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 89:
     ///
     /// `ParamTerm: <INITIAL, Generic>/(?-u:\b)param(?-u:\b)/ : Token;`
     ///
@@ -14657,7 +15210,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 87:
+    /// Semantic action for production 90:
+    ///
+    /// `PropertyTerm: <INITIAL, Generic>/(?-u:\b)property(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_term(&mut self, property_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let property_term = property_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let property_term_built = PropertyTerm { property_term };
+        // Calling user action here
+        self.user_grammar.property_term(&property_term_built)?;
+        self.push(ASTType::PropertyTerm(property_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 91:
     ///
     /// `ProtoTerm: <INITIAL, Generic>/(?-u:\b)proto(?-u:\b)/ : Token;`
     ///
@@ -14676,7 +15248,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 88:
+    /// Semantic action for production 92:
     ///
     /// `PubTerm: <INITIAL, Generic>/(?-u:\b)pub(?-u:\b)/ : Token;`
     ///
@@ -14695,7 +15267,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 89:
+    /// Semantic action for production 93:
     ///
     /// `RefTerm: <INITIAL, Generic>/(?-u:\b)ref(?-u:\b)/ : Token;`
     ///
@@ -14714,7 +15286,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 90:
+    /// Semantic action for production 94:
     ///
     /// `RepeatTerm: <INITIAL, Generic>/(?-u:\b)repeat(?-u:\b)/ : Token;`
     ///
@@ -14733,7 +15305,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 91:
+    /// Semantic action for production 95:
     ///
     /// `ResetTerm: <INITIAL, Generic>/(?-u:\b)reset(?-u:\b)/ : Token;`
     ///
@@ -14752,7 +15324,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 92:
+    /// Semantic action for production 96:
     ///
     /// `ResetAsyncHighTerm: <INITIAL, Generic>/(?-u:\b)reset_async_high(?-u:\b)/ : Token;`
     ///
@@ -14777,7 +15349,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 93:
+    /// Semantic action for production 97:
     ///
     /// `ResetAsyncLowTerm: <INITIAL, Generic>/(?-u:\b)reset_async_low(?-u:\b)/ : Token;`
     ///
@@ -14802,7 +15374,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 94:
+    /// Semantic action for production 98:
     ///
     /// `ResetSyncHighTerm: <INITIAL, Generic>/(?-u:\b)reset_sync_high(?-u:\b)/ : Token;`
     ///
@@ -14827,7 +15399,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 95:
+    /// Semantic action for production 99:
     ///
     /// `ResetSyncLowTerm: <INITIAL, Generic>/(?-u:\b)reset_sync_low(?-u:\b)/ : Token;`
     ///
@@ -14852,7 +15424,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 96:
+    /// Semantic action for production 100:
     ///
     /// `ReturnTerm: <INITIAL, Generic>/(?-u:\b)return(?-u:\b)/ : Token;`
     ///
@@ -14871,7 +15443,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 97:
+    /// Semantic action for production 101:
     ///
     /// `BreakTerm: <INITIAL, Generic>/(?-u:\b)break(?-u:\b)/ : Token;`
     ///
@@ -14890,7 +15462,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 98:
+    /// Semantic action for production 102:
     ///
     /// `SignedTerm: <INITIAL, Generic>/(?-u:\b)signed(?-u:\b)/ : Token;`
     ///
@@ -14909,7 +15481,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 99:
+    /// Semantic action for production 103:
     ///
     /// `StepTerm: <INITIAL, Generic>/(?-u:\b)step(?-u:\b)/ : Token;`
     ///
@@ -14928,7 +15500,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 100:
+    /// Semantic action for production 104:
     ///
     /// `StringTerm: <INITIAL, Generic>/(?-u:\b)string(?-u:\b)/ : Token;`
     ///
@@ -14947,7 +15519,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 101:
+    /// Semantic action for production 105:
     ///
     /// `StructTerm: <INITIAL, Generic>/(?-u:\b)struct(?-u:\b)/ : Token;`
     ///
@@ -14966,7 +15538,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 102:
+    /// Semantic action for production 106:
     ///
     /// `SwitchTerm: <INITIAL, Generic>/(?-u:\b)switch(?-u:\b)/ : Token;`
     ///
@@ -14985,7 +15557,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 103:
+    /// Semantic action for production 107:
     ///
     /// `TriTerm: <INITIAL, Generic>/(?-u:\b)tri(?-u:\b)/ : Token;`
     ///
@@ -15004,7 +15576,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 104:
+    /// Semantic action for production 108:
     ///
     /// `TypeTerm: <INITIAL, Generic>/(?-u:\b)type(?-u:\b)/ : Token;`
     ///
@@ -15023,7 +15595,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 105:
+    /// Semantic action for production 109:
     ///
     /// `U32Term: <INITIAL, Generic>/(?-u:\b)u32(?-u:\b)/ : Token;`
     ///
@@ -15042,7 +15614,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 106:
+    /// Semantic action for production 110:
     ///
     /// `U64Term: <INITIAL, Generic>/(?-u:\b)u64(?-u:\b)/ : Token;`
     ///
@@ -15061,7 +15633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 107:
+    /// Semantic action for production 111:
     ///
     /// `UnionTerm: <INITIAL, Generic>/(?-u:\b)union(?-u:\b)/ : Token;`
     ///
@@ -15080,7 +15652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 108:
+    /// Semantic action for production 112:
     ///
     /// `UnsafeTerm: <INITIAL, Generic>/(?-u:\b)unsafe(?-u:\b)/ : Token;`
     ///
@@ -15099,7 +15671,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 109:
+    /// Semantic action for production 113:
     ///
     /// `VarTerm: <INITIAL, Generic>/(?-u:\b)var(?-u:\b)/ : Token;`
     ///
@@ -15118,7 +15690,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 110:
+    /// Semantic action for production 114:
     ///
     /// `DollarIdentifierTerm: <INITIAL, Generic>/\$[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;`
     ///
@@ -15143,7 +15715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 111:
+    /// Semantic action for production 115:
     ///
     /// `IdentifierTerm: <INITIAL, Generic>/(?:r#)?[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;`
     ///
@@ -15162,7 +15734,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 112:
+    /// Semantic action for production 116:
     ///
     /// `AnyTerm: <Embed>/[^{}]*/ : Token;`
     ///
@@ -15181,7 +15753,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 113:
+    /// Semantic action for production 117:
     ///
     /// `Comments: CommentsOpt /* Option */;`
     ///
@@ -15197,7 +15769,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 114:
+    /// Semantic action for production 118:
     ///
     /// `CommentsOpt /* Option<T>::Some */: CommentsTerm;`
     ///
@@ -15213,7 +15785,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 115:
+    /// Semantic action for production 119:
     ///
     /// `CommentsOpt /* Option<T>::None */: ;`
     ///
@@ -15225,7 +15797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 116:
+    /// Semantic action for production 120:
     ///
     /// `StartToken: Comments;`
     ///
@@ -15243,7 +15815,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 117:
+    /// Semantic action for production 121:
     ///
     /// `StringLiteralToken: StringLiteralTerm : Token Comments;`
     ///
@@ -15273,7 +15845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 118:
+    /// Semantic action for production 122:
     ///
     /// `ExponentToken: ExponentTerm : Token Comments;`
     ///
@@ -15299,7 +15871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 119:
+    /// Semantic action for production 123:
     ///
     /// `FixedPointToken: FixedPointTerm : Token Comments;`
     ///
@@ -15326,7 +15898,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 120:
+    /// Semantic action for production 124:
     ///
     /// `BasedToken: BasedTerm : Token Comments;`
     ///
@@ -15352,7 +15924,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 121:
+    /// Semantic action for production 125:
     ///
     /// `BaseLessToken: BaseLessTerm : Token Comments;`
     ///
@@ -15378,7 +15950,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 122:
+    /// Semantic action for production 126:
     ///
     /// `AllBitToken: AllBitTerm : Token Comments;`
     ///
@@ -15404,7 +15976,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 123:
+    /// Semantic action for production 127:
     ///
     /// `AssignmentOperatorToken: AssignmentOperatorTerm : Token Comments;`
     ///
@@ -15439,7 +16011,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 124:
+    /// Semantic action for production 128:
     ///
     /// `Operator01Token: Operator01Term : Token Comments;`
     ///
@@ -15466,7 +16038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 125:
+    /// Semantic action for production 129:
     ///
     /// `Operator02Token: Operator02Term : Token Comments;`
     ///
@@ -15493,7 +16065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 126:
+    /// Semantic action for production 130:
     ///
     /// `Operator03Token: Operator03Term : Token Comments;`
     ///
@@ -15520,7 +16092,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 127:
+    /// Semantic action for production 131:
     ///
     /// `Operator04Token: Operator04Term : Token Comments;`
     ///
@@ -15547,7 +16119,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 128:
+    /// Semantic action for production 132:
     ///
     /// `Operator05Token: Operator05Term : Token Comments;`
     ///
@@ -15574,7 +16146,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 129:
+    /// Semantic action for production 133:
     ///
     /// `Operator06Token: Operator06Term : Token Comments;`
     ///
@@ -15601,7 +16173,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 130:
+    /// Semantic action for production 134:
     ///
     /// `Operator07Token: Operator07Term : Token Comments;`
     ///
@@ -15628,7 +16200,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 131:
+    /// Semantic action for production 135:
     ///
     /// `Operator08Token: Operator08Term : Token Comments;`
     ///
@@ -15655,7 +16227,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 132:
+    /// Semantic action for production 136:
     ///
     /// `Operator09Token: Operator09Term : Token Comments;`
     ///
@@ -15682,7 +16254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 133:
+    /// Semantic action for production 137:
     ///
     /// `Operator10Token: Operator10Term : Token Comments;`
     ///
@@ -15709,7 +16281,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 134:
+    /// Semantic action for production 138:
     ///
     /// `Operator11Token: Operator11Term : Token Comments;`
     ///
@@ -15736,7 +16308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 135:
+    /// Semantic action for production 139:
     ///
     /// `UnaryOperatorToken: UnaryOperatorTerm : Token Comments;`
     ///
@@ -15766,7 +16338,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 136:
+    /// Semantic action for production 140:
     ///
     /// `BackQuoteToken: BackQuoteTerm : Token Comments;`
     ///
@@ -15793,7 +16365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 137:
+    /// Semantic action for production 141:
     ///
     /// `ColonToken: ColonTerm : Token Comments;`
     ///
@@ -15819,7 +16391,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 138:
+    /// Semantic action for production 142:
     ///
     /// `ColonColonLAngleToken: ColonColonLAngleTerm : Token Comments;`
     ///
@@ -15854,7 +16426,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 139:
+    /// Semantic action for production 143:
     ///
     /// `ColonColonToken: ColonColonTerm : Token Comments;`
     ///
@@ -15881,7 +16453,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 140:
+    /// Semantic action for production 144:
     ///
     /// `CommaToken: CommaTerm : Token Comments;`
     ///
@@ -15907,7 +16479,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 141:
+    /// Semantic action for production 145:
     ///
     /// `DotDotToken: DotDotTerm : Token Comments;`
     ///
@@ -15933,7 +16505,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 142:
+    /// Semantic action for production 146:
     ///
     /// `DotDotEquToken: DotDotEquTerm : Token Comments;`
     ///
@@ -15960,7 +16532,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 143:
+    /// Semantic action for production 147:
     ///
     /// `DotToken: DotTerm : Token Comments;`
     ///
@@ -15986,7 +16558,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 144:
+    /// Semantic action for production 148:
     ///
     /// `EquToken: EquTerm : Token Comments;`
     ///
@@ -16012,7 +16584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 145:
+    /// Semantic action for production 149:
     ///
     /// `HashToken: HashTerm : Token Comments;`
     ///
@@ -16038,7 +16610,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 146:
+    /// Semantic action for production 150:
     ///
     /// `QuoteLBraceToken: QuoteLBraceTerm : Token Comments;`
     ///
@@ -16068,7 +16640,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 147:
+    /// Semantic action for production 151:
     ///
     /// `LAngleToken: LAngleTerm : Token Comments;`
     ///
@@ -16094,7 +16666,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 148:
+    /// Semantic action for production 152:
     ///
     /// `LBraceToken: LBraceTerm : Token Comments;`
     ///
@@ -16120,7 +16692,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 149:
+    /// Semantic action for production 153:
     ///
     /// `LBracketToken: LBracketTerm : Token Comments;`
     ///
@@ -16146,7 +16718,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 150:
+    /// Semantic action for production 154:
     ///
     /// `LParenToken: LParenTerm : Token Comments;`
     ///
@@ -16172,7 +16744,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 151:
+    /// Semantic action for production 155:
     ///
     /// `MinusColonToken: MinusColonTerm : Token Comments;`
     ///
@@ -16199,7 +16771,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 152:
+    /// Semantic action for production 156:
     ///
     /// `MinusGTToken: MinusGTTerm : Token Comments;`
     ///
@@ -16225,7 +16797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 153:
+    /// Semantic action for production 157:
     ///
     /// `PlusColonToken: PlusColonTerm : Token Comments;`
     ///
@@ -16252,7 +16824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 154:
+    /// Semantic action for production 158:
     ///
     /// `RAngleToken: RAngleTerm : Token Comments;`
     ///
@@ -16278,7 +16850,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 155:
+    /// Semantic action for production 159:
     ///
     /// `RBraceToken: RBraceTerm : Token Comments;`
     ///
@@ -16304,7 +16876,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 156:
+    /// Semantic action for production 160:
     ///
     /// `RBracketToken: RBracketTerm : Token Comments;`
     ///
@@ -16330,7 +16902,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 157:
+    /// Semantic action for production 161:
     ///
     /// `RParenToken: RParenTerm : Token Comments;`
     ///
@@ -16356,7 +16928,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 158:
+    /// Semantic action for production 162:
     ///
     /// `SemicolonToken: SemicolonTerm : Token Comments;`
     ///
@@ -16382,7 +16954,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 159:
+    /// Semantic action for production 163:
     ///
     /// `StarToken: StarTerm : Token Comments;`
     ///
@@ -16408,7 +16980,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 160:
+    /// Semantic action for production 164:
     ///
     /// `AlwaysCombToken: AlwaysCombTerm : Token Comments;`
     ///
@@ -16435,7 +17007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 161:
+    /// Semantic action for production 165:
     ///
     /// `AlwaysFfToken: AlwaysFfTerm : Token Comments;`
     ///
@@ -16461,7 +17033,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 162:
+    /// Semantic action for production 166:
     ///
     /// `AsToken: AsTerm : Token Comments;`
     ///
@@ -16487,7 +17059,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 163:
+    /// Semantic action for production 167:
+    ///
+    /// `AssertToken: AssertTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert_token(
+        &mut self,
+        _assert_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let assert_term = pop_item!(self, assert_term, AssertTerm, context);
+        let assert_token_built = AssertToken {
+            assert_term: (&assert_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.assert_token(&assert_token_built)?;
+        self.push(ASTType::AssertToken(assert_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 168:
     ///
     /// `AssignToken: AssignTerm : Token Comments;`
     ///
@@ -16513,7 +17111,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 164:
+    /// Semantic action for production 169:
+    ///
+    /// `AssumeToken: AssumeTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume_token(
+        &mut self,
+        _assume_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let assume_term = pop_item!(self, assume_term, AssumeTerm, context);
+        let assume_token_built = AssumeToken {
+            assume_term: (&assume_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.assume_token(&assume_token_built)?;
+        self.push(ASTType::AssumeToken(assume_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 170:
     ///
     /// `BitToken: BitTerm : Token Comments;`
     ///
@@ -16539,7 +17163,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 165:
+    /// Semantic action for production 171:
     ///
     /// `CaseToken: CaseTerm : Token Comments;`
     ///
@@ -16565,7 +17189,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 166:
+    /// Semantic action for production 172:
     ///
     /// `ClockToken: ClockTerm : Token Comments;`
     ///
@@ -16591,7 +17215,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 167:
+    /// Semantic action for production 173:
     ///
     /// `ClockPosedgeToken: ClockPosedgeTerm : Token Comments;`
     ///
@@ -16621,7 +17245,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 168:
+    /// Semantic action for production 174:
     ///
     /// `ClockNegedgeToken: ClockNegedgeTerm : Token Comments;`
     ///
@@ -16651,7 +17275,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 169:
+    /// Semantic action for production 175:
+    ///
+    /// `CoverToken: CoverTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover_token(
+        &mut self,
+        _cover_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let cover_term = pop_item!(self, cover_term, CoverTerm, context);
+        let cover_token_built = CoverToken {
+            cover_term: (&cover_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.cover_token(&cover_token_built)?;
+        self.push(ASTType::CoverToken(cover_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 176:
     ///
     /// `ConstToken: ConstTerm : Token Comments;`
     ///
@@ -16677,7 +17327,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 170:
+    /// Semantic action for production 177:
     ///
     /// `DefaultToken: DefaultTerm : Token Comments;`
     ///
@@ -16703,7 +17353,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 171:
+    /// Semantic action for production 178:
     ///
     /// `ElseToken: ElseTerm : Token Comments;`
     ///
@@ -16729,7 +17379,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 172:
+    /// Semantic action for production 179:
     ///
     /// `EmbedToken: EmbedTerm : Token Comments;`
     ///
@@ -16755,7 +17405,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 173:
+    /// Semantic action for production 180:
     ///
     /// `EnumToken: EnumTerm : Token Comments;`
     ///
@@ -16781,7 +17431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 174:
+    /// Semantic action for production 181:
     ///
     /// `ExportToken: ExportTerm : Token Comments;`
     ///
@@ -16807,7 +17457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 175:
+    /// Semantic action for production 182:
     ///
     /// `F32Token: F32Term : Token Comments;`
     ///
@@ -16833,7 +17483,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 176:
+    /// Semantic action for production 183:
     ///
     /// `F64Token: F64Term : Token Comments;`
     ///
@@ -16859,7 +17509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 177:
+    /// Semantic action for production 184:
     ///
     /// `FinalToken: FinalTerm : Token Comments;`
     ///
@@ -16885,7 +17535,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 178:
+    /// Semantic action for production 185:
     ///
     /// `ForToken: ForTerm : Token Comments;`
     ///
@@ -16911,7 +17561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 179:
+    /// Semantic action for production 186:
     ///
     /// `FunctionToken: FunctionTerm : Token Comments;`
     ///
@@ -16937,7 +17587,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 180:
+    /// Semantic action for production 187:
     ///
     /// `I32Token: I32Term : Token Comments;`
     ///
@@ -16963,7 +17613,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 181:
+    /// Semantic action for production 188:
     ///
     /// `I64Token: I64Term : Token Comments;`
     ///
@@ -16989,7 +17639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 182:
+    /// Semantic action for production 189:
     ///
     /// `IfResetToken: IfResetTerm : Token Comments;`
     ///
@@ -17015,7 +17665,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 183:
+    /// Semantic action for production 190:
     ///
     /// `IfToken: IfTerm : Token Comments;`
     ///
@@ -17041,7 +17691,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 184:
+    /// Semantic action for production 191:
     ///
     /// `ImportToken: ImportTerm : Token Comments;`
     ///
@@ -17067,7 +17717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 185:
+    /// Semantic action for production 192:
     ///
     /// `IncludeToken: IncludeTerm : Token Comments;`
     ///
@@ -17093,7 +17743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 186:
+    /// Semantic action for production 193:
     ///
     /// `InitialToken: InitialTerm : Token Comments;`
     ///
@@ -17119,7 +17769,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 187:
+    /// Semantic action for production 194:
     ///
     /// `InoutToken: InoutTerm : Token Comments;`
     ///
@@ -17145,7 +17795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 188:
+    /// Semantic action for production 195:
     ///
     /// `InputToken: InputTerm : Token Comments;`
     ///
@@ -17171,7 +17821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 189:
+    /// Semantic action for production 196:
     ///
     /// `InsideToken: InsideTerm : Token Comments;`
     ///
@@ -17197,7 +17847,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 190:
+    /// Semantic action for production 197:
     ///
     /// `InstToken: InstTerm : Token Comments;`
     ///
@@ -17223,7 +17873,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 191:
+    /// Semantic action for production 198:
     ///
     /// `InterfaceToken: InterfaceTerm : Token Comments;`
     ///
@@ -17249,7 +17899,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 192:
+    /// Semantic action for production 199:
     ///
     /// `InToken: InTerm : Token Comments;`
     ///
@@ -17275,7 +17925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 193:
+    /// Semantic action for production 200:
     ///
     /// `LetToken: LetTerm : Token Comments;`
     ///
@@ -17301,7 +17951,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 194:
+    /// Semantic action for production 201:
     ///
     /// `LogicToken: LogicTerm : Token Comments;`
     ///
@@ -17327,7 +17977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 195:
+    /// Semantic action for production 202:
     ///
     /// `LsbToken: LsbTerm : Token Comments;`
     ///
@@ -17353,7 +18003,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 196:
+    /// Semantic action for production 203:
     ///
     /// `ModportToken: ModportTerm : Token Comments;`
     ///
@@ -17379,7 +18029,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 197:
+    /// Semantic action for production 204:
     ///
     /// `ModuleToken: ModuleTerm : Token Comments;`
     ///
@@ -17405,7 +18055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 198:
+    /// Semantic action for production 205:
     ///
     /// `MsbToken: MsbTerm : Token Comments;`
     ///
@@ -17431,7 +18081,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 199:
+    /// Semantic action for production 206:
     ///
     /// `OutputToken: OutputTerm : Token Comments;`
     ///
@@ -17457,7 +18107,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 200:
+    /// Semantic action for production 207:
     ///
     /// `OutsideToken: OutsideTerm : Token Comments;`
     ///
@@ -17483,7 +18133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 201:
+    /// Semantic action for production 208:
     ///
     /// `PackageToken: PackageTerm : Token Comments;`
     ///
@@ -17509,7 +18159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 202:
+    /// Semantic action for production 209:
     ///
     /// `ParamToken: ParamTerm : Token Comments;`
     ///
@@ -17535,7 +18185,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 203:
+    /// Semantic action for production 210:
+    ///
+    /// `PropertyToken: PropertyTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_token(
+        &mut self,
+        _property_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let property_term = pop_item!(self, property_term, PropertyTerm, context);
+        let property_token_built = PropertyToken {
+            property_term: (&property_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.property_token(&property_token_built)?;
+        self.push(ASTType::PropertyToken(property_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 211:
     ///
     /// `ProtoToken: ProtoTerm : Token Comments;`
     ///
@@ -17561,7 +18237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 204:
+    /// Semantic action for production 212:
     ///
     /// `PubToken: PubTerm : Token Comments;`
     ///
@@ -17587,7 +18263,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 205:
+    /// Semantic action for production 213:
     ///
     /// `RefToken: RefTerm : Token Comments;`
     ///
@@ -17613,7 +18289,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 206:
+    /// Semantic action for production 214:
     ///
     /// `RepeatToken: RepeatTerm : Token Comments;`
     ///
@@ -17639,7 +18315,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 207:
+    /// Semantic action for production 215:
     ///
     /// `ResetToken: ResetTerm : Token Comments;`
     ///
@@ -17665,7 +18341,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 208:
+    /// Semantic action for production 216:
     ///
     /// `ResetAsyncHighToken: ResetAsyncHighTerm : Token Comments;`
     ///
@@ -17696,7 +18372,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 209:
+    /// Semantic action for production 217:
     ///
     /// `ResetAsyncLowToken: ResetAsyncLowTerm : Token Comments;`
     ///
@@ -17727,7 +18403,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 210:
+    /// Semantic action for production 218:
     ///
     /// `ResetSyncHighToken: ResetSyncHighTerm : Token Comments;`
     ///
@@ -17758,7 +18434,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 211:
+    /// Semantic action for production 219:
     ///
     /// `ResetSyncLowToken: ResetSyncLowTerm : Token Comments;`
     ///
@@ -17788,7 +18464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 212:
+    /// Semantic action for production 220:
     ///
     /// `ReturnToken: ReturnTerm : Token Comments;`
     ///
@@ -17814,7 +18490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 213:
+    /// Semantic action for production 221:
     ///
     /// `BreakToken: BreakTerm : Token Comments;`
     ///
@@ -17840,7 +18516,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 214:
+    /// Semantic action for production 222:
     ///
     /// `SignedToken: SignedTerm : Token Comments;`
     ///
@@ -17866,7 +18542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 215:
+    /// Semantic action for production 223:
     ///
     /// `StepToken: StepTerm : Token Comments;`
     ///
@@ -17892,7 +18568,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 216:
+    /// Semantic action for production 224:
     ///
     /// `StringToken: StringTerm : Token Comments;`
     ///
@@ -17918,7 +18594,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 217:
+    /// Semantic action for production 225:
     ///
     /// `StructToken: StructTerm : Token Comments;`
     ///
@@ -17944,7 +18620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 218:
+    /// Semantic action for production 226:
     ///
     /// `SwitchToken: SwitchTerm : Token Comments;`
     ///
@@ -17970,7 +18646,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 219:
+    /// Semantic action for production 227:
     ///
     /// `TriToken: TriTerm : Token Comments;`
     ///
@@ -17996,7 +18672,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 220:
+    /// Semantic action for production 228:
     ///
     /// `TypeToken: TypeTerm : Token Comments;`
     ///
@@ -18022,7 +18698,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 221:
+    /// Semantic action for production 229:
     ///
     /// `U32Token: U32Term : Token Comments;`
     ///
@@ -18048,7 +18724,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 222:
+    /// Semantic action for production 230:
     ///
     /// `U64Token: U64Term : Token Comments;`
     ///
@@ -18074,7 +18750,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 223:
+    /// Semantic action for production 231:
     ///
     /// `UnionToken: UnionTerm : Token Comments;`
     ///
@@ -18100,7 +18776,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 224:
+    /// Semantic action for production 232:
     ///
     /// `UnsafeToken: UnsafeTerm : Token Comments;`
     ///
@@ -18126,7 +18802,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 225:
+    /// Semantic action for production 233:
     ///
     /// `VarToken: VarTerm : Token Comments;`
     ///
@@ -18152,7 +18828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 226:
+    /// Semantic action for production 234:
     ///
     /// `DollarIdentifierToken: DollarIdentifierTerm : Token Comments;`
     ///
@@ -18183,7 +18859,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 227:
+    /// Semantic action for production 235:
     ///
     /// `IdentifierToken: IdentifierTerm : Token Comments;`
     ///
@@ -18210,7 +18886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 228:
+    /// Semantic action for production 236:
     ///
     /// `Start: StartToken : VerylToken;`
     ///
@@ -18230,7 +18906,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 229:
+    /// Semantic action for production 237:
     ///
     /// `StringLiteral: StringLiteralToken : VerylToken;`
     ///
@@ -18251,7 +18927,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 230:
+    /// Semantic action for production 238:
     ///
     /// `Exponent: ExponentToken : VerylToken;`
     ///
@@ -18271,7 +18947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 231:
+    /// Semantic action for production 239:
     ///
     /// `FixedPoint: FixedPointToken : VerylToken;`
     ///
@@ -18291,7 +18967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 232:
+    /// Semantic action for production 240:
     ///
     /// `Based: BasedToken : VerylToken;`
     ///
@@ -18311,7 +18987,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 233:
+    /// Semantic action for production 241:
     ///
     /// `BaseLess: BaseLessToken : VerylToken;`
     ///
@@ -18331,7 +19007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 234:
+    /// Semantic action for production 242:
     ///
     /// `AllBit: AllBitToken : VerylToken;`
     ///
@@ -18351,7 +19027,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 235:
+    /// Semantic action for production 243:
     ///
     /// `AssignmentOperator: AssignmentOperatorToken : VerylToken;`
     ///
@@ -18383,7 +19059,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 236:
+    /// Semantic action for production 244:
     ///
     /// `Operator01: Operator01Token : VerylToken;`
     ///
@@ -18403,7 +19079,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 237:
+    /// Semantic action for production 245:
     ///
     /// `Operator02: Operator02Token : VerylToken;`
     ///
@@ -18423,7 +19099,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 238:
+    /// Semantic action for production 246:
     ///
     /// `Operator03: Operator03Token : VerylToken;`
     ///
@@ -18443,7 +19119,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 239:
+    /// Semantic action for production 247:
     ///
     /// `Operator04: Operator04Token : VerylToken;`
     ///
@@ -18463,7 +19139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 240:
+    /// Semantic action for production 248:
     ///
     /// `Operator05: Operator05Token : VerylToken;`
     ///
@@ -18483,7 +19159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 241:
+    /// Semantic action for production 249:
     ///
     /// `Operator06: Operator06Token : VerylToken;`
     ///
@@ -18503,7 +19179,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 242:
+    /// Semantic action for production 250:
     ///
     /// `Operator07: Operator07Token : VerylToken;`
     ///
@@ -18523,7 +19199,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 243:
+    /// Semantic action for production 251:
     ///
     /// `Operator08: Operator08Token : VerylToken;`
     ///
@@ -18543,7 +19219,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 244:
+    /// Semantic action for production 252:
     ///
     /// `Operator09: Operator09Token : VerylToken;`
     ///
@@ -18563,7 +19239,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 245:
+    /// Semantic action for production 253:
     ///
     /// `Operator10: Operator10Token : VerylToken;`
     ///
@@ -18583,7 +19259,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 246:
+    /// Semantic action for production 254:
     ///
     /// `Operator11: Operator11Token : VerylToken;`
     ///
@@ -18603,7 +19279,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 247:
+    /// Semantic action for production 255:
     ///
     /// `UnaryOperator: UnaryOperatorToken : VerylToken;`
     ///
@@ -18624,7 +19300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 248:
+    /// Semantic action for production 256:
     ///
     /// `BackQuote: BackQuoteToken : VerylToken;`
     ///
@@ -18644,7 +19320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 249:
+    /// Semantic action for production 257:
     ///
     /// `Colon: ColonToken : VerylToken;`
     ///
@@ -18664,7 +19340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 250:
+    /// Semantic action for production 258:
     ///
     /// `ColonColonLAngle: ColonColonLAngleToken : VerylToken;`
     ///
@@ -18696,7 +19372,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 251:
+    /// Semantic action for production 259:
     ///
     /// `ColonColon: ColonColonToken : VerylToken;`
     ///
@@ -18716,7 +19392,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 252:
+    /// Semantic action for production 260:
     ///
     /// `Comma: CommaToken : VerylToken;`
     ///
@@ -18736,7 +19412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 253:
+    /// Semantic action for production 261:
     ///
     /// `DotDot: DotDotToken : VerylToken;`
     ///
@@ -18756,7 +19432,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 254:
+    /// Semantic action for production 262:
     ///
     /// `DotDotEqu: DotDotEquToken : VerylToken;`
     ///
@@ -18776,7 +19452,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 255:
+    /// Semantic action for production 263:
     ///
     /// `Dot: DotToken : VerylToken;`
     ///
@@ -18796,7 +19472,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 256:
+    /// Semantic action for production 264:
     ///
     /// `Equ: EquToken : VerylToken;`
     ///
@@ -18816,7 +19492,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 257:
+    /// Semantic action for production 265:
     ///
     /// `Hash: HashToken : VerylToken;`
     ///
@@ -18836,7 +19512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 258:
+    /// Semantic action for production 266:
     ///
     /// `QuoteLBrace: QuoteLBraceToken : VerylToken;`
     ///
@@ -18856,7 +19532,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 259:
+    /// Semantic action for production 267:
     ///
     /// `LAngle: LAngleToken : VerylToken;`
     ///
@@ -18876,7 +19552,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 260:
+    /// Semantic action for production 268:
     ///
     /// `LBrace: LBraceToken : VerylToken;`
     ///
@@ -18896,7 +19572,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 261:
+    /// Semantic action for production 269:
     ///
     /// `LBracket: LBracketToken : VerylToken;`
     ///
@@ -18916,7 +19592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 262:
+    /// Semantic action for production 270:
     ///
     /// `LParen: LParenToken : VerylToken;`
     ///
@@ -18936,7 +19612,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 263:
+    /// Semantic action for production 271:
     ///
     /// `MinusColon: MinusColonToken : VerylToken;`
     ///
@@ -18956,7 +19632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 264:
+    /// Semantic action for production 272:
     ///
     /// `MinusGT: MinusGTToken : VerylToken;`
     ///
@@ -18976,7 +19652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 265:
+    /// Semantic action for production 273:
     ///
     /// `PlusColon: PlusColonToken : VerylToken;`
     ///
@@ -18996,7 +19672,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 266:
+    /// Semantic action for production 274:
     ///
     /// `RAngle: RAngleToken : VerylToken;`
     ///
@@ -19016,7 +19692,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 267:
+    /// Semantic action for production 275:
     ///
     /// `RBrace: RBraceToken : VerylToken;`
     ///
@@ -19036,7 +19712,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 268:
+    /// Semantic action for production 276:
     ///
     /// `RBracket: RBracketToken : VerylToken;`
     ///
@@ -19056,7 +19732,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 269:
+    /// Semantic action for production 277:
     ///
     /// `RParen: RParenToken : VerylToken;`
     ///
@@ -19076,7 +19752,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 270:
+    /// Semantic action for production 278:
     ///
     /// `Semicolon: SemicolonToken : VerylToken;`
     ///
@@ -19096,7 +19772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 271:
+    /// Semantic action for production 279:
     ///
     /// `Star: StarToken : VerylToken;`
     ///
@@ -19116,7 +19792,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 272:
+    /// Semantic action for production 280:
     ///
     /// `AlwaysComb: AlwaysCombToken : VerylToken;`
     ///
@@ -19136,7 +19812,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 273:
+    /// Semantic action for production 281:
     ///
     /// `AlwaysFf: AlwaysFfToken : VerylToken;`
     ///
@@ -19156,7 +19832,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 274:
+    /// Semantic action for production 282:
     ///
     /// `As: AsToken : VerylToken;`
     ///
@@ -19176,7 +19852,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 275:
+    /// Semantic action for production 283:
+    ///
+    /// `Assert: AssertToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert(&mut self, _assert_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert_token = pop_item!(self, assert_token, AssertToken, context);
+        let assert_built = Assert {
+            assert_token: (&assert_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.assert(&assert_built)?;
+        self.push(ASTType::Assert(assert_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 284:
     ///
     /// `Assign: AssignToken : VerylToken;`
     ///
@@ -19196,7 +19892,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 276:
+    /// Semantic action for production 285:
+    ///
+    /// `Assume: AssumeToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume(&mut self, _assume_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume_token = pop_item!(self, assume_token, AssumeToken, context);
+        let assume_built = Assume {
+            assume_token: (&assume_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.assume(&assume_built)?;
+        self.push(ASTType::Assume(assume_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 286:
     ///
     /// `Bit: BitToken : VerylToken;`
     ///
@@ -19216,7 +19932,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 277:
+    /// Semantic action for production 287:
     ///
     /// `Break: BreakToken : VerylToken;`
     ///
@@ -19236,7 +19952,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 278:
+    /// Semantic action for production 288:
     ///
     /// `Case: CaseToken : VerylToken;`
     ///
@@ -19256,7 +19972,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 279:
+    /// Semantic action for production 289:
     ///
     /// `Clock: ClockToken : VerylToken;`
     ///
@@ -19276,7 +19992,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 280:
+    /// Semantic action for production 290:
     ///
     /// `ClockPosedge: ClockPosedgeToken : VerylToken;`
     ///
@@ -19296,7 +20012,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 281:
+    /// Semantic action for production 291:
     ///
     /// `ClockNegedge: ClockNegedgeToken : VerylToken;`
     ///
@@ -19316,7 +20032,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 282:
+    /// Semantic action for production 292:
+    ///
+    /// `Cover: CoverToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover(&mut self, _cover_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover_token = pop_item!(self, cover_token, CoverToken, context);
+        let cover_built = Cover {
+            cover_token: (&cover_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.cover(&cover_built)?;
+        self.push(ASTType::Cover(cover_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 293:
     ///
     /// `Const: ConstToken : VerylToken;`
     ///
@@ -19336,7 +20072,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 283:
+    /// Semantic action for production 294:
     ///
     /// `Defaul: DefaultToken : VerylToken;`
     ///
@@ -19356,7 +20092,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 284:
+    /// Semantic action for production 295:
     ///
     /// `Else: ElseToken : VerylToken;`
     ///
@@ -19376,7 +20112,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 285:
+    /// Semantic action for production 296:
     ///
     /// `Embed: EmbedToken : VerylToken;`
     ///
@@ -19396,7 +20132,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 286:
+    /// Semantic action for production 297:
     ///
     /// `Enum: EnumToken : VerylToken;`
     ///
@@ -19416,7 +20152,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 287:
+    /// Semantic action for production 298:
     ///
     /// `Export: ExportToken : VerylToken;`
     ///
@@ -19436,7 +20172,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 288:
+    /// Semantic action for production 299:
     ///
     /// `F32: F32Token : VerylToken;`
     ///
@@ -19456,7 +20192,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 289:
+    /// Semantic action for production 300:
     ///
     /// `F64: F64Token : VerylToken;`
     ///
@@ -19476,7 +20212,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 290:
+    /// Semantic action for production 301:
     ///
     /// `Final: FinalToken : VerylToken;`
     ///
@@ -19496,7 +20232,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 291:
+    /// Semantic action for production 302:
     ///
     /// `For: ForToken : VerylToken;`
     ///
@@ -19516,7 +20252,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 292:
+    /// Semantic action for production 303:
     ///
     /// `Function: FunctionToken : VerylToken;`
     ///
@@ -19536,7 +20272,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 293:
+    /// Semantic action for production 304:
     ///
     /// `I32: I32Token : VerylToken;`
     ///
@@ -19556,7 +20292,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 294:
+    /// Semantic action for production 305:
     ///
     /// `I64: I64Token : VerylToken;`
     ///
@@ -19576,7 +20312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 295:
+    /// Semantic action for production 306:
     ///
     /// `If: IfToken : VerylToken;`
     ///
@@ -19596,7 +20332,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 296:
+    /// Semantic action for production 307:
     ///
     /// `IfReset: IfResetToken : VerylToken;`
     ///
@@ -19616,7 +20352,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 297:
+    /// Semantic action for production 308:
     ///
     /// `Import: ImportToken : VerylToken;`
     ///
@@ -19636,7 +20372,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 298:
+    /// Semantic action for production 309:
     ///
     /// `In: InToken : VerylToken;`
     ///
@@ -19656,7 +20392,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 299:
+    /// Semantic action for production 310:
     ///
     /// `Include: IncludeToken : VerylToken;`
     ///
@@ -19676,7 +20412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 300:
+    /// Semantic action for production 311:
     ///
     /// `Initial: InitialToken : VerylToken;`
     ///
@@ -19696,7 +20432,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 301:
+    /// Semantic action for production 312:
     ///
     /// `Inout: InoutToken : VerylToken;`
     ///
@@ -19716,7 +20452,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 302:
+    /// Semantic action for production 313:
     ///
     /// `Input: InputToken : VerylToken;`
     ///
@@ -19736,7 +20472,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 303:
+    /// Semantic action for production 314:
     ///
     /// `Inside: InsideToken : VerylToken;`
     ///
@@ -19756,7 +20492,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 304:
+    /// Semantic action for production 315:
     ///
     /// `Inst: InstToken : VerylToken;`
     ///
@@ -19776,7 +20512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 305:
+    /// Semantic action for production 316:
     ///
     /// `Interface: InterfaceToken : VerylToken;`
     ///
@@ -19796,7 +20532,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 306:
+    /// Semantic action for production 317:
     ///
     /// `Let: LetToken : VerylToken;`
     ///
@@ -19816,7 +20552,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 307:
+    /// Semantic action for production 318:
     ///
     /// `Logic: LogicToken : VerylToken;`
     ///
@@ -19836,7 +20572,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 308:
+    /// Semantic action for production 319:
     ///
     /// `Lsb: LsbToken : VerylToken;`
     ///
@@ -19856,7 +20592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 309:
+    /// Semantic action for production 320:
     ///
     /// `Modport: ModportToken : VerylToken;`
     ///
@@ -19876,7 +20612,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 310:
+    /// Semantic action for production 321:
     ///
     /// `Module: ModuleToken : VerylToken;`
     ///
@@ -19896,7 +20632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 311:
+    /// Semantic action for production 322:
     ///
     /// `Msb: MsbToken : VerylToken;`
     ///
@@ -19916,7 +20652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 312:
+    /// Semantic action for production 323:
     ///
     /// `Output: OutputToken : VerylToken;`
     ///
@@ -19936,7 +20672,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 313:
+    /// Semantic action for production 324:
     ///
     /// `Outside: OutsideToken : VerylToken;`
     ///
@@ -19956,7 +20692,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 314:
+    /// Semantic action for production 325:
     ///
     /// `Package: PackageToken : VerylToken;`
     ///
@@ -19976,7 +20712,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 315:
+    /// Semantic action for production 326:
     ///
     /// `Param: ParamToken : VerylToken;`
     ///
@@ -19996,7 +20732,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 316:
+    /// Semantic action for production 327:
+    ///
+    /// `Property: PropertyToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property(&mut self, _property_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let property_token = pop_item!(self, property_token, PropertyToken, context);
+        let property_built = Property {
+            property_token: (&property_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.property(&property_built)?;
+        self.push(ASTType::Property(property_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 328:
     ///
     /// `Proto: ProtoToken : VerylToken;`
     ///
@@ -20016,7 +20772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 317:
+    /// Semantic action for production 329:
     ///
     /// `Pub: PubToken : VerylToken;`
     ///
@@ -20036,7 +20792,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 318:
+    /// Semantic action for production 330:
     ///
     /// `Ref: RefToken : VerylToken;`
     ///
@@ -20056,7 +20812,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 319:
+    /// Semantic action for production 331:
     ///
     /// `Repeat: RepeatToken : VerylToken;`
     ///
@@ -20076,7 +20832,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 320:
+    /// Semantic action for production 332:
     ///
     /// `Reset: ResetToken : VerylToken;`
     ///
@@ -20096,7 +20852,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 321:
+    /// Semantic action for production 333:
     ///
     /// `ResetAsyncHigh: ResetAsyncHighToken : VerylToken;`
     ///
@@ -20118,7 +20874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 322:
+    /// Semantic action for production 334:
     ///
     /// `ResetAsyncLow: ResetAsyncLowToken : VerylToken;`
     ///
@@ -20139,7 +20895,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 323:
+    /// Semantic action for production 335:
     ///
     /// `ResetSyncHigh: ResetSyncHighToken : VerylToken;`
     ///
@@ -20160,7 +20916,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 324:
+    /// Semantic action for production 336:
     ///
     /// `ResetSyncLow: ResetSyncLowToken : VerylToken;`
     ///
@@ -20181,7 +20937,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 325:
+    /// Semantic action for production 337:
     ///
     /// `Return: ReturnToken : VerylToken;`
     ///
@@ -20201,7 +20957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 326:
+    /// Semantic action for production 338:
     ///
     /// `Signed: SignedToken : VerylToken;`
     ///
@@ -20221,7 +20977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 327:
+    /// Semantic action for production 339:
     ///
     /// `Step: StepToken : VerylToken;`
     ///
@@ -20241,7 +20997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 328:
+    /// Semantic action for production 340:
     ///
     /// `Strin: StringToken : VerylToken;`
     ///
@@ -20261,7 +21017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 329:
+    /// Semantic action for production 341:
     ///
     /// `Struct: StructToken : VerylToken;`
     ///
@@ -20281,7 +21037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 330:
+    /// Semantic action for production 342:
     ///
     /// `Switch: SwitchToken : VerylToken;`
     ///
@@ -20301,7 +21057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 331:
+    /// Semantic action for production 343:
     ///
     /// `Tri: TriToken : VerylToken;`
     ///
@@ -20321,7 +21077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 332:
+    /// Semantic action for production 344:
     ///
     /// `Type: TypeToken : VerylToken;`
     ///
@@ -20341,7 +21097,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 333:
+    /// Semantic action for production 345:
     ///
     /// `U32: U32Token : VerylToken;`
     ///
@@ -20361,7 +21117,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 334:
+    /// Semantic action for production 346:
     ///
     /// `U64: U64Token : VerylToken;`
     ///
@@ -20381,7 +21137,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 335:
+    /// Semantic action for production 347:
     ///
     /// `Union: UnionToken : VerylToken;`
     ///
@@ -20401,7 +21157,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 336:
+    /// Semantic action for production 348:
     ///
     /// `Unsafe: UnsafeToken : VerylToken;`
     ///
@@ -20421,7 +21177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 337:
+    /// Semantic action for production 349:
     ///
     /// `Var: VarToken : VerylToken;`
     ///
@@ -20441,7 +21197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 338:
+    /// Semantic action for production 350:
     ///
     /// `DollarIdentifier: DollarIdentifierToken : VerylToken;`
     ///
@@ -20467,7 +21223,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 339:
+    /// Semantic action for production 351:
     ///
     /// `Identifier: IdentifierToken : VerylToken;`
     ///
@@ -20487,7 +21243,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 340:
+    /// Semantic action for production 352:
     ///
     /// `Number: IntegralNumber;`
     ///
@@ -20506,7 +21262,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 341:
+    /// Semantic action for production 353:
     ///
     /// `Number: RealNumber;`
     ///
@@ -20525,7 +21281,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 342:
+    /// Semantic action for production 354:
     ///
     /// `IntegralNumber: Based;`
     ///
@@ -20545,7 +21301,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 343:
+    /// Semantic action for production 355:
     ///
     /// `IntegralNumber: BaseLess;`
     ///
@@ -20565,7 +21321,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 344:
+    /// Semantic action for production 356:
     ///
     /// `IntegralNumber: AllBit;`
     ///
@@ -20585,7 +21341,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 345:
+    /// Semantic action for production 357:
     ///
     /// `RealNumber: FixedPoint;`
     ///
@@ -20604,7 +21360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 346:
+    /// Semantic action for production 358:
     ///
     /// `RealNumber: Exponent;`
     ///
@@ -20623,7 +21379,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 347:
+    /// Semantic action for production 359:
     ///
     /// `HierarchicalIdentifier: Identifier HierarchicalIdentifierList /* Vec */ HierarchicalIdentifierList0 /* Vec */;`
     ///
@@ -20664,7 +21420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 348:
+    /// Semantic action for production 360:
     ///
     /// `HierarchicalIdentifierList0 /* Vec<T>::Push */: Dot Identifier HierarchicalIdentifierList0List /* Vec */ HierarchicalIdentifierList0;`
     ///
@@ -20706,7 +21462,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 349:
+    /// Semantic action for production 361:
     ///
     /// `HierarchicalIdentifierList0List /* Vec<T>::Push */: Select HierarchicalIdentifierList0List;`
     ///
@@ -20737,7 +21493,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 350:
+    /// Semantic action for production 362:
     ///
     /// `HierarchicalIdentifierList0List /* Vec<T>::New */: ;`
     ///
@@ -20753,7 +21509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 351:
+    /// Semantic action for production 363:
     ///
     /// `HierarchicalIdentifierList0 /* Vec<T>::New */: ;`
     ///
@@ -20769,7 +21525,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 352:
+    /// Semantic action for production 364:
     ///
     /// `HierarchicalIdentifierList /* Vec<T>::Push */: Select HierarchicalIdentifierList;`
     ///
@@ -20800,7 +21556,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 353:
+    /// Semantic action for production 365:
     ///
     /// `HierarchicalIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -20816,7 +21572,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 354:
+    /// Semantic action for production 366:
     ///
     /// `ScopedIdentifier: ScopedIdentifierGroup ScopedIdentifierList /* Vec */;`
     ///
@@ -20847,7 +21603,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 355:
+    /// Semantic action for production 367:
     ///
     /// `ScopedIdentifierGroup: DollarIdentifier;`
     ///
@@ -20868,7 +21624,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 356:
+    /// Semantic action for production 368:
     ///
     /// `ScopedIdentifierGroup: Identifier ScopedIdentifierOpt /* Option */;`
     ///
@@ -20896,7 +21652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 357:
+    /// Semantic action for production 369:
     ///
     /// `ScopedIdentifierList /* Vec<T>::Push */: ColonColon Identifier ScopedIdentifierOpt0 /* Option */ ScopedIdentifierList;`
     ///
@@ -20930,7 +21686,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 358:
+    /// Semantic action for production 370:
     ///
     /// `ScopedIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -20946,7 +21702,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 359:
+    /// Semantic action for production 371:
     ///
     /// `ScopedIdentifierOpt0 /* Option<T>::Some */: WithGenericArgument;`
     ///
@@ -20969,7 +21725,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 360:
+    /// Semantic action for production 372:
     ///
     /// `ScopedIdentifierOpt0 /* Option<T>::None */: ;`
     ///
@@ -20981,7 +21737,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 361:
+    /// Semantic action for production 373:
     ///
     /// `ScopedIdentifierOpt /* Option<T>::Some */: WithGenericArgument;`
     ///
@@ -21004,7 +21760,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 362:
+    /// Semantic action for production 374:
     ///
     /// `ScopedIdentifierOpt /* Option<T>::None */: ;`
     ///
@@ -21016,7 +21772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 363:
+    /// Semantic action for production 375:
     ///
     /// `ExpressionIdentifier: ScopedIdentifier ExpressionIdentifierOpt /* Option */ ExpressionIdentifierList /* Vec */ ExpressionIdentifierList0 /* Vec */;`
     ///
@@ -21065,7 +21821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 364:
+    /// Semantic action for production 376:
     ///
     /// `ExpressionIdentifierList0 /* Vec<T>::Push */: Dot Identifier ExpressionIdentifierList0List /* Vec */ ExpressionIdentifierList0;`
     ///
@@ -21107,7 +21863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 365:
+    /// Semantic action for production 377:
     ///
     /// `ExpressionIdentifierList0List /* Vec<T>::Push */: Select ExpressionIdentifierList0List;`
     ///
@@ -21138,7 +21894,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 366:
+    /// Semantic action for production 378:
     ///
     /// `ExpressionIdentifierList0List /* Vec<T>::New */: ;`
     ///
@@ -21154,7 +21910,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 367:
+    /// Semantic action for production 379:
     ///
     /// `ExpressionIdentifierList0 /* Vec<T>::New */: ;`
     ///
@@ -21170,7 +21926,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 368:
+    /// Semantic action for production 380:
     ///
     /// `ExpressionIdentifierList /* Vec<T>::Push */: Select ExpressionIdentifierList;`
     ///
@@ -21201,7 +21957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 369:
+    /// Semantic action for production 381:
     ///
     /// `ExpressionIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -21217,7 +21973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 370:
+    /// Semantic action for production 382:
     ///
     /// `ExpressionIdentifierOpt /* Option<T>::Some */: Width;`
     ///
@@ -21236,7 +21992,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 371:
+    /// Semantic action for production 383:
     ///
     /// `ExpressionIdentifierOpt /* Option<T>::None */: ;`
     ///
@@ -21248,7 +22004,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 372:
+    /// Semantic action for production 384:
     ///
     /// `Expression: Expression01 ExpressionList /* Vec */;`
     ///
@@ -21272,7 +22028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 373:
+    /// Semantic action for production 385:
     ///
     /// `ExpressionList /* Vec<T>::Push */: Operator01 Expression01 ExpressionList;`
     ///
@@ -21298,7 +22054,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 374:
+    /// Semantic action for production 386:
     ///
     /// `ExpressionList /* Vec<T>::New */: ;`
     ///
@@ -21311,7 +22067,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 375:
+    /// Semantic action for production 387:
     ///
     /// `Expression01: Expression02 Expression01List /* Vec */;`
     ///
@@ -21336,7 +22092,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 376:
+    /// Semantic action for production 388:
     ///
     /// `Expression01List /* Vec<T>::Push */: Operator02 Expression02 Expression01List;`
     ///
@@ -21362,7 +22118,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 377:
+    /// Semantic action for production 389:
     ///
     /// `Expression01List /* Vec<T>::New */: ;`
     ///
@@ -21378,7 +22134,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 378:
+    /// Semantic action for production 390:
     ///
     /// `Expression02: Expression03 Expression02List /* Vec */;`
     ///
@@ -21403,7 +22159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 379:
+    /// Semantic action for production 391:
     ///
     /// `Expression02List /* Vec<T>::Push */: Operator03 Expression03 Expression02List;`
     ///
@@ -21429,7 +22185,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 380:
+    /// Semantic action for production 392:
     ///
     /// `Expression02List /* Vec<T>::New */: ;`
     ///
@@ -21445,7 +22201,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 381:
+    /// Semantic action for production 393:
     ///
     /// `Expression03: Expression04 Expression03List /* Vec */;`
     ///
@@ -21470,7 +22226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 382:
+    /// Semantic action for production 394:
     ///
     /// `Expression03List /* Vec<T>::Push */: Operator04 Expression04 Expression03List;`
     ///
@@ -21496,7 +22252,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 383:
+    /// Semantic action for production 395:
     ///
     /// `Expression03List /* Vec<T>::New */: ;`
     ///
@@ -21512,7 +22268,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 384:
+    /// Semantic action for production 396:
     ///
     /// `Expression04: Expression05 Expression04List /* Vec */;`
     ///
@@ -21537,7 +22293,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 385:
+    /// Semantic action for production 397:
     ///
     /// `Expression04List /* Vec<T>::Push */: Operator05 Expression05 Expression04List;`
     ///
@@ -21563,7 +22319,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 386:
+    /// Semantic action for production 398:
     ///
     /// `Expression04List /* Vec<T>::New */: ;`
     ///
@@ -21579,7 +22335,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 387:
+    /// Semantic action for production 399:
     ///
     /// `Expression05: Expression06 Expression05List /* Vec */;`
     ///
@@ -21604,7 +22360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 388:
+    /// Semantic action for production 400:
     ///
     /// `Expression05List /* Vec<T>::Push */: Operator06 Expression06 Expression05List;`
     ///
@@ -21630,7 +22386,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 389:
+    /// Semantic action for production 401:
     ///
     /// `Expression05List /* Vec<T>::New */: ;`
     ///
@@ -21646,7 +22402,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 390:
+    /// Semantic action for production 402:
     ///
     /// `Expression06: Expression07 Expression06List /* Vec */;`
     ///
@@ -21671,7 +22427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 391:
+    /// Semantic action for production 403:
     ///
     /// `Expression06List /* Vec<T>::Push */: Operator07 Expression07 Expression06List;`
     ///
@@ -21697,7 +22453,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 392:
+    /// Semantic action for production 404:
     ///
     /// `Expression06List /* Vec<T>::New */: ;`
     ///
@@ -21713,7 +22469,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 393:
+    /// Semantic action for production 405:
     ///
     /// `Expression07: Expression08 Expression07List /* Vec */;`
     ///
@@ -21738,7 +22494,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 394:
+    /// Semantic action for production 406:
     ///
     /// `Expression07List /* Vec<T>::Push */: Operator08 Expression08 Expression07List;`
     ///
@@ -21764,7 +22520,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 395:
+    /// Semantic action for production 407:
     ///
     /// `Expression07List /* Vec<T>::New */: ;`
     ///
@@ -21780,7 +22536,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 396:
+    /// Semantic action for production 408:
     ///
     /// `Expression08: Expression09 Expression08List /* Vec */;`
     ///
@@ -21805,7 +22561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 397:
+    /// Semantic action for production 409:
     ///
     /// `Expression08List /* Vec<T>::Push */: Operator09 Expression09 Expression08List;`
     ///
@@ -21831,7 +22587,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 398:
+    /// Semantic action for production 410:
     ///
     /// `Expression08List /* Vec<T>::New */: ;`
     ///
@@ -21847,7 +22603,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 399:
+    /// Semantic action for production 411:
     ///
     /// `Expression09: Expression10 Expression09List /* Vec */;`
     ///
@@ -21872,7 +22628,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 400:
+    /// Semantic action for production 412:
     ///
     /// `Expression09List /* Vec<T>::Push */: Expression09ListGroup Expression10 Expression09List;`
     ///
@@ -21903,7 +22659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 401:
+    /// Semantic action for production 413:
     ///
     /// `Expression09ListGroup: Operator10;`
     ///
@@ -21924,7 +22680,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 402:
+    /// Semantic action for production 414:
     ///
     /// `Expression09ListGroup: Star;`
     ///
@@ -21945,7 +22701,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 403:
+    /// Semantic action for production 415:
     ///
     /// `Expression09List /* Vec<T>::New */: ;`
     ///
@@ -21961,7 +22717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 404:
+    /// Semantic action for production 416:
     ///
     /// `Expression10: Expression11 Expression10List /* Vec */;`
     ///
@@ -21986,7 +22742,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 405:
+    /// Semantic action for production 417:
     ///
     /// `Expression10List /* Vec<T>::Push */: Operator11 Expression11 Expression10List;`
     ///
@@ -22012,7 +22768,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 406:
+    /// Semantic action for production 418:
     ///
     /// `Expression10List /* Vec<T>::New */: ;`
     ///
@@ -22028,7 +22784,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 407:
+    /// Semantic action for production 419:
     ///
     /// `Expression11: Expression12 Expression11Opt /* Option */;`
     ///
@@ -22052,7 +22808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 408:
+    /// Semantic action for production 420:
     ///
     /// `Expression11Opt /* Option<T>::Some */: As CastingType;`
     ///
@@ -22077,7 +22833,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 409:
+    /// Semantic action for production 421:
     ///
     /// `Expression11Opt /* Option<T>::None */: ;`
     ///
@@ -22089,7 +22845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 410:
+    /// Semantic action for production 422:
     ///
     /// `Expression12: Expression12List /* Vec */ Factor;`
     ///
@@ -22114,7 +22870,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 411:
+    /// Semantic action for production 423:
     ///
     /// `Expression12List /* Vec<T>::Push */: Expression12ListGroup Expression12List;`
     ///
@@ -22142,7 +22898,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 412:
+    /// Semantic action for production 424:
     ///
     /// `Expression12ListGroup: UnaryOperator;`
     ///
@@ -22163,7 +22919,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 413:
+    /// Semantic action for production 425:
     ///
     /// `Expression12ListGroup: Operator09;`
     ///
@@ -22184,7 +22940,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 414:
+    /// Semantic action for production 426:
     ///
     /// `Expression12ListGroup: Operator05;`
     ///
@@ -22205,7 +22961,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 415:
+    /// Semantic action for production 427:
     ///
     /// `Expression12ListGroup: Operator03;`
     ///
@@ -22226,7 +22982,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 416:
+    /// Semantic action for production 428:
     ///
     /// `Expression12ListGroup: Operator04;`
     ///
@@ -22247,7 +23003,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 417:
+    /// Semantic action for production 429:
     ///
     /// `Expression12List /* Vec<T>::New */: ;`
     ///
@@ -22263,7 +23019,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 418:
+    /// Semantic action for production 430:
     ///
     /// `Factor: Number;`
     ///
@@ -22282,7 +23038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 419:
+    /// Semantic action for production 431:
     ///
     /// `Factor: IdentifierFactor;`
     ///
@@ -22301,7 +23057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 420:
+    /// Semantic action for production 432:
     ///
     /// `Factor: LParen Expression RParen;`
     ///
@@ -22329,7 +23085,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 421:
+    /// Semantic action for production 433:
     ///
     /// `Factor: LBrace ConcatenationList RBrace;`
     ///
@@ -22357,7 +23113,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 422:
+    /// Semantic action for production 434:
     ///
     /// `Factor: QuoteLBrace ArrayLiteralList RBrace;`
     ///
@@ -22385,7 +23141,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 423:
+    /// Semantic action for production 435:
     ///
     /// `Factor: IfExpression;`
     ///
@@ -22404,7 +23160,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 424:
+    /// Semantic action for production 436:
     ///
     /// `Factor: CaseExpression;`
     ///
@@ -22423,7 +23179,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 425:
+    /// Semantic action for production 437:
     ///
     /// `Factor: SwitchExpression;`
     ///
@@ -22442,7 +23198,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 426:
+    /// Semantic action for production 438:
     ///
     /// `Factor: StringLiteral;`
     ///
@@ -22461,7 +23217,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 427:
+    /// Semantic action for production 439:
     ///
     /// `Factor: FactorGroup;`
     ///
@@ -22480,7 +23236,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 428:
+    /// Semantic action for production 440:
     ///
     /// `FactorGroup: Msb;`
     ///
@@ -22495,7 +23251,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 429:
+    /// Semantic action for production 441:
     ///
     /// `FactorGroup: Lsb;`
     ///
@@ -22510,7 +23266,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 430:
+    /// Semantic action for production 442:
     ///
     /// `Factor: InsideExpression;`
     ///
@@ -22529,7 +23285,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 431:
+    /// Semantic action for production 443:
     ///
     /// `Factor: OutsideExpression;`
     ///
@@ -22548,7 +23304,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 432:
+    /// Semantic action for production 444:
     ///
     /// `Factor: TypeExpression;`
     ///
@@ -22567,7 +23323,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 433:
+    /// Semantic action for production 445:
     ///
     /// `Factor: FactorType;`
     ///
@@ -22586,7 +23342,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 434:
+    /// Semantic action for production 446:
     ///
     /// `IdentifierFactor: ExpressionIdentifier IdentifierFactorOpt /* Option */;`
     ///
@@ -22613,17 +23369,25 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 435:
+    /// Semantic action for production 447:
     ///
-    /// `IdentifierFactorOpt /* Option<T>::Some */: FunctionCall;`
+    /// `IdentifierFactorOpt /* Option<T>::Some */: IdentifierFactorOptGroup;`
     ///
     #[parol_runtime::function_name::named]
-    fn identifier_factor_opt_0(&mut self, _function_call: &ParseTreeType<'t>) -> Result<()> {
+    fn identifier_factor_opt_0(
+        &mut self,
+        _identifier_factor_opt_group: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let function_call = pop_item!(self, function_call, FunctionCall, context);
+        let identifier_factor_opt_group = pop_item!(
+            self,
+            identifier_factor_opt_group,
+            IdentifierFactorOptGroup,
+            context
+        );
         let identifier_factor_opt_0_built = IdentifierFactorOpt {
-            function_call: Box::new(function_call),
+            identifier_factor_opt_group: Box::new(identifier_factor_opt_group),
         };
         self.push(
             ASTType::IdentifierFactorOpt(Some(identifier_factor_opt_0_built)),
@@ -22632,7 +23396,52 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 436:
+    /// Semantic action for production 448:
+    ///
+    /// `IdentifierFactorOptGroup: FunctionCall;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn identifier_factor_opt_group_0(&mut self, _function_call: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let function_call = pop_item!(self, function_call, FunctionCall, context);
+        let identifier_factor_opt_group_0_built = IdentifierFactorOptGroupFunctionCall {
+            function_call: Box::new(function_call),
+        };
+        let identifier_factor_opt_group_0_built =
+            IdentifierFactorOptGroup::FunctionCall(identifier_factor_opt_group_0_built);
+        self.push(
+            ASTType::IdentifierFactorOptGroup(identifier_factor_opt_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 449:
+    ///
+    /// `IdentifierFactorOptGroup: StructConstructor;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn identifier_factor_opt_group_1(
+        &mut self,
+        _struct_constructor: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let struct_constructor = pop_item!(self, struct_constructor, StructConstructor, context);
+        let identifier_factor_opt_group_1_built = IdentifierFactorOptGroupStructConstructor {
+            struct_constructor: Box::new(struct_constructor),
+        };
+        let identifier_factor_opt_group_1_built =
+            IdentifierFactorOptGroup::StructConstructor(identifier_factor_opt_group_1_built);
+        self.push(
+            ASTType::IdentifierFactorOptGroup(identifier_factor_opt_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 450:
     ///
     /// `IdentifierFactorOpt /* Option<T>::None */: ;`
     ///
@@ -22644,7 +23453,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 437:
+    /// Semantic action for production 451:
     ///
     /// `FunctionCall: LParen FunctionCallOpt /* Option */ RParen;`
     ///
@@ -22671,7 +23480,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 438:
+    /// Semantic action for production 452:
     ///
     /// `FunctionCallOpt /* Option<T>::Some */: ArgumentList;`
     ///
@@ -22690,7 +23499,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 439:
+    /// Semantic action for production 453:
     ///
     /// `FunctionCallOpt /* Option<T>::None */: ;`
     ///
@@ -22702,7 +23511,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 440:
+    /// Semantic action for production 454:
     ///
     /// `ArgumentList: ArgumentItem ArgumentListList /* Vec */ ArgumentListOpt /* Option */;`
     ///
@@ -22730,7 +23539,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 441:
+    /// Semantic action for production 455:
     ///
     /// `ArgumentListList /* Vec<T>::Push */: Comma ArgumentItem ArgumentListList;`
     ///
@@ -22756,7 +23565,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 442:
+    /// Semantic action for production 456:
     ///
     /// `ArgumentListList /* Vec<T>::New */: ;`
     ///
@@ -22772,7 +23581,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 443:
+    /// Semantic action for production 457:
     ///
     /// `ArgumentListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -22791,7 +23600,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 444:
+    /// Semantic action for production 458:
     ///
     /// `ArgumentListOpt /* Option<T>::None */: ;`
     ///
@@ -22803,7 +23612,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 445:
+    /// Semantic action for production 459:
     ///
     /// `ArgumentItem: Expression;`
     ///
@@ -22821,7 +23630,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 446:
+    /// Semantic action for production 460:
     ///
     /// `ConcatenationList: ConcatenationItem ConcatenationListList /* Vec */ ConcatenationListOpt /* Option */;`
     ///
@@ -22858,7 +23667,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 447:
+    /// Semantic action for production 461:
     ///
     /// `ConcatenationListList /* Vec<T>::Push */: Comma ConcatenationItem ConcatenationListList;`
     ///
@@ -22892,7 +23701,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 448:
+    /// Semantic action for production 462:
     ///
     /// `ConcatenationListList /* Vec<T>::New */: ;`
     ///
@@ -22908,7 +23717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 449:
+    /// Semantic action for production 463:
     ///
     /// `ConcatenationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -22927,7 +23736,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 450:
+    /// Semantic action for production 464:
     ///
     /// `ConcatenationListOpt /* Option<T>::None */: ;`
     ///
@@ -22939,7 +23748,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 451:
+    /// Semantic action for production 465:
     ///
     /// `ConcatenationItem: Expression ConcatenationItemOpt /* Option */;`
     ///
@@ -22968,7 +23777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 452:
+    /// Semantic action for production 466:
     ///
     /// `ConcatenationItemOpt /* Option<T>::Some */: Repeat Expression;`
     ///
@@ -22993,7 +23802,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 453:
+    /// Semantic action for production 467:
     ///
     /// `ConcatenationItemOpt /* Option<T>::None */: ;`
     ///
@@ -23005,7 +23814,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 454:
+    /// Semantic action for production 468:
     ///
     /// `ArrayLiteralList: ArrayLiteralItem ArrayLiteralListList /* Vec */ ArrayLiteralListOpt /* Option */;`
     ///
@@ -23035,7 +23844,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 455:
+    /// Semantic action for production 469:
     ///
     /// `ArrayLiteralListList /* Vec<T>::Push */: Comma ArrayLiteralItem ArrayLiteralListList;`
     ///
@@ -23065,7 +23874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 456:
+    /// Semantic action for production 470:
     ///
     /// `ArrayLiteralListList /* Vec<T>::New */: ;`
     ///
@@ -23081,7 +23890,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 457:
+    /// Semantic action for production 471:
     ///
     /// `ArrayLiteralListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -23100,7 +23909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 458:
+    /// Semantic action for production 472:
     ///
     /// `ArrayLiteralListOpt /* Option<T>::None */: ;`
     ///
@@ -23112,7 +23921,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 459:
+    /// Semantic action for production 473:
     ///
     /// `ArrayLiteralItem: ArrayLiteralItemGroup;`
     ///
@@ -23136,7 +23945,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 460:
+    /// Semantic action for production 474:
     ///
     /// `ArrayLiteralItemGroup: Expression ArrayLiteralItemOpt /* Option */;`
     ///
@@ -23164,7 +23973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 461:
+    /// Semantic action for production 475:
     ///
     /// `ArrayLiteralItemGroup: Defaul Colon Expression;`
     ///
@@ -23194,7 +24003,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 462:
+    /// Semantic action for production 476:
     ///
     /// `ArrayLiteralItemOpt /* Option<T>::Some */: Repeat Expression;`
     ///
@@ -23219,7 +24028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 463:
+    /// Semantic action for production 477:
     ///
     /// `ArrayLiteralItemOpt /* Option<T>::None */: ;`
     ///
@@ -23231,7 +24040,256 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 464:
+    /// Semantic action for production 478:
+    ///
+    /// `StructConstructor: QuoteLBrace StructConstructorList StructConstructorOpt /* Option */ RBrace;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor(
+        &mut self,
+        _quote_l_brace: &ParseTreeType<'t>,
+        _struct_constructor_list: &ParseTreeType<'t>,
+        _struct_constructor_opt: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let struct_constructor_opt =
+            pop_item!(self, struct_constructor_opt, StructConstructorOpt, context);
+        let struct_constructor_list = pop_item!(
+            self,
+            struct_constructor_list,
+            StructConstructorList,
+            context
+        );
+        let quote_l_brace = pop_item!(self, quote_l_brace, QuoteLBrace, context);
+        let struct_constructor_built = StructConstructor {
+            quote_l_brace: Box::new(quote_l_brace),
+            struct_constructor_list: Box::new(struct_constructor_list),
+            struct_constructor_opt,
+            r_brace: Box::new(r_brace),
+        };
+        // Calling user action here
+        self.user_grammar
+            .struct_constructor(&struct_constructor_built)?;
+        self.push(
+            ASTType::StructConstructor(struct_constructor_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 479:
+    ///
+    /// `StructConstructorOpt /* Option<T>::Some */: DotDot Defaul LParen Expression RParen;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_opt_0(
+        &mut self,
+        _dot_dot: &ParseTreeType<'t>,
+        _defaul: &ParseTreeType<'t>,
+        _l_paren: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let defaul = pop_item!(self, defaul, Defaul, context);
+        let dot_dot = pop_item!(self, dot_dot, DotDot, context);
+        let struct_constructor_opt_0_built = StructConstructorOpt {
+            dot_dot: Box::new(dot_dot),
+            defaul: Box::new(defaul),
+            l_paren: Box::new(l_paren),
+            expression: Box::new(expression),
+            r_paren: Box::new(r_paren),
+        };
+        self.push(
+            ASTType::StructConstructorOpt(Some(struct_constructor_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 480:
+    ///
+    /// `StructConstructorOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::StructConstructorOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 481:
+    ///
+    /// `StructConstructorList: StructConstructorItem StructConstructorListList /* Vec */ StructConstructorListOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_list(
+        &mut self,
+        _struct_constructor_item: &ParseTreeType<'t>,
+        _struct_constructor_list_list: &ParseTreeType<'t>,
+        _struct_constructor_list_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let struct_constructor_list_opt = pop_item!(
+            self,
+            struct_constructor_list_opt,
+            StructConstructorListOpt,
+            context
+        );
+        let struct_constructor_list_list = pop_and_reverse_item!(
+            self,
+            struct_constructor_list_list,
+            StructConstructorListList,
+            context
+        );
+        let struct_constructor_item = pop_item!(
+            self,
+            struct_constructor_item,
+            StructConstructorItem,
+            context
+        );
+        let struct_constructor_list_built = StructConstructorList {
+            struct_constructor_item: Box::new(struct_constructor_item),
+            struct_constructor_list_list,
+            struct_constructor_list_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .struct_constructor_list(&struct_constructor_list_built)?;
+        self.push(
+            ASTType::StructConstructorList(struct_constructor_list_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 482:
+    ///
+    /// `StructConstructorListList /* Vec<T>::Push */: Comma StructConstructorItem StructConstructorListList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_list_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _struct_constructor_item: &ParseTreeType<'t>,
+        _struct_constructor_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut struct_constructor_list_list = pop_item!(
+            self,
+            struct_constructor_list_list,
+            StructConstructorListList,
+            context
+        );
+        let struct_constructor_item = pop_item!(
+            self,
+            struct_constructor_item,
+            StructConstructorItem,
+            context
+        );
+        let comma = pop_item!(self, comma, Comma, context);
+        let struct_constructor_list_list_0_built = StructConstructorListList {
+            struct_constructor_item: Box::new(struct_constructor_item),
+            comma: Box::new(comma),
+        };
+        // Add an element to the vector
+        struct_constructor_list_list.push(struct_constructor_list_list_0_built);
+        self.push(
+            ASTType::StructConstructorListList(struct_constructor_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 483:
+    ///
+    /// `StructConstructorListList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_list_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let struct_constructor_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::StructConstructorListList(struct_constructor_list_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 484:
+    ///
+    /// `StructConstructorListOpt /* Option<T>::Some */: Comma;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comma = pop_item!(self, comma, Comma, context);
+        let struct_constructor_list_opt_0_built = StructConstructorListOpt {
+            comma: Box::new(comma),
+        };
+        self.push(
+            ASTType::StructConstructorListOpt(Some(struct_constructor_list_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 485:
+    ///
+    /// `StructConstructorListOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_list_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::StructConstructorListOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 486:
+    ///
+    /// `StructConstructorItem: Identifier Colon Expression;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn struct_constructor_item(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _colon: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression = pop_item!(self, expression, Expression, context);
+        let colon = pop_item!(self, colon, Colon, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let struct_constructor_item_built = StructConstructorItem {
+            identifier: Box::new(identifier),
+            colon: Box::new(colon),
+            expression: Box::new(expression),
+        };
+        // Calling user action here
+        self.user_grammar
+            .struct_constructor_item(&struct_constructor_item_built)?;
+        self.push(
+            ASTType::StructConstructorItem(struct_constructor_item_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 487:
     ///
     /// `IfExpression: If Expression LBrace Expression RBrace IfExpressionList /* Vec */ Else LBrace Expression RBrace;`
     ///
@@ -23280,7 +24338,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 465:
+    /// Semantic action for production 488:
     ///
     /// `IfExpressionList /* Vec<T>::Push */: Else If Expression LBrace Expression RBrace IfExpressionList;`
     ///
@@ -23318,7 +24376,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 466:
+    /// Semantic action for production 489:
     ///
     /// `IfExpressionList /* Vec<T>::New */: ;`
     ///
@@ -23334,7 +24392,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 467:
+    /// Semantic action for production 490:
     ///
     /// `CaseExpression: Case Expression LBrace CaseCondition Colon Expression Comma CaseExpressionList /* Vec */ Defaul Colon Expression CaseExpressionOpt /* Option */ RBrace;`
     ///
@@ -23392,7 +24450,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 468:
+    /// Semantic action for production 491:
     ///
     /// `CaseExpressionList /* Vec<T>::Push */: CaseCondition Colon Expression Comma CaseExpressionList;`
     ///
@@ -23425,7 +24483,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 469:
+    /// Semantic action for production 492:
     ///
     /// `CaseExpressionList /* Vec<T>::New */: ;`
     ///
@@ -23441,7 +24499,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 470:
+    /// Semantic action for production 493:
     ///
     /// `CaseExpressionOpt /* Option<T>::Some */: Comma;`
     ///
@@ -23460,7 +24518,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 471:
+    /// Semantic action for production 494:
     ///
     /// `CaseExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -23472,7 +24530,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 472:
+    /// Semantic action for production 495:
     ///
     /// `SwitchExpression: Switch LBrace SwitchCondition Colon Expression Comma SwitchExpressionList /* Vec */ Defaul Colon Expression SwitchExpressionOpt /* Option */ RBrace;`
     ///
@@ -23529,7 +24587,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 473:
+    /// Semantic action for production 496:
     ///
     /// `SwitchExpressionList /* Vec<T>::Push */: SwitchCondition Colon Expression Comma SwitchExpressionList;`
     ///
@@ -23565,7 +24623,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 474:
+    /// Semantic action for production 497:
     ///
     /// `SwitchExpressionList /* Vec<T>::New */: ;`
     ///
@@ -23581,7 +24639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 475:
+    /// Semantic action for production 498:
     ///
     /// `SwitchExpressionOpt /* Option<T>::Some */: Comma;`
     ///
@@ -23600,7 +24658,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 476:
+    /// Semantic action for production 499:
     ///
     /// `SwitchExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -23612,7 +24670,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 477:
+    /// Semantic action for production 500:
     ///
     /// `TypeExpression: Type LParen Expression RParen;`
     ///
@@ -23642,7 +24700,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 478:
+    /// Semantic action for production 501:
     ///
     /// `InsideExpression: Inside Expression LBrace RangeList RBrace;`
     ///
@@ -23676,7 +24734,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 479:
+    /// Semantic action for production 502:
     ///
     /// `OutsideExpression: Outside Expression LBrace RangeList RBrace;`
     ///
@@ -23713,7 +24771,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 480:
+    /// Semantic action for production 503:
     ///
     /// `RangeList: RangeItem RangeListList /* Vec */ RangeListOpt /* Option */;`
     ///
@@ -23740,7 +24798,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 481:
+    /// Semantic action for production 504:
     ///
     /// `RangeListList /* Vec<T>::Push */: Comma RangeItem RangeListList;`
     ///
@@ -23766,7 +24824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 482:
+    /// Semantic action for production 505:
     ///
     /// `RangeListList /* Vec<T>::New */: ;`
     ///
@@ -23779,7 +24837,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 483:
+    /// Semantic action for production 506:
     ///
     /// `RangeListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -23795,7 +24853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 484:
+    /// Semantic action for production 507:
     ///
     /// `RangeListOpt /* Option<T>::None */: ;`
     ///
@@ -23807,7 +24865,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 485:
+    /// Semantic action for production 508:
     ///
     /// `RangeItem: Range;`
     ///
@@ -23825,7 +24883,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 486:
+    /// Semantic action for production 509:
     ///
     /// `Select: LBracket Expression SelectOpt /* Option */ RBracket;`
     ///
@@ -23855,7 +24913,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 487:
+    /// Semantic action for production 510:
     ///
     /// `SelectOpt /* Option<T>::Some */: SelectOperator Expression;`
     ///
@@ -23877,7 +24935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 488:
+    /// Semantic action for production 511:
     ///
     /// `SelectOpt /* Option<T>::None */: ;`
     ///
@@ -23889,7 +24947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 489:
+    /// Semantic action for production 512:
     ///
     /// `SelectOperator: Colon;`
     ///
@@ -23909,7 +24967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 490:
+    /// Semantic action for production 513:
     ///
     /// `SelectOperator: PlusColon;`
     ///
@@ -23929,7 +24987,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 491:
+    /// Semantic action for production 514:
     ///
     /// `SelectOperator: MinusColon;`
     ///
@@ -23949,7 +25007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 492:
+    /// Semantic action for production 515:
     ///
     /// `SelectOperator: Step;`
     ///
@@ -23969,7 +25027,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 493:
+    /// Semantic action for production 516:
     ///
     /// `Width: LAngle Expression WidthList /* Vec */ RAngle;`
     ///
@@ -23999,7 +25057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 494:
+    /// Semantic action for production 517:
     ///
     /// `WidthList /* Vec<T>::Push */: Comma Expression WidthList;`
     ///
@@ -24025,7 +25083,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 495:
+    /// Semantic action for production 518:
     ///
     /// `WidthList /* Vec<T>::New */: ;`
     ///
@@ -24038,7 +25096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 496:
+    /// Semantic action for production 519:
     ///
     /// `Array: LBracket Expression ArrayList /* Vec */ RBracket;`
     ///
@@ -24068,7 +25126,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 497:
+    /// Semantic action for production 520:
     ///
     /// `ArrayList /* Vec<T>::Push */: Comma Expression ArrayList;`
     ///
@@ -24094,7 +25152,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 498:
+    /// Semantic action for production 521:
     ///
     /// `ArrayList /* Vec<T>::New */: ;`
     ///
@@ -24107,7 +25165,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 499:
+    /// Semantic action for production 522:
     ///
     /// `Range: Expression RangeOpt /* Option */;`
     ///
@@ -24131,7 +25189,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 500:
+    /// Semantic action for production 523:
     ///
     /// `RangeOpt /* Option<T>::Some */: RangeOperator Expression;`
     ///
@@ -24153,7 +25211,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 501:
+    /// Semantic action for production 524:
     ///
     /// `RangeOpt /* Option<T>::None */: ;`
     ///
@@ -24165,7 +25223,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 502:
+    /// Semantic action for production 525:
     ///
     /// `RangeOperator: DotDot;`
     ///
@@ -24184,7 +25242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 503:
+    /// Semantic action for production 526:
     ///
     /// `RangeOperator: DotDotEqu;`
     ///
@@ -24203,7 +25261,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 504:
+    /// Semantic action for production 527:
     ///
     /// `FixedType: U32;`
     ///
@@ -24220,7 +25278,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 505:
+    /// Semantic action for production 528:
     ///
     /// `FixedType: U64;`
     ///
@@ -24237,7 +25295,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 506:
+    /// Semantic action for production 529:
     ///
     /// `FixedType: I32;`
     ///
@@ -24254,7 +25312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 507:
+    /// Semantic action for production 530:
     ///
     /// `FixedType: I64;`
     ///
@@ -24271,7 +25329,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 508:
+    /// Semantic action for production 531:
     ///
     /// `FixedType: F32;`
     ///
@@ -24288,7 +25346,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 509:
+    /// Semantic action for production 532:
     ///
     /// `FixedType: F64;`
     ///
@@ -24305,7 +25363,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 510:
+    /// Semantic action for production 533:
     ///
     /// `FixedType: Strin;`
     ///
@@ -24324,7 +25382,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 511:
+    /// Semantic action for production 534:
     ///
     /// `VariableType: Clock;`
     ///
@@ -24343,7 +25401,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 512:
+    /// Semantic action for production 535:
     ///
     /// `VariableType: ClockPosedge;`
     ///
@@ -24362,7 +25420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 513:
+    /// Semantic action for production 536:
     ///
     /// `VariableType: ClockNegedge;`
     ///
@@ -24381,7 +25439,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 514:
+    /// Semantic action for production 537:
     ///
     /// `VariableType: Reset;`
     ///
@@ -24400,7 +25458,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 515:
+    /// Semantic action for production 538:
     ///
     /// `VariableType: ResetAsyncHigh;`
     ///
@@ -24419,7 +25477,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 516:
+    /// Semantic action for production 539:
     ///
     /// `VariableType: ResetAsyncLow;`
     ///
@@ -24438,7 +25496,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 517:
+    /// Semantic action for production 540:
     ///
     /// `VariableType: ResetSyncHigh;`
     ///
@@ -24457,7 +25515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 518:
+    /// Semantic action for production 541:
     ///
     /// `VariableType: ResetSyncLow;`
     ///
@@ -24476,7 +25534,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 519:
+    /// Semantic action for production 542:
     ///
     /// `VariableType: Logic;`
     ///
@@ -24495,7 +25553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 520:
+    /// Semantic action for production 543:
     ///
     /// `VariableType: Bit;`
     ///
@@ -24512,7 +25570,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 521:
+    /// Semantic action for production 544:
     ///
     /// `UserDefinedType: ScopedIdentifier;`
     ///
@@ -24531,7 +25589,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 522:
+    /// Semantic action for production 545:
     ///
     /// `TypeModifier: Tri;`
     ///
@@ -24548,7 +25606,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 523:
+    /// Semantic action for production 546:
     ///
     /// `TypeModifier: Signed;`
     ///
@@ -24567,7 +25625,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 524:
+    /// Semantic action for production 547:
     ///
     /// `FactorType: FactorTypeGroup;`
     ///
@@ -24585,7 +25643,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 525:
+    /// Semantic action for production 548:
     ///
     /// `FactorTypeGroup: VariableType FactorTypeOpt /* Option */;`
     ///
@@ -24609,7 +25667,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 526:
+    /// Semantic action for production 549:
     ///
     /// `FactorTypeGroup: FixedType;`
     ///
@@ -24626,7 +25684,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 527:
+    /// Semantic action for production 550:
     ///
     /// `FactorTypeOpt /* Option<T>::Some */: Width;`
     ///
@@ -24645,7 +25703,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 528:
+    /// Semantic action for production 551:
     ///
     /// `FactorTypeOpt /* Option<T>::None */: ;`
     ///
@@ -24657,7 +25715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 529:
+    /// Semantic action for production 552:
     ///
     /// `ScalarType: ScalarTypeList /* Vec */ ScalarTypeGroup;`
     ///
@@ -24682,7 +25740,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 530:
+    /// Semantic action for production 553:
     ///
     /// `ScalarTypeGroup: UserDefinedType ScalarTypeOpt /* Option */;`
     ///
@@ -24706,7 +25764,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 531:
+    /// Semantic action for production 554:
     ///
     /// `ScalarTypeGroup: FactorType;`
     ///
@@ -24723,7 +25781,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 532:
+    /// Semantic action for production 555:
     ///
     /// `ScalarTypeList /* Vec<T>::Push */: TypeModifier ScalarTypeList;`
     ///
@@ -24746,7 +25804,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 533:
+    /// Semantic action for production 556:
     ///
     /// `ScalarTypeList /* Vec<T>::New */: ;`
     ///
@@ -24759,7 +25817,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 534:
+    /// Semantic action for production 557:
     ///
     /// `ScalarTypeOpt /* Option<T>::Some */: Width;`
     ///
@@ -24778,7 +25836,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 535:
+    /// Semantic action for production 558:
     ///
     /// `ScalarTypeOpt /* Option<T>::None */: ;`
     ///
@@ -24790,7 +25848,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 536:
+    /// Semantic action for production 559:
     ///
     /// `ArrayType: ScalarType ArrayTypeOpt /* Option */;`
     ///
@@ -24814,7 +25872,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 537:
+    /// Semantic action for production 560:
     ///
     /// `ArrayTypeOpt /* Option<T>::Some */: Array;`
     ///
@@ -24830,7 +25888,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 538:
+    /// Semantic action for production 561:
     ///
     /// `ArrayTypeOpt /* Option<T>::None */: ;`
     ///
@@ -24842,7 +25900,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 539:
+    /// Semantic action for production 562:
     ///
     /// `CastingType: U32;`
     ///
@@ -24859,7 +25917,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 540:
+    /// Semantic action for production 563:
     ///
     /// `CastingType: U64;`
     ///
@@ -24876,7 +25934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 541:
+    /// Semantic action for production 564:
     ///
     /// `CastingType: I32;`
     ///
@@ -24893,7 +25951,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 542:
+    /// Semantic action for production 565:
     ///
     /// `CastingType: I64;`
     ///
@@ -24910,7 +25968,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 543:
+    /// Semantic action for production 566:
     ///
     /// `CastingType: F32;`
     ///
@@ -24927,7 +25985,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 544:
+    /// Semantic action for production 567:
     ///
     /// `CastingType: F64;`
     ///
@@ -24944,216 +26002,252 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 545:
+    /// Semantic action for production 568:
+    ///
+    /// `CastingType: Bit;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn casting_type_6(&mut self, _bit: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let bit = pop_item!(self, bit, Bit, context);
+        let casting_type_6_built = CastingTypeBit { bit: Box::new(bit) };
+        let casting_type_6_built = CastingType::Bit(casting_type_6_built);
+        // Calling user action here
+        self.user_grammar.casting_type(&casting_type_6_built)?;
+        self.push(ASTType::CastingType(casting_type_6_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 569:
+    ///
+    /// `CastingType: Logic;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn casting_type_7(&mut self, _logic: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let logic = pop_item!(self, logic, Logic, context);
+        let casting_type_7_built = CastingTypeLogic {
+            logic: Box::new(logic),
+        };
+        let casting_type_7_built = CastingType::Logic(casting_type_7_built);
+        // Calling user action here
+        self.user_grammar.casting_type(&casting_type_7_built)?;
+        self.push(ASTType::CastingType(casting_type_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 570:
     ///
     /// `CastingType: Clock;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_6(&mut self, _clock: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_8(&mut self, _clock: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let clock = pop_item!(self, clock, Clock, context);
-        let casting_type_6_built = CastingTypeClock {
+        let casting_type_8_built = CastingTypeClock {
             clock: Box::new(clock),
         };
-        let casting_type_6_built = CastingType::Clock(casting_type_6_built);
+        let casting_type_8_built = CastingType::Clock(casting_type_8_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_6_built)?;
-        self.push(ASTType::CastingType(casting_type_6_built), context);
+        self.user_grammar.casting_type(&casting_type_8_built)?;
+        self.push(ASTType::CastingType(casting_type_8_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 546:
+    /// Semantic action for production 571:
     ///
     /// `CastingType: ClockPosedge;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_7(&mut self, _clock_posedge: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_9(&mut self, _clock_posedge: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let clock_posedge = pop_item!(self, clock_posedge, ClockPosedge, context);
-        let casting_type_7_built = CastingTypeClockPosedge {
+        let casting_type_9_built = CastingTypeClockPosedge {
             clock_posedge: Box::new(clock_posedge),
         };
-        let casting_type_7_built = CastingType::ClockPosedge(casting_type_7_built);
+        let casting_type_9_built = CastingType::ClockPosedge(casting_type_9_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_7_built)?;
-        self.push(ASTType::CastingType(casting_type_7_built), context);
+        self.user_grammar.casting_type(&casting_type_9_built)?;
+        self.push(ASTType::CastingType(casting_type_9_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 547:
+    /// Semantic action for production 572:
     ///
     /// `CastingType: ClockNegedge;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_8(&mut self, _clock_negedge: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_10(&mut self, _clock_negedge: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let clock_negedge = pop_item!(self, clock_negedge, ClockNegedge, context);
-        let casting_type_8_built = CastingTypeClockNegedge {
+        let casting_type_10_built = CastingTypeClockNegedge {
             clock_negedge: Box::new(clock_negedge),
         };
-        let casting_type_8_built = CastingType::ClockNegedge(casting_type_8_built);
+        let casting_type_10_built = CastingType::ClockNegedge(casting_type_10_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_8_built)?;
-        self.push(ASTType::CastingType(casting_type_8_built), context);
+        self.user_grammar.casting_type(&casting_type_10_built)?;
+        self.push(ASTType::CastingType(casting_type_10_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 548:
+    /// Semantic action for production 573:
     ///
     /// `CastingType: Reset;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_9(&mut self, _reset: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_11(&mut self, _reset: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reset = pop_item!(self, reset, Reset, context);
-        let casting_type_9_built = CastingTypeReset {
+        let casting_type_11_built = CastingTypeReset {
             reset: Box::new(reset),
         };
-        let casting_type_9_built = CastingType::Reset(casting_type_9_built);
+        let casting_type_11_built = CastingType::Reset(casting_type_11_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_9_built)?;
-        self.push(ASTType::CastingType(casting_type_9_built), context);
+        self.user_grammar.casting_type(&casting_type_11_built)?;
+        self.push(ASTType::CastingType(casting_type_11_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 549:
+    /// Semantic action for production 574:
     ///
     /// `CastingType: ResetAsyncHigh;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_10(&mut self, _reset_async_high: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_12(&mut self, _reset_async_high: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reset_async_high = pop_item!(self, reset_async_high, ResetAsyncHigh, context);
-        let casting_type_10_built = CastingTypeResetAsyncHigh {
+        let casting_type_12_built = CastingTypeResetAsyncHigh {
             reset_async_high: Box::new(reset_async_high),
         };
-        let casting_type_10_built = CastingType::ResetAsyncHigh(casting_type_10_built);
+        let casting_type_12_built = CastingType::ResetAsyncHigh(casting_type_12_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_10_built)?;
-        self.push(ASTType::CastingType(casting_type_10_built), context);
+        self.user_grammar.casting_type(&casting_type_12_built)?;
+        self.push(ASTType::CastingType(casting_type_12_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 550:
+    /// Semantic action for production 575:
     ///
     /// `CastingType: ResetAsyncLow;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_11(&mut self, _reset_async_low: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_13(&mut self, _reset_async_low: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reset_async_low = pop_item!(self, reset_async_low, ResetAsyncLow, context);
-        let casting_type_11_built = CastingTypeResetAsyncLow {
+        let casting_type_13_built = CastingTypeResetAsyncLow {
             reset_async_low: Box::new(reset_async_low),
         };
-        let casting_type_11_built = CastingType::ResetAsyncLow(casting_type_11_built);
+        let casting_type_13_built = CastingType::ResetAsyncLow(casting_type_13_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_11_built)?;
-        self.push(ASTType::CastingType(casting_type_11_built), context);
+        self.user_grammar.casting_type(&casting_type_13_built)?;
+        self.push(ASTType::CastingType(casting_type_13_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 551:
+    /// Semantic action for production 576:
     ///
     /// `CastingType: ResetSyncHigh;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_12(&mut self, _reset_sync_high: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_14(&mut self, _reset_sync_high: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reset_sync_high = pop_item!(self, reset_sync_high, ResetSyncHigh, context);
-        let casting_type_12_built = CastingTypeResetSyncHigh {
+        let casting_type_14_built = CastingTypeResetSyncHigh {
             reset_sync_high: Box::new(reset_sync_high),
         };
-        let casting_type_12_built = CastingType::ResetSyncHigh(casting_type_12_built);
+        let casting_type_14_built = CastingType::ResetSyncHigh(casting_type_14_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_12_built)?;
-        self.push(ASTType::CastingType(casting_type_12_built), context);
+        self.user_grammar.casting_type(&casting_type_14_built)?;
+        self.push(ASTType::CastingType(casting_type_14_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 552:
+    /// Semantic action for production 577:
     ///
     /// `CastingType: ResetSyncLow;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_13(&mut self, _reset_sync_low: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_15(&mut self, _reset_sync_low: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reset_sync_low = pop_item!(self, reset_sync_low, ResetSyncLow, context);
-        let casting_type_13_built = CastingTypeResetSyncLow {
+        let casting_type_15_built = CastingTypeResetSyncLow {
             reset_sync_low: Box::new(reset_sync_low),
         };
-        let casting_type_13_built = CastingType::ResetSyncLow(casting_type_13_built);
+        let casting_type_15_built = CastingType::ResetSyncLow(casting_type_15_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_13_built)?;
-        self.push(ASTType::CastingType(casting_type_13_built), context);
+        self.user_grammar.casting_type(&casting_type_15_built)?;
+        self.push(ASTType::CastingType(casting_type_15_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 553:
+    /// Semantic action for production 578:
     ///
     /// `CastingType: UserDefinedType;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_14(&mut self, _user_defined_type: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_16(&mut self, _user_defined_type: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let user_defined_type = pop_item!(self, user_defined_type, UserDefinedType, context);
-        let casting_type_14_built = CastingTypeUserDefinedType {
+        let casting_type_16_built = CastingTypeUserDefinedType {
             user_defined_type: Box::new(user_defined_type),
         };
-        let casting_type_14_built = CastingType::UserDefinedType(casting_type_14_built);
+        let casting_type_16_built = CastingType::UserDefinedType(casting_type_16_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_14_built)?;
-        self.push(ASTType::CastingType(casting_type_14_built), context);
+        self.user_grammar.casting_type(&casting_type_16_built)?;
+        self.push(ASTType::CastingType(casting_type_16_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 554:
+    /// Semantic action for production 579:
     ///
     /// `CastingType: Based;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_15(&mut self, _based: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_17(&mut self, _based: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let based = pop_item!(self, based, Based, context);
-        let casting_type_15_built = CastingTypeBased {
+        let casting_type_17_built = CastingTypeBased {
             based: Box::new(based),
         };
-        let casting_type_15_built = CastingType::Based(casting_type_15_built);
+        let casting_type_17_built = CastingType::Based(casting_type_17_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_15_built)?;
-        self.push(ASTType::CastingType(casting_type_15_built), context);
+        self.user_grammar.casting_type(&casting_type_17_built)?;
+        self.push(ASTType::CastingType(casting_type_17_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 555:
+    /// Semantic action for production 580:
     ///
     /// `CastingType: BaseLess;`
     ///
     #[parol_runtime::function_name::named]
-    fn casting_type_16(&mut self, _base_less: &ParseTreeType<'t>) -> Result<()> {
+    fn casting_type_18(&mut self, _base_less: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let base_less = pop_item!(self, base_less, BaseLess, context);
-        let casting_type_16_built = CastingTypeBaseLess {
+        let casting_type_18_built = CastingTypeBaseLess {
             base_less: Box::new(base_less),
         };
-        let casting_type_16_built = CastingType::BaseLess(casting_type_16_built);
+        let casting_type_18_built = CastingType::BaseLess(casting_type_18_built);
         // Calling user action here
-        self.user_grammar.casting_type(&casting_type_16_built)?;
-        self.push(ASTType::CastingType(casting_type_16_built), context);
+        self.user_grammar.casting_type(&casting_type_18_built)?;
+        self.push(ASTType::CastingType(casting_type_18_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 556:
+    /// Semantic action for production 581:
     ///
     /// `ClockDomain: BackQuote Identifier;`
     ///
@@ -25177,7 +26271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 557:
+    /// Semantic action for production 582:
     ///
     /// `StatementBlock: LBrace StatementBlockList /* Vec */ RBrace;`
     ///
@@ -25205,7 +26299,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 558:
+    /// Semantic action for production 583:
     ///
     /// `StatementBlockList /* Vec<T>::Push */: StatementBlockGroup StatementBlockList;`
     ///
@@ -25230,7 +26324,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 559:
+    /// Semantic action for production 584:
     ///
     /// `StatementBlockList /* Vec<T>::New */: ;`
     ///
@@ -25246,7 +26340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 560:
+    /// Semantic action for production 585:
     ///
     /// `StatementBlockGroup: StatementBlockGroupList /* Vec */ StatementBlockGroupGroup;`
     ///
@@ -25284,7 +26378,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 561:
+    /// Semantic action for production 586:
     ///
     /// `StatementBlockGroupGroup: LBrace StatementBlockGroupGroupList /* Vec */ RBrace;`
     ///
@@ -25322,7 +26416,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 562:
+    /// Semantic action for production 587:
     ///
     /// `StatementBlockGroupGroupList /* Vec<T>::Push */: StatementBlockGroup StatementBlockGroupGroupList;`
     ///
@@ -25354,7 +26448,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 563:
+    /// Semantic action for production 588:
     ///
     /// `StatementBlockGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -25370,7 +26464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 564:
+    /// Semantic action for production 589:
     ///
     /// `StatementBlockGroupGroup: StatementBlockItem;`
     ///
@@ -25395,7 +26489,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 565:
+    /// Semantic action for production 590:
     ///
     /// `StatementBlockGroupList /* Vec<T>::Push */: Attribute StatementBlockGroupList;`
     ///
@@ -25426,7 +26520,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 566:
+    /// Semantic action for production 591:
     ///
     /// `StatementBlockGroupList /* Vec<T>::New */: ;`
     ///
@@ -25442,7 +26536,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 567:
+    /// Semantic action for production 592:
     ///
     /// `StatementBlockItem: VarDeclaration;`
     ///
@@ -25466,7 +26560,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 568:
+    /// Semantic action for production 593:
     ///
     /// `StatementBlockItem: LetStatement;`
     ///
@@ -25490,7 +26584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 569:
+    /// Semantic action for production 594:
     ///
     /// `StatementBlockItem: Statement;`
     ///
@@ -25514,7 +26608,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 570:
+    /// Semantic action for production 595:
     ///
     /// `Statement: IdentifierStatement;`
     ///
@@ -25534,7 +26628,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 571:
+    /// Semantic action for production 596:
     ///
     /// `Statement: IfStatement;`
     ///
@@ -25553,7 +26647,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 572:
+    /// Semantic action for production 597:
     ///
     /// `Statement: IfResetStatement;`
     ///
@@ -25572,7 +26666,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 573:
+    /// Semantic action for production 598:
     ///
     /// `Statement: ReturnStatement;`
     ///
@@ -25591,7 +26685,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 574:
+    /// Semantic action for production 599:
     ///
     /// `Statement: BreakStatement;`
     ///
@@ -25610,7 +26704,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 575:
+    /// Semantic action for production 600:
     ///
     /// `Statement: ForStatement;`
     ///
@@ -25629,7 +26723,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 576:
+    /// Semantic action for production 601:
     ///
     /// `Statement: CaseStatement;`
     ///
@@ -25648,7 +26742,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 577:
+    /// Semantic action for production 602:
     ///
     /// `Statement: SwitchStatement;`
     ///
@@ -25667,7 +26761,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 578:
+    /// Semantic action for production 603:
+    ///
+    /// `Statement: AssertionStatement;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_8(&mut self, _assertion_statement: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assertion_statement = pop_item!(self, assertion_statement, AssertionStatement, context);
+        let statement_8_built = StatementAssertionStatement {
+            assertion_statement: Box::new(assertion_statement),
+        };
+        let statement_8_built = Statement::AssertionStatement(statement_8_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_8_built)?;
+        self.push(ASTType::Statement(statement_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 604:
     ///
     /// `LetStatement: Let Identifier Colon LetStatementOpt /* Option */ ArrayType Equ Expression Semicolon;`
     ///
@@ -25709,7 +26822,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 579:
+    /// Semantic action for production 605:
     ///
     /// `LetStatementOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -25728,7 +26841,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 580:
+    /// Semantic action for production 606:
     ///
     /// `LetStatementOpt /* Option<T>::None */: ;`
     ///
@@ -25740,7 +26853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 581:
+    /// Semantic action for production 607:
     ///
     /// `IdentifierStatement: ExpressionIdentifier IdentifierStatementGroup Semicolon;`
     ///
@@ -25777,7 +26890,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 582:
+    /// Semantic action for production 608:
     ///
     /// `IdentifierStatementGroup: FunctionCall;`
     ///
@@ -25798,7 +26911,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 583:
+    /// Semantic action for production 609:
     ///
     /// `IdentifierStatementGroup: Assignment;`
     ///
@@ -25819,7 +26932,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 584:
+    /// Semantic action for production 610:
     ///
     /// `Assignment: AssignmentGroup Expression;`
     ///
@@ -25843,7 +26956,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 585:
+    /// Semantic action for production 611:
     ///
     /// `AssignmentGroup: Equ;`
     ///
@@ -25858,7 +26971,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 586:
+    /// Semantic action for production 612:
     ///
     /// `AssignmentGroup: AssignmentOperator;`
     ///
@@ -25876,7 +26989,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 587:
+    /// Semantic action for production 613:
     ///
     /// `IfStatement: If Expression StatementBlock IfStatementList /* Vec */ IfStatementOpt /* Option */;`
     ///
@@ -25910,7 +27023,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 588:
+    /// Semantic action for production 614:
     ///
     /// `IfStatementList /* Vec<T>::Push */: Else If Expression StatementBlock IfStatementList;`
     ///
@@ -25942,7 +27055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 589:
+    /// Semantic action for production 615:
     ///
     /// `IfStatementList /* Vec<T>::New */: ;`
     ///
@@ -25955,7 +27068,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 590:
+    /// Semantic action for production 616:
     ///
     /// `IfStatementOpt /* Option<T>::Some */: Else StatementBlock;`
     ///
@@ -25980,7 +27093,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 591:
+    /// Semantic action for production 617:
     ///
     /// `IfStatementOpt /* Option<T>::None */: ;`
     ///
@@ -25992,7 +27105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 592:
+    /// Semantic action for production 618:
     ///
     /// `IfResetStatement: IfReset StatementBlock IfResetStatementList /* Vec */ IfResetStatementOpt /* Option */;`
     ///
@@ -26025,7 +27138,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 593:
+    /// Semantic action for production 619:
     ///
     /// `IfResetStatementList /* Vec<T>::Push */: Else If Expression StatementBlock IfResetStatementList;`
     ///
@@ -26061,7 +27174,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 594:
+    /// Semantic action for production 620:
     ///
     /// `IfResetStatementList /* Vec<T>::New */: ;`
     ///
@@ -26077,7 +27190,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 595:
+    /// Semantic action for production 621:
     ///
     /// `IfResetStatementOpt /* Option<T>::Some */: Else StatementBlock;`
     ///
@@ -26102,7 +27215,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 596:
+    /// Semantic action for production 622:
     ///
     /// `IfResetStatementOpt /* Option<T>::None */: ;`
     ///
@@ -26114,7 +27227,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 597:
+    /// Semantic action for production 623:
     ///
     /// `ReturnStatement: Return Expression Semicolon;`
     ///
@@ -26142,7 +27255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 598:
+    /// Semantic action for production 624:
     ///
     /// `BreakStatement: Break Semicolon;`
     ///
@@ -26166,7 +27279,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 599:
+    /// Semantic action for production 625:
     ///
     /// `ForStatement: For Identifier Colon ScalarType In Range ForStatementOpt /* Option */ StatementBlock;`
     ///
@@ -26208,7 +27321,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 600:
+    /// Semantic action for production 626:
     ///
     /// `ForStatementOpt /* Option<T>::Some */: Step AssignmentOperator Expression;`
     ///
@@ -26236,7 +27349,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 601:
+    /// Semantic action for production 627:
     ///
     /// `ForStatementOpt /* Option<T>::None */: ;`
     ///
@@ -26248,7 +27361,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 602:
+    /// Semantic action for production 628:
     ///
     /// `CaseStatement: Case Expression LBrace CaseStatementList /* Vec */ RBrace;`
     ///
@@ -26282,7 +27395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 603:
+    /// Semantic action for production 629:
     ///
     /// `CaseStatementList /* Vec<T>::Push */: CaseItem CaseStatementList;`
     ///
@@ -26306,7 +27419,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 604:
+    /// Semantic action for production 630:
     ///
     /// `CaseStatementList /* Vec<T>::New */: ;`
     ///
@@ -26322,7 +27435,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 605:
+    /// Semantic action for production 631:
     ///
     /// `CaseItem: CaseItemGroup Colon CaseItemGroup0;`
     ///
@@ -26349,7 +27462,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 606:
+    /// Semantic action for production 632:
     ///
     /// `CaseItemGroup0: Statement;`
     ///
@@ -26366,7 +27479,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 607:
+    /// Semantic action for production 633:
     ///
     /// `CaseItemGroup0: StatementBlock;`
     ///
@@ -26383,7 +27496,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 608:
+    /// Semantic action for production 634:
     ///
     /// `CaseItemGroup: CaseCondition;`
     ///
@@ -26400,7 +27513,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 609:
+    /// Semantic action for production 635:
     ///
     /// `CaseItemGroup: Defaul;`
     ///
@@ -26417,7 +27530,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 610:
+    /// Semantic action for production 636:
     ///
     /// `CaseCondition: RangeItem CaseConditionList /* Vec */;`
     ///
@@ -26442,7 +27555,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 611:
+    /// Semantic action for production 637:
     ///
     /// `CaseConditionList /* Vec<T>::Push */: Comma RangeItem CaseConditionList;`
     ///
@@ -26469,7 +27582,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 612:
+    /// Semantic action for production 638:
     ///
     /// `CaseConditionList /* Vec<T>::New */: ;`
     ///
@@ -26485,7 +27598,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 613:
+    /// Semantic action for production 639:
     ///
     /// `SwitchStatement: Switch LBrace SwitchStatementList /* Vec */ RBrace;`
     ///
@@ -26517,7 +27630,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 614:
+    /// Semantic action for production 640:
     ///
     /// `SwitchStatementList /* Vec<T>::Push */: SwitchItem SwitchStatementList;`
     ///
@@ -26541,7 +27654,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 615:
+    /// Semantic action for production 641:
     ///
     /// `SwitchStatementList /* Vec<T>::New */: ;`
     ///
@@ -26557,7 +27670,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 616:
+    /// Semantic action for production 642:
     ///
     /// `SwitchItem: SwitchItemGroup Colon SwitchItemGroup0;`
     ///
@@ -26584,7 +27697,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 617:
+    /// Semantic action for production 643:
     ///
     /// `SwitchItemGroup0: Statement;`
     ///
@@ -26604,7 +27717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 618:
+    /// Semantic action for production 644:
     ///
     /// `SwitchItemGroup0: StatementBlock;`
     ///
@@ -26625,7 +27738,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 619:
+    /// Semantic action for production 645:
     ///
     /// `SwitchItemGroup: SwitchCondition;`
     ///
@@ -26642,7 +27755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 620:
+    /// Semantic action for production 646:
     ///
     /// `SwitchItemGroup: Defaul;`
     ///
@@ -26659,7 +27772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 621:
+    /// Semantic action for production 647:
     ///
     /// `SwitchCondition: Expression SwitchConditionList /* Vec */;`
     ///
@@ -26685,7 +27798,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 622:
+    /// Semantic action for production 648:
     ///
     /// `SwitchConditionList /* Vec<T>::Push */: Comma Expression SwitchConditionList;`
     ///
@@ -26712,7 +27825,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 623:
+    /// Semantic action for production 649:
     ///
     /// `SwitchConditionList /* Vec<T>::New */: ;`
     ///
@@ -26728,7 +27841,146 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 624:
+    /// Semantic action for production 650:
+    ///
+    /// `AssertionKind: Assert;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_kind_0(&mut self, _assert: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert = pop_item!(self, assert, Assert, context);
+        let assertion_kind_0_built = AssertionKindAssert {
+            assert: Box::new(assert),
+        };
+        let assertion_kind_0_built = AssertionKind::Assert(assertion_kind_0_built);
+        // Calling user action here
+        self.user_grammar.assertion_kind(&assertion_kind_0_built)?;
+        self.push(ASTType::AssertionKind(assertion_kind_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 651:
+    ///
+    /// `AssertionKind: Assume;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_kind_1(&mut self, _assume: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume = pop_item!(self, assume, Assume, context);
+        let assertion_kind_1_built = AssertionKindAssume {
+            assume: Box::new(assume),
+        };
+        let assertion_kind_1_built = AssertionKind::Assume(assertion_kind_1_built);
+        // Calling user action here
+        self.user_grammar.assertion_kind(&assertion_kind_1_built)?;
+        self.push(ASTType::AssertionKind(assertion_kind_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 652:
+    ///
+    /// `AssertionKind: Cover;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_kind_2(&mut self, _cover: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover = pop_item!(self, cover, Cover, context);
+        let assertion_kind_2_built = AssertionKindCover {
+            cover: Box::new(cover),
+        };
+        let assertion_kind_2_built = AssertionKind::Cover(assertion_kind_2_built);
+        // Calling user action here
+        self.user_grammar.assertion_kind(&assertion_kind_2_built)?;
+        self.push(ASTType::AssertionKind(assertion_kind_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 653:
+    ///
+    /// `AssertionStatement: AssertionKind LParen Expression RParen AssertionStatementOpt /* Option */ Semicolon;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_statement(
+        &mut self,
+        _assertion_kind: &ParseTreeType<'t>,
+        _l_paren: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+        _assertion_statement_opt: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let assertion_statement_opt = pop_item!(
+            self,
+            assertion_statement_opt,
+            AssertionStatementOpt,
+            context
+        );
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let assertion_kind = pop_item!(self, assertion_kind, AssertionKind, context);
+        let assertion_statement_built = AssertionStatement {
+            assertion_kind: Box::new(assertion_kind),
+            l_paren: Box::new(l_paren),
+            expression: Box::new(expression),
+            r_paren: Box::new(r_paren),
+            assertion_statement_opt,
+            semicolon: Box::new(semicolon),
+        };
+        // Calling user action here
+        self.user_grammar
+            .assertion_statement(&assertion_statement_built)?;
+        self.push(
+            ASTType::AssertionStatement(assertion_statement_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 654:
+    ///
+    /// `AssertionStatementOpt /* Option<T>::Some */: Else StringLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_statement_opt_0(
+        &mut self,
+        _else: &ParseTreeType<'t>,
+        _string_literal: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string_literal = pop_item!(self, string_literal, StringLiteral, context);
+        let r#else = pop_item!(self, r#else, Else, context);
+        let assertion_statement_opt_0_built = AssertionStatementOpt {
+            r#else: Box::new(r#else),
+            string_literal: Box::new(string_literal),
+        };
+        self.push(
+            ASTType::AssertionStatementOpt(Some(assertion_statement_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 655:
+    ///
+    /// `AssertionStatementOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_statement_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AssertionStatementOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 656:
     ///
     /// `Attribute: Hash LBracket Identifier AttributeOpt /* Option */ RBracket;`
     ///
@@ -26761,7 +28013,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 625:
+    /// Semantic action for production 657:
     ///
     /// `AttributeOpt /* Option<T>::Some */: LParen AttributeList RParen;`
     ///
@@ -26786,7 +28038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 626:
+    /// Semantic action for production 658:
     ///
     /// `AttributeOpt /* Option<T>::None */: ;`
     ///
@@ -26798,7 +28050,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 627:
+    /// Semantic action for production 659:
     ///
     /// `AttributeList: AttributeItem AttributeListList /* Vec */ AttributeListOpt /* Option */;`
     ///
@@ -26826,7 +28078,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 628:
+    /// Semantic action for production 660:
     ///
     /// `AttributeListList /* Vec<T>::Push */: Comma AttributeItem AttributeListList;`
     ///
@@ -26853,7 +28105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 629:
+    /// Semantic action for production 661:
     ///
     /// `AttributeListList /* Vec<T>::New */: ;`
     ///
@@ -26869,7 +28121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 630:
+    /// Semantic action for production 662:
     ///
     /// `AttributeListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26888,7 +28140,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 631:
+    /// Semantic action for production 663:
     ///
     /// `AttributeListOpt /* Option<T>::None */: ;`
     ///
@@ -26900,7 +28152,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 632:
+    /// Semantic action for production 664:
     ///
     /// `AttributeItem: Identifier;`
     ///
@@ -26919,7 +28171,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 633:
+    /// Semantic action for production 665:
     ///
     /// `AttributeItem: StringLiteral;`
     ///
@@ -26938,7 +28190,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 634:
+    /// Semantic action for production 666:
     ///
     /// `LetDeclaration: Let Identifier Colon LetDeclarationOpt /* Option */ ArrayType Equ Expression Semicolon;`
     ///
@@ -26980,7 +28232,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 635:
+    /// Semantic action for production 667:
     ///
     /// `LetDeclarationOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -26999,7 +28251,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 636:
+    /// Semantic action for production 668:
     ///
     /// `LetDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -27011,7 +28263,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 637:
+    /// Semantic action for production 669:
     ///
     /// `VarDeclaration: Var Identifier Colon VarDeclarationOpt /* Option */ ArrayType Semicolon;`
     ///
@@ -27047,7 +28299,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 638:
+    /// Semantic action for production 670:
     ///
     /// `VarDeclarationOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -27066,7 +28318,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 639:
+    /// Semantic action for production 671:
     ///
     /// `VarDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -27078,7 +28330,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 640:
+    /// Semantic action for production 672:
     ///
     /// `ConstDeclaration: Const Identifier Colon ConstDeclarationGroup Equ Expression Semicolon;`
     ///
@@ -27123,7 +28375,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 641:
+    /// Semantic action for production 673:
     ///
     /// `ConstDeclarationGroup: ArrayType;`
     ///
@@ -27144,7 +28396,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 642:
+    /// Semantic action for production 674:
     ///
     /// `ConstDeclarationGroup: Type;`
     ///
@@ -27165,7 +28417,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 643:
+    /// Semantic action for production 675:
     ///
     /// `TypeDefDeclaration: Type Identifier Equ ArrayType Semicolon;`
     ///
@@ -27202,7 +28454,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 644:
+    /// Semantic action for production 676:
     ///
     /// `AlwaysFfDeclaration: AlwaysFf AlwaysFfDeclarationOpt /* Option */ StatementBlock;`
     ///
@@ -27238,7 +28490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 645:
+    /// Semantic action for production 677:
     ///
     /// `AlwaysFfDeclarationOpt /* Option<T>::Some */: AlwaysFfEventList;`
     ///
@@ -27261,7 +28513,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 646:
+    /// Semantic action for production 678:
     ///
     /// `AlwaysFfDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -27273,7 +28525,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 647:
+    /// Semantic action for production 679:
     ///
     /// `AlwaysFfEventList: LParen AlwaysFfClock AlwaysFfEventListOpt /* Option */ RParen;`
     ///
@@ -27312,7 +28564,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 648:
+    /// Semantic action for production 680:
     ///
     /// `AlwaysFfEventListOpt /* Option<T>::Some */: Comma AlwaysFfReset;`
     ///
@@ -27337,7 +28589,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 649:
+    /// Semantic action for production 681:
     ///
     /// `AlwaysFfEventListOpt /* Option<T>::None */: ;`
     ///
@@ -27349,7 +28601,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 650:
+    /// Semantic action for production 682:
     ///
     /// `AlwaysFfClock: HierarchicalIdentifier;`
     ///
@@ -27372,7 +28624,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 651:
+    /// Semantic action for production 683:
     ///
     /// `AlwaysFfReset: HierarchicalIdentifier;`
     ///
@@ -27395,7 +28647,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 652:
+    /// Semantic action for production 684:
     ///
     /// `AlwaysCombDeclaration: AlwaysComb StatementBlock;`
     ///
@@ -27423,7 +28675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 653:
+    /// Semantic action for production 685:
     ///
     /// `AssignDeclaration: Assign HierarchicalIdentifier Equ Expression Semicolon;`
     ///
@@ -27465,7 +28717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 654:
+    /// Semantic action for production 686:
     ///
     /// `ModportDeclaration: Modport Identifier LBrace ModportList RBrace;`
     ///
@@ -27502,7 +28754,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 655:
+    /// Semantic action for production 687:
     ///
     /// `ModportList: ModportGroup ModportListList /* Vec */ ModportListOpt /* Option */;`
     ///
@@ -27530,7 +28782,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 656:
+    /// Semantic action for production 688:
     ///
     /// `ModportListList /* Vec<T>::Push */: Comma ModportGroup ModportListList;`
     ///
@@ -27556,7 +28808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 657:
+    /// Semantic action for production 689:
     ///
     /// `ModportListList /* Vec<T>::New */: ;`
     ///
@@ -27569,7 +28821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 658:
+    /// Semantic action for production 690:
     ///
     /// `ModportListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -27588,7 +28840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 659:
+    /// Semantic action for production 691:
     ///
     /// `ModportListOpt /* Option<T>::None */: ;`
     ///
@@ -27600,7 +28852,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 660:
+    /// Semantic action for production 692:
     ///
     /// `ModportGroup: ModportGroupList /* Vec */ ModportGroupGroup;`
     ///
@@ -27625,7 +28877,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 661:
+    /// Semantic action for production 693:
     ///
     /// `ModportGroupGroup: LBrace ModportList RBrace;`
     ///
@@ -27655,7 +28907,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 662:
+    /// Semantic action for production 694:
     ///
     /// `ModportGroupGroup: ModportItem;`
     ///
@@ -27676,7 +28928,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 663:
+    /// Semantic action for production 695:
     ///
     /// `ModportGroupList /* Vec<T>::Push */: Attribute ModportGroupList;`
     ///
@@ -27699,7 +28951,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 664:
+    /// Semantic action for production 696:
     ///
     /// `ModportGroupList /* Vec<T>::New */: ;`
     ///
@@ -27715,7 +28967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 665:
+    /// Semantic action for production 697:
     ///
     /// `ModportItem: Identifier Colon Direction;`
     ///
@@ -27742,7 +28994,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 666:
+    /// Semantic action for production 698:
     ///
     /// `EnumDeclaration: Enum Identifier EnumDeclarationOpt /* Option */ LBrace EnumList RBrace;`
     ///
@@ -27780,7 +29032,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 667:
+    /// Semantic action for production 699:
     ///
     /// `EnumDeclarationOpt /* Option<T>::Some */: Colon ScalarType;`
     ///
@@ -27805,7 +29057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 668:
+    /// Semantic action for production 700:
     ///
     /// `EnumDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -27817,7 +29069,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 669:
+    /// Semantic action for production 701:
     ///
     /// `EnumList: EnumGroup EnumListList /* Vec */ EnumListOpt /* Option */;`
     ///
@@ -27844,7 +29096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 670:
+    /// Semantic action for production 702:
     ///
     /// `EnumListList /* Vec<T>::Push */: Comma EnumGroup EnumListList;`
     ///
@@ -27870,7 +29122,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 671:
+    /// Semantic action for production 703:
     ///
     /// `EnumListList /* Vec<T>::New */: ;`
     ///
@@ -27883,7 +29135,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 672:
+    /// Semantic action for production 704:
     ///
     /// `EnumListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -27899,7 +29151,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 673:
+    /// Semantic action for production 705:
     ///
     /// `EnumListOpt /* Option<T>::None */: ;`
     ///
@@ -27911,7 +29163,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 674:
+    /// Semantic action for production 706:
     ///
     /// `EnumGroup: EnumGroupList /* Vec */ EnumGroupGroup;`
     ///
@@ -27935,7 +29187,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 675:
+    /// Semantic action for production 707:
     ///
     /// `EnumGroupGroup: LBrace EnumList RBrace;`
     ///
@@ -27962,7 +29214,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 676:
+    /// Semantic action for production 708:
     ///
     /// `EnumGroupGroup: EnumItem;`
     ///
@@ -27979,7 +29231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 677:
+    /// Semantic action for production 709:
     ///
     /// `EnumGroupList /* Vec<T>::Push */: Attribute EnumGroupList;`
     ///
@@ -28002,7 +29254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 678:
+    /// Semantic action for production 710:
     ///
     /// `EnumGroupList /* Vec<T>::New */: ;`
     ///
@@ -28015,7 +29267,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 679:
+    /// Semantic action for production 711:
     ///
     /// `EnumItem: Identifier EnumItemOpt /* Option */;`
     ///
@@ -28039,7 +29291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 680:
+    /// Semantic action for production 712:
     ///
     /// `EnumItemOpt /* Option<T>::Some */: Equ Expression;`
     ///
@@ -28061,7 +29313,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 681:
+    /// Semantic action for production 713:
     ///
     /// `EnumItemOpt /* Option<T>::None */: ;`
     ///
@@ -28073,7 +29325,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 682:
+    /// Semantic action for production 714:
     ///
     /// `StructUnion: Struct;`
     ///
@@ -28092,7 +29344,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 683:
+    /// Semantic action for production 715:
     ///
     /// `StructUnion: Union;`
     ///
@@ -28111,7 +29363,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 684:
+    /// Semantic action for production 716:
     ///
     /// `StructUnionDeclaration: StructUnion Identifier StructUnionDeclarationOpt /* Option */ LBrace StructUnionList RBrace;`
     ///
@@ -28156,7 +29408,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 685:
+    /// Semantic action for production 717:
     ///
     /// `StructUnionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -28179,7 +29431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 686:
+    /// Semantic action for production 718:
     ///
     /// `StructUnionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -28191,7 +29443,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 687:
+    /// Semantic action for production 719:
     ///
     /// `StructUnionList: StructUnionGroup StructUnionListList /* Vec */ StructUnionListOpt /* Option */;`
     ///
@@ -28221,7 +29473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 688:
+    /// Semantic action for production 720:
     ///
     /// `StructUnionListList /* Vec<T>::Push */: Comma StructUnionGroup StructUnionListList;`
     ///
@@ -28251,7 +29503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 689:
+    /// Semantic action for production 721:
     ///
     /// `StructUnionListList /* Vec<T>::New */: ;`
     ///
@@ -28267,7 +29519,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 690:
+    /// Semantic action for production 722:
     ///
     /// `StructUnionListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -28286,7 +29538,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 691:
+    /// Semantic action for production 723:
     ///
     /// `StructUnionListOpt /* Option<T>::None */: ;`
     ///
@@ -28298,7 +29550,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 692:
+    /// Semantic action for production 724:
     ///
     /// `StructUnionGroup: StructUnionGroupList /* Vec */ StructUnionGroupGroup;`
     ///
@@ -28329,7 +29581,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 693:
+    /// Semantic action for production 725:
     ///
     /// `StructUnionGroupGroup: LBrace StructUnionList RBrace;`
     ///
@@ -28359,7 +29611,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 694:
+    /// Semantic action for production 726:
     ///
     /// `StructUnionGroupGroup: StructUnionItem;`
     ///
@@ -28380,7 +29632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 695:
+    /// Semantic action for production 727:
     ///
     /// `StructUnionGroupList /* Vec<T>::Push */: Attribute StructUnionGroupList;`
     ///
@@ -28407,7 +29659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 696:
+    /// Semantic action for production 728:
     ///
     /// `StructUnionGroupList /* Vec<T>::New */: ;`
     ///
@@ -28423,7 +29675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 697:
+    /// Semantic action for production 729:
     ///
     /// `StructUnionItem: Identifier Colon ScalarType;`
     ///
@@ -28451,7 +29703,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 698:
+    /// Semantic action for production 730:
     ///
     /// `InitialDeclaration: Initial StatementBlock;`
     ///
@@ -28479,7 +29731,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 699:
+    /// Semantic action for production 731:
     ///
     /// `FinalDeclaration: Final StatementBlock;`
     ///
@@ -28504,7 +29756,92 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 700:
+    /// Semantic action for production 732:
+    ///
+    /// `AssertionDeclaration: AssertionKind Property LParen Expression RParen AssertionDeclarationOpt /* Option */ Semicolon;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration(
+        &mut self,
+        _assertion_kind: &ParseTreeType<'t>,
+        _property: &ParseTreeType<'t>,
+        _l_paren: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+        _assertion_declaration_opt: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let assertion_declaration_opt = pop_item!(
+            self,
+            assertion_declaration_opt,
+            AssertionDeclarationOpt,
+            context
+        );
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let property = pop_item!(self, property, Property, context);
+        let assertion_kind = pop_item!(self, assertion_kind, AssertionKind, context);
+        let assertion_declaration_built = AssertionDeclaration {
+            assertion_kind: Box::new(assertion_kind),
+            property: Box::new(property),
+            l_paren: Box::new(l_paren),
+            expression: Box::new(expression),
+            r_paren: Box::new(r_paren),
+            assertion_declaration_opt,
+            semicolon: Box::new(semicolon),
+        };
+        // Calling user action here
+        self.user_grammar
+            .assertion_declaration(&assertion_declaration_built)?;
+        self.push(
+            ASTType::AssertionDeclaration(assertion_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 733:
+    ///
+    /// `AssertionDeclarationOpt /* Option<T>::Some */: Else StringLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt_0(
+        &mut self,
+        _else: &ParseTreeType<'t>,
+        _string_literal: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string_literal = pop_item!(self, string_literal, StringLiteral, context);
+        let r#else = pop_item!(self, r#else, Else, context);
+        let assertion_declaration_opt_0_built = AssertionDeclarationOpt {
+            r#else: Box::new(r#else),
+            string_literal: Box::new(string_literal),
+        };
+        self.push(
+            ASTType::AssertionDeclarationOpt(Some(assertion_declaration_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 734:
+    ///
+    /// `AssertionDeclarationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AssertionDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 735:
     ///
     /// `InstDeclaration: Inst Identifier Colon ScopedIdentifier InstDeclarationOpt /* Option */ InstDeclarationOpt0 /* Option */ InstDeclarationOpt1 /* Option */ Semicolon;`
     ///
@@ -28550,7 +29887,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 701:
+    /// Semantic action for production 736:
     ///
     /// `InstDeclarationOpt1 /* Option<T>::Some */: LParen InstDeclarationOpt2 /* Option */ RParen;`
     ///
@@ -28579,7 +29916,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 702:
+    /// Semantic action for production 737:
     ///
     /// `InstDeclarationOpt2 /* Option<T>::Some */: InstPortList;`
     ///
@@ -28598,7 +29935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 703:
+    /// Semantic action for production 738:
     ///
     /// `InstDeclarationOpt2 /* Option<T>::None */: ;`
     ///
@@ -28610,7 +29947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 704:
+    /// Semantic action for production 739:
     ///
     /// `InstDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -28622,7 +29959,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 705:
+    /// Semantic action for production 740:
     ///
     /// `InstDeclarationOpt0 /* Option<T>::Some */: InstParameter;`
     ///
@@ -28641,7 +29978,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 706:
+    /// Semantic action for production 741:
     ///
     /// `InstDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -28653,7 +29990,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 707:
+    /// Semantic action for production 742:
     ///
     /// `InstDeclarationOpt /* Option<T>::Some */: Array;`
     ///
@@ -28672,7 +30009,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 708:
+    /// Semantic action for production 743:
     ///
     /// `InstDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -28684,7 +30021,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 709:
+    /// Semantic action for production 744:
     ///
     /// `InstParameter: Hash LParen InstParameterOpt /* Option */ RParen;`
     ///
@@ -28714,7 +30051,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 710:
+    /// Semantic action for production 745:
     ///
     /// `InstParameterOpt /* Option<T>::Some */: InstParameterList;`
     ///
@@ -28733,7 +30070,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 711:
+    /// Semantic action for production 746:
     ///
     /// `InstParameterOpt /* Option<T>::None */: ;`
     ///
@@ -28745,7 +30082,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 712:
+    /// Semantic action for production 747:
     ///
     /// `InstParameterList: InstParameterGroup InstParameterListList /* Vec */ InstParameterListOpt /* Option */;`
     ///
@@ -28783,7 +30120,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 713:
+    /// Semantic action for production 748:
     ///
     /// `InstParameterListList /* Vec<T>::Push */: Comma InstParameterGroup InstParameterListList;`
     ///
@@ -28818,7 +30155,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 714:
+    /// Semantic action for production 749:
     ///
     /// `InstParameterListList /* Vec<T>::New */: ;`
     ///
@@ -28834,7 +30171,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 715:
+    /// Semantic action for production 750:
     ///
     /// `InstParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -28853,7 +30190,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 716:
+    /// Semantic action for production 751:
     ///
     /// `InstParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -28865,7 +30202,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 717:
+    /// Semantic action for production 752:
     ///
     /// `InstParameterGroup: InstParameterGroupList /* Vec */ InstParameterGroupGroup;`
     ///
@@ -28903,7 +30240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 718:
+    /// Semantic action for production 753:
     ///
     /// `InstParameterGroupGroup: LBrace InstParameterList RBrace;`
     ///
@@ -28936,7 +30273,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 719:
+    /// Semantic action for production 754:
     ///
     /// `InstParameterGroupGroup: InstParameterItem;`
     ///
@@ -28960,7 +30297,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 720:
+    /// Semantic action for production 755:
     ///
     /// `InstParameterGroupList /* Vec<T>::Push */: Attribute InstParameterGroupList;`
     ///
@@ -28991,7 +30328,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 721:
+    /// Semantic action for production 756:
     ///
     /// `InstParameterGroupList /* Vec<T>::New */: ;`
     ///
@@ -29007,7 +30344,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 722:
+    /// Semantic action for production 757:
     ///
     /// `InstParameterItem: Identifier InstParameterItemOpt /* Option */;`
     ///
@@ -29036,7 +30373,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 723:
+    /// Semantic action for production 758:
     ///
     /// `InstParameterItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -29061,7 +30398,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 724:
+    /// Semantic action for production 759:
     ///
     /// `InstParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -29073,7 +30410,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 725:
+    /// Semantic action for production 760:
     ///
     /// `InstPortList: InstPortGroup InstPortListList /* Vec */ InstPortListOpt /* Option */;`
     ///
@@ -29101,7 +30438,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 726:
+    /// Semantic action for production 761:
     ///
     /// `InstPortListList /* Vec<T>::Push */: Comma InstPortGroup InstPortListList;`
     ///
@@ -29128,7 +30465,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 727:
+    /// Semantic action for production 762:
     ///
     /// `InstPortListList /* Vec<T>::New */: ;`
     ///
@@ -29144,7 +30481,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 728:
+    /// Semantic action for production 763:
     ///
     /// `InstPortListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -29163,7 +30500,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 729:
+    /// Semantic action for production 764:
     ///
     /// `InstPortListOpt /* Option<T>::None */: ;`
     ///
@@ -29175,7 +30512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 730:
+    /// Semantic action for production 765:
     ///
     /// `InstPortGroup: InstPortGroupList /* Vec */ InstPortGroupGroup;`
     ///
@@ -29201,7 +30538,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 731:
+    /// Semantic action for production 766:
     ///
     /// `InstPortGroupGroup: LBrace InstPortList RBrace;`
     ///
@@ -29231,7 +30568,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 732:
+    /// Semantic action for production 767:
     ///
     /// `InstPortGroupGroup: InstPortItem;`
     ///
@@ -29252,7 +30589,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 733:
+    /// Semantic action for production 768:
     ///
     /// `InstPortGroupList /* Vec<T>::Push */: Attribute InstPortGroupList;`
     ///
@@ -29276,7 +30613,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 734:
+    /// Semantic action for production 769:
     ///
     /// `InstPortGroupList /* Vec<T>::New */: ;`
     ///
@@ -29292,7 +30629,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 735:
+    /// Semantic action for production 770:
     ///
     /// `InstPortItem: Identifier InstPortItemOpt /* Option */;`
     ///
@@ -29316,7 +30653,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 736:
+    /// Semantic action for production 771:
     ///
     /// `InstPortItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -29341,7 +30678,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 737:
+    /// Semantic action for production 772:
     ///
     /// `InstPortItemOpt /* Option<T>::None */: ;`
     ///
@@ -29353,7 +30690,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 738:
+    /// Semantic action for production 773:
     ///
     /// `WithParameter: Hash LParen WithParameterOpt /* Option */ RParen;`
     ///
@@ -29383,7 +30720,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 739:
+    /// Semantic action for production 774:
     ///
     /// `WithParameterOpt /* Option<T>::Some */: WithParameterList;`
     ///
@@ -29402,7 +30739,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 740:
+    /// Semantic action for production 775:
     ///
     /// `WithParameterOpt /* Option<T>::None */: ;`
     ///
@@ -29414,7 +30751,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 741:
+    /// Semantic action for production 776:
     ///
     /// `WithParameterList: WithParameterGroup WithParameterListList /* Vec */ WithParameterListOpt /* Option */;`
     ///
@@ -29452,7 +30789,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 742:
+    /// Semantic action for production 777:
     ///
     /// `WithParameterListList /* Vec<T>::Push */: Comma WithParameterGroup WithParameterListList;`
     ///
@@ -29487,7 +30824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 743:
+    /// Semantic action for production 778:
     ///
     /// `WithParameterListList /* Vec<T>::New */: ;`
     ///
@@ -29503,7 +30840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 744:
+    /// Semantic action for production 779:
     ///
     /// `WithParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -29522,7 +30859,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 745:
+    /// Semantic action for production 780:
     ///
     /// `WithParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -29534,7 +30871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 746:
+    /// Semantic action for production 781:
     ///
     /// `WithParameterGroup: WithParameterGroupList /* Vec */ WithParameterGroupGroup;`
     ///
@@ -29572,7 +30909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 747:
+    /// Semantic action for production 782:
     ///
     /// `WithParameterGroupGroup: LBrace WithParameterList RBrace;`
     ///
@@ -29605,7 +30942,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 748:
+    /// Semantic action for production 783:
     ///
     /// `WithParameterGroupGroup: WithParameterItem;`
     ///
@@ -29629,7 +30966,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 749:
+    /// Semantic action for production 784:
     ///
     /// `WithParameterGroupList /* Vec<T>::Push */: Attribute WithParameterGroupList;`
     ///
@@ -29660,7 +30997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 750:
+    /// Semantic action for production 785:
     ///
     /// `WithParameterGroupList /* Vec<T>::New */: ;`
     ///
@@ -29676,7 +31013,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 751:
+    /// Semantic action for production 786:
     ///
     /// `WithParameterItem: WithParameterItemGroup Identifier Colon WithParameterItemGroup0 Equ Expression;`
     ///
@@ -29726,7 +31063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 752:
+    /// Semantic action for production 787:
     ///
     /// `WithParameterItemGroup0: ArrayType;`
     ///
@@ -29747,7 +31084,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 753:
+    /// Semantic action for production 788:
     ///
     /// `WithParameterItemGroup0: Type;`
     ///
@@ -29768,7 +31105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 754:
+    /// Semantic action for production 789:
     ///
     /// `WithParameterItemGroup: Param;`
     ///
@@ -29789,7 +31126,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 755:
+    /// Semantic action for production 790:
     ///
     /// `WithParameterItemGroup: Const;`
     ///
@@ -29810,7 +31147,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 756:
+    /// Semantic action for production 791:
     ///
     /// `GenericBound: Const;`
     ///
@@ -29829,7 +31166,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 757:
+    /// Semantic action for production 792:
     ///
     /// `GenericBound: Type;`
     ///
@@ -29848,7 +31185,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 758:
+    /// Semantic action for production 793:
     ///
     /// `GenericBound: Inst ScopedIdentifier;`
     ///
@@ -29873,7 +31210,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 759:
+    /// Semantic action for production 794:
     ///
     /// `GenericBound: ScopedIdentifier;`
     ///
@@ -29892,7 +31229,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 760:
+    /// Semantic action for production 795:
     ///
     /// `WithGenericParameter: ColonColonLAngle WithGenericParameterList RAngle;`
     ///
@@ -29928,7 +31265,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 761:
+    /// Semantic action for production 796:
     ///
     /// `WithGenericParameterList: WithGenericParameterItem WithGenericParameterListList /* Vec */ WithGenericParameterListOpt /* Option */;`
     ///
@@ -29974,7 +31311,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 762:
+    /// Semantic action for production 797:
     ///
     /// `WithGenericParameterListList /* Vec<T>::Push */: Comma WithGenericParameterItem WithGenericParameterListList;`
     ///
@@ -30013,7 +31350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 763:
+    /// Semantic action for production 798:
     ///
     /// `WithGenericParameterListList /* Vec<T>::New */: ;`
     ///
@@ -30029,7 +31366,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 764:
+    /// Semantic action for production 799:
     ///
     /// `WithGenericParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -30048,7 +31385,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 765:
+    /// Semantic action for production 800:
     ///
     /// `WithGenericParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -30060,7 +31397,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 766:
+    /// Semantic action for production 801:
     ///
     /// `WithGenericParameterItem: Identifier Colon GenericBound WithGenericParameterItemOpt /* Option */;`
     ///
@@ -30099,7 +31436,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 767:
+    /// Semantic action for production 802:
     ///
     /// `WithGenericParameterItemOpt /* Option<T>::Some */: Equ WithGenericArgumentItem;`
     ///
@@ -30129,7 +31466,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 768:
+    /// Semantic action for production 803:
     ///
     /// `WithGenericParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -30141,7 +31478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 769:
+    /// Semantic action for production 804:
     ///
     /// `WithGenericArgument: ColonColonLAngle %push(Generic) WithGenericArgumentOpt /* Option */ RAngle %pop();`
     ///
@@ -30177,7 +31514,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 770:
+    /// Semantic action for production 805:
     ///
     /// `WithGenericArgumentOpt /* Option<T>::Some */: WithGenericArgumentList;`
     ///
@@ -30204,7 +31541,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 771:
+    /// Semantic action for production 806:
     ///
     /// `WithGenericArgumentOpt /* Option<T>::None */: ;`
     ///
@@ -30216,7 +31553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 772:
+    /// Semantic action for production 807:
     ///
     /// `WithGenericArgumentList: WithGenericArgumentItem WithGenericArgumentListList /* Vec */ WithGenericArgumentListOpt /* Option */;`
     ///
@@ -30262,7 +31599,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 773:
+    /// Semantic action for production 808:
     ///
     /// `WithGenericArgumentListList /* Vec<T>::Push */: Comma WithGenericArgumentItem WithGenericArgumentListList;`
     ///
@@ -30301,7 +31638,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 774:
+    /// Semantic action for production 809:
     ///
     /// `WithGenericArgumentListList /* Vec<T>::New */: ;`
     ///
@@ -30317,7 +31654,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 775:
+    /// Semantic action for production 810:
     ///
     /// `WithGenericArgumentListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -30336,7 +31673,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 776:
+    /// Semantic action for production 811:
     ///
     /// `WithGenericArgumentListOpt /* Option<T>::None */: ;`
     ///
@@ -30348,7 +31685,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 777:
+    /// Semantic action for production 812:
     ///
     /// `WithGenericArgumentItem: ScopedIdentifier;`
     ///
@@ -30375,7 +31712,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 778:
+    /// Semantic action for production 813:
     ///
     /// `WithGenericArgumentItem: Number;`
     ///
@@ -30399,7 +31736,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 779:
+    /// Semantic action for production 814:
     ///
     /// `PortDeclaration: LParen PortDeclarationOpt /* Option */ RParen;`
     ///
@@ -30428,7 +31765,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 780:
+    /// Semantic action for production 815:
     ///
     /// `PortDeclarationOpt /* Option<T>::Some */: PortDeclarationList;`
     ///
@@ -30448,7 +31785,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 781:
+    /// Semantic action for production 816:
     ///
     /// `PortDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -30460,7 +31797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 782:
+    /// Semantic action for production 817:
     ///
     /// `PortDeclarationList: PortDeclarationGroup PortDeclarationListList /* Vec */ PortDeclarationListOpt /* Option */;`
     ///
@@ -30502,7 +31839,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 783:
+    /// Semantic action for production 818:
     ///
     /// `PortDeclarationListList /* Vec<T>::Push */: Comma PortDeclarationGroup PortDeclarationListList;`
     ///
@@ -30537,7 +31874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 784:
+    /// Semantic action for production 819:
     ///
     /// `PortDeclarationListList /* Vec<T>::New */: ;`
     ///
@@ -30553,7 +31890,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 785:
+    /// Semantic action for production 820:
     ///
     /// `PortDeclarationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -30572,7 +31909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 786:
+    /// Semantic action for production 821:
     ///
     /// `PortDeclarationListOpt /* Option<T>::None */: ;`
     ///
@@ -30584,7 +31921,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 787:
+    /// Semantic action for production 822:
     ///
     /// `PortDeclarationGroup: PortDeclarationGroupList /* Vec */ PortDeclarationGroupGroup;`
     ///
@@ -30622,7 +31959,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 788:
+    /// Semantic action for production 823:
     ///
     /// `PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;`
     ///
@@ -30656,7 +31993,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 789:
+    /// Semantic action for production 824:
     ///
     /// `PortDeclarationGroupGroup: PortDeclarationItem;`
     ///
@@ -30681,7 +32018,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 790:
+    /// Semantic action for production 825:
     ///
     /// `PortDeclarationGroupList /* Vec<T>::Push */: Attribute PortDeclarationGroupList;`
     ///
@@ -30712,7 +32049,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 791:
+    /// Semantic action for production 826:
     ///
     /// `PortDeclarationGroupList /* Vec<T>::New */: ;`
     ///
@@ -30728,7 +32065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 792:
+    /// Semantic action for production 827:
     ///
     /// `PortDeclarationItem: Identifier Colon PortDeclarationItemGroup;`
     ///
@@ -30764,7 +32101,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 793:
+    /// Semantic action for production 828:
     ///
     /// `PortDeclarationItemGroup: PortTypeConcrete;`
     ///
@@ -30788,7 +32125,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 794:
+    /// Semantic action for production 829:
     ///
     /// `PortDeclarationItemGroup: PortTypeAbstract;`
     ///
@@ -30812,7 +32149,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 795:
+    /// Semantic action for production 830:
     ///
     /// `PortTypeConcrete: Direction PortTypeConcreteOpt /* Option */ ArrayType PortTypeConcreteOpt0 /* Option */;`
     ///
@@ -30845,7 +32182,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 796:
+    /// Semantic action for production 831:
     ///
     /// `PortTypeConcreteOpt0 /* Option<T>::Some */: Equ PortDefaultValue;`
     ///
@@ -30870,7 +32207,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 797:
+    /// Semantic action for production 832:
     ///
     /// `PortTypeConcreteOpt0 /* Option<T>::None */: ;`
     ///
@@ -30882,7 +32219,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 798:
+    /// Semantic action for production 833:
     ///
     /// `PortTypeConcreteOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -30901,7 +32238,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 799:
+    /// Semantic action for production 834:
     ///
     /// `PortTypeConcreteOpt /* Option<T>::None */: ;`
     ///
@@ -30913,7 +32250,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 800:
+    /// Semantic action for production 835:
     ///
     /// `PortDefaultValue: Expression;`
     ///
@@ -30932,7 +32269,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 801:
+    /// Semantic action for production 836:
     ///
     /// `PortTypeAbstract: PortTypeAbstractOpt /* Option */ Interface PortTypeAbstractOpt0 /* Option */ PortTypeAbstractOpt1 /* Option */;`
     ///
@@ -30966,7 +32303,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 802:
+    /// Semantic action for production 837:
     ///
     /// `PortTypeAbstractOpt1 /* Option<T>::Some */: Array;`
     ///
@@ -30985,7 +32322,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 803:
+    /// Semantic action for production 838:
     ///
     /// `PortTypeAbstractOpt1 /* Option<T>::None */: ;`
     ///
@@ -30997,7 +32334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 804:
+    /// Semantic action for production 839:
     ///
     /// `PortTypeAbstractOpt0 /* Option<T>::Some */: ColonColon Identifier;`
     ///
@@ -31022,7 +32359,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 805:
+    /// Semantic action for production 840:
     ///
     /// `PortTypeAbstractOpt0 /* Option<T>::None */: ;`
     ///
@@ -31034,7 +32371,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 806:
+    /// Semantic action for production 841:
     ///
     /// `PortTypeAbstractOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -31053,7 +32390,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 807:
+    /// Semantic action for production 842:
     ///
     /// `PortTypeAbstractOpt /* Option<T>::None */: ;`
     ///
@@ -31065,7 +32402,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 808:
+    /// Semantic action for production 843:
     ///
     /// `Direction: Input;`
     ///
@@ -31084,7 +32421,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 809:
+    /// Semantic action for production 844:
     ///
     /// `Direction: Output;`
     ///
@@ -31103,7 +32440,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 810:
+    /// Semantic action for production 845:
     ///
     /// `Direction: Inout;`
     ///
@@ -31122,7 +32459,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 811:
+    /// Semantic action for production 846:
     ///
     /// `Direction: Ref;`
     ///
@@ -31141,7 +32478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 812:
+    /// Semantic action for production 847:
     ///
     /// `Direction: Modport;`
     ///
@@ -31160,7 +32497,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 813:
+    /// Semantic action for production 848:
     ///
     /// `Direction: Import;`
     ///
@@ -31179,7 +32516,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 814:
+    /// Semantic action for production 849:
     ///
     /// `FunctionDeclaration: Function Identifier FunctionDeclarationOpt /* Option */ FunctionDeclarationOpt0 /* Option */ FunctionDeclarationOpt1 /* Option */ StatementBlock;`
     ///
@@ -31234,7 +32571,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 815:
+    /// Semantic action for production 850:
     ///
     /// `FunctionDeclarationOpt1 /* Option<T>::Some */: MinusGT ScalarType;`
     ///
@@ -31259,7 +32596,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 816:
+    /// Semantic action for production 851:
     ///
     /// `FunctionDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -31271,7 +32608,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 817:
+    /// Semantic action for production 852:
     ///
     /// `FunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -31290,7 +32627,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 818:
+    /// Semantic action for production 853:
     ///
     /// `FunctionDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -31302,7 +32639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 819:
+    /// Semantic action for production 854:
     ///
     /// `FunctionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -31325,7 +32662,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 820:
+    /// Semantic action for production 855:
     ///
     /// `FunctionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31337,7 +32674,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 821:
+    /// Semantic action for production 856:
     ///
     /// `ImportDeclaration: Import ScopedIdentifier ImportDeclarationOpt /* Option */ Semicolon;`
     ///
@@ -31372,7 +32709,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 822:
+    /// Semantic action for production 857:
     ///
     /// `ImportDeclarationOpt /* Option<T>::Some */: ColonColon Star;`
     ///
@@ -31397,7 +32734,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 823:
+    /// Semantic action for production 858:
     ///
     /// `ImportDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31409,7 +32746,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 824:
+    /// Semantic action for production 859:
     ///
     /// `ExportDeclaration: Export ExportDeclarationGroup Semicolon;`
     ///
@@ -31445,7 +32782,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 825:
+    /// Semantic action for production 860:
     ///
     /// `ExportDeclarationGroup: Star;`
     ///
@@ -31466,7 +32803,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 826:
+    /// Semantic action for production 861:
     ///
     /// `ExportDeclarationGroup: ScopedIdentifier ExportDeclarationOpt /* Option */;`
     ///
@@ -31497,7 +32834,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 827:
+    /// Semantic action for production 862:
     ///
     /// `ExportDeclarationOpt /* Option<T>::Some */: ColonColon Star;`
     ///
@@ -31522,7 +32859,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 828:
+    /// Semantic action for production 863:
     ///
     /// `ExportDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31534,7 +32871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 829:
+    /// Semantic action for production 864:
     ///
     /// `UnsafeBlock: Unsafe LParen Identifier RParen LBrace UnsafeBlockList /* Vec */ RBrace;`
     ///
@@ -31574,7 +32911,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 830:
+    /// Semantic action for production 865:
     ///
     /// `UnsafeBlockList /* Vec<T>::Push */: GenerateGroup UnsafeBlockList;`
     ///
@@ -31597,7 +32934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 831:
+    /// Semantic action for production 866:
     ///
     /// `UnsafeBlockList /* Vec<T>::New */: ;`
     ///
@@ -31610,7 +32947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 832:
+    /// Semantic action for production 867:
     ///
     /// `ModuleDeclaration: ModuleDeclarationOpt /* Option */ Module Identifier ModuleDeclarationOpt0 /* Option */ ModuleDeclarationOpt1 /* Option */ ModuleDeclarationOpt2 /* Option */ ModuleDeclarationOpt3 /* Option */ LBrace ModuleDeclarationList /* Vec */ RBrace;`
     ///
@@ -31688,7 +33025,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 833:
+    /// Semantic action for production 868:
     ///
     /// `ModuleDeclarationList /* Vec<T>::Push */: ModuleGroup ModuleDeclarationList;`
     ///
@@ -31719,7 +33056,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 834:
+    /// Semantic action for production 869:
     ///
     /// `ModuleDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -31735,7 +33072,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 835:
+    /// Semantic action for production 870:
     ///
     /// `ModuleDeclarationOpt3 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -31754,7 +33091,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 836:
+    /// Semantic action for production 871:
     ///
     /// `ModuleDeclarationOpt3 /* Option<T>::None */: ;`
     ///
@@ -31766,7 +33103,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 837:
+    /// Semantic action for production 872:
     ///
     /// `ModuleDeclarationOpt2 /* Option<T>::Some */: WithParameter;`
     ///
@@ -31785,7 +33122,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 838:
+    /// Semantic action for production 873:
     ///
     /// `ModuleDeclarationOpt2 /* Option<T>::None */: ;`
     ///
@@ -31797,7 +33134,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 839:
+    /// Semantic action for production 874:
     ///
     /// `ModuleDeclarationOpt1 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -31822,7 +33159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 840:
+    /// Semantic action for production 875:
     ///
     /// `ModuleDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -31834,7 +33171,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 841:
+    /// Semantic action for production 876:
     ///
     /// `ModuleDeclarationOpt0 /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -31857,7 +33194,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 842:
+    /// Semantic action for production 877:
     ///
     /// `ModuleDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -31869,7 +33206,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 843:
+    /// Semantic action for production 878:
     ///
     /// `ModuleDeclarationOpt /* Option<T>::Some */: Pub;`
     ///
@@ -31888,7 +33225,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 844:
+    /// Semantic action for production 879:
     ///
     /// `ModuleDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31900,7 +33237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 845:
+    /// Semantic action for production 880:
     ///
     /// `ModuleGroup: ModuleGroupList /* Vec */ ModuleGroupGroup;`
     ///
@@ -31925,7 +33262,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 846:
+    /// Semantic action for production 881:
     ///
     /// `ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;`
     ///
@@ -31956,7 +33293,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 847:
+    /// Semantic action for production 882:
     ///
     /// `ModuleGroupGroupList /* Vec<T>::Push */: ModuleGroup ModuleGroupGroupList;`
     ///
@@ -31983,7 +33320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 848:
+    /// Semantic action for production 883:
     ///
     /// `ModuleGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -31999,7 +33336,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 849:
+    /// Semantic action for production 884:
     ///
     /// `ModuleGroupGroup: ModuleItem;`
     ///
@@ -32019,7 +33356,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 850:
+    /// Semantic action for production 885:
     ///
     /// `ModuleGroupList /* Vec<T>::Push */: Attribute ModuleGroupList;`
     ///
@@ -32042,7 +33379,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 851:
+    /// Semantic action for production 886:
     ///
     /// `ModuleGroupList /* Vec<T>::New */: ;`
     ///
@@ -32055,7 +33392,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 852:
+    /// Semantic action for production 887:
     ///
     /// `ModuleItem: GenerateItem;`
     ///
@@ -32073,7 +33410,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 853:
+    /// Semantic action for production 888:
     ///
     /// `InterfaceDeclaration: InterfaceDeclarationOpt /* Option */ Interface Identifier InterfaceDeclarationOpt0 /* Option */ InterfaceDeclarationOpt1 /* Option */ LBrace InterfaceDeclarationList /* Vec */ RBrace;`
     ///
@@ -32139,7 +33476,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 854:
+    /// Semantic action for production 889:
     ///
     /// `InterfaceDeclarationList /* Vec<T>::Push */: InterfaceGroup InterfaceDeclarationList;`
     ///
@@ -32170,7 +33507,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 855:
+    /// Semantic action for production 890:
     ///
     /// `InterfaceDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -32186,7 +33523,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 856:
+    /// Semantic action for production 891:
     ///
     /// `InterfaceDeclarationOpt1 /* Option<T>::Some */: WithParameter;`
     ///
@@ -32205,7 +33542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 857:
+    /// Semantic action for production 892:
     ///
     /// `InterfaceDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -32217,7 +33554,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 858:
+    /// Semantic action for production 893:
     ///
     /// `InterfaceDeclarationOpt0 /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -32240,7 +33577,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 859:
+    /// Semantic action for production 894:
     ///
     /// `InterfaceDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -32252,7 +33589,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 860:
+    /// Semantic action for production 895:
     ///
     /// `InterfaceDeclarationOpt /* Option<T>::Some */: Pub;`
     ///
@@ -32271,7 +33608,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 861:
+    /// Semantic action for production 896:
     ///
     /// `InterfaceDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -32283,7 +33620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 862:
+    /// Semantic action for production 897:
     ///
     /// `InterfaceGroup: InterfaceGroupList /* Vec */ InterfaceGroupGroup;`
     ///
@@ -32309,7 +33646,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 863:
+    /// Semantic action for production 898:
     ///
     /// `InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;`
     ///
@@ -32345,7 +33682,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 864:
+    /// Semantic action for production 899:
     ///
     /// `InterfaceGroupGroupList /* Vec<T>::Push */: InterfaceGroup InterfaceGroupGroupList;`
     ///
@@ -32376,7 +33713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 865:
+    /// Semantic action for production 900:
     ///
     /// `InterfaceGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -32392,7 +33729,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 866:
+    /// Semantic action for production 901:
     ///
     /// `InterfaceGroupGroup: InterfaceItem;`
     ///
@@ -32413,7 +33750,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 867:
+    /// Semantic action for production 902:
     ///
     /// `InterfaceGroupList /* Vec<T>::Push */: Attribute InterfaceGroupList;`
     ///
@@ -32437,7 +33774,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 868:
+    /// Semantic action for production 903:
     ///
     /// `InterfaceGroupList /* Vec<T>::New */: ;`
     ///
@@ -32453,7 +33790,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 869:
+    /// Semantic action for production 904:
     ///
     /// `InterfaceItem: GenerateItem;`
     ///
@@ -32472,7 +33809,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 870:
+    /// Semantic action for production 905:
     ///
     /// `InterfaceItem: ModportDeclaration;`
     ///
@@ -32491,7 +33828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 871:
+    /// Semantic action for production 906:
     ///
     /// `GenerateIfDeclaration: If Expression GenerateNamedBlock GenerateIfDeclarationList /* Vec */ GenerateIfDeclarationOpt /* Option */;`
     ///
@@ -32539,7 +33876,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 872:
+    /// Semantic action for production 907:
     ///
     /// `GenerateIfDeclarationList /* Vec<T>::Push */: Else If Expression GenerateOptionalNamedBlock GenerateIfDeclarationList;`
     ///
@@ -32584,7 +33921,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 873:
+    /// Semantic action for production 908:
     ///
     /// `GenerateIfDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -32600,7 +33937,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 874:
+    /// Semantic action for production 909:
     ///
     /// `GenerateIfDeclarationOpt /* Option<T>::Some */: Else GenerateOptionalNamedBlock;`
     ///
@@ -32630,7 +33967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 875:
+    /// Semantic action for production 910:
     ///
     /// `GenerateIfDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -32642,7 +33979,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 876:
+    /// Semantic action for production 911:
     ///
     /// `GenerateForDeclaration: For Identifier In Range GenerateForDeclarationOpt /* Option */ GenerateNamedBlock;`
     ///
@@ -32688,7 +34025,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 877:
+    /// Semantic action for production 912:
     ///
     /// `GenerateForDeclarationOpt /* Option<T>::Some */: Step AssignmentOperator Expression;`
     ///
@@ -32716,7 +34053,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 878:
+    /// Semantic action for production 913:
     ///
     /// `GenerateForDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -32728,7 +34065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 879:
+    /// Semantic action for production 914:
     ///
     /// `GenerateBlockDeclaration: GenerateNamedBlock;`
     ///
@@ -32754,7 +34091,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 880:
+    /// Semantic action for production 915:
     ///
     /// `GenerateNamedBlock: Colon Identifier LBrace GenerateNamedBlockList /* Vec */ RBrace;`
     ///
@@ -32796,7 +34133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 881:
+    /// Semantic action for production 916:
     ///
     /// `GenerateNamedBlockList /* Vec<T>::Push */: GenerateGroup GenerateNamedBlockList;`
     ///
@@ -32827,7 +34164,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 882:
+    /// Semantic action for production 917:
     ///
     /// `GenerateNamedBlockList /* Vec<T>::New */: ;`
     ///
@@ -32843,7 +34180,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 883:
+    /// Semantic action for production 918:
     ///
     /// `GenerateOptionalNamedBlock: GenerateOptionalNamedBlockOpt /* Option */ LBrace GenerateOptionalNamedBlockList /* Vec */ RBrace;`
     ///
@@ -32887,7 +34224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 884:
+    /// Semantic action for production 919:
     ///
     /// `GenerateOptionalNamedBlockList /* Vec<T>::Push */: GenerateGroup GenerateOptionalNamedBlockList;`
     ///
@@ -32918,7 +34255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 885:
+    /// Semantic action for production 920:
     ///
     /// `GenerateOptionalNamedBlockList /* Vec<T>::New */: ;`
     ///
@@ -32934,7 +34271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 886:
+    /// Semantic action for production 921:
     ///
     /// `GenerateOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;`
     ///
@@ -32959,7 +34296,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 887:
+    /// Semantic action for production 922:
     ///
     /// `GenerateOptionalNamedBlockOpt /* Option<T>::None */: ;`
     ///
@@ -32971,7 +34308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 888:
+    /// Semantic action for production 923:
     ///
     /// `GenerateGroup: GenerateGroupList /* Vec */ GenerateGroupGroup;`
     ///
@@ -32997,7 +34334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 889:
+    /// Semantic action for production 924:
     ///
     /// `GenerateGroupGroup: LBrace GenerateGroupGroupList /* Vec */ RBrace;`
     ///
@@ -33032,7 +34369,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 890:
+    /// Semantic action for production 925:
     ///
     /// `GenerateGroupGroupList /* Vec<T>::Push */: GenerateGroup GenerateGroupGroupList;`
     ///
@@ -33063,7 +34400,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 891:
+    /// Semantic action for production 926:
     ///
     /// `GenerateGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -33079,7 +34416,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 892:
+    /// Semantic action for production 927:
     ///
     /// `GenerateGroupGroup: GenerateItem;`
     ///
@@ -33100,7 +34437,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 893:
+    /// Semantic action for production 928:
     ///
     /// `GenerateGroupList /* Vec<T>::Push */: Attribute GenerateGroupList;`
     ///
@@ -33124,7 +34461,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 894:
+    /// Semantic action for production 929:
     ///
     /// `GenerateGroupList /* Vec<T>::New */: ;`
     ///
@@ -33140,7 +34477,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 895:
+    /// Semantic action for production 930:
     ///
     /// `GenerateItem: LetDeclaration;`
     ///
@@ -33159,7 +34496,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 896:
+    /// Semantic action for production 931:
     ///
     /// `GenerateItem: VarDeclaration;`
     ///
@@ -33178,7 +34515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 897:
+    /// Semantic action for production 932:
     ///
     /// `GenerateItem: InstDeclaration;`
     ///
@@ -33197,7 +34534,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 898:
+    /// Semantic action for production 933:
     ///
     /// `GenerateItem: ConstDeclaration;`
     ///
@@ -33216,7 +34553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 899:
+    /// Semantic action for production 934:
     ///
     /// `GenerateItem: AlwaysFfDeclaration;`
     ///
@@ -33236,7 +34573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 900:
+    /// Semantic action for production 935:
     ///
     /// `GenerateItem: AlwaysCombDeclaration;`
     ///
@@ -33260,7 +34597,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 901:
+    /// Semantic action for production 936:
     ///
     /// `GenerateItem: AssignDeclaration;`
     ///
@@ -33279,7 +34616,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 902:
+    /// Semantic action for production 937:
     ///
     /// `GenerateItem: FunctionDeclaration;`
     ///
@@ -33299,7 +34636,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 903:
+    /// Semantic action for production 938:
     ///
     /// `GenerateItem: GenerateIfDeclaration;`
     ///
@@ -33323,7 +34660,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 904:
+    /// Semantic action for production 939:
     ///
     /// `GenerateItem: GenerateForDeclaration;`
     ///
@@ -33347,7 +34684,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 905:
+    /// Semantic action for production 940:
     ///
     /// `GenerateItem: GenerateBlockDeclaration;`
     ///
@@ -33371,7 +34708,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 906:
+    /// Semantic action for production 941:
     ///
     /// `GenerateItem: TypeDefDeclaration;`
     ///
@@ -33391,7 +34728,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 907:
+    /// Semantic action for production 942:
     ///
     /// `GenerateItem: EnumDeclaration;`
     ///
@@ -33410,7 +34747,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 908:
+    /// Semantic action for production 943:
     ///
     /// `GenerateItem: StructUnionDeclaration;`
     ///
@@ -33434,7 +34771,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 909:
+    /// Semantic action for production 944:
     ///
     /// `GenerateItem: ImportDeclaration;`
     ///
@@ -33453,7 +34790,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 910:
+    /// Semantic action for production 945:
     ///
     /// `GenerateItem: InitialDeclaration;`
     ///
@@ -33472,7 +34809,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 911:
+    /// Semantic action for production 946:
     ///
     /// `GenerateItem: FinalDeclaration;`
     ///
@@ -33491,26 +34828,46 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 912:
+    /// Semantic action for production 947:
+    ///
+    /// `GenerateItem: AssertionDeclaration;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn generate_item_17(&mut self, _assertion_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assertion_declaration =
+            pop_item!(self, assertion_declaration, AssertionDeclaration, context);
+        let generate_item_17_built = GenerateItemAssertionDeclaration {
+            assertion_declaration: Box::new(assertion_declaration),
+        };
+        let generate_item_17_built = GenerateItem::AssertionDeclaration(generate_item_17_built);
+        // Calling user action here
+        self.user_grammar.generate_item(&generate_item_17_built)?;
+        self.push(ASTType::GenerateItem(generate_item_17_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 948:
     ///
     /// `GenerateItem: UnsafeBlock;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_17(&mut self, _unsafe_block: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_18(&mut self, _unsafe_block: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let unsafe_block = pop_item!(self, unsafe_block, UnsafeBlock, context);
-        let generate_item_17_built = GenerateItemUnsafeBlock {
+        let generate_item_18_built = GenerateItemUnsafeBlock {
             unsafe_block: Box::new(unsafe_block),
         };
-        let generate_item_17_built = GenerateItem::UnsafeBlock(generate_item_17_built);
+        let generate_item_18_built = GenerateItem::UnsafeBlock(generate_item_18_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_17_built)?;
-        self.push(ASTType::GenerateItem(generate_item_17_built), context);
+        self.user_grammar.generate_item(&generate_item_18_built)?;
+        self.push(ASTType::GenerateItem(generate_item_18_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 913:
+    /// Semantic action for production 949:
     ///
     /// `PackageDeclaration: PackageDeclarationOpt /* Option */ Package Identifier PackageDeclarationOpt0 /* Option */ LBrace PackageDeclarationList /* Vec */ RBrace;`
     ///
@@ -33568,7 +34925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 914:
+    /// Semantic action for production 950:
     ///
     /// `PackageDeclarationList /* Vec<T>::Push */: PackageGroup PackageDeclarationList;`
     ///
@@ -33599,7 +34956,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 915:
+    /// Semantic action for production 951:
     ///
     /// `PackageDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -33615,7 +34972,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 916:
+    /// Semantic action for production 952:
     ///
     /// `PackageDeclarationOpt0 /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -33638,7 +34995,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 917:
+    /// Semantic action for production 953:
     ///
     /// `PackageDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -33650,7 +35007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 918:
+    /// Semantic action for production 954:
     ///
     /// `PackageDeclarationOpt /* Option<T>::Some */: Pub;`
     ///
@@ -33669,7 +35026,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 919:
+    /// Semantic action for production 955:
     ///
     /// `PackageDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -33681,7 +35038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 920:
+    /// Semantic action for production 956:
     ///
     /// `PackageGroup: PackageGroupList /* Vec */ PackageGroupGroup;`
     ///
@@ -33706,7 +35063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 921:
+    /// Semantic action for production 957:
     ///
     /// `PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;`
     ///
@@ -33741,7 +35098,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 922:
+    /// Semantic action for production 958:
     ///
     /// `PackageGroupGroupList /* Vec<T>::Push */: PackageGroup PackageGroupGroupList;`
     ///
@@ -33772,7 +35129,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 923:
+    /// Semantic action for production 959:
     ///
     /// `PackageGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -33788,7 +35145,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 924:
+    /// Semantic action for production 960:
     ///
     /// `PackageGroupGroup: PackageItem;`
     ///
@@ -33809,7 +35166,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 925:
+    /// Semantic action for production 961:
     ///
     /// `PackageGroupList /* Vec<T>::Push */: Attribute PackageGroupList;`
     ///
@@ -33832,7 +35189,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 926:
+    /// Semantic action for production 962:
     ///
     /// `PackageGroupList /* Vec<T>::New */: ;`
     ///
@@ -33848,7 +35205,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 927:
+    /// Semantic action for production 963:
     ///
     /// `PackageItem: VarDeclaration;`
     ///
@@ -33867,7 +35224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 928:
+    /// Semantic action for production 964:
     ///
     /// `PackageItem: ConstDeclaration;`
     ///
@@ -33886,7 +35243,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 929:
+    /// Semantic action for production 965:
     ///
     /// `PackageItem: TypeDefDeclaration;`
     ///
@@ -33906,7 +35263,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 930:
+    /// Semantic action for production 966:
     ///
     /// `PackageItem: EnumDeclaration;`
     ///
@@ -33925,7 +35282,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 931:
+    /// Semantic action for production 967:
     ///
     /// `PackageItem: StructUnionDeclaration;`
     ///
@@ -33949,7 +35306,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 932:
+    /// Semantic action for production 968:
     ///
     /// `PackageItem: FunctionDeclaration;`
     ///
@@ -33969,7 +35326,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 933:
+    /// Semantic action for production 969:
     ///
     /// `PackageItem: ImportDeclaration;`
     ///
@@ -33988,7 +35345,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 934:
+    /// Semantic action for production 970:
     ///
     /// `PackageItem: ExportDeclaration;`
     ///
@@ -34007,7 +35364,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 935:
+    /// Semantic action for production 971:
     ///
     /// `ProtoModuleDeclaration: ProtoModuleDeclarationOpt /* Option */ Proto Module Identifier ProtoModuleDeclarationOpt0 /* Option */ ProtoModuleDeclarationOpt1 /* Option */ Semicolon;`
     ///
@@ -34065,7 +35422,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 936:
+    /// Semantic action for production 972:
     ///
     /// `ProtoModuleDeclarationOpt1 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -34087,7 +35444,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 937:
+    /// Semantic action for production 973:
     ///
     /// `ProtoModuleDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -34099,7 +35456,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 938:
+    /// Semantic action for production 974:
     ///
     /// `ProtoModuleDeclarationOpt0 /* Option<T>::Some */: WithParameter;`
     ///
@@ -34121,7 +35478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 939:
+    /// Semantic action for production 975:
     ///
     /// `ProtoModuleDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -34133,7 +35490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 940:
+    /// Semantic action for production 976:
     ///
     /// `ProtoModuleDeclarationOpt /* Option<T>::Some */: Pub;`
     ///
@@ -34152,7 +35509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 941:
+    /// Semantic action for production 977:
     ///
     /// `ProtoModuleDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -34164,7 +35521,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 942:
+    /// Semantic action for production 978:
     ///
     /// `EmbedDeclaration: Embed LParen Identifier RParen Identifier EmbedContent;`
     ///
@@ -34201,7 +35558,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 943:
+    /// Semantic action for production 979:
     ///
     /// `EmbedContent: EmbedContentToken : VerylToken;`
     ///
@@ -34221,7 +35578,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 944:
+    /// Semantic action for production 980:
     ///
     /// `EmbedContentToken: LBraceTerm %push(Embed) LBraceTerm LBraceTerm EmbedContentTokenList /* Vec */ RBraceTerm RBraceTerm RBraceTerm %pop() Comments;`
     ///
@@ -34272,7 +35629,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 945:
+    /// Semantic action for production 981:
     ///
     /// `EmbedContentTokenList /* Vec<T>::Push */: EmbedItem EmbedContentTokenList;`
     ///
@@ -34303,7 +35660,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 946:
+    /// Semantic action for production 982:
     ///
     /// `EmbedContentTokenList /* Vec<T>::New */: ;`
     ///
@@ -34319,7 +35676,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 947:
+    /// Semantic action for production 983:
     ///
     /// `EmbedItem: LBraceTerm EmbedItemList /* Vec */ RBraceTerm;`
     ///
@@ -34347,7 +35704,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 948:
+    /// Semantic action for production 984:
     ///
     /// `EmbedItemList /* Vec<T>::Push */: EmbedItem EmbedItemList;`
     ///
@@ -34370,7 +35727,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 949:
+    /// Semantic action for production 985:
     ///
     /// `EmbedItemList /* Vec<T>::New */: ;`
     ///
@@ -34383,7 +35740,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 950:
+    /// Semantic action for production 986:
     ///
     /// `EmbedItem: AnyTerm;`
     ///
@@ -34402,7 +35759,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 951:
+    /// Semantic action for production 987:
     ///
     /// `IncludeDeclaration: Include LParen Identifier Comma StringLiteral RParen Semicolon;`
     ///
@@ -34445,7 +35802,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 952:
+    /// Semantic action for production 988:
     ///
     /// `DescriptionGroup: DescriptionGroupList /* Vec */ DescriptionGroupGroup;`
     ///
@@ -34476,7 +35833,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 953:
+    /// Semantic action for production 989:
     ///
     /// `DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;`
     ///
@@ -34514,7 +35871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 954:
+    /// Semantic action for production 990:
     ///
     /// `DescriptionGroupGroupList /* Vec<T>::Push */: DescriptionGroup DescriptionGroupGroupList;`
     ///
@@ -34545,7 +35902,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 955:
+    /// Semantic action for production 991:
     ///
     /// `DescriptionGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -34561,7 +35918,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 956:
+    /// Semantic action for production 992:
     ///
     /// `DescriptionGroupGroup: DescriptionItem;`
     ///
@@ -34582,7 +35939,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 957:
+    /// Semantic action for production 993:
     ///
     /// `DescriptionGroupList /* Vec<T>::Push */: Attribute DescriptionGroupList;`
     ///
@@ -34609,7 +35966,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 958:
+    /// Semantic action for production 994:
     ///
     /// `DescriptionGroupList /* Vec<T>::New */: ;`
     ///
@@ -34625,7 +35982,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 959:
+    /// Semantic action for production 995:
     ///
     /// `DescriptionItem: ModuleDeclaration;`
     ///
@@ -34645,7 +36002,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 960:
+    /// Semantic action for production 996:
     ///
     /// `DescriptionItem: InterfaceDeclaration;`
     ///
@@ -34667,7 +36024,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 961:
+    /// Semantic action for production 997:
     ///
     /// `DescriptionItem: PackageDeclaration;`
     ///
@@ -34688,7 +36045,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 962:
+    /// Semantic action for production 998:
     ///
     /// `DescriptionItem: ProtoModuleDeclaration;`
     ///
@@ -34714,7 +36071,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 963:
+    /// Semantic action for production 999:
     ///
     /// `DescriptionItem: ImportDeclaration;`
     ///
@@ -34734,7 +36091,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 964:
+    /// Semantic action for production 1000:
     ///
     /// `DescriptionItem: EmbedDeclaration;`
     ///
@@ -34754,7 +36111,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 965:
+    /// Semantic action for production 1001:
     ///
     /// `DescriptionItem: IncludeDeclaration;`
     ///
@@ -34775,7 +36132,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 966:
+    /// Semantic action for production 1002:
     ///
     /// `Veryl: Start VerylList /* Vec */;`
     ///
@@ -34795,7 +36152,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 967:
+    /// Semantic action for production 1003:
     ///
     /// `VerylList /* Vec<T>::Push */: DescriptionGroup VerylList;`
     ///
@@ -34818,7 +36175,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 968:
+    /// Semantic action for production 1004:
     ///
     /// `VerylList /* Vec<T>::New */: ;`
     ///
@@ -34889,441 +36246,470 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
             44 => self.always_comb_term(&children[0]),
             45 => self.always_ff_term(&children[0]),
             46 => self.assign_term(&children[0]),
-            47 => self.as_term(&children[0]),
-            48 => self.bit_term(&children[0]),
-            49 => self.case_term(&children[0]),
-            50 => self.clock_term(&children[0]),
-            51 => self.clock_posedge_term(&children[0]),
-            52 => self.clock_negedge_term(&children[0]),
-            53 => self.const_term(&children[0]),
-            54 => self.default_term(&children[0]),
-            55 => self.else_term(&children[0]),
-            56 => self.embed_term(&children[0]),
-            57 => self.enum_term(&children[0]),
-            58 => self.export_term(&children[0]),
-            59 => self.f32_term(&children[0]),
-            60 => self.f64_term(&children[0]),
-            61 => self.final_term(&children[0]),
-            62 => self.for_term(&children[0]),
-            63 => self.function_term(&children[0]),
-            64 => self.i32_term(&children[0]),
-            65 => self.i64_term(&children[0]),
-            66 => self.if_reset_term(&children[0]),
-            67 => self.if_term(&children[0]),
-            68 => self.import_term(&children[0]),
-            69 => self.include_term(&children[0]),
-            70 => self.initial_term(&children[0]),
-            71 => self.inout_term(&children[0]),
-            72 => self.input_term(&children[0]),
-            73 => self.inside_term(&children[0]),
-            74 => self.inst_term(&children[0]),
-            75 => self.interface_term(&children[0]),
-            76 => self.in_term(&children[0]),
-            77 => self.let_term(&children[0]),
-            78 => self.logic_term(&children[0]),
-            79 => self.lsb_term(&children[0]),
-            80 => self.modport_term(&children[0]),
-            81 => self.module_term(&children[0]),
-            82 => self.msb_term(&children[0]),
-            83 => self.output_term(&children[0]),
-            84 => self.outside_term(&children[0]),
-            85 => self.package_term(&children[0]),
-            86 => self.param_term(&children[0]),
-            87 => self.proto_term(&children[0]),
-            88 => self.pub_term(&children[0]),
-            89 => self.ref_term(&children[0]),
-            90 => self.repeat_term(&children[0]),
-            91 => self.reset_term(&children[0]),
-            92 => self.reset_async_high_term(&children[0]),
-            93 => self.reset_async_low_term(&children[0]),
-            94 => self.reset_sync_high_term(&children[0]),
-            95 => self.reset_sync_low_term(&children[0]),
-            96 => self.return_term(&children[0]),
-            97 => self.break_term(&children[0]),
-            98 => self.signed_term(&children[0]),
-            99 => self.step_term(&children[0]),
-            100 => self.string_term(&children[0]),
-            101 => self.struct_term(&children[0]),
-            102 => self.switch_term(&children[0]),
-            103 => self.tri_term(&children[0]),
-            104 => self.type_term(&children[0]),
-            105 => self.u32_term(&children[0]),
-            106 => self.u64_term(&children[0]),
-            107 => self.union_term(&children[0]),
-            108 => self.unsafe_term(&children[0]),
-            109 => self.var_term(&children[0]),
-            110 => self.dollar_identifier_term(&children[0]),
-            111 => self.identifier_term(&children[0]),
-            112 => self.any_term(&children[0]),
-            113 => self.comments(&children[0]),
-            114 => self.comments_opt_0(&children[0]),
-            115 => self.comments_opt_1(),
-            116 => self.start_token(&children[0]),
-            117 => self.string_literal_token(&children[0], &children[1]),
-            118 => self.exponent_token(&children[0], &children[1]),
-            119 => self.fixed_point_token(&children[0], &children[1]),
-            120 => self.based_token(&children[0], &children[1]),
-            121 => self.base_less_token(&children[0], &children[1]),
-            122 => self.all_bit_token(&children[0], &children[1]),
-            123 => self.assignment_operator_token(&children[0], &children[1]),
-            124 => self.operator01_token(&children[0], &children[1]),
-            125 => self.operator02_token(&children[0], &children[1]),
-            126 => self.operator03_token(&children[0], &children[1]),
-            127 => self.operator04_token(&children[0], &children[1]),
-            128 => self.operator05_token(&children[0], &children[1]),
-            129 => self.operator06_token(&children[0], &children[1]),
-            130 => self.operator07_token(&children[0], &children[1]),
-            131 => self.operator08_token(&children[0], &children[1]),
-            132 => self.operator09_token(&children[0], &children[1]),
-            133 => self.operator10_token(&children[0], &children[1]),
-            134 => self.operator11_token(&children[0], &children[1]),
-            135 => self.unary_operator_token(&children[0], &children[1]),
-            136 => self.back_quote_token(&children[0], &children[1]),
-            137 => self.colon_token(&children[0], &children[1]),
-            138 => self.colon_colon_l_angle_token(&children[0], &children[1]),
-            139 => self.colon_colon_token(&children[0], &children[1]),
-            140 => self.comma_token(&children[0], &children[1]),
-            141 => self.dot_dot_token(&children[0], &children[1]),
-            142 => self.dot_dot_equ_token(&children[0], &children[1]),
-            143 => self.dot_token(&children[0], &children[1]),
-            144 => self.equ_token(&children[0], &children[1]),
-            145 => self.hash_token(&children[0], &children[1]),
-            146 => self.quote_l_brace_token(&children[0], &children[1]),
-            147 => self.l_angle_token(&children[0], &children[1]),
-            148 => self.l_brace_token(&children[0], &children[1]),
-            149 => self.l_bracket_token(&children[0], &children[1]),
-            150 => self.l_paren_token(&children[0], &children[1]),
-            151 => self.minus_colon_token(&children[0], &children[1]),
-            152 => self.minus_g_t_token(&children[0], &children[1]),
-            153 => self.plus_colon_token(&children[0], &children[1]),
-            154 => self.r_angle_token(&children[0], &children[1]),
-            155 => self.r_brace_token(&children[0], &children[1]),
-            156 => self.r_bracket_token(&children[0], &children[1]),
-            157 => self.r_paren_token(&children[0], &children[1]),
-            158 => self.semicolon_token(&children[0], &children[1]),
-            159 => self.star_token(&children[0], &children[1]),
-            160 => self.always_comb_token(&children[0], &children[1]),
-            161 => self.always_ff_token(&children[0], &children[1]),
-            162 => self.as_token(&children[0], &children[1]),
-            163 => self.assign_token(&children[0], &children[1]),
-            164 => self.bit_token(&children[0], &children[1]),
-            165 => self.case_token(&children[0], &children[1]),
-            166 => self.clock_token(&children[0], &children[1]),
-            167 => self.clock_posedge_token(&children[0], &children[1]),
-            168 => self.clock_negedge_token(&children[0], &children[1]),
-            169 => self.const_token(&children[0], &children[1]),
-            170 => self.default_token(&children[0], &children[1]),
-            171 => self.else_token(&children[0], &children[1]),
-            172 => self.embed_token(&children[0], &children[1]),
-            173 => self.enum_token(&children[0], &children[1]),
-            174 => self.export_token(&children[0], &children[1]),
-            175 => self.f32_token(&children[0], &children[1]),
-            176 => self.f64_token(&children[0], &children[1]),
-            177 => self.final_token(&children[0], &children[1]),
-            178 => self.for_token(&children[0], &children[1]),
-            179 => self.function_token(&children[0], &children[1]),
-            180 => self.i32_token(&children[0], &children[1]),
-            181 => self.i64_token(&children[0], &children[1]),
-            182 => self.if_reset_token(&children[0], &children[1]),
-            183 => self.if_token(&children[0], &children[1]),
-            184 => self.import_token(&children[0], &children[1]),
-            185 => self.include_token(&children[0], &children[1]),
-            186 => self.initial_token(&children[0], &children[1]),
-            187 => self.inout_token(&children[0], &children[1]),
-            188 => self.input_token(&children[0], &children[1]),
-            189 => self.inside_token(&children[0], &children[1]),
-            190 => self.inst_token(&children[0], &children[1]),
-            191 => self.interface_token(&children[0], &children[1]),
-            192 => self.in_token(&children[0], &children[1]),
-            193 => self.let_token(&children[0], &children[1]),
-            194 => self.logic_token(&children[0], &children[1]),
-            195 => self.lsb_token(&children[0], &children[1]),
-            196 => self.modport_token(&children[0], &children[1]),
-            197 => self.module_token(&children[0], &children[1]),
-            198 => self.msb_token(&children[0], &children[1]),
-            199 => self.output_token(&children[0], &children[1]),
-            200 => self.outside_token(&children[0], &children[1]),
-            201 => self.package_token(&children[0], &children[1]),
-            202 => self.param_token(&children[0], &children[1]),
-            203 => self.proto_token(&children[0], &children[1]),
-            204 => self.pub_token(&children[0], &children[1]),
-            205 => self.ref_token(&children[0], &children[1]),
-            206 => self.repeat_token(&children[0], &children[1]),
-            207 => self.reset_token(&children[0], &children[1]),
-            208 => self.reset_async_high_token(&children[0], &children[1]),
-            209 => self.reset_async_low_token(&children[0], &children[1]),
-            210 => self.reset_sync_high_token(&children[0], &children[1]),
-            211 => self.reset_sync_low_token(&children[0], &children[1]),
-            212 => self.return_token(&children[0], &children[1]),
-            213 => self.break_token(&children[0], &children[1]),
-            214 => self.signed_token(&children[0], &children[1]),
-            215 => self.step_token(&children[0], &children[1]),
-            216 => self.string_token(&children[0], &children[1]),
-            217 => self.struct_token(&children[0], &children[1]),
-            218 => self.switch_token(&children[0], &children[1]),
-            219 => self.tri_token(&children[0], &children[1]),
-            220 => self.type_token(&children[0], &children[1]),
-            221 => self.u32_token(&children[0], &children[1]),
-            222 => self.u64_token(&children[0], &children[1]),
-            223 => self.union_token(&children[0], &children[1]),
-            224 => self.unsafe_token(&children[0], &children[1]),
-            225 => self.var_token(&children[0], &children[1]),
-            226 => self.dollar_identifier_token(&children[0], &children[1]),
-            227 => self.identifier_token(&children[0], &children[1]),
-            228 => self.start(&children[0]),
-            229 => self.string_literal(&children[0]),
-            230 => self.exponent(&children[0]),
-            231 => self.fixed_point(&children[0]),
-            232 => self.based(&children[0]),
-            233 => self.base_less(&children[0]),
-            234 => self.all_bit(&children[0]),
-            235 => self.assignment_operator(&children[0]),
-            236 => self.operator01(&children[0]),
-            237 => self.operator02(&children[0]),
-            238 => self.operator03(&children[0]),
-            239 => self.operator04(&children[0]),
-            240 => self.operator05(&children[0]),
-            241 => self.operator06(&children[0]),
-            242 => self.operator07(&children[0]),
-            243 => self.operator08(&children[0]),
-            244 => self.operator09(&children[0]),
-            245 => self.operator10(&children[0]),
-            246 => self.operator11(&children[0]),
-            247 => self.unary_operator(&children[0]),
-            248 => self.back_quote(&children[0]),
-            249 => self.colon(&children[0]),
-            250 => self.colon_colon_l_angle(&children[0]),
-            251 => self.colon_colon(&children[0]),
-            252 => self.comma(&children[0]),
-            253 => self.dot_dot(&children[0]),
-            254 => self.dot_dot_equ(&children[0]),
-            255 => self.dot(&children[0]),
-            256 => self.equ(&children[0]),
-            257 => self.hash(&children[0]),
-            258 => self.quote_l_brace(&children[0]),
-            259 => self.l_angle(&children[0]),
-            260 => self.l_brace(&children[0]),
-            261 => self.l_bracket(&children[0]),
-            262 => self.l_paren(&children[0]),
-            263 => self.minus_colon(&children[0]),
-            264 => self.minus_g_t(&children[0]),
-            265 => self.plus_colon(&children[0]),
-            266 => self.r_angle(&children[0]),
-            267 => self.r_brace(&children[0]),
-            268 => self.r_bracket(&children[0]),
-            269 => self.r_paren(&children[0]),
-            270 => self.semicolon(&children[0]),
-            271 => self.star(&children[0]),
-            272 => self.always_comb(&children[0]),
-            273 => self.always_ff(&children[0]),
-            274 => self.r#as(&children[0]),
-            275 => self.assign(&children[0]),
-            276 => self.bit(&children[0]),
-            277 => self.r#break(&children[0]),
-            278 => self.case(&children[0]),
-            279 => self.clock(&children[0]),
-            280 => self.clock_posedge(&children[0]),
-            281 => self.clock_negedge(&children[0]),
-            282 => self.r#const(&children[0]),
-            283 => self.defaul(&children[0]),
-            284 => self.r#else(&children[0]),
-            285 => self.embed(&children[0]),
-            286 => self.r#enum(&children[0]),
-            287 => self.export(&children[0]),
-            288 => self.f32(&children[0]),
-            289 => self.f64(&children[0]),
-            290 => self.r#final(&children[0]),
-            291 => self.r#for(&children[0]),
-            292 => self.function(&children[0]),
-            293 => self.i32(&children[0]),
-            294 => self.i64(&children[0]),
-            295 => self.r#if(&children[0]),
-            296 => self.if_reset(&children[0]),
-            297 => self.import(&children[0]),
-            298 => self.r#in(&children[0]),
-            299 => self.include(&children[0]),
-            300 => self.initial(&children[0]),
-            301 => self.inout(&children[0]),
-            302 => self.input(&children[0]),
-            303 => self.inside(&children[0]),
-            304 => self.inst(&children[0]),
-            305 => self.interface(&children[0]),
-            306 => self.r#let(&children[0]),
-            307 => self.logic(&children[0]),
-            308 => self.lsb(&children[0]),
-            309 => self.modport(&children[0]),
-            310 => self.module(&children[0]),
-            311 => self.msb(&children[0]),
-            312 => self.output(&children[0]),
-            313 => self.outside(&children[0]),
-            314 => self.package(&children[0]),
-            315 => self.param(&children[0]),
-            316 => self.proto(&children[0]),
-            317 => self.r#pub(&children[0]),
-            318 => self.r#ref(&children[0]),
-            319 => self.repeat(&children[0]),
-            320 => self.reset(&children[0]),
-            321 => self.reset_async_high(&children[0]),
-            322 => self.reset_async_low(&children[0]),
-            323 => self.reset_sync_high(&children[0]),
-            324 => self.reset_sync_low(&children[0]),
-            325 => self.r#return(&children[0]),
-            326 => self.signed(&children[0]),
-            327 => self.step(&children[0]),
-            328 => self.strin(&children[0]),
-            329 => self.r#struct(&children[0]),
-            330 => self.switch(&children[0]),
-            331 => self.tri(&children[0]),
-            332 => self.r#type(&children[0]),
-            333 => self.u32(&children[0]),
-            334 => self.u64(&children[0]),
-            335 => self.r#union(&children[0]),
-            336 => self.r#unsafe(&children[0]),
-            337 => self.var(&children[0]),
-            338 => self.dollar_identifier(&children[0]),
-            339 => self.identifier(&children[0]),
-            340 => self.number_0(&children[0]),
-            341 => self.number_1(&children[0]),
-            342 => self.integral_number_0(&children[0]),
-            343 => self.integral_number_1(&children[0]),
-            344 => self.integral_number_2(&children[0]),
-            345 => self.real_number_0(&children[0]),
-            346 => self.real_number_1(&children[0]),
-            347 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
-            348 => self.hierarchical_identifier_list0_0(
+            47 => self.assert_term(&children[0]),
+            48 => self.assume_term(&children[0]),
+            49 => self.as_term(&children[0]),
+            50 => self.bit_term(&children[0]),
+            51 => self.case_term(&children[0]),
+            52 => self.clock_term(&children[0]),
+            53 => self.clock_posedge_term(&children[0]),
+            54 => self.clock_negedge_term(&children[0]),
+            55 => self.cover_term(&children[0]),
+            56 => self.const_term(&children[0]),
+            57 => self.default_term(&children[0]),
+            58 => self.else_term(&children[0]),
+            59 => self.embed_term(&children[0]),
+            60 => self.enum_term(&children[0]),
+            61 => self.export_term(&children[0]),
+            62 => self.f32_term(&children[0]),
+            63 => self.f64_term(&children[0]),
+            64 => self.final_term(&children[0]),
+            65 => self.for_term(&children[0]),
+            66 => self.function_term(&children[0]),
+            67 => self.i32_term(&children[0]),
+            68 => self.i64_term(&children[0]),
+            69 => self.if_reset_term(&children[0]),
+            70 => self.if_term(&children[0]),
+            71 => self.import_term(&children[0]),
+            72 => self.include_term(&children[0]),
+            73 => self.initial_term(&children[0]),
+            74 => self.inout_term(&children[0]),
+            75 => self.input_term(&children[0]),
+            76 => self.inside_term(&children[0]),
+            77 => self.inst_term(&children[0]),
+            78 => self.interface_term(&children[0]),
+            79 => self.in_term(&children[0]),
+            80 => self.let_term(&children[0]),
+            81 => self.logic_term(&children[0]),
+            82 => self.lsb_term(&children[0]),
+            83 => self.modport_term(&children[0]),
+            84 => self.module_term(&children[0]),
+            85 => self.msb_term(&children[0]),
+            86 => self.output_term(&children[0]),
+            87 => self.outside_term(&children[0]),
+            88 => self.package_term(&children[0]),
+            89 => self.param_term(&children[0]),
+            90 => self.property_term(&children[0]),
+            91 => self.proto_term(&children[0]),
+            92 => self.pub_term(&children[0]),
+            93 => self.ref_term(&children[0]),
+            94 => self.repeat_term(&children[0]),
+            95 => self.reset_term(&children[0]),
+            96 => self.reset_async_high_term(&children[0]),
+            97 => self.reset_async_low_term(&children[0]),
+            98 => self.reset_sync_high_term(&children[0]),
+            99 => self.reset_sync_low_term(&children[0]),
+            100 => self.return_term(&children[0]),
+            101 => self.break_term(&children[0]),
+            102 => self.signed_term(&children[0]),
+            103 => self.step_term(&children[0]),
+            104 => self.string_term(&children[0]),
+            105 => self.struct_term(&children[0]),
+            106 => self.switch_term(&children[0]),
+            107 => self.tri_term(&children[0]),
+            108 => self.type_term(&children[0]),
+            109 => self.u32_term(&children[0]),
+            110 => self.u64_term(&children[0]),
+            111 => self.union_term(&children[0]),
+            112 => self.unsafe_term(&children[0]),
+            113 => self.var_term(&children[0]),
+            114 => self.dollar_identifier_term(&children[0]),
+            115 => self.identifier_term(&children[0]),
+            116 => self.any_term(&children[0]),
+            117 => self.comments(&children[0]),
+            118 => self.comments_opt_0(&children[0]),
+            119 => self.comments_opt_1(),
+            120 => self.start_token(&children[0]),
+            121 => self.string_literal_token(&children[0], &children[1]),
+            122 => self.exponent_token(&children[0], &children[1]),
+            123 => self.fixed_point_token(&children[0], &children[1]),
+            124 => self.based_token(&children[0], &children[1]),
+            125 => self.base_less_token(&children[0], &children[1]),
+            126 => self.all_bit_token(&children[0], &children[1]),
+            127 => self.assignment_operator_token(&children[0], &children[1]),
+            128 => self.operator01_token(&children[0], &children[1]),
+            129 => self.operator02_token(&children[0], &children[1]),
+            130 => self.operator03_token(&children[0], &children[1]),
+            131 => self.operator04_token(&children[0], &children[1]),
+            132 => self.operator05_token(&children[0], &children[1]),
+            133 => self.operator06_token(&children[0], &children[1]),
+            134 => self.operator07_token(&children[0], &children[1]),
+            135 => self.operator08_token(&children[0], &children[1]),
+            136 => self.operator09_token(&children[0], &children[1]),
+            137 => self.operator10_token(&children[0], &children[1]),
+            138 => self.operator11_token(&children[0], &children[1]),
+            139 => self.unary_operator_token(&children[0], &children[1]),
+            140 => self.back_quote_token(&children[0], &children[1]),
+            141 => self.colon_token(&children[0], &children[1]),
+            142 => self.colon_colon_l_angle_token(&children[0], &children[1]),
+            143 => self.colon_colon_token(&children[0], &children[1]),
+            144 => self.comma_token(&children[0], &children[1]),
+            145 => self.dot_dot_token(&children[0], &children[1]),
+            146 => self.dot_dot_equ_token(&children[0], &children[1]),
+            147 => self.dot_token(&children[0], &children[1]),
+            148 => self.equ_token(&children[0], &children[1]),
+            149 => self.hash_token(&children[0], &children[1]),
+            150 => self.quote_l_brace_token(&children[0], &children[1]),
+            151 => self.l_angle_token(&children[0], &children[1]),
+            152 => self.l_brace_token(&children[0], &children[1]),
+            153 => self.l_bracket_token(&children[0], &children[1]),
+            154 => self.l_paren_token(&children[0], &children[1]),
+            155 => self.minus_colon_token(&children[0], &children[1]),
+            156 => self.minus_g_t_token(&children[0], &children[1]),
+            157 => self.plus_colon_token(&children[0], &children[1]),
+            158 => self.r_angle_token(&children[0], &children[1]),
+            159 => self.r_brace_token(&children[0], &children[1]),
+            160 => self.r_bracket_token(&children[0], &children[1]),
+            161 => self.r_paren_token(&children[0], &children[1]),
+            162 => self.semicolon_token(&children[0], &children[1]),
+            163 => self.star_token(&children[0], &children[1]),
+            164 => self.always_comb_token(&children[0], &children[1]),
+            165 => self.always_ff_token(&children[0], &children[1]),
+            166 => self.as_token(&children[0], &children[1]),
+            167 => self.assert_token(&children[0], &children[1]),
+            168 => self.assign_token(&children[0], &children[1]),
+            169 => self.assume_token(&children[0], &children[1]),
+            170 => self.bit_token(&children[0], &children[1]),
+            171 => self.case_token(&children[0], &children[1]),
+            172 => self.clock_token(&children[0], &children[1]),
+            173 => self.clock_posedge_token(&children[0], &children[1]),
+            174 => self.clock_negedge_token(&children[0], &children[1]),
+            175 => self.cover_token(&children[0], &children[1]),
+            176 => self.const_token(&children[0], &children[1]),
+            177 => self.default_token(&children[0], &children[1]),
+            178 => self.else_token(&children[0], &children[1]),
+            179 => self.embed_token(&children[0], &children[1]),
+            180 => self.enum_token(&children[0], &children[1]),
+            181 => self.export_token(&children[0], &children[1]),
+            182 => self.f32_token(&children[0], &children[1]),
+            183 => self.f64_token(&children[0], &children[1]),
+            184 => self.final_token(&children[0], &children[1]),
+            185 => self.for_token(&children[0], &children[1]),
+            186 => self.function_token(&children[0], &children[1]),
+            187 => self.i32_token(&children[0], &children[1]),
+            188 => self.i64_token(&children[0], &children[1]),
+            189 => self.if_reset_token(&children[0], &children[1]),
+            190 => self.if_token(&children[0], &children[1]),
+            191 => self.import_token(&children[0], &children[1]),
+            192 => self.include_token(&children[0], &children[1]),
+            193 => self.initial_token(&children[0], &children[1]),
+            194 => self.inout_token(&children[0], &children[1]),
+            195 => self.input_token(&children[0], &children[1]),
+            196 => self.inside_token(&children[0], &children[1]),
+            197 => self.inst_token(&children[0], &children[1]),
+            198 => self.interface_token(&children[0], &children[1]),
+            199 => self.in_token(&children[0], &children[1]),
+            200 => self.let_token(&children[0], &children[1]),
+            201 => self.logic_token(&children[0], &children[1]),
+            202 => self.lsb_token(&children[0], &children[1]),
+            203 => self.modport_token(&children[0], &children[1]),
+            204 => self.module_token(&children[0], &children[1]),
+            205 => self.msb_token(&children[0], &children[1]),
+            206 => self.output_token(&children[0], &children[1]),
+            207 => self.outside_token(&children[0], &children[1]),
+            208 => self.package_token(&children[0], &children[1]),
+            209 => self.param_token(&children[0], &children[1]),
+            210 => self.property_token(&children[0], &children[1]),
+            211 => self.proto_token(&children[0], &children[1]),
+            212 => self.pub_token(&children[0], &children[1]),
+            213 => self.ref_token(&children[0], &children[1]),
+            214 => self.repeat_token(&children[0], &children[1]),
+            215 => self.reset_token(&children[0], &children[1]),
+            216 => self.reset_async_high_token(&children[0], &children[1]),
+            217 => self.reset_async_low_token(&children[0], &children[1]),
+            218 => self.reset_sync_high_token(&children[0], &children[1]),
+            219 => self.reset_sync_low_token(&children[0], &children[1]),
+            220 => self.return_token(&children[0], &children[1]),
+            221 => self.break_token(&children[0], &children[1]),
+            222 => self.signed_token(&children[0], &children[1]),
+            223 => self.step_token(&children[0], &children[1]),
+            224 => self.string_token(&children[0], &children[1]),
+            225 => self.struct_token(&children[0], &children[1]),
+            226 => self.switch_token(&children[0], &children[1]),
+            227 => self.tri_token(&children[0], &children[1]),
+            228 => self.type_token(&children[0], &children[1]),
+            229 => self.u32_token(&children[0], &children[1]),
+            230 => self.u64_token(&children[0], &children[1]),
+            231 => self.union_token(&children[0], &children[1]),
+            232 => self.unsafe_token(&children[0], &children[1]),
+            233 => self.var_token(&children[0], &children[1]),
+            234 => self.dollar_identifier_token(&children[0], &children[1]),
+            235 => self.identifier_token(&children[0], &children[1]),
+            236 => self.start(&children[0]),
+            237 => self.string_literal(&children[0]),
+            238 => self.exponent(&children[0]),
+            239 => self.fixed_point(&children[0]),
+            240 => self.based(&children[0]),
+            241 => self.base_less(&children[0]),
+            242 => self.all_bit(&children[0]),
+            243 => self.assignment_operator(&children[0]),
+            244 => self.operator01(&children[0]),
+            245 => self.operator02(&children[0]),
+            246 => self.operator03(&children[0]),
+            247 => self.operator04(&children[0]),
+            248 => self.operator05(&children[0]),
+            249 => self.operator06(&children[0]),
+            250 => self.operator07(&children[0]),
+            251 => self.operator08(&children[0]),
+            252 => self.operator09(&children[0]),
+            253 => self.operator10(&children[0]),
+            254 => self.operator11(&children[0]),
+            255 => self.unary_operator(&children[0]),
+            256 => self.back_quote(&children[0]),
+            257 => self.colon(&children[0]),
+            258 => self.colon_colon_l_angle(&children[0]),
+            259 => self.colon_colon(&children[0]),
+            260 => self.comma(&children[0]),
+            261 => self.dot_dot(&children[0]),
+            262 => self.dot_dot_equ(&children[0]),
+            263 => self.dot(&children[0]),
+            264 => self.equ(&children[0]),
+            265 => self.hash(&children[0]),
+            266 => self.quote_l_brace(&children[0]),
+            267 => self.l_angle(&children[0]),
+            268 => self.l_brace(&children[0]),
+            269 => self.l_bracket(&children[0]),
+            270 => self.l_paren(&children[0]),
+            271 => self.minus_colon(&children[0]),
+            272 => self.minus_g_t(&children[0]),
+            273 => self.plus_colon(&children[0]),
+            274 => self.r_angle(&children[0]),
+            275 => self.r_brace(&children[0]),
+            276 => self.r_bracket(&children[0]),
+            277 => self.r_paren(&children[0]),
+            278 => self.semicolon(&children[0]),
+            279 => self.star(&children[0]),
+            280 => self.always_comb(&children[0]),
+            281 => self.always_ff(&children[0]),
+            282 => self.r#as(&children[0]),
+            283 => self.assert(&children[0]),
+            284 => self.assign(&children[0]),
+            285 => self.assume(&children[0]),
+            286 => self.bit(&children[0]),
+            287 => self.r#break(&children[0]),
+            288 => self.case(&children[0]),
+            289 => self.clock(&children[0]),
+            290 => self.clock_posedge(&children[0]),
+            291 => self.clock_negedge(&children[0]),
+            292 => self.cover(&children[0]),
+            293 => self.r#const(&children[0]),
+            294 => self.defaul(&children[0]),
+            295 => self.r#else(&children[0]),
+            296 => self.embed(&children[0]),
+            297 => self.r#enum(&children[0]),
+            298 => self.export(&children[0]),
+            299 => self.f32(&children[0]),
+            300 => self.f64(&children[0]),
+            301 => self.r#final(&children[0]),
+            302 => self.r#for(&children[0]),
+            303 => self.function(&children[0]),
+            304 => self.i32(&children[0]),
+            305 => self.i64(&children[0]),
+            306 => self.r#if(&children[0]),
+            307 => self.if_reset(&children[0]),
+            308 => self.import(&children[0]),
+            309 => self.r#in(&children[0]),
+            310 => self.include(&children[0]),
+            311 => self.initial(&children[0]),
+            312 => self.inout(&children[0]),
+            313 => self.input(&children[0]),
+            314 => self.inside(&children[0]),
+            315 => self.inst(&children[0]),
+            316 => self.interface(&children[0]),
+            317 => self.r#let(&children[0]),
+            318 => self.logic(&children[0]),
+            319 => self.lsb(&children[0]),
+            320 => self.modport(&children[0]),
+            321 => self.module(&children[0]),
+            322 => self.msb(&children[0]),
+            323 => self.output(&children[0]),
+            324 => self.outside(&children[0]),
+            325 => self.package(&children[0]),
+            326 => self.param(&children[0]),
+            327 => self.property(&children[0]),
+            328 => self.proto(&children[0]),
+            329 => self.r#pub(&children[0]),
+            330 => self.r#ref(&children[0]),
+            331 => self.repeat(&children[0]),
+            332 => self.reset(&children[0]),
+            333 => self.reset_async_high(&children[0]),
+            334 => self.reset_async_low(&children[0]),
+            335 => self.reset_sync_high(&children[0]),
+            336 => self.reset_sync_low(&children[0]),
+            337 => self.r#return(&children[0]),
+            338 => self.signed(&children[0]),
+            339 => self.step(&children[0]),
+            340 => self.strin(&children[0]),
+            341 => self.r#struct(&children[0]),
+            342 => self.switch(&children[0]),
+            343 => self.tri(&children[0]),
+            344 => self.r#type(&children[0]),
+            345 => self.u32(&children[0]),
+            346 => self.u64(&children[0]),
+            347 => self.r#union(&children[0]),
+            348 => self.r#unsafe(&children[0]),
+            349 => self.var(&children[0]),
+            350 => self.dollar_identifier(&children[0]),
+            351 => self.identifier(&children[0]),
+            352 => self.number_0(&children[0]),
+            353 => self.number_1(&children[0]),
+            354 => self.integral_number_0(&children[0]),
+            355 => self.integral_number_1(&children[0]),
+            356 => self.integral_number_2(&children[0]),
+            357 => self.real_number_0(&children[0]),
+            358 => self.real_number_1(&children[0]),
+            359 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
+            360 => self.hierarchical_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            349 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
-            350 => self.hierarchical_identifier_list0_list_1(),
-            351 => self.hierarchical_identifier_list0_1(),
-            352 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
-            353 => self.hierarchical_identifier_list_1(),
-            354 => self.scoped_identifier(&children[0], &children[1]),
-            355 => self.scoped_identifier_group_0(&children[0]),
-            356 => self.scoped_identifier_group_1(&children[0], &children[1]),
-            357 => self.scoped_identifier_list_0(
+            361 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
+            362 => self.hierarchical_identifier_list0_list_1(),
+            363 => self.hierarchical_identifier_list0_1(),
+            364 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
+            365 => self.hierarchical_identifier_list_1(),
+            366 => self.scoped_identifier(&children[0], &children[1]),
+            367 => self.scoped_identifier_group_0(&children[0]),
+            368 => self.scoped_identifier_group_1(&children[0], &children[1]),
+            369 => self.scoped_identifier_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            358 => self.scoped_identifier_list_1(),
-            359 => self.scoped_identifier_opt0_0(&children[0]),
-            360 => self.scoped_identifier_opt0_1(),
-            361 => self.scoped_identifier_opt_0(&children[0]),
-            362 => self.scoped_identifier_opt_1(),
-            363 => {
+            370 => self.scoped_identifier_list_1(),
+            371 => self.scoped_identifier_opt0_0(&children[0]),
+            372 => self.scoped_identifier_opt0_1(),
+            373 => self.scoped_identifier_opt_0(&children[0]),
+            374 => self.scoped_identifier_opt_1(),
+            375 => {
                 self.expression_identifier(&children[0], &children[1], &children[2], &children[3])
             }
-            364 => self.expression_identifier_list0_0(
+            376 => self.expression_identifier_list0_0(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+            ),
+            377 => self.expression_identifier_list0_list_0(&children[0], &children[1]),
+            378 => self.expression_identifier_list0_list_1(),
+            379 => self.expression_identifier_list0_1(),
+            380 => self.expression_identifier_list_0(&children[0], &children[1]),
+            381 => self.expression_identifier_list_1(),
+            382 => self.expression_identifier_opt_0(&children[0]),
+            383 => self.expression_identifier_opt_1(),
+            384 => self.expression(&children[0], &children[1]),
+            385 => self.expression_list_0(&children[0], &children[1], &children[2]),
+            386 => self.expression_list_1(),
+            387 => self.expression01(&children[0], &children[1]),
+            388 => self.expression01_list_0(&children[0], &children[1], &children[2]),
+            389 => self.expression01_list_1(),
+            390 => self.expression02(&children[0], &children[1]),
+            391 => self.expression02_list_0(&children[0], &children[1], &children[2]),
+            392 => self.expression02_list_1(),
+            393 => self.expression03(&children[0], &children[1]),
+            394 => self.expression03_list_0(&children[0], &children[1], &children[2]),
+            395 => self.expression03_list_1(),
+            396 => self.expression04(&children[0], &children[1]),
+            397 => self.expression04_list_0(&children[0], &children[1], &children[2]),
+            398 => self.expression04_list_1(),
+            399 => self.expression05(&children[0], &children[1]),
+            400 => self.expression05_list_0(&children[0], &children[1], &children[2]),
+            401 => self.expression05_list_1(),
+            402 => self.expression06(&children[0], &children[1]),
+            403 => self.expression06_list_0(&children[0], &children[1], &children[2]),
+            404 => self.expression06_list_1(),
+            405 => self.expression07(&children[0], &children[1]),
+            406 => self.expression07_list_0(&children[0], &children[1], &children[2]),
+            407 => self.expression07_list_1(),
+            408 => self.expression08(&children[0], &children[1]),
+            409 => self.expression08_list_0(&children[0], &children[1], &children[2]),
+            410 => self.expression08_list_1(),
+            411 => self.expression09(&children[0], &children[1]),
+            412 => self.expression09_list_0(&children[0], &children[1], &children[2]),
+            413 => self.expression09_list_group_0(&children[0]),
+            414 => self.expression09_list_group_1(&children[0]),
+            415 => self.expression09_list_1(),
+            416 => self.expression10(&children[0], &children[1]),
+            417 => self.expression10_list_0(&children[0], &children[1], &children[2]),
+            418 => self.expression10_list_1(),
+            419 => self.expression11(&children[0], &children[1]),
+            420 => self.expression11_opt_0(&children[0], &children[1]),
+            421 => self.expression11_opt_1(),
+            422 => self.expression12(&children[0], &children[1]),
+            423 => self.expression12_list_0(&children[0], &children[1]),
+            424 => self.expression12_list_group_0(&children[0]),
+            425 => self.expression12_list_group_1(&children[0]),
+            426 => self.expression12_list_group_2(&children[0]),
+            427 => self.expression12_list_group_3(&children[0]),
+            428 => self.expression12_list_group_4(&children[0]),
+            429 => self.expression12_list_1(),
+            430 => self.factor_0(&children[0]),
+            431 => self.factor_1(&children[0]),
+            432 => self.factor_2(&children[0], &children[1], &children[2]),
+            433 => self.factor_3(&children[0], &children[1], &children[2]),
+            434 => self.factor_4(&children[0], &children[1], &children[2]),
+            435 => self.factor_5(&children[0]),
+            436 => self.factor_6(&children[0]),
+            437 => self.factor_7(&children[0]),
+            438 => self.factor_8(&children[0]),
+            439 => self.factor_9(&children[0]),
+            440 => self.factor_group_0(&children[0]),
+            441 => self.factor_group_1(&children[0]),
+            442 => self.factor_10(&children[0]),
+            443 => self.factor_11(&children[0]),
+            444 => self.factor_12(&children[0]),
+            445 => self.factor_13(&children[0]),
+            446 => self.identifier_factor(&children[0], &children[1]),
+            447 => self.identifier_factor_opt_0(&children[0]),
+            448 => self.identifier_factor_opt_group_0(&children[0]),
+            449 => self.identifier_factor_opt_group_1(&children[0]),
+            450 => self.identifier_factor_opt_1(),
+            451 => self.function_call(&children[0], &children[1], &children[2]),
+            452 => self.function_call_opt_0(&children[0]),
+            453 => self.function_call_opt_1(),
+            454 => self.argument_list(&children[0], &children[1], &children[2]),
+            455 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
+            456 => self.argument_list_list_1(),
+            457 => self.argument_list_opt_0(&children[0]),
+            458 => self.argument_list_opt_1(),
+            459 => self.argument_item(&children[0]),
+            460 => self.concatenation_list(&children[0], &children[1], &children[2]),
+            461 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
+            462 => self.concatenation_list_list_1(),
+            463 => self.concatenation_list_opt_0(&children[0]),
+            464 => self.concatenation_list_opt_1(),
+            465 => self.concatenation_item(&children[0], &children[1]),
+            466 => self.concatenation_item_opt_0(&children[0], &children[1]),
+            467 => self.concatenation_item_opt_1(),
+            468 => self.array_literal_list(&children[0], &children[1], &children[2]),
+            469 => self.array_literal_list_list_0(&children[0], &children[1], &children[2]),
+            470 => self.array_literal_list_list_1(),
+            471 => self.array_literal_list_opt_0(&children[0]),
+            472 => self.array_literal_list_opt_1(),
+            473 => self.array_literal_item(&children[0]),
+            474 => self.array_literal_item_group_0(&children[0], &children[1]),
+            475 => self.array_literal_item_group_1(&children[0], &children[1], &children[2]),
+            476 => self.array_literal_item_opt_0(&children[0], &children[1]),
+            477 => self.array_literal_item_opt_1(),
+            478 => self.struct_constructor(&children[0], &children[1], &children[2], &children[3]),
+            479 => self.struct_constructor_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
+                &children[4],
             ),
-            365 => self.expression_identifier_list0_list_0(&children[0], &children[1]),
-            366 => self.expression_identifier_list0_list_1(),
-            367 => self.expression_identifier_list0_1(),
-            368 => self.expression_identifier_list_0(&children[0], &children[1]),
-            369 => self.expression_identifier_list_1(),
-            370 => self.expression_identifier_opt_0(&children[0]),
-            371 => self.expression_identifier_opt_1(),
-            372 => self.expression(&children[0], &children[1]),
-            373 => self.expression_list_0(&children[0], &children[1], &children[2]),
-            374 => self.expression_list_1(),
-            375 => self.expression01(&children[0], &children[1]),
-            376 => self.expression01_list_0(&children[0], &children[1], &children[2]),
-            377 => self.expression01_list_1(),
-            378 => self.expression02(&children[0], &children[1]),
-            379 => self.expression02_list_0(&children[0], &children[1], &children[2]),
-            380 => self.expression02_list_1(),
-            381 => self.expression03(&children[0], &children[1]),
-            382 => self.expression03_list_0(&children[0], &children[1], &children[2]),
-            383 => self.expression03_list_1(),
-            384 => self.expression04(&children[0], &children[1]),
-            385 => self.expression04_list_0(&children[0], &children[1], &children[2]),
-            386 => self.expression04_list_1(),
-            387 => self.expression05(&children[0], &children[1]),
-            388 => self.expression05_list_0(&children[0], &children[1], &children[2]),
-            389 => self.expression05_list_1(),
-            390 => self.expression06(&children[0], &children[1]),
-            391 => self.expression06_list_0(&children[0], &children[1], &children[2]),
-            392 => self.expression06_list_1(),
-            393 => self.expression07(&children[0], &children[1]),
-            394 => self.expression07_list_0(&children[0], &children[1], &children[2]),
-            395 => self.expression07_list_1(),
-            396 => self.expression08(&children[0], &children[1]),
-            397 => self.expression08_list_0(&children[0], &children[1], &children[2]),
-            398 => self.expression08_list_1(),
-            399 => self.expression09(&children[0], &children[1]),
-            400 => self.expression09_list_0(&children[0], &children[1], &children[2]),
-            401 => self.expression09_list_group_0(&children[0]),
-            402 => self.expression09_list_group_1(&children[0]),
-            403 => self.expression09_list_1(),
-            404 => self.expression10(&children[0], &children[1]),
-            405 => self.expression10_list_0(&children[0], &children[1], &children[2]),
-            406 => self.expression10_list_1(),
-            407 => self.expression11(&children[0], &children[1]),
-            408 => self.expression11_opt_0(&children[0], &children[1]),
-            409 => self.expression11_opt_1(),
-            410 => self.expression12(&children[0], &children[1]),
-            411 => self.expression12_list_0(&children[0], &children[1]),
-            412 => self.expression12_list_group_0(&children[0]),
-            413 => self.expression12_list_group_1(&children[0]),
-            414 => self.expression12_list_group_2(&children[0]),
-            415 => self.expression12_list_group_3(&children[0]),
-            416 => self.expression12_list_group_4(&children[0]),
-            417 => self.expression12_list_1(),
-            418 => self.factor_0(&children[0]),
-            419 => self.factor_1(&children[0]),
-            420 => self.factor_2(&children[0], &children[1], &children[2]),
-            421 => self.factor_3(&children[0], &children[1], &children[2]),
-            422 => self.factor_4(&children[0], &children[1], &children[2]),
-            423 => self.factor_5(&children[0]),
-            424 => self.factor_6(&children[0]),
-            425 => self.factor_7(&children[0]),
-            426 => self.factor_8(&children[0]),
-            427 => self.factor_9(&children[0]),
-            428 => self.factor_group_0(&children[0]),
-            429 => self.factor_group_1(&children[0]),
-            430 => self.factor_10(&children[0]),
-            431 => self.factor_11(&children[0]),
-            432 => self.factor_12(&children[0]),
-            433 => self.factor_13(&children[0]),
-            434 => self.identifier_factor(&children[0], &children[1]),
-            435 => self.identifier_factor_opt_0(&children[0]),
-            436 => self.identifier_factor_opt_1(),
-            437 => self.function_call(&children[0], &children[1], &children[2]),
-            438 => self.function_call_opt_0(&children[0]),
-            439 => self.function_call_opt_1(),
-            440 => self.argument_list(&children[0], &children[1], &children[2]),
-            441 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
-            442 => self.argument_list_list_1(),
-            443 => self.argument_list_opt_0(&children[0]),
-            444 => self.argument_list_opt_1(),
-            445 => self.argument_item(&children[0]),
-            446 => self.concatenation_list(&children[0], &children[1], &children[2]),
-            447 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
-            448 => self.concatenation_list_list_1(),
-            449 => self.concatenation_list_opt_0(&children[0]),
-            450 => self.concatenation_list_opt_1(),
-            451 => self.concatenation_item(&children[0], &children[1]),
-            452 => self.concatenation_item_opt_0(&children[0], &children[1]),
-            453 => self.concatenation_item_opt_1(),
-            454 => self.array_literal_list(&children[0], &children[1], &children[2]),
-            455 => self.array_literal_list_list_0(&children[0], &children[1], &children[2]),
-            456 => self.array_literal_list_list_1(),
-            457 => self.array_literal_list_opt_0(&children[0]),
-            458 => self.array_literal_list_opt_1(),
-            459 => self.array_literal_item(&children[0]),
-            460 => self.array_literal_item_group_0(&children[0], &children[1]),
-            461 => self.array_literal_item_group_1(&children[0], &children[1], &children[2]),
-            462 => self.array_literal_item_opt_0(&children[0], &children[1]),
-            463 => self.array_literal_item_opt_1(),
-            464 => self.if_expression(
+            480 => self.struct_constructor_opt_1(),
+            481 => self.struct_constructor_list(&children[0], &children[1], &children[2]),
+            482 => self.struct_constructor_list_list_0(&children[0], &children[1], &children[2]),
+            483 => self.struct_constructor_list_list_1(),
+            484 => self.struct_constructor_list_opt_0(&children[0]),
+            485 => self.struct_constructor_list_opt_1(),
+            486 => self.struct_constructor_item(&children[0], &children[1], &children[2]),
+            487 => self.if_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35335,7 +36721,7 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[8],
                 &children[9],
             ),
-            465 => self.if_expression_list_0(
+            488 => self.if_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35344,8 +36730,8 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            466 => self.if_expression_list_1(),
-            467 => self.case_expression(
+            489 => self.if_expression_list_1(),
+            490 => self.case_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35360,17 +36746,17 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[11],
                 &children[12],
             ),
-            468 => self.case_expression_list_0(
+            491 => self.case_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            469 => self.case_expression_list_1(),
-            470 => self.case_expression_opt_0(&children[0]),
-            471 => self.case_expression_opt_1(),
-            472 => self.switch_expression(
+            492 => self.case_expression_list_1(),
+            493 => self.case_expression_opt_0(&children[0]),
+            494 => self.case_expression_opt_1(),
+            495 => self.switch_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35384,130 +36770,133 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[10],
                 &children[11],
             ),
-            473 => self.switch_expression_list_0(
+            496 => self.switch_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            474 => self.switch_expression_list_1(),
-            475 => self.switch_expression_opt_0(&children[0]),
-            476 => self.switch_expression_opt_1(),
-            477 => self.type_expression(&children[0], &children[1], &children[2], &children[3]),
-            478 => self.inside_expression(
+            497 => self.switch_expression_list_1(),
+            498 => self.switch_expression_opt_0(&children[0]),
+            499 => self.switch_expression_opt_1(),
+            500 => self.type_expression(&children[0], &children[1], &children[2], &children[3]),
+            501 => self.inside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            479 => self.outside_expression(
+            502 => self.outside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            480 => self.range_list(&children[0], &children[1], &children[2]),
-            481 => self.range_list_list_0(&children[0], &children[1], &children[2]),
-            482 => self.range_list_list_1(),
-            483 => self.range_list_opt_0(&children[0]),
-            484 => self.range_list_opt_1(),
-            485 => self.range_item(&children[0]),
-            486 => self.select(&children[0], &children[1], &children[2], &children[3]),
-            487 => self.select_opt_0(&children[0], &children[1]),
-            488 => self.select_opt_1(),
-            489 => self.select_operator_0(&children[0]),
-            490 => self.select_operator_1(&children[0]),
-            491 => self.select_operator_2(&children[0]),
-            492 => self.select_operator_3(&children[0]),
-            493 => self.width(&children[0], &children[1], &children[2], &children[3]),
-            494 => self.width_list_0(&children[0], &children[1], &children[2]),
-            495 => self.width_list_1(),
-            496 => self.array(&children[0], &children[1], &children[2], &children[3]),
-            497 => self.array_list_0(&children[0], &children[1], &children[2]),
-            498 => self.array_list_1(),
-            499 => self.range(&children[0], &children[1]),
-            500 => self.range_opt_0(&children[0], &children[1]),
-            501 => self.range_opt_1(),
-            502 => self.range_operator_0(&children[0]),
-            503 => self.range_operator_1(&children[0]),
-            504 => self.fixed_type_0(&children[0]),
-            505 => self.fixed_type_1(&children[0]),
-            506 => self.fixed_type_2(&children[0]),
-            507 => self.fixed_type_3(&children[0]),
-            508 => self.fixed_type_4(&children[0]),
-            509 => self.fixed_type_5(&children[0]),
-            510 => self.fixed_type_6(&children[0]),
-            511 => self.variable_type_0(&children[0]),
-            512 => self.variable_type_1(&children[0]),
-            513 => self.variable_type_2(&children[0]),
-            514 => self.variable_type_3(&children[0]),
-            515 => self.variable_type_4(&children[0]),
-            516 => self.variable_type_5(&children[0]),
-            517 => self.variable_type_6(&children[0]),
-            518 => self.variable_type_7(&children[0]),
-            519 => self.variable_type_8(&children[0]),
-            520 => self.variable_type_9(&children[0]),
-            521 => self.user_defined_type(&children[0]),
-            522 => self.type_modifier_0(&children[0]),
-            523 => self.type_modifier_1(&children[0]),
-            524 => self.factor_type(&children[0]),
-            525 => self.factor_type_group_0(&children[0], &children[1]),
-            526 => self.factor_type_group_1(&children[0]),
-            527 => self.factor_type_opt_0(&children[0]),
-            528 => self.factor_type_opt_1(),
-            529 => self.scalar_type(&children[0], &children[1]),
-            530 => self.scalar_type_group_0(&children[0], &children[1]),
-            531 => self.scalar_type_group_1(&children[0]),
-            532 => self.scalar_type_list_0(&children[0], &children[1]),
-            533 => self.scalar_type_list_1(),
-            534 => self.scalar_type_opt_0(&children[0]),
-            535 => self.scalar_type_opt_1(),
-            536 => self.array_type(&children[0], &children[1]),
-            537 => self.array_type_opt_0(&children[0]),
-            538 => self.array_type_opt_1(),
-            539 => self.casting_type_0(&children[0]),
-            540 => self.casting_type_1(&children[0]),
-            541 => self.casting_type_2(&children[0]),
-            542 => self.casting_type_3(&children[0]),
-            543 => self.casting_type_4(&children[0]),
-            544 => self.casting_type_5(&children[0]),
-            545 => self.casting_type_6(&children[0]),
-            546 => self.casting_type_7(&children[0]),
-            547 => self.casting_type_8(&children[0]),
-            548 => self.casting_type_9(&children[0]),
-            549 => self.casting_type_10(&children[0]),
-            550 => self.casting_type_11(&children[0]),
-            551 => self.casting_type_12(&children[0]),
-            552 => self.casting_type_13(&children[0]),
-            553 => self.casting_type_14(&children[0]),
-            554 => self.casting_type_15(&children[0]),
-            555 => self.casting_type_16(&children[0]),
-            556 => self.clock_domain(&children[0], &children[1]),
-            557 => self.statement_block(&children[0], &children[1], &children[2]),
-            558 => self.statement_block_list_0(&children[0], &children[1]),
-            559 => self.statement_block_list_1(),
-            560 => self.statement_block_group(&children[0], &children[1]),
-            561 => self.statement_block_group_group_0(&children[0], &children[1], &children[2]),
-            562 => self.statement_block_group_group_list_0(&children[0], &children[1]),
-            563 => self.statement_block_group_group_list_1(),
-            564 => self.statement_block_group_group_1(&children[0]),
-            565 => self.statement_block_group_list_0(&children[0], &children[1]),
-            566 => self.statement_block_group_list_1(),
-            567 => self.statement_block_item_0(&children[0]),
-            568 => self.statement_block_item_1(&children[0]),
-            569 => self.statement_block_item_2(&children[0]),
-            570 => self.statement_0(&children[0]),
-            571 => self.statement_1(&children[0]),
-            572 => self.statement_2(&children[0]),
-            573 => self.statement_3(&children[0]),
-            574 => self.statement_4(&children[0]),
-            575 => self.statement_5(&children[0]),
-            576 => self.statement_6(&children[0]),
-            577 => self.statement_7(&children[0]),
-            578 => self.let_statement(
+            503 => self.range_list(&children[0], &children[1], &children[2]),
+            504 => self.range_list_list_0(&children[0], &children[1], &children[2]),
+            505 => self.range_list_list_1(),
+            506 => self.range_list_opt_0(&children[0]),
+            507 => self.range_list_opt_1(),
+            508 => self.range_item(&children[0]),
+            509 => self.select(&children[0], &children[1], &children[2], &children[3]),
+            510 => self.select_opt_0(&children[0], &children[1]),
+            511 => self.select_opt_1(),
+            512 => self.select_operator_0(&children[0]),
+            513 => self.select_operator_1(&children[0]),
+            514 => self.select_operator_2(&children[0]),
+            515 => self.select_operator_3(&children[0]),
+            516 => self.width(&children[0], &children[1], &children[2], &children[3]),
+            517 => self.width_list_0(&children[0], &children[1], &children[2]),
+            518 => self.width_list_1(),
+            519 => self.array(&children[0], &children[1], &children[2], &children[3]),
+            520 => self.array_list_0(&children[0], &children[1], &children[2]),
+            521 => self.array_list_1(),
+            522 => self.range(&children[0], &children[1]),
+            523 => self.range_opt_0(&children[0], &children[1]),
+            524 => self.range_opt_1(),
+            525 => self.range_operator_0(&children[0]),
+            526 => self.range_operator_1(&children[0]),
+            527 => self.fixed_type_0(&children[0]),
+            528 => self.fixed_type_1(&children[0]),
+            529 => self.fixed_type_2(&children[0]),
+            530 => self.fixed_type_3(&children[0]),
+            531 => self.fixed_type_4(&children[0]),
+            532 => self.fixed_type_5(&children[0]),
+            533 => self.fixed_type_6(&children[0]),
+            534 => self.variable_type_0(&children[0]),
+            535 => self.variable_type_1(&children[0]),
+            536 => self.variable_type_2(&children[0]),
+            537 => self.variable_type_3(&children[0]),
+            538 => self.variable_type_4(&children[0]),
+            539 => self.variable_type_5(&children[0]),
+            540 => self.variable_type_6(&children[0]),
+            541 => self.variable_type_7(&children[0]),
+            542 => self.variable_type_8(&children[0]),
+            543 => self.variable_type_9(&children[0]),
+            544 => self.user_defined_type(&children[0]),
+            545 => self.type_modifier_0(&children[0]),
+            546 => self.type_modifier_1(&children[0]),
+            547 => self.factor_type(&children[0]),
+            548 => self.factor_type_group_0(&children[0], &children[1]),
+            549 => self.factor_type_group_1(&children[0]),
+            550 => self.factor_type_opt_0(&children[0]),
+            551 => self.factor_type_opt_1(),
+            552 => self.scalar_type(&children[0], &children[1]),
+            553 => self.scalar_type_group_0(&children[0], &children[1]),
+            554 => self.scalar_type_group_1(&children[0]),
+            555 => self.scalar_type_list_0(&children[0], &children[1]),
+            556 => self.scalar_type_list_1(),
+            557 => self.scalar_type_opt_0(&children[0]),
+            558 => self.scalar_type_opt_1(),
+            559 => self.array_type(&children[0], &children[1]),
+            560 => self.array_type_opt_0(&children[0]),
+            561 => self.array_type_opt_1(),
+            562 => self.casting_type_0(&children[0]),
+            563 => self.casting_type_1(&children[0]),
+            564 => self.casting_type_2(&children[0]),
+            565 => self.casting_type_3(&children[0]),
+            566 => self.casting_type_4(&children[0]),
+            567 => self.casting_type_5(&children[0]),
+            568 => self.casting_type_6(&children[0]),
+            569 => self.casting_type_7(&children[0]),
+            570 => self.casting_type_8(&children[0]),
+            571 => self.casting_type_9(&children[0]),
+            572 => self.casting_type_10(&children[0]),
+            573 => self.casting_type_11(&children[0]),
+            574 => self.casting_type_12(&children[0]),
+            575 => self.casting_type_13(&children[0]),
+            576 => self.casting_type_14(&children[0]),
+            577 => self.casting_type_15(&children[0]),
+            578 => self.casting_type_16(&children[0]),
+            579 => self.casting_type_17(&children[0]),
+            580 => self.casting_type_18(&children[0]),
+            581 => self.clock_domain(&children[0], &children[1]),
+            582 => self.statement_block(&children[0], &children[1], &children[2]),
+            583 => self.statement_block_list_0(&children[0], &children[1]),
+            584 => self.statement_block_list_1(),
+            585 => self.statement_block_group(&children[0], &children[1]),
+            586 => self.statement_block_group_group_0(&children[0], &children[1], &children[2]),
+            587 => self.statement_block_group_group_list_0(&children[0], &children[1]),
+            588 => self.statement_block_group_group_list_1(),
+            589 => self.statement_block_group_group_1(&children[0]),
+            590 => self.statement_block_group_list_0(&children[0], &children[1]),
+            591 => self.statement_block_group_list_1(),
+            592 => self.statement_block_item_0(&children[0]),
+            593 => self.statement_block_item_1(&children[0]),
+            594 => self.statement_block_item_2(&children[0]),
+            595 => self.statement_0(&children[0]),
+            596 => self.statement_1(&children[0]),
+            597 => self.statement_2(&children[0]),
+            598 => self.statement_3(&children[0]),
+            599 => self.statement_4(&children[0]),
+            600 => self.statement_5(&children[0]),
+            601 => self.statement_6(&children[0]),
+            602 => self.statement_7(&children[0]),
+            603 => self.statement_8(&children[0]),
+            604 => self.let_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35517,45 +36906,45 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            579 => self.let_statement_opt_0(&children[0]),
-            580 => self.let_statement_opt_1(),
-            581 => self.identifier_statement(&children[0], &children[1], &children[2]),
-            582 => self.identifier_statement_group_0(&children[0]),
-            583 => self.identifier_statement_group_1(&children[0]),
-            584 => self.assignment(&children[0], &children[1]),
-            585 => self.assignment_group_0(&children[0]),
-            586 => self.assignment_group_1(&children[0]),
-            587 => self.if_statement(
+            605 => self.let_statement_opt_0(&children[0]),
+            606 => self.let_statement_opt_1(),
+            607 => self.identifier_statement(&children[0], &children[1], &children[2]),
+            608 => self.identifier_statement_group_0(&children[0]),
+            609 => self.identifier_statement_group_1(&children[0]),
+            610 => self.assignment(&children[0], &children[1]),
+            611 => self.assignment_group_0(&children[0]),
+            612 => self.assignment_group_1(&children[0]),
+            613 => self.if_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            588 => self.if_statement_list_0(
+            614 => self.if_statement_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            589 => self.if_statement_list_1(),
-            590 => self.if_statement_opt_0(&children[0], &children[1]),
-            591 => self.if_statement_opt_1(),
-            592 => self.if_reset_statement(&children[0], &children[1], &children[2], &children[3]),
-            593 => self.if_reset_statement_list_0(
+            615 => self.if_statement_list_1(),
+            616 => self.if_statement_opt_0(&children[0], &children[1]),
+            617 => self.if_statement_opt_1(),
+            618 => self.if_reset_statement(&children[0], &children[1], &children[2], &children[3]),
+            619 => self.if_reset_statement_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            594 => self.if_reset_statement_list_1(),
-            595 => self.if_reset_statement_opt_0(&children[0], &children[1]),
-            596 => self.if_reset_statement_opt_1(),
-            597 => self.return_statement(&children[0], &children[1], &children[2]),
-            598 => self.break_statement(&children[0], &children[1]),
-            599 => self.for_statement(
+            620 => self.if_reset_statement_list_1(),
+            621 => self.if_reset_statement_opt_0(&children[0], &children[1]),
+            622 => self.if_reset_statement_opt_1(),
+            623 => self.return_statement(&children[0], &children[1], &children[2]),
+            624 => self.break_statement(&children[0], &children[1]),
+            625 => self.for_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35565,53 +36954,66 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            600 => self.for_statement_opt_0(&children[0], &children[1], &children[2]),
-            601 => self.for_statement_opt_1(),
-            602 => self.case_statement(
+            626 => self.for_statement_opt_0(&children[0], &children[1], &children[2]),
+            627 => self.for_statement_opt_1(),
+            628 => self.case_statement(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            629 => self.case_statement_list_0(&children[0], &children[1]),
+            630 => self.case_statement_list_1(),
+            631 => self.case_item(&children[0], &children[1], &children[2]),
+            632 => self.case_item_group0_0(&children[0]),
+            633 => self.case_item_group0_1(&children[0]),
+            634 => self.case_item_group_0(&children[0]),
+            635 => self.case_item_group_1(&children[0]),
+            636 => self.case_condition(&children[0], &children[1]),
+            637 => self.case_condition_list_0(&children[0], &children[1], &children[2]),
+            638 => self.case_condition_list_1(),
+            639 => self.switch_statement(&children[0], &children[1], &children[2], &children[3]),
+            640 => self.switch_statement_list_0(&children[0], &children[1]),
+            641 => self.switch_statement_list_1(),
+            642 => self.switch_item(&children[0], &children[1], &children[2]),
+            643 => self.switch_item_group0_0(&children[0]),
+            644 => self.switch_item_group0_1(&children[0]),
+            645 => self.switch_item_group_0(&children[0]),
+            646 => self.switch_item_group_1(&children[0]),
+            647 => self.switch_condition(&children[0], &children[1]),
+            648 => self.switch_condition_list_0(&children[0], &children[1], &children[2]),
+            649 => self.switch_condition_list_1(),
+            650 => self.assertion_kind_0(&children[0]),
+            651 => self.assertion_kind_1(&children[0]),
+            652 => self.assertion_kind_2(&children[0]),
+            653 => self.assertion_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
+                &children[5],
             ),
-            603 => self.case_statement_list_0(&children[0], &children[1]),
-            604 => self.case_statement_list_1(),
-            605 => self.case_item(&children[0], &children[1], &children[2]),
-            606 => self.case_item_group0_0(&children[0]),
-            607 => self.case_item_group0_1(&children[0]),
-            608 => self.case_item_group_0(&children[0]),
-            609 => self.case_item_group_1(&children[0]),
-            610 => self.case_condition(&children[0], &children[1]),
-            611 => self.case_condition_list_0(&children[0], &children[1], &children[2]),
-            612 => self.case_condition_list_1(),
-            613 => self.switch_statement(&children[0], &children[1], &children[2], &children[3]),
-            614 => self.switch_statement_list_0(&children[0], &children[1]),
-            615 => self.switch_statement_list_1(),
-            616 => self.switch_item(&children[0], &children[1], &children[2]),
-            617 => self.switch_item_group0_0(&children[0]),
-            618 => self.switch_item_group0_1(&children[0]),
-            619 => self.switch_item_group_0(&children[0]),
-            620 => self.switch_item_group_1(&children[0]),
-            621 => self.switch_condition(&children[0], &children[1]),
-            622 => self.switch_condition_list_0(&children[0], &children[1], &children[2]),
-            623 => self.switch_condition_list_1(),
-            624 => self.attribute(
+            654 => self.assertion_statement_opt_0(&children[0], &children[1]),
+            655 => self.assertion_statement_opt_1(),
+            656 => self.attribute(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            625 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
-            626 => self.attribute_opt_1(),
-            627 => self.attribute_list(&children[0], &children[1], &children[2]),
-            628 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
-            629 => self.attribute_list_list_1(),
-            630 => self.attribute_list_opt_0(&children[0]),
-            631 => self.attribute_list_opt_1(),
-            632 => self.attribute_item_0(&children[0]),
-            633 => self.attribute_item_1(&children[0]),
-            634 => self.let_declaration(
+            657 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
+            658 => self.attribute_opt_1(),
+            659 => self.attribute_list(&children[0], &children[1], &children[2]),
+            660 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
+            661 => self.attribute_list_list_1(),
+            662 => self.attribute_list_opt_0(&children[0]),
+            663 => self.attribute_list_opt_1(),
+            664 => self.attribute_item_0(&children[0]),
+            665 => self.attribute_item_1(&children[0]),
+            666 => self.let_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35621,9 +37023,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            635 => self.let_declaration_opt_0(&children[0]),
-            636 => self.let_declaration_opt_1(),
-            637 => self.var_declaration(
+            667 => self.let_declaration_opt_0(&children[0]),
+            668 => self.let_declaration_opt_1(),
+            669 => self.var_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35631,9 +37033,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            638 => self.var_declaration_opt_0(&children[0]),
-            639 => self.var_declaration_opt_1(),
-            640 => self.const_declaration(
+            670 => self.var_declaration_opt_0(&children[0]),
+            671 => self.var_declaration_opt_1(),
+            672 => self.const_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35642,52 +37044,52 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            641 => self.const_declaration_group_0(&children[0]),
-            642 => self.const_declaration_group_1(&children[0]),
-            643 => self.type_def_declaration(
+            673 => self.const_declaration_group_0(&children[0]),
+            674 => self.const_declaration_group_1(&children[0]),
+            675 => self.type_def_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            644 => self.always_ff_declaration(&children[0], &children[1], &children[2]),
-            645 => self.always_ff_declaration_opt_0(&children[0]),
-            646 => self.always_ff_declaration_opt_1(),
-            647 => {
+            676 => self.always_ff_declaration(&children[0], &children[1], &children[2]),
+            677 => self.always_ff_declaration_opt_0(&children[0]),
+            678 => self.always_ff_declaration_opt_1(),
+            679 => {
                 self.always_ff_event_list(&children[0], &children[1], &children[2], &children[3])
             }
-            648 => self.always_ff_event_list_opt_0(&children[0], &children[1]),
-            649 => self.always_ff_event_list_opt_1(),
-            650 => self.always_ff_clock(&children[0]),
-            651 => self.always_ff_reset(&children[0]),
-            652 => self.always_comb_declaration(&children[0], &children[1]),
-            653 => self.assign_declaration(
+            680 => self.always_ff_event_list_opt_0(&children[0], &children[1]),
+            681 => self.always_ff_event_list_opt_1(),
+            682 => self.always_ff_clock(&children[0]),
+            683 => self.always_ff_reset(&children[0]),
+            684 => self.always_comb_declaration(&children[0], &children[1]),
+            685 => self.assign_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            654 => self.modport_declaration(
+            686 => self.modport_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            655 => self.modport_list(&children[0], &children[1], &children[2]),
-            656 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
-            657 => self.modport_list_list_1(),
-            658 => self.modport_list_opt_0(&children[0]),
-            659 => self.modport_list_opt_1(),
-            660 => self.modport_group(&children[0], &children[1]),
-            661 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
-            662 => self.modport_group_group_1(&children[0]),
-            663 => self.modport_group_list_0(&children[0], &children[1]),
-            664 => self.modport_group_list_1(),
-            665 => self.modport_item(&children[0], &children[1], &children[2]),
-            666 => self.enum_declaration(
+            687 => self.modport_list(&children[0], &children[1], &children[2]),
+            688 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
+            689 => self.modport_list_list_1(),
+            690 => self.modport_list_opt_0(&children[0]),
+            691 => self.modport_list_opt_1(),
+            692 => self.modport_group(&children[0], &children[1]),
+            693 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
+            694 => self.modport_group_group_1(&children[0]),
+            695 => self.modport_group_list_0(&children[0], &children[1]),
+            696 => self.modport_group_list_1(),
+            697 => self.modport_item(&children[0], &children[1], &children[2]),
+            698 => self.enum_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35695,24 +37097,24 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            667 => self.enum_declaration_opt_0(&children[0], &children[1]),
-            668 => self.enum_declaration_opt_1(),
-            669 => self.enum_list(&children[0], &children[1], &children[2]),
-            670 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
-            671 => self.enum_list_list_1(),
-            672 => self.enum_list_opt_0(&children[0]),
-            673 => self.enum_list_opt_1(),
-            674 => self.enum_group(&children[0], &children[1]),
-            675 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
-            676 => self.enum_group_group_1(&children[0]),
-            677 => self.enum_group_list_0(&children[0], &children[1]),
-            678 => self.enum_group_list_1(),
-            679 => self.enum_item(&children[0], &children[1]),
-            680 => self.enum_item_opt_0(&children[0], &children[1]),
-            681 => self.enum_item_opt_1(),
-            682 => self.struct_union_0(&children[0]),
-            683 => self.struct_union_1(&children[0]),
-            684 => self.struct_union_declaration(
+            699 => self.enum_declaration_opt_0(&children[0], &children[1]),
+            700 => self.enum_declaration_opt_1(),
+            701 => self.enum_list(&children[0], &children[1], &children[2]),
+            702 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
+            703 => self.enum_list_list_1(),
+            704 => self.enum_list_opt_0(&children[0]),
+            705 => self.enum_list_opt_1(),
+            706 => self.enum_group(&children[0], &children[1]),
+            707 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
+            708 => self.enum_group_group_1(&children[0]),
+            709 => self.enum_group_list_0(&children[0], &children[1]),
+            710 => self.enum_group_list_1(),
+            711 => self.enum_item(&children[0], &children[1]),
+            712 => self.enum_item_opt_0(&children[0], &children[1]),
+            713 => self.enum_item_opt_1(),
+            714 => self.struct_union_0(&children[0]),
+            715 => self.struct_union_1(&children[0]),
+            716 => self.struct_union_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35720,22 +37122,33 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            685 => self.struct_union_declaration_opt_0(&children[0]),
-            686 => self.struct_union_declaration_opt_1(),
-            687 => self.struct_union_list(&children[0], &children[1], &children[2]),
-            688 => self.struct_union_list_list_0(&children[0], &children[1], &children[2]),
-            689 => self.struct_union_list_list_1(),
-            690 => self.struct_union_list_opt_0(&children[0]),
-            691 => self.struct_union_list_opt_1(),
-            692 => self.struct_union_group(&children[0], &children[1]),
-            693 => self.struct_union_group_group_0(&children[0], &children[1], &children[2]),
-            694 => self.struct_union_group_group_1(&children[0]),
-            695 => self.struct_union_group_list_0(&children[0], &children[1]),
-            696 => self.struct_union_group_list_1(),
-            697 => self.struct_union_item(&children[0], &children[1], &children[2]),
-            698 => self.initial_declaration(&children[0], &children[1]),
-            699 => self.final_declaration(&children[0], &children[1]),
-            700 => self.inst_declaration(
+            717 => self.struct_union_declaration_opt_0(&children[0]),
+            718 => self.struct_union_declaration_opt_1(),
+            719 => self.struct_union_list(&children[0], &children[1], &children[2]),
+            720 => self.struct_union_list_list_0(&children[0], &children[1], &children[2]),
+            721 => self.struct_union_list_list_1(),
+            722 => self.struct_union_list_opt_0(&children[0]),
+            723 => self.struct_union_list_opt_1(),
+            724 => self.struct_union_group(&children[0], &children[1]),
+            725 => self.struct_union_group_group_0(&children[0], &children[1], &children[2]),
+            726 => self.struct_union_group_group_1(&children[0]),
+            727 => self.struct_union_group_list_0(&children[0], &children[1]),
+            728 => self.struct_union_group_list_1(),
+            729 => self.struct_union_item(&children[0], &children[1], &children[2]),
+            730 => self.initial_declaration(&children[0], &children[1]),
+            731 => self.final_declaration(&children[0], &children[1]),
+            732 => self.assertion_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+            ),
+            733 => self.assertion_declaration_opt_0(&children[0], &children[1]),
+            734 => self.assertion_declaration_opt_1(),
+            735 => self.inst_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35745,57 +37158,57 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            701 => self.inst_declaration_opt1_0(&children[0], &children[1], &children[2]),
-            702 => self.inst_declaration_opt2_0(&children[0]),
-            703 => self.inst_declaration_opt2_1(),
-            704 => self.inst_declaration_opt1_1(),
-            705 => self.inst_declaration_opt0_0(&children[0]),
-            706 => self.inst_declaration_opt0_1(),
-            707 => self.inst_declaration_opt_0(&children[0]),
-            708 => self.inst_declaration_opt_1(),
-            709 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
-            710 => self.inst_parameter_opt_0(&children[0]),
-            711 => self.inst_parameter_opt_1(),
-            712 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
-            713 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            714 => self.inst_parameter_list_list_1(),
-            715 => self.inst_parameter_list_opt_0(&children[0]),
-            716 => self.inst_parameter_list_opt_1(),
-            717 => self.inst_parameter_group(&children[0], &children[1]),
-            718 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            719 => self.inst_parameter_group_group_1(&children[0]),
-            720 => self.inst_parameter_group_list_0(&children[0], &children[1]),
-            721 => self.inst_parameter_group_list_1(),
-            722 => self.inst_parameter_item(&children[0], &children[1]),
-            723 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
-            724 => self.inst_parameter_item_opt_1(),
-            725 => self.inst_port_list(&children[0], &children[1], &children[2]),
-            726 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
-            727 => self.inst_port_list_list_1(),
-            728 => self.inst_port_list_opt_0(&children[0]),
-            729 => self.inst_port_list_opt_1(),
-            730 => self.inst_port_group(&children[0], &children[1]),
-            731 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
-            732 => self.inst_port_group_group_1(&children[0]),
-            733 => self.inst_port_group_list_0(&children[0], &children[1]),
-            734 => self.inst_port_group_list_1(),
-            735 => self.inst_port_item(&children[0], &children[1]),
-            736 => self.inst_port_item_opt_0(&children[0], &children[1]),
-            737 => self.inst_port_item_opt_1(),
-            738 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
-            739 => self.with_parameter_opt_0(&children[0]),
-            740 => self.with_parameter_opt_1(),
-            741 => self.with_parameter_list(&children[0], &children[1], &children[2]),
-            742 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            743 => self.with_parameter_list_list_1(),
-            744 => self.with_parameter_list_opt_0(&children[0]),
-            745 => self.with_parameter_list_opt_1(),
-            746 => self.with_parameter_group(&children[0], &children[1]),
-            747 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            748 => self.with_parameter_group_group_1(&children[0]),
-            749 => self.with_parameter_group_list_0(&children[0], &children[1]),
-            750 => self.with_parameter_group_list_1(),
-            751 => self.with_parameter_item(
+            736 => self.inst_declaration_opt1_0(&children[0], &children[1], &children[2]),
+            737 => self.inst_declaration_opt2_0(&children[0]),
+            738 => self.inst_declaration_opt2_1(),
+            739 => self.inst_declaration_opt1_1(),
+            740 => self.inst_declaration_opt0_0(&children[0]),
+            741 => self.inst_declaration_opt0_1(),
+            742 => self.inst_declaration_opt_0(&children[0]),
+            743 => self.inst_declaration_opt_1(),
+            744 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
+            745 => self.inst_parameter_opt_0(&children[0]),
+            746 => self.inst_parameter_opt_1(),
+            747 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
+            748 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            749 => self.inst_parameter_list_list_1(),
+            750 => self.inst_parameter_list_opt_0(&children[0]),
+            751 => self.inst_parameter_list_opt_1(),
+            752 => self.inst_parameter_group(&children[0], &children[1]),
+            753 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            754 => self.inst_parameter_group_group_1(&children[0]),
+            755 => self.inst_parameter_group_list_0(&children[0], &children[1]),
+            756 => self.inst_parameter_group_list_1(),
+            757 => self.inst_parameter_item(&children[0], &children[1]),
+            758 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
+            759 => self.inst_parameter_item_opt_1(),
+            760 => self.inst_port_list(&children[0], &children[1], &children[2]),
+            761 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
+            762 => self.inst_port_list_list_1(),
+            763 => self.inst_port_list_opt_0(&children[0]),
+            764 => self.inst_port_list_opt_1(),
+            765 => self.inst_port_group(&children[0], &children[1]),
+            766 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
+            767 => self.inst_port_group_group_1(&children[0]),
+            768 => self.inst_port_group_list_0(&children[0], &children[1]),
+            769 => self.inst_port_group_list_1(),
+            770 => self.inst_port_item(&children[0], &children[1]),
+            771 => self.inst_port_item_opt_0(&children[0], &children[1]),
+            772 => self.inst_port_item_opt_1(),
+            773 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
+            774 => self.with_parameter_opt_0(&children[0]),
+            775 => self.with_parameter_opt_1(),
+            776 => self.with_parameter_list(&children[0], &children[1], &children[2]),
+            777 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            778 => self.with_parameter_list_list_1(),
+            779 => self.with_parameter_list_opt_0(&children[0]),
+            780 => self.with_parameter_list_opt_1(),
+            781 => self.with_parameter_group(&children[0], &children[1]),
+            782 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            783 => self.with_parameter_group_group_1(&children[0]),
+            784 => self.with_parameter_group_list_0(&children[0], &children[1]),
+            785 => self.with_parameter_group_list_1(),
+            786 => self.with_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35803,76 +37216,76 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            752 => self.with_parameter_item_group0_0(&children[0]),
-            753 => self.with_parameter_item_group0_1(&children[0]),
-            754 => self.with_parameter_item_group_0(&children[0]),
-            755 => self.with_parameter_item_group_1(&children[0]),
-            756 => self.generic_bound_0(&children[0]),
-            757 => self.generic_bound_1(&children[0]),
-            758 => self.generic_bound_2(&children[0], &children[1]),
-            759 => self.generic_bound_3(&children[0]),
-            760 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
-            761 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
-            762 => {
+            787 => self.with_parameter_item_group0_0(&children[0]),
+            788 => self.with_parameter_item_group0_1(&children[0]),
+            789 => self.with_parameter_item_group_0(&children[0]),
+            790 => self.with_parameter_item_group_1(&children[0]),
+            791 => self.generic_bound_0(&children[0]),
+            792 => self.generic_bound_1(&children[0]),
+            793 => self.generic_bound_2(&children[0], &children[1]),
+            794 => self.generic_bound_3(&children[0]),
+            795 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
+            796 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
+            797 => {
                 self.with_generic_parameter_list_list_0(&children[0], &children[1], &children[2])
             }
-            763 => self.with_generic_parameter_list_list_1(),
-            764 => self.with_generic_parameter_list_opt_0(&children[0]),
-            765 => self.with_generic_parameter_list_opt_1(),
-            766 => self.with_generic_parameter_item(
+            798 => self.with_generic_parameter_list_list_1(),
+            799 => self.with_generic_parameter_list_opt_0(&children[0]),
+            800 => self.with_generic_parameter_list_opt_1(),
+            801 => self.with_generic_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            767 => self.with_generic_parameter_item_opt_0(&children[0], &children[1]),
-            768 => self.with_generic_parameter_item_opt_1(),
-            769 => self.with_generic_argument(&children[0], &children[1], &children[2]),
-            770 => self.with_generic_argument_opt_0(&children[0]),
-            771 => self.with_generic_argument_opt_1(),
-            772 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
-            773 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
-            774 => self.with_generic_argument_list_list_1(),
-            775 => self.with_generic_argument_list_opt_0(&children[0]),
-            776 => self.with_generic_argument_list_opt_1(),
-            777 => self.with_generic_argument_item_0(&children[0]),
-            778 => self.with_generic_argument_item_1(&children[0]),
-            779 => self.port_declaration(&children[0], &children[1], &children[2]),
-            780 => self.port_declaration_opt_0(&children[0]),
-            781 => self.port_declaration_opt_1(),
-            782 => self.port_declaration_list(&children[0], &children[1], &children[2]),
-            783 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
-            784 => self.port_declaration_list_list_1(),
-            785 => self.port_declaration_list_opt_0(&children[0]),
-            786 => self.port_declaration_list_opt_1(),
-            787 => self.port_declaration_group(&children[0], &children[1]),
-            788 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
-            789 => self.port_declaration_group_group_1(&children[0]),
-            790 => self.port_declaration_group_list_0(&children[0], &children[1]),
-            791 => self.port_declaration_group_list_1(),
-            792 => self.port_declaration_item(&children[0], &children[1], &children[2]),
-            793 => self.port_declaration_item_group_0(&children[0]),
-            794 => self.port_declaration_item_group_1(&children[0]),
-            795 => self.port_type_concrete(&children[0], &children[1], &children[2], &children[3]),
-            796 => self.port_type_concrete_opt0_0(&children[0], &children[1]),
-            797 => self.port_type_concrete_opt0_1(),
-            798 => self.port_type_concrete_opt_0(&children[0]),
-            799 => self.port_type_concrete_opt_1(),
-            800 => self.port_default_value(&children[0]),
-            801 => self.port_type_abstract(&children[0], &children[1], &children[2], &children[3]),
-            802 => self.port_type_abstract_opt1_0(&children[0]),
-            803 => self.port_type_abstract_opt1_1(),
-            804 => self.port_type_abstract_opt0_0(&children[0], &children[1]),
-            805 => self.port_type_abstract_opt0_1(),
-            806 => self.port_type_abstract_opt_0(&children[0]),
-            807 => self.port_type_abstract_opt_1(),
-            808 => self.direction_0(&children[0]),
-            809 => self.direction_1(&children[0]),
-            810 => self.direction_2(&children[0]),
-            811 => self.direction_3(&children[0]),
-            812 => self.direction_4(&children[0]),
-            813 => self.direction_5(&children[0]),
-            814 => self.function_declaration(
+            802 => self.with_generic_parameter_item_opt_0(&children[0], &children[1]),
+            803 => self.with_generic_parameter_item_opt_1(),
+            804 => self.with_generic_argument(&children[0], &children[1], &children[2]),
+            805 => self.with_generic_argument_opt_0(&children[0]),
+            806 => self.with_generic_argument_opt_1(),
+            807 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
+            808 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
+            809 => self.with_generic_argument_list_list_1(),
+            810 => self.with_generic_argument_list_opt_0(&children[0]),
+            811 => self.with_generic_argument_list_opt_1(),
+            812 => self.with_generic_argument_item_0(&children[0]),
+            813 => self.with_generic_argument_item_1(&children[0]),
+            814 => self.port_declaration(&children[0], &children[1], &children[2]),
+            815 => self.port_declaration_opt_0(&children[0]),
+            816 => self.port_declaration_opt_1(),
+            817 => self.port_declaration_list(&children[0], &children[1], &children[2]),
+            818 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
+            819 => self.port_declaration_list_list_1(),
+            820 => self.port_declaration_list_opt_0(&children[0]),
+            821 => self.port_declaration_list_opt_1(),
+            822 => self.port_declaration_group(&children[0], &children[1]),
+            823 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
+            824 => self.port_declaration_group_group_1(&children[0]),
+            825 => self.port_declaration_group_list_0(&children[0], &children[1]),
+            826 => self.port_declaration_group_list_1(),
+            827 => self.port_declaration_item(&children[0], &children[1], &children[2]),
+            828 => self.port_declaration_item_group_0(&children[0]),
+            829 => self.port_declaration_item_group_1(&children[0]),
+            830 => self.port_type_concrete(&children[0], &children[1], &children[2], &children[3]),
+            831 => self.port_type_concrete_opt0_0(&children[0], &children[1]),
+            832 => self.port_type_concrete_opt0_1(),
+            833 => self.port_type_concrete_opt_0(&children[0]),
+            834 => self.port_type_concrete_opt_1(),
+            835 => self.port_default_value(&children[0]),
+            836 => self.port_type_abstract(&children[0], &children[1], &children[2], &children[3]),
+            837 => self.port_type_abstract_opt1_0(&children[0]),
+            838 => self.port_type_abstract_opt1_1(),
+            839 => self.port_type_abstract_opt0_0(&children[0], &children[1]),
+            840 => self.port_type_abstract_opt0_1(),
+            841 => self.port_type_abstract_opt_0(&children[0]),
+            842 => self.port_type_abstract_opt_1(),
+            843 => self.direction_0(&children[0]),
+            844 => self.direction_1(&children[0]),
+            845 => self.direction_2(&children[0]),
+            846 => self.direction_3(&children[0]),
+            847 => self.direction_4(&children[0]),
+            848 => self.direction_5(&children[0]),
+            849 => self.function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35880,21 +37293,21 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            815 => self.function_declaration_opt1_0(&children[0], &children[1]),
-            816 => self.function_declaration_opt1_1(),
-            817 => self.function_declaration_opt0_0(&children[0]),
-            818 => self.function_declaration_opt0_1(),
-            819 => self.function_declaration_opt_0(&children[0]),
-            820 => self.function_declaration_opt_1(),
-            821 => self.import_declaration(&children[0], &children[1], &children[2], &children[3]),
-            822 => self.import_declaration_opt_0(&children[0], &children[1]),
-            823 => self.import_declaration_opt_1(),
-            824 => self.export_declaration(&children[0], &children[1], &children[2]),
-            825 => self.export_declaration_group_0(&children[0]),
-            826 => self.export_declaration_group_1(&children[0], &children[1]),
-            827 => self.export_declaration_opt_0(&children[0], &children[1]),
-            828 => self.export_declaration_opt_1(),
-            829 => self.unsafe_block(
+            850 => self.function_declaration_opt1_0(&children[0], &children[1]),
+            851 => self.function_declaration_opt1_1(),
+            852 => self.function_declaration_opt0_0(&children[0]),
+            853 => self.function_declaration_opt0_1(),
+            854 => self.function_declaration_opt_0(&children[0]),
+            855 => self.function_declaration_opt_1(),
+            856 => self.import_declaration(&children[0], &children[1], &children[2], &children[3]),
+            857 => self.import_declaration_opt_0(&children[0], &children[1]),
+            858 => self.import_declaration_opt_1(),
+            859 => self.export_declaration(&children[0], &children[1], &children[2]),
+            860 => self.export_declaration_group_0(&children[0]),
+            861 => self.export_declaration_group_1(&children[0], &children[1]),
+            862 => self.export_declaration_opt_0(&children[0], &children[1]),
+            863 => self.export_declaration_opt_1(),
+            864 => self.unsafe_block(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35903,9 +37316,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            830 => self.unsafe_block_list_0(&children[0], &children[1]),
-            831 => self.unsafe_block_list_1(),
-            832 => self.module_declaration(
+            865 => self.unsafe_block_list_0(&children[0], &children[1]),
+            866 => self.unsafe_block_list_1(),
+            867 => self.module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35917,27 +37330,27 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[8],
                 &children[9],
             ),
-            833 => self.module_declaration_list_0(&children[0], &children[1]),
-            834 => self.module_declaration_list_1(),
-            835 => self.module_declaration_opt3_0(&children[0]),
-            836 => self.module_declaration_opt3_1(),
-            837 => self.module_declaration_opt2_0(&children[0]),
-            838 => self.module_declaration_opt2_1(),
-            839 => self.module_declaration_opt1_0(&children[0], &children[1]),
-            840 => self.module_declaration_opt1_1(),
-            841 => self.module_declaration_opt0_0(&children[0]),
-            842 => self.module_declaration_opt0_1(),
-            843 => self.module_declaration_opt_0(&children[0]),
-            844 => self.module_declaration_opt_1(),
-            845 => self.module_group(&children[0], &children[1]),
-            846 => self.module_group_group_0(&children[0], &children[1], &children[2]),
-            847 => self.module_group_group_list_0(&children[0], &children[1]),
-            848 => self.module_group_group_list_1(),
-            849 => self.module_group_group_1(&children[0]),
-            850 => self.module_group_list_0(&children[0], &children[1]),
-            851 => self.module_group_list_1(),
-            852 => self.module_item(&children[0]),
-            853 => self.interface_declaration(
+            868 => self.module_declaration_list_0(&children[0], &children[1]),
+            869 => self.module_declaration_list_1(),
+            870 => self.module_declaration_opt3_0(&children[0]),
+            871 => self.module_declaration_opt3_1(),
+            872 => self.module_declaration_opt2_0(&children[0]),
+            873 => self.module_declaration_opt2_1(),
+            874 => self.module_declaration_opt1_0(&children[0], &children[1]),
+            875 => self.module_declaration_opt1_1(),
+            876 => self.module_declaration_opt0_0(&children[0]),
+            877 => self.module_declaration_opt0_1(),
+            878 => self.module_declaration_opt_0(&children[0]),
+            879 => self.module_declaration_opt_1(),
+            880 => self.module_group(&children[0], &children[1]),
+            881 => self.module_group_group_0(&children[0], &children[1], &children[2]),
+            882 => self.module_group_group_list_0(&children[0], &children[1]),
+            883 => self.module_group_group_list_1(),
+            884 => self.module_group_group_1(&children[0]),
+            885 => self.module_group_list_0(&children[0], &children[1]),
+            886 => self.module_group_list_1(),
+            887 => self.module_item(&children[0]),
+            888 => self.interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35947,41 +37360,41 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            854 => self.interface_declaration_list_0(&children[0], &children[1]),
-            855 => self.interface_declaration_list_1(),
-            856 => self.interface_declaration_opt1_0(&children[0]),
-            857 => self.interface_declaration_opt1_1(),
-            858 => self.interface_declaration_opt0_0(&children[0]),
-            859 => self.interface_declaration_opt0_1(),
-            860 => self.interface_declaration_opt_0(&children[0]),
-            861 => self.interface_declaration_opt_1(),
-            862 => self.interface_group(&children[0], &children[1]),
-            863 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
-            864 => self.interface_group_group_list_0(&children[0], &children[1]),
-            865 => self.interface_group_group_list_1(),
-            866 => self.interface_group_group_1(&children[0]),
-            867 => self.interface_group_list_0(&children[0], &children[1]),
-            868 => self.interface_group_list_1(),
-            869 => self.interface_item_0(&children[0]),
-            870 => self.interface_item_1(&children[0]),
-            871 => self.generate_if_declaration(
+            889 => self.interface_declaration_list_0(&children[0], &children[1]),
+            890 => self.interface_declaration_list_1(),
+            891 => self.interface_declaration_opt1_0(&children[0]),
+            892 => self.interface_declaration_opt1_1(),
+            893 => self.interface_declaration_opt0_0(&children[0]),
+            894 => self.interface_declaration_opt0_1(),
+            895 => self.interface_declaration_opt_0(&children[0]),
+            896 => self.interface_declaration_opt_1(),
+            897 => self.interface_group(&children[0], &children[1]),
+            898 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
+            899 => self.interface_group_group_list_0(&children[0], &children[1]),
+            900 => self.interface_group_group_list_1(),
+            901 => self.interface_group_group_1(&children[0]),
+            902 => self.interface_group_list_0(&children[0], &children[1]),
+            903 => self.interface_group_list_1(),
+            904 => self.interface_item_0(&children[0]),
+            905 => self.interface_item_1(&children[0]),
+            906 => self.generate_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            872 => self.generate_if_declaration_list_0(
+            907 => self.generate_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            873 => self.generate_if_declaration_list_1(),
-            874 => self.generate_if_declaration_opt_0(&children[0], &children[1]),
-            875 => self.generate_if_declaration_opt_1(),
-            876 => self.generate_for_declaration(
+            908 => self.generate_if_declaration_list_1(),
+            909 => self.generate_if_declaration_opt_0(&children[0], &children[1]),
+            910 => self.generate_if_declaration_opt_1(),
+            911 => self.generate_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -35989,54 +37402,55 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            877 => self.generate_for_declaration_opt_0(&children[0], &children[1], &children[2]),
-            878 => self.generate_for_declaration_opt_1(),
-            879 => self.generate_block_declaration(&children[0]),
-            880 => self.generate_named_block(
+            912 => self.generate_for_declaration_opt_0(&children[0], &children[1], &children[2]),
+            913 => self.generate_for_declaration_opt_1(),
+            914 => self.generate_block_declaration(&children[0]),
+            915 => self.generate_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            881 => self.generate_named_block_list_0(&children[0], &children[1]),
-            882 => self.generate_named_block_list_1(),
-            883 => self.generate_optional_named_block(
+            916 => self.generate_named_block_list_0(&children[0], &children[1]),
+            917 => self.generate_named_block_list_1(),
+            918 => self.generate_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            884 => self.generate_optional_named_block_list_0(&children[0], &children[1]),
-            885 => self.generate_optional_named_block_list_1(),
-            886 => self.generate_optional_named_block_opt_0(&children[0], &children[1]),
-            887 => self.generate_optional_named_block_opt_1(),
-            888 => self.generate_group(&children[0], &children[1]),
-            889 => self.generate_group_group_0(&children[0], &children[1], &children[2]),
-            890 => self.generate_group_group_list_0(&children[0], &children[1]),
-            891 => self.generate_group_group_list_1(),
-            892 => self.generate_group_group_1(&children[0]),
-            893 => self.generate_group_list_0(&children[0], &children[1]),
-            894 => self.generate_group_list_1(),
-            895 => self.generate_item_0(&children[0]),
-            896 => self.generate_item_1(&children[0]),
-            897 => self.generate_item_2(&children[0]),
-            898 => self.generate_item_3(&children[0]),
-            899 => self.generate_item_4(&children[0]),
-            900 => self.generate_item_5(&children[0]),
-            901 => self.generate_item_6(&children[0]),
-            902 => self.generate_item_7(&children[0]),
-            903 => self.generate_item_8(&children[0]),
-            904 => self.generate_item_9(&children[0]),
-            905 => self.generate_item_10(&children[0]),
-            906 => self.generate_item_11(&children[0]),
-            907 => self.generate_item_12(&children[0]),
-            908 => self.generate_item_13(&children[0]),
-            909 => self.generate_item_14(&children[0]),
-            910 => self.generate_item_15(&children[0]),
-            911 => self.generate_item_16(&children[0]),
-            912 => self.generate_item_17(&children[0]),
-            913 => self.package_declaration(
+            919 => self.generate_optional_named_block_list_0(&children[0], &children[1]),
+            920 => self.generate_optional_named_block_list_1(),
+            921 => self.generate_optional_named_block_opt_0(&children[0], &children[1]),
+            922 => self.generate_optional_named_block_opt_1(),
+            923 => self.generate_group(&children[0], &children[1]),
+            924 => self.generate_group_group_0(&children[0], &children[1], &children[2]),
+            925 => self.generate_group_group_list_0(&children[0], &children[1]),
+            926 => self.generate_group_group_list_1(),
+            927 => self.generate_group_group_1(&children[0]),
+            928 => self.generate_group_list_0(&children[0], &children[1]),
+            929 => self.generate_group_list_1(),
+            930 => self.generate_item_0(&children[0]),
+            931 => self.generate_item_1(&children[0]),
+            932 => self.generate_item_2(&children[0]),
+            933 => self.generate_item_3(&children[0]),
+            934 => self.generate_item_4(&children[0]),
+            935 => self.generate_item_5(&children[0]),
+            936 => self.generate_item_6(&children[0]),
+            937 => self.generate_item_7(&children[0]),
+            938 => self.generate_item_8(&children[0]),
+            939 => self.generate_item_9(&children[0]),
+            940 => self.generate_item_10(&children[0]),
+            941 => self.generate_item_11(&children[0]),
+            942 => self.generate_item_12(&children[0]),
+            943 => self.generate_item_13(&children[0]),
+            944 => self.generate_item_14(&children[0]),
+            945 => self.generate_item_15(&children[0]),
+            946 => self.generate_item_16(&children[0]),
+            947 => self.generate_item_17(&children[0]),
+            948 => self.generate_item_18(&children[0]),
+            949 => self.package_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -36045,28 +37459,28 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            914 => self.package_declaration_list_0(&children[0], &children[1]),
-            915 => self.package_declaration_list_1(),
-            916 => self.package_declaration_opt0_0(&children[0]),
-            917 => self.package_declaration_opt0_1(),
-            918 => self.package_declaration_opt_0(&children[0]),
-            919 => self.package_declaration_opt_1(),
-            920 => self.package_group(&children[0], &children[1]),
-            921 => self.package_group_group_0(&children[0], &children[1], &children[2]),
-            922 => self.package_group_group_list_0(&children[0], &children[1]),
-            923 => self.package_group_group_list_1(),
-            924 => self.package_group_group_1(&children[0]),
-            925 => self.package_group_list_0(&children[0], &children[1]),
-            926 => self.package_group_list_1(),
-            927 => self.package_item_0(&children[0]),
-            928 => self.package_item_1(&children[0]),
-            929 => self.package_item_2(&children[0]),
-            930 => self.package_item_3(&children[0]),
-            931 => self.package_item_4(&children[0]),
-            932 => self.package_item_5(&children[0]),
-            933 => self.package_item_6(&children[0]),
-            934 => self.package_item_7(&children[0]),
-            935 => self.proto_module_declaration(
+            950 => self.package_declaration_list_0(&children[0], &children[1]),
+            951 => self.package_declaration_list_1(),
+            952 => self.package_declaration_opt0_0(&children[0]),
+            953 => self.package_declaration_opt0_1(),
+            954 => self.package_declaration_opt_0(&children[0]),
+            955 => self.package_declaration_opt_1(),
+            956 => self.package_group(&children[0], &children[1]),
+            957 => self.package_group_group_0(&children[0], &children[1], &children[2]),
+            958 => self.package_group_group_list_0(&children[0], &children[1]),
+            959 => self.package_group_group_list_1(),
+            960 => self.package_group_group_1(&children[0]),
+            961 => self.package_group_list_0(&children[0], &children[1]),
+            962 => self.package_group_list_1(),
+            963 => self.package_item_0(&children[0]),
+            964 => self.package_item_1(&children[0]),
+            965 => self.package_item_2(&children[0]),
+            966 => self.package_item_3(&children[0]),
+            967 => self.package_item_4(&children[0]),
+            968 => self.package_item_5(&children[0]),
+            969 => self.package_item_6(&children[0]),
+            970 => self.package_item_7(&children[0]),
+            971 => self.proto_module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -36075,13 +37489,13 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            936 => self.proto_module_declaration_opt1_0(&children[0]),
-            937 => self.proto_module_declaration_opt1_1(),
-            938 => self.proto_module_declaration_opt0_0(&children[0]),
-            939 => self.proto_module_declaration_opt0_1(),
-            940 => self.proto_module_declaration_opt_0(&children[0]),
-            941 => self.proto_module_declaration_opt_1(),
-            942 => self.embed_declaration(
+            972 => self.proto_module_declaration_opt1_0(&children[0]),
+            973 => self.proto_module_declaration_opt1_1(),
+            974 => self.proto_module_declaration_opt0_0(&children[0]),
+            975 => self.proto_module_declaration_opt0_1(),
+            976 => self.proto_module_declaration_opt_0(&children[0]),
+            977 => self.proto_module_declaration_opt_1(),
+            978 => self.embed_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -36089,8 +37503,8 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            943 => self.embed_content(&children[0]),
-            944 => self.embed_content_token(
+            979 => self.embed_content(&children[0]),
+            980 => self.embed_content_token(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -36100,13 +37514,13 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            945 => self.embed_content_token_list_0(&children[0], &children[1]),
-            946 => self.embed_content_token_list_1(),
-            947 => self.embed_item_0(&children[0], &children[1], &children[2]),
-            948 => self.embed_item_list_0(&children[0], &children[1]),
-            949 => self.embed_item_list_1(),
-            950 => self.embed_item_1(&children[0]),
-            951 => self.include_declaration(
+            981 => self.embed_content_token_list_0(&children[0], &children[1]),
+            982 => self.embed_content_token_list_1(),
+            983 => self.embed_item_0(&children[0], &children[1], &children[2]),
+            984 => self.embed_item_list_0(&children[0], &children[1]),
+            985 => self.embed_item_list_1(),
+            986 => self.embed_item_1(&children[0]),
+            987 => self.include_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -36115,23 +37529,23 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            952 => self.description_group(&children[0], &children[1]),
-            953 => self.description_group_group_0(&children[0], &children[1], &children[2]),
-            954 => self.description_group_group_list_0(&children[0], &children[1]),
-            955 => self.description_group_group_list_1(),
-            956 => self.description_group_group_1(&children[0]),
-            957 => self.description_group_list_0(&children[0], &children[1]),
-            958 => self.description_group_list_1(),
-            959 => self.description_item_0(&children[0]),
-            960 => self.description_item_1(&children[0]),
-            961 => self.description_item_2(&children[0]),
-            962 => self.description_item_3(&children[0]),
-            963 => self.description_item_4(&children[0]),
-            964 => self.description_item_5(&children[0]),
-            965 => self.description_item_6(&children[0]),
-            966 => self.veryl(&children[0], &children[1]),
-            967 => self.veryl_list_0(&children[0], &children[1]),
-            968 => self.veryl_list_1(),
+            988 => self.description_group(&children[0], &children[1]),
+            989 => self.description_group_group_0(&children[0], &children[1], &children[2]),
+            990 => self.description_group_group_list_0(&children[0], &children[1]),
+            991 => self.description_group_group_list_1(),
+            992 => self.description_group_group_1(&children[0]),
+            993 => self.description_group_list_0(&children[0], &children[1]),
+            994 => self.description_group_list_1(),
+            995 => self.description_item_0(&children[0]),
+            996 => self.description_item_1(&children[0]),
+            997 => self.description_item_2(&children[0]),
+            998 => self.description_item_3(&children[0]),
+            999 => self.description_item_4(&children[0]),
+            1000 => self.description_item_5(&children[0]),
+            1001 => self.description_item_6(&children[0]),
+            1002 => self.veryl(&children[0], &children[1]),
+            1003 => self.veryl_list_0(&children[0], &children[1]),
+            1004 => self.veryl_list_1(),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {}",
                 prod_num
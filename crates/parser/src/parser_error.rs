@@ -121,12 +121,15 @@ pub enum TokenType {
     Star,
     AlwaysComb,
     AlwaysFf,
+    Assert,
     Assign,
+    Assume,
     AsyncHigh,
     AsyncLow,
     As,
     Bit,
     Case,
+    Cover,
     Default,
     Else,
     Enum,
@@ -161,6 +164,7 @@ pub enum TokenType {
     Package,
     Param,
     Posedge,
+    Property,
     Pub,
     Ref,
     Repeat,
@@ -228,12 +232,15 @@ impl From<&str> for TokenType {
             "StarTerm" => TokenType::Star,
             "AlwaysCombTerm" => TokenType::AlwaysComb,
             "AlwaysFfTerm" => TokenType::AlwaysFf,
+            "AssertTerm" => TokenType::Assert,
             "AssignTerm" => TokenType::Assign,
+            "AssumeTerm" => TokenType::Assume,
             "AsyncHighTerm" => TokenType::AsyncHigh,
             "AsyncLowTerm" => TokenType::AsyncLow,
             "AsTerm" => TokenType::As,
             "BitTerm" => TokenType::Bit,
             "CaseTerm" => TokenType::Case,
+            "CoverTerm" => TokenType::Cover,
             "DefaultTerm" => TokenType::Default,
             "ElseTerm" => TokenType::Else,
             "EnumTerm" => TokenType::Enum,
@@ -268,6 +275,7 @@ impl From<&str> for TokenType {
             "PackageTerm" => TokenType::Package,
             "ParamTerm" => TokenType::Param,
             "PosedgeTerm" => TokenType::Posedge,
+            "PropertyTerm" => TokenType::Property,
             "PubTerm" => TokenType::Pub,
             "RefTerm" => TokenType::Ref,
             "RepeatTerm" => TokenType::Repeat,
@@ -337,12 +345,15 @@ impl std::fmt::Display for TokenType {
             TokenType::Star => "*",
             TokenType::AlwaysComb => "always_comb",
             TokenType::AlwaysFf => "always_ff",
+            TokenType::Assert => "assert",
             TokenType::Assign => "assign",
+            TokenType::Assume => "assume",
             TokenType::AsyncHigh => "async_high",
             TokenType::AsyncLow => "async_low",
             TokenType::As => "as",
             TokenType::Bit => "bit",
             TokenType::Case => "case",
+            TokenType::Cover => "cover",
             TokenType::Default => "default",
             TokenType::Else => "else",
             TokenType::Enum => "enum",
@@ -377,6 +388,7 @@ impl std::fmt::Display for TokenType {
             TokenType::Package => "package",
             TokenType::Param => "param",
             TokenType::Posedge => "posedge",
+            TokenType::Property => "property",
             TokenType::Pub => "pub",
             TokenType::Ref => "ref",
             TokenType::Repeat => "repeat",
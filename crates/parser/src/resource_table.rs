@@ -1,6 +1,8 @@
 use bimap::BiMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Borrow;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
@@ -18,7 +20,7 @@ where
 impl<T, U> GlobalTable<T, U>
 where
     T: Hash + Eq,
-    U: Hash + Eq + Copy + Incrementable,
+    U: Hash + Eq + Copy + Default + Incrementable,
 {
     pub fn insert(&mut self, value: T) -> U {
         if let Some(id) = self.table.get_by_left(&value) {
@@ -38,6 +40,11 @@ where
     pub fn get_id<V: Borrow<T>>(&self, value: V) -> Option<U> {
         self.table.get_by_left(value.borrow()).copied()
     }
+
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.last = U::default();
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -83,6 +90,54 @@ impl fmt::Display for TokenId {
     }
 }
 
+// Ids are serialized through their values because they are valid only in the current process.
+
+impl Serialize for StrId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        get_str_value(*self)
+            .unwrap_or_default()
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StrId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(insert_str(&value))
+    }
+}
+
+impl Serialize for PathId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        get_path_value(*self)
+            .unwrap_or_default()
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PathId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = PathBuf::deserialize(deserializer)?;
+        Ok(insert_path(&value))
+    }
+}
+
+impl Serialize for TokenId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Token ids are renumbered on deserialization not to conflict with ids of the current process.
+/// The same id is mapped to the same new id until `clear_token_id_map` is called.
+impl<'de> Deserialize<'de> for TokenId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = usize::deserialize(deserializer)?;
+        let id = TOKEN_ID_MAP.with(|f| *f.borrow_mut().entry(value).or_insert_with(new_token_id));
+        Ok(id)
+    }
+}
+
 impl From<&str> for StrId {
     fn from(x: &str) -> Self {
         insert_str(x)
@@ -92,6 +147,7 @@ impl From<&str> for StrId {
 thread_local!(static STRING_TABLE: RefCell<GlobalTable<String, StrId>> = RefCell::new(GlobalTable::default()));
 thread_local!(static PATHBUF_TABLE: RefCell<GlobalTable<PathBuf, PathId>> = RefCell::new(GlobalTable::default()));
 thread_local!(static TOKEN_ID: RefCell<usize> = const { RefCell::new(0) });
+thread_local!(static TOKEN_ID_MAP: RefCell<HashMap<usize, TokenId>> = RefCell::new(HashMap::new()));
 
 pub fn insert_str(value: &str) -> StrId {
     STRING_TABLE.with(|f| f.borrow_mut().insert(value.to_owned()))
@@ -124,3 +180,17 @@ pub fn new_token_id() -> TokenId {
         TokenId(*ret)
     })
 }
+
+/// Clears the map from deserialized token ids to renumbered ones
+pub fn clear_token_id_map() {
+    TOKEN_ID_MAP.with(|f| f.borrow_mut().clear());
+}
+
+/// Clears all strings, paths and token ids.
+/// Ids got before clearing must not be used after it.
+pub fn clear() {
+    STRING_TABLE.with(|f| f.borrow_mut().clear());
+    PATHBUF_TABLE.with(|f| f.borrow_mut().clear());
+    TOKEN_ID.with(|f| *f.borrow_mut() = 0);
+    clear_token_id_map();
+}
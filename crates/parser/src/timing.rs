@@ -0,0 +1,142 @@
+use std::cell::{Cell, RefCell};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Measured time of a phase.
+///
+/// Records of nested phases and handlers are accumulated into the enclosing top-level phase,
+/// so there is a record per (name, parent, path) in the enclosing one.
+#[derive(Clone, Debug)]
+pub struct Record {
+    /// Name of the phase or handler
+    pub name: &'static str,
+    /// Enclosing phase or handler, `None` if the phase is top-level
+    pub parent: Option<&'static str>,
+    pub path: Option<PathBuf>,
+    /// Start time of the top-level phase including this record
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+struct Frame {
+    name: &'static str,
+    path: Option<PathBuf>,
+    children: Vec<(&'static str, &'static str, Duration)>,
+}
+
+impl Frame {
+    fn add(&mut self, name: &'static str, parent: &'static str, duration: Duration) {
+        if let Some(x) = self
+            .children
+            .iter_mut()
+            .find(|x| x.0 == name && x.1 == parent)
+        {
+            x.2 += duration;
+        } else {
+            self.children.push((name, parent, duration));
+        }
+    }
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDS: Mutex<Vec<Record>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static FRAMES: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+    static HANDLER: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// Enables timing instrumentation.
+/// `measure` and handlers called by walker are not measured until this is called.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Measures `f` as phase `name`.
+/// `path` is the file processed by the phase, and nested phases inherit it if `None`.
+pub fn measure<T, F: FnOnce() -> T>(name: &'static str, path: Option<&Path>, f: F) -> T {
+    if !is_enabled() {
+        return f();
+    }
+
+    let path = path
+        .map(|x| x.to_path_buf())
+        .or_else(|| FRAMES.with(|x| x.borrow().last().and_then(|x| x.path.clone())));
+    FRAMES.with(|x| {
+        x.borrow_mut().push(Frame {
+            name,
+            path,
+            children: Vec::new(),
+        })
+    });
+    let handler = HANDLER.replace(None);
+
+    let start = Instant::now();
+    let ret = f();
+    let duration = start.elapsed();
+
+    HANDLER.set(handler);
+    FRAMES.with(|x| {
+        let mut frames = x.borrow_mut();
+        let frame = frames.pop().unwrap();
+        if let Some(parent) = frames.last_mut() {
+            parent.add(name, handler.unwrap_or(parent.name), duration);
+            for (name, parent_name, duration) in frame.children {
+                parent.add(name, parent_name, duration);
+            }
+        } else {
+            let mut records = RECORDS.lock().unwrap();
+            for (name, parent, duration) in frame.children {
+                records.push(Record {
+                    name,
+                    parent: Some(parent),
+                    path: frame.path.clone(),
+                    start,
+                    duration,
+                });
+            }
+            records.push(Record {
+                name,
+                parent: None,
+                path: frame.path,
+                start,
+                duration,
+            });
+        }
+    });
+    ret
+}
+
+/// Measures a handler called by walker.
+/// The time is accumulated to the innermost phase, and is discarded if there is no phase.
+pub(crate) fn measure_handler<T, F: FnOnce() -> T>(name: &'static str, f: F) -> T {
+    let handler = HANDLER.replace(Some(name));
+    let start = Instant::now();
+    let ret = f();
+    let duration = start.elapsed();
+    HANDLER.set(handler);
+
+    FRAMES.with(|x| {
+        if let Some(frame) = x.borrow_mut().last_mut() {
+            frame.add(name, handler.unwrap_or(frame.name), duration);
+        }
+    });
+    ret
+}
+
+/// Returns all records and clears them
+pub fn take() -> Vec<Record> {
+    std::mem::take(&mut *RECORDS.lock().unwrap())
+}
+
+/// Returns the short name of handler type
+pub(crate) fn handler_name(type_name: &'static str) -> &'static str {
+    let name = type_name.split('<').next().unwrap_or(type_name);
+    name.rsplit("::").next().unwrap_or(name)
+}
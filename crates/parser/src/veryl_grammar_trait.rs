@@ -83,6 +83,34 @@ macro_rules! group_to_item {
     };
 }
 
+impl IdentifierFactor {
+    /// Returns the function call if the factor calls a function
+    pub fn function_call(&self) -> Option<&FunctionCall> {
+        match self
+            .identifier_factor_opt
+            .as_ref()?
+            .identifier_factor_opt_group
+            .as_ref()
+        {
+            IdentifierFactorOptGroup::FunctionCall(x) => Some(&x.function_call),
+            _ => None,
+        }
+    }
+
+    /// Returns the struct constructor if the factor constructs a struct
+    pub fn struct_constructor(&self) -> Option<&StructConstructor> {
+        match self
+            .identifier_factor_opt
+            .as_ref()?
+            .identifier_factor_opt_group
+            .as_ref()
+        {
+            IdentifierFactorOptGroup::StructConstructor(x) => Some(&x.struct_constructor),
+            _ => None,
+        }
+    }
+}
+
 impl From<&ScopedIdentifier> for Vec<Option<WithGenericArgument>> {
     fn from(value: &ScopedIdentifier) -> Self {
         let mut ret = Vec::new();
@@ -126,72 +154,123 @@ group_to_item!(Package);
 group_to_item!(Description);
 group_to_item!(StatementBlock);
 
-pub fn is_anonymous_expression(arg: &Expression) -> bool {
+/// Returns the operand if the expression consists of a single operand without any binary operator
+pub fn expression_operand(arg: &Expression) -> Option<&Expression11> {
     if !arg.expression_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*arg.expression01;
     if !exp.expression01_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression02;
     if !exp.expression02_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression03;
     if !exp.expression03_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression04;
     if !exp.expression04_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression05;
     if !exp.expression05_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression06;
     if !exp.expression06_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression07;
     if !exp.expression07_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression08;
     if !exp.expression08_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression09;
     if !exp.expression09_list.is_empty() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression10;
     if !exp.expression10_list.is_empty() {
-        return false;
+        return None;
     }
 
-    let exp = &*exp.expression11;
+    Some(&exp.expression11)
+}
+
+/// Returns the factor if the expression consists of a single factor without any operator
+pub fn expression_factor(arg: &Expression) -> Option<&Factor> {
+    let exp = expression_operand(arg)?;
     if exp.expression11_opt.is_some() {
-        return false;
+        return None;
     }
 
     let exp = &*exp.expression12;
     if !exp.expression12_list.is_empty() {
-        return false;
+        return None;
+    }
+
+    Some(&exp.factor)
+}
+
+/// Returns the identifier if the expression is a single identifier without function call
+pub fn expression_identifier(arg: &Expression) -> Option<&ExpressionIdentifier> {
+    match expression_factor(arg)? {
+        Factor::IdentifierFactor(x) if x.identifier_factor.identifier_factor_opt.is_none() => {
+            Some(&x.identifier_factor.expression_identifier)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the enable and the value if the expression is `if enable { value } else { 'z }`
+pub fn tristate_expression(arg: &Expression) -> Option<(&Expression, &Expression)> {
+    let Factor::IfExpression(x) = expression_factor(arg)? else {
+        return None;
+    };
+    let x = &x.if_expression;
+    if !x.if_expression_list.is_empty() {
+        return None;
     }
 
-    match &*exp.factor {
+    let Factor::Number(number) = expression_factor(&x.expression1)? else {
+        return None;
+    };
+    let Number::IntegralNumber(number) = number.number.as_ref() else {
+        return None;
+    };
+    let IntegralNumber::AllBit(all_bit) = number.integral_number.as_ref() else {
+        return None;
+    };
+    let text = all_bit.all_bit.all_bit_token.to_string();
+    if text.ends_with(['z', 'Z']) {
+        Some((&x.expression, &x.expression0))
+    } else {
+        None
+    }
+}
+
+pub fn is_anonymous_expression(arg: &Expression) -> bool {
+    let Some(factor) = expression_factor(arg) else {
+        return false;
+    };
+
+    match factor {
         Factor::IdentifierFactor(x) => {
             let factor = &x.identifier_factor;
 
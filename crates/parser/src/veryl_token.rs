@@ -4,9 +4,10 @@ use crate::veryl_grammar_trait::*;
 use once_cell::sync::Lazy;
 use paste::paste;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TokenSource {
     File(PathId),
     Builtin,
@@ -36,7 +37,7 @@ impl PartialEq<PathId> for TokenSource {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Token {
     pub id: TokenId,
     pub text: StrId,
@@ -130,7 +131,7 @@ impl From<Token> for miette::SourceSpan {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenRange {
     pub beg: Token,
     pub end: Token,
@@ -387,6 +388,14 @@ impl_token_range!(
     r_brace_token
 );
 impl_token_range!(TypeExpression, r#type, type_token, r_paren, r_paren_token);
+impl_token_range!(Width, l_angle, l_angle_token, r_angle, r_angle_token);
+impl_token_range!(
+    StructConstructor,
+    quote_l_brace,
+    quote_l_brace_token,
+    r_brace,
+    r_brace_token
+);
 
 impl From<&FactorGroup> for TokenRange {
     fn from(value: &FactorGroup) -> Self {
@@ -402,7 +411,12 @@ impl From<&Factor> for TokenRange {
         match value {
             Factor::Number(x) => x.number.as_ref().into(),
             Factor::IdentifierFactor(x) => {
-                x.identifier_factor.expression_identifier.as_ref().into()
+                let x = &x.identifier_factor;
+                let mut range: TokenRange = x.expression_identifier.as_ref().into();
+                if let Some(x) = x.struct_constructor() {
+                    range.end = x.r_brace.r_brace_token.token;
+                }
+                range
             }
             Factor::LParenExpressionRParen(x) => x.into(),
             Factor::LBraceConcatenationListRBrace(x) => x.into(),
@@ -637,6 +651,16 @@ impl From<&CastingType> for TokenRange {
                 let end = beg;
                 TokenRange { beg, end }
             }
+            CastingType::Bit(x) => {
+                let beg = x.bit.bit_token.token;
+                let end = beg;
+                TokenRange { beg, end }
+            }
+            CastingType::Logic(x) => {
+                let beg = x.logic.logic_token.token;
+                let end = beg;
+                TokenRange { beg, end }
+            }
             CastingType::Clock(x) => {
                 let beg = x.clock.clock_token.token;
                 let end = beg;
@@ -694,7 +718,7 @@ impl From<&CastingType> for TokenRange {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerylToken {
     pub token: Token,
     pub comments: Vec<Token>,
@@ -932,13 +956,16 @@ token_with_comments!(UnaryOperator);
 token_with_comments!(AlwaysComb);
 token_with_comments!(AlwaysFf);
 token_with_comments!(As);
+token_with_comments!(Assert);
 token_with_comments!(Assign);
+token_with_comments!(Assume);
 token_with_comments!(Bit);
 token_with_comments!(Break);
 token_with_comments!(Case);
 token_with_comments!(Clock);
 token_with_comments!(ClockPosedge);
 token_with_comments!(ClockNegedge);
+token_with_comments!(Cover);
 token_with_comments!(Const);
 token_with_comments!(Default);
 token_with_comments!(Else);
@@ -973,6 +1000,7 @@ token_with_comments!(Output);
 token_with_comments!(Outside);
 token_with_comments!(Package);
 token_with_comments!(Param);
+token_with_comments!(Property);
 token_with_comments!(Proto);
 token_with_comments!(Pub);
 token_with_comments!(Ref);
@@ -1,12 +1,18 @@
+use crate::timing;
 use crate::veryl_grammar_trait::*;
 use crate::veryl_token::VerylToken;
 
 macro_rules! before {
     ($x:ident, $y:ident, $z:ident) => {
         if let Some(mut handlers) = $x.get_handlers() {
+            let timing = timing::is_enabled();
             for handler in handlers.iter_mut() {
                 handler.set_point(HandlerPoint::Before);
-                let _ = handler.$y($z);
+                if timing {
+                    let _ = timing::measure_handler(handler.name(), || handler.$y($z));
+                } else {
+                    let _ = handler.$y($z);
+                }
             }
         }
     };
@@ -15,9 +21,14 @@ macro_rules! before {
 macro_rules! after {
     ($x:ident, $y:ident, $z:ident) => {
         if let Some(mut handlers) = $x.get_handlers() {
+            let timing = timing::is_enabled();
             for handler in handlers.iter_mut() {
                 handler.set_point(HandlerPoint::After);
-                let _ = handler.$y($z);
+                if timing {
+                    let _ = timing::measure_handler(handler.name(), || handler.$y($z));
+                } else {
+                    let _ = handler.$y($z);
+                }
             }
         }
     };
@@ -356,6 +367,13 @@ pub trait VerylWalker {
         after!(self, r#as, arg);
     }
 
+    /// Semantic action for non-terminal 'Assert'
+    fn assert(&mut self, arg: &Assert) {
+        before!(self, assert, arg);
+        self.veryl_token(&arg.assert_token);
+        after!(self, assert, arg);
+    }
+
     /// Semantic action for non-terminal 'Assign'
     fn assign(&mut self, arg: &Assign) {
         before!(self, assign, arg);
@@ -363,6 +381,13 @@ pub trait VerylWalker {
         after!(self, assign, arg);
     }
 
+    /// Semantic action for non-terminal 'Assume'
+    fn assume(&mut self, arg: &Assume) {
+        before!(self, assume, arg);
+        self.veryl_token(&arg.assume_token);
+        after!(self, assume, arg);
+    }
+
     /// Semantic action for non-terminal 'Bit'
     fn bit(&mut self, arg: &Bit) {
         before!(self, bit, arg);
@@ -405,6 +430,13 @@ pub trait VerylWalker {
         after!(self, r#const, arg);
     }
 
+    /// Semantic action for non-terminal 'Cover'
+    fn cover(&mut self, arg: &Cover) {
+        before!(self, cover, arg);
+        self.veryl_token(&arg.cover_token);
+        after!(self, cover, arg);
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, arg: &Defaul) {
         before!(self, defaul, arg);
@@ -636,6 +668,13 @@ pub trait VerylWalker {
         after!(self, param, arg);
     }
 
+    /// Semantic action for non-terminal 'Property'
+    fn property(&mut self, arg: &Property) {
+        before!(self, property, arg);
+        self.veryl_token(&arg.property_token);
+        after!(self, property, arg);
+    }
+
     /// Semantic action for non-terminal 'Proto'
     fn proto(&mut self, arg: &Proto) {
         before!(self, proto, arg);
@@ -1112,7 +1151,12 @@ pub trait VerylWalker {
         before!(self, identifier_factor, arg);
         self.expression_identifier(&arg.expression_identifier);
         if let Some(ref x) = arg.identifier_factor_opt {
-            self.function_call(&x.function_call);
+            match &*x.identifier_factor_opt_group {
+                IdentifierFactorOptGroup::FunctionCall(x) => self.function_call(&x.function_call),
+                IdentifierFactorOptGroup::StructConstructor(x) => {
+                    self.struct_constructor(&x.struct_constructor)
+                }
+            }
         }
         after!(self, identifier_factor, arg);
     }
@@ -1208,6 +1252,45 @@ pub trait VerylWalker {
         after!(self, array_literal_item, arg);
     }
 
+    /// Semantic action for non-terminal 'StructConstructor'
+    fn struct_constructor(&mut self, arg: &StructConstructor) {
+        before!(self, struct_constructor, arg);
+        self.quote_l_brace(&arg.quote_l_brace);
+        self.struct_constructor_list(&arg.struct_constructor_list);
+        if let Some(ref x) = arg.struct_constructor_opt {
+            self.dot_dot(&x.dot_dot);
+            self.defaul(&x.defaul);
+            self.l_paren(&x.l_paren);
+            self.expression(&x.expression);
+            self.r_paren(&x.r_paren);
+        }
+        self.r_brace(&arg.r_brace);
+        after!(self, struct_constructor, arg);
+    }
+
+    /// Semantic action for non-terminal 'StructConstructorList'
+    fn struct_constructor_list(&mut self, arg: &StructConstructorList) {
+        before!(self, struct_constructor_list, arg);
+        self.struct_constructor_item(&arg.struct_constructor_item);
+        for x in &arg.struct_constructor_list_list {
+            self.comma(&x.comma);
+            self.struct_constructor_item(&x.struct_constructor_item);
+        }
+        if let Some(ref x) = arg.struct_constructor_list_opt {
+            self.comma(&x.comma);
+        }
+        after!(self, struct_constructor_list, arg);
+    }
+
+    /// Semantic action for non-terminal 'StructConstructorItem'
+    fn struct_constructor_item(&mut self, arg: &StructConstructorItem) {
+        before!(self, struct_constructor_item, arg);
+        self.identifier(&arg.identifier);
+        self.colon(&arg.colon);
+        self.expression(&arg.expression);
+        after!(self, struct_constructor_item, arg);
+    }
+
     /// Semantic action for non-terminal 'IfExpression'
     fn if_expression(&mut self, arg: &IfExpression) {
         before!(self, if_expression, arg);
@@ -1512,6 +1595,8 @@ pub trait VerylWalker {
             CastingType::I64(x) => self.i64(&x.i64),
             CastingType::F32(x) => self.f32(&x.f32),
             CastingType::F64(x) => self.f64(&x.f64),
+            CastingType::Bit(x) => self.bit(&x.bit),
+            CastingType::Logic(x) => self.logic(&x.logic),
             CastingType::Clock(x) => self.clock(&x.clock),
             CastingType::ClockPosedge(x) => self.clock_posedge(&x.clock_posedge),
             CastingType::ClockNegedge(x) => self.clock_negedge(&x.clock_negedge),
@@ -1590,6 +1675,7 @@ pub trait VerylWalker {
             Statement::ForStatement(x) => self.for_statement(&x.for_statement),
             Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
             Statement::SwitchStatement(x) => self.switch_statement(&x.switch_statement),
+            Statement::AssertionStatement(x) => self.assertion_statement(&x.assertion_statement),
         };
         after!(self, statement, arg);
     }
@@ -1788,6 +1874,32 @@ pub trait VerylWalker {
         after!(self, switch_condition, arg);
     }
 
+    /// Semantic action for non-terminal 'AssertionKind'
+    fn assertion_kind(&mut self, arg: &AssertionKind) {
+        before!(self, assertion_kind, arg);
+        match arg {
+            AssertionKind::Assert(x) => self.assert(&x.assert),
+            AssertionKind::Assume(x) => self.assume(&x.assume),
+            AssertionKind::Cover(x) => self.cover(&x.cover),
+        };
+        after!(self, assertion_kind, arg);
+    }
+
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        before!(self, assertion_statement, arg);
+        self.assertion_kind(&arg.assertion_kind);
+        self.l_paren(&arg.l_paren);
+        self.expression(&arg.expression);
+        self.r_paren(&arg.r_paren);
+        if let Some(ref x) = arg.assertion_statement_opt {
+            self.r#else(&x.r#else);
+            self.string_literal(&x.string_literal);
+        }
+        self.semicolon(&arg.semicolon);
+        after!(self, assertion_statement, arg);
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, arg: &Attribute) {
         before!(self, attribute, arg);
@@ -2138,6 +2250,22 @@ pub trait VerylWalker {
         after!(self, final_declaration, arg);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        before!(self, assertion_declaration, arg);
+        self.assertion_kind(&arg.assertion_kind);
+        self.property(&arg.property);
+        self.l_paren(&arg.l_paren);
+        self.expression(&arg.expression);
+        self.r_paren(&arg.r_paren);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.r#else(&x.r#else);
+            self.string_literal(&x.string_literal);
+        }
+        self.semicolon(&arg.semicolon);
+        after!(self, assertion_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'InstDeclaration'
     fn inst_declaration(&mut self, arg: &InstDeclaration) {
         before!(self, inst_declaration, arg);
@@ -2831,6 +2959,9 @@ pub trait VerylWalker {
             GenerateItem::ImportDeclaration(x) => self.import_declaration(&x.import_declaration),
             GenerateItem::InitialDeclaration(x) => self.initial_declaration(&x.initial_declaration),
             GenerateItem::FinalDeclaration(x) => self.final_declaration(&x.final_declaration),
+            GenerateItem::AssertionDeclaration(x) => {
+                self.assertion_declaration(&x.assertion_declaration)
+            }
             GenerateItem::UnsafeBlock(x) => self.unsafe_block(&x.unsafe_block),
         };
         after!(self, generate_item, arg);
@@ -3012,4 +3143,9 @@ pub enum HandlerPoint {
 
 pub trait Handler: VerylGrammarTrait {
     fn set_point(&mut self, p: HandlerPoint);
+
+    /// Name shown in timing report
+    fn name(&self) -> &'static str {
+        timing::handler_name(std::any::type_name::<Self>())
+    }
 }
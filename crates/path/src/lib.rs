@@ -1,6 +1,7 @@
 use directories::ProjectDirs;
 #[cfg(not(target_family = "wasm"))]
 use fs4::fs_std::FileExt;
+use ignore::WalkBuilder;
 use log::debug;
 #[cfg(not(target_family = "wasm"))]
 use std::fs::File;
@@ -23,46 +24,66 @@ pub fn cache_path() -> PathBuf {
     project_dir.cache_dir().to_path_buf()
 }
 
+pub fn config_path() -> PathBuf {
+    let project_dir = ProjectDirs::from("org", "veryl-lang", "veryl").unwrap();
+    project_dir.config_dir().to_path_buf()
+}
+
+/// Returns files with the extension under `base_dir` except files of inner projects.
+/// If `gitignore` is true, files ignored by `.gitignore` are skipped.
 pub fn gather_files_with_extension<T: AsRef<Path>>(
     base_dir: T,
     ext: &str,
     symlink: bool,
+    gitignore: bool,
 ) -> Result<Vec<PathBuf>, PathError> {
+    let files: Vec<PathBuf> = if gitignore {
+        WalkBuilder::new(base_dir.as_ref())
+            .standard_filters(false)
+            .git_ignore(true)
+            .git_exclude(true)
+            .parents(true)
+            .require_git(false)
+            .follow_links(symlink)
+            .sort_by_file_name(|x, y| x.cmp(y))
+            .build()
+            .flatten()
+            .filter(|x| x.file_type().is_some_and(|x| x.is_file()))
+            .map(|x| x.into_path())
+            .collect()
+    } else {
+        WalkDir::new(base_dir.as_ref())
+            .follow_links(symlink)
+            .sort_by_file_name()
+            .into_iter()
+            .flatten()
+            .filter(|x| x.file_type().is_file())
+            .map(|x| x.into_path())
+            .collect()
+    };
+
     let mut inner_prj = Vec::new();
-    for entry in WalkDir::new(base_dir.as_ref())
-        .follow_links(symlink)
-        .into_iter()
-        .flatten()
-    {
-        if entry.file_type().is_file() {
-            if let Some(x) = entry.path().file_name() {
-                if x == "Veryl.toml" {
-                    let prj_dir = entry.path().parent().unwrap();
-                    if prj_dir != base_dir.as_ref() {
-                        debug!("Found inner project ({})", prj_dir.to_string_lossy());
-                        inner_prj.push(prj_dir.to_path_buf());
-                    }
+    for file in &files {
+        if let Some(x) = file.file_name() {
+            if x == "Veryl.toml" {
+                let prj_dir = file.parent().unwrap();
+                if prj_dir != base_dir.as_ref() {
+                    debug!("Found inner project ({})", prj_dir.to_string_lossy());
+                    inner_prj.push(prj_dir.to_path_buf());
                 }
             }
         }
     }
 
     let mut ret = Vec::new();
-    for entry in WalkDir::new(base_dir.as_ref())
-        .follow_links(symlink)
-        .sort_by_file_name()
-        .into_iter()
-        .flatten()
-    {
-        if entry.file_type().is_file() {
-            if let Some(x) = entry.path().extension() {
-                if x == ext {
-                    let is_inner = inner_prj.iter().any(|x| entry.path().starts_with(x));
+    for file in files {
+        if let Some(x) = file.extension() {
+            if x == ext {
+                let is_inner = inner_prj.iter().any(|x| file.starts_with(x));
 
-                    if !is_inner {
-                        debug!("Found file ({})", entry.path().to_string_lossy());
-                        ret.push(entry.path().to_path_buf());
-                    }
+                if !is_inner {
+                    debug!("Found file ({})", file.to_string_lossy());
+                    ret.push(file);
                 }
             }
         }
@@ -11,6 +11,7 @@ pub struct SourceMap {
     pub dst_path: PathBuf,
     pub map_path: PathBuf,
     pub src_path_from_map: String,
+    pub src_path_from_dst: String,
     pub map_path_from_dst: String,
     builder: SourceMapBuilder,
     source_map: Option<sourcemap::SourceMap>,
@@ -26,6 +27,11 @@ impl SourceMap {
         } else {
             src_path.to_string_lossy().to_string()
         };
+        let src_path_from_dst = if let Ok(x) = src_path.relative_to(dst_path.parent().unwrap()) {
+            x.as_str().to_owned()
+        } else {
+            src_path.to_string_lossy().to_string()
+        };
         let map_path_from_dst = if let Ok(x) = map_path.relative_to(dst_path.parent().unwrap()) {
             x.as_str().to_owned()
         } else {
@@ -37,6 +43,7 @@ impl SourceMap {
             dst_path,
             map_path,
             src_path_from_map,
+            src_path_from_dst,
             map_path_from_dst,
             builder,
             source_map: None,
@@ -55,6 +62,7 @@ impl SourceMap {
                 let src_path = src_path.to_path_buf();
                 let dst_path = PathBuf::new();
                 let src_path_from_map = String::new();
+                let src_path_from_dst = String::new();
                 let map_path_from_dst = String::new();
                 let builder =
                     SourceMapBuilder::new(Some(&map_path.file_name().unwrap().to_string_lossy()));
@@ -65,6 +73,7 @@ impl SourceMap {
                     dst_path,
                     map_path,
                     src_path_from_map,
+                    src_path_from_dst,
                     map_path_from_dst,
                     builder,
                     source_map,
@@ -112,6 +121,11 @@ impl SourceMap {
         self.source_map = Some(builder.into_sourcemap());
     }
 
+    /// Returns `line directive which maps the next line to `src_line` of the source
+    pub fn get_line_directive(&self, src_line: u32) -> String {
+        format!("`line {} \"{}\" 0", src_line, self.src_path_from_dst)
+    }
+
     pub fn get_link(&self) -> String {
         format!("{}{}", LINK_HEADER, self.map_path_from_dst)
     }
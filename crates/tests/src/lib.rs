@@ -1,6 +1,67 @@
 #[cfg(test)]
 const DEPENDENCY_TESTS: [&str; 2] = ["25_dependency", "68_std"];
 
+/// Checked-in expectations are regenerated instead of compared if `UPDATE_SNAPSHOTS=1`
+#[cfg(test)]
+fn update_snapshots() -> bool {
+    std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|x| x == "1")
+}
+
+/// `Veryl.toml` of test projects which output to `target` without std
+#[cfg(test)]
+fn default_toml(name: &str) -> String {
+    format!(
+        r#"
+[project]
+name = "{name}"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+sourcemap_target = {{type = "none"}}
+target = {{type = "directory", path = "target"}}
+"#
+    )
+}
+
+/// Creates a project at `path` from `Veryl.toml` and sources placed by relative paths
+#[cfg(test)]
+fn create_project(path: &std::path::Path, toml: &str, sources: &[(&str, &str)]) {
+    std::fs::create_dir_all(path.join("src")).unwrap();
+    std::fs::write(path.join("Veryl.toml"), toml).unwrap();
+    for (name, text) in sources {
+        let file = path.join(name);
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(file, text).unwrap();
+    }
+}
+
+/// Creates a project in a temporary directory
+#[cfg(test)]
+fn project(toml: &str, sources: &[(&str, &str)]) -> tempfile::TempDir {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    create_project(temp_dir.path(), toml, sources);
+    temp_dir
+}
+
+/// Loads metadata of the project at `path`, and resets global tables as a new process
+#[cfg(test)]
+fn load(path: &std::path::Path) -> veryl_metadata::Metadata {
+    let metadata = veryl_metadata::Metadata::load(path.join("Veryl.toml")).unwrap();
+    veryl_analyzer::Analyzer::new(&metadata).clear();
+    metadata
+}
+
+/// Builds the project at `path` as a new process
+#[cfg(test)]
+fn build(path: &std::path::Path, opt: veryl::OptBuild) -> Result<(), String> {
+    let mut metadata = load(path);
+    veryl::cmd_build::CmdBuild::new(opt)
+        .exec(&mut metadata, false)
+        .map(|_| ())
+        .map_err(|x| x.to_string())
+}
+
 #[cfg(test)]
 mod parser {
     use std::fs;
@@ -49,19 +110,33 @@ mod analyzer {
         let ret = Parser::parse(&input, &file).unwrap();
         let prj = &metadata.project.name;
         let analyzer = Analyzer::new(&metadata);
-        let errors = analyzer.analyze_pass1(&prj, &input, &file, &ret.veryl);
-        dbg!(&errors);
-        assert!(errors.is_empty());
-
+        let mut errors = analyzer.analyze_pass1(&prj, &input, &file, &ret.veryl);
         Analyzer::analyze_post_pass1();
-
-        let errors = analyzer.analyze_pass2(&prj, &input, &file, &ret.veryl);
+        errors.append(&mut analyzer.analyze_pass2(&prj, &input, &file, &ret.veryl));
+        errors.append(&mut analyzer.analyze_pass3(&prj, &input, &file, &ret.veryl));
         dbg!(&errors);
-        assert!(errors.is_empty());
 
-        let errors = analyzer.analyze_pass3(&prj, &input, &file, &ret.veryl);
-        dbg!(&errors);
-        assert!(errors.is_empty());
+        // testcases which have diagnostics intentionally list their codes in a sidecar file
+        let mut codes: Vec<_> = errors.iter().map(|x| x.error_code().as_str()).collect();
+        codes.sort();
+
+        let expected_path = format!("../../testcases/veryl/{}.expected_errors", name);
+        if crate::update_snapshots() {
+            if codes.is_empty() {
+                let _ = fs::remove_file(&expected_path);
+            } else {
+                fs::write(&expected_path, format!("{}\n", codes.join("\n"))).unwrap();
+            }
+        } else {
+            let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+            let mut expected: Vec<_> = expected
+                .lines()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty())
+                .collect();
+            expected.sort();
+            assert_eq!(expected, codes);
+        }
     }
 
     include!(concat!(env!("OUT_DIR"), "/test.rs"));
@@ -97,6 +172,14 @@ mod formatter {
         formatter.format(&ret.veryl);
 
         assert_eq!(original, formatter.as_str());
+
+        // formatting the formatted code should not change it
+        let formatted = formatter.as_str().to_string();
+        let ret = Parser::parse(&formatted, &file).unwrap();
+        let mut formatter = Formatter::new(&metadata);
+        formatter.format(&ret.veryl);
+
+        assert_eq!(formatted, formatter.as_str());
     }
 
     include!(concat!(env!("OUT_DIR"), "/test.rs"));
@@ -142,12 +225,24 @@ mod emitter {
         let mut emitter = Emitter::new(&metadata, &src_path, &dst_path, &map_path);
         emitter.emit(&prj, &ret.veryl);
 
-        let out_code = emitter.as_str();
+        let out_code = emitter.as_str().to_string();
+        let out_map = String::from_utf8(emitter.source_map().to_bytes().unwrap()).unwrap();
+
+        if crate::update_snapshots() {
+            let out_map = if cfg!(target_os = "windows") {
+                out_map.replace("\\r\\n", "\\n")
+            } else {
+                out_map
+            };
+            fs::write(&dst_path, out_code).unwrap();
+            fs::write(&map_path, out_map).unwrap();
+            return;
+        }
+
         let ref_code = fs::read_to_string(&dst_path).unwrap();
 
         assert_eq!(ref_code, out_code);
 
-        let out_map = String::from_utf8(emitter.source_map().to_bytes().unwrap()).unwrap();
         let ref_map = if cfg!(target_os = "windows") {
             fs::read_to_string(&map_path)
                 .unwrap()
@@ -165,7 +260,7 @@ mod emitter {
 #[cfg(test)]
 mod path {
     use std::path::PathBuf;
-    use veryl_metadata::{Metadata, SourceMapTarget, Target};
+    use veryl_metadata::{Metadata, OutputLayout, SourceMapTarget, Target};
 
     fn path_test(mut metadata: Metadata, src_exp: &str, dst_exp: &str, map_exp: &str) {
         let base = metadata.project_path();
@@ -232,6 +327,25 @@ mod path {
         );
     }
 
+    #[test]
+    fn directory_mirror() {
+        let metadata_path = Metadata::search_from_current().unwrap();
+        let mut metadata = Metadata::load(&metadata_path).unwrap();
+
+        metadata.build.target = Target::Directory {
+            path: "target/mirror".into(),
+        };
+        metadata.build.output_layout = OutputLayout::Mirror;
+        metadata.build.sourcemap_target = SourceMapTarget::Target;
+
+        path_test(
+            metadata,
+            "testcases/veryl/01_number.veryl",
+            "target/mirror/testcases/veryl/01_number.sv",
+            "target/mirror/testcases/veryl/01_number.sv.map",
+        );
+    }
+
     #[test]
     fn directory_directory() {
         let metadata_path = Metadata::search_from_current().unwrap();
@@ -335,3 +449,1611 @@ mod filelist {
         check_order(&paths, "ram.veryl", "05_module_c.veryl");
     }
 }
+
+#[cfg(test)]
+mod determinism {
+    use crate::{default_toml, project};
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::Path;
+    use veryl::OptBuild;
+
+    const SOURCES: [(&str, &str); 4] = [
+        (
+            "src/package_a.veryl",
+            r#"package PackageA {
+    const WIDTH: u32 = 8;
+    struct StructA {
+        a  : logic<WIDTH>,
+        bbb: logic       ,
+    }
+    function FuncA (
+        a: input logic<WIDTH>,
+    ) -> logic<WIDTH> {
+        return a + 1;
+    }
+}
+"#,
+        ),
+        (
+            "src/interface_a.veryl",
+            r#"interface InterfaceA {
+    var a  : PackageA::StructA;
+    var bbb: logic            ;
+    modport mp {
+        a  : output,
+        bbb: input ,
+    }
+}
+"#,
+        ),
+        (
+            "src/module_a.veryl",
+            r#"module ModuleA (
+    i_clk  : input  clock                 ,
+    i_rst  : input  reset                 ,
+    i_data : input  logic<PackageA::WIDTH>,
+    o_data : output logic<PackageA::WIDTH>,
+    if_a   : modport InterfaceA::mp       ,
+) {
+    var r_data: logic<PackageA::WIDTH>;
+    always_ff {
+        if_reset {
+            r_data = 0;
+        } else {
+            r_data = PackageA::FuncA(i_data);
+        }
+    }
+    inst u_b: ModuleB (
+        i_data: r_data,
+        o_data        ,
+    );
+    assign if_a.a.a   = r_data;
+    assign if_a.a.bbb = if_a.bbb;
+}
+"#,
+        ),
+        (
+            "src/module_b.veryl",
+            r#"module ModuleB (
+    i_data: input  logic<PackageA::WIDTH>,
+    o_data: output logic<PackageA::WIDTH>,
+) {
+    assign o_data = i_data;
+}
+"#,
+        ),
+    ];
+
+    fn collect(base: &Path, dir: &Path, ret: &mut BTreeMap<String, String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                collect(base, &path, ret);
+            } else {
+                let key = path
+                    .strip_prefix(base)
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned();
+                ret.insert(key, fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+
+    fn build(path: &Path) -> BTreeMap<String, String> {
+        let opt = OptBuild {
+            no_cache: true,
+            ..Default::default()
+        };
+        crate::build(path, opt).unwrap();
+
+        let mut ret = BTreeMap::new();
+        collect(path, path, &mut ret);
+        ret
+    }
+
+    #[test]
+    fn build_twice() {
+        let temp_dir = project(&default_toml("determinism"), &SOURCES);
+        let path = temp_dir.path();
+
+        let first = build(path);
+        let second = build(path);
+
+        assert!(first.contains_key("determinism.f"));
+        assert_eq!(first.keys().filter(|x| x.ends_with(".sv")).count(), 4);
+        assert_eq!(first, second);
+    }
+}
+
+#[cfg(test)]
+mod build_cache {
+    use crate::{default_toml, load, project};
+    use serde_json::Value;
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use veryl::cache::{BuildCache, CacheKey};
+    use veryl::OptBuild;
+    use veryl_analyzer::Analyzer;
+    use veryl_metadata::Metadata;
+    use veryl_parser::Parser;
+
+    const PACKAGE_A: &str = r#"package PackageA {
+    const WIDTH: u32 = 8;
+}
+"#;
+
+    const MODULE_A: &str = r#"module ModuleA (
+    i_data: input  logic<PackageA::WIDTH>,
+    o_data: output logic<PackageA::WIDTH>,
+) {
+    assign o_data = i_data;
+}
+"#;
+
+    const MODULE_B: &str = r#"module ModuleB (
+    i_data: input  logic,
+    o_data: output logic,
+) {
+    assign o_data = i_data;
+}
+"#;
+
+    fn keys(metadata: &Metadata, sources: &[(&str, &str)]) -> HashMap<PathBuf, CacheKey> {
+        // Global tables are reset for each build
+        Analyzer::new(metadata).clear();
+
+        let prj = &metadata.project.name;
+        let mut contexts = Vec::new();
+        for (path, text) in sources {
+            let parser = Parser::parse(text, path).unwrap();
+            let analyzer = Analyzer::new(metadata);
+            analyzer.analyze_pass1(prj, text, path, &parser.veryl);
+            contexts.push((path, text, parser, analyzer));
+        }
+        Analyzer::analyze_post_pass1();
+        for (path, text, parser, analyzer) in &contexts {
+            analyzer.analyze_pass2(prj, text, path, &parser.veryl);
+            analyzer.analyze_pass3(prj, text, path, &parser.veryl);
+        }
+
+        let sources: Vec<_> = sources
+            .iter()
+            .map(|(path, text)| (Path::new(path), *text))
+            .collect();
+        BuildCache::keys(metadata, &sources)
+    }
+
+    #[test]
+    fn cache_keys() {
+        let temp_dir = project(&default_toml("build_cache"), &[]);
+        let mut metadata = load(temp_dir.path());
+
+        let package_a = Path::new("package_a.veryl");
+        let module_a = Path::new("module_a.veryl");
+        let module_b = Path::new("module_b.veryl");
+
+        let sources = [
+            ("package_a.veryl", PACKAGE_A),
+            ("module_a.veryl", MODULE_A),
+            ("module_b.veryl", MODULE_B),
+        ];
+        let base = keys(&metadata, &sources);
+
+        // identical texts hit the cache
+        assert_eq!(keys(&metadata, &sources), base);
+
+        // the dependent through the type DAG is invalidated
+        let package_a_changed = PACKAGE_A.replace("8", "16");
+        let changed = keys(
+            &metadata,
+            &[
+                ("package_a.veryl", &package_a_changed),
+                ("module_a.veryl", MODULE_A),
+                ("module_b.veryl", MODULE_B),
+            ],
+        );
+        assert_ne!(changed[package_a], base[package_a]);
+        assert_ne!(changed[module_a], base[module_a]);
+        assert_eq!(changed[module_b], base[module_b]);
+
+        // build option change misses the cache
+        metadata.build.strip_comments = !metadata.build.strip_comments;
+        let changed = keys(&metadata, &sources);
+        assert_ne!(changed[package_a], base[package_a]);
+        assert_ne!(changed[module_a], base[module_a]);
+        assert_ne!(changed[module_b], base[module_b]);
+    }
+
+    /// Builds the project at `path`, and returns emitted files and source files which are parsed
+    fn build(path: &Path, no_cache: bool) -> (BTreeMap<String, String>, BTreeSet<String>) {
+        let timings = tempfile::NamedTempFile::new().unwrap();
+        let opt = OptBuild {
+            no_cache,
+            timings_json: Some(timings.path().to_path_buf()),
+            ..Default::default()
+        };
+        crate::build(path, opt).unwrap();
+
+        let mut outputs = BTreeMap::new();
+        for entry in fs::read_dir(path.join("target")).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|x| x == "sv") {
+                let name = path.file_name().unwrap().to_string_lossy().into_owned();
+                outputs.insert(name, fs::read_to_string(&path).unwrap());
+            }
+        }
+
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(timings.path()).unwrap()).unwrap();
+        let mut parsed = BTreeSet::new();
+        for file in json["files"].as_array().unwrap() {
+            let phases = file["phases"].as_array().unwrap();
+            if phases.iter().any(|x| x["name"] == "parse") {
+                parsed.insert(file["path"].as_str().unwrap().to_string());
+            }
+        }
+
+        (outputs, parsed)
+    }
+
+    #[test]
+    fn build_restores_unchanged_files() {
+        let temp_dir = project(
+            &default_toml("build_cache"),
+            &[
+                ("src/package_a.veryl", PACKAGE_A),
+                ("src/module_a.veryl", MODULE_A),
+                ("src/module_b.veryl", MODULE_B),
+            ],
+        );
+        let path = temp_dir.path();
+        let all: BTreeSet<_> = [
+            "src/module_a.veryl",
+            "src/module_b.veryl",
+            "src/package_a.veryl",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let (base, parsed) = build(path, false);
+        assert_eq!(base.len(), 3);
+        assert_eq!(parsed, all);
+
+        // all files are restored from the cache
+        let (outputs, parsed) = build(path, false);
+        assert_eq!(outputs, base);
+        assert!(parsed.is_empty());
+
+        // the changed file and its dependent are analyzed again
+        fs::write(
+            path.join("src/package_a.veryl"),
+            PACKAGE_A.replace("8", "16"),
+        )
+        .unwrap();
+        let (outputs, parsed) = build(path, false);
+        assert_eq!(
+            parsed,
+            BTreeSet::from([
+                "src/module_a.veryl".to_string(),
+                "src/package_a.veryl".to_string()
+            ])
+        );
+        assert_ne!(outputs, base);
+        assert_eq!(build(path, true).0, outputs);
+
+        // restored files are kept in the cache for the next build
+        let (restored, parsed) = build(path, false);
+        assert_eq!(restored, outputs);
+        assert!(parsed.is_empty());
+
+        // a file which has warnings is analyzed again to report them
+        let warned = MODULE_B.replace(
+            "assign o_data = i_data;",
+            "let a: logic = 1;\n    assign o_data = i_data;",
+        );
+        fs::write(path.join("src/module_b.veryl"), warned).unwrap();
+        build(path, false);
+        let (_, parsed) = build(path, false);
+        assert_eq!(parsed, BTreeSet::from(["src/module_b.veryl".to_string()]));
+    }
+}
+
+#[cfg(test)]
+mod header {
+    use crate::{default_toml, load, project};
+    use veryl::header::{self, PackageHeader};
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const SOURCE: &str = r#"#[export_header]
+package RegA {
+    enum Mode: logic<2> {
+        Idle = 0,
+        Run = 1,
+        Stop = 3,
+    }
+    struct CtrlReg {
+        enable: logic,
+        mode: Mode,
+        r#type: logic<3>,
+        width: logic<4>,
+        _reserved: logic<6>,
+    }
+}
+"#;
+
+    const NON_STATIC: &str = r#"#[export_header]
+package RegB::<W: const> {
+    struct DataReg {
+        data: logic<W>,
+    }
+}
+"#;
+
+    fn collect(source: &str) -> Result<Vec<PackageHeader>, String> {
+        let temp_dir = project(&default_toml("header"), &[]);
+        let path = temp_dir.path();
+        let metadata = load(path);
+
+        let src = path.join("src").join("reg.veryl");
+        let prj = &metadata.project.name;
+        let parser = Parser::parse(source, &src).unwrap();
+        let analyzer = Analyzer::new(&metadata);
+        analyzer.analyze_pass1(prj, source, &src, &parser.veryl);
+        Analyzer::analyze_post_pass1();
+        analyzer.analyze_pass2(prj, source, &src, &parser.veryl);
+        analyzer.analyze_pass3(prj, source, &src, &parser.veryl);
+
+        header::collect(&metadata).map_err(|x| x.to_string())
+    }
+
+    #[test]
+    fn register_header() {
+        let headers = collect(SOURCE).unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].file_name(), "header_reg_a");
+
+        let c = headers[0].to_c();
+        // the first member is placed at MSB
+        for (name, offset, width) in [
+            ("ENABLE", 15, 1),
+            ("MODE", 13, 2),
+            ("TYPE", 10, 3),
+            ("WIDTH", 6, 4),
+            ("RESERVED", 0, 6),
+        ] {
+            let prefix = format!("#define HEADER_REG_A_CTRL_REG_{name}");
+            assert!(c.contains(&format!("{prefix}_OFFSET {offset}\n")), "{name}");
+            assert!(c.contains(&format!("{prefix}_WIDTH {width}\n")), "{name}");
+        }
+        assert!(c.contains("#define HEADER_REG_A_CTRL_REG_WIDTH 16\n"));
+        assert!(c.contains("#define HEADER_REG_A_CTRL_REG_MODE_MASK 0x3u\n"));
+        assert!(c.contains("#define HEADER_REG_A_MODE_STOP 3u\n"));
+        assert!(!c.contains("__"));
+
+        let rust = headers[0].to_rust();
+        assert!(rust.contains("pub struct CtrlReg(pub u16);"));
+        assert!(rust.contains("pub const WIDTH: usize = 16;"));
+        assert!(rust.contains("pub const ENABLE_OFFSET: usize = 15;"));
+        assert!(rust.contains("pub const WIDTH__OFFSET: usize = 6;"));
+        assert!(rust.contains("pub const WIDTH__WIDTH: usize = 4;"));
+        assert!(rust.contains("pub fn r#type(&self) -> u8 {"));
+        assert!(rust.contains("pub fn set_type(&mut self, value: u8) {"));
+        assert!(rust.contains("pub fn mode(&self) -> Mode {"));
+        assert!(rust.contains("pub const STOP: Self = Self(3);"));
+    }
+
+    #[test]
+    fn non_static_width() {
+        let err = collect(NON_STATIC).unwrap_err();
+        assert_eq!(
+            err,
+            "width of member \"data\" of \"DataReg\" is not statically known (src/reg.veryl:4:9)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod hierarchy {
+    use crate::{default_toml, load, project};
+    use veryl::hierarchy::{self, InstanceHierarchy, InstanceNode};
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const SOURCE: &str = r#"module Top {
+    inst u_a: ModuleA [4];
+    inst u_b: ModuleB #(
+        WIDTH: 8,
+    );
+}
+
+module ModuleA {
+    inst u_c: ModuleC;
+}
+
+module ModuleB #(
+    param WIDTH: u32 = 1,
+) {}
+
+module ModuleC {}
+
+module Other {
+    inst u_c: ModuleC;
+}
+"#;
+
+    const CYCLIC: &str = r#"module ModuleX {
+    inst u_y: ModuleY;
+}
+
+module ModuleY {
+    inst u_x: ModuleX;
+}
+"#;
+
+    fn build(source: &str, tops: &[&str]) -> Result<Vec<InstanceNode>, String> {
+        let temp_dir = project(&default_toml("hierarchy"), &[]);
+        let path = temp_dir.path();
+        let metadata = load(path);
+
+        let src = path.join("src").join("top.veryl");
+        let prj = &metadata.project.name;
+        let parser = Parser::parse(source, &src).unwrap();
+        let analyzer = Analyzer::new(&metadata);
+        analyzer.analyze_pass1(prj, source, &src, &parser.veryl);
+        Analyzer::analyze_post_pass1();
+        analyzer.analyze_pass2(prj, source, &src, &parser.veryl);
+
+        let mut hierarchy = InstanceHierarchy::new(&metadata.project_path());
+        hierarchy.collect(&parser.veryl, true);
+        let tops: Vec<_> = tops.iter().map(|x| x.to_string()).collect();
+        hierarchy.build(&tops).map_err(|x| x.to_string())
+    }
+
+    #[test]
+    fn text_tree() {
+        let nodes = build(SOURCE, &[]).unwrap();
+        let expected = r#"Top (src/top.veryl:1:8)
+    u_a: ModuleA [4] (src/top.veryl:2:10)
+        u_c: ModuleC (src/top.veryl:9:10)
+    u_b: ModuleB #(WIDTH: 8) (src/top.veryl:3:10)
+Other (src/top.veryl:18:8)
+    u_c: ModuleC (src/top.veryl:19:10)
+"#;
+        assert_eq!(hierarchy::dump_tree(&nodes), expected);
+    }
+
+    #[test]
+    fn json_tree() {
+        let nodes = build(SOURCE, &[]).unwrap();
+        let json = serde_json::to_value(&nodes).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+
+        let top = &json[0];
+        assert_eq!(top["module"], "Top");
+        assert_eq!(top["source"], "src/top.veryl:1:8");
+
+        // instance array is collapsed to its range
+        let u_a = &top["children"][0];
+        assert_eq!(u_a["name"], "u_a");
+        assert_eq!(u_a["array"], serde_json::json!(["4"]));
+        assert_eq!(u_a["children"].as_array().unwrap().len(), 1);
+        assert_eq!(u_a["children"][0]["module"], "ModuleC");
+
+        let u_b = &top["children"][1];
+        assert_eq!(
+            u_b["parameters"],
+            serde_json::json!([{"name": "WIDTH", "value": "8"}])
+        );
+        assert!(u_b.get("children").is_none());
+    }
+
+    #[test]
+    fn select_top() {
+        let nodes = build(SOURCE, &["Other"]).unwrap();
+        let expected = r#"Other (src/top.veryl:18:8)
+    u_c: ModuleC (src/top.veryl:19:10)
+"#;
+        assert_eq!(hierarchy::dump_tree(&nodes), expected);
+
+        let err = build(SOURCE, &["Unknown"]).unwrap_err();
+        assert_eq!(err, "top module \"Unknown\" is not found");
+    }
+
+    #[test]
+    fn cyclic_instance() {
+        let err = build(CYCLIC, &["ModuleX"]).unwrap_err();
+        assert_eq!(
+            err,
+            "recursive instantiation is found (ModuleX -> ModuleY -> ModuleX) at src/top.veryl:6:10"
+        );
+    }
+}
+
+#[cfg(test)]
+mod workspace {
+    use crate::{create_project, load};
+    use std::fs;
+    use std::path::Path;
+    use veryl::cmd_build::CmdBuild;
+    use veryl::OptBuild;
+    use veryl_metadata::Workspace;
+
+    const MEMBERS: [(&str, &str, &str); 3] = [
+        (
+            "common",
+            r#"
+[project]
+name = "common"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+sourcemap_target = {type = "none"}
+target = {type = "directory", path = "target"}
+"#,
+            r#"pub package CommonPkg {
+    const WIDTH: u32 = 8;
+}
+"#,
+        ),
+        (
+            "chip_a",
+            r#"
+[project]
+name = "chip_a"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+sourcemap_target = {type = "none"}
+filelist_type = "relative"
+
+[dependencies]
+"../common" = {version = "0.1.0", name = "lib"}
+"#,
+            r#"module ChipA (
+    o: output logic<lib::CommonPkg::WIDTH>,
+) {
+    assign o = 0;
+}
+"#,
+        ),
+        (
+            "chip_b",
+            r#"
+[project]
+name = "chip_b"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+sourcemap_target = {type = "none"}
+omit_project_prefix = true
+"#,
+            r#"module ChipB (
+    o: output logic,
+) {
+    assign o = 0;
+}
+"#,
+        ),
+    ];
+
+    fn create_workspace(path: &Path) {
+        fs::write(
+            path.join("Veryl.toml"),
+            "[workspace]\nmembers = [\"common\", \"chip_a\", \"chip_b\"]\n",
+        )
+        .unwrap();
+        for (name, toml, source) in MEMBERS {
+            create_project(&path.join(name), toml, &[("src/top.veryl", source)]);
+        }
+    }
+
+    fn build(path: &Path, project: Option<&str>) {
+        let workspace = Workspace::load(path.join("Veryl.toml")).unwrap();
+        load(&path.join("common"));
+
+        let build = CmdBuild::new(OptBuild {
+            project: project.map(|x| x.to_string()),
+            no_cache: true,
+            ..Default::default()
+        });
+        build.exec_workspace(&workspace).unwrap();
+    }
+
+    #[test]
+    fn build_members() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path();
+        create_workspace(path);
+
+        build(path, None);
+
+        let common = path.join("common").join("target").join("top.sv");
+        assert!(common.exists());
+
+        let chip_a = fs::read_to_string(path.join("chip_a").join("src").join("top.sv")).unwrap();
+        assert!(chip_a.contains("module chip_a_ChipA"));
+        assert!(chip_a.contains("common_CommonPkg::WIDTH"));
+        let filelist = fs::read_to_string(path.join("chip_a").join("chip_a.f")).unwrap();
+        let filelist: Vec<_> = filelist.lines().collect();
+        assert_eq!(filelist, ["../common/target/top.sv", "src/top.sv"]);
+
+        // Build options of chip_b are not applied to chip_a
+        let chip_b = fs::read_to_string(path.join("chip_b").join("src").join("top.sv")).unwrap();
+        assert!(chip_b.contains("module ChipB"));
+    }
+
+    #[test]
+    fn build_selected_member() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path();
+        create_workspace(path);
+
+        build(path, Some("chip_a"));
+
+        assert!(path.join("common").join("common.f").exists());
+        assert!(path.join("chip_a").join("chip_a.f").exists());
+        assert!(!path.join("chip_b").join("chip_b.f").exists());
+    }
+}
+
+#[cfg(test)]
+mod constraints {
+    use crate::project;
+    use std::fs;
+    use std::path::Path;
+    use veryl::{ConstraintFormat, OptBuild};
+
+    const TOML: &str = r#"
+[project]
+name = "constraints"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+sourcemap_target = {type = "none"}
+omit_project_prefix = true
+target = {type = "directory", path = "target"}
+"#;
+
+    const SOURCE: &str = r#"module Top (
+    #[period("5ns")]
+    i_clk_a: input  `a clock,
+    i_clk_b: input  `b clock,
+    i_dat  : input  `a logic,
+    o_dat  : output `b logic,
+) {
+    unsafe (cdc) {
+        assign o_dat = i_dat;
+    }
+}
+"#;
+
+    fn build(path: &Path) {
+        let opt = OptBuild {
+            no_cache: true,
+            constraints: vec![ConstraintFormat::Sdc, ConstraintFormat::Json],
+            ..Default::default()
+        };
+        crate::build(path, opt).unwrap();
+    }
+
+    #[test]
+    fn build_constraints() {
+        let temp_dir = project(TOML, &[("src/top.veryl", SOURCE)]);
+        let path = temp_dir.path();
+
+        build(path);
+
+        let sdc_path = path.join("constraints").join("Top.sdc");
+        let sdc = fs::read_to_string(&sdc_path).unwrap();
+        assert!(sdc.contains("create_clock -name i_clk_a -period 5.000 [get_ports i_clk_a]"));
+        assert!(sdc.contains("# create_clock -name i_clk_b -period <period> [get_ports i_clk_b]"));
+        assert!(sdc.contains("set_false_path -from [get_clocks i_clk_a] -to [get_clocks i_clk_b]"));
+
+        let json = fs::read_to_string(path.join("constraints").join("Top.json")).unwrap();
+        assert!(json.contains("\"period\": 5.0"));
+        assert!(json.contains("\"from_clocks\": [\n        \"i_clk_a\"\n      ]"));
+
+        // Hand-edited parts outside of the generated block are kept
+        let edited = format!("set_input_delay 1 [all_inputs]\n{sdc}# hand-edited\n");
+        fs::write(&sdc_path, edited).unwrap();
+        let source = SOURCE.replace("5ns", "10ns");
+        fs::write(path.join("src").join("top.veryl"), source).unwrap();
+
+        build(path);
+
+        let sdc = fs::read_to_string(&sdc_path).unwrap();
+        assert!(sdc.starts_with("set_input_delay 1 [all_inputs]\n"));
+        assert!(sdc.ends_with("# hand-edited\n"));
+        assert!(sdc.contains("-period 10.000"));
+        assert!(!sdc.contains("-period 5.000"));
+    }
+}
+
+#[cfg(test)]
+mod profile {
+    use crate::project;
+    use std::fs;
+    use std::path::Path;
+    use veryl::OptBuild;
+
+    const TOML: &str = r#"
+[project]
+name = "profile"
+version = "0.1.0"
+
+[build]
+exclude_std = true
+sourcemap_target = {type = "none"}
+filelist_type = "relative"
+target = {type = "directory", path = "target"}
+
+[build.profiles.synth]
+reset_type = "sync_high"
+defines = ["FPGA"]
+"#;
+
+    const SOURCE: &str = r#"module Top (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    o_dat: output logic,
+) {
+    always_ff {
+        if_reset {
+            o_dat = 0;
+        } else {
+            o_dat = 1;
+        }
+    }
+}
+"#;
+
+    fn build(path: &Path, profile: Option<&str>) -> (String, String) {
+        let opt = OptBuild {
+            profile: profile.map(|x| x.to_string()),
+            ..Default::default()
+        };
+        crate::build(path, opt).unwrap();
+
+        let output = fs::read_to_string(path.join("target").join("top.sv")).unwrap();
+        let filelist = fs::read_to_string(path.join("profile.f")).unwrap();
+        (output, filelist)
+    }
+
+    #[test]
+    fn build_profile() {
+        let temp_dir = project(TOML, &[("src/top.veryl", SOURCE)]);
+        let path = temp_dir.path();
+
+        let (output, filelist) = build(path, None);
+        assert!(output.contains("negedge i_rst"));
+        assert_eq!(filelist, "target/top.sv\n");
+
+        // the cached output of the default profile is not reused
+        let (output, filelist) = build(path, Some("synth"));
+        assert!(!output.contains("negedge i_rst"));
+        assert!(output.contains("if (i_rst)"));
+        assert_eq!(filelist, "+define+FPGA\ntarget/top.sv\n");
+    }
+}
+
+#[cfg(test)]
+mod timings {
+    use crate::{build, default_toml, project};
+    use std::fs;
+    use veryl::OptBuild;
+
+    const SOURCE: &str = r#"module Top (
+    i_clk: input  clock,
+    i_dat: input  logic,
+    o_dat: output logic,
+) {
+    assign o_dat = i_dat;
+}
+"#;
+
+    #[test]
+    fn build_timings() {
+        let temp_dir = project(&default_toml("timings"), &[("src/top.veryl", SOURCE)]);
+        let path = temp_dir.path();
+
+        let json_path = path.join("timings.json");
+        let opt = OptBuild {
+            no_cache: true,
+            timings_json: Some(json_path.clone()),
+            ..Default::default()
+        };
+        build(path, opt).unwrap();
+
+        let json = fs::read_to_string(&json_path).unwrap();
+        for phase in [
+            "parse", "pass1", "resolve", "pass2", "pass3", "emit", "write",
+        ] {
+            assert!(json.contains(&format!("\"name\": \"{phase}\"")), "{phase}");
+        }
+        assert!(json.contains("\"name\": \"CreateSymbolTable\""));
+        assert!(json.contains("\"name\": \"CreateReference\""));
+        assert!(json.contains("\"name\": \"align\""));
+        assert!(json.contains("\"path\": \"src/top.veryl\""));
+    }
+}
+
+#[cfg(test)]
+mod check {
+    use crate::{default_toml, load, project};
+    use serde_json::Value;
+    use std::path::Path;
+    use veryl::cmd_check::{self, CheckError, CmdCheck};
+    use veryl::{DiagnosticFormat, OptCheck};
+
+    const SOURCE: &str = r#"module Top {
+    let a: logic = 1;
+    let b: logic = 1;
+}
+"#;
+
+    const DIAGNOSTICS_SOURCE: &str = r#"module Top (
+    o: output logic,
+) {
+    let a: logic = 1;
+    assign o = 0;
+    assign o = 1;
+}
+"#;
+
+    /// Returns whether the check passed
+    fn check(path: &Path, max_warnings: Option<usize>) -> bool {
+        let mut metadata = load(path);
+        let check = CmdCheck::new(OptCheck {
+            max_warnings,
+            ..Default::default()
+        });
+        check.exec(&mut metadata).unwrap_or(false)
+    }
+
+    fn collect(path: &Path, format: DiagnosticFormat) -> CheckError {
+        let mut metadata = load(path);
+        let check = CmdCheck::new(OptCheck {
+            format,
+            ..Default::default()
+        });
+        check.collect(&mut metadata).unwrap()
+    }
+
+    #[test]
+    fn max_warnings() {
+        let temp_dir = project(&default_toml("check"), &[("src/top.veryl", SOURCE)]);
+        let path = temp_dir.path();
+
+        // two unused_variable warnings
+        assert!(!check(path, None));
+        assert!(!check(path, Some(1)));
+        assert!(check(path, Some(2)));
+        assert!(check(path, Some(3)));
+    }
+
+    #[test]
+    fn json() {
+        let temp_dir = project(
+            &default_toml("check"),
+            &[("src/top.veryl", DIAGNOSTICS_SOURCE)],
+        );
+        let check_error = collect(temp_dir.path(), DiagnosticFormat::Json);
+        let text = cmd_check::render_json(&check_error.related).unwrap();
+        let json: Value = serde_json::from_str(&text).unwrap();
+
+        let diagnostics = json["diagnostics"].as_array().unwrap();
+        let find = |code: &str| {
+            diagnostics
+                .iter()
+                .find(|x| x["code"] == code)
+                .unwrap_or_else(|| panic!("{code} is not found in {text}"))
+        };
+
+        let error = find("multiple_assignment");
+        assert_eq!(error["severity"], "error");
+        assert_eq!(error["default_severity"], "error");
+        assert!(error["location"]["path"]
+            .as_str()
+            .unwrap()
+            .ends_with("top.veryl"));
+        assert_eq!(error["location"]["start_line"], 2);
+        assert_eq!(error["location"]["start_column"], 5);
+        let related: Vec<_> = error["related"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x["start_line"].as_u64().unwrap())
+            .collect();
+        assert_eq!(related, vec![5, 6]);
+
+        let warning = find("unused_variable");
+        assert_eq!(warning["severity"], "warning");
+        assert_eq!(warning["default_severity"], "warning");
+        assert_eq!(warning["location"]["start_line"], 4);
+        assert_eq!(warning["location"]["start_column"], 9);
+    }
+
+    #[test]
+    fn sarif() {
+        let temp_dir = project(
+            &default_toml("check"),
+            &[("src/top.veryl", DIAGNOSTICS_SOURCE)],
+        );
+        let check_error = collect(temp_dir.path(), DiagnosticFormat::Sarif);
+        let text = cmd_check::render_sarif(&check_error.related).unwrap();
+        let sarif: Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "veryl");
+
+        let rules: Vec<_> = run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x["id"].as_str().unwrap())
+            .collect();
+        assert!(rules.contains(&"multiple_assignment"));
+        assert!(rules.contains(&"unused_variable"));
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), check_error.related.len());
+        let find = |code: &str| {
+            results
+                .iter()
+                .find(|x| x["ruleId"] == code)
+                .unwrap_or_else(|| panic!("{code} is not found in {text}"))
+        };
+
+        let error = find("multiple_assignment");
+        assert_eq!(error["level"], "error");
+        let location = &error["locations"][0]["physicalLocation"];
+        assert!(location["artifactLocation"]["uri"]
+            .as_str()
+            .unwrap()
+            .ends_with("src/top.veryl"));
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 5);
+        let related: Vec<_> = error["relatedLocations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| {
+                x["physicalLocation"]["region"]["startLine"]
+                    .as_u64()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(related, vec![5, 6]);
+
+        let warning = find("unused_variable");
+        assert_eq!(warning["level"], "warning");
+        let location = &warning["locations"][0]["physicalLocation"];
+        assert_eq!(location["region"]["startLine"], 4);
+        assert_eq!(location["region"]["startColumn"], 9);
+    }
+}
+
+#[cfg(test)]
+mod questa {
+    use crate::{load, project};
+    use veryl::runner::Questa;
+    use veryl_metadata::Metadata;
+    use veryl_parser::resource_table;
+
+    const TOML: &str = r#"
+[project]
+name = "prj"
+version = "0.1.0"
+
+[build]
+target = {type = "directory", path = "target"}
+
+[test]
+simulator = "questa"
+
+[test.questa]
+compile_args = ["-work", "lib"]
+simulate_args = ["-voptargs=+acc"]
+"#;
+
+    fn metadata() -> (tempfile::TempDir, Metadata) {
+        let temp_dir = project(TOML, &[]);
+        let metadata = load(temp_dir.path());
+        (temp_dir, metadata)
+    }
+
+    #[test]
+    fn compile_args() {
+        let (_temp_dir, metadata) = metadata();
+        let test = resource_table::insert_str("test1");
+        let filelist = metadata.filelist_path().to_string_lossy().to_string();
+
+        let args = Questa::compile_args(&metadata, test, false);
+        assert_eq!(
+            args,
+            vec![
+                "-sv",
+                "-f",
+                &filelist,
+                "+define+__veryl_test_prj_test1__",
+                "-work",
+                "lib",
+            ]
+        );
+
+        let args = Questa::compile_args(&metadata, test, true);
+        assert_eq!(
+            args,
+            vec![
+                "-sv",
+                "-f",
+                &filelist,
+                "+define+__veryl_test_prj_test1__",
+                "+define+__veryl_wavedump_prj_test1__",
+                "-work",
+                "lib",
+            ]
+        );
+    }
+
+    #[test]
+    fn simulate_args() {
+        let (_temp_dir, metadata) = metadata();
+        let test = resource_table::insert_str("test1");
+
+        let args = Questa::simulate_args(&metadata, test, false);
+        assert_eq!(
+            args,
+            vec![
+                "-batch",
+                "test1",
+                "-do",
+                "run -all; quit -f",
+                "-voptargs=+acc"
+            ]
+        );
+
+        let args = Questa::simulate_args(&metadata, test, true);
+        assert_eq!(
+            args,
+            vec![
+                "-batch",
+                "test1",
+                "__veryl_wavedump",
+                "-do",
+                "run -all; quit -f",
+                "-voptargs=+acc",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_result() {
+        let passed = [
+            "# Loading work.test1",
+            "# ** Info: test passed",
+            "# ** Warning: (vsim-3015) port size mismatch",
+            "** Warning: top.sv(3): (vlog-2623) undefined variable",
+        ];
+        let mut questa = Questa::new();
+        for line in passed {
+            questa.parse_line(line);
+        }
+        assert!(questa.success());
+
+        for line in [
+            "# ** Error: assertion failed",
+            "# ** Fatal: simulation aborted",
+            "** Error: top.sv(5): (vlog-13069) syntax error",
+            "** Error (suppressible): top.sv(7): (vlog-2388) already declared",
+        ] {
+            let mut questa = Questa::new();
+            questa.parse_line(line);
+            assert!(!questa.success(), "{line}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod hook {
+    use crate::{create_project, default_toml, project};
+    use std::fs;
+    use std::path::Path;
+    use veryl::OptBuild;
+
+    const SOURCE: &str = r#"module Top {
+    var a: logic;
+    assign a = 1;
+}
+"#;
+
+    const ERROR_SOURCE: &str = r#"module Top {
+    var a: logic;
+    assign a = b;
+}
+"#;
+
+    fn toml(pre_build: &str) -> String {
+        let hooks = format!(
+            r#"
+[build.hooks]
+pre_build = ["{pre_build}"]
+post_build = ["echo done > post_build.txt"]
+"#
+        );
+        default_toml("hook") + &hooks
+    }
+
+    fn build(path: &Path) -> bool {
+        let opt = OptBuild {
+            no_cache: true,
+            ..Default::default()
+        };
+        crate::build(path, opt).is_ok()
+    }
+
+    #[test]
+    fn succeeded_build() {
+        let temp_dir = project(
+            &toml("echo done > pre_build.txt"),
+            &[("src/top.veryl", SOURCE)],
+        );
+        let path = temp_dir.path();
+
+        assert!(build(path));
+        assert!(path.join("pre_build.txt").exists());
+        assert!(path.join("target").join("top.sv").exists());
+        assert!(path.join("post_build.txt").exists());
+    }
+
+    #[test]
+    fn failed_pre_build() {
+        let temp_dir = project(&toml("exit 1"), &[("src/top.veryl", SOURCE)]);
+        let path = temp_dir.path();
+
+        assert!(!build(path));
+        assert!(!path.join("target").join("top.sv").exists());
+        assert!(!path.join("post_build.txt").exists());
+    }
+
+    #[test]
+    fn failed_analysis() {
+        let temp_dir = project(
+            &toml("echo done > pre_build.txt"),
+            &[("src/top.veryl", ERROR_SOURCE)],
+        );
+        let path = temp_dir.path();
+
+        assert!(!build(path));
+        assert!(path.join("pre_build.txt").exists());
+        assert!(!path.join("post_build.txt").exists());
+    }
+
+    // `echo` of cmd keeps quotes and variables are referred as `%VAR%`
+    #[cfg(unix)]
+    #[test]
+    fn path_with_space() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("my project");
+        let toml = default_toml("hook")
+            + r#"
+[build.hooks]
+post_emit = ["echo {output} > {target_dir}/post_emit.txt"]
+post_build = ["echo $VERYL_PROJECT > {target_dir}/post_build.txt"]
+"#;
+        create_project(&path, &toml, &[("src/top.veryl", SOURCE)]);
+
+        assert!(build(&path));
+
+        let target = path.join("target");
+        let output = fs::read_to_string(target.join("post_emit.txt")).unwrap();
+        assert_eq!(output.trim(), target.join("top.sv").to_string_lossy());
+        let project = fs::read_to_string(target.join("post_build.txt")).unwrap();
+        assert_eq!(project.trim(), "hook");
+    }
+}
+
+#[cfg(test)]
+mod template {
+    use crate::load;
+    use std::fs;
+    use std::path::Path;
+    use veryl::cmd_check::CmdCheck;
+    use veryl::template::{Template, TemplateOrigin};
+    use veryl::{DiagnosticFormat, OptCheck};
+
+    #[test]
+    fn builtin() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("block");
+
+        let template = Template::load("soc-block").unwrap();
+        assert!(matches!(template.origin, TemplateOrigin::Builtin));
+        template.generate(&path, "block").unwrap();
+
+        assert!(path.join("src").join("block.veryl").exists());
+        assert!(path.join("src").join("block_pkg.veryl").exists());
+        assert!(path.join("src").join("block_tb.veryl").exists());
+
+        // the generated project can be analyzed without errors
+        let mut metadata = load(&path);
+        assert_eq!(metadata.project.name, "block");
+
+        // errors are collected without stopping
+        let check = CmdCheck::new(OptCheck {
+            format: DiagnosticFormat::Json,
+            ..Default::default()
+        });
+        let check_error = check.collect(&mut metadata).unwrap();
+        let project_path = metadata.project_path();
+        let errors: Vec<_> = check_error
+            .related
+            .iter()
+            .filter(|x| {
+                x.locations()
+                    .iter()
+                    .any(|x| Path::new(&x.path).starts_with(&project_path))
+            })
+            .collect();
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+
+    #[test]
+    fn local() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let template_path = temp_dir.path().join("my_template");
+        fs::create_dir_all(template_path.join("src")).unwrap();
+        fs::write(
+            template_path.join("template.toml"),
+            "description = \"my template\"\n",
+        )
+        .unwrap();
+        fs::write(
+            template_path.join("src").join("{{project}}_top.veryl"),
+            "module {{project}}_top {}\n",
+        )
+        .unwrap();
+
+        let template = Template::load(&template_path.to_string_lossy()).unwrap();
+        assert_eq!(template.name, "my_template");
+        assert_eq!(template.description, "my template");
+
+        let path = temp_dir.path().join("prj");
+        template.generate(&path, "prj").unwrap();
+        let text = fs::read_to_string(path.join("src").join("prj_top.veryl")).unwrap();
+        assert_eq!(text, "module prj_top {}\n");
+    }
+
+    #[test]
+    fn unknown_template() {
+        assert!(Template::load("__unknown_template__").is_err());
+    }
+
+    #[test]
+    fn existing_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path();
+        fs::create_dir(path.join("src")).unwrap();
+        fs::write(path.join("src").join("block.veryl"), "").unwrap();
+
+        let template = Template::load("soc-block").unwrap();
+        assert!(template.generate(path, "block").is_err());
+
+        // nothing is written if any file exists
+        assert!(!path.join("Veryl.toml").exists());
+        assert!(!path.join("src").join("block_pkg.veryl").exists());
+        assert_eq!(
+            fs::read_to_string(path.join("src").join("block.veryl")).unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn undefined_placeholder() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let template_path = temp_dir.path().join("my_template");
+        fs::create_dir(&template_path).unwrap();
+        fs::write(template_path.join("template.toml"), "").unwrap();
+        fs::write(template_path.join("Veryl.toml"), "name = \"{{author}}\"\n").unwrap();
+
+        let template = Template::load(&template_path.to_string_lossy()).unwrap();
+        let path = temp_dir.path().join("prj");
+        assert!(template.generate(&path, "prj").is_err());
+        assert!(!path.join("Veryl.toml").exists());
+    }
+}
+
+#[cfg(test)]
+mod metadata {
+    use crate::{load, project};
+    use serde_json::Value;
+    use std::path::Path;
+    use veryl::cmd_metadata::CmdMetadata;
+    use veryl::{Format, OptMetadata};
+
+    const TOML: &str = r#"
+[project]
+name = "prj"
+version = "0.2.0"
+
+[build]
+exclude_std = true
+reset_type = "async_low"
+sourcemap_target = {type = "none"}
+target = {type = "directory", path = "target"}
+
+[build.profiles.synth]
+reset_type = "sync_high"
+
+[features]
+default = ["a"]
+a = {files = ["src/a/*.veryl"]}
+b = {files = ["src/b/*.veryl"]}
+"#;
+
+    const SOURCES: [(&str, &str); 3] = [
+        ("src/top.veryl", "module Top {}\n"),
+        ("src/a/a.veryl", "module A {}\n"),
+        ("src/b/b.veryl", "module B {}\n"),
+    ];
+
+    fn render(path: &Path, profile: Option<&str>) -> Result<String, String> {
+        let mut metadata = load(path);
+        let cmd = CmdMetadata::new(OptMetadata {
+            format: Format::Json,
+            profile: profile.map(|x| x.to_string()),
+        });
+        cmd.render(&mut metadata).map_err(|x| x.to_string())
+    }
+
+    #[test]
+    fn json() {
+        let temp_dir = project(TOML, &SOURCES);
+        let path = temp_dir.path();
+
+        let text = render(path, Some("synth")).unwrap();
+        let json: Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["root"], "prj");
+        assert_eq!(json["profile"], "synth");
+        assert_eq!(json["build"]["reset_type"], "sync_high");
+        assert!(json["filelist"].as_str().unwrap().ends_with("prj.f"));
+
+        let projects = json["projects"].as_array().unwrap();
+        assert_eq!(projects.len(), 1);
+        let project = &projects[0];
+        assert_eq!(project["name"], "prj");
+        assert_eq!(project["version"], "0.2.0");
+        assert_eq!(project["features"], serde_json::json!(["a"]));
+        assert!(project["dependencies"].as_array().unwrap().is_empty());
+
+        // sources of disabled features are not listed
+        let sources: Vec<_> = project["sources"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| {
+                let source = Path::new(x["source"].as_str().unwrap());
+                let output = Path::new(x["output"].as_str().unwrap());
+                assert!(output.starts_with(path.join("target")));
+                source.file_name().unwrap().to_string_lossy().to_string()
+            })
+            .collect();
+        assert_eq!(sources, vec!["a.veryl", "top.veryl"]);
+    }
+
+    #[test]
+    fn unknown_profile() {
+        let temp_dir = project(TOML, &SOURCES);
+        assert!(render(temp_dir.path(), Some("sim")).is_err());
+    }
+}
+
+#[cfg(test)]
+mod fmt_stdin {
+    use crate::project;
+    use veryl::cmd_fmt::CmdFmt;
+
+    const TOML: &str = r#"
+[project]
+name = "fmt"
+version = "0.1.0"
+
+[format]
+indent_width = 2
+"#;
+
+    const SOURCE: &str = "module Top { var a: logic; assign a = 1; }\n";
+
+    #[test]
+    fn project_format() {
+        let temp_dir = project(TOML, &[]);
+        let path = temp_dir.path();
+
+        // the file doesn't need to exist
+        let src = path.join("src").join("top.veryl");
+        let metadata = CmdFmt::stdin_metadata(Some(&src)).unwrap();
+        assert_eq!(metadata.project.name, "fmt");
+
+        let output = CmdFmt::format_str(&metadata, SOURCE, &src).unwrap();
+        assert!(output.contains("\n  var a: logic;\n"), "{output}");
+    }
+
+    #[test]
+    fn default_format() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let src = temp_dir.path().join("top.veryl");
+
+        for path in [None, Some(src.as_path())] {
+            let metadata = CmdFmt::stdin_metadata(path).unwrap();
+            let output = CmdFmt::format_str(&metadata, SOURCE, &src).unwrap();
+            assert!(output.contains("\n    var a: logic;\n"), "{output}");
+        }
+    }
+
+    #[test]
+    fn syntax_error() {
+        let metadata = CmdFmt::stdin_metadata(None).unwrap();
+        let src = std::path::Path::new("<stdin>");
+        assert!(CmdFmt::format_str(&metadata, "module Top {", src).is_err());
+    }
+}
+
+#[cfg(test)]
+mod doc {
+    use crate::{default_toml, load, project};
+    use std::collections::HashMap;
+    use veryl::cmd_doc::CmdDoc;
+    use veryl::OptDoc;
+    use veryl_analyzer::Analyzer;
+    use veryl_parser::Parser;
+
+    const SOURCE: &str = r#"
+pub package PkgA {
+    struct StructA {
+        a: logic,
+    }
+}
+
+pub module ModuleA (
+    i_a: input PkgA::StructA,
+    i_b: input sub::PkgB::StructB,
+    i_c: input other::PkgC::StructC,
+) {
+    inst u_b: ModuleB;
+}
+
+pub module ModuleB {}
+"#;
+
+    const SUB_SOURCE: &str = r#"
+pub package PkgB {
+    struct StructB {
+        b: logic,
+    }
+}
+"#;
+
+    const OTHER_SOURCE: &str = r#"
+pub package PkgC {
+    struct StructC {
+        c: logic,
+    }
+}
+"#;
+
+    /// Returns rendered markdown of each page.
+    /// Sources of dependencies are analyzed together, but only `sub` has documents.
+    fn render() -> HashMap<String, String> {
+        let temp_dir = project(&default_toml("prj"), &[("src/a.veryl", SOURCE)]);
+        let mut metadata = load(temp_dir.path());
+        for name in ["sub", "other"] {
+            metadata
+                .workspace_dependencies
+                .insert(name.to_string(), name.to_string());
+        }
+
+        let sources = [
+            ("prj", SOURCE),
+            ("sub", SUB_SOURCE),
+            ("other", OTHER_SOURCE),
+        ];
+        let mut contexts = Vec::new();
+        for (prj, input) in sources {
+            let path = format!("{prj}.veryl");
+            let parser = Parser::parse(input, &path).unwrap();
+            let analyzer = Analyzer::new(&metadata);
+            analyzer.analyze_pass1(prj, input, &path, &parser.veryl);
+            contexts.push((prj, input, path, parser, analyzer));
+        }
+        Analyzer::analyze_post_pass1();
+        for (prj, input, path, parser, analyzer) in &contexts {
+            let errors = analyzer.analyze_pass2(prj, input, path, &parser.veryl);
+            assert!(errors.is_empty(), "{errors:?}");
+        }
+        for (prj, input, path, parser, analyzer) in &contexts {
+            analyzer.analyze_pass3(prj, input, path, &parser.veryl);
+        }
+
+        let dependency_docs =
+            HashMap::from([("sub".to_string(), "https://example.com/sub".to_string())]);
+        let builder = CmdDoc::new(OptDoc::default())
+            .builder(&metadata, dependency_docs)
+            .unwrap();
+        builder.render().into_iter().collect()
+    }
+
+    #[test]
+    fn type_link() {
+        let pages = render();
+        let module = &pages["ModuleA.md"];
+
+        // types in the project link to their page
+        assert!(
+            module.contains("<a href=\"PkgA.html\">PkgA::StructA</a>"),
+            "{module}"
+        );
+        // types in dependencies link into their documents
+        assert!(
+            module.contains("<a href=\"https://example.com/sub/PkgB.html\">sub::PkgB::StructB</a>"),
+            "{module}"
+        );
+        // types without documents are plain text
+        assert!(
+            module.contains("<span class=\"hljs-type\">other::PkgC::StructC</span>"),
+            "{module}"
+        );
+    }
+
+    #[test]
+    fn instances() {
+        let pages = render();
+
+        let module = &pages["ModuleA.md"];
+        assert!(module.contains("### Instantiates"), "{module}");
+        assert!(
+            module.contains("<a href=\"ModuleB.html\">ModuleB</a>"),
+            "{module}"
+        );
+        assert!(!module.contains("### Instantiated By"), "{module}");
+
+        let module = &pages["ModuleB.md"];
+        assert!(module.contains("### Instantiated By"), "{module}");
+        assert!(
+            module.contains("<a href=\"ModuleA.html\">ModuleA</a>"),
+            "{module}"
+        );
+        assert!(!module.contains("### Instantiates"), "{module}");
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let pages = render();
+
+        let module = &pages["ModuleA.md"];
+        let begin = module.find("<svg").expect(module);
+        let end = module.find("</svg>").expect(module);
+        let svg = &module[begin..end];
+        assert!(!svg.contains("\n\n"), "{svg}");
+        assert!(svg.contains(">ModuleB</text>"), "{svg}");
+        assert!(svg.contains("<a href=\"ModuleB.html\">"), "{svg}");
+        assert!(svg.contains("<a href=\"PkgA.html\">"), "{svg}");
+        assert!(svg.contains("<line "), "{svg}");
+        assert!(!module.contains("```mermaid"), "{module}");
+
+        // no graph without dependencies
+        let module = &pages["ModuleB.md"];
+        assert!(!module.contains("<svg"), "{module}");
+    }
+}
@@ -0,0 +1,295 @@
+use log::debug;
+use miette::{IntoDiagnostic, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use veryl_analyzer::snapshot::{ExternalSymbol, Snapshot};
+use veryl_analyzer::symbol::SymbolKind;
+use veryl_analyzer::{symbol_table, type_dag};
+use veryl_metadata::Metadata;
+use veryl_parser::veryl_token::TokenSource;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub key: String,
+    /// Files which `key` is calculated from
+    pub files: Vec<PathBuf>,
+    /// Whether the file declares generic items, which are emitted for instances in other files
+    pub generic: bool,
+    /// Build which wrote this entry. Snapshots are restored only if all of them are written by the same build.
+    pub session: String,
+    pub outputs: Vec<CachedOutput>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CachedOutput {
+    /// Output path listed in filelist
+    pub dst: PathBuf,
+    pub map: PathBuf,
+    pub output: String,
+    pub source_map: Option<String>,
+    #[serde(default)]
+    pub init_files: Vec<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheKey {
+    pub key: String,
+    pub files: Vec<PathBuf>,
+    pub generic: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheIndex {
+    session: String,
+    externals: Vec<ExternalSymbol>,
+}
+
+/// BuildCache stores analysis results and emitted outputs of each source file between builds.
+///
+/// The key of an entry is calculated from the tool version, build options, the list of source files,
+/// and source texts of the file and files which it depends on through the type DAG.
+/// Because the files are recorded in the entry, the key can be checked before parsing.
+/// Snapshots of the symbol table and the type DAG are restored for unchanged files,
+/// so they skip parse, analysis and emission.
+pub struct BuildCache {
+    path: PathBuf,
+    enabled: bool,
+}
+
+impl BuildCache {
+    pub fn new(metadata: &Metadata, enabled: bool) -> Self {
+        Self {
+            path: metadata.build_cache_path(),
+            enabled,
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns an id which distinguishes entries written by each build
+    pub fn new_session() -> String {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_nanos())
+            .unwrap_or_default();
+        format!("{time:x}-{:x}", std::process::id())
+    }
+
+    fn entry_path(&self, src: &Path, ext: &str) -> PathBuf {
+        let name = blake3::hash(src.to_string_lossy().as_bytes()).to_hex();
+        self.path.join(format!("{name}.{ext}"))
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.path.join("index.json")
+    }
+
+    fn write(&self, path: &Path, text: &str) -> Result<()> {
+        if !self.path.exists() {
+            fs::create_dir_all(&self.path).into_diagnostic()?;
+        }
+        fs::write(path, text).into_diagnostic()
+    }
+
+    pub fn get(&self, src: &Path) -> Option<CacheEntry> {
+        if !self.enabled {
+            return None;
+        }
+
+        let text = fs::read_to_string(self.entry_path(src, "json")).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    /// Returns whether a snapshot of `src` is stored
+    pub fn has_snapshot(&self, src: &Path) -> bool {
+        self.enabled && self.entry_path(src, "snapshot.json").exists()
+    }
+
+    /// Deserializes the snapshot of `src`.
+    /// This should be called after `snapshot::begin_restore` because ids in it are renumbered.
+    pub fn get_snapshot(&self, src: &Path) -> Option<Snapshot> {
+        if !self.enabled {
+            return None;
+        }
+
+        let text = fs::read_to_string(self.entry_path(src, "snapshot.json")).ok()?;
+        let snapshot = serde_json::from_str(&text).ok()?;
+        debug!("Found cache ({})", src.to_string_lossy());
+        Some(snapshot)
+    }
+
+    /// Returns the session of the last build and symbols referred by snapshots of it
+    pub fn get_index(&self) -> Option<(String, Vec<ExternalSymbol>)> {
+        if !self.enabled {
+            return None;
+        }
+
+        let text = fs::read_to_string(self.index_path()).ok()?;
+        let index: CacheIndex = serde_json::from_str(&text).ok()?;
+        Some((index.session, index.externals))
+    }
+
+    /// Stores the entry of `src`.
+    /// The previous snapshot is removed if `snapshot` is none, then the file is analyzed again.
+    pub fn insert(
+        &self,
+        src: &Path,
+        entry: &CacheEntry,
+        snapshot: Option<&Snapshot>,
+    ) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let snapshot_path = self.entry_path(src, "snapshot.json");
+        if let Some(snapshot) = snapshot {
+            let text = serde_json::to_string(snapshot).into_diagnostic()?;
+            self.write(&snapshot_path, &text)?;
+        } else if snapshot_path.exists() {
+            fs::remove_file(&snapshot_path).into_diagnostic()?;
+        }
+
+        let text = serde_json::to_string(entry).into_diagnostic()?;
+        self.write(&self.entry_path(src, "json"), &text)
+    }
+
+    /// Stores the index.
+    /// This should be called after all entries of the session are stored.
+    pub fn insert_index(&self, session: &str, externals: Vec<ExternalSymbol>) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let index = CacheIndex {
+            session: session.to_string(),
+            externals,
+        };
+        let text = serde_json::to_string(&index).into_diagnostic()?;
+        self.write(&self.index_path(), &text)
+    }
+
+    fn options(metadata: &Metadata) -> String {
+        let features: BTreeMap<_, _> = metadata
+            .feature_table
+            .iter()
+            .map(|(name, x)| (name, &x.enabled))
+            .collect();
+        let name_overrides: BTreeMap<_, _> = metadata.build.name_overrides.iter().collect();
+        serde_json::to_string(&(
+            &metadata.project,
+            &metadata.build,
+            &metadata.format,
+            &metadata.lint,
+            &features,
+            &name_overrides,
+        ))
+        .unwrap_or_default()
+    }
+
+    /// Calculates the cache key of a source file from `files` which it depends on.
+    /// `sources` are all source files and their hashes.
+    pub fn key(
+        metadata: &Metadata,
+        sources: &BTreeMap<PathBuf, blake3::Hash>,
+        files: &[PathBuf],
+    ) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update(Self::options(metadata).as_bytes());
+        // Adding or removing files may change resolution of names
+        for path in sources.keys() {
+            hasher.update(path.to_string_lossy().as_bytes());
+        }
+        for file in files {
+            hasher.update(file.to_string_lossy().as_bytes());
+            if let Some(x) = sources.get(file) {
+                hasher.update(x.as_bytes());
+            }
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Returns hashes of source texts, which are passed to `key`
+    pub fn hashes(sources: &[(&Path, &str)]) -> BTreeMap<PathBuf, blake3::Hash> {
+        sources
+            .iter()
+            .map(|(path, text)| (path.to_path_buf(), blake3::hash(text.as_bytes())))
+            .collect()
+    }
+
+    /// Calculates cache keys of source files.
+    /// This must be called after analysis because it refers the symbol table and the type DAG.
+    pub fn keys(metadata: &Metadata, sources: &[(&Path, &str)]) -> HashMap<PathBuf, CacheKey> {
+        let hashes = Self::hashes(sources);
+
+        let mut dependencies: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+        let mut dependents: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+        let mut generic_files = HashSet::new();
+        for symbol in symbol_table::get_all() {
+            let path = if let TokenSource::File(x) = symbol.token.source {
+                PathBuf::from(format!("{x}"))
+            } else {
+                continue;
+            };
+
+            if matches!(symbol.kind, SymbolKind::GenericParameter(_)) {
+                generic_files.insert(path.clone());
+            }
+
+            for dependency in type_dag::dependencies(symbol.id) {
+                if let TokenSource::File(x) = dependency.token.source {
+                    let dependency = PathBuf::from(format!("{x}"));
+                    if dependency != path {
+                        dependencies
+                            .entry(path.clone())
+                            .or_default()
+                            .insert(dependency.clone());
+                        dependents
+                            .entry(dependency)
+                            .or_default()
+                            .insert(path.clone());
+                    }
+                }
+            }
+        }
+
+        let mut ret = HashMap::new();
+        for (path, _) in sources {
+            // Generic items are emitted for each instantiation,
+            // so files including them depend on their dependents too.
+            let generic = generic_files.contains(*path);
+
+            let mut files = BTreeSet::new();
+            let mut stack = vec![path.to_path_buf()];
+            while let Some(x) = stack.pop() {
+                if files.insert(x.clone()) {
+                    if let Some(x) = dependencies.get(&x) {
+                        stack.extend(x.iter().cloned());
+                    }
+                    if generic {
+                        if let Some(x) = dependents.get(&x) {
+                            stack.extend(x.iter().cloned());
+                        }
+                    }
+                }
+            }
+
+            let files: Vec<_> = files.into_iter().collect();
+            let key = Self::key(metadata, &hashes, &files);
+            ret.insert(
+                path.to_path_buf(),
+                CacheKey {
+                    key,
+                    files,
+                    generic,
+                },
+            );
+        }
+        ret
+    }
+}
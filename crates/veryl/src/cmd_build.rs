@@ -1,139 +1,709 @@
+use crate::cache::{BuildCache, CacheEntry, CachedOutput};
 use crate::cmd_check::CheckError;
-use crate::OptBuild;
+use crate::constraints;
+use crate::header;
+use crate::hook::{self, HookKind};
+use crate::timings::Timings;
+use crate::{ConstraintFormat, OptBuild};
 use log::{debug, info};
-use miette::{IntoDiagnostic, Result, WrapErr};
-use std::collections::HashMap;
+use miette::{bail, IntoDiagnostic, Result, WrapErr};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::mem;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tempfile::TempDir;
+use veryl_analyzer::attribute::Attribute as Attr;
 use veryl_analyzer::namespace::Namespace;
-use veryl_analyzer::symbol::SymbolKind;
-use veryl_analyzer::{symbol_table, type_dag, Analyzer};
+use veryl_analyzer::symbol::{Direction, SymbolKind, Type, TypeKind};
+use veryl_analyzer::{attribute_table, snapshot, symbol_table, type_dag, Analyzer, AnalyzerError};
 use veryl_emitter::Emitter;
-use veryl_metadata::{FilelistType, Metadata, SourceMapTarget, Target};
-use veryl_parser::{resource_table, veryl_token::TokenSource, Parser};
+use veryl_metadata::{
+    FeatureSelection, FilelistType, HeaderLang, Metadata, MetadataError, OutputLayout,
+    SourceMapTarget, Target, Workspace,
+};
+use veryl_parser::{resource_table, timing, veryl_token::TokenSource, Parser};
 use veryl_path::PathSet;
 
 pub struct CmdBuild {
     opt: OptBuild,
 }
 
+/// Results of analysis shared by projects
+struct Analysis {
+    /// Source texts of all files
+    texts: HashMap<PathBuf, String>,
+    /// Parsed syntax trees of files analyzed in this build
+    parsers: HashMap<PathBuf, Parser>,
+    /// Files restored from the build cache
+    cached: HashSet<PathBuf>,
+    /// Files which have warnings
+    warned: HashSet<PathBuf>,
+    /// Name of the project which analyzes each file
+    analyzed_by: HashMap<PathBuf, String>,
+    session: String,
+}
+
+struct Output {
+    /// Output path listed in filelist
+    dst: PathBuf,
+    /// Path actually written, which differs from `dst` in bundle target
+    written: PathBuf,
+}
+
 impl CmdBuild {
     pub fn new(opt: OptBuild) -> Self {
         Self { opt }
     }
 
     pub fn exec(&self, metadata: &mut Metadata, include_tests: bool) -> Result<bool> {
-        let paths = metadata.paths(&self.opt.files, true)?;
+        let start = self.start_timings();
+        let paths = self.paths(metadata, true)?;
+
+        self.run_hooks(metadata, HookKind::PreBuild, None)?;
+
+        let (analysis, mut check_errors) = self.analyze(&[(metadata, &paths)])?;
+
+        // Temporary directory of bundle target is kept until filelist is generated
+        let mut outputs = HashMap::new();
+        let _temp_dir = self.emit(metadata, &paths, &analysis, &mut outputs)?;
+
+        self.gen_filelist(metadata, &paths, &outputs, include_tests)?;
+
+        if let Some(ref path) = self.opt.dpi_header {
+            self.gen_dpi_header(&[metadata], path)?;
+        }
+        self.gen_headers(metadata)?;
+        self.gen_constraints(metadata)?;
+
+        self.report_timings(start, &metadata.project_path())?;
+
+        let _ = check_errors.remove(0).check_err()?;
+
+        // post_build hooks are run only if the build succeeded
+        self.run_hooks(metadata, HookKind::PostBuild, None)?;
+        Ok(true)
+    }
+
+    /// Builds members of the workspace.
+    ///
+    /// Sources shared by members are parsed and analyzed only once,
+    /// and each member is emitted with its own metadata into its own target.
+    pub fn exec_workspace(&self, workspace: &Workspace) -> Result<bool> {
+        if !self.opt.files.is_empty() {
+            bail!("target files can't be specified in workspace, use --project instead");
+        }
+
+        let start = self.start_timings();
+        let mut members = workspace.load_members()?;
+        let selected = self.select_members(&members)?;
+
+        let mut member_paths = HashMap::new();
+        for &i in &selected {
+            let explicit = self.opt.project.is_none()
+                || self.opt.project.as_ref() == Some(&members[i].project.name);
+            let paths = self.paths(&mut members[i], explicit)?;
+            member_paths.insert(i, paths);
+        }
+
+        for &i in &selected {
+            self.run_hooks(&members[i], HookKind::PreBuild, None)?;
+        }
+
+        let projects: Vec<_> = selected
+            .iter()
+            .map(|i| (&members[*i], member_paths[i].as_slice()))
+            .collect();
+        let (analysis, check_errors) = self.analyze(&projects)?;
+
+        let mut outputs = HashMap::new();
+        let mut temp_dirs = Vec::new();
+        for &i in &selected {
+            info!("Building project ({})", members[i].project.name);
+            temp_dirs.extend(self.emit(&members[i], &member_paths[&i], &analysis, &mut outputs)?);
+        }
+
+        for &i in &selected {
+            // Sources of member dependencies are listed before ones of the member
+            let mut paths = Vec::new();
+            for j in Self::member_dependencies(&members, i) {
+                let name = &members[j].project.name;
+                paths.extend(member_paths[&j].iter().filter(|x| &x.prj == name).cloned());
+            }
+            for path in &member_paths[&i] {
+                if !paths.iter().any(|x| x.src == path.src) {
+                    paths.push(path.clone());
+                }
+            }
+            self.gen_filelist(&members[i], &paths, &outputs, false)?;
+        }
+
+        let metadata: Vec<_> = selected.iter().map(|i| &members[*i]).collect();
+        if let Some(ref path) = self.opt.dpi_header {
+            self.gen_dpi_header(&metadata, path)?;
+        }
+        for metadata in &metadata {
+            self.gen_headers(metadata)?;
+            self.gen_constraints(metadata)?;
+        }
+
+        let base = workspace.metadata_path.parent().unwrap_or(Path::new(""));
+        self.report_timings(start, base)?;
+
+        for (metadata, check_error) in metadata.iter().zip(check_errors) {
+            let _ = Self::check_member(metadata, check_error)?;
+        }
+
+        // post_build hooks are run only if all members are built successfully
+        for metadata in &metadata {
+            self.run_hooks(metadata, HookKind::PostBuild, None)?;
+        }
+        Ok(true)
+    }
+
+    fn paths(&self, metadata: &mut Metadata, explicit: bool) -> Result<Vec<PathSet>> {
+        metadata.feature_selection = if explicit {
+            FeatureSelection {
+                features: self.opt.features.clone(),
+                default_features: !self.opt.no_default_features,
+            }
+        } else {
+            FeatureSelection::default()
+        };
+        metadata.offline = self.opt.offline;
+        metadata.no_ignore = self.opt.no_ignore;
+        if let Some(ref profile) = self.opt.profile {
+            // dependent members which don't define the profile are built by `[build]`
+            if explicit || metadata.build.profiles.contains_key(profile) {
+                metadata.apply_profile(profile)?;
+            }
+        }
+        let mut paths = metadata.paths(&self.opt.files, true)?;
+        paths.retain(|x| metadata.is_enabled_path(x));
+        Ok(paths)
+    }
+
+    /// Returns indexes of members to be built in dependency order
+    fn select_members(&self, members: &[Metadata]) -> Result<Vec<usize>> {
+        let Some(ref name) = self.opt.project else {
+            let mut ret = Vec::new();
+            for i in 0..members.len() {
+                for j in Self::member_dependencies(members, i) {
+                    if !ret.contains(&j) {
+                        ret.push(j);
+                    }
+                }
+                if !ret.contains(&i) {
+                    ret.push(i);
+                }
+            }
+            return Ok(ret);
+        };
+
+        let Some(i) = members.iter().position(|x| &x.project.name == name) else {
+            let available: Vec<_> = members.iter().map(|x| x.project.name.as_str()).collect();
+            return Err(MetadataError::UnknownMember {
+                name: name.clone(),
+                available: available.join(", "),
+            }
+            .into());
+        };
+        let mut ret = Self::member_dependencies(members, i);
+        ret.push(i);
+        Ok(ret)
+    }
+
+    /// Returns indexes of members on which `members[index]` depends transitively in dependency order
+    fn member_dependencies(members: &[Metadata], index: usize) -> Vec<usize> {
+        fn visit(members: &[Metadata], index: usize, ret: &mut Vec<usize>) {
+            let mut names: Vec<_> = members[index].workspace_dependencies.values().collect();
+            names.sort();
+            for name in names {
+                let Some(i) = members.iter().position(|x| &x.project.name == name) else {
+                    continue;
+                };
+                if !ret.contains(&i) {
+                    ret.push(i);
+                    visit(members, i, ret);
+                    // Move after its dependencies
+                    ret.retain(|x| *x != i);
+                    ret.push(i);
+                }
+            }
+        }
+
+        let mut ret = Vec::new();
+        visit(members, index, &mut ret);
+        ret.retain(|x| *x != index);
+        ret
+    }
+
+    fn run_hooks(&self, metadata: &Metadata, kind: HookKind, output: Option<&Path>) -> Result<()> {
+        if self.opt.no_hooks {
+            Ok(())
+        } else {
+            hook::run(metadata, kind, output)
+        }
+    }
+
+    /// Enables timing instrumentation if it is required, and returns the start time of build
+    fn start_timings(&self) -> Option<Instant> {
+        if self.opt.timings || self.opt.timings_json.is_some() {
+            timing::enable();
+            // Discard records of the previous build in the same process
+            let _ = timing::take();
+            Some(Instant::now())
+        } else {
+            None
+        }
+    }
+
+    fn report_timings(&self, start: Option<Instant>, base: &Path) -> Result<()> {
+        let Some(start) = start else {
+            return Ok(());
+        };
+
+        let timings = Timings::new(&timing::take(), start, base);
+        if self.opt.timings {
+            println!("{}", timings.to_table());
+        }
+        if let Some(ref path) = self.opt.timings_json {
+            info!("Output timings ({})", path.to_string_lossy());
+            fs::write(path, timings.to_json()).into_diagnostic()?;
+        }
+        Ok(())
+    }
+
+    fn check_member(metadata: &Metadata, check_error: CheckError) -> Result<CheckError> {
+        check_error
+            .check_err()
+            .wrap_err_with(|| format!("project \"{}\" has errors", metadata.project.name))
+    }
+
+    /// Parses and analyzes source files of projects.
+    /// Files shared by multiple projects are processed with the metadata of the first one.
+    /// Analysis results of unchanged files are restored from the build cache instead.
+    fn analyze(&self, projects: &[(&Metadata, &[PathSet])]) -> Result<(Analysis, Vec<CheckError>)> {
+        let mut check_errors: Vec<_> = projects.iter().map(|_| CheckError::default()).collect();
+        let mut check = |i: usize, errors: &mut Vec<AnalyzerError>| -> Result<()> {
+            let check_error = mem::take(&mut check_errors[i]).append(errors);
+            check_errors[i] = if projects.len() > 1 {
+                Self::check_member(projects[i].0, check_error)?
+            } else {
+                check_error.check_err()?
+            };
+            Ok(())
+        };
+
+        let mut texts = HashMap::new();
+        let mut analyzed_by = HashMap::new();
+        for (i, (_, paths)) in projects.iter().enumerate() {
+            for path in paths.iter() {
+                if !texts.contains_key(&path.src) {
+                    let input = fs::read_to_string(&path.src)
+                        .into_diagnostic()
+                        .wrap_err("")?;
+                    texts.insert(path.src.clone(), input);
+                    analyzed_by.insert(path.src.clone(), i);
+                }
+            }
+        }
+
+        // Namespaces of dependencies are registered before restoring snapshots referring them
+        let analyzers: Vec<_> = projects
+            .iter()
+            .map(|(metadata, _)| Analyzer::new(metadata))
+            .collect();
+
+        let session = BuildCache::new_session();
+        let cached = timing::measure("restore", None, || {
+            self.restore(projects, &texts, &analyzed_by)
+        });
 
-        let mut check_error = CheckError::default();
         let mut contexts = Vec::new();
+        let mut visited = HashSet::new();
+        let mut warned = HashSet::new();
+
+        for (i, (_, paths)) in projects.iter().enumerate() {
+            for path in paths.iter() {
+                if analyzed_by[&path.src] != i || !visited.insert(path.src.clone()) {
+                    continue;
+                }
+                if cached.contains(&path.src) {
+                    debug!("Restored file ({})", path.src.to_string_lossy());
+                    continue;
+                }
 
-        for path in &paths {
-            info!("Processing file ({})", path.src.to_string_lossy());
+                info!("Processing file ({})", path.src.to_string_lossy());
 
-            let input = fs::read_to_string(&path.src)
-                .into_diagnostic()
-                .wrap_err("")?;
-            let parser = Parser::parse(&input, &path.src)?;
+                let input = &texts[&path.src];
+                // error is boxed not to move the large error through the closure
+                let mut parser = timing::measure("parse", Some(&path.src), || {
+                    Parser::parse(input, &path.src).map_err(Box::new)
+                })
+                .map_err(|x| *x)?;
+
+                let analyzer = &analyzers[i];
+                let mut errors = analyzer.apply_features(&path.prj, input, &mut parser.veryl);
+                if !errors.is_empty() {
+                    warned.insert(path.src.clone());
+                }
+                check(i, &mut errors)?;
 
-            let analyzer = Analyzer::new(metadata);
-            let mut errors = analyzer.analyze_pass1(&path.prj, &input, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+                let mut errors = analyzer.analyze_pass1(&path.prj, input, &path.src, &parser.veryl);
+                if !errors.is_empty() {
+                    warned.insert(path.src.clone());
+                }
+                check(i, &mut errors)?;
 
-            contexts.push((path, input, parser, analyzer));
+                contexts.push((i, path, parser));
+            }
         }
 
         Analyzer::analyze_post_pass1();
 
-        for (path, input, parser, analyzer) in &contexts {
-            let mut errors = analyzer.analyze_pass2(&path.prj, input, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+        for (i, path, parser) in &contexts {
+            let input = &texts[&path.src];
+            let mut errors =
+                analyzers[*i].analyze_pass2(&path.prj, input, &path.src, &parser.veryl);
+            if !errors.is_empty() {
+                warned.insert(path.src.clone());
+            }
+            check(*i, &mut errors)?;
         }
 
-        for (path, input, parser, analyzer) in &contexts {
-            let mut errors = analyzer.analyze_pass3(&path.prj, input, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+        for (i, path, parser) in &contexts {
+            let input = &texts[&path.src];
+            let mut errors =
+                analyzers[*i].analyze_pass3(&path.prj, input, &path.src, &parser.veryl);
+            if !errors.is_empty() {
+                warned.insert(path.src.clone());
+            }
+            check(*i, &mut errors)?;
+        }
+
+        let parsers = contexts
+            .into_iter()
+            .map(|(_, path, parser)| (path.src.clone(), parser))
+            .collect();
+        let analyzed_by = analyzed_by
+            .into_iter()
+            .map(|(path, i)| (path, projects[i].0.project.name.clone()))
+            .collect();
+        let analysis = Analysis {
+            texts,
+            parsers,
+            cached,
+            warned,
+            analyzed_by,
+            session,
+        };
+        Ok((analysis, check_errors))
+    }
+
+    /// Returns the build cache of `metadata`, which is disabled by `--no-cache`
+    fn cache(&self, metadata: &Metadata) -> BuildCache {
+        // Source maps in bundle target refer temporary directory which changes every build
+        let bundle = matches!(metadata.build.target, Target::Bundle { .. });
+        BuildCache::new(metadata, !self.opt.no_cache && !bundle)
+    }
+
+    /// Restores snapshots of unchanged files from the build cache,
+    /// and returns the restored files which are not parsed, analyzed and emitted again.
+    fn restore(
+        &self,
+        projects: &[(&Metadata, &[PathSet])],
+        texts: &HashMap<PathBuf, String>,
+        analyzed_by: &HashMap<PathBuf, usize>,
+    ) -> HashSet<PathBuf> {
+        let caches: Vec<_> = projects.iter().map(|(x, _)| self.cache(x)).collect();
+        let Some((session, externals)) = caches[0].get_index() else {
+            return HashSet::new();
+        };
+
+        // Entries of all projects which emit the file should be valid,
+        // and the snapshot is stored in the cache of the project which analyzes the file.
+        let mut entries = HashMap::new();
+        let mut invalid = HashSet::new();
+        for (i, (metadata, paths)) in projects.iter().enumerate() {
+            let sources: Vec<_> = paths
+                .iter()
+                .map(|x| (x.src.as_path(), texts[&x.src].as_str()))
+                .collect();
+            let hashes = BuildCache::hashes(&sources);
+            for path in paths.iter() {
+                let entry = caches[i].get(&path.src).filter(|x| {
+                    x.session == session && x.key == BuildCache::key(metadata, &hashes, &x.files)
+                });
+                let analyzing = analyzed_by[&path.src] == i;
+                match entry {
+                    Some(x) if analyzing && caches[i].has_snapshot(&path.src) => {
+                        entries.insert(path.src.clone(), x);
+                    }
+                    Some(_) if !analyzing => (),
+                    _ => {
+                        invalid.insert(path.src.clone());
+                    }
+                }
+            }
+        }
+        entries.retain(|x, _| !invalid.contains(x));
+
+        // Generic items may have instances created by analysis of any file
+        if entries.len() != texts.len() {
+            entries.retain(|_, x| !x.generic);
+        }
+
+        // Files which depend on files analyzed again should be analyzed too
+        loop {
+            let missed: Vec<_> = entries
+                .iter()
+                .filter(|(_, x)| {
+                    x.files
+                        .iter()
+                        .any(|x| texts.contains_key(x) && !entries.contains_key(x))
+                })
+                .map(|(x, _)| x.clone())
+                .collect();
+            if missed.is_empty() {
+                break;
+            }
+            for x in missed {
+                entries.remove(&x);
+            }
+        }
+
+        if entries.is_empty() {
+            return HashSet::new();
+        }
+
+        snapshot::begin_restore(&externals);
+        let mut snapshots = Vec::new();
+        for path in entries.keys() {
+            let Some(x) = caches[analyzed_by[path]].get_snapshot(path) else {
+                return HashSet::new();
+            };
+            snapshots.push(x);
         }
+        if !snapshot::restore(snapshots) {
+            debug!("Discarded cache referring missing symbols");
+            return HashSet::new();
+        }
+
+        entries.into_keys().collect()
+    }
 
+    /// Emits `paths` and records output files of each source to `outputs`.
+    /// The returned temporary directory holds outputs of bundle target until filelist is generated.
+    fn emit(
+        &self,
+        metadata: &Metadata,
+        paths: &[PathSet],
+        analysis: &Analysis,
+        outputs: &mut HashMap<PathBuf, Vec<Output>>,
+    ) -> Result<Option<TempDir>> {
         let temp_dir = if let Target::Bundle { .. } = &metadata.build.target {
             Some(TempDir::new().into_diagnostic()?)
         } else {
             None
         };
 
-        for (path, input, parser, _) in &contexts {
-            let (dst, map) = if let Some(ref temp_dir) = temp_dir {
-                let dst_temp = temp_dir.path().join(
-                    path.dst
-                        .strip_prefix(metadata.project_path())
-                        .into_diagnostic()?,
-                );
-                let map_temp = temp_dir.path().join(
-                    path.map
-                        .strip_prefix(metadata.project_path())
-                        .into_diagnostic()?,
-                );
-                (dst_temp, map_temp)
+        let cache = self.cache(metadata);
+        let texts: Vec<_> = paths
+            .iter()
+            .map(|path| (path.src.as_path(), analysis.texts[&path.src].as_str()))
+            .collect();
+        let keys = BuildCache::keys(metadata, &texts);
+
+        // Snapshots of files analyzed with this project are stored to the cache
+        let mut snapshot_paths = Vec::new();
+        let mut entries = Vec::new();
+
+        for path in paths {
+            let key = &keys[&path.src];
+            let entry = cache.get(&path.src);
+            let entry = if analysis.cached.contains(&path.src) {
+                entry
+            } else {
+                entry.filter(|x| x.key == key.key)
+            };
+            let cached_outputs = if let Some(entry) = entry {
+                debug!("Found cache ({})", path.src.to_string_lossy());
+                entry.outputs
             } else {
-                (path.dst.clone(), path.map.clone())
+                self.emit_file(metadata, path, analysis, temp_dir.as_ref())?
             };
 
-            let mut emitter = Emitter::new(metadata, &path.src, &dst, &map);
-            emitter.emit(&path.prj, &parser.veryl);
+            let mut path_outputs = Vec::new();
+            for cached_output in &cached_outputs {
+                let dst = written_path(metadata, temp_dir.as_ref(), &cached_output.dst)?;
+                let map = written_path(metadata, temp_dir.as_ref(), &cached_output.map)?;
+                path_outputs.push(Output {
+                    dst: cached_output.dst.clone(),
+                    written: dst.clone(),
+                });
 
-            let dst_dir = dst.parent().unwrap();
-            if !dst_dir.exists() {
-                std::fs::create_dir_all(dst.parent().unwrap()).into_diagnostic()?;
-            }
+                timing::measure("write", Some(&path.src), || -> Result<()> {
+                    let dst_dir = dst.parent().unwrap();
+                    if !dst_dir.exists() {
+                        std::fs::create_dir_all(dst.parent().unwrap()).into_diagnostic()?;
+                    }
 
-            let mut file = OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(&dst)
-                .into_diagnostic()?;
-            file.write_all(emitter.as_str().as_bytes())
-                .into_diagnostic()?;
-            file.flush().into_diagnostic()?;
+                    let mut file = OpenOptions::new()
+                        .create(true)
+                        .write(true)
+                        .truncate(true)
+                        .open(&dst)
+                        .into_diagnostic()?;
+                    file.write_all(cached_output.output.as_bytes())
+                        .into_diagnostic()?;
+                    file.flush().into_diagnostic()
+                })?;
 
-            debug!("Output file ({})", dst.to_string_lossy());
+                debug!("Output file ({})", dst.to_string_lossy());
 
-            if metadata.build.sourcemap_target != SourceMapTarget::None {
-                let source_map = emitter.source_map();
-                source_map.set_source_content(input);
-                let source_map = source_map.to_bytes().into_diagnostic()?;
+                self.run_hooks(metadata, HookKind::PostEmit, Some(&dst))?;
 
-                let map_dir = map.parent().unwrap();
-                if !map_dir.exists() {
-                    std::fs::create_dir_all(map.parent().unwrap()).into_diagnostic()?;
+                // Init files are placed beside the output file which is added to filelist
+                let dst_dir = path.dst.parent().unwrap();
+                for init_file in &cached_output.init_files {
+                    // Missing init file is reported as warning by analyzer
+                    let Some(name) = init_file.file_name() else {
+                        continue;
+                    };
+                    if !init_file.exists() {
+                        continue;
+                    }
+                    if !dst_dir.exists() {
+                        std::fs::create_dir_all(dst_dir).into_diagnostic()?;
+                    }
+                    let init_dst = dst_dir.join(name);
+                    fs::copy(init_file, &init_dst).into_diagnostic()?;
+
+                    debug!("Output init file ({})", init_dst.to_string_lossy());
+                }
+
+                if let Some(ref source_map) = cached_output.source_map {
+                    timing::measure("write", Some(&path.src), || -> Result<()> {
+                        let map_dir = map.parent().unwrap();
+                        if !map_dir.exists() {
+                            std::fs::create_dir_all(map.parent().unwrap()).into_diagnostic()?;
+                        }
+
+                        let mut file = OpenOptions::new()
+                            .create(true)
+                            .write(true)
+                            .truncate(true)
+                            .open(&map)
+                            .into_diagnostic()?;
+                        file.write_all(source_map.as_bytes()).into_diagnostic()?;
+                        file.flush().into_diagnostic()
+                    })?;
+
+                    debug!("Output map ({})", map.to_string_lossy());
                 }
+            }
+            outputs.insert(path.src.clone(), path_outputs);
 
-                let mut file = OpenOptions::new()
-                    .create(true)
-                    .write(true)
-                    .truncate(true)
-                    .open(&map)
-                    .into_diagnostic()?;
-                file.write_all(&source_map).into_diagnostic()?;
-                file.flush().into_diagnostic()?;
+            // Files with warnings are analyzed again to report them
+            let stores_snapshot = analysis.analyzed_by[&path.src] == metadata.project.name
+                && !analysis.warned.contains(&path.src);
+            if stores_snapshot {
+                snapshot_paths.push(path.src.clone());
+            }
+            let entry = CacheEntry {
+                key: key.key.clone(),
+                files: key.files.clone(),
+                generic: key.generic,
+                session: analysis.session.clone(),
+                outputs: cached_outputs,
+            };
+            entries.push((path.src.clone(), entry, stores_snapshot));
+        }
 
-                debug!("Output map ({})", map.to_string_lossy());
+        timing::measure("cache", None, || -> Result<()> {
+            if !cache.enabled() {
+                return Ok(());
+            }
+            let mut snapshots: HashMap<_, _> = snapshot::take(&snapshot_paths)
+                .into_iter()
+                .zip(&snapshot_paths)
+                .map(|(x, path)| (path.clone(), x))
+                .collect();
+            for (path, entry, _) in &entries {
+                cache.insert(path, entry, snapshots.remove(path).as_ref())?;
+            }
+            cache.insert_index(&analysis.session, snapshot::externals())
+        })?;
+
+        Ok(temp_dir)
+    }
+
+    /// Emits a source file and returns outputs of it
+    fn emit_file(
+        &self,
+        metadata: &Metadata,
+        path: &PathSet,
+        analysis: &Analysis,
+        temp_dir: Option<&TempDir>,
+    ) -> Result<Vec<CachedOutput>> {
+        let input = &analysis.texts[&path.src];
+        let parser = &analysis.parsers[&path.src];
+
+        // Each target is a tuple of (emitted groups, output file, source map)
+        let mut targets = Vec::new();
+        if metadata.build.output_layout == OutputLayout::PerModule {
+            let mut emitter = Emitter::new(metadata, &path.src, &path.dst, &path.map);
+            for (name, groups) in emitter.declaration_groups(&parser.veryl) {
+                let dst = path.dst.with_file_name(format!("{name}.sv"));
+                let map = path.map.with_file_name(format!("{name}.sv.map"));
+                targets.push((Some(groups), dst, map));
             }
         }
+        if targets.is_empty() {
+            targets.push((None, path.dst.clone(), path.map.clone()));
+        }
 
-        self.gen_filelist(metadata, &paths, temp_dir, include_tests)?;
+        let mut ret = Vec::new();
+        for (groups, out_dst, out_map) in targets {
+            let dst = written_path(metadata, temp_dir, &out_dst)?;
+            let map = written_path(metadata, temp_dir, &out_map)?;
 
-        let _ = check_error.check_err()?;
-        Ok(true)
+            let mut emitter = Emitter::new(metadata, &path.src, &dst, &map);
+            timing::measure("emit", Some(&path.src), || {
+                if let Some(ref groups) = groups {
+                    emitter.emit_groups(&path.prj, &parser.veryl, groups);
+                } else {
+                    emitter.emit(&path.prj, &parser.veryl);
+                }
+            });
+
+            let source_map = if metadata.build.sourcemap_target != SourceMapTarget::None {
+                let source_map = emitter.source_map();
+                source_map.set_source_content(input);
+                let source_map = source_map.to_bytes().into_diagnostic()?;
+                Some(String::from_utf8(source_map).into_diagnostic()?)
+            } else {
+                None
+            };
+
+            ret.push(CachedOutput {
+                dst: out_dst,
+                map: out_map,
+                output: emitter.as_str().to_string(),
+                source_map,
+                init_files: emitter.init_files().to_vec(),
+            });
+        }
+        Ok(ret)
     }
 
     fn gen_filelist_line(&self, metadata: &Metadata, path: &Path) -> Result<String> {
         let base_path = metadata.project_path();
         let path = path.canonicalize().into_diagnostic()?;
-        let relative = path.strip_prefix(&base_path).into_diagnostic()?;
+        let relative = relative_path(&path, &base_path);
         Ok(match metadata.build.filelist_type {
             FilelistType::Absolute => format!("{}\n", path.to_string_lossy()),
             FilelistType::Relative => format!("{}\n", relative.to_string_lossy()),
@@ -143,11 +713,25 @@ impl CmdBuild {
         })
     }
 
+    /// Returns lines defining macros of `defines`, which are referred by `ifdef` attributes
+    fn gen_filelist_defines(&self, metadata: &Metadata) -> String {
+        let mut ret = String::new();
+        for define in &metadata.build.defines {
+            match metadata.build.filelist_type {
+                FilelistType::Absolute | FilelistType::Relative => {
+                    ret.push_str(&format!("+define+{define}\n"));
+                }
+                FilelistType::Flgen => ret.push_str(&format!("define_macro '{define}'\n")),
+            }
+        }
+        ret
+    }
+
     fn gen_filelist(
         &self,
         metadata: &Metadata,
         paths: &[PathSet],
-        temp_dir: Option<TempDir>,
+        outputs: &HashMap<PathBuf, Vec<Output>>,
         include_tests: bool,
     ) -> Result<()> {
         let filelist_path = metadata.filelist_path();
@@ -155,17 +739,15 @@ impl CmdBuild {
 
         let paths = Self::sort_filelist(metadata, paths, include_tests);
 
-        let text = if let Target::Bundle { path } = &metadata.build.target {
-            let temp_dir = temp_dir.unwrap();
+        let mut text = self.gen_filelist_defines(metadata);
+        let files = if let Target::Bundle { path } = &metadata.build.target {
             let mut text = String::new();
             let target_path = base_path.join(path);
 
             for path in paths {
-                let dst = temp_dir
-                    .path()
-                    .join(path.dst.strip_prefix(&base_path).into_diagnostic()?);
-
-                text.push_str(&fs::read_to_string(&dst).into_diagnostic()?);
+                for output in &outputs[&path.src] {
+                    text.push_str(&fs::read_to_string(&output.written).into_diagnostic()?);
+                }
             }
 
             debug!("Output file ({})", target_path.to_string_lossy());
@@ -182,11 +764,14 @@ impl CmdBuild {
         } else {
             let mut text = String::new();
             for path in paths {
-                let line = self.gen_filelist_line(metadata, &path.dst)?;
-                text.push_str(&line);
+                for output in &outputs[&path.src] {
+                    let line = self.gen_filelist_line(metadata, &output.dst)?;
+                    text.push_str(&line);
+                }
             }
             text
         };
+        text.push_str(&files);
 
         info!("Output filelist ({})", filelist_path.to_string_lossy());
         let mut file = OpenOptions::new()
@@ -201,6 +786,170 @@ impl CmdBuild {
         Ok(())
     }
 
+    fn gen_dpi_header(&self, projects: &[&Metadata], path: &Path) -> Result<()> {
+        let prj_namespaces: Vec<_> = projects
+            .iter()
+            .map(|metadata| {
+                let mut prj_namespace = Namespace::new();
+                prj_namespace.push(resource_table::insert_str(&metadata.project.name));
+                prj_namespace
+            })
+            .collect();
+
+        let mut imports = String::new();
+        let mut exports = String::new();
+        for symbol in symbol_table::get_all() {
+            let SymbolKind::Function(ref x) = symbol.kind else {
+                continue;
+            };
+            if !prj_namespaces.iter().any(|x| symbol.namespace.included(x)) {
+                continue;
+            }
+
+            let text = if attribute_table::contains(&symbol.token, Attr::DpiImport) {
+                &mut imports
+            } else if attribute_table::contains(&symbol.token, Attr::DpiExport) {
+                &mut exports
+            } else {
+                continue;
+            };
+
+            let ret = x
+                .ret
+                .as_ref()
+                .map(|x| dpi_c_type(x, false))
+                .unwrap_or("void");
+            let args: Vec<_> = x
+                .ports
+                .iter()
+                .map(|port| {
+                    let property = port.property();
+                    let pointer = property.direction != Direction::Input;
+                    let r#type = property
+                        .r#type
+                        .as_ref()
+                        .map(|x| dpi_c_type(x, pointer))
+                        .unwrap_or("void*");
+                    format!("{} {}", r#type, port.name())
+                })
+                .collect();
+            let args = if args.is_empty() {
+                "void".to_string()
+            } else {
+                args.join(", ")
+            };
+            text.push_str(&format!("{} {}({});\n", ret, symbol.token, args));
+        }
+
+        let guard: String = path
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default()
+            .chars()
+            .map(|x| {
+                if x.is_ascii_alphanumeric() {
+                    x.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        let mut text = String::new();
+        text.push_str("// This file is generated by veryl. Do not edit.\n");
+        text.push_str(&format!("#ifndef {guard}\n#define {guard}\n\n"));
+        text.push_str("#include \"svdpi.h\"\n\n");
+        text.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+        if !imports.is_empty() {
+            text.push_str("\n// Functions imported to SystemVerilog\n");
+            text.push_str(&imports);
+        }
+        if !exports.is_empty() {
+            text.push_str("\n// Functions exported from SystemVerilog\n");
+            text.push_str(&exports);
+        }
+        text.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif\n");
+
+        info!("Output DPI header ({})", path.to_string_lossy());
+        fs::write(path, text).into_diagnostic()?;
+
+        Ok(())
+    }
+
+    fn gen_headers(&self, metadata: &Metadata) -> Result<()> {
+        let langs: Vec<HeaderLang> = if self.opt.headers.is_empty() {
+            metadata.build.headers.clone()
+        } else {
+            self.opt.headers.iter().map(|x| (*x).into()).collect()
+        };
+        if langs.is_empty() {
+            return Ok(());
+        }
+
+        let base_path = metadata.project_path();
+
+        for package in header::collect(metadata)? {
+            let dir = if let Some(ref path) = metadata.build.header_path {
+                base_path.join(path)
+            } else {
+                match &metadata.build.target {
+                    Target::Source => package.path.parent().unwrap().to_path_buf(),
+                    Target::Directory { path } => base_path.join(path),
+                    Target::Bundle { path } => base_path.join(path).parent().unwrap().to_path_buf(),
+                }
+            };
+            if !dir.exists() {
+                fs::create_dir_all(&dir).into_diagnostic()?;
+            }
+
+            for lang in &langs {
+                let (ext, text) = match lang {
+                    HeaderLang::C => ("h", package.to_c()),
+                    HeaderLang::Rust => ("rs", package.to_rust()),
+                };
+                let path = dir.join(format!("{}.{ext}", package.file_name()));
+
+                info!("Output header ({})", path.to_string_lossy());
+                fs::write(&path, text).into_diagnostic()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn gen_constraints(&self, metadata: &Metadata) -> Result<()> {
+        if self.opt.constraints.is_empty() {
+            return Ok(());
+        }
+
+        let dir = metadata.project_path().join("constraints");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).into_diagnostic()?;
+        }
+
+        for module in constraints::collect(metadata) {
+            for format in &self.opt.constraints {
+                let (path, text) = match format {
+                    ConstraintFormat::Sdc => {
+                        let path = dir.join(format!("{}.sdc", module.name));
+                        // Hand-edited parts outside of the generated block are kept
+                        let existing = fs::read_to_string(&path).ok();
+                        (path, module.to_sdc(existing.as_deref()))
+                    }
+                    ConstraintFormat::Json => {
+                        let path = dir.join(format!("{}.json", module.name));
+                        (path, module.to_json())
+                    }
+                };
+
+                info!("Output constraints ({})", path.to_string_lossy());
+                fs::write(&path, text).into_diagnostic()?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn sort_filelist(
         metadata: &Metadata,
         paths: &[PathSet],
@@ -253,10 +1002,67 @@ impl CmdBuild {
             }
         }
 
-        for path in used_paths.into_values() {
-            ret.push(path.clone());
+        // Files without any module, interface or package keep the original order
+        for path in paths {
+            if let Some(x) = used_paths.remove(&path.src) {
+                ret.push(x.clone());
+            }
         }
 
         ret
     }
 }
+
+/// Returns the path actually written for the output `path`, which is in temporary directory of bundle target
+fn written_path(metadata: &Metadata, temp_dir: Option<&TempDir>, path: &Path) -> Result<PathBuf> {
+    if let Some(temp_dir) = temp_dir {
+        let relative = path
+            .strip_prefix(metadata.project_path())
+            .into_diagnostic()?;
+        Ok(temp_dir.path().join(relative))
+    } else {
+        Ok(path.to_path_buf())
+    }
+}
+
+/// Returns `path` relative to `base`, which may go up with `..`
+fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let path: Vec<_> = path.components().collect();
+    let base: Vec<_> = base.components().collect();
+    let common = path.iter().zip(&base).take_while(|(a, b)| a == b).count();
+
+    let mut ret = PathBuf::new();
+    for _ in common..base.len() {
+        ret.push("..");
+    }
+    for x in &path[common..] {
+        ret.push(x);
+    }
+    ret
+}
+
+/// Returns C type corresponding to DPI-compatible type
+fn dpi_c_type(r#type: &Type, pointer: bool) -> &'static str {
+    match (&r#type.kind, pointer) {
+        (TypeKind::Bit, false) => "svBit",
+        (TypeKind::Bit, true) => "svBit*",
+        (TypeKind::Logic, false) => "svLogic",
+        (TypeKind::Logic, true) => "svLogic*",
+        (TypeKind::U32, false) => "unsigned int",
+        (TypeKind::U32, true) => "unsigned int*",
+        (TypeKind::I32, false) => "int",
+        (TypeKind::I32, true) => "int*",
+        (TypeKind::U64, false) => "unsigned long long",
+        (TypeKind::U64, true) => "unsigned long long*",
+        (TypeKind::I64, false) => "long long",
+        (TypeKind::I64, true) => "long long*",
+        (TypeKind::F32, false) => "float",
+        (TypeKind::F32, true) => "float*",
+        (TypeKind::F64, false) => "double",
+        (TypeKind::F64, true) => "double*",
+        (TypeKind::String, false) => "const char*",
+        (TypeKind::String, true) => "const char**",
+        // Invalid types are reported by analyzer
+        _ => "void*",
+    }
+}
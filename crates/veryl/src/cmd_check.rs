@@ -1,9 +1,12 @@
-use crate::OptCheck;
+use crate::{DiagnosticFormat, OptCheck};
 use log::info;
 use miette::{self, Diagnostic, IntoDiagnostic, Result, Severity, WrapErr};
+use serde::Serialize;
+use serde_json::json;
 use std::fs;
+use std::mem;
 use thiserror::Error;
-use veryl_analyzer::{Analyzer, AnalyzerError};
+use veryl_analyzer::{Analyzer, AnalyzerError, AnalyzerErrorLocation};
 use veryl_metadata::Metadata;
 use veryl_parser::Parser;
 
@@ -24,12 +27,21 @@ impl CheckError {
         self
     }
 
-    pub fn check_err(self) -> Result<Self> {
-        if self
-            .related
+    pub fn has_error(&self) -> bool {
+        self.related
+            .iter()
+            .any(|x| matches!(x.severity(), Some(Severity::Error) | None))
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.related
             .iter()
-            .all(|x| !matches!(x.severity(), Some(Severity::Error) | None))
-        {
+            .filter(|x| matches!(x.severity(), Some(Severity::Warning)))
+            .count()
+    }
+
+    pub fn check_err(self) -> Result<Self> {
+        if !self.has_error() {
             Ok(self)
         } else {
             Err(self.into())
@@ -45,15 +57,202 @@ impl CheckError {
     }
 }
 
+#[derive(Serialize)]
+struct JsonDiagnostic {
+    code: &'static str,
+    severity: &'static str,
+    default_severity: &'static str,
+    message: String,
+    help: Option<String>,
+    url: Option<String>,
+    location: Option<JsonLocation>,
+    related: Vec<JsonLocation>,
+}
+
+#[derive(Serialize)]
+struct JsonLocation {
+    path: String,
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+    label: Option<String>,
+}
+
+impl From<AnalyzerErrorLocation> for JsonLocation {
+    fn from(x: AnalyzerErrorLocation) -> Self {
+        Self {
+            path: x.path,
+            start_line: x.start_line,
+            start_column: x.start_column,
+            end_line: x.end_line,
+            end_column: x.end_column,
+            label: x.label,
+        }
+    }
+}
+
+fn severity_str(x: Option<Severity>) -> &'static str {
+    match x {
+        Some(Severity::Advice) => "advice",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Error) | None => "error",
+    }
+}
+
+fn sarif_level(x: &AnalyzerError) -> &'static str {
+    match x.severity() {
+        Some(Severity::Advice) => "note",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Error) | None => "error",
+    }
+}
+
+fn sarif_location(x: &AnalyzerErrorLocation) -> serde_json::Value {
+    json!({
+        "artifactLocation": {
+            "uri": x.path.replace('\\', "/"),
+        },
+        "region": {
+            "startLine": x.start_line,
+            "startColumn": x.start_column,
+            "endLine": x.end_line,
+            "endColumn": x.end_column,
+        },
+    })
+}
+
+/// Renders diagnostics as JSON
+pub fn render_json(errors: &[AnalyzerError]) -> Result<String> {
+    let diagnostics: Vec<_> = errors
+        .iter()
+        .map(|x| {
+            let mut locations = x.locations().into_iter().map(JsonLocation::from);
+            JsonDiagnostic {
+                code: x.error_code().as_str(),
+                severity: severity_str(x.severity()),
+                default_severity: severity_str(x.default_severity()),
+                message: x.to_string(),
+                help: x.help().map(|x| x.to_string()).filter(|x| !x.is_empty()),
+                url: x.url().map(|x| x.to_string()).filter(|x| !x.is_empty()),
+                location: locations.next(),
+                related: locations.collect(),
+            }
+        })
+        .collect();
+    serde_json::to_string_pretty(&json!({ "diagnostics": diagnostics })).into_diagnostic()
+}
+
+/// Renders diagnostics as SARIF 2.1.0
+pub fn render_sarif(errors: &[AnalyzerError]) -> Result<String> {
+    let mut rules = Vec::new();
+    let mut results = Vec::new();
+    for x in errors {
+        let code = x.error_code().as_str();
+        if !rules.iter().any(|x: &serde_json::Value| x["id"] == code) {
+            let mut rule = json!({ "id": code });
+            if let Some(url) = x.url().map(|x| x.to_string()).filter(|x| !x.is_empty()) {
+                rule["helpUri"] = json!(url);
+            }
+            rules.push(rule);
+        }
+
+        let locations = x.locations();
+        let mut result = json!({
+            "ruleId": code,
+            "level": sarif_level(x),
+            "message": { "text": x.to_string() },
+        });
+        if let Some((first, rest)) = locations.split_first() {
+            result["locations"] = json!([{ "physicalLocation": sarif_location(first) }]);
+            let related: Vec<_> = rest
+                .iter()
+                .enumerate()
+                .map(|(i, x)| {
+                    json!({
+                        "id": i,
+                        "physicalLocation": sarif_location(x),
+                        "message": { "text": x.label.clone().unwrap_or_default() },
+                    })
+                })
+                .collect();
+            if !related.is_empty() {
+                result["relatedLocations"] = json!(related);
+            }
+        }
+        results.push(result);
+    }
+
+    let sarif = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "veryl",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://veryl-lang.org",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    serde_json::to_string_pretty(&sarif).into_diagnostic()
+}
+
 impl CmdCheck {
     pub fn new(opt: OptCheck) -> Self {
         Self { opt }
     }
 
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
-        let paths = metadata.paths(&self.opt.files, true)?;
+        let check_error = self.collect(metadata)?;
+
+        let passed = if let Some(max_warnings) = self.opt.max_warnings {
+            !check_error.has_error() && check_error.warnings() <= max_warnings
+        } else {
+            check_error.related.is_empty()
+        };
+
+        match self.opt.format {
+            DiagnosticFormat::Human => {
+                if !passed {
+                    return Err(check_error.into());
+                }
+                // warnings within the limit are reported without failure
+                for x in check_error.related {
+                    eprintln!("{:?}", miette::Report::new(x));
+                }
+                Ok(true)
+            }
+            DiagnosticFormat::Json => {
+                println!("{}", render_json(&check_error.related)?);
+                Ok(passed)
+            }
+            DiagnosticFormat::Sarif => {
+                println!("{}", render_sarif(&check_error.related)?);
+                Ok(passed)
+            }
+        }
+    }
+
+    /// Collects diagnostics of the project without reporting them
+    pub fn collect(&self, metadata: &mut Metadata) -> Result<CheckError> {
+        if self.opt.unused_modules {
+            metadata.lint.unused_module = true;
+        }
 
         let mut check_error = CheckError::default();
+        self.analyze(metadata, &mut check_error)?;
+        Ok(check_error)
+    }
+
+    fn analyze(&self, metadata: &mut Metadata, check_error: &mut CheckError) -> Result<()> {
+        metadata.no_ignore = self.opt.no_ignore;
+        let mut paths = metadata.paths(&self.opt.files, true)?;
+        paths.retain(|x| metadata.is_enabled_path(x));
+
         let mut contexts = Vec::new();
 
         for path in &paths {
@@ -62,11 +261,20 @@ impl CmdCheck {
             let input = fs::read_to_string(&path.src)
                 .into_diagnostic()
                 .wrap_err("")?;
-            let parser = Parser::parse(&input, &path.src)?;
+            let mut parser = Parser::parse(&input, &path.src)?;
 
             let analyzer = Analyzer::new(metadata);
+            let mut errors = analyzer.apply_features(&path.prj, &input, &mut parser.veryl);
+            check_error.related.append(&mut errors);
+            if self.stop(check_error)? {
+                return Ok(());
+            }
+
             let mut errors = analyzer.analyze_pass1(&path.prj, &input, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+            check_error.related.append(&mut errors);
+            if self.stop(check_error)? {
+                return Ok(());
+            }
 
             contexts.push((path, input, parser, analyzer));
         }
@@ -75,15 +283,32 @@ impl CmdCheck {
 
         for (path, input, parser, analyzer) in &contexts {
             let mut errors = analyzer.analyze_pass2(&path.prj, input, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+            check_error.related.append(&mut errors);
+            if self.stop(check_error)? {
+                return Ok(());
+            }
         }
 
         for (path, input, parser, analyzer) in &contexts {
             let mut errors = analyzer.analyze_pass3(&path.prj, input, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+            check_error.related.append(&mut errors);
+            if self.stop(check_error)? {
+                return Ok(());
+            }
         }
 
-        let _ = check_error.check_all()?;
-        Ok(true)
+        Ok(())
+    }
+
+    /// Returns whether the analysis should be stopped by errors.
+    /// Errors are reported through miette immediately in human-readable format.
+    fn stop(&self, check_error: &mut CheckError) -> Result<bool> {
+        if !check_error.has_error() {
+            Ok(false)
+        } else if let DiagnosticFormat::Human = self.opt.format {
+            Err(mem::take(check_error).into())
+        } else {
+            Ok(true)
+        }
     }
 }
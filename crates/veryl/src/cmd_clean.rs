@@ -47,6 +47,12 @@ impl CmdClean {
             fs::remove_dir_all(&doc_path).into_diagnostic()?;
         }
 
+        let build_cache_path = metadata.build_cache_path();
+        if build_cache_path.exists() {
+            info!("Removing dir  ({})", build_cache_path.to_string_lossy());
+            fs::remove_dir_all(&build_cache_path).into_diagnostic()?;
+        }
+
         Ok(true)
     }
 }
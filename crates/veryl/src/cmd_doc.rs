@@ -1,19 +1,27 @@
-use crate::doc::{DocBuilder, TopLevelItem};
+use crate::doc::{dependency_docs, DocBuilder, TopLevelItem};
 use crate::OptDoc;
 use log::info;
-use miette::{IntoDiagnostic, Result, WrapErr};
-use std::collections::BTreeMap;
+use miette::{self, Diagnostic, IntoDiagnostic, Result, WrapErr};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use thiserror::Error;
 use veryl_analyzer::symbol::{SymbolId, SymbolKind};
 use veryl_analyzer::{symbol_table, Analyzer};
 use veryl_metadata::Metadata;
 use veryl_parser::resource_table;
-use veryl_parser::Parser;
+use veryl_parser::{Parser, ParserError};
 
 pub struct CmdDoc {
     opt: OptDoc,
 }
 
+#[derive(Error, Diagnostic, Debug, Default)]
+#[error("veryl doc failed because some code blocks in documents can't be parsed")]
+pub struct DocTestError {
+    #[related]
+    pub related: Vec<ParserError>,
+}
+
 impl CmdDoc {
     pub fn new(opt: OptDoc) -> Self {
         Self { opt }
@@ -47,6 +55,33 @@ impl CmdDoc {
             analyzer.analyze_pass3(&path.prj, input, &path.src, &parser.veryl);
         }
 
+        let builder = self.builder(metadata, dependency_docs(metadata)?)?;
+
+        if self.opt.test_docs {
+            let mut errors = DocTestError::default();
+            for (label, code) in builder.examples() {
+                info!("Testing code block ({})", label);
+                if let Err(x) = Parser::parse(&code, &label) {
+                    errors.related.push(x);
+                }
+            }
+            if !errors.related.is_empty() {
+                return Err(errors.into());
+            }
+        }
+
+        builder.build()?;
+
+        Ok(true)
+    }
+
+    /// Returns the document builder of the analyzed project.
+    /// `dependency_docs` maps dependency names to the base URL of their documents.
+    pub fn builder(
+        &self,
+        metadata: &Metadata,
+        dependency_docs: HashMap<String, String>,
+    ) -> Result<DocBuilder> {
         let mut modules = BTreeMap::new();
         let mut proto_modules = BTreeMap::new();
         let mut interfaces = BTreeMap::new();
@@ -104,10 +139,15 @@ impl CmdDoc {
         let interfaces: Vec<_> = interfaces.into_values().collect();
         let packages: Vec<_> = packages.into_values().collect();
 
-        let builder = DocBuilder::new(metadata, modules, proto_modules, interfaces, packages)?;
-        builder.build()?;
-
-        Ok(true)
+        DocBuilder::new(
+            metadata,
+            modules,
+            proto_modules,
+            interfaces,
+            packages,
+            self.opt.document_private_items,
+            dependency_docs,
+        )
     }
 }
 
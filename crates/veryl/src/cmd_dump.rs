@@ -1,4 +1,5 @@
-use crate::OptDump;
+use crate::hierarchy::{self, InstanceHierarchy};
+use crate::{Format, OptDump};
 use log::info;
 use miette::{IntoDiagnostic, Result, WrapErr};
 use std::fs;
@@ -67,6 +68,26 @@ impl CmdDump {
             println!("{}", veryl_analyzer::unsafe_table::dump());
         }
 
+        if self.opt.cdc_table {
+            println!("{}", veryl_analyzer::cdc_table::dump());
+        }
+
+        if self.opt.hierarchy {
+            let mut hierarchy = InstanceHierarchy::new(&metadata.project_path());
+            for (path, _, parser, _) in &contexts {
+                let project = path.prj == metadata.project.name;
+                hierarchy.collect(&parser.veryl, project);
+            }
+            let nodes = hierarchy.build(&self.opt.top)?;
+            match self.opt.format {
+                Format::Pretty => print!("{}", hierarchy::dump_tree(&nodes)),
+                Format::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&nodes).into_diagnostic()?
+                ),
+            }
+        }
+
         Ok(true)
     }
 }
@@ -6,8 +6,9 @@ use similar::{ChangeTag, TextDiff};
 use std::fmt;
 use std::fs;
 use std::fs::OpenOptions;
-use std::io::Write;
-use std::path::Path;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use veryl_formatter::Formatter;
 use veryl_metadata::Metadata;
 use veryl_parser::Parser;
@@ -22,7 +23,12 @@ impl CmdFmt {
     }
 
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
-        let paths = metadata.paths(&self.opt.files, true)?;
+        if self.opt.stdin {
+            return self.exec_stdin(metadata);
+        }
+
+        metadata.no_ignore = self.opt.no_ignore;
+        let paths = metadata.format_paths(&self.opt.files, true)?;
 
         let mut all_pass = true;
         for path in &paths {
@@ -57,6 +63,49 @@ impl CmdFmt {
 
         Ok(all_pass)
     }
+
+    fn exec_stdin(&self, metadata: &Metadata) -> Result<bool> {
+        let path = self
+            .opt
+            .stdin_path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("<stdin>"));
+
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .into_diagnostic()
+            .wrap_err("")?;
+        let output = Self::format_str(metadata, &input, &path)?;
+
+        let mut stdout = io::stdout();
+        stdout.write_all(output.as_bytes()).into_diagnostic()?;
+        stdout.flush().into_diagnostic()?;
+
+        Ok(true)
+    }
+
+    /// Loads metadata used to format the source read from stdin.
+    /// The project containing `stdin_path` is used, or default format options if not found.
+    pub fn stdin_metadata(stdin_path: Option<&Path>) -> Result<Metadata> {
+        let metadata_path = stdin_path
+            .and_then(|x| Some(std::env::current_dir().ok()?.join(x)))
+            .and_then(|x| Metadata::search_from(x).ok());
+        if let Some(metadata_path) = metadata_path {
+            Ok(Metadata::load(metadata_path)?)
+        } else {
+            let metadata = Metadata::create_default_toml("dummy").unwrap();
+            Ok(Metadata::from_str(&metadata)?)
+        }
+    }
+
+    /// Formats `input` which is treated as the content of `path`
+    pub fn format_str(metadata: &Metadata, input: &str, path: &Path) -> Result<String> {
+        let parser = Parser::parse(input, &path)?;
+        let mut formatter = Formatter::new(metadata);
+        formatter.format(&parser.veryl);
+        Ok(formatter.as_str().to_string())
+    }
 }
 
 struct Line(Option<usize>);
@@ -1,8 +1,7 @@
+use crate::template::Template;
 use crate::OptInit;
 use log::info;
 use miette::{bail, IntoDiagnostic, Result};
-use std::fs::File;
-use std::io::Write;
 use veryl_metadata::Metadata;
 
 pub struct CmdInit {
@@ -25,16 +24,10 @@ impl CmdInit {
         if let Some(name) = self.opt.path.canonicalize().into_diagnostic()?.file_name() {
             let name = name.to_string_lossy();
 
-            let toml = Metadata::create_default_toml(&name).into_diagnostic()?;
-            let toml_path = self.opt.path.join("Veryl.toml");
-
-            if toml_path.exists() {
-                bail!("\"{}\" exists", toml_path.to_string_lossy());
-            }
-
-            let mut file = File::create(toml_path).into_diagnostic()?;
-            write!(file, "{toml}").into_diagnostic()?;
-            file.flush().into_diagnostic()?;
+            // check whether the project name is valid
+            Metadata::create_default_toml(&name).into_diagnostic()?;
+            let template = Template::load(&self.opt.template)?;
+            template.generate(&self.opt.path, &name)?;
 
             info!("Created \"{}\" project", name);
         } else {
@@ -1,6 +1,52 @@
 use crate::{Format, OptMetadata};
 use miette::{IntoDiagnostic, Result};
-use veryl_metadata::Metadata;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use veryl_metadata::{Build, Metadata};
+
+/// Version of the JSON schema.
+/// This should be incremented when a field is removed or changed incompatibly.
+const SCHEMA_VERSION: u32 = 1;
+
+/// Resolved view of the workspace for build system integration
+#[derive(Serialize)]
+struct ResolvedMetadata {
+    schema_version: u32,
+    workspace_root: PathBuf,
+    root: String,
+    projects: Vec<ResolvedProject>,
+    profile: Option<String>,
+    build: Build,
+    filelist: PathBuf,
+}
+
+#[derive(Serialize)]
+struct ResolvedProject {
+    name: String,
+    version: Option<String>,
+    path: Option<PathBuf>,
+    url: Option<String>,
+    revision: Option<String>,
+    features: Vec<String>,
+    dependencies: Vec<ResolvedDependency>,
+    sources: Vec<ResolvedSource>,
+}
+
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct ResolvedDependency {
+    name: String,
+    version: String,
+    url: String,
+    revision: String,
+}
+
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord)]
+struct ResolvedSource {
+    source: PathBuf,
+    output: PathBuf,
+    source_map: PathBuf,
+}
 
 pub struct CmdMetadata {
     opt: OptMetadata,
@@ -11,14 +57,121 @@ impl CmdMetadata {
         Self { opt }
     }
 
-    pub fn exec(&self, metadata: &Metadata) -> Result<bool> {
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        println!("{}", self.render(metadata)?);
+
+        Ok(true)
+    }
+
+    /// Renders metadata in the specified format
+    pub fn render(&self, metadata: &mut Metadata) -> Result<String> {
+        if let Some(ref profile) = self.opt.profile {
+            metadata.apply_profile(profile)?;
+        }
+
         let text = match self.opt.format {
-            Format::Json => serde_json::to_string(metadata).into_diagnostic()?,
+            Format::Json => {
+                let resolved = Self::resolve(metadata)?;
+                // Value sorts keys of objects for deterministic output
+                let value = serde_json::to_value(resolved).into_diagnostic()?;
+                serde_json::to_string(&value).into_diagnostic()?
+            }
             Format::Pretty => format!("{metadata:#?}"),
         };
 
-        println!("{text}");
+        Ok(text)
+    }
+
+    fn resolve(metadata: &mut Metadata) -> Result<ResolvedMetadata> {
+        let paths = metadata.paths::<PathBuf>(&[], true)?;
 
-        Ok(true)
+        let mut projects: BTreeMap<String, ResolvedProject> = BTreeMap::new();
+        let project = |name: &str| ResolvedProject {
+            name: name.to_string(),
+            version: None,
+            path: None,
+            url: None,
+            revision: None,
+            features: Vec::new(),
+            dependencies: Vec::new(),
+            sources: Vec::new(),
+        };
+
+        let mut root = project(&metadata.project.name);
+        root.version = Some(metadata.project.version.to_string());
+        root.path = Some(metadata.project_path());
+        for (url, dep) in &metadata.dependencies {
+            for entry in dep.entries() {
+                let lock = metadata
+                    .lockfile
+                    .lock_table
+                    .get(url)
+                    .and_then(|x| x.iter().find(|x| entry.version.matches(&x.version)));
+                if let Some(lock) = lock {
+                    root.dependencies.push(ResolvedDependency {
+                        name: lock.name.clone(),
+                        version: lock.version.to_string(),
+                        url: lock.url.to_string(),
+                        revision: lock.revision.clone(),
+                    });
+                }
+            }
+        }
+        projects.insert(root.name.clone(), root);
+
+        for (lock, path) in metadata.lockfile.project_paths()? {
+            let mut x = project(&lock.name);
+            x.version = Some(lock.version.to_string());
+            x.path = Some(path);
+            x.url = Some(lock.url.to_string());
+            x.revision = Some(lock.revision.clone());
+            x.dependencies = lock
+                .dependencies
+                .iter()
+                .map(|x| ResolvedDependency {
+                    name: x.name.clone(),
+                    version: x.version.to_string(),
+                    url: x.url.to_string(),
+                    revision: x.revision.clone(),
+                })
+                .collect();
+            projects.insert(x.name.clone(), x);
+        }
+
+        for path in &paths {
+            if !metadata.is_enabled_path(path) {
+                continue;
+            }
+            let x = projects
+                .entry(path.prj.clone())
+                .or_insert_with(|| project(&path.prj));
+            x.sources.push(ResolvedSource {
+                source: path.src.clone(),
+                output: path.dst.clone(),
+                source_map: path.map.clone(),
+            });
+        }
+
+        for (name, features) in &metadata.feature_table {
+            if let Some(x) = projects.get_mut(name) {
+                x.features = features.enabled.iter().cloned().collect();
+            }
+        }
+
+        let mut projects: Vec<_> = projects.into_values().collect();
+        for x in &mut projects {
+            x.dependencies.sort();
+            x.sources.sort();
+        }
+
+        Ok(ResolvedMetadata {
+            schema_version: SCHEMA_VERSION,
+            workspace_root: metadata.project_path(),
+            root: metadata.project.name.clone(),
+            projects,
+            profile: metadata.profile.clone(),
+            build: metadata.build.clone(),
+            filelist: metadata.filelist_path(),
+        })
     }
 }
@@ -1,8 +1,7 @@
+use crate::template::{Template, TemplateOrigin};
 use crate::OptNew;
 use log::info;
 use miette::{bail, IntoDiagnostic, Result};
-use std::fs::{self, File};
-use std::io::Write;
 use veryl_metadata::Metadata;
 
 pub struct CmdNew {
@@ -15,22 +14,36 @@ impl CmdNew {
     }
 
     pub fn exec(&self) -> Result<bool> {
-        if self.opt.path.exists() {
-            bail!("path \"{}\" exists", self.opt.path.to_string_lossy());
+        if self.opt.list_templates {
+            for template in Template::list()? {
+                let origin = match template.origin {
+                    TemplateOrigin::Builtin => "built-in".to_string(),
+                    TemplateOrigin::Local(ref x) => x.to_string_lossy().to_string(),
+                };
+                println!(
+                    "{:<16} {} ({})",
+                    template.name, template.description, origin
+                );
+            }
+            return Ok(true);
         }
 
-        if let Some(name) = self.opt.path.file_name() {
+        let path = self.opt.path.as_ref().unwrap();
+        if path.exists() {
+            bail!("path \"{}\" exists", path.to_string_lossy());
+        }
+
+        if let Some(name) = path.file_name() {
             let name = name.to_string_lossy();
-            let toml = Metadata::create_default_toml(&name).into_diagnostic()?;
+            // check whether the project name is valid
+            Metadata::create_default_toml(&name).into_diagnostic()?;
+            let template = Template::load(&self.opt.template)?;
 
-            fs::create_dir_all(&self.opt.path).into_diagnostic()?;
-            let mut file = File::create(self.opt.path.join("Veryl.toml")).into_diagnostic()?;
-            write!(file, "{toml}").into_diagnostic()?;
-            file.flush().into_diagnostic()?;
+            template.generate(path, &name)?;
 
             info!("Created \"{}\" project", name);
         } else {
-            bail!("path \"{}\" is not valid", self.opt.path.to_string_lossy());
+            bail!("path \"{}\" is not valid", path.to_string_lossy());
         }
 
         Ok(true)
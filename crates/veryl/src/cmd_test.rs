@@ -1,8 +1,9 @@
 use crate::cmd_build::CmdBuild;
-use crate::runner::{Cocotb, CocotbSource, Vcs, Verilator, Vivado};
+use crate::runner::{check_executables, Cocotb, CocotbSource, Questa, Vcs, Verilator, Vivado};
 use crate::{OptBuild, OptTest};
 use log::{error, info};
-use miette::Result;
+use miette::{IntoDiagnostic, Result};
+use std::time::{Duration, Instant};
 use veryl_analyzer::symbol::{SymbolKind, TestType};
 use veryl_analyzer::symbol_table;
 use veryl_metadata::{FilelistType, Metadata, SimType};
@@ -19,9 +20,12 @@ impl CmdTest {
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
         // force filelist_type to absolute which can be refered from temporary directory
         metadata.build.filelist_type = FilelistType::Absolute;
+        // assertions are required for simulation
+        metadata.build.strip_assertions = false;
 
         let build = CmdBuild::new(OptBuild {
             files: self.opt.files.clone(),
+            ..Default::default()
         });
         build.exec(metadata, true)?;
 
@@ -38,6 +42,13 @@ impl CmdTest {
                     None
                 }
             })
+            .filter(|(test, _)| {
+                if let Some(ref filter) = self.opt.filter {
+                    test.to_string().contains(filter)
+                } else {
+                    true
+                }
+            })
             .collect();
 
         let sim_type = if let Some(x) = self.opt.sim {
@@ -46,26 +57,52 @@ impl CmdTest {
             metadata.test.simulator
         };
 
-        let mut success = 0;
-        let mut failure = 0;
+        let mut results = Vec::new();
         for (test, property) in &tests {
             let mut runner = match property.r#type {
                 TestType::Inline => match sim_type {
                     SimType::Verilator => Verilator::new().runner(),
                     SimType::Vcs => Vcs::new().runner(),
                     SimType::Vivado => Vivado::new().runner(),
+                    SimType::Questa => Questa::new().runner(),
                 },
                 TestType::CocotbEmbed(x) => Cocotb::new(CocotbSource::Embed(x)).runner(),
                 TestType::CocotbInclude(x) => Cocotb::new(CocotbSource::Include(x)).runner(),
             };
 
-            if runner.run(metadata, *test, property.top, property.path, self.opt.wave)? {
-                success += 1;
-            } else {
-                failure += 1;
+            check_executables(runner.as_ref())?;
+
+            let temp_dir = tempfile::tempdir().into_diagnostic()?;
+
+            let start = Instant::now();
+            let result = runner.run(
+                metadata,
+                *test,
+                property.top,
+                property.path,
+                temp_dir.path(),
+                self.opt.wave,
+            )?;
+            let elapsed = start.elapsed();
+
+            if self.opt.keep_artifacts {
+                let path = temp_dir.into_path();
+                info!(
+                    "Kept artifacts of test ({}) : {}",
+                    test,
+                    path.to_string_lossy()
+                );
             }
+
+            results.push((test.to_string(), result, elapsed));
         }
 
+        if !results.is_empty() {
+            print_summary(&results);
+        }
+
+        let success = results.iter().filter(|(_, x, _)| *x).count();
+        let failure = results.len() - success;
         if failure == 0 {
             info!("Completed tests : {} passed, {} failed", success, failure);
             Ok(true)
@@ -75,3 +112,24 @@ impl CmdTest {
         }
     }
 }
+
+fn print_summary(results: &[(String, bool, Duration)]) {
+    let width = results
+        .iter()
+        .map(|(x, _, _)| x.len())
+        .max()
+        .unwrap_or(0)
+        .max("Test".len());
+
+    info!("{:width$}  {:6}  {:>10}", "Test", "Result", "Time");
+    info!("{}", "-".repeat(width + 20));
+    for (test, result, elapsed) in results {
+        let result = if *result { "passed" } else { "failed" };
+        info!(
+            "{:width$}  {:6}  {:>9.2}s",
+            test,
+            result,
+            elapsed.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use crate::OptVendor;
+use miette::Result;
+use veryl_metadata::Metadata;
+
+pub struct CmdVendor {
+    _opt: OptVendor,
+}
+
+impl CmdVendor {
+    pub fn new(opt: OptVendor) -> Self {
+        Self { _opt: opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        metadata.vendor()?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,299 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use veryl_analyzer::attribute::{self, Attribute as Attr};
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{ClockDomain, Direction, Symbol, SymbolId, SymbolKind};
+use veryl_analyzer::{attribute_table, cdc_table, symbol_table, type_dag};
+use veryl_emitter::emitter::{symbol_string, SymbolContext};
+use veryl_metadata::Metadata;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_token::{Token, TokenSource, VerylToken};
+
+const BLOCK_BEGIN: &str =
+    "# ---- veryl: begin generated constraints (edit outside of this block) ----";
+const BLOCK_END: &str = "# ---- veryl: end generated constraints ----";
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Clock {
+    /// Port name
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Period in nanoseconds specified by `period` attribute
+    pub period: Option<f64>,
+    pub source: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Crossing {
+    /// Module including the crossing
+    pub module: String,
+    pub from: String,
+    pub to: String,
+    /// Clock ports of the source domain, which are collected only if the crossing is in the top module
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub from_clocks: Vec<String>,
+    /// Clock ports of the destination domain, which are collected only if the crossing is in the top module
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub to_clocks: Vec<String>,
+    pub source: String,
+}
+
+/// Clocks and clock domain crossings of a top module
+#[derive(Clone, Debug, Serialize)]
+pub struct ModuleConstraints {
+    /// Emitted name of the top module
+    pub name: String,
+    pub source: String,
+    pub clocks: Vec<Clock>,
+    pub crossings: Vec<Crossing>,
+}
+
+/// Collects constraints of top modules in the project.
+/// Testbench modules and generic modules are not treated as top modules.
+pub fn collect(metadata: &Metadata) -> Vec<ModuleConstraints> {
+    let base = metadata.project_path();
+    let project_name = resource_table::insert_str(&metadata.project.name);
+    let mut prj_namespace = Namespace::new();
+    prj_namespace.push(project_name);
+
+    let context = SymbolContext {
+        project_name: Some(project_name),
+        build_opt: metadata.build.clone(),
+        in_import: false,
+        generic_map: Vec::new(),
+    };
+
+    let is_testbench = |x: &Symbol| attribute_table::contains(&x.token, Attr::Testbench);
+
+    let mut children: HashMap<SymbolId, Vec<SymbolId>> = HashMap::new();
+    for (parent, child, _) in type_dag::instances() {
+        let parent = type_dag::get_symbol(parent);
+        if !is_testbench(&parent) {
+            let child = type_dag::get_symbol(child);
+            children.entry(parent.id).or_default().push(child.id);
+        }
+    }
+    let instantiated: HashSet<_> = children.values().flatten().copied().collect();
+
+    let mut tops: Vec<_> = symbol_table::get_all()
+        .into_iter()
+        .filter(|x| match x.kind {
+            SymbolKind::Module(ref module) => {
+                x.namespace.included(&prj_namespace)
+                    && module.generic_parameters.is_empty()
+                    && !is_testbench(x)
+                    && !instantiated.contains(&x.id)
+            }
+            _ => false,
+        })
+        .collect();
+    tops.sort_by_key(|x| (x.token.source.to_string(), x.token.line));
+
+    let mut crossings = cdc_table::get_all();
+    crossings.sort_by_key(|x| {
+        let token = x.range.beg;
+        (token.source.to_string(), token.line, token.column)
+    });
+
+    let mut ret = Vec::new();
+    for top in tops {
+        let SymbolKind::Module(ref module) = top.kind else {
+            unreachable!();
+        };
+
+        let mut clocks = Vec::new();
+        let mut domain_clocks: HashMap<ClockDomain, Vec<String>> = HashMap::new();
+        for port in &module.ports {
+            let property = port.property();
+            let is_clock = property.r#type.as_ref().is_some_and(|x| x.kind.is_clock());
+            if !is_clock || property.direction != Direction::Input {
+                continue;
+            }
+
+            let name = name(&property.token);
+            let period = attribute_table::get(&property.token)
+                .iter()
+                .find_map(|x| match x {
+                    Attr::Period(x) => attribute::period_ns(&x.to_string()),
+                    _ => None,
+                });
+            domain_clocks
+                .entry(property.clock_domain)
+                .or_default()
+                .push(name.clone());
+            clocks.push(Clock {
+                name,
+                domain: domain_name(&property.clock_domain),
+                period,
+                source: source(&property.token, &base),
+            });
+        }
+
+        // Modules reachable from the top module through instances
+        let mut modules = HashSet::new();
+        let mut stack = vec![top.id];
+        while let Some(id) = stack.pop() {
+            if modules.insert(id) {
+                if let Some(x) = children.get(&id) {
+                    stack.extend(x);
+                }
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut top_crossings = Vec::new();
+        for x in &crossings {
+            if !modules.contains(&x.module) || !visited.insert((x.module, x.from, x.to)) {
+                continue;
+            }
+            let Some(module) = symbol_table::get(x.module) else {
+                continue;
+            };
+
+            let (from_clocks, to_clocks) = if x.module == top.id {
+                (
+                    domain_clocks.get(&x.from).cloned().unwrap_or_default(),
+                    domain_clocks.get(&x.to).cloned().unwrap_or_default(),
+                )
+            } else {
+                (Vec::new(), Vec::new())
+            };
+
+            top_crossings.push(Crossing {
+                module: name(&module.token),
+                from: domain_name(&x.from).unwrap_or_default(),
+                to: domain_name(&x.to).unwrap_or_default(),
+                from_clocks,
+                to_clocks,
+                source: source(&x.range.beg, &base),
+            });
+        }
+
+        ret.push(ModuleConstraints {
+            name: symbol_string(&VerylToken::new(top.token), &top, &context),
+            source: source(&top.token, &base),
+            clocks,
+            crossings: top_crossings,
+        });
+    }
+    ret
+}
+
+impl ModuleConstraints {
+    /// Returns SDC text merged with `existing` file.
+    /// Only the generated block is replaced, and the other hand-edited parts are kept.
+    pub fn to_sdc(&self, existing: Option<&str>) -> String {
+        let block = self.sdc_block();
+
+        let Some(existing) = existing else {
+            return block;
+        };
+
+        let begin = existing.find(BLOCK_BEGIN);
+        let end = existing.find(BLOCK_END);
+        match (begin, end) {
+            (Some(begin), Some(end)) if begin < end => {
+                let rest = &existing[end + BLOCK_END.len()..];
+                let rest = rest.strip_prefix('\n').unwrap_or(rest);
+                format!("{}{}{}", &existing[..begin], block, rest)
+            }
+            _ if existing.trim().is_empty() => block,
+            _ => format!("{}\n{}", existing.trim_end(), block),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!("{}\n", serde_json::to_string_pretty(self).unwrap())
+    }
+
+    fn sdc_block(&self) -> String {
+        let mut ret = String::new();
+        ret.push_str(BLOCK_BEGIN);
+        ret.push('\n');
+        ret.push_str(&format!(
+            "# Constraints of {} ({}) generated by veryl\n",
+            self.name, self.source
+        ));
+
+        if !self.clocks.is_empty() {
+            ret.push_str("\n# Clocks\n");
+        }
+        for clock in &self.clocks {
+            let domain = clock.domain.as_deref().unwrap_or("default");
+            ret.push_str(&format!("# {} (clock domain: {domain})\n", clock.source));
+            if let Some(period) = clock.period {
+                ret.push_str(&format!(
+                    "create_clock -name {name} -period {period:.3} [get_ports {name}]\n",
+                    name = clock.name
+                ));
+            } else {
+                ret.push_str("# TODO: specify period by `#[period(\"...\")]` attribute\n");
+                ret.push_str(&format!(
+                    "# create_clock -name {name} -period <period> [get_ports {name}]\n",
+                    name = clock.name
+                ));
+            }
+        }
+
+        if !self.crossings.is_empty() {
+            ret.push_str("\n# Clock domain crossings\n");
+        }
+        for x in &self.crossings {
+            ret.push_str(&format!(
+                "# {} -> {} in {} ({})\n",
+                x.from, x.to, x.module, x.source
+            ));
+            if x.from_clocks.is_empty() || x.to_clocks.is_empty() {
+                ret.push_str("# TODO: constrain the crossing between clocks of the top module\n");
+            } else {
+                ret.push_str(&format!(
+                    "set_false_path -from [get_clocks {}] -to [get_clocks {}]\n",
+                    tcl_list(&x.from_clocks),
+                    tcl_list(&x.to_clocks)
+                ));
+            }
+        }
+
+        ret.push_str(BLOCK_END);
+        ret.push('\n');
+        ret
+    }
+}
+
+fn tcl_list(x: &[String]) -> String {
+    if x.len() == 1 {
+        x[0].clone()
+    } else {
+        format!("{{{}}}", x.join(" "))
+    }
+}
+
+fn domain_name(x: &ClockDomain) -> Option<String> {
+    match x {
+        ClockDomain::None => None,
+        x => Some(x.to_string()),
+    }
+}
+
+fn name(token: &Token) -> String {
+    let text = token.to_string();
+    text.strip_prefix("r#")
+        .map(|x| x.to_string())
+        .unwrap_or(text)
+}
+
+fn source(token: &Token, base: &Path) -> String {
+    let path = match token.source {
+        TokenSource::File(x) => {
+            let path = resource_table::get_path_value(x).unwrap();
+            match path.strip_prefix(base) {
+                Ok(x) => x.to_string_lossy().to_string(),
+                Err(_) => path.to_string_lossy().to_string(),
+            }
+        }
+        x => x.to_string(),
+    };
+    format!("{}:{}:{}", path, token.line, token.column)
+}
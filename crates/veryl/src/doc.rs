@@ -1,4 +1,5 @@
 mod doc_builder;
+mod graph;
 mod mermaid;
 mod utils;
 mod wavedrom;
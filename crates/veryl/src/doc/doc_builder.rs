@@ -1,17 +1,23 @@
+use crate::doc::graph::{Graph, GraphNode};
+use crate::doc::utils::{markdown_to_html, veryl_code_blocks};
 use crate::doc::{Mermaid, Wavedrom};
 use handlebars::Handlebars;
 use mdbook::{Config, MDBook};
-use miette::{IntoDiagnostic, Result};
+use miette::{IntoDiagnostic, Result, WrapErr};
 use serde::Serialize;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
 use tempfile::TempDir;
-use veryl_analyzer::symbol::{ClockDomain, ParameterKind, Symbol, SymbolKind};
-use veryl_analyzer::symbol_table;
+use veryl_analyzer::attribute::Attribute as Attr;
+use veryl_analyzer::symbol::{
+    ClockDomain, ParameterKind, Symbol, SymbolId, SymbolKind, Type, TypeKind,
+};
+use veryl_analyzer::{attribute_table, symbol_table, type_dag};
 use veryl_metadata::Metadata;
-use veryl_parser::veryl_token::Token;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_token::{Token, TokenSource};
 
 const SUMMARY_TMPL: &str = r###"
 # Summary
@@ -78,6 +84,10 @@ const INDEX_TMPL: &str = r###"
 </tbody>
 </table>
 
+{{#if readme}}
+{{readme}}
+{{/if}}
+
 {{{{raw}}}}
 {{#include modules.md}}
 {{#include proto_modules.md}}
@@ -93,6 +103,7 @@ struct IndexData {
     version: String,
     repository: Option<String>,
     license: Option<String>,
+    readme: Option<String>,
 }
 
 const LIST_TMPL: &str = r###"
@@ -198,6 +209,43 @@ const MODULE_TMPL: &str = r#"
 </tbody>
 </table>
 {{/if}}
+
+{{#if instantiates}}
+### Instantiates
+---
+
+<table class="table_list">
+<tbody>
+{{#each instantiates}}
+<tr>
+    <th class="table_list_item">{{this}}</th>
+</tr>
+{{/each}}
+</tbody>
+</table>
+{{/if}}
+
+{{#if instantiated_by}}
+### Instantiated By
+---
+
+<table class="table_list">
+<tbody>
+{{#each instantiated_by}}
+<tr>
+    <th class="table_list_item">{{this}}</th>
+</tr>
+{{/each}}
+</tbody>
+</table>
+{{/if}}
+
+{{#if dependencies}}
+### Dependencies
+---
+
+{{dependencies}}
+{{/if}}
 "#;
 
 #[derive(Serialize)]
@@ -208,6 +256,9 @@ struct ModuleData {
     parameters: Vec<ParameterData>,
     clock_domains: Vec<String>,
     ports: Vec<PortData>,
+    instantiates: Vec<String>,
+    instantiated_by: Vec<String>,
+    dependencies: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -321,6 +372,13 @@ const INTERFACE_TMPL: &str = r#"
 </tbody>
 </table>
 {{/if}}
+
+{{#if dependencies}}
+### Dependencies
+---
+
+{{dependencies}}
+{{/if}}
 "#;
 
 #[derive(Serialize)]
@@ -328,6 +386,7 @@ struct InterfaceData {
     name: String,
     description: String,
     parameters: Vec<ParameterData>,
+    dependencies: Option<String>,
 }
 
 const PACKAGE_TMPL: &str = r###"
@@ -335,12 +394,19 @@ const PACKAGE_TMPL: &str = r###"
 
 {{description}}
 
+{{#if dependencies}}
+### Dependencies
+---
+
+{{dependencies}}
+{{/if}}
 "###;
 
 #[derive(Serialize)]
 struct PackageData {
     name: String,
     description: String,
+    dependencies: Option<String>,
 }
 
 pub struct DocBuilder {
@@ -354,6 +420,13 @@ pub struct DocBuilder {
     proto_modules: Vec<TopLevelItem>,
     interfaces: Vec<TopLevelItem>,
     packages: Vec<TopLevelItem>,
+    pages: HashSet<String>,
+    readme: Option<String>,
+    included_docs: HashMap<SymbolId, String>,
+    document_private_items: bool,
+    instantiates: HashMap<SymbolId, BTreeMap<String, Symbol>>,
+    instantiated_by: HashMap<SymbolId, BTreeMap<String, Symbol>>,
+    dependency_docs: HashMap<String, String>,
 }
 
 #[derive(Clone)]
@@ -370,6 +443,8 @@ impl DocBuilder {
         proto_modules: Vec<TopLevelItem>,
         interfaces: Vec<TopLevelItem>,
         packages: Vec<TopLevelItem>,
+        document_private_items: bool,
+        dependency_docs: HashMap<String, String>,
     ) -> Result<Self> {
         let temp_dir = tempfile::tempdir().into_diagnostic()?;
         let root_dir = temp_dir.path().to_path_buf();
@@ -378,6 +453,68 @@ impl DocBuilder {
         fs::create_dir(&src_dir).into_diagnostic()?;
         fs::create_dir(&theme_dir).into_diagnostic()?;
 
+        let pages: HashSet<_> = modules
+            .iter()
+            .chain(proto_modules.iter())
+            .chain(interfaces.iter())
+            .chain(packages.iter())
+            .map(|x| x.file_name.clone())
+            .collect();
+
+        let readme = metadata.project_path().join("README.md");
+        let readme = if readme.exists() {
+            let text = fs::read_to_string(&readme)
+                .into_diagnostic()
+                .wrap_err(format!("failed to read {}", readme.to_string_lossy()))?;
+            Some(text)
+        } else {
+            None
+        };
+
+        let mut included_docs = HashMap::new();
+        for x in modules
+            .iter()
+            .chain(proto_modules.iter())
+            .chain(interfaces.iter())
+            .chain(packages.iter())
+        {
+            if let Some(text) = read_included_docs(&x.symbol)? {
+                included_docs.insert(x.symbol.id, text);
+            }
+        }
+
+        let mut instantiates: HashMap<_, BTreeMap<_, _>> = HashMap::new();
+        let mut instantiated_by: HashMap<_, BTreeMap<_, _>> = HashMap::new();
+        for symbol in symbol_table::get_all() {
+            if let SymbolKind::Instance(x) = &symbol.kind {
+                let parent = if is_project_symbol(&symbol, metadata) {
+                    symbol.namespace.paths.get(1).and_then(|name| {
+                        modules
+                            .iter()
+                            .find(|x| x.symbol.token.text == *name)
+                            .map(|x| x.symbol.clone())
+                    })
+                } else {
+                    None
+                };
+                let child = symbol_table::resolve((&x.type_name.generic_path(), &symbol.namespace))
+                    .ok()
+                    .map(|x| x.found)
+                    .filter(|x| matches!(x.kind, SymbolKind::Module(_)));
+
+                if let (Some(parent), Some(child)) = (parent, child) {
+                    instantiated_by
+                        .entry(child.id)
+                        .or_default()
+                        .insert(symbol_label(&parent, metadata), parent.clone());
+                    instantiates
+                        .entry(parent.id)
+                        .or_default()
+                        .insert(symbol_label(&child, metadata), child);
+                }
+            }
+        }
+
         Ok(Self {
             metadata: metadata.clone(),
             temp_dir,
@@ -388,37 +525,21 @@ impl DocBuilder {
             proto_modules,
             interfaces,
             packages,
+            pages,
+            readme,
+            included_docs,
+            document_private_items,
+            instantiates,
+            instantiated_by,
+            dependency_docs,
         })
     }
 
     pub fn build(&self) -> Result<()> {
         self.build_theme()?;
 
-        self.build_component("SUMMARY.md", self.build_summary())?;
-        self.build_component("index.md", self.build_index())?;
-        self.build_component("modules.md", self.build_modules())?;
-        self.build_component("proto_modules.md", self.build_proto_modules())?;
-        self.build_component("interfaces.md", self.build_interfaces())?;
-        self.build_component("packages.md", self.build_packages())?;
-
-        for x in &self.modules {
-            let file = format!("{}.md", x.file_name);
-            self.build_component(&file, self.build_module(&x.html_name, &x.symbol))?;
-        }
-
-        for x in &self.proto_modules {
-            let file = format!("{}.md", x.file_name);
-            self.build_component(&file, self.build_proto_module(&x.html_name, &x.symbol))?;
-        }
-
-        for x in &self.interfaces {
-            let file = format!("{}.md", x.file_name);
-            self.build_component(&file, self.build_interface(&x.html_name, &x.symbol))?;
-        }
-
-        for x in &self.packages {
-            let file = format!("{}.md", x.file_name);
-            self.build_component(&file, self.build_package(&x.html_name, &x.symbol))?;
+        for (name, content) in self.render() {
+            self.build_component(&name, content)?;
         }
 
         let mut cfg = Config::default();
@@ -426,6 +547,7 @@ impl DocBuilder {
         cfg.set("output.html.no-section-label", true).unwrap();
         cfg.set("output.html.fold.enable", true).unwrap();
         cfg.set("output.html.fold.level", 1).unwrap();
+        cfg.set("output.html.search.enable", true).unwrap();
         cfg.set("output.html.additional-css", vec!["theme/custom.css"])
             .unwrap();
         cfg.set(
@@ -434,6 +556,7 @@ impl DocBuilder {
                 "theme/wavedrom.min.js",
                 "theme/wavedrom_skin.js",
                 "theme/mermaid.min.js",
+                "theme/veryl.js",
             ],
         )
         .unwrap();
@@ -447,6 +570,40 @@ impl DocBuilder {
         Ok(())
     }
 
+    /// Returns markdown files of the book and their contents
+    pub fn render(&self) -> Vec<(String, String)> {
+        let mut ret = vec![
+            ("SUMMARY.md".to_string(), self.build_summary()),
+            ("index.md".to_string(), self.build_index()),
+            ("modules.md".to_string(), self.build_modules()),
+            ("proto_modules.md".to_string(), self.build_proto_modules()),
+            ("interfaces.md".to_string(), self.build_interfaces()),
+            ("packages.md".to_string(), self.build_packages()),
+        ];
+
+        for x in &self.modules {
+            let file = format!("{}.md", x.file_name);
+            ret.push((file, self.build_module(&x.html_name, &x.symbol)));
+        }
+
+        for x in &self.proto_modules {
+            let file = format!("{}.md", x.file_name);
+            ret.push((file, self.build_proto_module(&x.html_name, &x.symbol)));
+        }
+
+        for x in &self.interfaces {
+            let file = format!("{}.md", x.file_name);
+            ret.push((file, self.build_interface(&x.html_name, &x.symbol)));
+        }
+
+        for x in &self.packages {
+            let file = format!("{}.md", x.file_name);
+            ret.push((file, self.build_package(&x.html_name, &x.symbol)));
+        }
+
+        ret
+    }
+
     fn build_theme(&self) -> Result<()> {
         let custom_css = r##"
 .affix {
@@ -490,6 +647,11 @@ impl DocBuilder {
         let mut file = File::create(file).into_diagnostic()?;
         file.write(mermaid).into_diagnostic()?;
 
+        let veryl = include_bytes!("../../resource/highlightjs/veryl.js");
+        let file = self.theme_dir.join("veryl.js");
+        let mut file = File::create(file).into_diagnostic()?;
+        file.write(veryl).into_diagnostic()?;
+
         Ok(())
     }
 
@@ -546,6 +708,7 @@ impl DocBuilder {
             description: self.metadata.project.description.clone(),
             repository: self.metadata.project.repository.clone(),
             license: self.metadata.project.license.clone(),
+            readme: self.readme.clone(),
         };
 
         let mut handlebars = Handlebars::new();
@@ -560,7 +723,7 @@ impl DocBuilder {
             .map(|x| ListItem {
                 file_name: x.file_name.clone(),
                 html_name: x.html_name.clone(),
-                description: x.symbol.doc_comment.format(true),
+                description: markdown_to_html(&x.symbol.doc_comment.format(true)),
             })
             .collect();
 
@@ -581,7 +744,7 @@ impl DocBuilder {
             .map(|x| ListItem {
                 file_name: x.file_name.clone(),
                 html_name: x.html_name.clone(),
-                description: x.symbol.doc_comment.format(true),
+                description: markdown_to_html(&x.symbol.doc_comment.format(true)),
             })
             .collect();
 
@@ -602,7 +765,7 @@ impl DocBuilder {
             .map(|x| ListItem {
                 file_name: x.file_name.clone(),
                 html_name: x.html_name.clone(),
-                description: x.symbol.doc_comment.format(true),
+                description: markdown_to_html(&x.symbol.doc_comment.format(true)),
             })
             .collect();
 
@@ -623,7 +786,7 @@ impl DocBuilder {
             .map(|x| ListItem {
                 file_name: x.file_name.clone(),
                 html_name: x.html_name.clone(),
-                description: x.symbol.doc_comment.format(true),
+                description: markdown_to_html(&x.symbol.doc_comment.format(true)),
             })
             .collect();
 
@@ -661,7 +824,7 @@ impl DocBuilder {
                 .filter(|x| matches!(x.property().kind, ParameterKind::Param,))
                 .map(|x| ParameterData {
                     name: x.name.to_string(),
-                    typ: format!("{}", x.property().r#type),
+                    typ: self.fmt_type(&x.property().r#type),
                     description: get_comment_from_token(&x.property().token),
                 })
                 .collect();
@@ -693,19 +856,25 @@ impl DocBuilder {
                         name: x.name().to_string(),
                         direction: format!("{}", x.property().direction),
                         clock_domain,
-                        typ: x.property().r#type.as_ref().map(|x| format!("{}", x)),
+                        typ: x.property().r#type.as_ref().map(|x| self.fmt_type(x)),
                         description: get_comment_from_token(&x.property().token),
                     }
                 })
                 .collect();
 
+            let instantiates = self.fmt_instances(self.instantiates.get(&symbol.id));
+            let instantiated_by = self.fmt_instances(self.instantiated_by.get(&symbol.id));
+
             let data = ModuleData {
                 name: name.to_string(),
-                description: symbol.doc_comment.format(false),
+                description: self.description(symbol),
                 generic_parameters,
                 parameters,
                 clock_domains,
                 ports,
+                instantiates,
+                instantiated_by,
+                dependencies: self.build_dependency_graph(symbol),
             };
 
             let mut handlebars = Handlebars::new();
@@ -724,7 +893,7 @@ impl DocBuilder {
                 .filter(|x| matches!(x.property().kind, ParameterKind::Param,))
                 .map(|x| ParameterData {
                     name: x.name.to_string(),
-                    typ: format!("{}", x.property().r#type),
+                    typ: self.fmt_type(&x.property().r#type),
                     description: get_comment_from_token(&x.property().token),
                 })
                 .collect();
@@ -756,7 +925,7 @@ impl DocBuilder {
                         name: x.name().to_string(),
                         direction: format!("{}", x.property().direction),
                         clock_domain,
-                        typ: x.property().r#type.as_ref().map(|x| format!("{}", x)),
+                        typ: x.property().r#type.as_ref().map(|x| self.fmt_type(x)),
                         description: get_comment_from_token(&x.property().token),
                     }
                 })
@@ -764,7 +933,7 @@ impl DocBuilder {
 
             let data = ProtoModuleData {
                 name: name.to_string(),
-                description: symbol.doc_comment.format(false),
+                description: self.description(symbol),
                 parameters,
                 clock_domains,
                 ports,
@@ -788,15 +957,16 @@ impl DocBuilder {
                 .filter(|x| matches!(x.property().kind, ParameterKind::Param,))
                 .map(|x| ParameterData {
                     name: x.name.to_string(),
-                    typ: format!("{}", x.property().r#type),
+                    typ: self.fmt_type(&x.property().r#type),
                     description: get_comment_from_token(&x.property().token),
                 })
                 .collect();
 
             let data = InterfaceData {
                 name: name.to_string(),
-                description: symbol.doc_comment.format(false),
+                description: self.description(symbol),
                 parameters,
+                dependencies: self.build_dependency_graph(symbol),
             };
 
             let mut handlebars = Handlebars::new();
@@ -811,7 +981,8 @@ impl DocBuilder {
         if let SymbolKind::Package(_) = &symbol.kind {
             let data = PackageData {
                 name: name.to_string(),
-                description: symbol.doc_comment.format(false),
+                description: self.description(symbol),
+                dependencies: self.build_dependency_graph(symbol),
             };
 
             let mut handlebars = Handlebars::new();
@@ -821,11 +992,229 @@ impl DocBuilder {
             String::new()
         }
     }
+
+    /// Returns doc comments followed by documents included by `doc_include` attribute
+    fn description(&self, symbol: &Symbol) -> String {
+        let mut ret = symbol.doc_comment.format(false);
+        if let Some(x) = self.included_docs.get(&symbol.id) {
+            ret.push('\n');
+            ret.push_str(x);
+        }
+        ret
+    }
+
+    /// Returns code blocks of Veryl in README.md and documents of top-level items
+    pub fn examples(&self) -> Vec<(String, String)> {
+        let mut ret = Vec::new();
+        if let Some(x) = &self.readme {
+            for code in veryl_code_blocks(x) {
+                ret.push(("README.md".to_string(), code));
+            }
+        }
+        for x in self
+            .modules
+            .iter()
+            .chain(self.proto_modules.iter())
+            .chain(self.interfaces.iter())
+            .chain(self.packages.iter())
+        {
+            for code in veryl_code_blocks(&self.description(&x.symbol)) {
+                ret.push((symbol_label(&x.symbol, &self.metadata), code));
+            }
+        }
+        ret
+    }
+
+    /// Returns the link to the page documenting the symbol.
+    /// Symbols of dependencies are linked into their documents if available.
+    fn get_link(&self, symbol: &Symbol) -> Option<String> {
+        if self.is_hidden(symbol) {
+            return None;
+        }
+
+        match symbol.kind {
+            SymbolKind::Module(_)
+            | SymbolKind::ProtoModule(_)
+            | SymbolKind::Interface(_)
+            | SymbolKind::Package(_)
+            | SymbolKind::Modport(_)
+            | SymbolKind::Struct(_)
+            | SymbolKind::Union(_)
+            | SymbolKind::Enum(_)
+            | SymbolKind::TypeDef(_) => (),
+            _ => return None,
+        }
+
+        let page = if let Some(x) = symbol.namespace.paths.get(1) {
+            x.to_string()
+        } else {
+            symbol.token.text.to_string()
+        };
+
+        if is_project_symbol(symbol, &self.metadata) {
+            self.pages.contains(&page).then(|| format!("{}.html", page))
+        } else {
+            let project = symbol.namespace.paths.first()?.to_string();
+            let base = self.dependency_docs.get(&project)?;
+            Some(format!("{}/{}.html", base, page))
+        }
+    }
+
+    /// Private items of packages are hidden unless `--document-private-items` is specified
+    fn is_hidden(&self, symbol: &Symbol) -> bool {
+        !self.document_private_items && symbol.is_private()
+    }
+
+    fn fmt_link(&self, symbol: &Symbol, text: &str) -> String {
+        if let Some(link) = self.get_link(symbol) {
+            format!("<a href=\"{}\">{}</a>", link, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn fmt_type(&self, r#type: &Type) -> String {
+        let text = format!("{}", r#type);
+        if let TypeKind::UserDefined(x) = &r#type.kind {
+            if let Some(symbol) = x.symbol.and_then(symbol_table::get) {
+                return self.fmt_link(&symbol, &text);
+            }
+        }
+        text
+    }
+
+    fn fmt_instances(&self, instances: Option<&BTreeMap<String, Symbol>>) -> Vec<String> {
+        if let Some(instances) = instances {
+            instances
+                .iter()
+                .map(|(label, symbol)| self.fmt_link(symbol, label))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns SVG of types which the symbol depends on transitively
+    fn build_dependency_graph(&self, symbol: &Symbol) -> Option<String> {
+        let mut graph = Graph::default();
+        let mut ids: Vec<SymbolId> = Vec::new();
+        let mut edges = Vec::new();
+        let mut stack = vec![symbol.clone()];
+
+        while let Some(x) = stack.pop() {
+            if ids.contains(&x.id) {
+                continue;
+            }
+            ids.push(x.id);
+            graph.nodes.push(GraphNode {
+                label: symbol_label(&x, &self.metadata),
+                link: self.get_link(&x),
+            });
+            for dependency in type_dag::dependencies(x.id) {
+                if self.is_hidden(&dependency) {
+                    continue;
+                }
+                edges.push((x.id, dependency.id));
+                stack.push(dependency);
+            }
+        }
+
+        if edges.is_empty() {
+            return None;
+        }
+
+        let index = |id: SymbolId| ids.iter().position(|x| *x == id).unwrap();
+        graph.edges = edges
+            .into_iter()
+            .map(|(start, end)| (index(start), index(end)))
+            .collect();
+        Some(graph.to_svg())
+    }
+}
+
+/// Returns the base URL of documents of each dependency.
+/// URL specified by `doc.url` of the dependency is used, and local documents are used if they are built.
+pub fn dependency_docs(metadata: &Metadata) -> Result<HashMap<String, String>> {
+    let mut ret = HashMap::new();
+    for (lock, path) in metadata.lockfile.project_paths()? {
+        let dependency = Metadata::load(path.join("Veryl.toml"))?;
+        if let Some(url) = &dependency.doc.url {
+            ret.insert(lock.name.clone(), url.trim_end_matches('/').to_string());
+        } else {
+            let doc_path = dependency.doc_path();
+            if doc_path.join("index.html").exists() {
+                let doc_path = doc_path.to_string_lossy().replace('\\', "/");
+                ret.insert(lock.name.clone(), format!("file://{}", doc_path));
+            }
+        }
+    }
+    Ok(ret)
+}
+
+fn is_project_symbol(symbol: &Symbol, metadata: &Metadata) -> bool {
+    symbol
+        .namespace
+        .paths
+        .first()
+        .map(|x| x.to_string() == metadata.project.name)
+        .unwrap_or(false)
+}
+
+/// Returns the symbol path without the project name if it is in the project
+fn symbol_label(symbol: &Symbol, metadata: &Metadata) -> String {
+    let skip = if is_project_symbol(symbol, metadata) {
+        1
+    } else {
+        0
+    };
+    let mut paths: Vec<_> = symbol
+        .namespace
+        .paths
+        .iter()
+        .skip(skip)
+        .map(|x| x.to_string())
+        .collect();
+    paths.push(symbol.token.text.to_string());
+    paths.join("::")
+}
+
+/// Reads documents specified by `doc_include` attribute.
+/// Relative paths are resolved from the directory of the source file.
+fn read_included_docs(symbol: &Symbol) -> Result<Option<String>> {
+    let paths: Vec<_> = attribute_table::get(&symbol.token)
+        .into_iter()
+        .filter_map(|x| match x {
+            Attr::DocInclude(x) => Some(PathBuf::from(x.to_string())),
+            _ => None,
+        })
+        .collect();
+    if paths.is_empty() {
+        return Ok(None);
+    }
+
+    let source = match symbol.token.source {
+        TokenSource::File(x) => resource_table::get_path_value(x),
+        _ => None,
+    };
+
+    let mut ret = String::new();
+    for path in paths {
+        let path = match source.as_ref().and_then(|x| x.parent()) {
+            Some(dir) if path.is_relative() => dir.join(&path),
+            _ => path,
+        };
+        let text = fs::read_to_string(&path)
+            .into_diagnostic()
+            .wrap_err(format!("failed to read {}", path.to_string_lossy()))?;
+        ret.push_str(&text);
+        ret.push('\n');
+    }
+    Ok(Some(ret))
 }
 
 fn get_comment_from_token(token: &Token) -> Option<String> {
     if let Ok(symbol) = symbol_table::resolve(token) {
-        Some(symbol.found.doc_comment.format(false))
+        Some(markdown_to_html(&symbol.found.doc_comment.format(false)))
     } else {
         None
     }
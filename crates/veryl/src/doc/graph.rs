@@ -0,0 +1,111 @@
+const CHAR_WIDTH: usize = 8;
+const NODE_HEIGHT: usize = 30;
+const NODE_PADDING: usize = 10;
+const HORIZONTAL_GAP: usize = 40;
+const VERTICAL_GAP: usize = 10;
+const MARGIN: usize = 10;
+
+pub struct GraphNode {
+    pub label: String,
+    pub link: Option<String>,
+}
+
+/// Directed graph rendered as SVG from left to right
+#[derive(Default)]
+pub struct Graph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    /// Returns the column of each node, which is the longest path from the roots
+    fn columns(&self) -> Vec<usize> {
+        let mut ret = vec![0; self.nodes.len()];
+        for _ in 0..self.nodes.len() {
+            let mut changed = false;
+            for (start, end) in &self.edges {
+                if ret[*end] < ret[*start] + 1 {
+                    ret[*end] = ret[*start] + 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        ret
+    }
+
+    pub fn to_svg(&self) -> String {
+        let columns = self.columns();
+        let mut rows = vec![0; self.nodes.len()];
+        let mut column_size = vec![0; columns.iter().max().map(|x| x + 1).unwrap_or(0)];
+        for (i, column) in columns.iter().enumerate() {
+            rows[i] = column_size[*column];
+            column_size[*column] += 1;
+        }
+
+        let max_label = self
+            .nodes
+            .iter()
+            .map(|x| x.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let node_width = max_label * CHAR_WIDTH + NODE_PADDING * 2;
+        let position = |i: usize| {
+            let x = MARGIN + columns[i] * (node_width + HORIZONTAL_GAP);
+            let y = MARGIN + rows[i] * (NODE_HEIGHT + VERTICAL_GAP);
+            (x, y)
+        };
+
+        let num_columns = column_size.len();
+        let num_rows = column_size.iter().max().copied().unwrap_or(0);
+        let width =
+            MARGIN * 2 + num_columns * node_width + num_columns.saturating_sub(1) * HORIZONTAL_GAP;
+        let height =
+            MARGIN * 2 + num_rows * NODE_HEIGHT + num_rows.saturating_sub(1) * VERTICAL_GAP;
+
+        // SVG is written without blank lines not to terminate HTML block of markdown
+        let mut ret = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" class=\"dependency_graph\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+        );
+        ret.push_str("<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\"><path d=\"M 0 0 L 10 5 L 0 10 z\" fill=\"currentColor\"/></marker></defs>\n");
+
+        for (start, end) in &self.edges {
+            let (x0, y0) = position(*start);
+            let (x1, y1) = position(*end);
+            ret.push_str(&format!(
+                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"currentColor\" marker-end=\"url(#arrow)\"/>\n",
+                x0 + node_width,
+                y0 + NODE_HEIGHT / 2,
+                x1,
+                y1 + NODE_HEIGHT / 2
+            ));
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let (x, y) = position(i);
+            let mut item = format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{node_width}\" height=\"{NODE_HEIGHT}\" rx=\"4\" fill=\"none\" stroke=\"currentColor\"/><text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\" font-family=\"monospace\" font-size=\"13\" fill=\"currentColor\">{}</text>",
+                x + node_width / 2,
+                y + NODE_HEIGHT / 2,
+                escape(&node.label)
+            );
+            if let Some(link) = &node.link {
+                item = format!("<a href=\"{}\">{}</a>", escape(link), item);
+            }
+            ret.push_str(&item);
+            ret.push('\n');
+        }
+
+        ret.push_str("</svg>\n");
+        ret
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,3 +1,5 @@
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
 pub fn escape_html(s: &str) -> String {
     let mut output = String::new();
     for c in s.chars() {
@@ -11,3 +13,57 @@ pub fn escape_html(s: &str) -> String {
     }
     output
 }
+
+/// Markdown extensions enabled by mdbook
+fn options() -> Options {
+    let mut opts = Options::empty();
+    opts.insert(Options::ENABLE_TABLES);
+    opts.insert(Options::ENABLE_FOOTNOTES);
+    opts.insert(Options::ENABLE_STRIKETHROUGH);
+    opts.insert(Options::ENABLE_TASKLISTS);
+    opts
+}
+
+/// Renders markdown of doc comments placed in table cells.
+/// The paragraph wrapping the whole text is removed to keep the cell compact.
+pub fn markdown_to_html(s: &str) -> String {
+    let mut output = String::new();
+    pulldown_cmark::html::push_html(&mut output, Parser::new_ext(s, options()));
+    let output = output.trim_end();
+    match output
+        .strip_prefix("<p>")
+        .and_then(|x| x.strip_suffix("</p>"))
+    {
+        Some(x) if !x.contains("<p>") => x.to_string(),
+        _ => output.to_string(),
+    }
+}
+
+/// Returns the content of fenced code blocks of Veryl in the markdown.
+/// Code blocks with `ignore` like ```` ```veryl,ignore ```` are skipped.
+pub fn veryl_code_blocks(s: &str) -> Vec<String> {
+    let mut ret = Vec::new();
+    let mut code: Option<String> = None;
+    for event in Parser::new_ext(s, options()) {
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                let mut attrs = info.split([',', ' ']).filter(|x| !x.is_empty());
+                if attrs.next() == Some("veryl") && attrs.all(|x| x != "ignore") {
+                    code = Some(String::new());
+                }
+            }
+            Event::Text(text) => {
+                if let Some(code) = &mut code {
+                    code.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some(code) = code.take() {
+                    ret.push(code);
+                }
+            }
+            _ => (),
+        }
+    }
+    ret
+}
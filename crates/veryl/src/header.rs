@@ -0,0 +1,526 @@
+use miette::{bail, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use veryl_analyzer::attribute::Attribute as Attr;
+use veryl_analyzer::evaluator::Evaluator;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{Symbol, SymbolId, SymbolKind, TypeKind};
+use veryl_analyzer::{attribute_table, symbol_table};
+use veryl_emitter::emitter::{symbol_string, SymbolContext};
+use veryl_metadata::Metadata;
+use veryl_parser::resource_table;
+use veryl_parser::veryl_token::{Token, TokenSource, VerylToken};
+
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords which can't be used as raw identifiers
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+const PACKING_NOTE: &str = "Bit offsets are counted from LSB of the packed value.\n\
+                            The first member of a struct is placed at the most significant bits.";
+
+#[derive(Clone, Debug)]
+struct Field {
+    name: String,
+    offset: usize,
+    width: usize,
+    /// Type name if the member type is generated in the same header
+    r#type: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+enum Item {
+    Struct {
+        name: String,
+        width: usize,
+        fields: Vec<Field>,
+    },
+    Enum {
+        name: String,
+        width: usize,
+        variants: Vec<(String, usize)>,
+    },
+}
+
+/// Register layouts of a package with `export_header` attribute
+#[derive(Clone, Debug)]
+pub struct PackageHeader {
+    /// Emitted name of the package
+    pub name: String,
+    /// Source file of the package
+    pub path: PathBuf,
+    items: Vec<Item>,
+}
+
+/// Collects packages with `export_header` attribute in the project
+pub fn collect(metadata: &Metadata) -> Result<Vec<PackageHeader>> {
+    let base = metadata.project_path();
+    let project_name = resource_table::insert_str(&metadata.project.name);
+    let mut prj_namespace = Namespace::new();
+    prj_namespace.push(project_name);
+
+    let context = SymbolContext {
+        project_name: Some(project_name),
+        build_opt: metadata.build.clone(),
+        in_import: false,
+        generic_map: Vec::new(),
+    };
+
+    let symbols = symbol_table::get_all();
+    let mut packages: Vec<_> = symbols
+        .iter()
+        .filter(|x| {
+            matches!(x.kind, SymbolKind::Package(_))
+                && x.namespace.included(&prj_namespace)
+                && attribute_table::contains(&x.token, Attr::ExportHeader)
+        })
+        .collect();
+    packages.sort_by_key(|x| (x.token.source.to_string(), x.token.line));
+
+    let mut ret = Vec::new();
+    for package in packages {
+        let mut namespace = package.namespace.clone();
+        namespace.push(package.token.text);
+
+        let mut members: Vec<_> = symbols
+            .iter()
+            .filter(|x| {
+                x.namespace == namespace
+                    && matches!(x.kind, SymbolKind::Struct(_) | SymbolKind::Enum(_))
+            })
+            .collect();
+        members.sort_by_key(|x| (x.token.line, x.token.column));
+
+        let generated: HashMap<SymbolId, String> =
+            members.iter().map(|x| (x.id, name(&x.token))).collect();
+
+        let mut items = Vec::new();
+        for member in members {
+            let item = match member.kind {
+                SymbolKind::Struct(_) => struct_item(member, &generated, &base)?,
+                _ => enum_item(member, &base)?,
+            };
+            items.push(item);
+        }
+
+        let path = match package.token.source {
+            TokenSource::File(x) => resource_table::get_path_value(x).unwrap(),
+            _ => PathBuf::new(),
+        };
+
+        ret.push(PackageHeader {
+            name: symbol_string(&VerylToken::new(package.token), package, &context),
+            path,
+            items,
+        });
+    }
+    Ok(ret)
+}
+
+fn struct_item(
+    symbol: &Symbol,
+    generated: &HashMap<SymbolId, String>,
+    base: &Path,
+) -> Result<Item> {
+    let SymbolKind::Struct(ref x) = symbol.kind else {
+        unreachable!();
+    };
+
+    let mut evaluator = Evaluator::new();
+    let mut fields = Vec::new();
+    for id in &x.members {
+        let member = symbol_table::get(*id).unwrap();
+        let SymbolKind::StructMember(ref x) = member.kind else {
+            continue;
+        };
+
+        let Some(width) = evaluator.total_width(&x.r#type) else {
+            bail!(
+                "width of member \"{}\" of \"{}\" is not statically known ({})",
+                name(&member.token),
+                name(&symbol.token),
+                source(&member.token, base)
+            );
+        };
+
+        let r#type = match x.r#type.kind {
+            TypeKind::UserDefined(ref y) if x.r#type.width.is_empty() => {
+                y.symbol.and_then(|x| generated.get(&x).cloned())
+            }
+            _ => None,
+        };
+
+        fields.push(Field {
+            name: name(&member.token),
+            offset: 0,
+            width,
+            r#type,
+        });
+    }
+
+    // the first member is placed at MSB as packed struct of SystemVerilog
+    let mut offset = 0;
+    for field in fields.iter_mut().rev() {
+        field.offset = offset;
+        offset += field.width;
+    }
+
+    Ok(Item::Struct {
+        name: name(&symbol.token),
+        width: offset,
+        fields,
+    })
+}
+
+fn enum_item(symbol: &Symbol, base: &Path) -> Result<Item> {
+    let SymbolKind::Enum(ref x) = symbol.kind else {
+        unreachable!();
+    };
+
+    if x.width == 0 {
+        bail!(
+            "width of \"{}\" is not statically known ({})",
+            name(&symbol.token),
+            source(&symbol.token, base)
+        );
+    }
+
+    let mut variants = Vec::new();
+    for id in &x.members {
+        let member = symbol_table::get(*id).unwrap();
+        let SymbolKind::EnumMember(ref y) = member.kind else {
+            continue;
+        };
+
+        let Some(value) = y.value.value() else {
+            bail!(
+                "value of variant \"{}\" of \"{}\" is not statically known ({})",
+                name(&member.token),
+                name(&symbol.token),
+                source(&member.token, base)
+            );
+        };
+        variants.push((name(&member.token), value));
+    }
+
+    Ok(Item::Enum {
+        name: name(&symbol.token),
+        width: x.width,
+        variants,
+    })
+}
+
+impl PackageHeader {
+    /// Returns file name of the header without extension
+    pub fn file_name(&self) -> String {
+        snake_case(&self.name)
+    }
+
+    /// Generates C header with bitfield macros
+    pub fn to_c(&self) -> String {
+        let guard = c_name(&[&self.file_name(), "h"]);
+
+        let mut ret = String::new();
+        ret.push_str("// This file is generated by veryl. Do not edit.\n");
+        ret.push_str(&format!("#ifndef {guard}\n#define {guard}\n\n"));
+        for line in PACKING_NOTE.lines() {
+            ret.push_str(&format!("// {line}\n"));
+        }
+
+        for item in &self.items {
+            ret.push('\n');
+            match item {
+                Item::Struct {
+                    name,
+                    width,
+                    fields,
+                } => {
+                    let prefix = c_name(&[&self.name, name]);
+                    let suffix = c_suffix(*width);
+                    ret.push_str(&format!("// {name} ({width} bits)\n"));
+                    ret.push_str(&format!("#define {prefix}_WIDTH {width}\n"));
+                    for field in fields {
+                        // field macros always have suffix, so they don't conflict with `_WIDTH` of the struct
+                        let field_prefix = c_name(&[&prefix, &field.name]);
+                        ret.push_str(&format!("#define {field_prefix}_OFFSET {}\n", field.offset));
+                        ret.push_str(&format!("#define {field_prefix}_WIDTH {}\n", field.width));
+
+                        // accessors are available if the whole struct can be represented by integer type
+                        if *width <= 64 {
+                            ret.push_str(&format!(
+                                "#define {field_prefix}_MASK 0x{:x}{suffix}\n",
+                                mask(field.width)
+                            ));
+                            ret.push_str(&format!(
+                                "#define {field_prefix}_GET(x) (((x) >> {field_prefix}_OFFSET) & {field_prefix}_MASK)\n"
+                            ));
+                            ret.push_str(&format!(
+                                "#define {field_prefix}_SET(x, v) (((x) & ~({field_prefix}_MASK << {field_prefix}_OFFSET)) | (((v) & {field_prefix}_MASK) << {field_prefix}_OFFSET))\n"
+                            ));
+                        }
+                    }
+                }
+                Item::Enum {
+                    name,
+                    width,
+                    variants,
+                } => {
+                    let prefix = c_name(&[&self.name, name]);
+                    let suffix = c_suffix(*width);
+                    ret.push_str(&format!("// {name} ({width} bits)\n"));
+                    ret.push_str(&format!("#define {prefix}_WIDTH {width}\n"));
+                    for (variant, value) in variants {
+                        let variant = escape_width(&c_name(&[variant]));
+                        ret.push_str(&format!("#define {prefix}_{variant} {value}{suffix}\n"));
+                    }
+                }
+            }
+        }
+
+        ret.push_str("\n#endif\n");
+        ret
+    }
+
+    /// Generates Rust source with constants and typed bitfield accessors
+    pub fn to_rust(&self) -> String {
+        let mut ret = String::new();
+        ret.push_str("// This file is generated by veryl. Do not edit.\n");
+        ret.push_str("#![allow(dead_code, non_camel_case_types, non_upper_case_globals)]\n");
+        ret.push_str("#![allow(clippy::all)]\n\n");
+        for line in PACKING_NOTE.lines() {
+            ret.push_str(&format!("//! {line}\n"));
+        }
+
+        for item in &self.items {
+            ret.push('\n');
+            match item {
+                Item::Struct {
+                    name,
+                    width,
+                    fields,
+                } => {
+                    let name = rust_escape(name);
+                    let storage = rust_uint(*width);
+                    ret.push_str(&format!("/// {name} ({width} bits)\n"));
+                    ret.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]\n");
+                    if let Some(storage) = storage {
+                        ret.push_str(&format!("pub struct {name}(pub {storage});\n\n"));
+                    } else {
+                        ret.push_str(&format!("pub struct {name};\n\n"));
+                    }
+
+                    ret.push_str(&format!("impl {name} {{\n"));
+                    ret.push_str(&format!("    pub const WIDTH: usize = {width};\n"));
+                    for field in fields {
+                        let field_name = escape_width(&snake_case(&field.name).to_uppercase());
+                        ret.push_str(&format!(
+                            "    pub const {field_name}_OFFSET: usize = {};\n",
+                            field.offset
+                        ));
+                        ret.push_str(&format!(
+                            "    pub const {field_name}_WIDTH: usize = {};\n",
+                            field.width
+                        ));
+                    }
+
+                    if let Some(storage) = storage {
+                        for field in fields {
+                            ret.push('\n');
+                            ret.push_str(&rust_accessor(field, storage));
+                        }
+                    }
+                    ret.push_str("}\n");
+                }
+                Item::Enum {
+                    name,
+                    width,
+                    variants,
+                } => {
+                    let name = rust_escape(name);
+                    let storage = rust_uint(*width);
+                    ret.push_str(&format!("/// {name} ({width} bits)\n"));
+                    ret.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]\n");
+                    if let Some(storage) = storage {
+                        ret.push_str(&format!("pub struct {name}(pub {storage});\n\n"));
+                    } else {
+                        ret.push_str(&format!("pub struct {name};\n\n"));
+                    }
+
+                    ret.push_str(&format!("impl {name} {{\n"));
+                    ret.push_str(&format!("    pub const WIDTH: usize = {width};\n"));
+                    for (variant, value) in variants {
+                        let variant = escape_width(&snake_case(variant).to_uppercase());
+                        if storage.is_some() {
+                            ret.push_str(&format!(
+                                "    pub const {variant}: Self = Self({value});\n"
+                            ));
+                        } else {
+                            ret.push_str(&format!("    pub const {variant}: usize = {value};\n"));
+                        }
+                    }
+                    ret.push_str("}\n");
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+fn rust_accessor(field: &Field, storage: &str) -> String {
+    let name = snake_case(&field.name);
+    let getter = rust_escape(&name);
+    let setter = format!("set_{name}");
+    let constant = escape_width(&name.to_uppercase());
+    let mask = format!("0x{:x}", mask(field.width));
+    let uint = rust_uint(field.width).unwrap();
+
+    let cast = |x: String, from: &str, to: &str| {
+        if from == to {
+            x
+        } else {
+            format!("{x} as {to}")
+        }
+    };
+
+    let value = format!("((self.0 >> Self::{constant}_OFFSET) & {mask})");
+    let (r#type, get, set) = if let Some(ref r#type) = field.r#type {
+        let r#type = rust_escape(r#type);
+        let get = format!("{type}({})", cast(value, storage, uint), type = r#type);
+        let set = cast("value.0".to_string(), uint, storage);
+        (r#type, get, set)
+    } else {
+        let get = cast(value, storage, uint);
+        let set = cast("value".to_string(), uint, storage);
+        (uint.to_string(), get, set)
+    };
+
+    let mut ret = String::new();
+    ret.push_str(&format!("    pub fn {getter}(&self) -> {type} {{\n", type = r#type));
+    ret.push_str(&format!("        {get}\n"));
+    ret.push_str("    }\n\n");
+    ret.push_str(&format!(
+        "    pub fn {setter}(&mut self, value: {type}) {{\n",
+        type = r#type
+    ));
+    ret.push_str(&format!(
+        "        let mask: {storage} = {mask} << Self::{constant}_OFFSET;\n"
+    ));
+    ret.push_str(&format!(
+        "        self.0 = (self.0 & !mask) | ((({set}) << Self::{constant}_OFFSET) & mask);\n"
+    ));
+    ret.push_str("    }\n");
+    ret
+}
+
+/// Returns the identifier text without raw identifier prefix
+fn name(token: &Token) -> String {
+    let text = token.to_string();
+    text.strip_prefix("r#")
+        .map(|x| x.to_string())
+        .unwrap_or(text)
+}
+
+fn source(token: &Token, base: &Path) -> String {
+    let path = match token.source {
+        TokenSource::File(x) => {
+            let path = resource_table::get_path_value(x).unwrap();
+            match path.strip_prefix(base) {
+                Ok(x) => x.to_string_lossy().to_string(),
+                Err(_) => path.to_string_lossy().to_string(),
+            }
+        }
+        x => x.to_string(),
+    };
+    format!("{}:{}:{}", path, token.line, token.column)
+}
+
+fn snake_case(x: &str) -> String {
+    let chars: Vec<_> = x.chars().collect();
+    let mut ret = String::new();
+    for (i, x) in chars.iter().enumerate() {
+        if i != 0 && x.is_ascii_uppercase() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|x| x.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                ret.push('_');
+            }
+        }
+        ret.push(x.to_ascii_lowercase());
+    }
+    ret
+}
+
+/// Returns C macro name joining `parts`.
+/// Leading and consecutive underscores are removed because they are reserved.
+fn c_name(parts: &[&str]) -> String {
+    let text = parts
+        .iter()
+        .map(|x| snake_case(x).to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    let mut ret = String::new();
+    for x in text.chars() {
+        if x == '_' && (ret.is_empty() || ret.ends_with('_')) {
+            continue;
+        }
+        ret.push(x);
+    }
+    ret
+}
+
+fn c_suffix(width: usize) -> &'static str {
+    if width <= 32 {
+        "u"
+    } else {
+        "ULL"
+    }
+}
+
+/// Avoids conflict with `WIDTH` constant of the type
+fn escape_width(x: &str) -> String {
+    if x == "WIDTH" {
+        format!("{x}_")
+    } else {
+        x.to_string()
+    }
+}
+
+fn rust_escape(x: &str) -> String {
+    if RUST_NON_RAW_KEYWORDS.contains(&x) {
+        format!("{x}_")
+    } else if RUST_KEYWORDS.contains(&x) {
+        format!("r#{x}")
+    } else {
+        x.to_string()
+    }
+}
+
+fn rust_uint(width: usize) -> Option<&'static str> {
+    match width {
+        0..=8 => Some("u8"),
+        9..=16 => Some("u16"),
+        17..=32 => Some("u32"),
+        33..=64 => Some("u64"),
+        65..=128 => Some("u128"),
+        _ => None,
+    }
+}
+
+fn mask(width: usize) -> u128 {
+    if width == 0 {
+        0
+    } else {
+        u128::MAX >> (128 - width.min(128))
+    }
+}
@@ -0,0 +1,339 @@
+use miette::{bail, Result};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use veryl_analyzer::evaluator::{Evaluated, Evaluator};
+use veryl_analyzer::symbol::{SymbolId, SymbolKind};
+use veryl_analyzer::symbol_table;
+use veryl_parser::resource_table;
+use veryl_parser::stringifier::Stringifier;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenSource};
+use veryl_parser::veryl_walker::VerylWalker;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct InstanceParameter {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct InstanceNode {
+    pub name: String,
+    pub module: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub generic_arguments: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub array: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<InstanceParameter>,
+    pub source: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<InstanceNode>,
+}
+
+impl InstanceNode {
+    fn fmt_tree(&self, ret: &mut String, depth: usize) {
+        ret.push_str(&"    ".repeat(depth));
+        if depth == 0 {
+            ret.push_str(&self.module);
+        } else {
+            ret.push_str(&format!("{}: {}", self.name, self.module));
+        }
+        if !self.generic_arguments.is_empty() {
+            ret.push_str(&format!("::<{}>", self.generic_arguments.join(", ")));
+        }
+        if !self.array.is_empty() {
+            ret.push_str(&format!(" [{}]", self.array.join(", ")));
+        }
+        if !self.parameters.is_empty() {
+            let params: Vec<_> = self
+                .parameters
+                .iter()
+                .map(|x| format!("{}: {}", x.name, x.value))
+                .collect();
+            ret.push_str(&format!(" #({})", params.join(", ")));
+        }
+        ret.push_str(&format!(" ({})\n", self.source));
+
+        for child in &self.children {
+            child.fmt_tree(ret, depth + 1);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Instance {
+    node: InstanceNode,
+    module: Option<SymbolId>,
+}
+
+#[derive(Clone, Debug)]
+struct Module {
+    name: String,
+    token: Token,
+    project: bool,
+}
+
+/// InstanceHierarchy collects instance declarations of each module,
+/// and elaborates the instance tree from top modules.
+///
+/// Instance arrays are not expanded, and parameter values are shown only if they are constant.
+#[derive(Default)]
+pub struct InstanceHierarchy {
+    base: Option<std::path::PathBuf>,
+    modules: HashMap<SymbolId, Module>,
+    instances: HashMap<SymbolId, Vec<Instance>>,
+    current_module: Option<SymbolId>,
+    current_project: bool,
+    generic_arguments: Vec<String>,
+    in_generic_argument: bool,
+    parameters: Vec<InstanceParameter>,
+    in_parameter: bool,
+}
+
+impl InstanceHierarchy {
+    pub fn new(base: &Path) -> Self {
+        Self {
+            base: Some(base.to_path_buf()),
+            ..Default::default()
+        }
+    }
+
+    /// Collects instance declarations from the syntax tree of a file.
+    /// `project` should be true if the file belongs to the current project.
+    pub fn collect(&mut self, veryl: &Veryl, project: bool) {
+        self.current_project = project;
+        self.veryl(veryl);
+    }
+
+    pub fn build(&self, tops: &[String]) -> Result<Vec<InstanceNode>> {
+        let mut tops: Vec<_> = if tops.is_empty() {
+            let instantiated: HashSet<_> = self
+                .instances
+                .values()
+                .flatten()
+                .filter_map(|x| x.module)
+                .collect();
+            self.modules
+                .iter()
+                .filter(|(id, x)| x.project && !instantiated.contains(id))
+                .map(|(id, _)| *id)
+                .collect()
+        } else {
+            let mut ret = Vec::new();
+            for top in tops {
+                if let Some((id, _)) = self.modules.iter().find(|(_, x)| &x.name == top) {
+                    ret.push(*id);
+                } else {
+                    bail!("top module \"{}\" is not found", top);
+                }
+            }
+            ret
+        };
+        tops.sort_by_key(|x| {
+            let module = &self.modules[x];
+            (module.token.source.to_string(), module.token.line)
+        });
+
+        let mut ret = Vec::new();
+        for top in tops {
+            let module = &self.modules[&top];
+            let mut node = InstanceNode {
+                name: module.name.clone(),
+                module: module.name.clone(),
+                generic_arguments: Vec::new(),
+                array: Vec::new(),
+                parameters: Vec::new(),
+                source: self.source(&module.token),
+                children: Vec::new(),
+            };
+            let mut stack = vec![top];
+            self.elaborate(&mut node, &mut stack)?;
+            ret.push(node);
+        }
+        Ok(ret)
+    }
+
+    fn elaborate(&self, node: &mut InstanceNode, stack: &mut Vec<SymbolId>) -> Result<()> {
+        let Some(instances) = self.instances.get(stack.last().unwrap()) else {
+            return Ok(());
+        };
+
+        for instance in instances {
+            let mut child = instance.node.clone();
+            if let Some(id) = instance.module {
+                if stack.contains(&id) {
+                    let mut path: Vec<_> = stack
+                        .iter()
+                        .skip_while(|x| **x != id)
+                        .map(|x| self.modules[x].name.clone())
+                        .collect();
+                    path.push(self.modules[&id].name.clone());
+                    bail!(
+                        "recursive instantiation is found ({}) at {}",
+                        path.join(" -> "),
+                        child.source
+                    );
+                }
+                stack.push(id);
+                self.elaborate(&mut child, stack)?;
+                stack.pop();
+            }
+            node.children.push(child);
+        }
+        Ok(())
+    }
+
+    fn source(&self, token: &Token) -> String {
+        let path = match token.source {
+            TokenSource::File(x) => {
+                let path = resource_table::get_path_value(x).unwrap();
+                match self
+                    .base
+                    .as_ref()
+                    .and_then(|base| path.strip_prefix(base).ok())
+                {
+                    Some(x) => x.to_string_lossy().to_string(),
+                    None => path.to_string_lossy().to_string(),
+                }
+            }
+            x => x.to_string(),
+        };
+        format!("{}:{}:{}", path, token.line, token.column)
+    }
+}
+
+pub fn dump_tree(nodes: &[InstanceNode]) -> String {
+    let mut ret = String::new();
+    for node in nodes {
+        node.fmt_tree(&mut ret, 0);
+    }
+    ret
+}
+
+fn stringify<F: FnOnce(&mut Stringifier)>(f: F) -> String {
+    let mut stringifier = Stringifier::new();
+    f(&mut stringifier);
+    stringifier.as_str().to_string()
+}
+
+fn evaluate(arg: &Expression) -> Option<String> {
+    match Evaluator::new().expression(arg) {
+        Evaluated::Fixed { value, .. } => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+impl VerylWalker for InstanceHierarchy {
+    /// Semantic action for non-terminal 'ModuleDeclaration'
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) {
+        if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+            self.modules.insert(
+                symbol.found.id,
+                Module {
+                    name: arg.identifier.identifier_token.to_string(),
+                    token: arg.identifier.identifier_token.token,
+                    project: self.current_project,
+                },
+            );
+            self.current_module = Some(symbol.found.id);
+            for x in &arg.module_declaration_list {
+                self.module_group(&x.module_group);
+            }
+            self.current_module = None;
+        }
+    }
+
+    /// Semantic action for non-terminal 'InstDeclaration'
+    fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        let Some(parent) = self.current_module else {
+            return;
+        };
+
+        let resolved = symbol_table::resolve(arg.scoped_identifier.as_ref())
+            .ok()
+            .map(|x| x.found);
+        // instance of generic module refers the base module
+        let resolved = match resolved {
+            Some(x) => match x.kind {
+                SymbolKind::GenericInstance(ref x) => symbol_table::get(x.base),
+                _ => Some(x),
+            },
+            None => None,
+        };
+        let module = resolved
+            .as_ref()
+            .map(|x| x.token.to_string())
+            .unwrap_or_else(|| arg.scoped_identifier.identifier().to_string());
+        let module_id = resolved
+            .filter(|x| matches!(x.kind, SymbolKind::Module(_)))
+            .map(|x| x.id);
+
+        self.generic_arguments.clear();
+        self.in_generic_argument = true;
+        self.scoped_identifier(&arg.scoped_identifier);
+        self.in_generic_argument = false;
+
+        // array of instances is shown as its range once
+        let mut array = Vec::new();
+        if let Some(ref x) = arg.inst_declaration_opt {
+            let x = &x.array;
+            let mut exps = vec![x.expression.as_ref()];
+            exps.extend(x.array_list.iter().map(|x| x.expression.as_ref()));
+            for exp in exps {
+                array.push(evaluate(exp).unwrap_or_else(|| stringify(|s| s.expression(exp))));
+            }
+        }
+
+        self.parameters.clear();
+        if let Some(ref x) = arg.inst_declaration_opt0 {
+            self.in_parameter = true;
+            self.inst_parameter(&x.inst_parameter);
+            self.in_parameter = false;
+        }
+
+        let node = InstanceNode {
+            name: arg.identifier.identifier_token.to_string(),
+            module,
+            generic_arguments: self.generic_arguments.drain(..).collect(),
+            array,
+            parameters: self.parameters.drain(..).collect(),
+            source: self.source(&arg.identifier.identifier_token.token),
+            children: Vec::new(),
+        };
+        self.instances.entry(parent).or_default().push(Instance {
+            node,
+            module: module_id,
+        });
+    }
+
+    /// Semantic action for non-terminal 'WithGenericArgumentItem'
+    fn with_generic_argument_item(&mut self, arg: &WithGenericArgumentItem) {
+        if !self.in_generic_argument {
+            return;
+        }
+        let text = match arg {
+            WithGenericArgumentItem::ScopedIdentifier(x) => {
+                stringify(|s| s.scoped_identifier(&x.scoped_identifier))
+            }
+            WithGenericArgumentItem::Number(x) => stringify(|s| s.number(&x.number)),
+        };
+        self.generic_arguments.push(text);
+    }
+
+    /// Semantic action for non-terminal 'InstParameterItem'
+    fn inst_parameter_item(&mut self, arg: &InstParameterItem) {
+        if !self.in_parameter {
+            return;
+        }
+        if let Some(ref x) = arg.inst_parameter_item_opt {
+            if let Some(value) = evaluate(&x.expression) {
+                self.parameters.push(InstanceParameter {
+                    name: arg.identifier.identifier_token.to_string(),
+                    value,
+                });
+            }
+        }
+    }
+}
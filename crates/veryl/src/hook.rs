@@ -0,0 +1,132 @@
+use log::{info, warn};
+use miette::{bail, IntoDiagnostic, Result, WrapErr};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Instant;
+use veryl_metadata::{Metadata, Target};
+
+/// Kinds of hooks specified by `[build.hooks]`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookKind {
+    PreBuild,
+    PostEmit,
+    PostBuild,
+}
+
+impl HookKind {
+    fn name(&self) -> &'static str {
+        match self {
+            HookKind::PreBuild => "pre_build",
+            HookKind::PostEmit => "post_emit",
+            HookKind::PostBuild => "post_build",
+        }
+    }
+
+    fn commands<'a>(&self, metadata: &'a Metadata) -> &'a [String] {
+        let hooks = &metadata.build.hooks;
+        match self {
+            HookKind::PreBuild => &hooks.pre_build,
+            HookKind::PostEmit => &hooks.post_emit,
+            HookKind::PostBuild => &hooks.post_build,
+        }
+    }
+}
+
+/// Directory where emitted files are placed
+pub fn target_dir(metadata: &Metadata) -> PathBuf {
+    let base_path = metadata.project_path();
+    match &metadata.build.target {
+        Target::Source => base_path,
+        Target::Directory { path } => base_path.join(path),
+        Target::Bundle { path } => base_path.join(path).parent().unwrap().to_path_buf(),
+    }
+}
+
+/// Executes hook commands of `kind` in the project directory.
+/// `output` replaces `{output}` placeholder and is given to `post_emit` hooks.
+/// The substituted values are also given as `VERYL_PROJECT`, `VERYL_TARGET_DIR` and `VERYL_OUTPUT`.
+pub fn run(metadata: &Metadata, kind: HookKind, output: Option<&Path>) -> Result<()> {
+    for command in kind.commands(metadata) {
+        let command = substitute(metadata, command, output);
+        run_command(metadata, kind.name(), &command, output)?;
+    }
+    Ok(())
+}
+
+fn substitute(metadata: &Metadata, command: &str, output: Option<&Path>) -> String {
+    let mut ret = command
+        .replace("{project}", &quote(&metadata.project.name))
+        .replace(
+            "{target_dir}",
+            &quote(&target_dir(metadata).to_string_lossy()),
+        );
+    if let Some(output) = output {
+        ret = ret.replace("{output}", &quote(&output.to_string_lossy()));
+    }
+    ret
+}
+
+/// Quotes `x` as a single argument of the shell executing hooks
+fn quote(x: &str) -> String {
+    if cfg!(windows) {
+        // `"` can't be included in paths on Windows
+        format!("\"{x}\"")
+    } else {
+        format!("'{}'", x.replace('\'', "'\\''"))
+    }
+}
+
+fn run_command(
+    metadata: &Metadata,
+    name: &str,
+    command: &str,
+    output: Option<&Path>,
+) -> Result<()> {
+    let prefix = format!("{}:{}", metadata.project.name, name);
+    info!("Running hook ({prefix}) : {command}");
+
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+
+    let start = Instant::now();
+    let mut child = Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .current_dir(metadata.project_path())
+        .env("VERYL_PROJECT", &metadata.project.name)
+        .env("VERYL_TARGET_DIR", target_dir(metadata))
+        .env("VERYL_OUTPUT", output.unwrap_or(Path::new("")))
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .into_diagnostic()
+        .wrap_err(format!("failed to execute hook ({prefix})"))?;
+
+    let stderr = child.stderr.take().unwrap();
+    let stderr_prefix = prefix.clone();
+    let stderr = thread::spawn(move || {
+        for line in BufReader::new(stderr).lines().map_while(|x| x.ok()) {
+            warn!("Hook ({stderr_prefix}) {line}");
+        }
+    });
+
+    let stdout = child.stdout.take().unwrap();
+    for line in BufReader::new(stdout).lines().map_while(|x| x.ok()) {
+        info!("Hook ({prefix}) {line}");
+    }
+    let _ = stderr.join();
+
+    let status = child.wait().into_diagnostic()?;
+    let elapsed = start.elapsed().as_secs_f64();
+    if !status.success() {
+        bail!("hook ({prefix}) failed with {status} : {command}");
+    }
+    info!("Finished hook ({prefix}) in {elapsed:.2}s");
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
+pub mod cache;
 pub mod cmd_build;
 pub mod cmd_check;
 pub mod cmd_clean;
@@ -13,8 +14,15 @@ pub mod cmd_new;
 pub mod cmd_publish;
 pub mod cmd_test;
 pub mod cmd_update;
+pub mod cmd_vendor;
+pub mod constraints;
 pub mod doc;
+pub mod header;
+pub mod hierarchy;
+pub mod hook;
 pub mod runner;
+pub mod template;
+pub mod timings;
 
 // ---------------------------------------------------------------------------------------------------------------------
 // Opt
@@ -59,6 +67,7 @@ pub enum Commands {
     Build(OptBuild),
     Clean(OptClean),
     Update(OptUpdate),
+    Vendor(OptVendor),
     Publish(OptPublish),
     Doc(OptDoc),
     Metadata(OptMetadata),
@@ -69,7 +78,16 @@ pub enum Commands {
 /// Create a new project
 #[derive(Args)]
 pub struct OptNew {
-    pub path: PathBuf,
+    #[arg(required_unless_present = "list_templates")]
+    pub path: Option<PathBuf>,
+
+    /// Template name or path to a template directory
+    #[arg(long, default_value = "default")]
+    pub template: String,
+
+    /// List available templates
+    #[arg(long)]
+    pub list_templates: bool,
 }
 
 /// Create a new project in an existing directory
@@ -77,6 +95,10 @@ pub struct OptNew {
 pub struct OptInit {
     #[arg(default_value = ".")]
     pub path: PathBuf,
+
+    /// Template name or path to a template directory
+    #[arg(long, default_value = "default")]
+    pub template: String,
 }
 
 /// Format the current project
@@ -88,20 +110,108 @@ pub struct OptFmt {
     /// Run fmt in check mode
     #[arg(long)]
     pub check: bool,
+
+    /// Read source from stdin and write the formatted result to stdout
+    #[arg(long, conflicts_with_all = ["files", "check"])]
+    pub stdin: bool,
+
+    /// Path of the source read from stdin used to find the project
+    #[arg(long, requires = "stdin")]
+    pub stdin_path: Option<PathBuf>,
+
+    /// Gather source files ignored by `.gitignore`
+    #[arg(long)]
+    pub no_ignore: bool,
 }
 
 /// Analyze the current project
-#[derive(Args)]
+#[derive(Args, Default)]
 pub struct OptCheck {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// output format of diagnostics
+    #[arg(long, value_enum, default_value_t)]
+    pub format: DiagnosticFormat,
+
+    /// Fail only if errors are reported or warnings exceed the number
+    #[arg(long)]
+    pub max_warnings: Option<usize>,
+
+    /// Report modules, interfaces and packages unreachable from the top modules
+    #[arg(long)]
+    pub unused_modules: bool,
+
+    /// Gather source files ignored by `.gitignore`
+    #[arg(long)]
+    pub no_ignore: bool,
+}
+
+#[derive(Clone, Copy, Default, Debug, ValueEnum)]
+pub enum DiagnosticFormat {
+    #[default]
+    Human,
+    Json,
+    Sarif,
 }
 
 /// Build the target codes corresponding to the current project
-#[derive(Args)]
+#[derive(Args, Default)]
 pub struct OptBuild {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// Build only the specified workspace member and members it depends on
+    #[arg(long, short)]
+    pub project: Option<String>,
+
+    /// Disable build cache
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Comma separated list of features to enable (`dependency/feature` enables the feature of the dependency)
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Disable the default features
+    #[arg(long)]
+    pub no_default_features: bool,
+
+    /// Output C header of DPI functions to the specified path
+    #[arg(long)]
+    pub dpi_header: Option<PathBuf>,
+
+    /// Comma separated list of languages of register headers generated from packages with export_header attribute
+    #[arg(long, value_delimiter = ',')]
+    pub headers: Vec<HeaderLang>,
+
+    /// Skip hooks specified by `[build.hooks]`
+    #[arg(long)]
+    pub no_hooks: bool,
+
+    /// Comma separated list of formats of synthesis constraint stubs generated for top modules
+    #[arg(long, value_delimiter = ',')]
+    pub constraints: Vec<ConstraintFormat>,
+
+    /// Print time spent in each phase and handler
+    #[arg(long)]
+    pub timings: bool,
+
+    /// Output time spent in each phase and handler as JSON to the specified path
+    #[arg(long)]
+    pub timings_json: Option<PathBuf>,
+
+    /// Resolve dependencies from the vendor directory without network access
+    #[arg(long)]
+    pub offline: bool,
+
+    /// Build profile which overrides `[build]` by `[build.profiles.<name>]`
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Gather source files ignored by `.gitignore`
+    #[arg(long)]
+    pub no_ignore: bool,
 }
 
 /// Clean-up the current project
@@ -112,6 +222,10 @@ pub struct OptClean {}
 #[derive(Args)]
 pub struct OptUpdate {}
 
+/// Copy dependencies into the vendor directory for offline build
+#[derive(Args)]
+pub struct OptVendor {}
+
 /// Publish the current project
 #[derive(Args)]
 pub struct OptPublish {
@@ -121,10 +235,18 @@ pub struct OptPublish {
 }
 
 /// Build the document corresponding to the current project
-#[derive(Args)]
+#[derive(Args, Default)]
 pub struct OptDoc {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// Include private items of packages
+    #[arg(long)]
+    pub document_private_items: bool,
+
+    /// Check that `veryl` code blocks in documents can be parsed
+    #[arg(long)]
+    pub test_docs: bool,
 }
 
 /// Execute tests
@@ -140,6 +262,39 @@ pub struct OptTest {
     /// Dump waveform
     #[arg(long)]
     pub wave: bool,
+
+    /// Run tests whose name contains the specified string only
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Keep temporary directories for each test
+    #[arg(long)]
+    pub keep_artifacts: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum HeaderLang {
+    /// C header with bitfield macros
+    C,
+    /// Rust source with constants and bitfield accessors
+    Rust,
+}
+
+impl From<HeaderLang> for veryl_metadata::HeaderLang {
+    fn from(x: HeaderLang) -> Self {
+        match x {
+            HeaderLang::C => veryl_metadata::HeaderLang::C,
+            HeaderLang::Rust => veryl_metadata::HeaderLang::Rust,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ConstraintFormat {
+    /// SDC with clock definitions and false paths of clock domain crossings
+    Sdc,
+    /// JSON of the same data for custom flows
+    Json,
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -150,6 +305,8 @@ pub enum SimType {
     Vcs,
     /// AMD Vivado Simulator
     Vivado,
+    /// Siemens Questa
+    Questa,
 }
 
 impl From<SimType> for veryl_metadata::SimType {
@@ -158,6 +315,7 @@ impl From<SimType> for veryl_metadata::SimType {
             SimType::Verilator => veryl_metadata::SimType::Verilator,
             SimType::Vcs => veryl_metadata::SimType::Vcs,
             SimType::Vivado => veryl_metadata::SimType::Vivado,
+            SimType::Questa => veryl_metadata::SimType::Questa,
         }
     }
 }
@@ -189,6 +347,10 @@ pub struct OptMetadata {
     /// output format
     #[arg(long, value_enum, default_value_t)]
     pub format: Format,
+
+    /// Build profile which overrides `[build]` by `[build.profiles.<name>]`
+    #[arg(long)]
+    pub profile: Option<String>,
 }
 
 #[derive(Clone, Copy, Default, Debug, ValueEnum)]
@@ -231,4 +393,20 @@ pub struct OptDump {
     /// output unsafe table
     #[arg(long)]
     pub unsafe_table: bool,
+
+    /// output clock domain crossings allowed by unsafe (cdc)
+    #[arg(long)]
+    pub cdc_table: bool,
+
+    /// output instance hierarchy
+    #[arg(long)]
+    pub hierarchy: bool,
+
+    /// top module of instance hierarchy
+    #[arg(long)]
+    pub top: Vec<String>,
+
+    /// output format of instance hierarchy
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
 }
@@ -8,7 +8,7 @@ use miette::{IntoDiagnostic, Result};
 use std::process::ExitCode;
 use std::str::FromStr;
 use std::time::Instant;
-use veryl_metadata::Metadata;
+use veryl_metadata::{Metadata, Workspace};
 
 use veryl::*;
 
@@ -67,12 +67,26 @@ fn main() -> Result<ExitCode> {
         .apply()
         .into_diagnostic()?;
 
+    // Workspace manifest has no project, so workspace build doesn't load metadata
+    let workspace = match opt.command {
+        Commands::Build(_) => Workspace::search_from_current()?,
+        _ => None,
+    };
+
     let mut metadata = match opt.command {
         Commands::New(_) | Commands::Init(_) => {
             // dummy metadata
             let metadata = Metadata::create_default_toml("dummy").unwrap();
             Metadata::from_str(&metadata)?
         }
+        Commands::Fmt(ref x) if x.stdin => {
+            cmd_fmt::CmdFmt::stdin_metadata(x.stdin_path.as_deref())?
+        }
+        _ if workspace.is_some() => {
+            // dummy metadata
+            let metadata = Metadata::create_default_toml("dummy").unwrap();
+            Metadata::from_str(&metadata)?
+        }
         _ => {
             let metadata_path = Metadata::search_from_current()?;
             Metadata::load(metadata_path)?
@@ -86,12 +100,20 @@ fn main() -> Result<ExitCode> {
         Commands::Init(x) => cmd_init::CmdInit::new(x).exec()?,
         Commands::Fmt(x) => cmd_fmt::CmdFmt::new(x).exec(&mut metadata)?,
         Commands::Check(x) => cmd_check::CmdCheck::new(x).exec(&mut metadata)?,
-        Commands::Build(x) => cmd_build::CmdBuild::new(x).exec(&mut metadata, false)?,
+        Commands::Build(x) => {
+            let build = cmd_build::CmdBuild::new(x);
+            if let Some(ref workspace) = workspace {
+                build.exec_workspace(workspace)?
+            } else {
+                build.exec(&mut metadata, false)?
+            }
+        }
         Commands::Clean(x) => cmd_clean::CmdClean::new(x).exec(&mut metadata)?,
         Commands::Update(x) => cmd_update::CmdUpdate::new(x).exec(&mut metadata)?,
+        Commands::Vendor(x) => cmd_vendor::CmdVendor::new(x).exec(&mut metadata)?,
         Commands::Publish(x) => cmd_publish::CmdPublish::new(x).exec(&mut metadata)?,
         Commands::Doc(x) => cmd_doc::CmdDoc::new(x).exec(&mut metadata)?,
-        Commands::Metadata(x) => cmd_metadata::CmdMetadata::new(x).exec(&metadata)?,
+        Commands::Metadata(x) => cmd_metadata::CmdMetadata::new(x).exec(&mut metadata)?,
         Commands::Dump(x) => cmd_dump::CmdDump::new(x).exec(&mut metadata)?,
         Commands::Test(x) => cmd_test::CmdTest::new(x).exec(&mut metadata)?,
     };
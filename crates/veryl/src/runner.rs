@@ -1,8 +1,9 @@
 use anstyle::{AnsiColor, Style};
 use log::{debug, log_enabled, Level};
-use miette::{IntoDiagnostic, Result};
+use miette::{bail, IntoDiagnostic, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use veryl_metadata::{Metadata, WaveFormTarget};
@@ -10,10 +11,12 @@ use veryl_parser::resource_table::{PathId, StrId};
 use veryl_sourcemap::SourceMap;
 
 mod cocotb;
+mod questa;
 mod vcs;
 mod verilator;
 mod vivado;
 pub use cocotb::*;
+pub use questa::*;
 pub use vcs::*;
 pub use verilator::*;
 pub use vivado::*;
@@ -25,11 +28,15 @@ pub trait Runner {
         test: StrId,
         top: Option<StrId>,
         path: PathId,
+        work_path: &Path,
         wave: bool,
     ) -> Result<bool>;
 
     fn name(&self) -> &'static str;
 
+    /// Executables which should be found in PATH
+    fn executables(&self) -> &'static [&'static str];
+
     fn failure(&mut self);
 
     fn debug(&self, line: &str) {
@@ -73,6 +80,30 @@ pub trait Runner {
     }
 }
 
+pub fn check_executables(runner: &dyn Runner) -> Result<()> {
+    for executable in runner.executables() {
+        let found = env::var_os("PATH")
+            .map(|x| env::split_paths(&x).any(|x| is_executable(&x.join(executable))))
+            .unwrap_or(false);
+        if !found {
+            bail!(
+                "\"{}\" required by {} is not found in PATH. Install it, or select another simulator by \"simulator\" in [test] section of Veryl.toml or \"--sim\" option",
+                executable,
+                runner.name()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn is_executable(path: &Path) -> bool {
+    if cfg!(windows) {
+        path.is_file() || path.with_extension("exe").is_file()
+    } else {
+        path.is_file()
+    }
+}
+
 pub fn remap_msg_by_regex(line: &str, re: &Regex) -> String {
     let mut ret = line.to_string();
 
@@ -4,6 +4,7 @@ use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
@@ -92,6 +93,11 @@ impl Cocotb {
             let line = line.into_diagnostic()?;
             self.parse_line(&line);
         }
+
+        let status = child.wait().await.into_diagnostic()?;
+        if !status.success() {
+            self.failure();
+        }
         Ok(())
     }
 }
@@ -103,15 +109,14 @@ impl Runner for Cocotb {
         test: StrId,
         top: Option<StrId>,
         path: PathId,
+        work_path: &Path,
         _wave: bool,
     ) -> Result<bool> {
         self.success = true;
 
-        let temp_dir = tempfile::tempdir().into_diagnostic()?;
-
         info!("Executing test ({})", test);
 
-        let src_path = temp_dir.path().join(format!("{}.py", test));
+        let src_path = work_path.join(format!("{}.py", test));
 
         match self.source {
             CocotbSource::Embed(x) => {
@@ -147,7 +152,7 @@ impl Runner for Cocotb {
 
         let module = format!("{}_{}", metadata.project.name, top.unwrap());
 
-        let runner_path = temp_dir.path().join("runner.py");
+        let runner_path = work_path.join("runner.py");
         let runner_text = format!(
             r#"
 import cocotb
@@ -183,7 +188,7 @@ runner.test(
         rt.block_on(async {
             let compile = Command::new("python3")
                 .arg("runner.py")
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
                 .spawn()
@@ -206,6 +211,10 @@ runner.test(
         "Cocotb"
     }
 
+    fn executables(&self) -> &'static [&'static str] {
+        &["python3"]
+    }
+
     fn failure(&mut self) {
         self.success = false;
     }
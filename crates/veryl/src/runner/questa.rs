@@ -0,0 +1,196 @@
+use crate::runner::{copy_wave, remap_msg_by_regex, Runner};
+use futures::prelude::*;
+use log::{error, info};
+use miette::{IntoDiagnostic, Result, WrapErr};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::{Child, Command};
+use tokio::runtime::Runtime;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use veryl_metadata::Metadata;
+use veryl_parser::resource_table::{PathId, StrId};
+
+pub struct Questa {
+    success: bool,
+}
+
+fn parse_msg(line: &str) -> String {
+    static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^# \*\* [A-Za-z]+: (.*)").unwrap());
+
+    if let Some(caps) = RE.captures(line) {
+        caps[1].to_string()
+    } else {
+        line.to_string()
+    }
+}
+
+fn remap_msg(line: &str) -> String {
+    static RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?<path>[^ ()]+)\((?<line>[0-9]+)\)").unwrap());
+
+    remap_msg_by_regex(line, &RE)
+}
+
+impl Questa {
+    pub fn new() -> Self {
+        Self { success: true }
+    }
+
+    pub fn runner(self) -> Box<dyn Runner> {
+        Box::new(self) as Box<dyn Runner>
+    }
+
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    /// Arguments of `vlog` to compile the test
+    pub fn compile_args(metadata: &Metadata, test: StrId, wave: bool) -> Vec<String> {
+        let mut args = vec![
+            "-sv".to_string(),
+            "-f".to_string(),
+            metadata.filelist_path().to_string_lossy().to_string(),
+            format!("+define+__veryl_test_{}_{}__", metadata.project.name, test),
+        ];
+
+        if wave {
+            args.push(format!(
+                "+define+__veryl_wavedump_{}_{}__",
+                metadata.project.name, test
+            ));
+        }
+
+        args.extend(metadata.test.questa.compile_args.iter().cloned());
+        args
+    }
+
+    /// Arguments of `vsim` to simulate the test
+    pub fn simulate_args(metadata: &Metadata, test: StrId, wave: bool) -> Vec<String> {
+        let mut args = vec!["-batch".to_string(), test.to_string()];
+
+        if wave {
+            args.push("__veryl_wavedump".to_string());
+        }
+
+        args.push("-do".to_string());
+        args.push("run -all; quit -f".to_string());
+        args.extend(metadata.test.questa.simulate_args.iter().cloned());
+        args
+    }
+
+    pub fn parse_line(&mut self, line: &str) {
+        self.debug(line);
+
+        if line.starts_with("# ** Info: ") {
+            self.info(&parse_msg(line));
+        } else if line.starts_with("# ** Warning: ") {
+            self.warning(&parse_msg(line));
+        } else if line.starts_with("# ** Error: ") {
+            self.error(&parse_msg(line));
+        } else if line.starts_with("# ** Fatal: ") {
+            self.fatal(&parse_msg(line));
+        } else if line.starts_with("** Warning: ") {
+            self.warning(&remap_msg(line));
+        } else if line.starts_with("** Error: ") || line.starts_with("** Error (suppressible): ") {
+            self.error(&remap_msg(line));
+        }
+    }
+
+    async fn parse(&mut self, mut child: Child) -> Result<()> {
+        let stdout = child.stdout.take().unwrap();
+        let mut reader = FramedRead::new(stdout, LinesCodec::new());
+        while let Some(line) = reader.next().await {
+            let line = line.into_diagnostic()?;
+            self.parse_line(&line);
+        }
+
+        let status = child.wait().await.into_diagnostic()?;
+        if !status.success() {
+            self.failure();
+        }
+        Ok(())
+    }
+}
+
+impl Default for Questa {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runner for Questa {
+    fn run(
+        &mut self,
+        metadata: &Metadata,
+        test: StrId,
+        _top: Option<StrId>,
+        path: PathId,
+        work_path: &Path,
+        wave: bool,
+    ) -> Result<bool> {
+        self.success = true;
+
+        info!("Compiling test ({})", test);
+
+        let rt = Runtime::new().unwrap();
+
+        rt.block_on(async {
+            let compile = Command::new("vlog")
+                .args(Self::compile_args(metadata, test, wave))
+                .current_dir(work_path)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .into_diagnostic()
+                .wrap_err("Failed to run \"vlog\"")?;
+
+            self.parse(compile).await
+        })?;
+
+        if !self.success {
+            error!("Failed compile ({})", test);
+            return Ok(false);
+        }
+
+        info!("Executing test ({})", test);
+
+        rt.block_on(async {
+            let simulate = Command::new("vsim")
+                .args(Self::simulate_args(metadata, test, wave))
+                .current_dir(work_path)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .into_diagnostic()
+                .wrap_err("Failed to run \"vsim\"")?;
+
+            self.parse(simulate).await
+        })?;
+
+        if wave {
+            copy_wave(test, path, metadata, work_path)?;
+        }
+
+        if self.success {
+            info!("Succeeded test ({})", test);
+            Ok(true)
+        } else {
+            error!("Failed test ({})", test);
+            Ok(false)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Questa"
+    }
+
+    fn executables(&self) -> &'static [&'static str] {
+        &["vlog", "vsim"]
+    }
+
+    fn failure(&mut self) {
+        self.success = false;
+    }
+}
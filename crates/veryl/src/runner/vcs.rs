@@ -4,6 +4,7 @@ use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
@@ -107,6 +108,11 @@ impl Vcs {
             let line = line.into_diagnostic()?;
             self.parse_line(&line);
         }
+
+        let status = child.wait().await.into_diagnostic()?;
+        if !status.success() {
+            self.failure();
+        }
         Ok(())
     }
 }
@@ -124,12 +130,11 @@ impl Runner for Vcs {
         test: StrId,
         _top: Option<StrId>,
         path: PathId,
+        work_path: &Path,
         wave: bool,
     ) -> Result<bool> {
         self.success = true;
 
-        let temp_dir = tempfile::tempdir().into_diagnostic()?;
-
         info!("Compiling test ({})", test);
 
         let mut defines = vec![format!(
@@ -153,7 +158,7 @@ impl Runner for Vcs {
                 .arg(metadata.filelist_path())
                 .args(&defines)
                 .args(&metadata.test.vcs.compile_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
                 .spawn()
@@ -173,7 +178,7 @@ impl Runner for Vcs {
         rt.block_on(async {
             let simulate = Command::new("./simv")
                 .args(&metadata.test.vcs.simulate_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
                 .spawn()
@@ -184,7 +189,7 @@ impl Runner for Vcs {
         })?;
 
         if wave {
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, path, metadata, work_path)?;
         }
 
         if self.success {
@@ -200,6 +205,10 @@ impl Runner for Vcs {
         "VCS"
     }
 
+    fn executables(&self) -> &'static [&'static str] {
+        &["vcs"]
+    }
+
     fn failure(&mut self) {
         self.success = false;
     }
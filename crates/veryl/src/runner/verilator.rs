@@ -4,6 +4,7 @@ use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
@@ -117,6 +118,11 @@ impl Verilator {
             let line = line.into_diagnostic()?;
             self.parse_line(&line);
         }
+
+        let status = child.wait().await.into_diagnostic()?;
+        if !status.success() {
+            self.failure();
+        }
         Ok(())
     }
 }
@@ -134,12 +140,11 @@ impl Runner for Verilator {
         test: StrId,
         _top: Option<StrId>,
         path: PathId,
+        work_path: &Path,
         wave: bool,
     ) -> Result<bool> {
         self.success = true;
 
-        let temp_dir = tempfile::tempdir().into_diagnostic()?;
-
         info!("Compiling test ({})", test);
 
         let mut defines = vec![format!(
@@ -171,7 +176,7 @@ impl Runner for Verilator {
                 .arg("simv")
                 .args(&defines)
                 .args(&metadata.test.verilator.compile_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn()
@@ -191,7 +196,7 @@ impl Runner for Verilator {
         rt.block_on(async {
             let simulate = Command::new("./obj_dir/simv")
                 .args(&metadata.test.verilator.simulate_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
                 .spawn()
@@ -202,7 +207,7 @@ impl Runner for Verilator {
         })?;
 
         if wave {
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, path, metadata, work_path)?;
         }
 
         if self.success {
@@ -218,6 +223,10 @@ impl Runner for Verilator {
         "Verilator"
     }
 
+    fn executables(&self) -> &'static [&'static str] {
+        &["verilator"]
+    }
+
     fn failure(&mut self) {
         self.success = false;
     }
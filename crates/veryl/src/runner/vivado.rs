@@ -4,6 +4,7 @@ use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
@@ -90,6 +91,11 @@ impl Vivado {
             let line = line.into_diagnostic()?;
             self.parse_line(&line);
         }
+
+        let status = child.wait().await.into_diagnostic()?;
+        if !status.success() {
+            self.failure();
+        }
         Ok(())
     }
 }
@@ -107,12 +113,11 @@ impl Runner for Vivado {
         test: StrId,
         _top: Option<StrId>,
         path: PathId,
+        work_path: &Path,
         wave: bool,
     ) -> Result<bool> {
         self.success = true;
 
-        let temp_dir = tempfile::tempdir().into_diagnostic()?;
-
         info!("Compiling test ({})", test);
 
         let mut defines = vec![
@@ -137,7 +142,7 @@ impl Runner for Vivado {
                 .arg(metadata.filelist_path())
                 .args(&defines)
                 .args(&metadata.test.vivado.compile_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
                 .spawn()
@@ -168,7 +173,7 @@ impl Runner for Vivado {
                 .arg("-s")
                 .arg("simv")
                 .args(&metadata.test.vivado.elaborate_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
                 .spawn()
@@ -190,7 +195,7 @@ impl Runner for Vivado {
                 .arg("simv")
                 .arg("--runall")
                 .args(&metadata.test.vivado.simulate_args)
-                .current_dir(temp_dir.path())
+                .current_dir(work_path)
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
                 .spawn()
@@ -201,7 +206,7 @@ impl Runner for Vivado {
         })?;
 
         if wave {
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, path, metadata, work_path)?;
         }
 
         if self.success {
@@ -217,6 +222,10 @@ impl Runner for Vivado {
         "Vivado"
     }
 
+    fn executables(&self) -> &'static [&'static str] {
+        &["xvlog", "xelab", "xsim"]
+    }
+
     fn failure(&mut self) {
         self.success = false;
     }
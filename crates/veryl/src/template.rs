@@ -0,0 +1,229 @@
+use handlebars::Handlebars;
+use miette::{bail, IntoDiagnostic, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const MANIFEST: &str = "template.toml";
+
+/// Files of template as (path, content)
+type TemplateFiles = &'static [(&'static str, &'static str)];
+
+/// Built-in templates as (name, manifest, files)
+const BUILTIN_TEMPLATES: &[(&str, &str, TemplateFiles)] = &[
+    (
+        "default",
+        include_str!("../resource/template/default/template.toml"),
+        &[(
+            "Veryl.toml",
+            include_str!("../resource/template/default/Veryl.toml"),
+        )],
+    ),
+    (
+        "soc-block",
+        include_str!("../resource/template/soc-block/template.toml"),
+        &[
+            (
+                "Veryl.toml",
+                include_str!("../resource/template/soc-block/Veryl.toml"),
+            ),
+            (
+                "src/{{project}}.veryl",
+                include_str!("../resource/template/soc-block/src/{{project}}.veryl"),
+            ),
+            (
+                "src/{{project}}_pkg.veryl",
+                include_str!("../resource/template/soc-block/src/{{project}}_pkg.veryl"),
+            ),
+            (
+                "src/{{project}}_tb.veryl",
+                include_str!("../resource/template/soc-block/src/{{project}}_tb.veryl"),
+            ),
+        ],
+    ),
+];
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default)]
+    description: String,
+}
+
+#[derive(Serialize)]
+struct TemplateData<'a> {
+    project: &'a str,
+}
+
+/// Project template which consists of files containing `{{project}}` placeholders.
+/// Placeholders in file paths are replaced too.
+#[derive(Clone, Debug)]
+pub struct Template {
+    pub name: String,
+    pub description: String,
+    pub origin: TemplateOrigin,
+    files: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug)]
+pub enum TemplateOrigin {
+    Builtin,
+    Local(PathBuf),
+}
+
+impl Template {
+    /// Loads the template specified by path or name.
+    /// Names are searched from the local template directory, then built-in templates.
+    pub fn load(name: &str) -> Result<Self> {
+        let path = Path::new(name);
+        if path.is_dir() {
+            return Self::load_local(path);
+        }
+
+        let local = Self::local_dir().join(name);
+        if local.is_dir() {
+            return Self::load_local(&local);
+        }
+
+        if let Some(x) = Self::builtins().into_iter().find(|x| x.name == name) {
+            return Ok(x);
+        }
+
+        bail!("template \"{name}\" is not found");
+    }
+
+    /// Returns all available templates
+    pub fn list() -> Result<Vec<Self>> {
+        let mut ret = Vec::new();
+
+        let local_dir = Self::local_dir();
+        if local_dir.is_dir() {
+            let mut dirs: Vec<_> = fs::read_dir(&local_dir)
+                .into_diagnostic()?
+                .filter_map(|x| x.ok())
+                .map(|x| x.path())
+                .filter(|x| x.join(MANIFEST).is_file())
+                .collect();
+            dirs.sort();
+            for dir in dirs {
+                ret.push(Self::load_local(&dir)?);
+            }
+        }
+
+        for x in Self::builtins() {
+            if ret.iter().all(|y| y.name != x.name) {
+                ret.push(x);
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Directory of the local templates which can be selected by name
+    pub fn local_dir() -> PathBuf {
+        veryl_path::config_path().join("templates")
+    }
+
+    fn builtins() -> Vec<Self> {
+        BUILTIN_TEMPLATES
+            .iter()
+            .map(|(name, manifest, files)| {
+                let manifest: Manifest = toml::from_str(manifest).unwrap();
+                Template {
+                    name: name.to_string(),
+                    description: manifest.description,
+                    origin: TemplateOrigin::Builtin,
+                    files: files
+                        .iter()
+                        .map(|(path, content)| (path.to_string(), content.to_string()))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn load_local(path: &Path) -> Result<Self> {
+        let manifest_path = path.join(MANIFEST);
+        let manifest = fs::read_to_string(&manifest_path)
+            .into_diagnostic()
+            .wrap_err(format!(
+                "failed to read \"{}\"",
+                manifest_path.to_string_lossy()
+            ))?;
+        let manifest: Manifest = toml::from_str(&manifest)
+            .into_diagnostic()
+            .wrap_err(format!(
+                "failed to parse \"{}\"",
+                manifest_path.to_string_lossy()
+            ))?;
+
+        let mut files = Vec::new();
+        for entry in WalkDir::new(path).sort_by_file_name() {
+            let entry = entry.into_diagnostic()?;
+            if !entry.file_type().is_file() || entry.path() == manifest_path {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(path).into_diagnostic()?;
+            let relative: Vec<_> = relative
+                .components()
+                .map(|x| x.as_os_str().to_string_lossy().to_string())
+                .collect();
+            let content = fs::read_to_string(entry.path()).into_diagnostic()?;
+            files.push((relative.join("/"), content));
+        }
+
+        let name = path
+            .canonicalize()
+            .into_diagnostic()?
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Ok(Template {
+            name,
+            description: manifest.description,
+            origin: TemplateOrigin::Local(path.to_path_buf()),
+            files,
+        })
+    }
+
+    /// Generates files of the template into `base`.
+    /// Nothing is written if rendering fails or any file exists.
+    pub fn generate(&self, base: &Path, project: &str) -> Result<()> {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        handlebars.set_strict_mode(true);
+        let data = TemplateData { project };
+
+        let mut rendered = Vec::new();
+        for (path, content) in &self.files {
+            let path = handlebars
+                .render_template(path, &data)
+                .into_diagnostic()
+                .wrap_err(format!("failed to render path \"{path}\""))?;
+            let content = handlebars
+                .render_template(content, &data)
+                .into_diagnostic()
+                .wrap_err(format!("failed to render \"{path}\""))?;
+            let path = base.join(path);
+            if path.exists() {
+                bail!("\"{}\" exists", path.to_string_lossy());
+            }
+            rendered.push((path, content));
+        }
+
+        fs::create_dir_all(base).into_diagnostic()?;
+        for (path, content) in rendered {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).into_diagnostic()?;
+            }
+            let mut file = File::create(&path).into_diagnostic()?;
+            write!(file, "{content}").into_diagnostic()?;
+            file.flush().into_diagnostic()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use veryl_parser::timing::Record;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Phase {
+    pub name: String,
+    /// Elapsed time while the phase is running on any thread.
+    /// This is available for top-level phases only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wall: Option<f64>,
+    /// Time summed over all files and threads
+    pub cpu: f64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Phase>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FileTimings {
+    pub path: String,
+    pub cpu: f64,
+    pub phases: Vec<Phase>,
+}
+
+/// Summary of timing records of a build
+#[derive(Clone, Debug, Serialize)]
+pub struct Timings {
+    /// Elapsed time of the whole build
+    pub wall: f64,
+    /// Time summed over all top-level phases
+    pub cpu: f64,
+    /// Elapsed time not covered by any phase (e.g. hooks and filelist generation)
+    pub other: f64,
+    pub phases: Vec<Phase>,
+    pub files: Vec<FileTimings>,
+}
+
+impl Timings {
+    pub fn new(records: &[Record], start: Instant, base: &Path) -> Self {
+        let wall = start.elapsed();
+
+        let mut names: Vec<_> = records.iter().filter(|x| x.parent.is_none()).collect();
+        names.sort_by_key(|x| x.start);
+        let mut order: Vec<&str> = Vec::new();
+        for x in names {
+            if !order.contains(&x.name) {
+                order.push(x.name);
+            }
+        }
+
+        let mut phases = Vec::new();
+        for name in &order {
+            let top: Vec<_> = records
+                .iter()
+                .filter(|x| x.parent.is_none() && x.name == *name)
+                .collect();
+            let intervals: Vec<_> = top.iter().map(|x| (x.start, x.duration)).collect();
+            let mut phase = Self::phase(name, records);
+            phase.wall = Some(seconds(union(&intervals)));
+            phases.push(phase);
+        }
+
+        let mut paths: Vec<&PathBuf> = Vec::new();
+        for x in records {
+            if let Some(ref path) = x.path {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        for path in paths {
+            let records: Vec<_> = records
+                .iter()
+                .filter(|x| x.path.as_ref() == Some(path))
+                .cloned()
+                .collect();
+            let phases: Vec<_> = order
+                .iter()
+                .filter(|x| records.iter().any(|y| y.parent.is_none() && y.name == **x))
+                .map(|x| Self::phase(x, &records))
+                .collect();
+            let cpu = phases.iter().map(|x| x.cpu).sum();
+            let path = path.strip_prefix(base).unwrap_or(path);
+            files.push(FileTimings {
+                path: path.to_string_lossy().to_string(),
+                cpu,
+                phases,
+            });
+        }
+        files.sort_by(|x, y| y.cpu.total_cmp(&x.cpu));
+
+        let intervals: Vec<_> = records
+            .iter()
+            .filter(|x| x.parent.is_none())
+            .map(|x| (x.start, x.duration))
+            .collect();
+        let other = wall.saturating_sub(union(&intervals));
+
+        Self {
+            wall: seconds(wall),
+            cpu: phases.iter().map(|x| x.cpu).sum(),
+            other: seconds(other),
+            phases,
+            files,
+        }
+    }
+
+    /// Builds phase `name` with its children.
+    /// Children are sorted by time in descending order.
+    fn phase(name: &str, records: &[Record]) -> Phase {
+        Self::phase_inner(name, None, records)
+    }
+
+    fn phase_inner(name: &str, parent: Option<&str>, records: &[Record]) -> Phase {
+        let cpu: Duration = records
+            .iter()
+            .filter(|x| x.name == name && x.parent == parent)
+            .map(|x| x.duration)
+            .sum();
+
+        let mut children_names: Vec<&str> = Vec::new();
+        for x in records {
+            if x.parent == Some(name) && !children_names.contains(&x.name) {
+                children_names.push(x.name);
+            }
+        }
+        let mut children: Vec<_> = children_names
+            .into_iter()
+            .map(|x| Self::phase_inner(x, Some(name), records))
+            .collect();
+        children.sort_by(|x, y| y.cpu.total_cmp(&x.cpu));
+
+        Phase {
+            name: name.to_string(),
+            wall: None,
+            cpu: seconds(cpu),
+            children,
+        }
+    }
+
+    pub fn to_table(&self) -> String {
+        let mut ret = format!(
+            "Timings: wall {:.3}s, cpu {:.3}s, {} files\n",
+            self.wall,
+            self.cpu,
+            self.files.len()
+        );
+        ret.push_str(&format!(
+            "{:<40} {:>10} {:>10} {:>7}\n",
+            "phase", "wall", "cpu", "share"
+        ));
+        for phase in &self.phases {
+            self.table_row(&mut ret, phase, 0);
+        }
+        ret.push_str(&format!(
+            "{:<40} {:>10} {:>10} {:>7}\n",
+            "(other)",
+            format!("{:.3}s", self.other),
+            "-",
+            "-"
+        ));
+
+        if !self.files.is_empty() {
+            ret.push_str("\nSlowest files\n");
+            for file in self.files.iter().take(5) {
+                ret.push_str(&format!(
+                    "{:<51} {:>10}\n",
+                    file.path,
+                    seconds_str(file.cpu)
+                ));
+            }
+        }
+        ret
+    }
+
+    fn table_row(&self, ret: &mut String, phase: &Phase, depth: usize) {
+        let name = format!("{}{}", "  ".repeat(depth), phase.name);
+        let wall = phase.wall.map(seconds_str).unwrap_or("-".to_string());
+        let share = if self.cpu > 0.0 {
+            format!("{:.1}%", phase.cpu / self.cpu * 100.0)
+        } else {
+            "-".to_string()
+        };
+        ret.push_str(&format!(
+            "{:<40} {:>10} {:>10} {:>7}\n",
+            name,
+            wall,
+            seconds_str(phase.cpu),
+            share
+        ));
+        for child in &phase.children {
+            self.table_row(ret, child, depth + 1);
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!("{}\n", serde_json::to_string_pretty(self).unwrap())
+    }
+}
+
+/// Returns the total length of intervals excluding overlaps
+fn union(intervals: &[(Instant, Duration)]) -> Duration {
+    let mut intervals: Vec<_> = intervals.iter().map(|(s, d)| (*s, *s + *d)).collect();
+    intervals.sort();
+
+    let mut ret = Duration::ZERO;
+    let mut current: Option<(Instant, Instant)> = None;
+    for (beg, end) in intervals {
+        match current {
+            Some((_, cur_end)) if beg <= cur_end => {
+                current = current.map(|(b, e)| (b, e.max(end)));
+            }
+            _ => {
+                if let Some((b, e)) = current {
+                    ret += e - b;
+                }
+                current = Some((beg, end));
+            }
+        }
+    }
+    if let Some((b, e)) = current {
+        ret += e - b;
+    }
+    ret
+}
+
+fn seconds(x: Duration) -> f64 {
+    x.as_secs_f64()
+}
+
+fn seconds_str(x: f64) -> String {
+    format!("{x:.3}s")
+}
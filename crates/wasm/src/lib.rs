@@ -0,0 +1,103 @@
+use serde::Serialize;
+use veryl_formatter::Formatter;
+use veryl_metadata::Format;
+use veryl_parser::{doc_comment_table, resource_table, Parser, ParserError};
+use wasm_bindgen::prelude::*;
+
+/// Virtual path of the source given to the entry points
+const SOURCE_PATH: &str = "playground.veryl";
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub code: Option<String>,
+    /// Byte offset of the error location
+    pub offset: usize,
+    /// Byte length of the error location
+    pub length: usize,
+    /// 1-based line of the error location
+    pub line: usize,
+    /// 1-based column in characters of the error location
+    pub column: usize,
+}
+
+impl ParseError {
+    fn new(err: ParserError, source: &str) -> Self {
+        use miette::Diagnostic;
+
+        let (offset, length) = err
+            .labels()
+            .and_then(|mut x| x.next())
+            .map(|x| (x.offset(), x.len()))
+            .unwrap_or((0, 0));
+        let offset = offset.min(source.len());
+        let boundary = (0..=offset)
+            .rev()
+            .find(|x| source.is_char_boundary(*x))
+            .unwrap_or(0);
+        let before = &source[..boundary];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map(|x| x.chars().count())
+            .unwrap_or(0)
+            + 1;
+
+        Self {
+            code: err.code().map(|x| x.to_string()),
+            message: err.to_string(),
+            offset,
+            length,
+            line,
+            column,
+        }
+    }
+}
+
+/// Clears the global tables filled by the previous call.
+/// The entry points don't keep any tokens across calls, so it is safe to drop them.
+fn clear_tables() {
+    resource_table::clear();
+    doc_comment_table::clear();
+}
+
+fn parse(source: &str) -> Result<Parser, ParseError> {
+    clear_tables();
+    Parser::parse(source, &SOURCE_PATH).map_err(|x| ParseError::new(x, source))
+}
+
+/// Parses format options in the same shape as `[format]` section of Veryl.toml.
+/// Empty string means the default options.
+pub fn format_options(options_json: &str) -> Result<Format, String> {
+    if options_json.trim().is_empty() {
+        Ok(Format::default())
+    } else {
+        serde_json::from_str(options_json).map_err(|x| x.to_string())
+    }
+}
+
+/// Formats `source` by the format options given as JSON.
+/// An error is returned if the options are invalid or `source` can't be parsed.
+#[wasm_bindgen]
+pub fn format(source: &str, options_json: &str) -> Result<String, String> {
+    let format = format_options(options_json)?;
+    let parser = parse(source).map_err(|x| x.message)?;
+
+    let mut formatter = Formatter::with_format(&format);
+    formatter.format(&parser.veryl);
+    Ok(formatter.as_str().to_string())
+}
+
+/// Returns syntax errors of `source` as JSON array of `ParseError`.
+#[wasm_bindgen]
+pub fn parse_errors(source: &str) -> String {
+    let errors = match parse(source) {
+        Ok(_) => vec![],
+        Err(x) => vec![x],
+    };
+    serde_json::to_string(&errors).unwrap()
+}
+
+#[cfg(test)]
+mod tests;
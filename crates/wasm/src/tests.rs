@@ -0,0 +1,32 @@
+use crate::*;
+
+#[test]
+fn format_with_options() {
+    let code = "module ModuleA {\nassign a = 1;\n}\n";
+
+    let ret = format(code, "").unwrap();
+    assert_eq!(ret, "module ModuleA {\n    assign a = 1;\n}\n");
+
+    let ret = format(code, r#"{"indent_width": 2}"#).unwrap();
+    assert_eq!(ret, "module ModuleA {\n  assign a = 1;\n}\n");
+
+    // repeated calls don't depend on the previous one
+    let ret = format(code, "{}").unwrap();
+    assert_eq!(ret, "module ModuleA {\n    assign a = 1;\n}\n");
+
+    assert!(format(code, r#"{"unknown": 1}"#).is_err());
+    assert!(format("module {", "").is_err());
+}
+
+#[test]
+fn parse_errors_as_json() {
+    let ret = parse_errors("module ModuleA {\n    assign a = 1;\n}\n");
+    assert_eq!(ret, "[]");
+
+    let ret = parse_errors("module ModuleA {\n    assign a = ;\n}\n");
+    let errors: Vec<serde_json::Value> = serde_json::from_str(&ret).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["line"], 2);
+    assert_eq!(errors[0]["column"], 16);
+    assert_eq!(errors[0]["offset"], 32);
+}
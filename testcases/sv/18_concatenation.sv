@@ -1,7 +1,7 @@
 module veryl_testcase_Module18;
-    logic a;
-    logic b;
-    logic c;
+    logic [12-1:0]  a;
+    logic [114-1:0] b;
+    logic           c;
     always_comb c = 1;
 
     always_comb a = {a[10:0], c};
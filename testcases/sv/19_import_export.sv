@@ -13,6 +13,8 @@ module veryl_testcase_Module19
 ;
     import veryl_testcase_PackageA::A;
     import veryl_testcase_PackageA::*;
+
+    localparam int unsigned B = A;
 endmodule
 
 interface veryl_testcase_Interface19
@@ -21,6 +23,8 @@ interface veryl_testcase_Interface19
 ;
     import veryl_testcase_PackageA::A;
     import veryl_testcase_PackageA::*;
+
+    localparam int unsigned B = A;
 endinterface
 
 package veryl_testcase_Package19;
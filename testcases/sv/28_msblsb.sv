@@ -46,4 +46,32 @@ module veryl_testcase_ModuleB;
     logic _z;
     always_comb _z = veryl_testcase_Package28C::C[0][($size(veryl_testcase_Package28C::C, 2) - 1)];
 endmodule
+
+package veryl_testcase_Package28D;
+    typedef struct packed {
+        logic [3-1:0][4-1:0] b;
+    } StructB;
+    typedef union packed {
+        logic [8-1:0]        c;
+        logic [2-1:0][4-1:0] d;
+    } UnionC;
+    typedef struct packed {
+        StructB [2-1:0] a;
+        UnionC          u;
+    } StructA;
+endpackage
+
+module veryl_testcase_Module28C (
+    input logic [2-1:0] i
+);
+    veryl_testcase_Package28D::StructA [4-1:0] a;
+    always_comb a = 0;
+
+    logic _x;
+    always_comb _x = a[i].a[1].b[($size(a[0].a[0].b, 1) - 1)];
+    logic _y;
+    always_comb _y = a[0].a[i].b[0][($size(a[0].a[0].b, 2) - 1)];
+    logic _z;
+    always_comb _z = a[i].u.d[($size(a[0].u.d, 1) - 1)];
+endmodule
 //# sourceMappingURL=../map/testcases/sv/28_msblsb.sv.map
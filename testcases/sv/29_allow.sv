@@ -17,9 +17,12 @@ module veryl_testcase_Module29 (
         end
     end
 
-    veryl_testcase_Module29 u0 (
-
+    veryl_testcase_Module29B u0 ();
+endmodule
 
-    );
+module veryl_testcase_Module29B (
+    input logic clk  ,
+    input logic rst_n
+);
 endmodule
 //# sourceMappingURL=../map/testcases/sv/29_allow.sv.map
@@ -30,22 +30,22 @@ module veryl_testcase___Module56Sub__1;
     endfunction
 endmodule
 
-/// Generic interface test for doc comment
+// Generic interface test for doc comment
 interface veryl_testcase___Interface56A__Package56A_X;
     logic [veryl_testcase_Package56A::X-1:0]                            _a;
 endinterface
 
-/// Generic interface test for doc comment
+// Generic interface test for doc comment
 interface veryl_testcase___Interface56A__Package56B_X;
     logic [veryl_testcase_Package56B::X-1:0]                            _a;
 endinterface
 
-/// Generic interface test for doc comment
+// Generic interface test for doc comment
 interface veryl_testcase___Interface56A__1;
     logic [1-1:0]                            _a;
 endinterface
 
-/// Generic interface test for doc comment
+// Generic interface test for doc comment
 interface veryl_testcase___Interface56B__Package56A_X;
     logic [veryl_testcase_Package56A::X-1:0]                            _b;
 endinterface
@@ -19,12 +19,23 @@ module veryl_testcase_Module70 (
 );
     veryl_testcase_Interface70A a ();
     veryl_testcase___Interface70B__8 b ();
+    veryl_testcase_Interface70A d [0:4-1] ();
 
     veryl_testcase_Module70A u (
         .a (a),
         .b (b),
         .c (c)
     );
+
+    veryl_testcase_Module70A v (
+        .a (d[1]),
+        .b (b   ),
+        .c (c   )
+    );
+
+    veryl_testcase_Module70B w (
+        .a (d)
+    );
 endmodule
 
 module veryl_testcase_Module70A (
@@ -33,4 +44,9 @@ module veryl_testcase_Module70A (
     interface c
 );
 endmodule
+
+module veryl_testcase_Module70B (
+    veryl_testcase_Interface70A.port a [0:4-1]
+);
+endmodule
 //# sourceMappingURL=../map/testcases/sv/70_interface_connect.sv.map
@@ -0,0 +1,25 @@
+module veryl_testcase_Module74 (
+    input logic         i_clk  ,
+    input logic         i_rst_n,
+    input logic [2-1:0] i_a
+);
+    logic [2-1:0] a;
+
+    always_comb begin
+        a = i_a;
+        assert (a == i_a) else $error("mismatch");
+    end
+
+    always_ff @ (posedge i_clk, negedge i_rst_n) begin
+        if (!i_rst_n) begin
+            assume (i_a != 3);
+        end else begin
+            cover (i_a == 2);
+        end
+    end
+
+    assert property (@(posedge i_clk) disable iff (!i_rst_n) a != 3) else $error("a must not be 3");
+    assume property (@(posedge i_clk) disable iff (!i_rst_n) i_a != 3);
+    cover property (@(posedge i_clk) disable iff (!i_rst_n) a == 2);
+endmodule
+//# sourceMappingURL=../map/testcases/sv/74_assertion.sv.map
@@ -0,0 +1,26 @@
+package veryl_testcase_Package75;
+
+    localparam int unsigned WIDTH = 8;
+
+    typedef logic [WIDTH-1:0] data_t;
+
+    function automatic data_t inc(
+        input data_t a
+    ) ;
+        return a + 1;
+    endfunction
+
+    function automatic data_t inc2(
+        input data_t a
+    ) ;
+        return inc(inc(a));
+    endfunction
+endpackage
+
+module veryl_testcase_Module75 (
+    input  veryl_testcase_Package75::data_t i_a,
+    output veryl_testcase_Package75::data_t o_b
+);
+    always_comb o_b = veryl_testcase_Package75::inc2(i_a);
+endmodule
+//# sourceMappingURL=../map/testcases/sv/75_private_item.sv.map
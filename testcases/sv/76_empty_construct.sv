@@ -0,0 +1,30 @@
+module veryl_testcase_Module76A ();
+endmodule
+
+module veryl_testcase_Module76B #() ();
+    // only comment
+endmodule
+
+module veryl_testcase_Module76C #(
+    // parameter comment
+) (
+    // port comment
+);
+    veryl_testcase_Module76A u0 ();
+    veryl_testcase_Module76A u1 (
+        // instance comment
+    );
+    veryl_testcase_Module76B #() u2 ();
+    veryl_testcase_Module76B #(
+        // parameter comment
+    ) u3 ();
+    veryl_testcase_Module76A u4 ();
+endmodule
+
+interface veryl_testcase_Interface76;
+endinterface
+
+package veryl_testcase_Package76;
+    // only comment
+endpackage
+//# sourceMappingURL=../map/testcases/sv/76_empty_construct.sv.map
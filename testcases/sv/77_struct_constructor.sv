@@ -0,0 +1,22 @@
+package veryl_testcase_Package77;
+    typedef struct packed {
+        logic [8-1:0] a;
+        logic         b;
+        logic [4-1:0] c;
+    } StructA;
+endpackage
+
+module veryl_testcase_Module77 (
+    input  logic                             [8-1:0] i_x,
+    output veryl_testcase_Package77::StructA         o_a,
+    output veryl_testcase_Package77::StructA         o_b,
+    output veryl_testcase_Package77::StructA         o_c
+);
+    typedef veryl_testcase_Package77::StructA AliasA;
+
+    always_comb o_a = veryl_testcase_Package77::StructA'{a: i_x, b: 1'b0, c: 4'h3};
+    always_comb o_b = AliasA'{a: i_x, default: 0};
+    always_comb o_c = veryl_testcase_Package77::StructA'{a: i_x, c: 4'h1 // comment
+    , default: '0};
+endmodule
+//# sourceMappingURL=../map/testcases/sv/77_struct_constructor.sv.map